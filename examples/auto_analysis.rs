@@ -0,0 +1,152 @@
+mod utils;
+
+use utils::*;
+use eva_rt_engine::prelude::*;
+use eva_rt_engine::algorithms::registry;
+
+#[derive(clap::Parser, Debug,  Clone)]
+pub struct Args {
+    pub input_file: String,
+
+    #[command(flatten)]
+    pub platform: PlatformArgs,
+
+    #[command(flatten)]
+    pub what_if: WhatIfArgs,
+
+    /// Validate the taskset and configuration files against their JSON
+    /// Schemas before the analysis. Requires a versioned taskset.
+    #[arg(long, default_value_t = false)]
+    pub strict: bool,
+
+    /// Write a self-contained report of the run
+    #[arg(long, value_enum)]
+    pub report: Option<report::ReportFormat>,
+
+    /// Path of the report [default: report.md or report.html]
+    #[arg(long, requires = "report")]
+    pub report_file: Option<String>,
+
+    /// Write a certification evidence bundle of the run: a ZIP archive with the
+    /// input files, the results with their assumptions, the reports and the
+    /// manifest of their SHA-256 digests
+    #[arg(long)]
+    pub evidence: Option<String>,
+
+    /// Sign the manifest of the evidence bundle with HMAC-SHA256, with the
+    /// content of the given file, without trailing whitespace, as the shared
+    /// secret key. This is not a public-key signature: anyone who holds the
+    /// key can check, and forge, the bundle
+    #[arg(long, requires = "evidence")]
+    pub signing_key_file: Option<String>,
+
+    /// Print the tests which do not apply, with the reason of their exclusion.
+    #[arg(long, default_value_t = false)]
+    pub show_excluded: bool,
+}
+
+fn main() -> anyhow::Result<()> {
+    let args = <Args as clap::Parser>::parse();
+
+    if args.strict {
+        if !args.input_file.ends_with(".taskset.json") {
+            return Err(anyhow::format_err!("strict mode requires a versioned taskset (*.taskset.json)"));
+        }
+
+        schema::validate_file(schema::SchemaKind::Taskset, &args.input_file)?;
+
+        if let Some(config_file) = &args.platform.config {
+            schema::validate_file(schema::SchemaKind::Config, config_file)?;
+        }
+    }
+
+    let (taskset, margins) = parse_annotated_taskset(&args.input_file, TasksetPlainUnit::Millis)?;
+    let measured = args.what_if.apply(&taskset)?;
+
+    let (platform, latencies, profile) = args.platform.resolve()?;
+
+    // The scheduling overheads are charged to the WCETs, for every test.
+    let overheads = args.platform.overheads()?;
+    overheads.check_preconditions()?;
+    let taskset = overheads.inflate_taskset(&measured);
+
+    let mut resolution = registry::resolve(&taskset, &platform, profile)?;
+    for warning in resolution.select(&args.platform.selected_tests()?)? {
+        eprintln!("Warning: {warning}");
+    }
+
+    if args.show_excluded {
+        for exclusion in &resolution.excluded {
+            println!("Excluded \"{}\" ({}): {}", exclusion.analyzer, exclusion.id, exclusion.reason);
+        }
+    }
+
+    let taskset: std::sync::Arc<[RTTask]> = taskset.into();
+    let (results, excluded) = run_profile(resolution, taskset.clone(), &latencies, profile)?;
+
+    // The same tests, on the WCETs inflated by the margins of the tasks.
+    let results_with_margins =
+        if margins.is_none() {
+            None
+        } else {
+            margins.check_preconditions(&measured)?;
+            println!("With WCET margins:");
+
+            let inflated = overheads.inflate_taskset(&margins.apply(&measured));
+            let mut resolution = registry::resolve(&inflated, &platform, profile)?;
+            resolution.select(&args.platform.selected_tests()?)?;
+
+            let (results_with_margins, _) = run_profile(resolution, inflated.into(), &latencies, profile)?;
+
+            let (verdict, verdict_with_margins) = (report::verdict(&results), report::verdict(&results_with_margins));
+            println!("Verdict: {} without WCET margins, {} with them{}", report::verdict_name(verdict),
+                report::verdict_name(verdict_with_margins),
+                if verdict != verdict_with_margins { ", the verdict depends on the WCET estimation error" } else { "" });
+
+            Some(results_with_margins)
+        };
+
+    if args.report.is_some() || args.evidence.is_some() {
+        let report = report::Report {
+            input_file: &args.input_file,
+            taskset: &taskset,
+            platform: &platform,
+            profile,
+            results,
+            results_with_margins,
+            excluded,
+            response_times: report::response_times(&taskset, &platform, &latencies),
+            provenance: report::Provenance::of_file(&args.input_file,
+                [args.platform.provenance_parameters(&latencies)?, args.what_if.provenance_parameters()].concat())?,
+        };
+
+        if let Some(format) = args.report {
+            let report_file = args.report_file.clone().unwrap_or_else(|| format!("report.{}", format.extension()));
+            std::fs::write(&report_file, report.render(format))?;
+            println!("Report written to {report_file}");
+        }
+
+        if let Some(evidence_file) = &args.evidence {
+            let inputs = std::iter::once(&args.input_file).chain(&args.platform.config)
+                .map(|file| {
+                    let name = std::path::Path::new(file).file_name()
+                        .map_or_else(|| file.clone(), |name| name.to_string_lossy().into_owned());
+                    Ok((name, std::fs::read(file)?))
+                })
+                .collect::<anyhow::Result<Vec<_>>>()?;
+
+            let signing_key = args.signing_key_file.as_ref()
+                .map(|key_file| {
+                    let key = std::fs::read(key_file)?.trim_ascii_end().to_vec();
+                    anyhow::ensure!(!key.is_empty(), "the signing key file {key_file} is empty");
+                    Ok(key)
+                })
+                .transpose()?;
+
+            std::fs::write(evidence_file, evidence::bundle(&report, &inputs, signing_key.as_deref())?)?;
+            println!("Evidence bundle written to {evidence_file}");
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,104 @@
+use eva_rt_engine::prelude::*;
+use eva_rt_engine::utils::utilization_bounds;
+
+#[derive(clap::Parser, Debug,  Clone)]
+pub struct Args {
+    #[command(subcommand)]
+    pub bound: Bound,
+}
+
+/// Closed-form utilization bounds for Rate Monotonic scheduling.
+#[derive(clap::Subcommand, Debug, Clone)]
+pub enum Bound {
+    /// Liu & Layland bound for the given number of tasks
+    Rm {
+        #[arg(long)]
+        tasks: u64,
+
+        /// Print the bounds for every number of tasks up to the given one
+        #[arg(long, default_value_t = false)]
+        table: bool,
+    },
+    /// Hyperbolic bound for the given utilizations
+    Hyperbolic {
+        #[arg(long, num_args = 1.., required = true)]
+        utilizations: Vec<f64>,
+    },
+    /// Kuo & Mok bound for the given number of harmonic chains, or for the
+    /// chains of the given periods
+    Harmonic {
+        #[arg(long, required_unless_present = "periods")]
+        chains: Option<u64>,
+
+        #[arg(long, num_args = 1.., conflicts_with = "chains")]
+        periods: Vec<f64>,
+    },
+    /// Burchard, Liebeherr, Oh & Son bound for the spread of the given periods
+    Spread {
+        #[arg(long, num_args = 1.., required = true)]
+        periods: Vec<f64>,
+    },
+    /// Hyperbolic bound with blocking, for tasks sorted by period
+    Blocking {
+        /// Tasks' WCETs
+        #[arg(long, num_args = 1.., required = true)]
+        wcets: Vec<f64>,
+
+        /// Tasks' periods
+        #[arg(long, num_args = 1.., required = true)]
+        periods: Vec<f64>,
+
+        /// Tasks' blocking times
+        #[arg(long, num_args = 1.., required = true)]
+        blocking: Vec<f64>,
+    },
+}
+
+fn main() -> anyhow::Result<()> {
+    let args = <Args as clap::Parser>::parse();
+
+    match args.bound {
+        Bound::Rm { tasks, table } => {
+            let from = if table { 1 } else { tasks };
+
+            for n in from..=tasks {
+                println!("n = {n}: U <= {:.6}", utilization_bounds::liu_layland(n));
+            }
+        },
+        Bound::Hyperbolic { utilizations } => {
+            let product = utilization_bounds::hyperbolic(utilizations.iter().copied());
+
+            println!("prod(U_i + 1) = {product:.6} ({})", if product <= 2.0 { "schedulable" } else { "bound exceeded" });
+            println!("largest utilization of an additional task: {:.6}", f64::max(2.0 / product - 1.0, 0.0));
+        },
+        Bound::Harmonic { chains, periods } => {
+            let chains = chains.unwrap_or_else(|| {
+                let periods: Vec<_> = periods.into_iter().map(Time::nanos).collect();
+                utilization_bounds::harmonic_chains(&periods)
+            });
+
+            println!("K = {chains}: U <= {:.6}", utilization_bounds::kuo_mok(chains));
+        },
+        Bound::Spread { periods } => {
+            let n = periods.len();
+            let periods: Vec<_> = periods.into_iter().map(Time::nanos).collect();
+
+            println!("n = {n}: U <= {:.6}", utilization_bounds::burchard(&periods));
+        },
+        Bound::Blocking { wcets, periods, blocking } => {
+            anyhow::ensure!(wcets.len() == periods.len() && periods.len() == blocking.len(),
+                "the WCETs, periods and blocking times must be as many as the tasks");
+
+            let taskset: Vec<_> = wcets.iter().zip(&periods)
+                .map(|(&wcet, &period)| RTTask { wcet: Time::nanos(wcet), deadline: Time::nanos(period), period: Time::nanos(period) })
+                .collect();
+            let blocking: Vec<_> = blocking.into_iter().map(Time::nanos).collect();
+
+            let bound = utilization_bounds::hyperbolic_blocking(&taskset, &blocking);
+            println!("max_i (U_i + B_i/T_i + 1) prod_(j<i)(U_j + 1) = {bound:.6} ({})",
+                if bound <= 2.0 { "schedulable" } else { "bound exceeded" });
+        },
+    }
+
+    Ok(())
+}
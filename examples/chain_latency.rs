@@ -0,0 +1,99 @@
+mod utils;
+
+use utils::*;
+use eva_rt_engine::prelude::*;
+use eva_rt_engine::algorithms::full_preemption::end_to_end::*;
+
+#[derive(clap::Parser, Debug,  Clone)]
+pub struct Args {
+    /// Taskset file
+    pub input_file: String,
+
+    /// Chains file, declaring the tasks of each chain by index and its
+    /// end-to-end deadline in milliseconds:
+    /// { "chains": [ { "name": "control", "tasks": [0, 2], "deadline": 50 } ] }
+    pub chains_file: String,
+
+    #[command(flatten)]
+    pub platform: PlatformArgs,
+
+    #[command(flatten)]
+    pub what_if: WhatIfArgs,
+
+    /// Report how much the WCET of each task of the chains can grow before
+    /// any chain misses its deadline, from the most critical task
+    #[arg(long, default_value_t = false)]
+    pub sensitivity: bool,
+}
+
+#[derive(serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ChainsFile {
+    chains: Vec<ChainEntry>,
+}
+
+#[derive(serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ChainEntry {
+    #[serde(default)]
+    name: Option<String>,
+    tasks: Vec<usize>,
+    /// End-to-end deadline, milliseconds
+    deadline: f64,
+}
+
+/// Verify the end-to-end deadlines of the task chains, reporting the latency
+/// and the slack left after each hop of every chain.
+fn main() -> anyhow::Result<()> {
+    let args = <Args as clap::Parser>::parse();
+
+    let taskset = args.what_if.apply(&parse_taskset(&args.input_file, TasksetPlainUnit::Millis)?)?;
+    let (platform, latencies, _) = args.platform.resolve()?;
+
+    let entries: ChainsFile = serde_json::from_str(&std::fs::read_to_string(&args.chains_file)?)?;
+    let names: Vec<_> = entries.chains.iter().enumerate()
+        .map(|(c, entry)| entry.name.clone().unwrap_or_else(|| format!("chain {c}")))
+        .collect();
+    let chains: Vec<_> = entries.chains.into_iter()
+        .map(|entry| TaskChain { tasks: entry.tasks, deadline: Time::millis(entry.deadline) })
+        .collect();
+
+    for (chain, name) in chains.iter().zip(&names) {
+        anyhow::ensure!(!chain.tasks.is_empty(), "{name} has no tasks");
+        if let Some(i) = chain.tasks.iter().find(|&&i| i >= taskset.len()) {
+            anyhow::bail!("task {i} of {name} is not in the taskset");
+        }
+    }
+
+    let analysis = report::response_time_analysis(&platform, &latencies)
+        .ok_or_else(|| anyhow::format_err!("no response time analysis applies to the platform"))?;
+    let response_times = analysis.is_schedulable(&taskset)?;
+
+    let mut missed = 0;
+    for ((chain, latency), name) in chains.iter().zip(chain_latencies(&taskset, &response_times, &chains)).zip(&names) {
+        println!("{name}: {} (latency {}ms, deadline {}ms, slack {}ms)",
+            if latency.is_met() { "met" } else { "MISSED" },
+            latency.latency().as_millis(), latency.deadline.as_millis(), latency.slack().as_millis());
+
+        for ((&i, hop), slack) in chain.tasks.iter().zip(&latency.hops).zip(latency.slack_along()) {
+            println!("\ttask {i}: latency {}ms, slack {}ms", hop.as_millis(), slack.as_millis());
+        }
+
+        if !latency.is_met() {
+            missed += 1;
+        }
+    }
+
+    anyhow::ensure!(missed == 0, "{missed} of {} chains miss their end-to-end deadline", chains.len());
+
+    if args.sensitivity {
+        println!("WCET sensitivity, most critical first:");
+
+        let analysis = AnalysisEndToEnd { chains, analysis };
+        for sensitivity in analysis.wcet_sensitivity(&taskset)? {
+            println!("\ttask {}: WCET can grow by {}ms", sensitivity.task, sensitivity.max_increase.as_millis());
+        }
+    }
+
+    Ok(())
+}
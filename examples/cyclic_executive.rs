@@ -0,0 +1,63 @@
+mod utils;
+
+use utils::*;
+use utils::dispatch_table::{DispatchTable, write_dispatch_table};
+use eva_rt_engine::prelude::*;
+use eva_rt_engine::algorithms::cyclic_executive;
+
+#[derive(clap::Parser, Debug,  Clone)]
+pub struct Args {
+    pub input_file: String,
+
+    /// Time quantum of the table (in microseconds)
+    #[arg(long="quantum-us", default_value_t = 1.0)]
+    pub quantum_us: f64,
+
+    /// Shorten the periods to harmonic ones, for a shorter major frame
+    #[arg(long, default_value_t = false)]
+    pub harmonize: bool,
+
+    /// Write the table to the given .table.json or .csv file, with the times
+    /// in milliseconds
+    #[arg(long)]
+    pub output: Option<String>,
+}
+
+/// Synthesize the dispatch table of a cyclic executive for the taskset, and
+/// print it frame by frame.
+fn main() -> anyhow::Result<()> {
+    let args = <Args as clap::Parser>::parse();
+
+    let taskset = parse_taskset(&args.input_file, TasksetPlainUnit::Millis)?;
+
+    let designer = cyclic_executive::Designer {
+        quantization: Quantization { quantum: Time::micros(args.quantum_us) },
+        harmonize: args.harmonize,
+    };
+
+    let executive = designer.design(&taskset[..])?;
+
+    println!("Major frame: {}ms, minor frame: {}ms, {} frames",
+        executive.major_frame.as_millis(), executive.minor_frame.as_millis(), executive.num_frames());
+
+    if args.harmonize {
+        for (i, task) in executive.tasks.iter().enumerate() {
+            println!("\ttask {i}: dispatched every {}ms, deadline {}ms", task.period.as_millis(), task.deadline.as_millis());
+        }
+    }
+
+    for frame in 0..executive.num_frames() {
+        println!("Frame {frame}:");
+
+        for dispatch in executive.frame(frame) {
+            println!("\t{:>10}ms: task {} job {} for {}ms", dispatch.start.as_millis(), dispatch.task,
+                dispatch.job, dispatch.duration.as_millis());
+        }
+    }
+
+    if let Some(output) = args.output {
+        write_dispatch_table(output, &DispatchTable::from_executive(&executive), TasksetPlainUnit::Millis)?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,65 @@
+mod utils;
+
+use utils::*;
+use utils::dispatch_table::parse_dispatch_table;
+use eva_rt_engine::prelude::*;
+use eva_rt_engine::algorithms::cyclic_executive::checker::{self, Precedence};
+
+#[derive(clap::Parser, Debug,  Clone)]
+pub struct Args {
+    pub input_file: String,
+
+    /// Dispatch table, either a .table.json or a .csv file
+    pub table_file: String,
+
+    /// Unit of the times of CSV tables
+    #[arg(long, value_enum, default_value_t = TasksetPlainUnit::Millis)]
+    pub unit: TasksetPlainUnit,
+
+    /// Length of the major frame, in the unit of the table [default: the one
+    /// of the table, or the hyperperiod]
+    #[arg(long)]
+    pub major_frame: Option<f64>,
+
+    /// Additional precedence constraint, as `predecessor:successor` task
+    /// indices
+    #[arg(long = "precedence", value_parser = parse_precedence)]
+    pub precedences: Vec<Precedence>,
+}
+
+fn parse_precedence(precedence: &str) -> Result<Precedence, String> {
+    let (predecessor, successor) = precedence.split_once(':')
+        .ok_or_else(|| format!("invalid precedence \"{precedence}\", expected predecessor:successor"))?;
+    let parse = |value: &str| value.trim().parse::<usize>().map_err(|err| format!("invalid precedence \"{precedence}\": {err}"));
+
+    Ok(Precedence { predecessor: parse(predecessor)?, successor: parse(successor)? })
+}
+
+/// Verify an existing dispatch table against the taskset over one major frame,
+/// reporting every violation with its exact time. Fails if the table is not
+/// feasible. Refer to [`checker`] for the checked constraints.
+fn main() -> anyhow::Result<()> {
+    let args = <Args as clap::Parser>::parse();
+
+    let taskset = parse_taskset(&args.input_file, TasksetPlainUnit::Millis)?;
+    let mut table = parse_dispatch_table(&args.table_file, args.unit)?;
+    table.precedences.extend(args.precedences.iter().copied());
+
+    let major_frame = args.major_frame
+        .map(|major_frame| Time::nanos(major_frame * args.unit.nanos()))
+        .or(table.major_frame)
+        .unwrap_or_else(|| RTUtils::hyperperiod(&taskset));
+
+    let violations = checker::check(&taskset, major_frame, &table.table, &table.precedences)?;
+
+    println!("Checked {} dispatches of {} tasks over a major frame of {}ns:",
+        table.table.len(), taskset.len(), major_frame.as_nanos());
+
+    for violation in violations.iter() {
+        println!("\tat {:>12}ns: {violation}", violation.time().as_nanos());
+    }
+
+    anyhow::ensure!(violations.is_empty(), "{} violation(s) of the dispatch table", violations.len());
+    println!("\tno violations");
+    Ok(())
+}
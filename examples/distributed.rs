@@ -0,0 +1,86 @@
+mod utils;
+
+use utils::*;
+use eva_rt_engine::prelude::*;
+use eva_rt_engine::algorithms::full_preemption::distributed::*;
+
+#[derive(clap::Parser, Debug,  Clone)]
+pub struct Args {
+    /// Distributed system file (*.system.json)
+    pub system_file: String,
+
+    /// Validate the system file against its JSON Schema before the analysis.
+    #[arg(long, default_value_t = false)]
+    pub strict: bool,
+
+    /// Analysis of the system. The offset based ones treat the flows as
+    /// transactions without static offsets.
+    #[arg(long, value_enum, default_value="holistic")]
+    pub analysis: DistributedAnalysis,
+}
+
+#[derive(Debug, Clone, Copy)]
+#[derive(clap::ValueEnum)]
+pub enum DistributedAnalysis {
+    /// Holistic analysis, Tindell & Clark 1994
+    Holistic,
+    /// Worst-case dynamic offsets, Palencia & González Harbour 1998
+    Wcdo,
+    /// WCDO with precedence relations, Palencia & González Harbour 1999
+    Wcdops,
+}
+
+/// Run the chosen analysis of a distributed system, reporting the response
+/// times of the tasks and messages, and the latency and slack of every flow.
+fn main() -> anyhow::Result<()> {
+    let args = <Args as clap::Parser>::parse();
+
+    if args.strict {
+        schema::validate_file(schema::SchemaKind::System, &args.system_file)?;
+    }
+
+    let system = schema::parse_system(&args.system_file)?;
+
+    let analysis: Box<dyn for<'a> SchedAnalysis<holistic94::HolisticResponseTimes, &'a DistributedSystem>> =
+        match args.analysis {
+            DistributedAnalysis::Holistic => Box::new(holistic94::Analysis),
+            DistributedAnalysis::Wcdo => Box::new(palencia98::Analysis { offsets: Vec::new() }),
+            DistributedAnalysis::Wcdops => Box::new(palencia98::AnalysisPrecedence { offsets: Vec::new() }),
+        };
+
+    println!("Running \"{}\":", analysis.analyzer_name());
+    let result = analysis.is_schedulable(&system)?;
+
+    let print_response_times = |kind: &str, response_times: &[Time]| {
+        let response_times: Vec<_> = response_times.iter()
+            .map(|response| format!("{}ms", response.as_millis()))
+            .collect();
+
+        println!("\t{kind}: {}", response_times.join(", "));
+    };
+
+    for (n, response_times) in result.nodes.iter().enumerate() {
+        print_response_times(&format!("node {n}"), response_times);
+    }
+
+    for (k, response_times) in result.networks.iter().enumerate() {
+        print_response_times(&format!("network {k}"), response_times);
+    }
+
+    for ((f, flow), latency) in system.flows.iter().enumerate().zip(&result.flows) {
+        println!("\tflow {f}: latency {}ms, deadline {}ms, slack {}ms",
+            latency.latency().as_millis(), latency.deadline.as_millis(), latency.slack().as_millis());
+
+        for ((step, hop), slack) in flow.steps.iter().zip(&latency.hops).zip(latency.slack_along()) {
+            let step =
+                match step {
+                    Step::Task { node, task } => format!("task {task} of node {node}"),
+                    Step::Message { network, message } => format!("message {message} of network {network}"),
+                };
+
+            println!("\t\t{step}: latency {}ms, slack {}ms", hop.as_millis(), slack.as_millis());
+        }
+    }
+
+    Ok(())
+}
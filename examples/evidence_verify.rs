@@ -0,0 +1,36 @@
+mod utils;
+
+use utils::*;
+
+#[derive(clap::Parser, Debug,  Clone)]
+pub struct Args {
+    /// Evidence bundle, as written by auto_analysis --evidence
+    pub bundle_file: String,
+
+    /// Check the HMAC-SHA256 signature of the manifest with the shared secret
+    /// key in the given file (not a public-key signature)
+    #[arg(long)]
+    pub signing_key_file: Option<String>,
+}
+
+/// Check the files of an evidence bundle against the digests of its manifest,
+/// and the signature of the manifest if a key is given.
+fn main() -> anyhow::Result<()> {
+    let args = <Args as clap::Parser>::parse();
+
+    let signing_key = args.signing_key_file.as_ref()
+        .map(|key_file| std::fs::read(key_file).map(|key| key.trim_ascii_end().to_vec()))
+        .transpose()?;
+
+    let checked = evidence::verify(&std::fs::read(&args.bundle_file)?, signing_key.as_deref())?;
+
+    for path in checked {
+        println!("\t{path}: ok");
+    }
+
+    if signing_key.is_some() {
+        println!("Manifest signature: ok");
+    }
+
+    Ok(())
+}
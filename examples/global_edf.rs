@@ -1,6 +1,7 @@
 mod utils;
 
 use utils::*;
+use eva_rt_engine::prelude::*;
 use eva_rt_engine::algorithms::full_preemption::global_multiprocessor::earliest_deadline_first::*;
 
 #[derive(clap::Parser, Debug,  Clone)]
@@ -9,17 +10,35 @@ pub struct Args {
 
     #[arg(short='c')]
     pub cpus: u64,
+
+    #[command(flatten)]
+    pub latencies: KernelLatencyArgs,
+
+    #[command(flatten)]
+    pub what_if: WhatIfArgs,
+
+    /// Discretize the taskset to the given time quantum (in microseconds),
+    /// rounding WCETs up and deadlines and periods down.
+    #[arg(long="quantum-us")]
+    pub quantum_us: Option<f64>,
 }
 
 fn main() -> anyhow::Result<()> {
     let args = <Args as clap::Parser>::parse();
 
-    let taskset = parse_taskset(&args.input_file, TasksetPlainUnit::Millis)?;
+    let mut taskset = args.what_if.apply(&parse_taskset(&args.input_file, TasksetPlainUnit::Millis)?)?;
+
+    if let Some(quantum_us) = args.quantum_us {
+        let quantization = Quantization { quantum: Time::micros(quantum_us) };
+        quantization.check_preconditions(&taskset)?;
+
+        taskset = quantization.quantize_taskset(&taskset);
+    }
 
-    run_analysis(gbf03::AnalysisSporadic { num_processors: args.cpus }, &taskset)?;
-    run_analysis(baker03::Analysis { num_processors: args.cpus }, &taskset)?;
-    run_analysis(bcl05::Analysis { num_processors: args.cpus }, &taskset)?;
-    run_analysis(bcl09::Analysis { num_processors: args.cpus }, &taskset)?;
+    run_analysis(args.latencies.apply(gbf03::AnalysisSporadic { num_processors: args.cpus }), &taskset)?;
+    run_analysis(args.latencies.apply(baker03::Analysis { num_processors: args.cpus }), &taskset)?;
+    run_analysis(args.latencies.apply(bcl05::Analysis { num_processors: args.cpus }), &taskset)?;
+    run_analysis(args.latencies.apply(bcl09::Analysis { num_processors: args.cpus }), &taskset)?;
 
     Ok(())
 }
@@ -9,16 +9,22 @@ pub struct Args {
 
     #[arg(short='c')]
     pub cpus: u64,
+
+    #[command(flatten)]
+    pub latencies: KernelLatencyArgs,
+
+    #[command(flatten)]
+    pub what_if: WhatIfArgs,
 }
 
 fn main() -> anyhow::Result<()> {
     let args = <Args as clap::Parser>::parse();
 
-    let taskset = parse_taskset(&args.input_file, TasksetPlainUnit::Millis)?;
+    let taskset = args.what_if.apply(&parse_taskset(&args.input_file, TasksetPlainUnit::Millis)?)?;
 
-    run_analysis(deadline_monotonic_bcl05::Analysis { num_processors: args.cpus }, &taskset)?;
-    run_analysis(bcl09::Analysis { num_processors: args.cpus }, &taskset)?;
-    run_analysis(rta_lc09::Analysis { num_processors: args.cpus }, &taskset)?;
+    run_analysis(args.latencies.apply(deadline_monotonic_bcl05::Analysis { num_processors: args.cpus }), &taskset)?;
+    run_analysis(args.latencies.apply(bcl09::Analysis { num_processors: args.cpus }), &taskset)?;
+    run_analysis(args.latencies.apply(rta_lc09::Analysis { num_processors: args.cpus }), &taskset)?;
 
     Ok(())
 }
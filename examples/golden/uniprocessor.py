@@ -0,0 +1,153 @@
+#!/usr/bin/env python3
+"""Reference implementation of the uniprocessor tests, for golden_check.
+
+Reads the test ID, the platform and the taskset as JSON from the standard
+input, and prints the verdict. Times are integer nanoseconds and utilizations
+exact fractions, independently of the floating point arithmetic of EVA.
+"""
+
+import json
+import math
+import sys
+from fractions import Fraction
+
+UNITS = {"millis": 1_000_000, "micros": 1_000, "nanos": 1}
+
+
+def tasks_of(taskset):
+    scale = UNITS[taskset["unit"]]
+    return [(round(task["wcet"] * scale), round(task["deadline"] * scale), round(task["period"] * scale))
+            for task in taskset["tasks"]]
+
+
+def utilization(tasks):
+    return sum(Fraction(c, t) for c, _, t in tasks)
+
+
+def edf73(tasks):
+    return utilization(tasks) <= 1
+
+
+def brh90(tasks):
+    # Processor demand at every deadline within the synchronous busy period.
+    if utilization(tasks) > 1:
+        return False
+
+    busy_period = fixpoint(sum(c for c, _, _ in tasks), lambda w: sum(-(-w // t) * c for c, _, t in tasks))
+    deadlines = {d + k * t for c, d, t in tasks for k in range(busy_period // t + 1) if d + k * t <= busy_period}
+
+    return all(sum(max(0, (l - d) // t + 1) * c for c, d, t in tasks) <= l for l in deadlines)
+
+
+def rate_monotonic73(tasks):
+    n = len(tasks)
+    return float(utilization(tasks)) <= n * (2 ** (1 / n) - 1)
+
+
+def hyperbolic01(tasks):
+    bound = Fraction(1)
+    for c, _, t in tasks:
+        bound *= Fraction(c, t) + 1
+    return bound <= 2
+
+
+def kuo_mok91(tasks):
+    if not tasks:
+        return True
+
+    # Minimum number of harmonic chains, by a maximum matching between each
+    # period and its multiples.
+    periods = [t for _, _, t in tasks]
+    follows = [[j for j in range(len(periods)) if j != i and periods[j] % periods[i] == 0
+                and (periods[i] != periods[j] or i < j)] for i in range(len(periods))]
+    next_of = {}
+
+    def augment(i, visited):
+        for j in follows[i]:
+            if j not in visited:
+                visited.add(j)
+                if j not in next_of or augment(next_of[j], visited):
+                    next_of[j] = i
+                    return True
+        return False
+
+    k = len(periods) - sum(augment(i, set()) for i in range(len(periods)))
+    return float(utilization(tasks)) <= k * (2 ** (1 / k) - 1)
+
+
+def burchard95(tasks):
+    if not tasks:
+        return True
+
+    n = len(tasks)
+    fractions = sorted(math.log2(t) % 1 for _, _, t in tasks)
+    gaps = [b - a for a, b in zip(fractions, fractions[1:])] + [fractions[0] + 1 - fractions[-1]]
+    beta = 1 - max(gaps)
+
+    if n <= 1 or beta >= 1 - 1 / n:
+        bound = n * (2 ** (1 / n) - 1)
+    else:
+        bound = (n - 1) * (2 ** (beta / (n - 1)) - 1) + 2 ** (1 - beta) - 1
+    return float(utilization(tasks)) <= bound
+
+
+def deadline_monotonic90(tasks):
+    return all(c + sum(math.ceil(d / t) * hc for hc, _, t in tasks[:i]) <= d
+               for i, (c, d, _) in enumerate(tasks))
+
+
+def fixpoint(start, demand):
+    current = start
+    while True:
+        following = demand(current)
+        if following == current:
+            return current
+        current = following
+
+
+def rta86(tasks):
+    # Lehoczky's busy period analysis, exact for arbitrary deadlines.
+    if utilization(tasks) > 1:
+        return False
+
+    for i, (c, d, t) in enumerate(tasks):
+        hp = tasks[:i]
+        busy_period = fixpoint(c, lambda w: sum(-(-w // ht) * hc for hc, _, ht in tasks[:i + 1]))
+
+        for q in range(-(-busy_period // t)):
+            finish = fixpoint((q + 1) * c, lambda w: (q + 1) * c + sum(-(-w // ht) * hc for hc, _, ht in hp))
+            if finish - q * t > d:
+                return False
+    return True
+
+
+TESTS = {
+    "up.edf.edf73": edf73,
+    "up.edf.brh90": brh90,
+    # Exact as the processor demand criterion, only faster.
+    "up.edf.qpa09": brh90,
+    "up.fp.rate_monotonic73": rate_monotonic73,
+    "up.fp.hyperbolic01": hyperbolic01,
+    "up.fp.kuo_mok91": kuo_mok91,
+    "up.fp.burchard95": burchard95,
+    "up.fp.deadline_monotonic90": deadline_monotonic90,
+    "up.fp.rta86": rta86,
+    # Exact as the response time analysis, on constrained deadlines.
+    "up.fp.hyperplanes04": rta86,
+    "up.fp.tda89": rta86,
+}
+
+
+def main():
+    document = json.load(sys.stdin)
+
+    test = TESTS.get(document["test"])
+    if test is None:
+        sys.exit(f"no reference implementation of \"{document['test']}\"")
+
+    schedulable = test(tasks_of(document["taskset"]))
+    print("schedulable" if schedulable else "non schedulable")
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,60 @@
+mod utils;
+
+use utils::*;
+use eva_rt_engine::prelude::*;
+use eva_rt_engine::algorithms::registry;
+
+#[derive(clap::Parser, Debug,  Clone)]
+pub struct Args {
+    /// Taskset files
+    #[arg(required = true)]
+    pub input_files: Vec<String>,
+
+    #[command(flatten)]
+    pub platform: PlatformArgs,
+
+    /// Reference command, e.g. `-- python3 examples/golden/uniprocessor.py`
+    #[arg(last = true, required = true)]
+    pub reference: Vec<String>,
+}
+
+/// Run the selected tests and their reference implementation on each taskset,
+/// and report the differing verdicts, failing if any. Every selected test runs
+/// regardless of the profile, on the tasks as given, i.e. without the kernel
+/// latencies. Refer to [`golden`] for the reference protocol.
+fn main() -> anyhow::Result<()> {
+    let args = <Args as clap::Parser>::parse();
+
+    let (platform, _, _) = args.platform.resolve()?;
+    let mut mismatches = 0;
+
+    for input_file in &args.input_files {
+        println!("Taskset \"{input_file}\":");
+        let taskset = parse_taskset(input_file, TasksetPlainUnit::Millis)?;
+
+        let mut resolution = registry::resolve(&taskset, &platform, registry::Profile::Exhaustive)?;
+        for warning in resolution.select(&args.platform.selected_tests()?)? {
+            eprintln!("Warning: {warning}");
+        }
+
+        for registered in resolution.applicable.iter().chain(&resolution.escalation) {
+            let check = golden::CrossCheck {
+                id: registered.id.to_owned(),
+                analyzer: registered.analysis.analyzer_name().to_owned(),
+                outcome: SchedOutcome::from_result(&registered.analysis.is_schedulable(&taskset[..])),
+                reference: golden::run_reference(&args.reference, registered.id, &platform, &taskset)?,
+            };
+
+            println!("\t\"{}\" ({}): {}, reference {}{}", check.analyzer, check.id,
+                report::verdict_name(check.outcome), report::verdict_name(check.reference),
+                if check.agrees() { "" } else { " MISMATCH" });
+
+            if !check.agrees() {
+                mismatches += 1;
+            }
+        }
+    }
+
+    anyhow::ensure!(mismatches == 0, "{mismatches} verdict(s) differ from the reference");
+    Ok(())
+}
@@ -0,0 +1,20 @@
+mod utils;
+
+use utils::*;
+
+#[derive(clap::Parser, Debug,  Clone)]
+pub struct Args {
+    /// Format of the JSON Schema
+    #[arg(value_enum)]
+    pub kind: schema::SchemaKind,
+}
+
+/// Print the JSON Schema of the given format, as published in the `schemas`
+/// directory.
+fn main() -> anyhow::Result<()> {
+    let args = <Args as clap::Parser>::parse();
+
+    println!("{}", serde_json::to_string_pretty(&schema::json_schema(args.kind))?);
+
+    Ok(())
+}
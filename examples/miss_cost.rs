@@ -0,0 +1,77 @@
+mod utils;
+
+use utils::*;
+use eva_rt_engine::prelude::*;
+use eva_rt_engine::simulator::{Simulator, releases};
+use eva_rt_engine::simulator::miss_cost;
+
+#[derive(clap::Parser, Debug,  Clone)]
+pub struct Args {
+    /// Taskset files of the candidate designs
+    #[arg(required = true)]
+    pub input_files: Vec<String>,
+
+    #[command(flatten)]
+    pub platform: PlatformArgs,
+
+    /// Cost of a deadline miss: either one for every task, or one per task,
+    /// in order
+    #[arg(long = "cost", required = true)]
+    pub costs: Vec<f64>,
+
+    /// Number of simulations with random sporadic releases, besides the
+    /// synchronous periodic one
+    #[arg(long, default_value_t = 100)]
+    pub sporadic_runs: u64,
+
+    /// Length of each simulation, in hyperperiods
+    #[arg(long, default_value_t = 10)]
+    pub hyperperiods: u64,
+}
+
+/// Estimate the expected cost of the deadline misses of each candidate design
+/// by Monte Carlo simulation, and rank the candidates by increasing expected
+/// cost per second. Refer to [`miss_cost`] for the cost model.
+fn main() -> anyhow::Result<()> {
+    let args = <Args as clap::Parser>::parse();
+
+    let (platform, _, _) = args.platform.resolve()?;
+    let mut scheduler = simulated_scheduler(platform.algorithm)?;
+
+    let mut estimates = Vec::with_capacity(args.input_files.len());
+    for input_file in &args.input_files {
+        let taskset = parse_taskset(input_file, TasksetPlainUnit::Millis)?;
+        let costs =
+            if args.costs.len() == 1 { vec![args.costs[0]; taskset.len()] } else { args.costs.clone() };
+
+        let simulator = Simulator {
+            num_processors: platform.cpus,
+            time_unit: TimeRescaling::from_taskset(&taskset).unit,
+            horizon: RTUtils::hyperperiod(&taskset) * args.hyperperiods as f64,
+        };
+
+        // The seeds are shared, so that the candidates face the same release
+        // patterns as far as their periods allow.
+        let mut release_models: Vec<Box<dyn releases::ReleaseModel>> = vec![Box::new(releases::Periodic)];
+        release_models.extend((0..args.sporadic_runs)
+            .map(|seed| Box::new(releases::Sporadic::new(0.5, seed)) as Box<dyn releases::ReleaseModel>));
+
+        let estimate = miss_cost::expected_cost(&simulator, &taskset, &costs, scheduler.as_mut(), &mut release_models)?;
+        estimates.push((input_file, estimate));
+    }
+
+    estimates.sort_by(|(_, l), (_, r)| l.per_second().total_cmp(&r.per_second()));
+
+    println!("{:<40} {:>16} {:>12} {:>14} {:>12}", "candidate", "cost/hyperperiod", "std. error", "hyperperiod", "cost/s");
+    for (input_file, estimate) in estimates {
+        println!("{:<40} {:>16.3} {:>12.3} {:>12}ms {:>12.3}",
+            input_file,
+            estimate.expected(),
+            estimate.standard_error,
+            estimate.hyperperiod.as_millis(),
+            estimate.per_second(),
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,57 @@
+mod utils;
+
+use utils::*;
+use eva_rt_engine::prelude::*;
+use eva_rt_engine::algorithms::full_preemption::uniprocessor::mixed_criticality::*;
+
+#[derive(clap::Parser, Debug,  Clone)]
+pub struct Args {
+    /// Mixed-criticality taskset file, one task per line with its criticality
+    /// (LO or HI), LO and HI WCETs, deadline and period, in milliseconds
+    pub input_file: String,
+}
+
+/// Run the EDF-VD test and design the virtual deadlines of the HI tasks.
+fn main() -> anyhow::Result<()> {
+    let args = <Args as clap::Parser>::parse();
+
+    let taskset = std::fs::read_to_string(&args.input_file)?
+        .lines()
+        .filter(|line| !line.trim_ascii().is_empty())
+        .map(parse_task)
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    run_analysis(edf_vd12::Analysis, taskset.as_slice())?;
+
+    if let Ok(scaling_factor) = edf_vd12::Designer.design(&taskset) {
+        println!("Virtual deadline scaling factor: {scaling_factor}");
+
+        for (i, task) in taskset.iter().enumerate() {
+            println!("\ttask {i}: virtual deadline {}ms", edf_vd12::virtual_deadline(task, scaling_factor).as_millis());
+        }
+    }
+
+    Ok(())
+}
+
+fn parse_task(line: &str) -> anyhow::Result<MCTask> {
+    let fields: Vec<_> = line.split_ascii_whitespace().collect();
+    let [criticality, times @ ..] = fields.as_slice()
+        else { unreachable!("empty lines are skipped") };
+
+    let criticality =
+        match *criticality {
+            "LO" => Criticality::Lo,
+            "HI" => Criticality::Hi,
+            _ => anyhow::bail!("the criticality must be either LO or HI, found '{criticality}'"),
+        };
+
+    let times = times.iter()
+        .map(|value| value.parse::<f64>().map(Time::millis))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let &[wcet, wcet_hi, deadline, period] = times.as_slice()
+        else { anyhow::bail!("a task requires four numeric fields (LO wcet, HI wcet, deadline and period)") };
+
+    Ok(MCTask { task: RTTask { wcet, deadline, period }, wcet_hi, criticality })
+}
@@ -3,7 +3,7 @@ mod utils;
 use eva_rt_common::time::Time;
 use utils::*;
 use eva_rt_engine::{algorithms::full_preemption::global_multiprocessor::
-    hierarchical::mpr_model09::fixed_priority::bcl09::*, prelude::SchedDesign as _};
+    hierarchical::mpr_model09::{MPRModel, extra::SearchCheckpoint, fixed_priority::bcl09::*}, prelude::{SchedDesign as _, SchedDesignResumable as _}};
 
 #[derive(clap::Parser, Debug,  Clone)]
 pub struct Args {
@@ -25,6 +25,80 @@ pub struct Args {
     /// Resource Search Step, nanoseconds
     #[arg(long="resource-step", default_value="100")]
     pub resource_step_ns: u64,
+
+    /// Print the statistics of the design search on stderr
+    #[arg(long, default_value_t = false)]
+    pub stats: bool,
+
+    /// Save the progress of the search to this file, resuming from it if it
+    /// exists. The file is removed once the search completes
+    #[arg(long)]
+    pub checkpoint: Option<String>,
+
+    /// Seconds between two saves of the checkpoint
+    #[arg(long="checkpoint-interval", default_value="60")]
+    pub checkpoint_interval_s: u64,
+}
+
+/// Progress of the search, bound to the arguments it was started with.
+#[derive(serde::Serialize, serde::Deserialize, PartialEq)]
+struct SearchArgs {
+    taskset: Vec<(f64, f64, f64)>,
+    min_period_ms: u64,
+    max_period_ms: u64,
+    period_step_ms: u64,
+    resource_step_ns: u64,
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+struct CheckpointFile {
+    search: SearchArgs,
+    /// Searched periods in nanoseconds, with the model found for each, as
+    /// resource in nanoseconds and concurrency.
+    searched: Vec<(f64, Option<(f64, u64)>)>,
+}
+
+impl CheckpointFile {
+    fn load(path: &str, search: &SearchArgs) -> anyhow::Result<Option<SearchCheckpoint>> {
+        if !std::path::Path::new(path).exists() {
+            return Ok(None);
+        }
+
+        let file: Self = serde_json::from_str(&std::fs::read_to_string(path)?)?;
+        anyhow::ensure!(&file.search == search, "checkpoint {path} belongs to a different search");
+
+        Ok(Some(SearchCheckpoint {
+            searched: file.searched.into_iter()
+                .map(|(period, model)| (
+                    Time::nanos(period),
+                    model.map(|(resource, concurrency)| MPRModel {
+                        resource: Time::nanos(resource),
+                        period: Time::nanos(period),
+                        concurrency,
+                    }),
+                ))
+                .collect(),
+        }))
+    }
+
+    // Written to a temporary file first, so that an interruption never
+    // leaves a truncated checkpoint.
+    fn save(path: &str, search: SearchArgs, checkpoint: &SearchCheckpoint) -> anyhow::Result<()> {
+        let file = Self {
+            search,
+            searched: checkpoint.searched.iter()
+                .map(|(period, model)| (
+                    period.as_nanos(),
+                    model.as_ref().map(|model| (model.resource.as_nanos(), model.concurrency)),
+                ))
+                .collect(),
+        };
+
+        let temp = format!("{path}.tmp");
+        std::fs::write(&temp, serde_json::to_string(&file)?)?;
+        std::fs::rename(&temp, path)?;
+        Ok(())
+    }
 }
 
 fn main() -> anyhow::Result<()> {
@@ -42,14 +116,68 @@ fn main() -> anyhow::Result<()> {
             resource_step: Time::nanos(args.resource_step_ns as f64),
         };
 
-    let best_model =
-        designer.design(&taskset)?;
+    if let Some(path) = &args.checkpoint {
+        let search = || SearchArgs {
+            taskset: taskset.iter()
+                .map(|task| (task.wcet.as_nanos(), task.deadline.as_nanos(), task.period.as_nanos()))
+                .collect(),
+            min_period_ms: args.min_period_ms,
+            max_period_ms: args.max_period_ms,
+            period_step_ms: args.period_step_ms,
+            resource_step_ns: args.resource_step_ns,
+        };
 
-    println!("{} {:.0} {:.0}",
-        best_model.concurrency,
-        (best_model.resource / best_model.concurrency as f64).as_millis(),
-        best_model.period.as_millis(),
-    );
+        let checkpoint = CheckpointFile::load(path, &search())?.unwrap_or_default();
+        if !checkpoint.searched.is_empty() {
+            eprintln!("resuming after {} periods", checkpoint.searched.len());
+        }
+
+        let interval = std::time::Duration::from_secs(args.checkpoint_interval_s);
+        let mut last_save = std::time::Instant::now();
+        let mut save_error = None;
+
+        let best_model = designer.design_resumable(&taskset, checkpoint, &mut |checkpoint| {
+            if save_error.is_none() && last_save.elapsed() >= interval {
+                save_error = CheckpointFile::save(path, search(), checkpoint).err();
+                last_save = std::time::Instant::now();
+            }
+        });
+
+        if let Some(err) = save_error {
+            return Err(err.context(format!("cannot save checkpoint {path}")));
+        }
+
+        let best_model = best_model?;
+        std::fs::remove_file(path).ok();
+
+        print_model(&best_model);
+        return Ok(());
+    }
+
+    let result =
+        designer.design_with_stats(&taskset);
+
+    if args.stats {
+        eprintln!("evaluated {} candidates", result.candidates);
+        if let Some(model) = &result.tightest_failing {
+            eprintln!("tightest failing: concurrency {}, resource {:.3}ms, period {:.3}ms",
+                model.concurrency, model.resource.as_millis(), model.period.as_millis());
+        }
+        for (dimension, time) in &result.search_time {
+            eprintln!("{dimension}: {:.3}s", time.as_secs_f64());
+        }
+    }
+
+    let best_model = result.model?;
+    print_model(&best_model);
 
     Ok(())
+}
+
+fn print_model(model: &MPRModel) {
+    println!("{} {:.0} {:.0}",
+        model.concurrency,
+        (model.resource / model.concurrency as f64).as_millis(),
+        model.period.as_millis(),
+    );
 }
\ No newline at end of file
@@ -0,0 +1,101 @@
+mod utils;
+
+use eva_rt_engine::prelude::*;
+use eva_rt_engine::utils::parameter_ranges::*;
+use eva_rt_engine::algorithms::full_preemption::uniprocessor::fixed_priority::rta86;
+use eva_rt_engine::algorithms::full_preemption::uniprocessor::earliest_deadline_first::qpa09;
+
+#[derive(clap::Parser, Debug,  Clone)]
+pub struct Args {
+    /// Ranges file, with the parameters of each task, in milliseconds, as
+    /// values or [min, max] ranges, sorted by priority with Fixed Priority
+    /// scheduling. Without a deadline, the task has implicit deadlines:
+    /// { "tasks": [ { "wcet": [1, 2], "period": [10, 20] }, { "wcet": 3, "deadline": 15, "period": 20 } ] }
+    pub ranges_file: String,
+
+    /// Schedule the tasks with EDF, rather than with Fixed Priority
+    #[arg(long)]
+    pub edf: bool,
+}
+
+#[derive(serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RangesFile {
+    tasks: Vec<TaskEntry>,
+}
+
+#[derive(serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+struct TaskEntry {
+    wcet: RangeEntry,
+    #[serde(default)]
+    deadline: Option<RangeEntry>,
+    period: RangeEntry,
+}
+
+#[derive(serde::Deserialize)]
+#[serde(untagged)]
+enum RangeEntry {
+    Value(f64),
+    Range([f64; 2]),
+}
+
+impl RangeEntry {
+    fn range(&self) -> ParameterRange {
+        match *self {
+            RangeEntry::Value(value) => ParameterRange::exactly(Time::millis(value)),
+            RangeEntry::Range([min, max]) => ParameterRange { min: Time::millis(min), max: Time::millis(max) },
+        }
+    }
+}
+
+/// Tell whether every, some or none of the tasksets whose parameters lie in
+/// the given ranges are schedulable on a uniprocessor, with the exact tests.
+fn main() -> anyhow::Result<()> {
+    let args = <Args as clap::Parser>::parse();
+
+    let entries: RangesFile = serde_json::from_str(&std::fs::read_to_string(&args.ranges_file)?)?;
+    let taskset: Vec<_> = entries.tasks.iter()
+        .map(|entry| RTTaskRange {
+            wcet: entry.wcet.range(),
+            deadline: entry.deadline.as_ref().unwrap_or(&entry.period).range(),
+            period: entry.period.range(),
+        })
+        .collect();
+
+    if args.edf {
+        print_outcome(WithParameterRanges { analysis: qpa09::Analysis, sustainable: true, exact: true }, &taskset, |_| ())
+    } else {
+        print_outcome(WithParameterRanges { analysis: rta86::Analysis, sustainable: true, exact: true }, &taskset, |response_times| {
+            for (i, response) in response_times.iter().enumerate() {
+                println!("\ttask {i}: response time at most {}ms", response.as_millis());
+            }
+        })
+    }
+}
+
+fn print_outcome<T, A>(analysis: WithParameterRanges<A>, taskset: &[RTTaskRange], print_results: impl Fn(T)) -> anyhow::Result<()>
+    where A: for<'a> SchedAnalysis<T, &'a [RTTask]>,
+{
+    println!("Running \"{}\":", analysis.analyzer_name());
+    match analysis.is_schedulable(taskset)? {
+        RangeOutcome::AllSchedulable(results) => {
+            println!("\tschedulable for every parameter in the ranges");
+            print_results(results);
+        },
+        RangeOutcome::SomeSchedulable(witness, _) => {
+            println!("\tschedulable for some parameters in the ranges, e.g.:");
+
+            for (i, task) in witness.iter().enumerate() {
+                println!("\ttask {i}: WCET {}ms, deadline {}ms, period {}ms", task.wcet.as_millis(),
+                    task.deadline.as_millis(), task.period.as_millis());
+            }
+        },
+        RangeOutcome::NoneSchedulable =>
+            println!("\tnon schedulable for every parameter in the ranges"),
+        RangeOutcome::Inconclusive =>
+            println!("\tinconclusive"),
+    }
+
+    Ok(())
+}
@@ -0,0 +1,69 @@
+mod utils;
+
+use utils::*;
+use eva_rt_engine::prelude::*;
+use eva_rt_engine::algorithms::full_preemption::reservation_groups::LocalScheduler;
+use eva_rt_engine::algorithms::full_preemption::uniprocessor::hierarchical::arinc653;
+
+#[derive(clap::Parser, Debug,  Clone)]
+pub struct Args {
+    pub input_file: String,
+
+    /// Tasks of a partition, as comma separated task indices, sorted by
+    /// priority with Fixed Priority local scheduling. Repeat for each
+    /// partition.
+    #[arg(long = "partition", required = true)]
+    pub partitions: Vec<String>,
+
+    /// Length of the major frame (in milliseconds)
+    #[arg(long="major-frame-ms")]
+    pub major_frame_ms: f64,
+
+    /// Number of minor frames in the major frame, each with a window per
+    /// partition
+    #[arg(long, default_value_t = 1)]
+    pub minor_frames: u64,
+
+    /// Time quantum of the windows (in microseconds)
+    #[arg(long="quantum-us", default_value_t = 1.0)]
+    pub quantum_us: f64,
+
+    /// Schedule the tasks of each partition with EDF, rather than with Fixed
+    /// Priority
+    #[arg(long, default_value_t = false)]
+    pub edf: bool,
+}
+
+/// Design the ARINC 653 partition windows of the taskset, and print the
+/// schedule of the major frame.
+fn main() -> anyhow::Result<()> {
+    let args = <Args as clap::Parser>::parse();
+
+    let taskset = parse_taskset(&args.input_file, TasksetPlainUnit::Millis)?;
+
+    let partitions = args.partitions.iter()
+        .map(|partition| partition.split(',')
+            .map(|task| task.trim().parse::<usize>()
+                .map_err(|err| anyhow::format_err!("invalid partition \"{partition}\": {err}")))
+            .collect::<anyhow::Result<Vec<_>>>())
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    let designer = arinc653::Designer {
+        partitions,
+        local_scheduler: if args.edf { LocalScheduler::EarliestDeadlineFirst } else { LocalScheduler::FixedPriority },
+        major_frame: Time::millis(args.major_frame_ms),
+        minor_frames: args.minor_frames,
+        quantization: Quantization { quantum: Time::micros(args.quantum_us) },
+    };
+
+    let schedule = designer.design(&taskset[..])?;
+
+    println!("Major frame: {}ms, minor frame: {}ms", schedule.major_frame.as_millis(), schedule.minor_frame.as_millis());
+
+    for window in schedule.windows.iter() {
+        println!("\t{:>10}ms: partition {} for {}ms", window.offset.as_millis(), window.partition,
+            window.duration.as_millis());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,77 @@
+mod utils;
+
+use utils::*;
+use eva_rt_engine::prelude::*;
+use eva_rt_engine::utils::resources::{CriticalSection, CriticalSections};
+use eva_rt_engine::algorithms::full_preemption::uniprocessor::fixed_priority::pcp90;
+use eva_rt_engine::algorithms::full_preemption::uniprocessor::earliest_deadline_first::srp91;
+
+#[derive(clap::Parser, Debug,  Clone)]
+pub struct Args {
+    /// Taskset file, sorted by priority, highest first, with Fixed Priority
+    /// scheduling
+    pub input_file: String,
+
+    /// Critical sections file, listing the critical sections of each task, in
+    /// the order of the taskset, with their durations in milliseconds:
+    /// { "tasks": [ [ { "resource": 0, "duration": 0.5 } ], [] ] }
+    pub critical_sections_file: String,
+
+    /// Schedule the tasks with EDF under the Stack Resource Policy, rather
+    /// than with Fixed Priority under the Priority Ceiling Protocol
+    #[arg(long)]
+    pub edf: bool,
+}
+
+#[derive(serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+struct CriticalSectionsFile {
+    tasks: Vec<Vec<CriticalSectionEntry>>,
+}
+
+#[derive(serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+struct CriticalSectionEntry {
+    resource: usize,
+    /// Milliseconds
+    duration: f64,
+}
+
+/// Analyze the taskset sharing resources under the Priority Ceiling Protocol,
+/// or the Stack Resource Policy with EDF, printing the blocking time of each
+/// task.
+fn main() -> anyhow::Result<()> {
+    let args = <Args as clap::Parser>::parse();
+
+    let taskset = parse_taskset(&args.input_file, TasksetPlainUnit::Millis)?;
+
+    let entries: CriticalSectionsFile = serde_json::from_str(&std::fs::read_to_string(&args.critical_sections_file)?)?;
+    let critical_sections = CriticalSections {
+        sections: entries.tasks.into_iter()
+            .map(|sections| sections.into_iter()
+                .map(|entry| CriticalSection { resource: entry.resource, duration: Time::millis(entry.duration) })
+                .collect())
+            .collect(),
+    };
+
+    critical_sections.check_preconditions(&taskset)?;
+    let blocking_times =
+        if args.edf {
+            srp91::blocking_times(&taskset, &critical_sections)
+        } else {
+            pcp90::blocking_times(&critical_sections)
+        };
+
+    for (i, blocking) in blocking_times.iter().enumerate() {
+        println!("Task {i} blocked for up to {}ms", blocking.as_millis());
+    }
+
+    if args.edf {
+        run_analysis(srp91::Analysis { critical_sections: critical_sections.clone() }, &taskset[..])?;
+        run_analysis(srp91::AnalysisDemand { critical_sections }, &taskset[..])?;
+    } else {
+        run_analysis(pcp90::Analysis { critical_sections }, &taskset[..])?;
+    }
+
+    Ok(())
+}
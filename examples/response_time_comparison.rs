@@ -0,0 +1,76 @@
+mod utils;
+
+use utils::*;
+use eva_rt_engine::prelude::*;
+use eva_rt_engine::simulator::{Simulator, releases, schedulers};
+use eva_rt_engine::simulator::response_times::{self, Discrepancy};
+
+#[derive(clap::Parser, Debug,  Clone)]
+pub struct Args {
+    pub input_file: String,
+
+    #[command(flatten)]
+    pub platform: PlatformArgs,
+
+    /// Length of each simulation, in hyperperiods
+    #[arg(long, default_value_t = 10)]
+    pub hyperperiods: u64,
+
+    /// Number of simulations with random sporadic releases, besides the
+    /// synchronous periodic one
+    #[arg(long, default_value_t = 0)]
+    pub sporadic_runs: u64,
+
+    /// Flag the tasks whose bound exceeds the observed response time by more
+    /// than the given fraction of the bound
+    #[arg(long, default_value_t = 0.5)]
+    pub pessimism_threshold: f64,
+}
+
+/// Compare, per task, the response times of the exact response time analysis
+/// of the platform with the largest ones observed in simulations of the
+/// schedule, flagging the violations of the bounds and the large gaps. The
+/// simulations do not model the kernel latencies, which only make the bounds
+/// larger.
+fn main() -> anyhow::Result<()> {
+    let args = <Args as clap::Parser>::parse();
+
+    let taskset = parse_taskset(&args.input_file, TasksetPlainUnit::Millis)?;
+    let (platform, latencies, _) = args.platform.resolve()?;
+
+    let Some(analysis) = report::response_time_analysis(&platform, &latencies)
+        else { anyhow::bail!("no response time analysis for {:?} scheduling on a {:?} platform", platform.algorithm, platform.model); };
+
+    let simulator = Simulator {
+        num_processors: platform.cpus,
+        time_unit: TimeRescaling::from_taskset(&taskset).unit,
+        horizon: RTUtils::hyperperiod(&taskset) * args.hyperperiods as f64
+            + taskset.iter().map(|task| task.deadline).fold(Time::zero(), Time::max),
+    };
+
+    let mut release_models: Vec<Box<dyn releases::ReleaseModel>> = vec![Box::new(releases::Periodic)];
+    release_models.extend((0..args.sporadic_runs)
+        .map(|seed| Box::new(releases::Sporadic::new(0.5, seed)) as Box<dyn releases::ReleaseModel>));
+
+    println!("Comparing \"{}\" with {} simulation(s) of {}ms:", analysis.analyzer_name(),
+        release_models.len(), simulator.horizon.as_millis());
+
+    let comparison = response_times::compare(analysis, &simulator, &taskset, &mut schedulers::FixedPriority, &mut release_models)?;
+
+    let mut violations = 0;
+    for (i, task) in comparison.iter().enumerate() {
+        let observed = task.observed.map_or("none".to_owned(), |observed| format!("{}ms", observed.as_millis()));
+        let flag =
+            match task.classify(args.pessimism_threshold) {
+                Discrepancy::Consistent => "",
+                Discrepancy::Pessimistic => " (possible pessimism)",
+                Discrepancy::Violation => { violations += 1; " VIOLATION (possible bug)" },
+                Discrepancy::Unobserved => " (no job completed)",
+            };
+
+        println!("\ttask {i}: analytic {}ms, observed {observed}{flag}", task.analytic.as_millis());
+    }
+
+    anyhow::ensure!(violations == 0, "{violations} observed response time(s) exceed the analytic bounds");
+    Ok(())
+}
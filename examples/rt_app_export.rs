@@ -0,0 +1,77 @@
+mod utils;
+
+use utils::*;
+use utils::rt_app::*;
+use eva_rt_engine::prelude::*;
+use eva_rt_engine::algorithms::full_preemption::global_multiprocessor::hierarchical::mpr_model09::MPRModel;
+
+#[derive(clap::Parser, Debug,  Clone)]
+pub struct Args {
+    /// Taskset file
+    pub input_file: String,
+
+    /// Scheduling policy of the generated threads
+    #[arg(short='p', long="policy", default_value="fifo")]
+    pub policy: RtAppPolicy,
+
+    /// Duration of the experiment, seconds
+    #[arg(short='d', long="duration", default_value="10")]
+    pub duration_secs: u64,
+
+    /// Also reserve the servers of an MPR model designed for the taskset,
+    /// given as resource (ms), period (ms) and concurrency
+    #[arg(long="mpr", num_args=3, value_names=["RESOURCE", "PERIOD", "CONCURRENCY"])]
+    pub mpr: Option<Vec<f64>>,
+
+    /// Best-effort background utilization of each CPU, which the real-time
+    /// threads leave room for by priority, or by RT throttling if a
+    /// throttling period is given
+    #[arg(long="background-util", num_args=1.., requires="sysctl_file")]
+    pub background_util: Vec<f64>,
+
+    /// RT throttling period, ms
+    #[arg(long="throttling-period-ms", requires="background_util")]
+    pub throttling_period_ms: Option<f64>,
+
+    /// Write the RT throttling settings for the background load to the given
+    /// file, in sysctl.conf format
+    #[arg(long="sysctl", requires="background_util")]
+    pub sysctl_file: Option<String>,
+}
+
+fn main() -> anyhow::Result<()> {
+    let args = <Args as clap::Parser>::parse();
+
+    let taskset = parse_taskset(&args.input_file, TasksetPlainUnit::Millis)?;
+
+    let workload =
+        match args.mpr.as_deref() {
+            Some(&[resource_ms, period_ms, concurrency]) => {
+                let model = MPRModel {
+                    resource: Time::millis(resource_ms),
+                    period: Time::millis(period_ms),
+                    concurrency: concurrency as u64,
+                };
+
+                serialize_rt_app_reservations(&taskset, args.policy, &model, args.duration_secs)?
+            },
+            _ => serialize_rt_app_taskset(&taskset, args.policy, args.duration_secs)?,
+        };
+
+    println!("{workload}");
+
+    if let Some(sysctl_file) = &args.sysctl_file {
+        let load = BackgroundLoad {
+            utilization: args.background_util.clone(),
+            policy: match args.throttling_period_ms {
+                Some(period_ms) => BackgroundPolicy::Throttling { period: Time::millis(period_ms) },
+                None => BackgroundPolicy::Priority,
+            },
+        };
+        load.check_preconditions()?;
+
+        std::fs::write(sysctl_file, load.to_sysctl())?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,33 @@
+mod utils;
+
+use utils::*;
+
+#[derive(clap::Parser, Debug,  Clone)]
+pub struct Args {
+    /// Taskset file
+    pub input_file: String,
+
+    /// Unit of the times of plain tasksets
+    #[arg(short='u', long="unit", value_enum, default_value="millis")]
+    pub unit: TasksetPlainUnit,
+
+    /// Further multiply the times by the given integer factor, to hide their
+    /// greatest common divisor as well
+    #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u64).range(1..))]
+    pub scale: u64,
+}
+
+/// Print the given taskset in the current versioned format, anonymized: the
+/// names of the tasks are dropped and the times are expressed in multiples of
+/// their greatest common divisor, preserving their ratios. The times are
+/// written as nanoseconds, though they are in an arbitrary unit.
+fn main() -> anyhow::Result<()> {
+    let args = <Args as clap::Parser>::parse();
+
+    let (taskset, margins) = parse_annotated_taskset(&args.input_file, args.unit)?;
+    let taskset = anonymize::anonymize_taskset(&taskset, args.scale);
+
+    println!("{}", serde_json::to_string_pretty(&schema::serialize_taskset(&taskset, &margins, TasksetPlainUnit::Nanos))?);
+
+    Ok(())
+}
@@ -0,0 +1,105 @@
+mod utils;
+
+use utils::*;
+use eva_rt_engine::prelude::*;
+use eva_rt_engine::algorithms::registry;
+
+#[derive(clap::Parser, Debug,  Clone)]
+pub struct Args {
+    /// Taskset before the change
+    pub old_file: String,
+
+    /// Taskset after the change
+    pub new_file: String,
+
+    #[command(flatten)]
+    pub platform: PlatformArgs,
+
+    /// Platform of the new taskset, if different from the old one's
+    #[arg(long)]
+    pub new_config: Option<String>,
+
+    /// Only run the tests whose name contains any of the given strings
+    /// (case insensitive), among the ones selected by --test
+    #[arg(long)]
+    pub analysis: Vec<String>,
+}
+
+/// Report the changes in the parameters of two tasksets, and the resulting
+/// changes in verdicts, response times and margins.
+fn main() -> anyhow::Result<()> {
+    let args = <Args as clap::Parser>::parse();
+
+    let old_platform = args.platform.resolve()?;
+    let new_platform =
+        match &args.new_config {
+            Some(config_file) => PlatformArgs { config: Some(config_file.clone()), ..args.platform.clone() }.resolve()?,
+            None => old_platform.clone(),
+        };
+
+    // The scheduling overheads are charged to the WCETs, for every test.
+    let old_overheads = args.platform.overheads()?;
+    let new_overheads =
+        match &args.new_config {
+            Some(config_file) => schema::parse_config(config_file)?.overheads,
+            None => old_overheads.clone(),
+        };
+    old_overheads.check_preconditions()?;
+    new_overheads.check_preconditions()?;
+
+    println!("Old taskset \"{}\":", args.old_file);
+    let old_taskset: std::sync::Arc<[RTTask]> = old_overheads.inflate_taskset(&parse_taskset(&args.old_file, TasksetPlainUnit::Millis)?).into();
+    let (platform, latencies, profile) = &old_platform;
+    let old = run(&args, &args.old_file, &old_taskset, platform, latencies, *profile)?;
+
+    println!("\nNew taskset \"{}\":", args.new_file);
+    let new_taskset: std::sync::Arc<[RTTask]> = new_overheads.inflate_taskset(&parse_taskset(&args.new_file, TasksetPlainUnit::Millis)?).into();
+    let (platform, latencies, profile) = &new_platform;
+    let new = run(&args, &args.new_file, &new_taskset, platform, latencies, *profile)?;
+
+    println!("\nDifferences:");
+    let lines = diff::diff(&old, &new);
+    if lines.is_empty() {
+        println!("\tnone");
+    }
+    for line in lines {
+        println!("\t{line}");
+    }
+
+    Ok(())
+}
+
+fn run<'a>(
+    args: &Args,
+    input_file: &'a str,
+    taskset: &'a std::sync::Arc<[RTTask]>,
+    platform: &'a Platform,
+    latencies: &KernelLatencies,
+    profile: registry::Profile,
+) -> anyhow::Result<report::Report<'a>> {
+    let mut resolution = registry::resolve(taskset, platform, profile)?;
+    for warning in resolution.select(&args.platform.selected_tests()?)? {
+        eprintln!("Warning: {warning}");
+    }
+
+    let selected = |registered: &registry::Registered| {
+        let name = registered.analysis.analyzer_name().to_lowercase();
+        args.analysis.is_empty() || args.analysis.iter().any(|filter| name.contains(&filter.to_lowercase()))
+    };
+    resolution.applicable.retain(selected);
+    resolution.escalation.retain(selected);
+
+    let (results, excluded) = run_profile(resolution, taskset.clone(), latencies, profile)?;
+
+    Ok(report::Report {
+        input_file,
+        taskset,
+        platform,
+        profile,
+        results,
+        results_with_margins: None,
+        excluded,
+        response_times: report::response_times(taskset, platform, latencies),
+        provenance: report::Provenance::of_file(input_file, args.platform.provenance_parameters(latencies)?)?,
+    })
+}
@@ -0,0 +1,23 @@
+mod utils;
+
+use utils::*;
+
+#[derive(clap::Parser, Debug,  Clone)]
+pub struct Args {
+    /// Plain taskset file
+    pub input_file: String,
+
+    /// Unit of the plain taskset's times
+    #[arg(short='u', long="unit", value_enum, default_value="millis")]
+    pub unit: TasksetPlainUnit,
+}
+
+/// Print the given plain taskset in the current versioned format.
+fn main() -> anyhow::Result<()> {
+    let args = <Args as clap::Parser>::parse();
+
+    let taskset = parse_taskset(&args.input_file, args.unit)?;
+    println!("{}", serde_json::to_string_pretty(&schema::migrate_plain_taskset(&taskset, args.unit))?);
+
+    Ok(())
+}
@@ -0,0 +1,90 @@
+mod utils;
+
+use utils::*;
+use eva_rt_engine::prelude::*;
+use eva_rt_engine::algorithms::full_preemption::end_to_end::TaskChain;
+use eva_rt_engine::algorithms::full_preemption::tdma;
+
+#[derive(clap::Parser, Debug,  Clone)]
+pub struct Args {
+    /// Taskset file
+    pub input_file: String,
+
+    /// Message streams file, in the taskset format, whose WCETs are the
+    /// transmission times of the messages
+    pub messages_file: String,
+
+    /// Chains file, declaring the hops of each chain by index, the tasks first
+    /// and then the messages, and its end-to-end deadline in milliseconds:
+    /// { "chains": [ { "name": "control", "tasks": [0, 3, 2], "deadline": 50 } ] }
+    #[arg(long)]
+    pub chains_file: Option<String>,
+
+    #[command(flatten)]
+    pub platform: PlatformArgs,
+
+    /// Length of the TDMA cycle (in milliseconds)
+    #[arg(long="cycle-ms")]
+    pub cycle_ms: f64,
+
+    /// Time quantum of the slots (in microseconds)
+    #[arg(long="quantum-us", default_value_t = 1.0)]
+    pub quantum_us: f64,
+}
+
+#[derive(serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ChainsFile {
+    chains: Vec<ChainEntry>,
+}
+
+#[derive(serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ChainEntry {
+    #[serde(default)]
+    #[allow(dead_code)]
+    name: Option<String>,
+    tasks: Vec<usize>,
+    /// End-to-end deadline, milliseconds
+    deadline: f64,
+}
+
+/// Design the slots of a TDMA network for the message streams, meeting their
+/// deadlines and, if given, the end-to-end deadlines of the chains of tasks
+/// and messages, and print the cycle.
+fn main() -> anyhow::Result<()> {
+    let args = <Args as clap::Parser>::parse();
+
+    let taskset = parse_taskset(&args.input_file, TasksetPlainUnit::Millis)?;
+    let messages = parse_taskset(&args.messages_file, TasksetPlainUnit::Millis)?;
+
+    let designer = tdma::Designer {
+        cycle: Time::millis(args.cycle_ms),
+        quantization: Quantization { quantum: Time::micros(args.quantum_us) },
+    };
+
+    let schedule =
+        if let Some(chains_file) = &args.chains_file {
+            let entries: ChainsFile = serde_json::from_str(&std::fs::read_to_string(chains_file)?)?;
+            let chains = entries.chains.into_iter()
+                .map(|entry| TaskChain { tasks: entry.tasks, deadline: Time::millis(entry.deadline) })
+                .collect();
+
+            let (platform, latencies, _) = args.platform.resolve()?;
+            let analysis = report::response_time_analysis(&platform, &latencies)
+                .ok_or_else(|| anyhow::format_err!("no response time analysis applies to the platform"))?;
+
+            tdma::DesignerEndToEnd { designer, chains, analysis }.design((&taskset[..], &messages[..]))?
+        } else {
+            designer.design(&messages[..])?
+        };
+
+    println!("Cycle: {}ms", schedule.cycle.as_millis());
+
+    for (slot, response) in schedule.slots.iter().zip(schedule.response_times(&messages)) {
+        println!("\t{:>10}ms: message {} for {}ms, response time {}ms", slot.offset.as_millis(), slot.message,
+            slot.duration.as_millis(), response.as_millis());
+    }
+
+    Ok(())
+}
@@ -6,14 +6,36 @@ use eva_rt_engine::algorithms::full_preemption::uniprocessor::earliest_deadline_
 #[derive(clap::Parser, Debug,  Clone)]
 pub struct Args {
     pub input_file: String,
+
+    /// Read the tasks one at a time, for very large plain tasksets. Only the
+    /// linear-time tests are run.
+    #[arg(long, default_value_t = false)]
+    pub streaming: bool,
+
+    #[command(flatten)]
+    pub latencies: KernelLatencyArgs,
+
+    #[command(flatten)]
+    pub what_if: WhatIfArgs,
 }
 
 fn main() -> anyhow::Result<()> {
     let args = <Args as clap::Parser>::parse();
 
-    let taskset = parse_taskset(&args.input_file, TasksetPlainUnit::Millis)?;
+    if args.streaming {
+        anyhow::ensure!(args.latencies.is_zero(), "kernel latencies are not supported in streaming mode");
+        anyhow::ensure!(args.what_if.is_identity(), "what-if transformations are not supported in streaming mode");
+
+        let tasks = stream_taskset(&args.input_file, TasksetPlainUnit::Millis)?;
+
+        run_analysis(edf73::AnalysisStreaming, tasks)?;
+
+        return Ok(());
+    }
+
+    let taskset = args.what_if.apply(&parse_taskset(&args.input_file, TasksetPlainUnit::Millis)?)?;
 
-    run_analysis(edf73::Analysis, &taskset)?;
+    run_analysis(args.latencies.apply(edf73::Analysis), &taskset)?;
 
     Ok(())
 }
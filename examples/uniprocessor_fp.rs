@@ -1,23 +1,104 @@
 mod utils;
 
 use utils::*;
+use eva_rt_engine::prelude::*;
 use eva_rt_engine::algorithms::full_preemption::uniprocessor::fixed_priority::*;
 
 #[derive(clap::Parser, Debug,  Clone)]
 pub struct Args {
     pub input_file: String,
+
+    /// Read the tasks one at a time, for very large plain tasksets. Only the
+    /// linear-time tests are run.
+    #[arg(long, default_value_t = false)]
+    pub streaming: bool,
+
+    /// Re-check the tests' inequalities with careful numerics, and report the
+    /// verdicts which depend on floating point rounding.
+    #[arg(long, default_value_t = false)]
+    pub validate: bool,
+
+    /// Bracket the response times with cheap bounds, running the full RTA
+    /// only for the tasks they do not decide.
+    #[arg(long, default_value_t = false)]
+    pub bracketing: bool,
+
+    /// Priority assignment of the tasks, sorting the taskset before the
+    /// analysis
+    #[arg(long, value_enum, default_value="given")]
+    pub priority: PriorityArg,
+
+    #[command(flatten)]
+    pub latencies: KernelLatencyArgs,
+
+    #[command(flatten)]
+    pub what_if: WhatIfArgs,
 }
 
 fn main() -> anyhow::Result<()> {
     let args = <Args as clap::Parser>::parse();
 
-    let taskset = parse_taskset(&args.input_file, TasksetPlainUnit::Millis)?;
+    if args.streaming {
+        anyhow::ensure!(args.latencies.is_zero(), "kernel latencies are not supported in streaming mode");
+        anyhow::ensure!(args.what_if.is_identity(), "what-if transformations are not supported in streaming mode");
+
+        let tasks = stream_taskset(&args.input_file, TasksetPlainUnit::Millis)?;
+        run_analysis(rate_monotonic73::AnalysisStreaming, tasks)?;
+
+        let tasks = stream_taskset(&args.input_file, TasksetPlainUnit::Millis)?;
+        run_analysis(hyperbolic01::AnalysisStreaming, tasks)?;
+
+        return Ok(());
+    }
+
+    let jittered_taskset = parse_jittered_taskset(&args.input_file, TasksetPlainUnit::Millis)?;
+    let has_jitters = jittered_taskset.iter().any(|task| task.release_jitter > Time::zero());
+
+    let taskset: Vec<_> = jittered_taskset.iter().map(|task| task.task.clone()).collect();
+    let taskset = args.what_if.apply(&taskset)?;
+    let order = args.priority.order().order(&taskset)?;
+
+    // The release jitters of the tasks add to the kernel latencies.
+    let jittered_taskset: Vec<_> = order.iter()
+        .map(|&i| rta86::JitteredTask {
+            task: taskset[i].clone(),
+            release_jitter: jittered_taskset[i].release_jitter + args.latencies.latencies().release_jitter(),
+        })
+        .collect();
+    let taskset: Vec<_> = order.into_iter().map(|i| taskset[i].clone()).collect();
+
+    run_analysis(args.latencies.apply(rate_monotonic73::Analysis), &taskset)?;
+    run_analysis(args.latencies.apply(rate_monotonic73::AnalysisSimple), &taskset)?;
+    run_analysis(args.latencies.apply(hyperbolic01::Analysis), &taskset)?;
+    run_analysis(args.latencies.apply(kuo_mok91::Analysis), &taskset)?;
+    run_analysis(args.latencies.apply(burchard95::Analysis), &taskset)?;
+    run_analysis(args.latencies.apply(deadline_monotonic90::Analysis), &taskset)?;
+    run_analysis(args.latencies.apply(hyperplanes04::Analysis), &taskset)?;
+    run_analysis(args.latencies.apply(tda89::Analysis), &taskset)?;
+    if args.bracketing {
+        anyhow::ensure!(args.latencies.is_zero(), "kernel latencies are not supported with bracketing");
+        run_analysis(rta86::AnalysisBracketing, &taskset)?;
+    } else {
+        run_analysis(rta86::AnalysisKernelLatencies { latencies: args.latencies.latencies() }, &taskset)?;
+    }
+    if has_jitters {
+        run_analysis(rta86::AnalysisJitter, jittered_taskset.as_slice())?;
+    }
+
+    if args.validate {
+        let warnings = [
+            rate_monotonic73::Analysis.validate(&taskset),
+            rate_monotonic73::AnalysisSimple.validate(&taskset),
+            hyperbolic01::Analysis.validate(&taskset),
+            kuo_mok91::Analysis.validate(&taskset),
+            burchard95::Analysis.validate(&taskset),
+            rta86::Analysis.validate(&taskset),
+        ];
 
-    run_analysis(rate_monotonic73::Analysis, &taskset)?;
-    run_analysis(rate_monotonic73::AnalysisSimple, &taskset)?;
-    run_analysis(hyperbolic01::Analysis, &taskset)?;
-    run_analysis(deadline_monotonic90::Analysis, &taskset)?;
-    run_analysis(rta86::Analysis, &taskset)?;
+        for warning in warnings.iter().flatten() {
+            eprintln!("Numeric warning: {warning}");
+        }
+    }
 
     Ok(())
 }
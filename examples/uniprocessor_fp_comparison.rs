@@ -0,0 +1,70 @@
+mod utils;
+
+use utils::*;
+use eva_rt_engine::prelude::*;
+use eva_rt_engine::algorithms::full_preemption::uniprocessor::fixed_priority::*;
+
+#[derive(clap::Parser, Debug,  Clone)]
+pub struct Args {
+    /// Taskset files of the corpus
+    #[arg(required=true)]
+    pub input_files: Vec<String>,
+}
+
+type BoxedAnalysis = Box<dyn for<'a> SchedAnalysis<(), &'a [RTTask]>>;
+
+fn main() -> anyhow::Result<()> {
+    let args = <Args as clap::Parser>::parse();
+
+    let analyses: Vec<BoxedAnalysis> = vec![
+        Box::new(rate_monotonic73::Analysis),
+        Box::new(rate_monotonic73::AnalysisSimple),
+        Box::new(hyperbolic01::Analysis),
+        Box::new(kuo_mok91::Analysis),
+        Box::new(burchard95::Analysis),
+        Box::new(deadline_monotonic90::Analysis),
+        Box::new(hyperplanes04::Analysis),
+    ];
+
+    let mut stats: Vec<_> = analyses.iter()
+        .map(|analysis| SchedStats::new(analysis.analyzer_name()))
+        .chain(std::iter::once(SchedStats::new(rta86::Analysis.analyzer_name())))
+        .collect();
+
+    let tasksets = args.input_files.iter()
+        .map(|input_file| parse_taskset(input_file, TasksetPlainUnit::Millis))
+        .collect::<anyhow::Result<Vec<_>>>()?;
+    let tasksets: Vec<_> = tasksets.iter().map(Vec::as_slice).collect();
+
+    // The exact test is the reference for all the others.
+    let (results, measurement) = measure(|| rta86::Analysis.run_batch(&tasksets));
+    let references: Vec<_> = results.iter().map(SchedOutcome::from_result).collect();
+
+    let reference_stats = stats.last_mut().unwrap();
+    let runtime = measurement.runtime.div_f64(usize::max(tasksets.len(), 1) as f64);
+    for &reference in references.iter() {
+        reference_stats.record_with_reference(reference, reference, runtime);
+    }
+    reference_stats.record_memory(measurement.memory);
+
+    for (analysis, stats) in analyses.iter().zip(stats.iter_mut()) {
+        let _ = stats.run_batch_with_reference(analysis.as_ref(), &tasksets, &references);
+    }
+
+    rank_by_accuracy_per_second(&mut stats);
+
+    println!("{:<60} {:>10} {:>9} {:>8} {:>14} {:>14} {:>12}", "test", "analyzed", "accuracy", "unsound", "mean runtime", "accuracy/s", "peak memory");
+    for stats in stats {
+        println!("{:<60} {:>10} {:>8.2}% {:>8} {:>14?} {:>14.1} {:>12}",
+            stats.analyzer_name,
+            stats.analyzed(),
+            stats.accuracy() * 100.0,
+            stats.unsound,
+            stats.mean_runtime(),
+            stats.accuracy_per_second(),
+            stats.peak_bytes.map_or("-".to_owned(), |bytes| format!("{:.1} KiB", bytes as f64 / 1024.0)),
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,27 @@
+//! Anonymization of tasksets, to share them in bug reports.
+//!
+//! Taskset files may carry the names of the tasks, e.g. the threads of rt-app
+//! workloads, and their times reveal the rates of the controlled system. An
+//! anonymized taskset keeps only the parameters of the tasks, in their
+//! priority order, expressed in multiples of their greatest common divisor
+//! (refer to [`TimeRescaling`]) times an optional integer factor. The ratios
+//! among the times are preserved exactly, and so are the verdicts of the tests,
+//! while the absolute times are not disclosed.
+
+use eva_rt_engine::prelude::*;
+
+/// Tasks expressed in multiples of their greatest common divisor, times the
+/// given factor, as nanoseconds. Tasksets whose times are not integer
+/// nanoseconds are only multiplied by the factor.
+pub fn anonymize_taskset(taskset: &[RTTask], scale: u64) -> Vec<RTTask> {
+    let rescaling = TimeRescaling::from_taskset(taskset);
+    let scale = scale as f64;
+
+    rescaling.rescale_taskset(taskset).into_iter()
+        .map(|task| RTTask {
+            wcet: task.wcet * scale,
+            deadline: task.deadline * scale,
+            period: task.period * scale,
+        })
+        .collect()
+}
@@ -0,0 +1,90 @@
+//! Differences between two analysis runs, e.g. of two versions of a taskset,
+//! to review the schedulability impact of a model change at a glance.
+//!
+//! Tasks are matched by their index, and tests by their ID, which is stable
+//! across renames. The differences cover the tasks' parameters, the verdict of
+//! each test, the response times and slacks of the tasks, when known, and the
+//! utilization margin of the platform.
+
+use eva_rt_engine::prelude::*;
+use super::report::{Report, verdict_name};
+
+/// Differences between the old and the new run, one per line. Unchanged
+/// values are omitted.
+pub fn diff(old: &Report, new: &Report) -> Vec<String> {
+    let mut lines = Vec::new();
+
+    if old.platform != new.platform {
+        lines.push(format!("platform: {:?} -> {:?}", old.platform, new.platform));
+    }
+
+    for i in 0..usize::max(old.taskset.len(), new.taskset.len()) {
+        match (old.taskset.get(i), new.taskset.get(i)) {
+            (Some(old_task), Some(new_task)) => {
+                let fields = [
+                    ("wcet", old_task.wcet, new_task.wcet),
+                    ("deadline", old_task.deadline, new_task.deadline),
+                    ("period", old_task.period, new_task.period),
+                ];
+
+                for (name, old_value, new_value) in fields {
+                    if old_value != new_value {
+                        lines.push(format!("task {i}: {name} {} -> {} ms", old_value.as_millis(), new_value.as_millis()));
+                    }
+                }
+            },
+            (None, Some(task)) =>
+                lines.push(format!("task {i}: added ({})", task_parameters(task))),
+            (Some(task), None) =>
+                lines.push(format!("task {i}: removed ({})", task_parameters(task))),
+            (None, None) => unreachable!(),
+        }
+    }
+
+    for old_result in &old.results {
+        match new.results.iter().find(|result| result.id == old_result.id) {
+            Some(new_result) if new_result.outcome != old_result.outcome =>
+                lines.push(format!("\"{}\": {} -> {}",
+                    old_result.analyzer, verdict_name(old_result.outcome), verdict_name(new_result.outcome))),
+            Some(_) => {},
+            None =>
+                lines.push(format!("\"{}\": only run on the old taskset", old_result.analyzer)),
+        }
+    }
+
+    for new_result in &new.results {
+        if !old.results.iter().any(|result| result.id == new_result.id) {
+            lines.push(format!("\"{}\": only run on the new taskset", new_result.analyzer));
+        }
+    }
+
+    if let (Some(old_rts), Some(new_rts)) = (&old.response_times, &new.response_times) {
+        for (i, (old_rt, new_rt)) in old_rts.iter().zip(new_rts).enumerate() {
+            if old_rt != new_rt {
+                let old_slack = old.taskset[i].deadline - *old_rt;
+                let new_slack = new.taskset[i].deadline - *new_rt;
+
+                lines.push(format!("task {i}: response time {} -> {} ms (slack {} -> {} ms)",
+                    old_rt.as_millis(), new_rt.as_millis(), old_slack.as_millis(), new_slack.as_millis()));
+            }
+        }
+    } else if old.response_times.is_some() != new.response_times.is_some() {
+        let known = if old.response_times.is_some() { "old" } else { "new" };
+        lines.push(format!("response times: only known for the {known} taskset"));
+    }
+
+    let (old_margin, new_margin) = (old.utilization_margin(), new.utilization_margin());
+    if old_margin != new_margin {
+        lines.push(format!("utilization margin: {old_margin:.4} -> {new_margin:.4}"));
+    }
+
+    if old.verdict() != new.verdict() {
+        lines.push(format!("verdict: {} -> {}", verdict_name(old.verdict()), verdict_name(new.verdict())));
+    }
+
+    lines
+}
+
+fn task_parameters(task: &RTTask) -> String {
+    format!("C = {}, D = {}, T = {} ms", task.wcet.as_millis(), task.deadline.as_millis(), task.period.as_millis())
+}
@@ -0,0 +1,108 @@
+//! Import and export of the static dispatch tables of cyclic executives.
+//!
+//! Files with the `.table.json` extension are versioned dispatch tables (refer
+//! to [`super::schema`]), while files with the `.csv` extension list one
+//! dispatched slice per line, with an optional header and `#` comments:
+//! ```csv
+//! task,job,start,duration
+//! 0,0,0,1
+//! 1,0,1,1.8
+//! ```
+//! whose times are expressed in the given unit. CSV tables declare neither the
+//! major frame nor the precedence constraints.
+
+use eva_rt_engine::prelude::*;
+use eva_rt_engine::algorithms::cyclic_executive::{CyclicExecutive, Dispatch};
+use eva_rt_engine::algorithms::cyclic_executive::checker::Precedence;
+use super::TasksetPlainUnit;
+use super::schema;
+
+/// Static dispatch table, as read from or written to a file.
+#[derive(Debug, Clone)]
+pub struct DispatchTable {
+    /// Length of the major frame, the hyperperiod of the taskset if missing.
+    pub major_frame: Option<Time>,
+    pub table: Vec<Dispatch>,
+    pub precedences: Vec<Precedence>,
+}
+
+impl DispatchTable {
+    pub fn from_executive(executive: &CyclicExecutive) -> Self {
+        Self { major_frame: Some(executive.major_frame), table: executive.table.clone(), precedences: Vec::new() }
+    }
+}
+
+fn is_json<P: AsRef<std::path::Path>>(table_file: P) -> bool {
+    table_file.as_ref().to_string_lossy().ends_with(".table.json")
+}
+
+/// Parse the given dispatch table file, refer to the [module](`self`) level
+/// documentation. The unit only applies to CSV tables.
+pub fn parse_dispatch_table<P: AsRef<std::path::Path>>(table_file: P, unit: TasksetPlainUnit) -> anyhow::Result<DispatchTable> {
+    if is_json(&table_file) {
+        return schema::parse_dispatch_table(table_file);
+    }
+
+    anyhow::ensure!(table_file.as_ref().extension().is_some_and(|extension| extension == "csv"),
+        "dispatch tables must be either .table.json or .csv files");
+
+    deserialize_csv_table(&std::fs::read_to_string(table_file)?, unit)
+}
+
+/// Write the dispatch table file, refer to the [module](`self`) level
+/// documentation.
+pub fn write_dispatch_table<P: AsRef<std::path::Path>>(table_file: P, table: &DispatchTable, unit: TasksetPlainUnit) -> anyhow::Result<()> {
+    let data =
+        if is_json(&table_file) {
+            serde_json::to_string_pretty(&schema::serialize_dispatch_table(table, unit))?
+        } else {
+            serialize_csv_table(table, unit)
+        };
+
+    Ok(std::fs::write(table_file, data)?)
+}
+
+pub fn deserialize_csv_table(data: &str, unit: TasksetPlainUnit) -> anyhow::Result<DispatchTable> {
+    let lines = data.lines().enumerate()
+        .map(|(n, line)| (n + 1, line.trim()))
+        .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'))
+        .skip_while(|(_, line)| line.starts_with("task"));
+
+    let table = lines
+        .map(|(n, line)| deserialize_csv_dispatch(line, unit)
+            .map_err(|err| anyhow::format_err!("line {n}: {err}")))
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    Ok(DispatchTable { major_frame: None, table, precedences: Vec::new() })
+}
+
+fn deserialize_csv_dispatch(line: &str, unit: TasksetPlainUnit) -> anyhow::Result<Dispatch> {
+    let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+    anyhow::ensure!(fields.len() == 4, "expected four fields (task, job, start, duration), got {}", fields.len());
+
+    let time = |i: usize, name: &str| -> anyhow::Result<Time> {
+        let value = fields[i].parse::<f64>()
+            .map_err(|err| anyhow::format_err!("Failed to parse field '{name}': {err}"))?;
+        anyhow::ensure!(value >= 0.0, "field '{name}' must be non-negative");
+
+        Ok(Time::nanos(value * unit.nanos()))
+    };
+
+    Ok(Dispatch {
+        task: fields[0].parse().map_err(|err| anyhow::format_err!("Failed to parse field 'task': {err}"))?,
+        job: fields[1].parse().map_err(|err| anyhow::format_err!("Failed to parse field 'job': {err}"))?,
+        start: time(2, "start")?,
+        duration: time(3, "duration")?,
+    })
+}
+
+pub fn serialize_csv_table(table: &DispatchTable, unit: TasksetPlainUnit) -> String {
+    let divider = unit.nanos();
+
+    let mut data = String::from("task,job,start,duration\n");
+    for dispatch in table.table.iter() {
+        data += &format!("{},{},{},{}\n", dispatch.task, dispatch.job,
+            dispatch.start.as_nanos() / divider, dispatch.duration.as_nanos() / divider);
+    }
+    data
+}
@@ -0,0 +1,277 @@
+//! Certification evidence bundles: a single archive with everything needed to
+//! review, reproduce and audit an analysis run, e.g. as verification evidence
+//! for DO-178C or ISO 26262.
+//!
+//! The bundle is a ZIP archive containing:
+//! - `input/`: the taskset file and, if any, the configuration file, as read;
+//! - `results.json`: the platform, profile and parameters of the run, the
+//!   verdict of each test together with its version and model assumptions,
+//!   the excluded tests and the overall verdict (refer to [`results_json`]);
+//! - `report.md` and `report.html`: the reports of the run;
+//! - `manifest.json`: the engine version, the time of the run, and the size
+//!   and SHA-256 digest of every other file;
+//! - `manifest.sig`: if a signing key is given, the HMAC-SHA256 of the
+//!   manifest with the key, hex encoded.
+//!
+//! The files are stored uncompressed, with a fixed modification time, so that
+//! the archive can be inspected with any ZIP tool and only differs between two
+//! runs where their contents do.
+//!
+//! A *signed* bundle carries a message authentication code computed with a
+//! shared secret key, not a public-key signature: it can be checked, and
+//! forged, by anyone who holds the key. Public-key signatures of the bundle,
+//! if required, are left to the signing tools of the organization (e.g.
+//! detached GPG signatures). Bundles are checked with [`verify`].
+
+use std::io::{Cursor, Read, Write};
+use eva_rt_engine::prelude::*;
+use eva_rt_engine::algorithms::registry;
+use super::report::{self, Report, ReportFormat, TestResult};
+use serde_json::{Value, json};
+use hmac::{Hmac, Mac};
+use sha2::{Digest, Sha256};
+use zip::{CompressionMethod, DateTime, ZipArchive, ZipWriter, write::SimpleFileOptions};
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Version of the manifest and results formats.
+pub const BUNDLE_VERSION: u64 = 1;
+
+/// Evidence bundle of the run, refer to the [module](`self`) level
+/// documentation. The `inputs` are the names and contents of the input files.
+pub fn bundle(report: &Report, inputs: &[(String, Vec<u8>)], signing_key: Option<&[u8]>) -> anyhow::Result<Vec<u8>> {
+    let mut files: Vec<(String, Vec<u8>)> = inputs.iter()
+        .map(|(name, content)| (format!("input/{name}"), content.clone()))
+        .collect();
+
+    files.push(("results.json".to_owned(), serde_json::to_vec_pretty(&results_json(report)?)?));
+    files.push(("report.md".to_owned(), report.render(ReportFormat::Markdown).into_bytes()));
+    files.push(("report.html".to_owned(), report.render(ReportFormat::Html).into_bytes()));
+
+    let manifest = json!({
+        "version": BUNDLE_VERSION,
+        "engine": format!("eva-rt-engine {}", report.provenance.engine_version),
+        "generated": report::utc_time(report.provenance.timestamp),
+        "signature": signing_key.map(|_| "hmac-sha256"),
+        "files": files.iter()
+            .map(|(name, content)| json!({ "path": name, "size": content.len(), "sha256": hex(&Sha256::digest(content)) }))
+            .collect::<Vec<_>>(),
+    });
+    let manifest = serde_json::to_vec_pretty(&manifest)?;
+
+    if let Some(key) = signing_key {
+        files.push(("manifest.sig".to_owned(), hex(&hmac(key).chain_update(&manifest).finalize().into_bytes()).into_bytes()));
+    }
+    files.insert(0, ("manifest.json".to_owned(), manifest));
+
+    zip_stored(&files)
+}
+
+/// Results of the run, as stored in the bundle.
+pub fn results_json(report: &Report) -> anyhow::Result<Value> {
+    let results = |results: &[TestResult]| -> Vec<Value> {
+        results.iter()
+            .map(|result| json!({
+                "id": result.id,
+                "version": registry::test_version(&result.id),
+                "analyzer": result.analyzer,
+                "exact": result.exact,
+                "verdict": report::verdict_name(result.outcome),
+                "details": result.details,
+                "runtime_ns": result.runtime.as_nanos() as u64,
+                "assumptions": result.assumptions.iter().map(Assumption::id).collect::<Vec<_>>(),
+            }))
+            .collect()
+    };
+
+    Ok(json!({
+        "version": BUNDLE_VERSION,
+        "input": { "file": report.input_file, "fnv1a": format!("{:016x}", report.provenance.input_hash) },
+        "platform": serde_json::to_value(report.platform)?,
+        "profile": serde_json::to_value(report.profile)?,
+        "parameters": report.provenance.parameters.iter()
+            .map(|(name, value)| json!({ "name": name, "value": value }))
+            .collect::<Vec<_>>(),
+        "verdict": report::verdict_name(report.verdict()),
+        "results": results(&report.results),
+        "results_with_margins": report.results_with_margins.as_deref().map(results),
+        "excluded": report.excluded.iter()
+            .map(|exclusion| json!({ "id": exclusion.id, "analyzer": exclusion.analyzer, "reason": exclusion.reason }))
+            .collect::<Vec<_>>(),
+        "assumptions": report.assumptions().into_iter()
+            .map(|(assumption, ids)| json!({ "id": assumption.id(), "description": assumption.description(), "tests": ids }))
+            .collect::<Vec<_>>(),
+    }))
+}
+
+/// Check the digests of the files of the bundle against its manifest and, if
+/// a key is given, the HMAC of the manifest. Returns the paths of the checked
+/// files.
+///
+/// The archive must be laid out as written by [`bundle`]: the central
+/// directory lists every file once, and every byte before it belongs to one
+/// of the listed files, so that no ZIP tool can extract a file which has not
+/// been checked.
+pub fn verify(archive: &[u8], signing_key: Option<&[u8]>) -> anyhow::Result<Vec<String>> {
+    let files = zip_entries(archive)?;
+    let file = |path: &str| files.iter()
+        .find(|(name, _)| name == path)
+        .map(|(_, content)| content.as_slice())
+        .ok_or_else(|| anyhow::format_err!("the bundle has no {path}"));
+
+    let manifest_bytes = file("manifest.json")?;
+    let manifest: Value = serde_json::from_slice(manifest_bytes)?;
+
+    if let Some(key) = signing_key {
+        let signature = unhex(file("manifest.sig")?)
+            .ok_or_else(|| anyhow::format_err!("the signature of the manifest is not hex encoded"))?;
+
+        hmac(key).chain_update(manifest_bytes).verify_slice(&signature)
+            .map_err(|_| anyhow::format_err!("the signature of the manifest does not match the key"))?;
+    }
+
+    let entries = manifest["files"].as_array()
+        .ok_or_else(|| anyhow::format_err!("the manifest lists no files"))?;
+
+    let mut checked = Vec::with_capacity(entries.len());
+    for entry in entries {
+        let (Some(path), Some(digest)) = (entry["path"].as_str(), entry["sha256"].as_str())
+            else { return Err(anyhow::format_err!("invalid manifest entry {entry}")); };
+
+        if checked.iter().any(|checked| checked == path) {
+            return Err(anyhow::format_err!("{path} is listed twice in the manifest"));
+        }
+
+        if hex(&Sha256::digest(file(path)?)) != digest {
+            return Err(anyhow::format_err!("the digest of {path} does not match the manifest"));
+        }
+
+        checked.push(path.to_owned());
+    }
+
+    if let Some((path, _)) = files.iter().find(|(path, _)| !checked.contains(path) && path != "manifest.json" && path != "manifest.sig") {
+        return Err(anyhow::format_err!("{path} is not in the manifest"));
+    }
+
+    Ok(checked)
+}
+
+// HMAC-SHA256 with the given key.
+fn hmac(key: &[u8]) -> HmacSha256 {
+    HmacSha256::new_from_slice(key)
+        .expect("HMAC accepts keys of any length")
+}
+
+/// ZIP archive of the files, stored without compression. All the files are
+/// dated 1980-01-01, the earliest MS-DOS date.
+pub fn zip_stored(files: &[(String, Vec<u8>)]) -> anyhow::Result<Vec<u8>> {
+    let options = SimpleFileOptions::default()
+        .compression_method(CompressionMethod::Stored)
+        .last_modified_time(DateTime::default())
+        .unix_permissions(0o644);
+
+    let mut writer = ZipWriter::new(Cursor::new(Vec::new()));
+    for (name, content) in files {
+        writer.start_file(name.as_str(), options)?;
+        writer.write_all(content)?;
+    }
+
+    Ok(writer.finish()?.into_inner())
+}
+
+/// Files of a ZIP archive as written by [`zip_stored`], in the order of its
+/// central directory. The end of central directory record must close the
+/// archive and agree with the directory, no two files may share a name, and
+/// the files must be stored back to back from the start of the archive to the
+/// central directory, each under the name it is listed with: data which is not
+/// listed, e.g. a second local entry appended after the listed one, is
+/// rejected by its offset.
+pub fn zip_entries(archive: &[u8]) -> anyhow::Result<Vec<(String, Vec<u8>)>> {
+    const END_RECORD_LEN: usize = 22;
+    const LOCAL_HEADER_LEN: usize = 30;
+
+    let invalid = |reason: &str| anyhow::format_err!("not an evidence bundle: {reason}");
+    let u16_at = |at: usize| archive.get(at..at + 2).map(|bytes| u16::from_le_bytes([bytes[0], bytes[1]]) as usize);
+    let u32_at = |at: usize| archive.get(at..at + 4).map(|bytes| u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) as usize);
+
+    // End of central directory record, without comment.
+    let end_record = archive.len().checked_sub(END_RECORD_LEN)
+        .ok_or_else(|| invalid("truncated ZIP archive"))?;
+    let field = |offset: usize| u16_at(end_record + offset).unwrap_or_default();
+
+    if u32_at(end_record) != Some(0x06054b50) || field(4) != 0 || field(6) != 0 || field(20) != 0 {
+        return Err(invalid("the archive does not end with its end of central directory record"));
+    }
+
+    let (entries, directory_size, directory_start) = (field(10), u32_at(end_record + 12), u32_at(end_record + 16));
+    if field(8) != entries || directory_start.zip(directory_size).map(|(start, size)| start + size) != Some(end_record) {
+        return Err(invalid("the end of central directory record does not match the central directory"));
+    }
+
+    let mut zip = ZipArchive::new(Cursor::new(archive))?;
+    if zip.offset() != 0 || Some(zip.central_directory_start() as usize) != directory_start {
+        return Err(invalid("the central directory is not where the end record places it"));
+    }
+
+    // Files with the same name collapse into a single entry of the archive.
+    if zip.len() != entries {
+        return Err(anyhow::format_err!("the bundle has duplicate file names"));
+    }
+
+    let mut files = Vec::with_capacity(entries);
+    let mut end = 0;
+
+    for index in 0..zip.len() {
+        let mut file = zip.by_index(index)?;
+        let name = file.name().to_owned();
+
+        if file.compression() != CompressionMethod::Stored {
+            return Err(invalid("compressed file"));
+        }
+
+        let header = file.header_start() as usize;
+        if header != end {
+            return Err(anyhow::format_err!("the bundle has data before {name} which is not in its central directory"));
+        }
+
+        let name_len = u16_at(header + 26).ok_or_else(|| invalid("truncated ZIP archive"))?;
+        let local_name = archive.get(header + LOCAL_HEADER_LEN..header + LOCAL_HEADER_LEN + name_len);
+        if local_name != Some(file.name_raw()) {
+            return Err(anyhow::format_err!("the local header of {name} does not match the central directory"));
+        }
+
+        // A stored file cannot be larger than the archive which holds it, the
+        // declared size is checked before the content is allocated.
+        if file.size() > archive.len() as u64 {
+            return Err(anyhow::format_err!("{name} is declared larger than the bundle"));
+        }
+
+        // The CRC is checked at the end of the file.
+        let mut content = Vec::with_capacity(file.size() as usize);
+        file.read_to_end(&mut content)
+            .map_err(|err| anyhow::format_err!("{name} is corrupted: {err}"))?;
+
+        end = file.data_start().ok_or_else(|| invalid("truncated ZIP archive"))? as usize + content.len();
+        files.push((name, content));
+    }
+
+    if files.is_empty() || Some(end) != directory_start {
+        return Err(anyhow::format_err!("the bundle has data which is not in its central directory"));
+    }
+
+    Ok(files)
+}
+
+pub fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+fn unhex(text: &[u8]) -> Option<Vec<u8>> {
+    if !text.len().is_multiple_of(2) {
+        return None;
+    }
+
+    text.chunks_exact(2)
+        .map(|digits| u8::from_str_radix(std::str::from_utf8(digits).ok()?, 16).ok())
+        .collect()
+}
@@ -0,0 +1,75 @@
+//! Cross checks of the registered tests against external reference
+//! implementations, e.g. SchedCAT scripts or MAST, run on the same tasksets.
+//!
+//! The reference is any command which reads a JSON document from its standard
+//! input, with the ID of the test to reproduce, the platform and the taskset in
+//! the current versioned format (refer to [`reference_input`]), and prints the
+//! name of its verdict, as in the reports, on the first line of its standard
+//! output: `schedulable`, `non schedulable`, `inconclusive` or `error`. The
+//! reference implementations of the published tests should agree with the
+//! registered ones on every taskset, up to numerical rounding.
+
+use eva_rt_engine::prelude::*;
+use super::report::verdict_name;
+use super::schema;
+use super::TasksetPlainUnit;
+
+/// Verdicts of a registered test and of its reference implementation on the
+/// same taskset.
+#[derive(Debug, Clone)]
+pub struct CrossCheck {
+    pub id: String,
+    pub analyzer: String,
+    pub outcome: SchedOutcome,
+    pub reference: SchedOutcome,
+}
+
+impl CrossCheck {
+    pub fn agrees(&self) -> bool {
+        self.outcome == self.reference
+    }
+}
+
+/// Document written to the standard input of the reference, refer to the
+/// [module](`self`) level documentation.
+pub fn reference_input(id: &str, platform: &Platform, taskset: &[RTTask]) -> anyhow::Result<serde_json::Value> {
+    Ok(serde_json::json!({
+        "test": id,
+        "platform": serde_json::to_value(platform)?,
+        "taskset": schema::serialize_taskset(taskset, &WcetMargins::none(taskset.len()), TasksetPlainUnit::Nanos),
+    }))
+}
+
+/// Parse the verdict printed by the reference.
+pub fn parse_verdict(output: &str) -> anyhow::Result<SchedOutcome> {
+    let line = output.lines().next().unwrap_or_default().trim();
+
+    [SchedOutcome::Schedulable, SchedOutcome::NonSchedulable, SchedOutcome::Inconclusive, SchedOutcome::Error]
+        .into_iter()
+        .find(|outcome| verdict_name(*outcome) == line)
+        .ok_or_else(|| anyhow::format_err!("unknown verdict \"{line}\" of the reference"))
+}
+
+/// Run the reference command on the taskset, for the test with the given ID.
+pub fn run_reference(command: &[String], id: &str, platform: &Platform, taskset: &[RTTask]) -> anyhow::Result<SchedOutcome> {
+    use std::io::Write;
+    use anyhow::Context;
+
+    let (program, args) = command.split_first()
+        .ok_or_else(|| anyhow::format_err!("the reference command is empty"))?;
+
+    let mut child = std::process::Command::new(program)
+        .args(args)
+        .stdin(std::process::Stdio::piped())
+        .stdout(std::process::Stdio::piped())
+        .spawn()
+        .with_context(|| format!("cannot run the reference \"{program}\""))?;
+
+    let input = serde_json::to_vec(&reference_input(id, platform, taskset)?)?;
+    child.stdin.take().unwrap().write_all(&input)?;
+
+    let output = child.wait_with_output()?;
+    anyhow::ensure!(output.status.success(), "the reference \"{program}\" failed with {}", output.status);
+
+    parse_verdict(&String::from_utf8_lossy(&output.stdout))
+}
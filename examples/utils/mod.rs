@@ -1,21 +1,479 @@
+
 use eva_rt_engine::prelude::*;
+use eva_rt_engine::algorithms::registry::{self, Exclusion, Profile, Registered, Resolution};
 
 pub mod taskset_serde;
+pub mod rt_app;
+pub mod schema;
+// Only used by the tools which write and compare reports.
+#[allow(dead_code)]
+pub mod report;
+#[allow(dead_code)]
+pub mod diff;
+#[allow(dead_code)]
+pub mod evidence;
+#[allow(dead_code)]
+pub mod anonymize;
+#[allow(dead_code)]
+pub mod golden;
+#[allow(dead_code)]
+pub mod dispatch_table;
 
 pub use taskset_serde::*;
 
-pub fn run_analysis<A, T, Taskset>(analysis: A, taskset: Taskset) -> anyhow::Result<()>
+// Count the allocations of the tests, whose memory usage is then reported.
+#[cfg(feature = "alloc-stats")]
+#[global_allocator]
+static ALLOCATOR: eva_rt_engine::utils::instrumentation::CountingAllocator =
+    eva_rt_engine::utils::instrumentation::CountingAllocator;
+
+/// Kernel latencies of the target platform, applied to every analysis.
+#[derive(clap::Args, Debug, Clone)]
+pub struct KernelLatencyArgs {
+    /// Maximum IRQ-to-release latency, microseconds
+    #[arg(long="irq-latency-us", default_value_t = 0.0)]
+    pub irq_latency_us: f64,
+
+    /// Maximum scheduling latency, microseconds
+    #[arg(long="sched-latency-us", default_value_t = 0.0)]
+    pub sched_latency_us: f64,
+}
+
+#[allow(dead_code)]
+impl KernelLatencyArgs {
+    pub fn latencies(&self) -> KernelLatencies {
+        KernelLatencies {
+            irq_to_release: Time::micros(self.irq_latency_us),
+            scheduling: Time::micros(self.sched_latency_us),
+        }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.irq_latency_us == 0.0 && self.sched_latency_us == 0.0
+    }
+
+    pub fn apply<A>(&self, analysis: A) -> WithKernelLatencies<A> {
+        WithKernelLatencies { latencies: self.latencies(), analysis }
+    }
+}
+
+/// What-if transformations of the taskset, applied before the analyses.
+#[derive(clap::Args, Debug, Clone)]
+pub struct WhatIfArgs {
+    /// Multiply the WCET of every task by the given factor, rounding up to
+    /// the nanosecond
+    #[arg(long="scale-wcet", default_value_t = 1.0)]
+    pub scale_wcet: f64,
+
+    /// Multiply the period and the deadline of every task by the given
+    /// factor, rounding down to the nanosecond
+    #[arg(long="scale-period", default_value_t = 1.0)]
+    pub scale_period: f64,
+
+    /// Add the given release jitter to every task, milliseconds. A task (C,
+    /// D, T) is analysed as (C, D - J, T - J), after the scaling.
+    #[arg(long="add-jitter", default_value_t = 0.0)]
+    pub add_jitter: f64,
+}
+
+#[allow(dead_code)]
+impl WhatIfArgs {
+    pub fn is_identity(&self) -> bool {
+        self.scale_wcet == 1.0 && self.scale_period == 1.0 && self.add_jitter == 0.0
+    }
+
+    pub fn apply(&self, taskset: &[RTTask]) -> anyhow::Result<Vec<RTTask>> {
+        anyhow::ensure!(self.scale_wcet > 0.0 && self.scale_period > 0.0, "the scaling factors must be positive");
+        anyhow::ensure!(self.add_jitter >= 0.0, "the added jitter must be non-negative");
+
+        let scaled: Vec<_> = taskset.iter()
+            .map(|task| RTTask {
+                wcet: (task.wcet * self.scale_wcet).ceil(),
+                deadline: (task.deadline * self.scale_period).floor(),
+                period: (task.period * self.scale_period).floor(),
+            })
+            .collect();
+
+        // The release jitter transforms the taskset as the kernel latencies.
+        let jitter = KernelLatencies { irq_to_release: Time::millis(self.add_jitter), scheduling: Time::zero() };
+        jitter.check_preconditions(&scaled)?;
+
+        Ok(jitter.to_sporadic_taskset(&scaled))
+    }
+
+    /// Parameters of the run for its [`report::Provenance`], if any
+    /// transformation applies.
+    pub fn provenance_parameters(&self) -> Vec<(String, String)> {
+        [
+            ("WCET scaling", self.scale_wcet, 1.0, ""),
+            ("Period scaling", self.scale_period, 1.0, ""),
+            ("Added jitter", self.add_jitter, 0.0, "ms"),
+        ]
+            .into_iter()
+            .filter(|(_, value, identity, _)| value != identity)
+            .map(|(name, value, _, unit)| (name.to_owned(), format!("{value}{unit}")))
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+#[derive(clap::ValueEnum)]
+pub enum Algorithm {
+    Edf,
+    Fp,
+    Edzl,
+    Fpzl,
+}
+
+/// Priority assignment of the tasks, refer to [`PriorityOrder`].
+#[derive(Debug, Clone, Copy)]
+#[derive(clap::ValueEnum)]
+pub enum PriorityArg {
+    /// The order of the taskset file, highest priority first
+    Given,
+    /// Rate Monotonic
+    Rm,
+    /// Deadline Monotonic
+    Dm,
+}
+
+impl PriorityArg {
+    pub fn order(&self) -> PriorityOrder {
+        match self {
+            PriorityArg::Given => PriorityOrder::Given,
+            PriorityArg::Rm => PriorityOrder::RateMonotonic,
+            PriorityArg::Dm => PriorityOrder::DeadlineMonotonic,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+#[derive(clap::ValueEnum)]
+pub enum ProfileArg {
+    Quick,
+    Thorough,
+    Exhaustive,
+}
+
+/// Target platform and analysis profile, given either on the command line or
+/// by a configuration file.
+#[derive(clap::Args, Debug, Clone)]
+pub struct PlatformArgs {
+    #[arg(short='c', default_value_t = 1)]
+    pub cpus: u64,
+
+    #[arg(short='a', value_enum, required_unless_present = "config")]
+    pub algorithm: Option<Algorithm>,
+
+    /// Read the platform and the kernel latencies from a configuration file,
+    /// in place of the other options.
+    #[arg(long, conflicts_with_all = ["cpus", "algorithm"])]
+    pub config: Option<String>,
+
+    /// Analysis profile, overriding the one of the configuration file
+    /// [default: thorough]
+    #[arg(short='p', long, value_enum)]
+    pub profile: Option<ProfileArg>,
+
+    /// Run only the test with the given ID, e.g. up.fp.rta86, or the tests
+    /// under the given prefix, e.g. smp.edf. Repeatable, overrides the tests
+    /// of the configuration file.
+    #[arg(long = "test")]
+    pub tests: Vec<String>,
+
+    /// Priority assignment of the tasks under fixed priority scheduling,
+    /// overriding the one of the configuration file [default: given]
+    #[arg(long, value_enum)]
+    pub priority: Option<PriorityArg>,
+}
+
+#[allow(dead_code)]
+impl PlatformArgs {
+    pub fn resolve(&self) -> anyhow::Result<(Platform, KernelLatencies, Profile)> {
+        let (mut platform, latencies, config_profile) =
+            match (&self.config, self.algorithm) {
+                (Some(config_file), _) => {
+                    let config = schema::parse_config(config_file)?;
+                    (config.platform, config.latencies, config.profile)
+                },
+                (None, Some(algorithm)) => {
+                    let algorithm =
+                        match algorithm {
+                            Algorithm::Edf => SchedulingAlgorithm::EarliestDeadlineFirst,
+                            Algorithm::Fp => SchedulingAlgorithm::FixedPriority,
+                            Algorithm::Edzl => SchedulingAlgorithm::EarliestDeadlineZeroLaxity,
+                            Algorithm::Fpzl => SchedulingAlgorithm::FixedPriorityZeroLaxity,
+                        };
+
+                    let platform =
+                        if self.cpus == 1 {
+                            Platform::uniprocessor(algorithm)
+                        } else {
+                            Platform::global(self.cpus, algorithm)
+                        };
+
+                    (platform, KernelLatencies::zero(), None)
+                },
+                (None, None) => unreachable!("the algorithm is required without a configuration"),
+            };
+
+        if let Some(priority) = self.priority {
+            platform.priority_order = priority.order();
+        }
+
+        let profile =
+            match self.profile {
+                Some(ProfileArg::Quick) => Profile::Quick,
+                Some(ProfileArg::Thorough) => Profile::Thorough,
+                Some(ProfileArg::Exhaustive) => Profile::Exhaustive,
+                None => config_profile.unwrap_or(Profile::Thorough),
+            };
+
+        Ok((platform, latencies, profile))
+    }
+
+    /// Parameters of the run for its [`report::Provenance`], besides the
+    /// platform and the profile.
+    pub fn provenance_parameters(&self, latencies: &KernelLatencies) -> anyhow::Result<Vec<(String, String)>> {
+        let mut parameters = vec![
+            ("IRQ latency".to_owned(), format!("{}us", latencies.irq_to_release.as_nanos() / Time::MICRO_TO_NANO)),
+            ("Scheduling latency".to_owned(), format!("{}us", latencies.scheduling.as_nanos() / Time::MICRO_TO_NANO)),
+        ];
+
+        let overheads = self.overheads()?;
+        if !overheads.is_zero() {
+            parameters.extend([
+                ("Context switch".to_owned(), format!("{}us", overheads.context_switch.as_nanos() / Time::MICRO_TO_NANO)),
+                ("Interrupt period".to_owned(), format!("{}us", overheads.interrupt_period.as_nanos() / Time::MICRO_TO_NANO)),
+                ("Interrupt overhead".to_owned(), format!("{}us", overheads.interrupt_overhead.as_nanos() / Time::MICRO_TO_NANO)),
+            ]);
+        }
+
+        if let Some(config_file) = &self.config {
+            parameters.push(("Configuration".to_owned(),
+                format!("{config_file} (FNV-1a {:016x})", report::fnv1a(&std::fs::read(config_file)?))));
+        }
+
+        if let Some(priority) = self.priority {
+            parameters.push(("Priority order".to_owned(), format!("{:?}", priority.order())));
+        }
+
+        let tests = self.selected_tests()?;
+        if !tests.is_empty() {
+            parameters.push(("Selected tests".to_owned(), tests.join(", ")));
+        }
+
+        Ok(parameters)
+    }
+
+    /// Scheduling overheads of the configuration, if any.
+    pub fn overheads(&self) -> anyhow::Result<SchedulingOverheads> {
+        match &self.config {
+            Some(config_file) => Ok(schema::parse_config(config_file)?.overheads),
+            None => Ok(SchedulingOverheads::zero()),
+        }
+    }
+
+    /// IDs of the tests to run, or prefixes of them; all if empty.
+    pub fn selected_tests(&self) -> anyhow::Result<Vec<String>> {
+        match &self.config {
+            Some(config_file) if self.tests.is_empty() => Ok(schema::parse_config(config_file)?.tests),
+            _ => Ok(self.tests.clone()),
+        }
+    }
+}
+
+#[allow(dead_code)]
+pub fn run_analysis<A, T, Taskset>(analysis: A, taskset: Taskset) -> anyhow::Result<SchedOutcome>
     where
         A: SchedAnalysis<T, Taskset>
 {
+    print!("Running \"{}\":\n\t", analysis.analyzer_name());
+
+    report_result(analysis.is_schedulable(taskset))
+}
+
+/// Run the analysis on a separate thread, giving up after the timeout, if any.
+/// Tests which time out are reported as inconclusive.
+#[allow(dead_code)]
+pub fn run_analysis_with_timeout<A>(
+    id: &str,
+    analysis: A,
+    taskset: std::sync::Arc<[RTTask]>,
+    timeout: Option<std::time::Duration>,
+    exact: bool,
+) -> anyhow::Result<report::TestResult>
+    where
+        A: for<'a> SchedAnalysis<(), &'a [RTTask]> + Send + 'static
+{
+    spawn_analysis(id, analysis, taskset, timeout, exact).wait()
+}
+
+/// Analysis running on its own thread, started by [`spawn_analysis`].
+pub struct RunningAnalysis {
+    id: String,
+    name: String,
+    exact: bool,
+    timeout: Option<std::time::Duration>,
+    start: std::time::Instant,
+    receiver: std::sync::mpsc::Receiver<(anyhow::Result<()>, Measurement)>,
+    // Set once the result is no longer awaited.
+    cancelled: std::sync::Arc<std::sync::atomic::AtomicBool>,
+}
+
+/// Start the analysis on a separate thread, whose timeout, if any, runs from
+/// now. Refer to [`RunningAnalysis::wait`] for its result.
+///
+/// The analyses cannot be interrupted: an analysis is cancelled when it times
+/// out, or when its [`RunningAnalysis`] is dropped without waiting, but if its
+/// thread has already started, the thread is left running detached until the
+/// analysis ends, and only its result is discarded. The detached threads end
+/// at the latest with the process, and only the profiles with a timeout leave
+/// them behind. These profiles never escalate to a further stage, but the
+/// detached threads still compete for the processors with the analyses of any
+/// later [`run_profile`], e.g. on the WCETs inflated by the margins or on the
+/// next taskset of a diff, whose runtimes, and thus timeouts, are measured
+/// under that load.
+pub fn spawn_analysis<A>(
+    id: &str,
+    analysis: A,
+    taskset: std::sync::Arc<[RTTask]>,
+    timeout: Option<std::time::Duration>,
+    exact: bool,
+) -> RunningAnalysis
+    where
+        A: for<'a> SchedAnalysis<(), &'a [RTTask]> + Send + 'static
+{
+    let name = analysis.analyzer_name().to_owned();
+
+    let cancelled = std::sync::Arc::new(std::sync::atomic::AtomicBool::new(false));
+
+    let (sender, receiver) = std::sync::mpsc::channel();
+    let thread_cancelled = cancelled.clone();
+    std::thread::spawn(move || {
+        use std::sync::atomic::Ordering;
+
+        if thread_cancelled.load(Ordering::Relaxed) {
+            return;
+        }
+
+        let (result, measurement) = measured_is_schedulable(&analysis, &taskset[..]);
+
+        if !thread_cancelled.load(Ordering::Relaxed) {
+            let _ = sender.send((result, measurement));
+        }
+    });
+
+    RunningAnalysis { id: id.to_owned(), name, exact, timeout, start: std::time::Instant::now(), receiver, cancelled }
+}
+
+impl RunningAnalysis {
+    /// Wait for the result of the analysis, until its timeout, and print it.
+    /// The analysis is cancelled if it times out, refer to [`spawn_analysis`].
+    pub fn wait(self) -> anyhow::Result<report::TestResult> {
+        let (id, name, exact, timeout) = (self.id.clone(), self.name.clone(), self.exact, self.timeout);
+        print!("Running \"{name}\":\n\t");
+
+        use std::sync::mpsc::RecvTimeoutError;
+
+        let received =
+            match timeout {
+                Some(timeout) => self.receiver.recv_timeout(timeout.saturating_sub(self.start.elapsed())),
+                None => self.receiver.recv().map_err(|_| RecvTimeoutError::Disconnected),
+            };
+
+        // Dropping self cancels the analysis, if it is still running.
+        drop(self);
+
+        match received {
+            Ok((result, measurement)) => {
+                let test_result = report::TestResult {
+                    memory: measurement.memory,
+                    ..report::TestResult::from_result(&id, &name, &result, measurement.runtime, exact)
+                };
+                report_result(result)?;
+                Ok(test_result)
+            },
+            Err(RecvTimeoutError::Disconnected) =>
+                Err(anyhow::format_err!("the test \"{name}\" panicked")),
+            Err(RecvTimeoutError::Timeout) => {
+                let timeout = timeout.unwrap_or_default();
+                println!("timed out after {timeout:?}");
+
+                Ok(report::TestResult {
+                    assumptions: registry::test_assumptions(&id).unwrap_or_default(),
+                    id,
+                    analyzer: name,
+                    outcome: SchedOutcome::Inconclusive,
+                    details: Some(format!("timed out after {timeout:?}")),
+                    runtime: timeout,
+                    memory: None,
+                    exact,
+                })
+            },
+        }
+    }
+}
+
+impl Drop for RunningAnalysis {
+    fn drop(&mut self) {
+        self.cancelled.store(true, std::sync::atomic::Ordering::Relaxed);
+    }
+}
+
+/// Run the applicable tests of the resolution, escalating to the exact ones if
+/// none deems the taskset schedulable. Returns the results and the excluded
+/// tests. The tests are independent, thus the ones of each stage run
+/// concurrently, and their results are reported in order.
+#[allow(dead_code)]
+pub fn run_profile(
+    resolution: Resolution,
+    taskset: std::sync::Arc<[RTTask]>,
+    latencies: &KernelLatencies,
+    profile: Profile,
+) -> anyhow::Result<(Vec<report::TestResult>, Vec<Exclusion>)> {
+    let run_all = |stage: Vec<Registered>| -> anyhow::Result<Vec<report::TestResult>> {
+        let running: Vec<_> = stage.into_iter()
+            .map(|registered| {
+                let analysis = WithKernelLatencies { latencies: latencies.clone(), analysis: registered.analysis };
+                spawn_analysis(registered.id, analysis, taskset.clone(), profile.timeout(), registered.exact)
+            })
+            .collect();
+
+        running.into_iter().map(RunningAnalysis::wait).collect()
+    };
+
+    let mut results = run_all(resolution.applicable)?;
+
+    if !results.iter().any(|result| result.outcome == SchedOutcome::Schedulable) {
+        results.extend(run_all(resolution.escalation)?);
+    }
+
+    Ok((results, resolution.excluded))
+}
+
+/// Simulated scheduling policy of the given algorithm.
+#[allow(dead_code)]
+pub fn simulated_scheduler(algorithm: SchedulingAlgorithm) -> anyhow::Result<Box<dyn eva_rt_engine::simulator::Scheduler>> {
+    use eva_rt_engine::simulator::schedulers::*;
+
+    match algorithm {
+        SchedulingAlgorithm::FixedPriority => Ok(Box::new(FixedPriority)),
+        SchedulingAlgorithm::EarliestDeadlineFirst => Ok(Box::new(EarliestDeadlineFirst)),
+        SchedulingAlgorithm::EarliestDeadlineZeroLaxity => Ok(Box::new(EarliestDeadlineZeroLaxity)),
+        algorithm => Err(anyhow::format_err!("no simulated scheduler for {algorithm:?} scheduling")),
+    }
+}
+
+fn report_result<T>(result: anyhow::Result<T>) -> anyhow::Result<SchedOutcome> {
     use SchedError as Err;
 
-    print!("Running \"{}\":\n\t", analysis.analyzer_name());
+    let outcome = SchedOutcome::from_result(&result);
 
-    match analysis.is_schedulable(taskset) {
+    match result {
         Ok(_) => {
             println!("schedulable");
-            Ok(())
+            Ok(outcome)
         },
         Err(err) => {
             let Some(ref_err) = err.downcast_ref::<Err>()
@@ -25,9 +483,9 @@ pub fn run_analysis<A, T, Taskset>(analysis: A, taskset: Taskset) -> anyhow::Res
                 Err::Other(_) => Err(err),
                 _ => {
                     println!("{ref_err}");
-                    Ok(())
+                    Ok(outcome)
                 }
             }
         },
     }
-}
\ No newline at end of file
+}
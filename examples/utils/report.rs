@@ -0,0 +1,487 @@
+//! Self-contained reports of an analysis run, in Markdown or HTML, to attach to
+//! CI runs and design reviews.
+//!
+//! A report summarizes the taskset and the platform, lists the verdict of each
+//! test together with its running time and the tests which were excluded, and
+//! shows the margins of the taskset: the spare capacity of the platform and,
+//! when an exact response time analysis applies, the slack of each task. The
+//! Markdown report plots the tasks' utilizations with a Mermaid chart, which
+//! both GitHub and GitLab render, while the HTML report inlines an SVG chart.
+//!
+//! When the tasks declare WCET margins, the tests are also run on the WCETs
+//! inflated by their margins, and the report shows both verdicts.
+//!
+//! Each result carries the model [`Assumption`]s under which it holds, and the
+//! report lists them, with their stable IDs and the tests relying on each, so
+//! that the certification documents can be generated from the reports.
+//!
+//! Every report closes with its [`Provenance`]: the engine version, the ID and
+//! version of each test, the parameters of the run, the hash of the input file
+//! and the time of the run, so that archived reports stay interpretable and
+//! reproducible.
+
+use eva_rt_engine::prelude::*;
+use eva_rt_engine::algorithms::registry::{self, Exclusion, Profile};
+use eva_rt_engine::algorithms::full_preemption::uniprocessor::fixed_priority::rta86;
+use eva_rt_engine::algorithms::full_preemption::global_multiprocessor::fixed_priority::rta_lc09;
+use std::fmt::Write;
+
+#[derive(Debug, Clone, Copy)]
+#[derive(clap::ValueEnum)]
+pub enum ReportFormat {
+    Markdown,
+    Html,
+}
+
+impl ReportFormat {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ReportFormat::Markdown => "md",
+            ReportFormat::Html => "html",
+        }
+    }
+}
+
+/// Verdict of a single test.
+#[derive(Debug, Clone)]
+pub struct TestResult {
+    /// Stable ID of the test, refer to [`registry`](eva_rt_engine::algorithms::registry).
+    pub id: String,
+    pub analyzer: String,
+    pub outcome: SchedOutcome,
+    /// Reason of the verdict, if the test did not deem the taskset schedulable.
+    pub details: Option<String>,
+    pub runtime: std::time::Duration,
+    /// Memory usage of the test, if the allocations are counted.
+    pub memory: Option<MemoryUsage>,
+    pub exact: bool,
+    /// Model assumptions under which the verdict holds, none for unregistered
+    /// tests.
+    pub assumptions: Vec<Assumption>,
+}
+
+impl TestResult {
+    pub fn from_result<T>(id: &str, analyzer: &str, result: &anyhow::Result<T>, runtime: std::time::Duration, exact: bool) -> Self {
+        Self {
+            id: id.to_owned(),
+            analyzer: analyzer.to_owned(),
+            outcome: SchedOutcome::from_result(result),
+            details: result.as_ref().err().map(|err| match err.downcast_ref::<SchedError>() {
+                Some(sched_err) => sched_err.to_string(),
+                None => format!("{err:#}"),
+            }),
+            runtime,
+            memory: None,
+            exact,
+            assumptions: registry::test_assumptions(id).unwrap_or_default(),
+        }
+    }
+}
+
+/// Results of an analysis run.
+pub struct Report<'a> {
+    pub input_file: &'a str,
+    pub taskset: &'a [RTTask],
+    pub platform: &'a Platform,
+    pub profile: Profile,
+    pub results: Vec<TestResult>,
+    /// Results of the tests on the WCETs inflated by their margins, if the
+    /// tasks declare any.
+    pub results_with_margins: Option<Vec<TestResult>>,
+    pub excluded: Vec<Exclusion>,
+    /// Worst-case response times of the tasks, if known.
+    pub response_times: Option<Vec<Time>>,
+    pub provenance: Provenance,
+}
+
+/// Provenance of the results of a run.
+#[derive(Debug, Clone)]
+pub struct Provenance {
+    pub engine_version: &'static str,
+    /// FNV-1a hash of the content of the input file.
+    pub input_hash: u64,
+    /// Seconds since the UNIX epoch.
+    pub timestamp: u64,
+    /// Parameters of the run besides the platform and the profile, as name and
+    /// value.
+    pub parameters: Vec<(String, String)>,
+}
+
+impl Provenance {
+    pub fn new(input: &[u8], parameters: Vec<(String, String)>) -> Self {
+        Self {
+            engine_version: env!("CARGO_PKG_VERSION"),
+            input_hash: fnv1a(input),
+            timestamp: std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH)
+                .map_or(0, |elapsed| elapsed.as_secs()),
+            parameters,
+        }
+    }
+
+    pub fn of_file(input_file: &str, parameters: Vec<(String, String)>) -> anyhow::Result<Self> {
+        Ok(Self::new(&std::fs::read(input_file)?, parameters))
+    }
+}
+
+/// 64-bit FNV-1a hash, whose value is stable across platforms and releases.
+pub fn fnv1a(data: &[u8]) -> u64 {
+    data.iter().fold(0xcbf29ce484222325, |hash, &byte| (hash ^ byte as u64).wrapping_mul(0x100000001b3))
+}
+
+/// ISO 8601 UTC time of the given seconds since the UNIX epoch.
+pub fn utc_time(seconds: u64) -> String {
+    // Civil date of the days since the epoch, counting from March 1st of year
+    // 0, so that leap days are the last of each year.
+    let days = (seconds / 86_400) as i64 + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era = (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month + 2) / 5 + 1;
+    let month = if month < 10 { month + 3 } else { month - 9 };
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+
+    let time = seconds % 86_400;
+    format!("{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}Z", time / 3_600, time / 60 % 60, time % 60)
+}
+
+impl Report<'_> {
+    /// Overall verdict of the tests, refer to [`verdict`].
+    pub fn verdict(&self) -> SchedOutcome {
+        verdict(&self.results)
+    }
+
+    /// Spare capacity of the platform, in processors.
+    pub fn utilization_margin(&self) -> f64 {
+        self.platform.cpus as f64 - RTUtils::total_utilization(self.taskset)
+    }
+
+    pub fn render(&self, format: ReportFormat) -> String {
+        match format {
+            ReportFormat::Markdown => self.markdown(),
+            ReportFormat::Html => self.html(),
+        }
+    }
+
+    fn markdown(&self) -> String {
+        let mut out = String::new();
+
+        let _ = writeln!(out, "# Schedulability report: `{}`\n", self.input_file);
+        let _ = writeln!(out, "**Verdict: {}**{}\n", verdict_name(self.verdict()), self.margin_verdict());
+        let _ = writeln!(out, "| Platform | Algorithm | CPUs | Profile | Tasks | Utilization | Utilization margin |");
+        let _ = writeln!(out, "|---|---|---|---|---|---|---|");
+        let _ = writeln!(out, "| {:?} | {:?} | {} | {:?} | {} | {:.4} | {:.4} |\n",
+            self.platform.model, self.platform.algorithm, self.platform.cpus, self.profile,
+            self.taskset.len(), RTUtils::total_utilization(self.taskset), self.utilization_margin());
+
+        let _ = writeln!(out, "## Tasks\n");
+        let _ = writeln!(out, "| Task | WCET (ms) | Deadline (ms) | Period (ms) | Utilization | Response time (ms) | Slack (ms) |");
+        let _ = writeln!(out, "|---|---|---|---|---|---|---|");
+        for (i, task) in self.taskset.iter().enumerate() {
+            let (response, slack) = self.task_margin(i);
+            let _ = writeln!(out, "| {i} | {} | {} | {} | {:.4} | {response} | {slack} |",
+                task.wcet.as_millis(), task.deadline.as_millis(), task.period.as_millis(), task.utilization());
+        }
+
+        let _ = writeln!(out, "\n```mermaid\nxychart-beta\n    title \"Utilization per task\"");
+        let _ = writeln!(out, "    x-axis [{}]", (0..self.taskset.len()).map(|i| i.to_string()).collect::<Vec<_>>().join(", "));
+        let _ = writeln!(out, "    y-axis \"Utilization\" 0 --> 1");
+        let _ = writeln!(out, "    bar [{}]\n```\n",
+            self.taskset.iter().map(|task| format!("{:.4}", task.utilization())).collect::<Vec<_>>().join(", "));
+
+        let _ = writeln!(out, "## Tests\n");
+        markdown_tests(&mut out, &self.results);
+
+        if let Some(results) = &self.results_with_margins {
+            let _ = writeln!(out, "\n## Tests with WCET margins\n");
+            markdown_tests(&mut out, results);
+        }
+
+        let assumptions = self.assumptions();
+        if !assumptions.is_empty() {
+            let _ = writeln!(out, "\n## Assumptions\n");
+            let _ = writeln!(out, "| ID | Assumption | Tests |");
+            let _ = writeln!(out, "|---|---|---|");
+            for (assumption, ids) in assumptions {
+                let _ = writeln!(out, "| `{}` | {} | {} |", assumption.id(), assumption.description(),
+                    ids.iter().map(|id| format!("`{id}`")).collect::<Vec<_>>().join(", "));
+            }
+        }
+
+        if !self.excluded.is_empty() {
+            let _ = writeln!(out, "\n<details><summary>Excluded tests ({})</summary>\n", self.excluded.len());
+            for exclusion in &self.excluded {
+                let _ = writeln!(out, "- **{}** (`{}`): {}", exclusion.analyzer, exclusion.id, exclusion.reason);
+            }
+            let _ = writeln!(out, "\n</details>");
+        }
+
+        let _ = writeln!(out, "\n## Provenance\n");
+        let _ = writeln!(out, "| | |");
+        let _ = writeln!(out, "|---|---|");
+        for (name, value) in self.provenance_rows() {
+            let _ = writeln!(out, "| {name} | {} |", value.replace('|', "\\|"));
+        }
+
+        out
+    }
+
+    fn html(&self) -> String {
+        let mut out = String::new();
+
+        let _ = writeln!(out, "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">");
+        let _ = writeln!(out, "<title>Schedulability report: {}</title>", escape(self.input_file));
+        let _ = writeln!(out, "<style>body {{ font-family: sans-serif; }} table {{ border-collapse: collapse; }} \
+            td, th {{ border: 1px solid #999; padding: 2px 8px; }}</style>\n</head>\n<body>");
+        let _ = writeln!(out, "<h1>Schedulability report: <code>{}</code></h1>", escape(self.input_file));
+        let _ = writeln!(out, "<p><b>Verdict: {}</b>{}</p>", verdict_name(self.verdict()), self.margin_verdict());
+        let _ = writeln!(out, "<table>\n<tr><th>Platform</th><th>Algorithm</th><th>CPUs</th><th>Profile</th><th>Tasks</th>\
+            <th>Utilization</th><th>Utilization margin</th></tr>");
+        let _ = writeln!(out, "<tr><td>{:?}</td><td>{:?}</td><td>{}</td><td>{:?}</td><td>{}</td><td>{:.4}</td><td>{:.4}</td></tr>\n</table>",
+            self.platform.model, self.platform.algorithm, self.platform.cpus, self.profile,
+            self.taskset.len(), RTUtils::total_utilization(self.taskset), self.utilization_margin());
+
+        let _ = writeln!(out, "<h2>Tasks</h2>\n<table>\n<tr><th>Task</th><th>WCET (ms)</th><th>Deadline (ms)</th><th>Period (ms)</th>\
+            <th>Utilization</th><th>Response time (ms)</th><th>Slack (ms)</th></tr>");
+        for (i, task) in self.taskset.iter().enumerate() {
+            let (response, slack) = self.task_margin(i);
+            let _ = writeln!(out, "<tr><td>{i}</td><td>{}</td><td>{}</td><td>{}</td><td>{:.4}</td><td>{response}</td><td>{slack}</td></tr>",
+                task.wcet.as_millis(), task.deadline.as_millis(), task.period.as_millis(), task.utilization());
+        }
+        let _ = writeln!(out, "</table>");
+        out.push_str(&self.svg_chart());
+
+        let _ = writeln!(out, "<h2>Tests</h2>");
+        html_tests(&mut out, &self.results);
+
+        if let Some(results) = &self.results_with_margins {
+            let _ = writeln!(out, "<h2>Tests with WCET margins</h2>");
+            html_tests(&mut out, results);
+        }
+
+        let assumptions = self.assumptions();
+        if !assumptions.is_empty() {
+            let _ = writeln!(out, "<h2>Assumptions</h2>\n<table>\n<tr><th>ID</th><th>Assumption</th><th>Tests</th></tr>");
+            for (assumption, ids) in assumptions {
+                let _ = writeln!(out, "<tr><td><code>{}</code></td><td>{}</td><td>{}</td></tr>", assumption.id(),
+                    escape(assumption.description()),
+                    ids.iter().map(|id| format!("<code>{}</code>", escape(id))).collect::<Vec<_>>().join(", "));
+            }
+            let _ = writeln!(out, "</table>");
+        }
+
+        if !self.excluded.is_empty() {
+            let _ = writeln!(out, "<details><summary>Excluded tests ({})</summary>\n<ul>", self.excluded.len());
+            for exclusion in &self.excluded {
+                let _ = writeln!(out, "<li><b>{}</b> (<code>{}</code>): {}</li>", escape(&exclusion.analyzer), exclusion.id, escape(&exclusion.reason));
+            }
+            let _ = writeln!(out, "</ul>\n</details>");
+        }
+
+        let _ = writeln!(out, "<h2>Provenance</h2>\n<table>");
+        for (name, value) in self.provenance_rows() {
+            let _ = writeln!(out, "<tr><th>{}</th><td>{}</td></tr>", escape(&name), escape(&value));
+        }
+        let _ = writeln!(out, "</table>");
+
+        let _ = writeln!(out, "</body>\n</html>");
+        out
+    }
+
+    // Bars of the tasks' utilizations, with the response time to deadline
+    // ratio overlaid when known.
+    fn svg_chart(&self) -> String {
+        const BAR_HEIGHT: usize = 20;
+        const WIDTH: f64 = 400.0;
+
+        let mut out = String::new();
+        let height = BAR_HEIGHT * self.taskset.len();
+
+        let _ = writeln!(out, "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{height}\">", WIDTH + 60.0);
+        for (i, task) in self.taskset.iter().enumerate() {
+            let y = i * BAR_HEIGHT;
+            let _ = writeln!(out, "<text x=\"0\" y=\"{}\" font-size=\"12\">task {i}</text>", y + 14);
+            let _ = writeln!(out, "<rect x=\"60\" y=\"{}\" width=\"{:.1}\" height=\"8\" fill=\"#4878d0\"><title>utilization {:.4}</title></rect>",
+                y + 2, WIDTH * task.utilization().min(1.0), task.utilization());
+
+            if let Some(response) = self.response_times.as_ref().and_then(|rts| rts.get(i)) {
+                let ratio = *response / task.deadline;
+                let _ = writeln!(out, "<rect x=\"60\" y=\"{}\" width=\"{:.1}\" height=\"8\" fill=\"#ee854a\"><title>response time / deadline {:.4}</title></rect>",
+                    y + 10, WIDTH * ratio.min(1.0), ratio);
+            }
+        }
+        let _ = writeln!(out, "</svg>");
+
+        out
+    }
+
+    /// Assumptions of the results, with the IDs of the tests relying on each,
+    /// in the order of [`Assumption::ALL`].
+    pub fn assumptions(&self) -> Vec<(Assumption, Vec<&str>)> {
+        let results: Vec<_> = self.results.iter()
+            .chain(self.results_with_margins.iter().flatten())
+            .collect();
+
+        Assumption::ALL.iter()
+            .map(|&assumption| {
+                let mut ids: Vec<&str> = Vec::new();
+                for result in results.iter().filter(|result| result.assumptions.contains(&assumption)) {
+                    if !ids.contains(&result.id.as_str()) {
+                        ids.push(&result.id);
+                    }
+                }
+
+                (assumption, ids)
+            })
+            .filter(|(_, ids)| !ids.is_empty())
+            .collect()
+    }
+
+    // Verdict with the WCET margins, if any, after the overall one.
+    fn margin_verdict(&self) -> String {
+        match &self.results_with_margins {
+            Some(results) => format!(" (with WCET margins: {})", verdict_name(verdict(results))),
+            None => String::new(),
+        }
+    }
+
+    // Provenance of the report, as name and value.
+    fn provenance_rows(&self) -> Vec<(String, String)> {
+        let provenance = &self.provenance;
+
+        let mut rows = vec![
+            ("Engine".to_owned(), format!("eva-rt-engine {}", provenance.engine_version)),
+            ("Input".to_owned(), format!("{} (FNV-1a {:016x})", self.input_file, provenance.input_hash)),
+            ("Generated".to_owned(), utc_time(provenance.timestamp)),
+            ("Platform".to_owned(), format!("{:?}", self.platform)),
+            ("Profile".to_owned(), format!("{:?}", self.profile)),
+        ];
+
+        rows.extend(provenance.parameters.iter().cloned());
+        rows.extend(self.results.iter().map(|result| (
+            format!("Test {}", result.id),
+            registry::test_version(&result.id).map_or_else(|| "unregistered".to_owned(), |version| format!("version {version}")),
+        )));
+
+        rows
+    }
+
+    fn task_margin(&self, i: usize) -> (String, String) {
+        match self.response_times.as_ref().and_then(|rts| rts.get(i)) {
+            Some(response) => (
+                format!("{}", response.as_millis()),
+                format!("{}", (self.taskset[i].deadline - *response).as_millis()),
+            ),
+            None => ("-".to_owned(), "-".to_owned()),
+        }
+    }
+}
+
+/// Overall verdict of the tests: schedulable if any test deems the taskset
+/// schedulable, non-schedulable only if an exact test says so.
+pub fn verdict(results: &[TestResult]) -> SchedOutcome {
+    if results.iter().any(|result| result.outcome == SchedOutcome::Schedulable) {
+        SchedOutcome::Schedulable
+    } else if results.iter().any(|result| result.exact && result.outcome == SchedOutcome::NonSchedulable) {
+        SchedOutcome::NonSchedulable
+    } else {
+        SchedOutcome::Inconclusive
+    }
+}
+
+fn markdown_tests(out: &mut String, results: &[TestResult]) {
+    let _ = writeln!(out, "| Test | ID | Exact | Verdict | Time | Peak memory | Details |");
+    let _ = writeln!(out, "|---|---|---|---|---|---|---|");
+    for result in results {
+        let _ = writeln!(out, "| {} | `{}` | {} | {} | {:?} | {} | {} |",
+            result.analyzer, result.id, if result.exact { "yes" } else { "no" }, verdict_name(result.outcome),
+            result.runtime, memory_name(result.memory), result.details.as_deref().unwrap_or("").replace('|', "\\|"));
+    }
+}
+
+fn html_tests(out: &mut String, results: &[TestResult]) {
+    let _ = writeln!(out, "<table>\n<tr><th>Test</th><th>ID</th><th>Exact</th><th>Verdict</th><th>Time</th><th>Peak memory</th><th>Details</th></tr>");
+    for result in results {
+        let _ = writeln!(out, "<tr><td>{}</td><td><code>{}</code></td><td>{}</td><td>{}</td><td>{:?}</td><td>{}</td><td>{}</td></tr>",
+            escape(&result.analyzer), escape(&result.id), if result.exact { "yes" } else { "no" }, verdict_name(result.outcome),
+            result.runtime, memory_name(result.memory), escape(result.details.as_deref().unwrap_or("")));
+    }
+    let _ = writeln!(out, "</table>");
+}
+
+/// Peak memory and allocations of a test, `-` if not counted.
+pub fn memory_name(memory: Option<MemoryUsage>) -> String {
+    match memory {
+        Some(memory) => format!("{:.1} KiB ({} allocations)", memory.peak_bytes as f64 / 1024.0, memory.allocations),
+        None => "-".to_owned(),
+    }
+}
+
+/// Response times of the tasks, when a response time analysis applies.
+pub fn response_times(taskset: &[RTTask], platform: &Platform, latencies: &KernelLatencies) -> Option<Vec<Time>> {
+    response_time_analysis(platform, latencies)?.is_schedulable(taskset).ok()
+}
+
+/// Analysis giving the response times of the tasks.
+pub type ResponseTimeAnalysis = Box<dyn for<'a> SchedAnalysis<Vec<Time>, &'a [RTTask]>>;
+
+/// Exact response time analysis of the platform, if any. The interrupt
+/// service routines of the platform interfere with the tasks, but their
+/// response times are not returned. The response times are those of the tasks
+/// in their given order, under the priority order of the platform.
+pub fn response_time_analysis(platform: &Platform, latencies: &KernelLatencies) -> Option<ResponseTimeAnalysis> {
+    use SchedulingAlgorithm as Alg;
+
+    let analysis: ResponseTimeAnalysis =
+        if platform.matches(PlatformModel::Uniprocessor, Alg::FixedPriority, 1) {
+            Box::new(rta86::AnalysisKernelLatencies { latencies: latencies.clone() })
+        } else if platform.matches(PlatformModel::GlobalMultiprocessor, Alg::FixedPriority, platform.cpus)
+            && latencies.release_jitter() == Time::zero()
+        {
+            Box::new(rta_lc09::AnalysisWarmStart { num_processors: platform.cpus, initial_response_times: Vec::new() })
+        } else {
+            return None;
+        };
+
+    let analysis: ResponseTimeAnalysis =
+        if platform.isrs.is_empty() {
+            analysis
+        } else {
+            Box::new(TaskResponseTimes(WithInterruptServiceRoutines { isrs: platform.isrs.clone(), analysis }))
+        };
+
+    if platform.priority_order == PriorityOrder::Given {
+        Some(analysis)
+    } else {
+        Some(Box::new(Prioritized { order: platform.priority_order.clone(), analysis }))
+    }
+}
+
+// Drop the response times of the interrupt service routines.
+struct TaskResponseTimes(WithInterruptServiceRoutines<ResponseTimeAnalysis>);
+
+impl SchedAnalysis<Vec<Time>, &[RTTask]> for TaskResponseTimes {
+    fn analyzer_name(&self) -> &str { self.0.analyzer_name() }
+
+    fn check_preconditions(&self, taskset: &&[RTTask]) -> Result<(), SchedError> {
+        self.0.check_preconditions(taskset)
+    }
+
+    fn run_test(&self, taskset: &[RTTask]) -> Result<Vec<Time>, SchedError> {
+        self.0.run_test(taskset).map(|response_times| response_times[self.0.isrs.len()..].to_vec())
+    }
+}
+
+pub fn verdict_name(outcome: SchedOutcome) -> &'static str {
+    match outcome {
+        SchedOutcome::Schedulable => "schedulable",
+        SchedOutcome::NonSchedulable => "non schedulable",
+        SchedOutcome::Inconclusive => "inconclusive",
+        SchedOutcome::Error => "error",
+    }
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+}
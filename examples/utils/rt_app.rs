@@ -0,0 +1,313 @@
+//! Taskset import from [rt-app](https://github.com/scheduler-tools/rt-app)
+//! workload descriptions.
+//!
+//! Each thread (times its number of instances) is mapped to a sporadic task:
+//! - the WCET is the sum of the `run`/`runtime` events of a phase;
+//! - the period is the period of the phase's `timer` event, or the
+//!   `dl-period` of SCHED_DEADLINE threads;
+//! - the deadline is the `dl-deadline` of SCHED_DEADLINE threads, or the
+//!   period otherwise.
+//!
+//! Threads with multiple phases are mapped to a single task which dominates all
+//! of its phases (largest WCET, shortest period and deadline). Tasks are sorted
+//! by decreasing `priority`, and by thread name for equal priorities. Files
+//! whose threads amount to more than [`MAX_RT_APP_TASKS`] tasks are rejected.
+//!
+//! Tasksets can also be exported as rt-app workloads, running each task either
+//! as a SCHED_FIFO thread (with priority given by the taskset's order) or as a
+//! SCHED_DEADLINE thread whose reservation matches the task's parameters.
+//! Reservations designed for a taskset (the server tasks of an MPR model) are
+//! exported next to the tasks as SCHED_DEADLINE threads, which reserve their
+//! bandwidth on the machine.
+//!
+//! All the times in rt-app files are expressed in microseconds.
+
+use eva_rt_engine::prelude::*;
+use eva_rt_engine::algorithms::full_preemption::global_multiprocessor::hierarchical::mpr_model09::MPRModel;
+use serde_json::{Map, Value, json};
+
+/// Maximum number of tasks of an rt-app file, counting the instances of each
+/// thread.
+pub const MAX_RT_APP_TASKS: u64 = 1 << 16;
+
+#[derive(Debug, Clone, Copy)]
+#[derive(PartialEq, Eq)]
+#[derive(clap::ValueEnum)]
+pub enum RtAppPolicy {
+    Fifo,
+    Deadline,
+}
+
+#[allow(dead_code)]
+pub fn deserialize_rt_app_taskset(data: &str) -> anyhow::Result<Vec<RTTask>> {
+    deserialize_rt_app_reader(data.as_bytes())
+}
+
+/// Parse an rt-app workload description from the given reader. Threads are
+/// deserialized one at a time, without building the whole JSON document in
+/// memory.
+pub fn deserialize_rt_app_reader<R: std::io::Read>(reader: R) -> anyhow::Result<Vec<RTTask>> {
+    let mut deserializer = serde_json::Deserializer::from_reader(reader);
+    let RtAppTasks(tasks) = serde::Deserialize::deserialize(&mut deserializer)?;
+    deserializer.end()?;
+
+    let mut tasks = tasks
+        .ok_or_else(|| anyhow::format_err!("rt-app file must contain a 'tasks' object"))?;
+
+    tasks.sort_by(|(l_priority, l_name, _), (r_priority, r_name, _)| {
+        r_priority.cmp(l_priority).then_with(|| l_name.cmp(r_name))
+    });
+
+    Ok(tasks.into_iter().map(|(_, _, task)| task).collect())
+}
+
+// Threads of the 'tasks' object, with their priority and name.
+struct RtAppTasks(Option<Vec<(i64, String, RTTask)>>);
+
+impl<'de> serde::Deserialize<'de> for RtAppTasks {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct FileVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for FileVisitor {
+            type Value = RtAppTasks;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                write!(f, "an rt-app workload description")
+            }
+
+            fn visit_map<A: serde::de::MapAccess<'de>>(self, mut map: A) -> Result<RtAppTasks, A::Error> {
+                let mut tasks = None;
+
+                while let Some(key) = map.next_key::<String>()? {
+                    if key == "tasks" {
+                        tasks = Some(map.next_value::<RtAppThreads>()?.0);
+                    } else {
+                        map.next_value::<serde::de::IgnoredAny>()?;
+                    }
+                }
+
+                Ok(RtAppTasks(tasks))
+            }
+        }
+
+        deserializer.deserialize_map(FileVisitor)
+    }
+}
+
+struct RtAppThreads(Vec<(i64, String, RTTask)>);
+
+impl<'de> serde::Deserialize<'de> for RtAppThreads {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct ThreadsVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for ThreadsVisitor {
+            type Value = RtAppThreads;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                write!(f, "a 'tasks' object")
+            }
+
+            fn visit_map<A: serde::de::MapAccess<'de>>(self, mut map: A) -> Result<RtAppThreads, A::Error> {
+                use serde::de::Error as _;
+
+                let mut tasks = Vec::new();
+
+                while let Some(name) = map.next_key::<String>()? {
+                    let thread: Map<String, Value> = map.next_value()
+                        .map_err(|err| A::Error::custom(format!("rt-app thread '{name}' must be an object: {err}")))?;
+
+                    let task = deserialize_thread(&thread)
+                        .map_err(|err| A::Error::custom(format!("Failed to parse rt-app thread '{name}': {err}")))?;
+
+                    let instances = match thread.get("instance") {
+                        None => 1,
+                        Some(instances) => instances.as_u64()
+                            .ok_or_else(|| A::Error::custom(format!(
+                                "rt-app thread '{name}': 'instance' must be a non-negative integer")))?,
+                    };
+
+                    if instances > MAX_RT_APP_TASKS - tasks.len() as u64 {
+                        return Err(A::Error::custom(format!(
+                            "rt-app thread '{name}': the file has more than {MAX_RT_APP_TASKS} tasks")));
+                    }
+
+                    let priority = thread.get("priority").and_then(Value::as_i64).unwrap_or(0);
+
+                    for _ in 0..instances {
+                        tasks.push((priority, name.clone(), task.clone()));
+                    }
+                }
+
+                Ok(RtAppThreads(tasks))
+            }
+        }
+
+        deserializer.deserialize_map(ThreadsVisitor)
+    }
+}
+
+fn deserialize_thread(thread: &Map<String, Value>) -> anyhow::Result<RTTask> {
+    let phases: Vec<&Map<String, Value>> =
+        match thread.get("phases").and_then(Value::as_object) {
+            Some(phases) => phases.values().filter_map(Value::as_object).collect(),
+            None => vec![thread],
+        };
+
+    if phases.is_empty() {
+        return Err(anyhow::format_err!("thread has no phases"));
+    }
+
+    let dl_period = micros(thread, "dl-period")?;
+    let dl_deadline = micros(thread, "dl-deadline")?;
+
+    let mut task: Option<RTTask> = None;
+
+    for phase in phases {
+        let wcet = phase.iter()
+            .filter(|(event, _)| is_event(event, "run") || is_event(event, "runtime"))
+            .map(|(event, value)| value_to_time(event, value))
+            .sum::<anyhow::Result<Time>>()?;
+
+        let timer_period = phase.iter()
+            .find(|(event, _)| is_event(event, "timer"))
+            .map(|(event, timer)| {
+                timer.as_object()
+                    .ok_or_else(|| anyhow::format_err!("'{event}' must be an object"))
+                    .and_then(|timer| micros(timer, "period"))
+            })
+            .transpose()?
+            .flatten();
+
+        let period = timer_period.or(dl_period)
+            .ok_or_else(|| anyhow::format_err!("phase has neither a timer nor a 'dl-period'"))?;
+        let deadline = dl_deadline.unwrap_or(period);
+
+        task = Some(match task {
+            None => RTTask { wcet, deadline, period },
+            Some(task) => RTTask {
+                wcet: Time::max(task.wcet, wcet),
+                deadline: Time::min(task.deadline, deadline),
+                period: Time::min(task.period, period),
+            },
+        });
+    }
+
+    Ok(task.unwrap())
+}
+
+// rt-app allows multiple events of the same kind in a phase by appending a
+// numeric suffix to the event name (e.g. run0, run1).
+fn is_event(key: &str, event: &str) -> bool {
+    key.strip_prefix(event)
+        .is_some_and(|suffix| suffix.chars().all(|ch| ch.is_ascii_digit()))
+}
+
+fn micros(object: &Map<String, Value>, key: &str) -> anyhow::Result<Option<Time>> {
+    object.get(key)
+        .map(|value| value_to_time(key, value))
+        .transpose()
+}
+
+fn value_to_time(key: &str, value: &Value) -> anyhow::Result<Time> {
+    let micros = value.as_f64()
+        .ok_or_else(|| anyhow::format_err!("Failed to parse field '{key}': expected a number"))?;
+
+    Ok(Time::nanos(micros * Time::MICRO_TO_NANO))
+}
+
+/// Serialize the taskset as an rt-app workload running for the given duration.
+/// Runtimes are rounded up and periods/deadlines are rounded down to the
+/// microsecond.
+#[allow(dead_code)]
+pub fn serialize_rt_app_taskset(
+    taskset: &[RTTask],
+    policy: RtAppPolicy,
+    duration_secs: u64,
+) -> anyhow::Result<String> {
+    serialize_rt_app_workload(taskset, policy, None, duration_secs)
+}
+
+/// Serialize the taskset as in [`serialize_rt_app_taskset`], together with the
+/// server tasks of the given MPR model as SCHED_DEADLINE reservations.
+#[allow(dead_code)]
+pub fn serialize_rt_app_reservations(
+    taskset: &[RTTask],
+    policy: RtAppPolicy,
+    model: &MPRModel,
+    duration_secs: u64,
+) -> anyhow::Result<String> {
+    serialize_rt_app_workload(taskset, policy, Some(model), duration_secs)
+}
+
+fn serialize_rt_app_workload(
+    taskset: &[RTTask],
+    policy: RtAppPolicy,
+    model: Option<&MPRModel>,
+    duration_secs: u64,
+) -> anyhow::Result<String> {
+    const MAX_FIFO_PRIORITY: usize = 99;
+
+    if policy == RtAppPolicy::Fifo && taskset.len() > MAX_FIFO_PRIORITY {
+        return Err(anyhow::format_err!("rt-app SCHED_FIFO export supports at most {MAX_FIFO_PRIORITY} tasks"));
+    }
+
+    let tasks = taskset.iter().enumerate()
+        .map(|(i, task)| {
+            let name = format!("task{i}");
+            let thread = serialize_thread(&name, task, policy, MAX_FIFO_PRIORITY - i);
+
+            (name, thread)
+        });
+
+    let servers = model.map(MPRModel::to_periodic_tasks).unwrap_or_default();
+    let servers = servers.iter().enumerate()
+        .map(|(i, server)| {
+            let name = format!("server{i}");
+            let thread = serialize_thread(&name, server, RtAppPolicy::Deadline, 0);
+
+            (name, thread)
+        });
+
+    let threads: Map<String, Value> = tasks.chain(servers).collect();
+
+    let workload = json!({
+        "tasks": threads,
+        "global": {
+            "duration": duration_secs,
+            "default_policy": "SCHED_OTHER",
+            "calibration": "CPU0",
+        },
+    });
+
+    Ok(serde_json::to_string_pretty(&workload)?)
+}
+
+fn serialize_thread(name: &str, task: &RTTask, policy: RtAppPolicy, priority: usize) -> Value {
+    let runtime = (task.wcet.as_nanos() / Time::MICRO_TO_NANO).ceil() as u64;
+    let deadline = (task.deadline.as_nanos() / Time::MICRO_TO_NANO).floor() as u64;
+    let period = (task.period.as_nanos() / Time::MICRO_TO_NANO).floor() as u64;
+
+    let thread_policy = match policy {
+        RtAppPolicy::Fifo => json!({
+            "policy": "SCHED_FIFO",
+            "priority": priority,
+        }),
+        RtAppPolicy::Deadline => json!({
+            "policy": "SCHED_DEADLINE",
+            "dl-runtime": runtime,
+            "dl-deadline": deadline,
+            "dl-period": period,
+        }),
+    };
+
+    let mut thread = json!({
+        "loop": -1,
+        "run": runtime,
+        "timer": { "ref": name, "period": period },
+    });
+
+    thread.as_object_mut().unwrap()
+        .extend(thread_policy.as_object().unwrap().clone());
+
+    thread
+}
@@ -0,0 +1,827 @@
+//! Versioned JSON formats of the tools: tasksets and configurations.
+//!
+//! Every file declares the `version` of its schema. Files written for an older
+//! version are migrated to the current one when read, while files written for
+//! a newer version are parsed as far as possible, ignoring the fields this
+//! version does not know. Unknown fields in files of a known version, and
+//! fields which have been renamed, are reported all at once.
+//!
+//! Taskset files (`*.taskset.json`):
+//! ```json
+//! { "version": 3, "unit": "millis", "tasks": [ { "wcet": 1, "deadline": 10, "period": 10, "wcet_margin": 0.2, "release_jitter": 1 } ] }
+//! ```
+//! The optional `wcet_margin` of a task is the relative margin of a WCET
+//! derived from measurements, e.g. 0.2 for the measured maximum plus 20%
+//! (refer to [`WcetMargins`]). The optional `release_jitter` of a task is the
+//! maximum delay between the arrival and the release of its jobs (refer to
+//! [`JitteredTask`]).
+//!
+//! Plain tasksets are the unversioned predecessors of this format, and can be
+//! migrated with [`migrate_plain_taskset`].
+//!
+//! Configuration files:
+//! ```json
+//! { "version": 4, "platform": { "model": "GlobalMultiprocessor", "algorithm": "EarliestDeadlineFirst", "cpus": 4 },
+//!   "irq_latency_us": 10, "sched_latency_us": 20, "profile": "quick", "tests": ["smp.edf"],
+//!   "context_switch_us": 2, "interrupt_period_us": 1000, "interrupt_overhead_us": 5 }
+//! ```
+//! The optional scheduling overheads are accounted by inflating the WCETs
+//! (refer to [`SchedulingOverheads`]).
+//!
+//! Distributed system files (`*.system.json`), whose flows refer to the tasks
+//! and messages by index:
+//! ```json
+//! { "version": 1, "unit": "millis",
+//!   "nodes": [ { "algorithm": "FixedPriority", "tasks": [ { "wcet": 1, "deadline": 10, "period": 10 } ] } ],
+//!   "networks": [ { "messages": [ { "wcet": 0.5, "deadline": 10, "period": 10 } ] } ],
+//!   "flows": [ { "deadline": 10, "steps": [ { "node": 0, "task": 0 }, { "network": 0, "message": 0 } ] } ] }
+//! ```
+//!
+//! Dispatch table files (`*.table.json`) of a cyclic executive, whose
+//! dispatches and precedence constraints refer to the tasks by index:
+//! ```json
+//! { "version": 1, "unit": "millis", "major_frame": 20,
+//!   "precedences": [ { "predecessor": 0, "successor": 1 } ],
+//!   "dispatches": [ { "task": 0, "job": 0, "start": 0, "duration": 1 } ] }
+//! ```
+//! The optional `major_frame` defaults to the hyperperiod of the taskset.
+//!
+//! The JSON Schemas of the formats are published in the `schemas` directory
+//! (refer to [`json_schema`]), and in strict mode the files are validated
+//! against them before being read (refer to [`validate`]).
+
+use eva_rt_engine::prelude::*;
+use eva_rt_engine::algorithms::registry::Profile;
+use serde_json::{Map, Value, json};
+use eva_rt_engine::algorithms::full_preemption::distributed::*;
+use eva_rt_engine::algorithms::cyclic_executive::Dispatch;
+use eva_rt_engine::algorithms::cyclic_executive::checker::Precedence;
+use eva_rt_engine::algorithms::full_preemption::uniprocessor::fixed_priority::rta86::JitteredTask;
+use super::TasksetPlainUnit;
+use super::dispatch_table::DispatchTable;
+
+/// Migration of an object from the given version to the next one.
+pub type Migration = fn(u64, Map<String, Value>) -> anyhow::Result<Map<String, Value>>;
+
+/// Description of a versioned JSON object.
+pub struct Schema {
+    pub name: &'static str,
+    pub version: u64,
+    /// Fields of the current version, besides `version`.
+    pub fields: &'static [&'static str],
+    /// Fields renamed by a version: (version, old name, new name).
+    pub renamed: &'static [(u64, &'static str, &'static str)],
+    pub migrate: Migration,
+}
+
+pub const TASKSET_SCHEMA: Schema = Schema {
+    name: "taskset",
+    version: 3,
+    fields: &["unit", "tasks"],
+    renamed: &[],
+    migrate: migrate_taskset,
+};
+
+/// Tasks of taskset files.
+pub const TASKSET_TASK_SCHEMA: Schema = Schema {
+    name: "task",
+    version: 3,
+    fields: &["wcet", "deadline", "period", "wcet_margin", "release_jitter"],
+    renamed: &[],
+    migrate: migrate_taskset,
+};
+
+/// Tasks and messages of distributed system files.
+pub const TASK_SCHEMA: Schema = Schema {
+    name: "task",
+    version: 1,
+    fields: &["wcet", "deadline", "period"],
+    renamed: &[],
+    migrate: no_migration,
+};
+
+pub const CONFIG_SCHEMA: Schema = Schema {
+    name: "configuration",
+    version: 4,
+    fields: &[
+        "platform", "irq_latency_us", "sched_latency_us", "profile", "tests",
+        "context_switch_us", "interrupt_period_us", "interrupt_overhead_us",
+    ],
+    renamed: &[],
+    migrate: migrate_config,
+};
+
+pub const SYSTEM_SCHEMA: Schema = Schema {
+    name: "system",
+    version: 1,
+    fields: &["unit", "nodes", "networks", "flows"],
+    renamed: &[],
+    migrate: no_migration,
+};
+
+pub const NODE_SCHEMA: Schema = Schema {
+    name: "node",
+    version: 1,
+    fields: &["algorithm", "tasks"],
+    renamed: &[],
+    migrate: no_migration,
+};
+
+pub const NETWORK_SCHEMA: Schema = Schema {
+    name: "network",
+    version: 1,
+    fields: &["messages"],
+    renamed: &[],
+    migrate: no_migration,
+};
+
+pub const FLOW_SCHEMA: Schema = Schema {
+    name: "flow",
+    version: 1,
+    fields: &["deadline", "steps"],
+    renamed: &[],
+    migrate: no_migration,
+};
+
+pub const TABLE_SCHEMA: Schema = Schema {
+    name: "dispatch table",
+    version: 1,
+    fields: &["unit", "major_frame", "precedences", "dispatches"],
+    renamed: &[],
+    migrate: no_migration,
+};
+
+pub const DISPATCH_SCHEMA: Schema = Schema {
+    name: "dispatch",
+    version: 1,
+    fields: &["task", "job", "start", "duration"],
+    renamed: &[],
+    migrate: no_migration,
+};
+
+pub const PRECEDENCE_SCHEMA: Schema = Schema {
+    name: "precedence",
+    version: 1,
+    fields: &["predecessor", "successor"],
+    renamed: &[],
+    migrate: no_migration,
+};
+
+fn no_migration(version: u64, _: Map<String, Value>) -> anyhow::Result<Map<String, Value>> {
+    Err(anyhow::format_err!("no migration from version {version}"))
+}
+
+fn migrate_taskset(version: u64, object: Map<String, Value>) -> anyhow::Result<Map<String, Value>> {
+    match version {
+        // Version 2 adds the optional WCET margin of the tasks, and version 3
+        // their optional release jitter.
+        1..=2 => Ok(object),
+        _ => no_migration(version, object),
+    }
+}
+
+fn migrate_config(version: u64, object: Map<String, Value>) -> anyhow::Result<Map<String, Value>> {
+    match version {
+        // Version 2 adds the optional analysis profile, version 3 the
+        // optional selection of the tests, and version 4 the optional
+        // scheduling overheads.
+        1..=3 => Ok(object),
+        _ => no_migration(version, object),
+    }
+}
+
+impl Schema {
+    /// Migrate the object to the current version and check its fields. The
+    /// version of nested objects is the one of the file.
+    pub fn upgrade(&self, mut object: Map<String, Value>, version: u64) -> anyhow::Result<Map<String, Value>> {
+        for from in version..self.version {
+            object = (self.migrate)(from, object)?;
+        }
+
+        if version > self.version {
+            // Forward-compatible parsing: ignore the fields of newer versions.
+            object.retain(|key, _| self.fields.contains(&key.as_str()));
+            return Ok(object);
+        }
+
+        let mut errors: Vec<String> = Vec::new();
+        for key in object.keys().filter(|key| !self.fields.contains(&key.as_str())) {
+            match self.renamed.iter().find(|(_, old, _)| old == key) {
+                Some((since, _, new)) =>
+                    errors.push(format!("'{key}' was renamed to '{new}' in version {since}")),
+                None =>
+                    errors.push(format!("unknown field '{key}'")),
+            }
+        }
+
+        if !errors.is_empty() {
+            return Err(anyhow::format_err!("invalid {} (version {}): {}", self.name, self.version, errors.join(", ")));
+        }
+
+        Ok(object)
+    }
+}
+
+/// Split the `version` field off a file's top-level object.
+pub fn read_version(value: Value) -> anyhow::Result<(u64, Map<String, Value>)> {
+    let Value::Object(mut object) = value
+        else { return Err(anyhow::format_err!("the file must contain a JSON object")); };
+
+    let version = object.remove("version")
+        .ok_or_else(|| anyhow::format_err!("missing 'version' field"))?
+        .as_u64()
+        .ok_or_else(|| anyhow::format_err!("'version' must be a non-negative integer"))?;
+
+    Ok((version, object))
+}
+
+#[allow(dead_code)]
+pub fn deserialize_taskset<R: std::io::Read>(reader: R) -> anyhow::Result<Vec<RTTask>> {
+    Ok(deserialize_annotated_taskset(reader)?.0)
+}
+
+/// Deserialize a taskset together with the WCET margins of its tasks, zero if
+/// not given.
+pub fn deserialize_annotated_taskset<R: std::io::Read>(reader: R) -> anyhow::Result<(Vec<RTTask>, WcetMargins)> {
+    let (version, object) = read_version(serde_json::from_reader(reader)?)?;
+    let object = TASKSET_SCHEMA.upgrade(object, version)?;
+
+    let (tasks, margins) =
+        deserialize_tasks(object.get("tasks"), "tasks", &TASKSET_TASK_SCHEMA, version, unit_multiplier(&object)?)?;
+
+    Ok((tasks.into_iter().map(|task| task.task).collect(), margins))
+}
+
+/// Deserialize a taskset together with the release jitters of its tasks, zero
+/// if not given.
+pub fn deserialize_jittered_taskset<R: std::io::Read>(reader: R) -> anyhow::Result<Vec<JitteredTask>> {
+    let (version, object) = read_version(serde_json::from_reader(reader)?)?;
+    let object = TASKSET_SCHEMA.upgrade(object, version)?;
+
+    Ok(deserialize_tasks(object.get("tasks"), "tasks", &TASKSET_TASK_SCHEMA, version, unit_multiplier(&object)?)?.0)
+}
+
+fn unit_name(unit: TasksetPlainUnit) -> &'static str {
+    match unit {
+        TasksetPlainUnit::Millis => "millis",
+        TasksetPlainUnit::Micros => "micros",
+        TasksetPlainUnit::Nanos => "nanos",
+    }
+}
+
+/// Nanoseconds per unit of the file's times.
+fn unit_multiplier(object: &Map<String, Value>) -> anyhow::Result<f64> {
+    match object.get("unit").and_then(Value::as_str) {
+        Some("millis") => Ok(Time::MILLI_TO_NANO),
+        Some("micros") => Ok(Time::MICRO_TO_NANO),
+        Some("nanos") => Ok(1.0),
+        _ => Err(anyhow::format_err!("'unit' must be one of \"millis\", \"micros\" or \"nanos\"")),
+    }
+}
+
+fn deserialize_tasks(
+    tasks: Option<&Value>,
+    name: &str,
+    schema: &Schema,
+    version: u64,
+    multiplier: f64,
+) -> anyhow::Result<(Vec<JitteredTask>, WcetMargins)> {
+    let tasks = tasks.and_then(Value::as_array)
+        .ok_or_else(|| anyhow::format_err!("'{name}' must be an array"))?;
+
+    let (tasks, margins) = tasks.iter().enumerate()
+        .map(|(i, task)| {
+            let task = task.as_object().cloned()
+                .ok_or_else(|| anyhow::format_err!("task {i} must be an object"))?;
+            let task = schema.upgrade(task, version)
+                .map_err(|err| anyhow::format_err!("task {i}: {err}"))?;
+
+            let field = |name: &str| -> anyhow::Result<Time> {
+                task.get(name).and_then(Value::as_f64)
+                    .map(|value| Time::nanos(value * multiplier))
+                    .ok_or_else(|| anyhow::format_err!("task {i}: missing numeric field '{name}'"))
+            };
+
+            let margin = match task.get("wcet_margin") {
+                None => 0.0,
+                Some(value) => value.as_f64()
+                    .filter(|margin| *margin >= 0.0)
+                    .ok_or_else(|| anyhow::format_err!("task {i}: 'wcet_margin' must be a non-negative number"))?,
+            };
+
+            let release_jitter = match task.get("release_jitter") {
+                None => Time::zero(),
+                Some(value) => value.as_f64()
+                    .filter(|jitter| *jitter >= 0.0)
+                    .map(|jitter| Time::nanos(jitter * multiplier))
+                    .ok_or_else(|| anyhow::format_err!("task {i}: 'release_jitter' must be a non-negative number"))?,
+            };
+
+            let task = RTTask { wcet: field("wcet")?, deadline: field("deadline")?, period: field("period")? };
+            Ok((JitteredTask { task, release_jitter }, margin))
+        })
+        .collect::<anyhow::Result<(Vec<_>, Vec<_>)>>()?;
+
+    Ok((tasks, WcetMargins { margins }))
+}
+
+#[allow(dead_code)]
+pub fn parse_system<P: AsRef<std::path::Path>>(system_file: P) -> anyhow::Result<DistributedSystem> {
+    deserialize_system(std::io::BufReader::new(std::fs::File::open(system_file)?))
+}
+
+pub fn deserialize_system<R: std::io::Read>(reader: R) -> anyhow::Result<DistributedSystem> {
+    let (version, object) = read_version(serde_json::from_reader(reader)?)?;
+    let object = SYSTEM_SCHEMA.upgrade(object, version)?;
+    let multiplier = unit_multiplier(&object)?;
+
+    let array = |name: &str| -> anyhow::Result<Vec<Map<String, Value>>> {
+        object.get(name).and_then(Value::as_array)
+            .ok_or_else(|| anyhow::format_err!("'{name}' must be an array"))?
+            .iter().enumerate()
+            .map(|(i, item)| item.as_object().cloned()
+                .ok_or_else(|| anyhow::format_err!("{name} {i} must be an object")))
+            .collect()
+    };
+
+    let nodes = array("nodes")?.into_iter().enumerate()
+        .map(|(n, node)| {
+            let node = NODE_SCHEMA.upgrade(node, version)
+                .map_err(|err| anyhow::format_err!("node {n}: {err}"))?;
+
+            let algorithm = serde_json::from_value(
+                node.get("algorithm").cloned().ok_or_else(|| anyhow::format_err!("node {n}: missing 'algorithm' field"))?
+            ).map_err(|err| anyhow::format_err!("node {n}: 'algorithm': {err}"))?;
+
+            let (tasks, _) = deserialize_tasks(node.get("tasks"), "tasks", &TASK_SCHEMA, version, multiplier)
+                .map_err(|err| anyhow::format_err!("node {n}: {err}"))?;
+
+            Ok(Node { tasks: tasks.into_iter().map(|task| task.task).collect(), algorithm })
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    let networks = array("networks")?.into_iter().enumerate()
+        .map(|(k, network)| {
+            let network = NETWORK_SCHEMA.upgrade(network, version)
+                .map_err(|err| anyhow::format_err!("network {k}: {err}"))?;
+
+            let (messages, _) = deserialize_tasks(network.get("messages"), "messages", &TASK_SCHEMA, version, multiplier)
+                .map_err(|err| anyhow::format_err!("network {k}: {err}"))?;
+
+            Ok(Network { messages: messages.into_iter().map(|message| message.task).collect() })
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    let flows = array("flows")?.into_iter().enumerate()
+        .map(|(f, flow)| {
+            let flow = FLOW_SCHEMA.upgrade(flow, version)
+                .map_err(|err| anyhow::format_err!("flow {f}: {err}"))?;
+
+            let deadline = flow.get("deadline").and_then(Value::as_f64)
+                .map(|value| Time::nanos(value * multiplier))
+                .ok_or_else(|| anyhow::format_err!("flow {f}: missing numeric field 'deadline'"))?;
+
+            let steps = flow.get("steps").and_then(Value::as_array)
+                .ok_or_else(|| anyhow::format_err!("flow {f}: 'steps' must be an array"))?
+                .iter().enumerate()
+                .map(|(s, step)| deserialize_step(step)
+                    .ok_or_else(|| anyhow::format_err!("flow {f}: step {s} must be either {{ \"node\", \"task\" }} or {{ \"network\", \"message\" }}")))
+                .collect::<anyhow::Result<Vec<_>>>()?;
+
+            Ok(Flow { steps, deadline })
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    Ok(DistributedSystem { nodes, networks, flows })
+}
+
+#[allow(dead_code)]
+pub fn parse_dispatch_table<P: AsRef<std::path::Path>>(table_file: P) -> anyhow::Result<DispatchTable> {
+    deserialize_dispatch_table(std::io::BufReader::new(std::fs::File::open(table_file)?))
+}
+
+pub fn deserialize_dispatch_table<R: std::io::Read>(reader: R) -> anyhow::Result<DispatchTable> {
+    let (version, object) = read_version(serde_json::from_reader(reader)?)?;
+    let object = TABLE_SCHEMA.upgrade(object, version)?;
+    let multiplier = unit_multiplier(&object)?;
+
+    let major_frame = object.get("major_frame")
+        .map(|value| value.as_f64()
+            .map(|value| Time::nanos(value * multiplier))
+            .ok_or_else(|| anyhow::format_err!("'major_frame' must be a number")))
+        .transpose()?;
+
+    let objects = |name: &str, schema: &Schema| -> anyhow::Result<Vec<Map<String, Value>>> {
+        let Some(array) = object.get(name) else { return Ok(Vec::new()); };
+
+        array.as_array()
+            .ok_or_else(|| anyhow::format_err!("'{name}' must be an array"))?
+            .iter().enumerate()
+            .map(|(i, item)| {
+                let item = item.as_object().cloned()
+                    .ok_or_else(|| anyhow::format_err!("{} {i} must be an object", schema.name))?;
+                schema.upgrade(item, version)
+                    .map_err(|err| anyhow::format_err!("{} {i}: {err}", schema.name))
+            })
+            .collect()
+    };
+
+    let field = |item: &Map<String, Value>, name: &str| -> anyhow::Result<f64> {
+        item.get(name).and_then(Value::as_f64)
+            .filter(|value| *value >= 0.0)
+            .ok_or_else(|| anyhow::format_err!("missing non-negative numeric field '{name}'"))
+    };
+    let index = |item: &Map<String, Value>, name: &str| -> anyhow::Result<u64> {
+        item.get(name).and_then(Value::as_u64)
+            .ok_or_else(|| anyhow::format_err!("missing index field '{name}'"))
+    };
+
+    let dispatch = |dispatch: &Map<String, Value>| -> anyhow::Result<Dispatch> {
+        Ok(Dispatch {
+            task: index(dispatch, "task")? as usize,
+            job: index(dispatch, "job")?,
+            start: Time::nanos(field(dispatch, "start")? * multiplier),
+            duration: Time::nanos(field(dispatch, "duration")? * multiplier),
+        })
+    };
+    let precedence = |precedence: &Map<String, Value>| -> anyhow::Result<Precedence> {
+        Ok(Precedence {
+            predecessor: index(precedence, "predecessor")? as usize,
+            successor: index(precedence, "successor")? as usize,
+        })
+    };
+
+    anyhow::ensure!(object.contains_key("dispatches"), "missing 'dispatches' field");
+    let table = objects("dispatches", &DISPATCH_SCHEMA)?.iter().enumerate()
+        .map(|(i, item)| dispatch(item).map_err(|err| anyhow::format_err!("dispatch {i}: {err}")))
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    let precedences = objects("precedences", &PRECEDENCE_SCHEMA)?.iter().enumerate()
+        .map(|(i, item)| precedence(item).map_err(|err| anyhow::format_err!("precedence {i}: {err}")))
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    Ok(DispatchTable { major_frame, table, precedences })
+}
+
+/// Serialize the dispatch table in the current schema, with the times in the
+/// given unit.
+#[allow(dead_code)]
+pub fn serialize_dispatch_table(table: &DispatchTable, unit: TasksetPlainUnit) -> Value {
+    let divider = unit.nanos();
+
+    let dispatches: Vec<Value> = table.table.iter()
+        .map(|dispatch| json!({
+            "task": dispatch.task,
+            "job": dispatch.job,
+            "start": dispatch.start.as_nanos() / divider,
+            "duration": dispatch.duration.as_nanos() / divider,
+        }))
+        .collect();
+
+    let precedences: Vec<Value> = table.precedences.iter()
+        .map(|precedence| json!({ "predecessor": precedence.predecessor, "successor": precedence.successor }))
+        .collect();
+
+    let mut document = json!({
+        "version": TABLE_SCHEMA.version,
+        "unit": unit_name(unit),
+        "precedences": precedences,
+        "dispatches": dispatches,
+    });
+
+    if let Some(major_frame) = table.major_frame {
+        document["major_frame"] = json!(major_frame.as_nanos() / divider);
+    }
+    document
+}
+
+fn deserialize_step(step: &Value) -> Option<Step> {
+    let step = step.as_object()?;
+    let index = |name: &str| step.get(name).and_then(Value::as_u64).map(|index| index as usize);
+
+    match step.len() {
+        2 if step.contains_key("node") => Some(Step::Task { node: index("node")?, task: index("task")? }),
+        2 if step.contains_key("network") => Some(Step::Message { network: index("network")?, message: index("message")? }),
+        _ => None,
+    }
+}
+
+/// Migrate a plain taskset to the current taskset schema.
+#[allow(dead_code)]
+pub fn migrate_plain_taskset(taskset: &[RTTask], unit: TasksetPlainUnit) -> Value {
+    serialize_taskset(taskset, &WcetMargins::none(taskset.len()), unit)
+}
+
+/// Serialize the taskset in the current taskset schema, with the times in the
+/// given unit. Only the non-zero WCET margins are written.
+#[allow(dead_code)]
+pub fn serialize_taskset(taskset: &[RTTask], margins: &WcetMargins, unit: TasksetPlainUnit) -> Value {
+    let (name, divider) = (unit_name(unit), unit.nanos());
+
+    let tasks: Vec<Value> = taskset.iter().zip(&margins.margins)
+        .map(|(task, margin)| {
+            let mut task = json!({
+                "wcet": task.wcet.as_nanos() / divider,
+                "deadline": task.deadline.as_nanos() / divider,
+                "period": task.period.as_nanos() / divider,
+            });
+
+            if *margin != 0.0 {
+                task["wcet_margin"] = json!(margin);
+            }
+            task
+        })
+        .collect();
+
+    json!({ "version": TASKSET_SCHEMA.version, "unit": name, "tasks": tasks })
+}
+
+/// Configuration of the tools.
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct Config {
+    pub platform: Platform,
+    pub latencies: KernelLatencies,
+    pub overheads: SchedulingOverheads,
+    pub profile: Option<Profile>,
+    /// IDs of the tests to run, or prefixes of them; all if empty.
+    pub tests: Vec<String>,
+}
+
+#[allow(dead_code)]
+pub fn parse_config<P: AsRef<std::path::Path>>(config_file: P) -> anyhow::Result<Config> {
+    deserialize_config(&std::fs::read_to_string(config_file)?)
+}
+
+pub fn deserialize_config(data: &str) -> anyhow::Result<Config> {
+    let (version, object) = read_version(serde_json::from_str(data)?)?;
+    let mut object = CONFIG_SCHEMA.upgrade(object, version)?;
+
+    let platform: Platform = serde_json::from_value(
+        object.remove("platform").ok_or_else(|| anyhow::format_err!("missing 'platform' field"))?
+    )?;
+    platform.check_preconditions()?;
+
+    let profile = object.remove("profile")
+        .map(serde_json::from_value)
+        .transpose()
+        .map_err(|err| anyhow::format_err!("'profile': {err}"))?;
+
+    let tests = object.remove("tests")
+        .map(serde_json::from_value)
+        .transpose()
+        .map_err(|err| anyhow::format_err!("'tests': {err}"))?
+        .unwrap_or_default();
+
+    let micros = |name: &str| -> anyhow::Result<Time> {
+        match object.get(name) {
+            None => Ok(Time::zero()),
+            Some(value) => value.as_f64()
+                .map(Time::micros)
+                .ok_or_else(|| anyhow::format_err!("'{name}' must be a number")),
+        }
+    };
+
+    Ok(Config {
+        platform,
+        latencies: KernelLatencies { irq_to_release: micros("irq_latency_us")?, scheduling: micros("sched_latency_us")? },
+        overheads: SchedulingOverheads {
+            context_switch: micros("context_switch_us")?,
+            interrupt_period: micros("interrupt_period_us")?,
+            interrupt_overhead: micros("interrupt_overhead_us")?,
+        },
+        profile,
+        tests,
+    })
+}
+
+// Documents of the current version, only used to generate their JSON Schemas.
+#[derive(schemars::JsonSchema)]
+#[serde(rename = "Taskset", deny_unknown_fields)]
+#[allow(dead_code)]
+struct TasksetDocument {
+    #[schemars(range(min = 1, max = 3))]
+    version: u64,
+    /// Unit of the tasks' times.
+    unit: TimeUnit,
+    /// Tasks, sorted by priority (highest first) for fixed priority scheduling.
+    tasks: Vec<TasksetTaskDocument>,
+}
+
+#[derive(schemars::JsonSchema)]
+#[serde(rename_all = "lowercase")]
+#[allow(dead_code)]
+enum TimeUnit { Millis, Micros, Nanos }
+
+#[derive(schemars::JsonSchema)]
+#[serde(rename = "Task", deny_unknown_fields)]
+#[allow(dead_code)]
+struct TaskDocument {
+    #[schemars(range(min = 0))]
+    wcet: f64,
+    #[schemars(range(min = 0))]
+    deadline: f64,
+    #[schemars(range(min = 0))]
+    period: f64,
+}
+
+#[derive(schemars::JsonSchema)]
+#[serde(rename = "Task", deny_unknown_fields)]
+#[allow(dead_code)]
+struct TasksetTaskDocument {
+    #[schemars(range(min = 0))]
+    wcet: f64,
+    #[schemars(range(min = 0))]
+    deadline: f64,
+    #[schemars(range(min = 0))]
+    period: f64,
+    /// Relative margin of a WCET derived from measurements, e.g. 0.2 for the
+    /// measured maximum plus 20%.
+    #[schemars(range(min = 0))]
+    wcet_margin: Option<f64>,
+    /// Maximum delay between the arrival and the release of the jobs.
+    #[schemars(range(min = 0))]
+    release_jitter: Option<f64>,
+}
+
+#[derive(schemars::JsonSchema)]
+#[serde(rename = "Configuration", deny_unknown_fields)]
+#[allow(dead_code)]
+struct ConfigDocument {
+    #[schemars(range(min = 1, max = 4))]
+    version: u64,
+    platform: Platform,
+    /// Latency from the interrupt to the release of the job, in microseconds.
+    #[schemars(range(min = 0))]
+    irq_latency_us: Option<f64>,
+    /// Latency of the scheduler, in microseconds.
+    #[schemars(range(min = 0))]
+    sched_latency_us: Option<f64>,
+    /// Analysis profile, selecting the tests to run.
+    profile: Option<Profile>,
+    /// IDs of the tests to run, e.g. "up.fp.rta86", or prefixes of them, e.g.
+    /// "smp.edf". All the tests of the profile run if missing.
+    tests: Option<Vec<String>>,
+    /// Cost of a context switch, in microseconds.
+    #[schemars(range(min = 0))]
+    context_switch_us: Option<f64>,
+    /// Period of the periodic interrupt, e.g. the scheduler's tick, in
+    /// microseconds.
+    #[schemars(range(min = 0))]
+    interrupt_period_us: Option<f64>,
+    /// Cost of each periodic interrupt, in microseconds.
+    #[schemars(range(min = 0))]
+    interrupt_overhead_us: Option<f64>,
+}
+
+#[derive(schemars::JsonSchema)]
+#[serde(rename = "System", deny_unknown_fields)]
+#[allow(dead_code)]
+struct SystemDocument {
+    #[schemars(range(min = 1, max = 1))]
+    version: u64,
+    /// Unit of the times of the tasks, messages and flows.
+    unit: TimeUnit,
+    /// Processing nodes.
+    nodes: Vec<NodeDocument>,
+    /// Networks, e.g. CAN buses, transmitting their frames non-preemptively.
+    networks: Vec<NetworkDocument>,
+    /// End-to-end flows of tasks and messages.
+    flows: Vec<FlowDocument>,
+}
+
+#[derive(schemars::JsonSchema)]
+#[serde(rename = "Node", deny_unknown_fields)]
+#[allow(dead_code)]
+struct NodeDocument {
+    algorithm: SchedulingAlgorithm,
+    /// Tasks, sorted by priority (highest first) for fixed priority scheduling.
+    tasks: Vec<TaskDocument>,
+}
+
+#[derive(schemars::JsonSchema)]
+#[serde(rename = "Network", deny_unknown_fields)]
+#[allow(dead_code)]
+struct NetworkDocument {
+    /// Messages, sorted by priority (highest first), whose WCET is the
+    /// transmission time of their longest frame.
+    messages: Vec<TaskDocument>,
+}
+
+#[derive(schemars::JsonSchema)]
+#[serde(rename = "Flow", deny_unknown_fields)]
+#[allow(dead_code)]
+struct FlowDocument {
+    /// End-to-end deadline.
+    #[schemars(range(min = 0))]
+    deadline: f64,
+    /// Tasks and messages of the flow, from the first to the last.
+    steps: Vec<StepDocument>,
+}
+
+#[derive(schemars::JsonSchema)]
+#[serde(rename = "Step", untagged, deny_unknown_fields)]
+#[allow(dead_code)]
+enum StepDocument {
+    /// Task of a node, by index.
+    Task { node: u64, task: u64 },
+    /// Message of a network, by index.
+    Message { network: u64, message: u64 },
+}
+
+#[derive(schemars::JsonSchema)]
+#[serde(rename = "DispatchTable", deny_unknown_fields)]
+#[allow(dead_code)]
+struct TableDocument {
+    #[schemars(range(min = 1, max = 1))]
+    version: u64,
+    /// Unit of the times of the table.
+    unit: TimeUnit,
+    /// Length of the major frame, the hyperperiod of the taskset if missing.
+    #[schemars(range(min = 0))]
+    major_frame: Option<f64>,
+    /// Precedence constraints between tasks of equal period.
+    precedences: Option<Vec<PrecedenceDocument>>,
+    /// Dispatched job slices, a job may be split into several slices.
+    dispatches: Vec<DispatchDocument>,
+}
+
+#[derive(schemars::JsonSchema)]
+#[serde(rename = "Precedence", deny_unknown_fields)]
+#[allow(dead_code)]
+struct PrecedenceDocument {
+    /// Task whose jobs complete first, by index.
+    predecessor: u64,
+    /// Task whose jobs start after the same job of the predecessor, by index.
+    successor: u64,
+}
+
+#[derive(schemars::JsonSchema)]
+#[serde(rename = "Dispatch", deny_unknown_fields)]
+#[allow(dead_code)]
+struct DispatchDocument {
+    /// Task, by index.
+    task: u64,
+    /// Job of the task within the major frame, by index.
+    job: u64,
+    /// Offset from the start of the major frame.
+    #[schemars(range(min = 0))]
+    start: f64,
+    #[schemars(range(min = 0))]
+    duration: f64,
+}
+
+/// Formats with a published JSON Schema.
+#[derive(Debug, Clone, Copy)]
+#[derive(clap::ValueEnum)]
+pub enum SchemaKind {
+    Taskset,
+    Platform,
+    Config,
+    System,
+    Table,
+}
+
+/// JSON Schema of the given format, as published in the `schemas` directory.
+pub fn json_schema(kind: SchemaKind) -> Value {
+    let schema =
+        match kind {
+            SchemaKind::Taskset => schemars::schema_for!(TasksetDocument),
+            SchemaKind::Platform => schemars::schema_for!(Platform),
+            SchemaKind::Config => schemars::schema_for!(ConfigDocument),
+            SchemaKind::System => schemars::schema_for!(SystemDocument),
+            SchemaKind::Table => schemars::schema_for!(TableDocument),
+        };
+
+    schema.to_value()
+}
+
+/// Validate the document against the JSON Schema of its format, reporting
+/// every violation together with the path of the offending field.
+pub fn validate(kind: SchemaKind, document: &Value) -> anyhow::Result<()> {
+    let validator = jsonschema::validator_for(&json_schema(kind))
+        .map_err(|err| anyhow::format_err!("invalid JSON Schema: {err}"))?;
+
+    let errors: Vec<String> = validator.iter_errors(document)
+        .map(|err| {
+            let path = err.instance_path().to_string();
+            format!("at '{}': {err}", if path.is_empty() { "/" } else { &path })
+        })
+        .collect();
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(anyhow::format_err!("the {kind:?} does not match its JSON Schema:\n  {}", errors.join("\n  ")))
+    }
+}
+
+/// Validate the file against the JSON Schema of its format.
+#[allow(dead_code)]
+pub fn validate_file<P: AsRef<std::path::Path>>(kind: SchemaKind, file: P) -> anyhow::Result<()> {
+    let file = file.as_ref();
+    let reader = std::io::BufReader::new(std::fs::File::open(file)?);
+
+    validate(kind, &serde_json::from_reader(reader)?)
+        .map_err(|err| anyhow::format_err!("{}: {err}", file.display()))
+}
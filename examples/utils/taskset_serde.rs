@@ -1,4 +1,5 @@
 use eva_rt_engine::prelude::*;
+use eva_rt_engine::algorithms::full_preemption::uniprocessor::fixed_priority::rta86::JitteredTask;
 
 #[derive(Debug, Clone, Copy)]
 #[derive(PartialEq, Eq)]
@@ -9,55 +10,151 @@ pub enum TasksetPlainUnit {
     Nanos
 }
 
+impl TasksetPlainUnit {
+    /// Nanoseconds per unit.
+    pub fn nanos(&self) -> f64 {
+        match self {
+            TasksetPlainUnit::Millis => Time::MILLI_TO_NANO,
+            TasksetPlainUnit::Micros => Time::MICRO_TO_NANO,
+            TasksetPlainUnit::Nanos => 1.0,
+        }
+    }
+}
+
+/// Parse the given taskset file. Files with the `.taskset.json` extension are
+/// parsed as versioned tasksets (refer to [`super::schema`]), other files with
+/// the `.json` extension as rt-app workload descriptions, while any other file
+/// is parsed as a plain taskset expressed in the given unit.
+///
+/// The file is read incrementally, without loading it whole in memory.
+#[allow(dead_code)]
 pub fn parse_taskset<P: AsRef<std::path::Path>>(
     taskset_file: P,
     unit: TasksetPlainUnit,
 ) -> anyhow::Result<Vec<RTTask>> {
-    let taskset_data = std::fs::read_to_string(taskset_file)?;
-    Ok(plain_deserialize_taskset(&taskset_data, unit)?)
+    Ok(parse_annotated_taskset(taskset_file, unit)?.0)
 }
 
-fn plain_deserialize_taskset(data: &str, unit: TasksetPlainUnit) -> anyhow::Result<Vec<RTTask>> {
-    data.trim_ascii()
-        .lines()
-        .map(|line| plain_deserialize_task(line, unit))
-        .collect()
+/// Parse the given taskset file, as [`parse_taskset`], together with the WCET
+/// margins of its tasks. Only versioned tasksets declare margins, which are
+/// zero otherwise.
+#[allow(dead_code)]
+pub fn parse_annotated_taskset<P: AsRef<std::path::Path>>(
+    taskset_file: P,
+    unit: TasksetPlainUnit,
+) -> anyhow::Result<(Vec<RTTask>, WcetMargins)> {
+    let is_rt_app = taskset_file.as_ref().extension()
+        .is_some_and(|extension| extension == "json");
+
+    let is_versioned = taskset_file.as_ref().to_string_lossy().ends_with(".taskset.json");
+
+    let reader = std::io::BufReader::new(std::fs::File::open(taskset_file)?);
+
+    if is_versioned {
+        return super::schema::deserialize_annotated_taskset(reader);
+    }
+
+    let taskset =
+        if is_rt_app {
+            super::rt_app::deserialize_rt_app_reader(reader)?
+        } else {
+            plain_deserialize_reader(reader, unit)?
+        };
+
+    let margins = WcetMargins::none(taskset.len());
+    Ok((taskset, margins))
+}
+
+/// Parse the given taskset file, as [`parse_taskset`], together with the
+/// release jitters of its tasks. Only versioned tasksets declare jitters, which
+/// are zero otherwise.
+#[allow(dead_code)]
+pub fn parse_jittered_taskset<P: AsRef<std::path::Path>>(
+    taskset_file: P,
+    unit: TasksetPlainUnit,
+) -> anyhow::Result<Vec<JitteredTask>> {
+    if taskset_file.as_ref().to_string_lossy().ends_with(".taskset.json") {
+        let reader = std::io::BufReader::new(std::fs::File::open(taskset_file)?);
+
+        return super::schema::deserialize_jittered_taskset(reader);
+    }
+
+    Ok(parse_taskset(taskset_file, unit)?.into_iter().map(JitteredTask::new).collect())
+}
+
+/// Lazily parse the given plain taskset file, one task per line, without
+/// reading the whole file in memory. Meant for the streaming analyses.
+#[allow(dead_code)]
+pub fn stream_taskset<P: AsRef<std::path::Path>>(
+    taskset_file: P,
+    unit: TasksetPlainUnit,
+) -> anyhow::Result<impl Iterator<Item = anyhow::Result<RTTask>>> {
+    if taskset_file.as_ref().extension().is_some_and(|extension| extension == "json") {
+        return Err(anyhow::format_err!("rt-app workload descriptions cannot be streamed"));
+    }
+
+    Ok(PlainTasks {
+        reader: std::io::BufReader::new(std::fs::File::open(taskset_file)?),
+        line: String::new(),
+        unit,
+    })
+}
+
+// Plain tasks of a reader, reusing the same line buffer for every task.
+struct PlainTasks<R> {
+    reader: R,
+    line: String,
+    unit: TasksetPlainUnit,
+}
+
+impl<R: std::io::BufRead> Iterator for PlainTasks<R> {
+    type Item = anyhow::Result<RTTask>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            self.line.clear();
+
+            match self.reader.read_line(&mut self.line) {
+                Ok(0) => return None,
+                Ok(_) if self.line.trim_ascii().is_empty() => continue,
+                Ok(_) => return Some(plain_deserialize_task(&self.line, self.unit)),
+                Err(err) => return Some(Err(err.into())),
+            }
+        }
+    }
+}
+
+/// Plain tasks of a reader, one per line, skipping the blank lines.
+pub fn plain_deserialize_reader<R: std::io::BufRead>(reader: R, unit: TasksetPlainUnit) -> anyhow::Result<Vec<RTTask>> {
+    PlainTasks { reader, line: String::new(), unit }.collect()
 }
 
 fn plain_deserialize_task(data: &str, unit: TasksetPlainUnit) -> anyhow::Result<RTTask> {
-    let fields: Vec<&str> = data
-        .trim_ascii()
-        .split_ascii_whitespace()
-        .collect();
+    let mut fields = data.split_ascii_whitespace();
 
-    let multiplier =
-        match unit {
-            TasksetPlainUnit::Millis => Time::MILLI_TO_NANO,
-            TasksetPlainUnit::Micros => Time::MICRO_TO_NANO,
-            TasksetPlainUnit::Nanos => 1.0,
-        };
+    let multiplier = unit.nanos();
 
-    if fields.len() != 3 {
+    let mut field = |name: &str| -> anyhow::Result<Time> {
+        let value = fields.next()
+            .ok_or_else(|| anyhow::format_err!("RTTask parsing requires three numeric fields (wcet, deadline and period)"))?;
+
+        let value = value.parse::<f64>()
+            .map_err(|err| anyhow::format_err!("Failed to parse field '{name}': {err}"))?;
+
+        Ok(Time::nanos(value * multiplier))
+    };
+
+    let task = RTTask {
+        wcet: field("wcet")?,
+        deadline: field("deadline")?,
+        period: field("period")?,
+    };
+
+    if fields.next().is_some() {
         return Err(
             anyhow::format_err!("RTTask parsing requires three numeric fields (wcet, deadline and period)")
         );
     }
 
-    Ok(RTTask {
-        wcet: Time::nanos(fields[0].parse::<f64>()
-            .map_err(|err|
-                anyhow::format_err!("Failed to parse field 'wcet': {err}")
-            )? * multiplier
-        ),
-        deadline: Time::nanos(fields[1].parse::<f64>()
-            .map_err(|err|
-                anyhow::format_err!("Failed to parse field 'deadline': {err}")
-            )? * multiplier
-        ),
-        period: Time::nanos(fields[2].parse::<f64>()
-            .map_err(|err|
-                anyhow::format_err!("Failed to parse field 'period': {err}")
-            )? * multiplier
-        ),
-    })
-}
\ No newline at end of file
+    Ok(task)
+}
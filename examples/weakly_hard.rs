@@ -0,0 +1,84 @@
+mod utils;
+
+use utils::*;
+use eva_rt_engine::prelude::*;
+use eva_rt_engine::simulator::{Simulator, releases};
+use eva_rt_engine::simulator::weakly_hard::{self, WeaklyHard};
+
+#[derive(clap::Parser, Debug,  Clone)]
+pub struct Args {
+    pub input_file: String,
+
+    #[command(flatten)]
+    pub platform: PlatformArgs,
+
+    /// (m, k)-firm constraint, as `m/k`: either one for every task, or one
+    /// per task, in order [default: 1/1]
+    #[arg(long = "constraint", value_parser = parse_constraint)]
+    pub constraints: Vec<WeaklyHard>,
+
+    /// Length of each simulation, in hyperperiods
+    #[arg(long, default_value_t = 10)]
+    pub hyperperiods: u64,
+
+    /// Number of simulations with random sporadic releases, besides the
+    /// synchronous periodic one
+    #[arg(long, default_value_t = 0)]
+    pub sporadic_runs: u64,
+}
+
+fn parse_constraint(constraint: &str) -> Result<WeaklyHard, String> {
+    let (m, k) = constraint.split_once('/')
+        .ok_or_else(|| format!("invalid constraint \"{constraint}\", expected m/k"))?;
+    let parse = |value: &str| value.trim().parse::<u64>().map_err(|err| format!("invalid constraint \"{constraint}\": {err}"));
+
+    Ok(WeaklyHard { m: parse(m)?, k: parse(k)? })
+}
+
+/// Verify the (m, k)-firm constraints of the tasks on simulations of their
+/// schedule, reporting the tasks which satisfy them. A violation is a
+/// counterexample, while the satisfied constraints hold for the simulated
+/// releases only. Fails if any constraint is violated.
+fn main() -> anyhow::Result<()> {
+    let args = <Args as clap::Parser>::parse();
+
+    let taskset = parse_taskset(&args.input_file, TasksetPlainUnit::Millis)?;
+    let (platform, _, _) = args.platform.resolve()?;
+
+    let constraints =
+        match args.constraints.len() {
+            0 => vec![WeaklyHard::hard(); taskset.len()],
+            1 => vec![args.constraints[0]; taskset.len()],
+            _ => args.constraints.clone(),
+        };
+
+    let mut scheduler = simulated_scheduler(platform.algorithm)?;
+
+    let simulator = Simulator {
+        num_processors: platform.cpus,
+        time_unit: TimeRescaling::from_taskset(&taskset).unit,
+        horizon: RTUtils::hyperperiod(&taskset) * args.hyperperiods as f64
+            + taskset.iter().map(|task| task.deadline).fold(Time::zero(), Time::max),
+    };
+
+    let mut release_models: Vec<Box<dyn releases::ReleaseModel>> = vec![Box::new(releases::Periodic)];
+    release_models.extend((0..args.sporadic_runs)
+        .map(|seed| Box::new(releases::Sporadic::new(0.5, seed)) as Box<dyn releases::ReleaseModel>));
+
+    println!("Verifying with {} simulation(s) of {}ms under \"{}\":", release_models.len(),
+        simulator.horizon.as_millis(), scheduler.scheduler_name());
+
+    let verdicts = weakly_hard::verify(&simulator, &taskset, &constraints, scheduler.as_mut(), &mut release_models)?;
+
+    let mut violations = 0;
+    for (i, verdict) in verdicts.iter().enumerate() {
+        let WeaklyHard { m, k } = verdict.constraint;
+        let outcome = if verdict.is_satisfied() { "satisfied" } else { violations += 1; "VIOLATED" };
+
+        println!("\ttask {i}: ({m}, {k})-firm {outcome}, {} of {} jobs missed, at least {} of any {k} met",
+            verdict.misses, verdict.jobs, verdict.min_met);
+    }
+
+    anyhow::ensure!(violations == 0, "{violations} task(s) violate their (m, k)-firm constraint");
+    Ok(())
+}
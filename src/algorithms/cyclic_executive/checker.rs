@@ -0,0 +1,280 @@
+//! ## Dispatch Table Checker
+//!
+//! Verifies an existing static dispatch table, e.g. produced by another tool
+//! or edited by hand, against the taskset it dispatches, over one major frame.
+//! Unlike the synthesized tables, the jobs may be split into several slices,
+//! as long as the slices do not overlap. Every job released in the major frame
+//! must:
+//! - execute for at least its WCET, within the major frame;
+//! - start no earlier than its release and complete by its deadline;
+//! - start only after the same job of each of its predecessors completed, for
+//!   the given precedence constraints between tasks of equal period.
+//!
+//! Each violation is reported with the exact instant at which it occurs,
+//! relative to the start of the major frame.
+//!
+//! #### Preconditions:
+//! - Constrained Deadlines
+//! - The major frame is a multiple of every period
+//! - The precedence constraints relate tasks of equal period
+//!
+//! #### Implements:
+//! - [`check`] \
+//!   | Violations of the table, sorted by time. \
+//!   | \
+//!   | O(*dispatches* log(*dispatches*) + *jobs*) complexity
+
+use crate::prelude::*;
+use super::Dispatch;
+
+/// The same job of the successor starts only after the job of the predecessor
+/// completes.
+#[derive(Debug, Clone, Copy)]
+#[derive(PartialEq, Eq)]
+pub struct Precedence {
+    pub predecessor: usize,
+    pub successor: usize,
+}
+
+/// Violation of the dispatch table, refer to the [module](`self`) level
+/// documentation.
+#[derive(Debug, Clone)]
+#[derive(PartialEq)]
+pub enum Violation {
+    /// A slice exceeds the bounds of the major frame.
+    OutOfFrame { task: usize, job: u64, start: Time, end: Time },
+    /// A slice dispatches a job which is not released in the major frame.
+    UnknownJob { task: usize, job: u64, start: Time },
+    /// A slice starts before the previous one ends.
+    Overlap { task: usize, job: u64, start: Time, busy_until: Time },
+    /// A job runs for less than its WCET.
+    MissingExecution { task: usize, job: u64, executed: Time, wcet: Time, deadline: Time },
+    EarlyStart { task: usize, job: u64, start: Time, release: Time },
+    DeadlineMiss { task: usize, job: u64, completion: Time, deadline: Time },
+    /// A job starts before the same job of its predecessor completes.
+    Precedence { predecessor: usize, successor: usize, job: u64, start: Time, completion: Time },
+}
+
+impl Violation {
+    /// Instant of the violation, relative to the start of the major frame.
+    pub fn time(&self) -> Time {
+        match self {
+            Self::OutOfFrame { start, .. } => *start,
+            Self::UnknownJob { start, .. } => *start,
+            Self::Overlap { start, .. } => *start,
+            Self::MissingExecution { deadline, .. } => *deadline,
+            Self::EarlyStart { start, .. } => *start,
+            Self::DeadlineMiss { deadline, .. } => *deadline,
+            Self::Precedence { start, .. } => *start,
+        }
+    }
+}
+
+impl std::fmt::Display for Violation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::OutOfFrame { task, job, start, end } =>
+                write!(f, "task {task} job {job} runs in [{}ns, {}ns), outside the major frame",
+                    start.as_nanos(), end.as_nanos()),
+            Self::UnknownJob { task, job, start } =>
+                write!(f, "task {task} job {job}, dispatched at {}ns, is not released in the major frame",
+                    start.as_nanos()),
+            Self::Overlap { task, job, start, busy_until } =>
+                write!(f, "task {task} job {job} starts at {}ns, while the processor is busy until {}ns",
+                    start.as_nanos(), busy_until.as_nanos()),
+            Self::MissingExecution { task, job, executed, wcet, deadline } =>
+                write!(f, "task {task} job {job} runs for {}ns, less than its WCET of {}ns due by {}ns",
+                    executed.as_nanos(), wcet.as_nanos(), deadline.as_nanos()),
+            Self::EarlyStart { task, job, start, release } =>
+                write!(f, "task {task} job {job} starts at {}ns, before its release at {}ns",
+                    start.as_nanos(), release.as_nanos()),
+            Self::DeadlineMiss { task, job, completion, deadline } =>
+                write!(f, "task {task} job {job} completes at {}ns, after its deadline at {}ns",
+                    completion.as_nanos(), deadline.as_nanos()),
+            Self::Precedence { predecessor, successor, job, start, completion } =>
+                write!(f, "task {successor} job {job} starts at {}ns, before task {predecessor} completes it at {}ns",
+                    start.as_nanos(), completion.as_nanos()),
+        }
+    }
+}
+
+// Dispatched execution of a job.
+#[derive(Debug, Clone, Copy)]
+struct Execution {
+    executed: Time,
+    start: Time,
+    completion: Time,
+}
+
+/// Verify the dispatch table of the taskset over the given major frame,
+/// refer to the [module](`self`) level documentation. Returns the violations
+/// sorted by time, none if the table is feasible.
+pub fn check(
+    taskset: &[RTTask],
+    major_frame: Time,
+    table: &[Dispatch],
+    precedences: &[Precedence],
+) -> Result<Vec<Violation>, SchedError> {
+    check_preconditions(taskset, major_frame, table, precedences)?;
+
+    let num_jobs: Vec<u64> = taskset.iter()
+        .map(|task| (major_frame / task.period).round() as u64)
+        .collect();
+
+    let mut executions: Vec<Vec<Option<Execution>>> = num_jobs.iter()
+        .map(|&jobs| vec![None; jobs as usize])
+        .collect();
+
+    let mut violations = Vec::new();
+
+    let mut sorted: Vec<&Dispatch> = table.iter().collect();
+    sorted.sort_by_key(|dispatch| dispatch.start);
+
+    let mut busy_until = Time::zero();
+    for &Dispatch { task, job, start, duration } in sorted {
+        let end = start + duration;
+
+        if start < Time::zero() || end > major_frame {
+            violations.push(Violation::OutOfFrame { task, job, start, end });
+        }
+
+        if start < busy_until {
+            violations.push(Violation::Overlap { task, job, start, busy_until });
+        }
+        busy_until = busy_until.max(end);
+
+        let Some(execution) = executions[task].get_mut(job as usize) else {
+            violations.push(Violation::UnknownJob { task, job, start });
+            continue;
+        };
+
+        let execution = execution.get_or_insert(Execution { executed: Time::zero(), start, completion: end });
+        execution.executed = execution.executed + duration;
+        execution.completion = execution.completion.max(end);
+    }
+
+    for (i, task) in taskset.iter().enumerate() {
+        for (job, execution) in executions[i].iter().enumerate() {
+            let job = job as u64;
+            let release = task.period * job as f64;
+            let deadline = release + task.deadline;
+
+            let Some(execution) = execution else {
+                violations.push(Violation::MissingExecution { task: i, job, executed: Time::zero(), wcet: task.wcet, deadline });
+                continue;
+            };
+
+            if execution.executed < task.wcet {
+                violations.push(Violation::MissingExecution {
+                    task: i, job, executed: execution.executed, wcet: task.wcet, deadline
+                });
+            }
+
+            if execution.start < release {
+                violations.push(Violation::EarlyStart { task: i, job, start: execution.start, release });
+            }
+
+            if execution.completion > deadline {
+                violations.push(Violation::DeadlineMiss { task: i, job, completion: execution.completion, deadline });
+            }
+        }
+    }
+
+    for &Precedence { predecessor, successor } in precedences {
+        let jobs = executions[predecessor].iter().zip(&executions[successor]).enumerate();
+
+        for (job, (before, after)) in jobs {
+            if let (Some(before), Some(after)) = (before, after)
+                && after.start < before.completion
+            {
+                violations.push(Violation::Precedence {
+                    predecessor, successor, job: job as u64, start: after.start, completion: before.completion
+                });
+            }
+        }
+    }
+
+    violations.sort_by_key(Violation::time);
+    Ok(violations)
+}
+
+fn check_preconditions(
+    taskset: &[RTTask],
+    major_frame: Time,
+    table: &[Dispatch],
+    precedences: &[Precedence],
+) -> Result<(), SchedError> {
+    let is_multiple = |period: Time| {
+        let jobs = major_frame / period;
+        jobs >= 1.0 && (jobs - jobs.round()).abs() < 1e-9
+    };
+
+    if !RTUtils::constrained_deadlines(taskset) {
+        Err(SchedError::constrained_deadlines())
+    } else if let Some(i) = taskset.iter().position(|task| !is_multiple(task.period)) {
+        Err(SchedError::Precondition(Some(anyhow::format_err!(
+            "the major frame of {}ns is not a multiple of the period of task {i}.", major_frame.as_nanos()))))
+    } else if let Some(dispatch) = table.iter().find(|dispatch| dispatch.task >= taskset.len()) {
+        Err(SchedError::Precondition(Some(anyhow::format_err!(
+            "the table dispatches task {}, but there are {} tasks.", dispatch.task, taskset.len()))))
+    } else if let Some(precedence) = precedences.iter()
+        .find(|precedence| precedence.predecessor >= taskset.len() || precedence.successor >= taskset.len())
+    {
+        Err(SchedError::Precondition(Some(anyhow::format_err!(
+            "the precedence {} -> {} refers to a missing task.", precedence.predecessor, precedence.successor))))
+    } else if let Some(precedence) = precedences.iter()
+        .find(|precedence| taskset[precedence.predecessor].period != taskset[precedence.successor].period)
+    {
+        Err(SchedError::Precondition(Some(anyhow::format_err!(
+            "the precedence {} -> {} relates tasks of different periods.", precedence.predecessor, precedence.successor))))
+    } else {
+        Ok(())
+    }
+}
+
+#[test]
+fn dispatch_table_check() {
+    use super::Designer;
+
+    let at = |t: u64| Time::nanos(t as f64);
+    let dispatch = |task: usize, job: u64, start: u64, duration: u64|
+        Dispatch { task, job, start: at(start), duration: at(duration) };
+
+    // The synthesized tables are feasible.
+    let taskset = [RTTask::new_ns(1, 4, 4), RTTask::new_ns(2, 8, 8), RTTask::new_ns(1, 8, 8)];
+    let executive = Designer { quantization: Quantization::nanos(), harmonize: false }.design(&taskset[..]).unwrap();
+    assert_eq!(executive.check(&[]).unwrap(), vec![]);
+
+    // A hand-written table, with the second job split in two slices.
+    let precedences = [Precedence { predecessor: 1, successor: 2 }];
+    let mut table = vec![
+        dispatch(0, 0, 0, 1),
+        dispatch(1, 0, 1, 1),
+        dispatch(1, 0, 4, 1),
+        dispatch(0, 1, 5, 1),
+        dispatch(2, 0, 6, 1),
+    ];
+    assert_eq!(check(&taskset, at(8), &table, &precedences).unwrap(), vec![]);
+
+    // The successor now starts before the predecessor completes, and the
+    // second job of the first task misses its deadline.
+    table[4].start = at(3);
+    table[3].start = at(7);
+    table[3].duration = at(2);
+    assert_eq!(check(&taskset, at(8), &table, &precedences).unwrap(), vec![
+        Violation::Precedence { predecessor: 1, successor: 2, job: 0, start: at(3), completion: at(5) },
+        Violation::OutOfFrame { task: 0, job: 1, start: at(7), end: at(9) },
+        Violation::DeadlineMiss { task: 0, job: 1, completion: at(9), deadline: at(8) },
+    ]);
+
+    // Overlapping and missing jobs.
+    let table = [dispatch(0, 0, 0, 1), dispatch(1, 0, 0, 2), dispatch(2, 0, 2, 1), dispatch(0, 2, 3, 1)];
+    assert_eq!(check(&taskset, at(8), &table, &[]).unwrap(), vec![
+        Violation::Overlap { task: 1, job: 0, start: at(0), busy_until: at(1) },
+        Violation::UnknownJob { task: 0, job: 2, start: at(3) },
+        Violation::MissingExecution { task: 0, job: 1, executed: at(0), wcet: at(1), deadline: at(8) },
+    ]);
+
+    assert!(check(&taskset, at(6), &table, &[]).is_err());
+    assert!(check(&taskset, at(8), &table, &[Precedence { predecessor: 0, successor: 1 }]).is_err());
+}
@@ -0,0 +1,342 @@
+//! ## Cyclic Executive Synthesis - Baker & Shaw 1989
+//!
+//! Time-triggered dispatch table, as deployed in many safety-critical systems:
+//! the major frame, i.e. the hyperperiod of the tasks, is split into minor
+//! frames of equal length, and each frame dispatches a fixed list of jobs
+//! back to back, at most one timer interrupt per frame \[1\]. The jobs are not
+//! split among frames, and a job is dispatched only in frames which start
+//! after its release and end before its deadline. The length *f* of the
+//! minor frame must satisfy the frame constraints of \[1\], \[2\]:
+//! 1. *f >= max(C_i)*, every job fits in a frame;
+//! 2. *f* divides the period of at least one task, and thus the major frame;
+//! 3. *2f - gcd(T_i, f) <= D_i* for every task, a whole frame lies between
+//!    the release and the deadline of every job.
+//!
+//! The candidate minor frames are tried from the longest, i.e. fewest timer
+//! interrupts, and the jobs are assigned to the frames in deadline order,
+//! first fit. Since assigning unsplittable jobs to frames is a bin packing
+//! problem, the assignment is a heuristic, and a taskset may be rejected even
+//! if some table for it exists.
+//!
+//! Tasksets with unrelated periods have long hyperperiods, thus large tables.
+//! The periods may be *harmonized*, i.e. each shortened to the smallest period
+//! times the largest power of two which does not exceed it, as in the period
+//! specialization of \[3\], and the deadlines to at most the new periods. The
+//! table then dispatches the tasks more often than required, within a major
+//! frame of at most the largest period.
+//!
+//! #### Model:
+//! - Periodic/Sporadic Task model, the sporadic tasks being dispatched at
+//!   their minimum inter-arrival time
+//! - Non-Preemptive, time-triggered scheduling of the jobs
+//!
+//! #### Preconditions:
+//! - Constrained Deadlines
+//! - Positive time quantum, not greater than the deadlines and periods
+//!
+//! #### Implements:
+//! - [`Designer::design`] \
+//!   | Dispatch table of the taskset, refer to [`CyclicExecutive`]. \
+//!   | \
+//!   | O(*jobs* log(*jobs*)) per candidate minor frame
+//! - [`checker`] \
+//!   | Verify an existing dispatch table against the taskset
+//!
+//! ---
+//! #### References:
+//! 1. T. P. Baker and A. Shaw, “The cyclic executive model and Ada,”
+//!    Real-Time Systems, vol. 1, no. 1, pp. 7–25, June 1989,
+//!    doi: 10.1007/BF02341919.
+//! 2. J. W. S. Liu, “Real-Time Systems,” Prentice Hall, 2000, ch. 5.
+//! 3. C.-C. Han and H. Tyan, “A better polynomial-time schedulability test for
+//!    real-time fixed-priority scheduling algorithms,” in Proceedings
+//!    Real-Time Systems Symposium, Dec. 1997, pp. 36–45,
+//!    doi: 10.1109/REAL.1997.641267.
+
+use crate::prelude::*;
+
+pub mod checker;
+
+const ALGORITHM: &str = "Cyclic Executive Synthesis (Baker & Shaw 1989)";
+
+/// Job dispatched by the cyclic executive.
+#[derive(Debug, Clone)]
+#[derive(PartialEq)]
+pub struct Dispatch {
+    pub task: usize,
+    /// Index of the job of the task within the major frame.
+    pub job: u64,
+    /// Offset from the start of the major frame.
+    pub start: Time,
+    pub duration: Time,
+}
+
+/// Static dispatch table of a cyclic executive.
+///
+/// Refer to the [module](`self`) level documentation.
+#[derive(Debug, Clone)]
+pub struct CyclicExecutive {
+    /// Tasks as dispatched, quantized and, if harmonized, with the shortened
+    /// periods and deadlines.
+    pub tasks: Vec<RTTask>,
+    pub major_frame: Time,
+    pub minor_frame: Time,
+    /// Dispatched jobs, sorted by start time. The table repeats every major
+    /// frame.
+    pub table: Vec<Dispatch>,
+}
+
+impl CyclicExecutive {
+    pub fn num_frames(&self) -> u64 {
+        (self.major_frame / self.minor_frame).round() as u64
+    }
+
+    /// Jobs dispatched in the given minor frame.
+    pub fn frame(&self, frame: u64) -> impl Iterator<Item = &Dispatch> {
+        let start = self.minor_frame * frame as f64;
+        let end = start + self.minor_frame;
+
+        self.table.iter()
+            .filter(move |dispatch| dispatch.start >= start && dispatch.start < end)
+    }
+
+    /// Verify the table against the dispatched tasks and the given precedence
+    /// constraints, refer to [`checker::check`].
+    pub fn check(&self, precedences: &[checker::Precedence]) -> Result<Vec<checker::Violation>, SchedError> {
+        checker::check(&self.tasks, self.major_frame, &self.table, precedences)
+    }
+}
+
+/// Cyclic Executive Synthesis - Baker & Shaw 1989 \[1\]
+///
+/// Refer to the [module](`self`) level documentation.
+pub struct Designer {
+    pub quantization: Quantization,
+    /// Shorten the periods to harmonic ones, refer to the [module](`self`)
+    /// level documentation.
+    pub harmonize: bool,
+}
+
+// Task parameters in quanta.
+#[derive(Debug, Clone, Copy)]
+struct Quanta {
+    wcet: u64,
+    deadline: u64,
+    period: u64,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Job {
+    task: usize,
+    job: u64,
+    release: u64,
+    deadline: u64,
+    wcet: u64,
+}
+
+impl SchedDesign<&[RTTask], CyclicExecutive> for Designer {
+    fn designer_name(&self) -> &str { ALGORITHM }
+
+    fn check_preconditions(&self, taskset: &&[RTTask]) -> Result<(), SchedError> {
+        if !RTUtils::constrained_deadlines(taskset) {
+            Err(SchedError::constrained_deadlines())
+        } else {
+            self.quantization.check_preconditions(taskset)
+        }
+    }
+
+    fn run_designer(&self, taskset: &[RTTask]) -> Result<CyclicExecutive, SchedError> {
+        let tasks = self.dispatched_tasks(taskset);
+        let quanta: Vec<_> = tasks.iter().map(|task| self.quanta(task)).collect();
+
+        let utilization = RTUtils::total_utilization(&tasks);
+        if utilization > 1.0 {
+            return Err(SchedError::NonSchedulable(Some(
+                anyhow::format_err!("the utilization of {utilization} exceeds the processor."))));
+        }
+
+        let major_frame = quanta.iter()
+            .try_fold(1u64, |hyperperiod, task| lcm(hyperperiod, task.period))
+            .ok_or_else(|| SchedError::NonSchedulable(Some(
+                anyhow::format_err!("the hyperperiod of the tasks overflows, consider harmonizing the periods."))))?;
+
+        let minor_frames = minor_frames(&quanta);
+        if minor_frames.is_empty() {
+            return Err(SchedError::NonSchedulable(Some(
+                anyhow::format_err!("no minor frame satisfies the frame constraints."))));
+        }
+
+        let jobs = jobs(&quanta, major_frame);
+        let (minor_frame, table) = minor_frames.into_iter()
+            .find_map(|minor_frame| assign(&jobs, major_frame, minor_frame).map(|table| (minor_frame, table)))
+            .ok_or_else(|| SchedError::NonSchedulable(Some(
+                anyhow::format_err!("the jobs do not fit in the frames of any minor frame."))))?;
+
+        let quantum = self.quantization.quantum;
+        Ok(CyclicExecutive {
+            tasks,
+            major_frame: quantum * major_frame as f64,
+            minor_frame: quantum * minor_frame as f64,
+            table: table.into_iter()
+                .map(|(job, start)| Dispatch {
+                    task: job.task,
+                    job: job.job,
+                    start: quantum * start as f64,
+                    duration: quantum * job.wcet as f64,
+                })
+                .collect(),
+        })
+    }
+}
+
+impl Designer {
+    fn dispatched_tasks(&self, taskset: &[RTTask]) -> Vec<RTTask> {
+        let tasks = self.quantization.quantize_taskset(taskset);
+        if !self.harmonize {
+            return tasks;
+        }
+
+        let base = tasks.iter().map(|task| task.period).fold(Time::nanos(f64::INFINITY), Time::min);
+        tasks.into_iter()
+            .map(|task| {
+                let period = base * 2f64.powi((task.period / base).log2().floor() as i32);
+                RTTask { wcet: task.wcet, deadline: task.deadline.min(period), period }
+            })
+            .collect()
+    }
+
+    fn quanta(&self, task: &RTTask) -> Quanta {
+        let quanta = |time: Time| (time / self.quantization.quantum).round() as u64;
+
+        Quanta { wcet: quanta(task.wcet), deadline: quanta(task.deadline), period: quanta(task.period) }
+    }
+}
+
+fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+fn lcm(a: u64, b: u64) -> Option<u64> {
+    (a / gcd(a, b)).checked_mul(b)
+}
+
+// Minor frames satisfying the frame constraints [1], longest first.
+fn minor_frames(tasks: &[Quanta]) -> Vec<u64> {
+    let max_wcet = tasks.iter().map(|task| task.wcet).max().unwrap_or(0).max(1);
+
+    let mut frames: Vec<u64> = tasks.iter()
+        .flat_map(|task| divisors(task.period))
+        .filter(|&frame| frame >= max_wcet)
+        .filter(|&frame| tasks.iter().all(|task| 2 * frame - gcd(task.period, frame) <= task.deadline))
+        .collect();
+
+    frames.sort_unstable_by(|a, b| b.cmp(a));
+    frames.dedup();
+    frames
+}
+
+fn divisors(n: u64) -> impl Iterator<Item = u64> {
+    (1..).take_while(move |d| d * d <= n)
+        .filter(move |d| n.is_multiple_of(*d))
+        .flat_map(move |d| [d, n / d])
+}
+
+// Jobs of the major frame, sorted by release.
+fn jobs(tasks: &[Quanta], major_frame: u64) -> Vec<Job> {
+    let mut jobs: Vec<_> = tasks.iter().enumerate()
+        .flat_map(|(i, task)| (0..major_frame / task.period)
+            .map(move |job| Job {
+                task: i,
+                job,
+                release: job * task.period,
+                deadline: job * task.period + task.deadline,
+                wcet: task.wcet,
+            }))
+        .collect();
+
+    jobs.sort_by_key(|job| job.release);
+    jobs
+}
+
+// First fit of the released jobs into each frame, earliest deadline first.
+// Returns the start time of each job, or None if a job misses all its frames.
+fn assign(jobs: &[Job], major_frame: u64, minor_frame: u64) -> Option<Vec<(Job, u64)>> {
+    let mut table = Vec::with_capacity(jobs.len());
+    let mut ready: Vec<Job> = Vec::new();
+    let mut next = 0;
+
+    for frame_start in (0..major_frame).step_by(minor_frame as usize) {
+        let frame_end = frame_start + minor_frame;
+
+        while next < jobs.len() && jobs[next].release <= frame_start {
+            ready.push(jobs[next]);
+            next += 1;
+        }
+
+        if ready.iter().any(|job| job.deadline < frame_end) {
+            return None;
+        }
+
+        ready.sort_by_key(|job| (job.deadline, job.task));
+
+        let mut time = frame_start;
+        ready.retain(|job| {
+            if time + job.wcet > frame_end {
+                return true;
+            }
+
+            table.push((*job, time));
+            time += job.wcet;
+            false
+        });
+    }
+
+    (ready.is_empty() && next == jobs.len()).then_some(table)
+}
+
+#[test]
+fn cyclic_executive() {
+    let designer = Designer { quantization: Quantization::nanos(), harmonize: false };
+
+    // Example of [2], scaled by 10: the frame constraints only admit f = 20.
+    let taskset = [
+        RTTask::new_ns(10, 40, 40),
+        RTTask::new_ns(18, 50, 50),
+        RTTask::new_ns(10, 200, 200),
+        RTTask::new_ns(20, 200, 200),
+    ];
+
+    let executive = designer.design(&taskset[..]).unwrap();
+    assert_eq!(executive.major_frame, Time::nanos(200.0));
+    assert_eq!(executive.minor_frame, Time::nanos(20.0));
+    assert_eq!(executive.table.len(), 5 + 4 + 1 + 1);
+
+    for frame in 0..executive.num_frames() {
+        let busy: Time = executive.frame(frame).map(|dispatch| dispatch.duration).sum();
+        assert!(busy <= executive.minor_frame);
+    }
+
+    for dispatch in executive.table.iter() {
+        let task = &taskset[dispatch.task];
+        let release = task.period * dispatch.job as f64;
+        assert!(dispatch.start >= release);
+        assert!(dispatch.start + dispatch.duration <= release + task.deadline);
+    }
+
+    // Harmonized periods: 4, 4, 8.
+    let designer = Designer { harmonize: true, ..designer };
+    let taskset = [RTTask::new_ns(1, 4, 4), RTTask::new_ns(1, 5, 5), RTTask::new_ns(2, 9, 9)];
+    let executive = designer.design(&taskset[..]).unwrap();
+    assert_eq!(executive.major_frame, Time::nanos(8.0));
+    assert_eq!(executive.tasks[1].deadline, Time::nanos(4.0));
+    assert_eq!(executive.table.len(), 2 + 2 + 1);
+
+    // EDF schedulable, but the long job only fits in frames of 6 or 12, which
+    // are too long for the short deadlines.
+    let designer = Designer { quantization: Quantization::nanos(), harmonize: false };
+    let taskset = [RTTask::new_ns(1, 4, 4), RTTask::new_ns(5, 12, 12)];
+    assert!(designer.design(&taskset[..]).is_err());
+}
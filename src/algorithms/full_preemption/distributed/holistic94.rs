@@ -0,0 +1,255 @@
+//! ## Holistic Analysis - Tindell & Clark 1994
+//!
+//! The response times of the steps of the flows depend on each other, since
+//! each step inherits the variability of the completion of its predecessor as
+//! release jitter \[1\]. The analysis alternates the response time analysis of
+//! every node and network with the update of the jitters, *J = R* of the
+//! preceding step, until they reach a fixpoint. The nodes are analyzed with
+//! release jitter (refer to [`rta86::AnalysisJitter`]), and the networks with
+//! the sufficient test for CAN \[2\], in which a frame may be blocked by the
+//! longest frame of the network, even its own previous one.
+//!
+//! #### Model:
+//! - [`DistributedSystem`] of Periodic/Sporadic tasks and messages
+//! - Fully-Preemptive Fixed Priority scheduling of the nodes
+//! - Non-Preemptive Fixed Priority scheduling of the networks
+//!
+//! #### Preconditions:
+//! - Constrained Deadlines, measured from the activation of the flows
+//! - Fixed Priority nodes
+//!
+//! #### Implements:
+//! - [`Analysis::is_schedulable`] \
+//!   | pseudo-polynomial complexity per iteration
+//!
+//! ---
+//! #### References:
+//! 1. K. Tindell and J. Clark, “Holistic schedulability analysis for
+//!    distributed hard real-time systems,” Microprocessing and
+//!    Microprogramming, vol. 40, no. 2, pp. 117–134, Apr. 1994,
+//!    doi: 10.1016/0165-6074(94)90080-9.
+//! 2. R. I. Davis, A. Burns, R. J. Bril, and J. J. Lukkien, “Controller Area
+//!    Network (CAN) schedulability analysis: Refuted, revisited and revised,”
+//!    Real-Time Syst, vol. 35, no. 3, pp. 239–272, Apr. 2007,
+//!    doi: 10.1007/s11241-007-9012-7.
+
+use crate::prelude::*;
+use crate::algorithms::full_preemption::end_to_end::ChainLatency;
+use crate::algorithms::full_preemption::uniprocessor::fixed_priority::rta86;
+use super::*;
+
+const ALGORITHM: &str = "Holistic Analysis (Tindell & Clark 1994)";
+
+/// Holistic Analysis, Tindell & Clark 1994 \[1\]
+///
+/// Refer to the [module](`self`) level documentation.
+pub struct Analysis;
+
+/// Worst-case response times of the distributed system, measured from the
+/// activation of the flows.
+#[derive(Debug, Clone)]
+pub struct HolisticResponseTimes {
+    /// Response times of the tasks of each node.
+    pub nodes: Vec<Vec<Time>>,
+    /// Response times of the messages of each network.
+    pub networks: Vec<Vec<Time>>,
+    /// Latency of each flow, step by step.
+    pub flows: Vec<ChainLatency>,
+}
+
+impl SchedAnalysis<HolisticResponseTimes, &DistributedSystem> for Analysis {
+    fn analyzer_name(&self) -> &str { ALGORITHM }
+
+    fn check_preconditions(&self, system: &&DistributedSystem) -> Result<(), SchedError> {
+        system.check_preconditions()?;
+
+        for (n, node) in system.nodes.iter().enumerate() {
+            if node.algorithm != SchedulingAlgorithm::FixedPriority {
+                return Err(SchedError::Precondition(Some(
+                    anyhow::format_err!("node {n} must use fixed priority scheduling."))));
+            }
+
+            let jittered_tasks: Vec<_> = node.tasks.iter().cloned().map(rta86::JitteredTask::new).collect();
+            rta86::AnalysisJitter
+                .check_preconditions(&jittered_tasks.as_slice())
+                .map_err(|err| err.with_context(&format!("node {n}")))?;
+        }
+
+        for (k, network) in system.networks.iter().enumerate() {
+            if !RTUtils::constrained_deadlines(&network.messages) {
+                return Err(SchedError::constrained_deadlines().with_context(&format!("network {k}")));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn run_test(&self, system: &DistributedSystem) -> Result<HolisticResponseTimes, SchedError> {
+        let mut node_tasks: Vec<Vec<_>> = system.nodes.iter()
+            .map(|node| node.tasks.iter().cloned().map(rta86::JitteredTask::new).collect())
+            .collect();
+        let mut network_jitters: Vec<_> = system.networks.iter()
+            .map(|network| vec![Time::zero(); network.messages.len()])
+            .collect();
+
+        loop {
+            let nodes = node_tasks.iter().enumerate()
+                .map(|(n, tasks)| {
+                    rta86::AnalysisJitter
+                        .run_test(tasks)
+                        .map_err(|err| err.with_context(&format!("node {n}")))
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+
+            let networks = system.networks.iter().zip(&network_jitters).enumerate()
+                .map(|(k, (network, jitters))| {
+                    message_response_times(&network.messages, jitters)
+                        .map_err(|err| err.with_context(&format!("network {k}")))
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+
+            let response_time = |step: Step| match step {
+                Step::Task { node, task } => nodes[node][task],
+                Step::Message { network, message } => networks[network][message],
+            };
+
+            // Each step is released by the completion of its predecessor.
+            let mut changed = false;
+            for flow in system.flows.iter() {
+                for pair in flow.steps.windows(2) {
+                    let jitter =
+                        match pair[1] {
+                            Step::Task { node, task } => &mut node_tasks[node][task].release_jitter,
+                            Step::Message { network, message } => &mut network_jitters[network][message],
+                        };
+
+                    let release = response_time(pair[0]);
+                    if *jitter != release {
+                        *jitter = release;
+                        changed = true;
+                    }
+                }
+            }
+
+            if changed {
+                continue;
+            }
+
+            let flows: Vec<_> = system.flows.iter()
+                .map(|flow| ChainLatency {
+                    hops: flow.steps.iter().map(|&step| response_time(step)).collect(),
+                    deadline: flow.deadline,
+                })
+                .collect();
+
+            return match flows.iter().position(|flow| !flow.is_met()) {
+                Some(f) => Err(SchedError::NonSchedulable(Some(anyhow::format_err!(
+                    "flow {f} has an end-to-end latency of {}ns, past its deadline of {}ns.",
+                    flows[f].latency().as_nanos(), flows[f].deadline.as_nanos())))),
+                None => Ok(HolisticResponseTimes { nodes, networks, flows }),
+            };
+        }
+    }
+}
+
+fn message_response_times(messages: &[RTTask], jitters: &[Time]) -> Result<Vec<Time>, SchedError> {
+    let blocking = messages.iter()
+        .map(|message| message.wcet)
+        .max()
+        .unwrap_or(Time::zero());
+
+    messages.iter().enumerate()
+        .map(|(i, message)| {
+            let response_time = message_response_time(&messages[0..=i], &jitters[0..=i], blocking);
+
+            if response_time > message.deadline {
+                Err(SchedError::NonSchedulable(Some(
+                    anyhow::format_err!("message {i} misses its deadline.")
+                )))
+            } else {
+                Ok(response_time)
+            }
+        })
+        .collect()
+}
+
+// Equation 16 [2]: the queuing delay of a message is bounded by the longest
+// frame of the network, plus the higher priority frames queued up to the
+// start of its transmission, with their largest jitter:
+//   w = B + sum_(j < i) (floor((w + J_j) / T_j) + 1) C_j
+// and the response time from the arrival is J_i + w + C_i. The queuing delay
+// is stopped as soon as the response time exceeds the deadline.
+fn message_response_time(messages: &[RTTask], jitters: &[Time], blocking: Time) -> Time {
+    let message = messages.last().unwrap();
+    let hp_messages = &messages[0..messages.len() - 1];
+    let jitter = *jitters.last().unwrap();
+
+    let queuing_delay =
+        fixpoint_search_with_limit(
+            blocking,
+            message.deadline - jitter - message.wcet + Time::one(),
+            |delay: &Time| {
+                let interference: Time =
+                    hp_messages.iter().zip(jitters)
+                        .map(|(message_j, jitter_j)|
+                            message_j.wcet * (((*delay + *jitter_j) / message_j.period).floor() + 1.0))
+                        .sum();
+
+                blocking + interference
+            }
+        );
+
+    jitter + queuing_delay + message.wcet
+}
+
+#[test]
+fn holistic_analysis() {
+    let at = |t: u64| Time::nanos(t as f64);
+
+    // A sensor task on node 0 sends a message over the network to an actuator
+    // task on node 1, each with a higher priority neighbour.
+    let mut system = DistributedSystem {
+        nodes: vec![
+            Node {
+                tasks: vec![RTTask::new_ns(1, 5, 5), RTTask::new_ns(2, 20, 20)],
+                algorithm: SchedulingAlgorithm::FixedPriority,
+            },
+            Node {
+                tasks: vec![RTTask::new_ns(1, 4, 4), RTTask::new_ns(2, 20, 20)],
+                algorithm: SchedulingAlgorithm::FixedPriority,
+            },
+        ],
+        networks: vec![
+            Network { messages: vec![RTTask::new_ns(1, 20, 20), RTTask::new_ns(2, 10, 10)] },
+        ],
+        flows: vec![
+            Flow {
+                steps: vec![
+                    Step::Task { node: 0, task: 1 },
+                    Step::Message { network: 0, message: 0 },
+                    Step::Task { node: 1, task: 1 },
+                ],
+                deadline: at(12),
+            },
+        ],
+    };
+
+    // The sensor completes by 3, the message is blocked by the longest frame
+    // and delivered by 3 + 2 + 1, and the actuator completes by 6 + 3.
+    let result = Analysis.is_schedulable(&system).unwrap();
+    assert_eq!(result.flows[0].hops, vec![at(3), at(6), at(9)]);
+    assert_eq!(result.flows[0].slack(), at(3));
+    assert_eq!(result.networks[0], vec![at(6), at(5)]);
+
+    system.flows[0].deadline = at(8);
+    let err = Analysis.is_schedulable(&system).unwrap_err();
+    assert!(format!("{err:#}").contains("flow 0"));
+
+    system.flows[0].deadline = at(12);
+    system.nodes[1].algorithm = SchedulingAlgorithm::EarliestDeadlineFirst;
+    assert!(Analysis.is_schedulable(&system).is_err());
+
+    system.nodes[1].algorithm = SchedulingAlgorithm::FixedPriority;
+    system.flows.push(Flow { steps: vec![Step::Task { node: 0, task: 1 }], deadline: at(20) });
+    assert!(Analysis.is_schedulable(&system).is_err());
+}
@@ -0,0 +1,164 @@
+//! ## Distributed System Model
+//!
+//! Processing nodes, each scheduling its own taskset, exchange messages over
+//! shared networks, such as CAN buses or priority-queued Ethernet links, on
+//! which the frames are transmitted non-preemptively in priority order.
+//!
+//! End-to-end flows, or transactions, traverse the system step by step: the
+//! first step is released by the flow's activation, and every other step by
+//! the completion of its predecessor, e.g. a message is queued once the task
+//! which sends it completes. Each flow must complete within its end-to-end
+//! deadline. The tasks and messages of the flows share the periods of their
+//! flows.
+//!
+//! In the transaction model \[1\], each flow is a transaction whose steps may
+//! also have static offsets from its activation: a step is released when its
+//! predecessor completes, but never before its offset. The tasks and messages
+//! of no flow are transactions of a single step.
+//!
+//! #### Implements:
+//! - [`DistributedSystem`] \
+//!   | Nodes, networks and flows
+//! - [`Transaction`] \
+//!   | Flows with static offsets
+//! - [`holistic94`] \
+//!   | Holistic analysis of fixed priority nodes and networks
+//! - [`palencia98`] \
+//!   | Offset based analysis of fixed priority nodes and networks
+//!
+//! ---
+//! #### References:
+//! 1. J. C. Palencia and M. González Harbour, “Schedulability analysis for
+//!    tasks with static and dynamic offsets,” in Proceedings 19th IEEE
+//!    Real-Time Systems Symposium, Dec. 1998, pp. 26–37,
+//!    doi: 10.1109/REAL.1998.739728.
+
+use crate::prelude::*;
+
+pub mod holistic94;
+pub mod palencia98;
+
+/// Processing node, scheduling its taskset.
+#[derive(Debug, Clone)]
+pub struct Node {
+    /// Tasks of the node. With fixed priority scheduling, they are sorted by
+    /// priority, highest first.
+    pub tasks: Vec<RTTask>,
+    pub algorithm: SchedulingAlgorithm,
+}
+
+/// Network transmitting non-preemptively the frames of its messages, in
+/// priority order.
+#[derive(Debug, Clone)]
+pub struct Network {
+    /// Messages sent on the network, sorted by priority, highest first. The
+    /// WCET of a message is the transmission time of its longest frame.
+    pub messages: Vec<RTTask>,
+}
+
+/// Task of a node or message of a network.
+#[derive(Debug, Clone, Copy)]
+#[derive(PartialEq, Eq)]
+pub enum Step {
+    Task { node: usize, task: usize },
+    Message { network: usize, message: usize },
+}
+
+/// End-to-end flow of tasks and messages.
+#[derive(Debug, Clone)]
+pub struct Flow {
+    /// Steps of the flow, from the first to the last.
+    pub steps: Vec<Step>,
+    pub deadline: Time,
+}
+
+/// Distributed system of nodes, networks and flows.
+///
+/// Refer to the [module](`self`) level documentation.
+#[derive(Debug, Clone)]
+pub struct DistributedSystem {
+    pub nodes: Vec<Node>,
+    pub networks: Vec<Network>,
+    pub flows: Vec<Flow>,
+}
+
+impl DistributedSystem {
+    /// Task or message of the step, if any.
+    pub fn step_task(&self, step: Step) -> Option<&RTTask> {
+        match step {
+            Step::Task { node, task } =>
+                self.nodes.get(node).and_then(|node| node.tasks.get(task)),
+            Step::Message { network, message } =>
+                self.networks.get(network).and_then(|network| network.messages.get(message)),
+        }
+    }
+
+    /// Check that the flows are made of the tasks and messages of the system,
+    /// each belonging to at most one step of one flow.
+    pub fn check_preconditions(&self) -> Result<(), SchedError> {
+        let mut steps: Vec<Step> = Vec::new();
+
+        for (f, flow) in self.flows.iter().enumerate() {
+            if flow.steps.is_empty() {
+                return Err(SchedError::Precondition(Some(
+                    anyhow::format_err!("flow {f} has no steps."))));
+            }
+
+            for &step in flow.steps.iter() {
+                if self.step_task(step).is_none() {
+                    return Err(SchedError::Precondition(Some(
+                        anyhow::format_err!("flow {f} has a step which is not in the system: {step:?}."))));
+                } else if steps.contains(&step) {
+                    return Err(SchedError::Precondition(Some(
+                        anyhow::format_err!("flow {f} shares a step with another flow: {step:?}."))));
+                }
+
+                steps.push(step);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Transaction of steps, activated periodically.
+///
+/// Refer to the [module](`self`) level documentation.
+#[derive(Debug, Clone)]
+pub struct Transaction {
+    pub period: Time,
+    /// Steps of the transaction, from the first to the last.
+    pub steps: Vec<Step>,
+    /// Static offset of each step from the activation of the transaction.
+    pub offsets: Vec<Time>,
+    pub deadline: Time,
+}
+
+impl DistributedSystem {
+    /// Transactions of the system: the flows, with the given static offsets of
+    /// their steps, or none if empty, followed by a transaction for each task
+    /// and message of no flow.
+    pub fn transactions(&self, offsets: &[Vec<Time>]) -> Vec<Transaction> {
+        let flows = self.flows.iter().enumerate()
+            .map(|(f, flow)| Transaction {
+                period: self.step_task(flow.steps[0]).map(|task| task.period).unwrap_or(Time::zero()),
+                steps: flow.steps.clone(),
+                offsets: offsets.get(f).cloned().unwrap_or_else(|| vec![Time::zero(); flow.steps.len()]),
+                deadline: flow.deadline,
+            });
+
+        let steps = self.nodes.iter().enumerate()
+            .flat_map(|(node, tasks)| (0..tasks.tasks.len()).map(move |task| Step::Task { node, task }))
+            .chain(self.networks.iter().enumerate()
+                .flat_map(|(network, messages)| (0..messages.messages.len()).map(move |message| Step::Message { network, message })));
+
+        let singles = steps
+            .filter(|step| self.flows.iter().all(|flow| !flow.steps.contains(step)))
+            .map(|step| {
+                let task = self.step_task(step).unwrap();
+                Transaction { period: task.period, steps: vec![step], offsets: vec![Time::zero()], deadline: task.deadline }
+            });
+
+        flows.chain(singles).collect()
+    }
+}
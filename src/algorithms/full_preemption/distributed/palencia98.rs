@@ -0,0 +1,475 @@
+//! ## Offset Based Analysis - Palencia & González Harbour 1998
+//!
+//! The holistic analysis assumes that the steps of a transaction may be
+//! released at the same time, even if each of them must wait for its
+//! predecessor. The worst-case dynamic offsets analysis (WCDO) \[1\] instead
+//! accounts for the offsets of the steps from the activation of their
+//! transaction: each step is released from its offset *Φ*, the latest of its
+//! static offset and the offset of its predecessor, up to a jitter *J*, the
+//! latest of its static offset and the response time of its predecessor,
+//! minus *Φ*. The interference of each other transaction is the worst among
+//! the critical instants started by each of its higher priority steps, while
+//! every higher priority step of the transaction under analysis, and the
+//! analyzed one, is tried as the start of the busy period. As in the holistic
+//! analysis, the response times and the jitters are updated until they reach a
+//! fixpoint. The best-case response times are not known, thus the dynamic
+//! offsets follow the static ones only.
+//!
+//! The WCDOPS variant exploits the precedence relations of the transaction
+//! under analysis \[2\]: the higher priority steps of the same activation as
+//! the analyzed job either complete before it is released or are released
+//! after it completes, thus they do not interfere with it.
+//!
+//! The networks are analyzed as preemptive resources, with the longest frame
+//! of the network as blocking, which bounds their non-preemptive response
+//! times.
+//!
+//! #### Model:
+//! - [`DistributedSystem`] of Periodic/Sporadic tasks and messages, whose
+//!   flows are [`Transaction`]s
+//! - Fully-Preemptive Fixed Priority scheduling of the nodes
+//! - Non-Preemptive Fixed Priority scheduling of the networks
+//!
+//! #### Preconditions:
+//! - Constrained Deadlines, measured from the activation of the transactions
+//! - Fixed Priority nodes
+//! - Steps of each flow sharing the period of the flow
+//! - Non-negative static offsets
+//!
+//! #### Implements:
+//! - [`Analysis::is_schedulable`] \
+//!   | pseudo-polynomial complexity per iteration
+//! - [`AnalysisPrecedence::is_schedulable`] \
+//!   | pseudo-polynomial complexity per iteration
+//!
+//! ---
+//! #### References:
+//! 1. J. C. Palencia and M. González Harbour, “Schedulability analysis for
+//!    tasks with static and dynamic offsets,” in Proceedings 19th IEEE
+//!    Real-Time Systems Symposium, Dec. 1998, pp. 26–37,
+//!    doi: 10.1109/REAL.1998.739728.
+//! 2. J. C. Palencia and M. González Harbour, “Exploiting precedence relations
+//!    in the schedulability analysis of distributed real-time systems,” in
+//!    Proceedings 20th IEEE Real-Time Systems Symposium, Dec. 1999,
+//!    pp. 328–339, doi: 10.1109/REAL.1999.818860.
+
+use crate::prelude::*;
+use crate::algorithms::full_preemption::end_to_end::ChainLatency;
+use super::*;
+use super::holistic94::HolisticResponseTimes;
+
+const ALGORITHM: &str = "WCDO Analysis (Palencia & González Harbour 1998)";
+const ALGORITHM_PRECEDENCE: &str = "WCDOPS Analysis (Palencia & González Harbour 1999)";
+
+/// Offset Based Analysis (WCDO), Palencia & González Harbour 1998 \[1\]
+///
+/// Refer to the [module](`self`) level documentation.
+///
+/// The `offsets` are the static offsets of the steps of each flow, or none if
+/// empty.
+pub struct Analysis {
+    pub offsets: Vec<Vec<Time>>,
+}
+
+/// Offset Based Analysis with Precedence Relations (WCDOPS), Palencia &
+/// González Harbour 1999 \[2\]
+///
+/// Refer to the [module](`self`) level documentation.
+///
+/// The `offsets` are the static offsets of the steps of each flow, or none if
+/// empty.
+pub struct AnalysisPrecedence {
+    pub offsets: Vec<Vec<Time>>,
+}
+
+impl SchedAnalysis<HolisticResponseTimes, &DistributedSystem> for Analysis {
+    fn analyzer_name(&self) -> &str { ALGORITHM }
+
+    fn check_preconditions(&self, system: &&DistributedSystem) -> Result<(), SchedError> {
+        check_preconditions(system, &self.offsets)
+    }
+
+    fn run_test(&self, system: &DistributedSystem) -> Result<HolisticResponseTimes, SchedError> {
+        response_times(system, &self.offsets, false)
+    }
+}
+
+impl SchedAnalysis<HolisticResponseTimes, &DistributedSystem> for AnalysisPrecedence {
+    fn analyzer_name(&self) -> &str { ALGORITHM_PRECEDENCE }
+
+    fn check_preconditions(&self, system: &&DistributedSystem) -> Result<(), SchedError> {
+        check_preconditions(system, &self.offsets)
+    }
+
+    fn run_test(&self, system: &DistributedSystem) -> Result<HolisticResponseTimes, SchedError> {
+        response_times(system, &self.offsets, true)
+    }
+}
+
+fn check_preconditions(system: &DistributedSystem, offsets: &[Vec<Time>]) -> Result<(), SchedError> {
+    system.check_preconditions()?;
+
+    for (n, node) in system.nodes.iter().enumerate() {
+        if node.algorithm != SchedulingAlgorithm::FixedPriority {
+            return Err(SchedError::Precondition(Some(
+                anyhow::format_err!("node {n} must use fixed priority scheduling."))));
+        } else if !RTUtils::constrained_deadlines(&node.tasks) {
+            return Err(SchedError::constrained_deadlines().with_context(&format!("node {n}")));
+        }
+    }
+
+    for (k, network) in system.networks.iter().enumerate() {
+        if !RTUtils::constrained_deadlines(&network.messages) {
+            return Err(SchedError::constrained_deadlines().with_context(&format!("network {k}")));
+        }
+    }
+
+    if !offsets.is_empty() && offsets.len() != system.flows.len() {
+        return Err(SchedError::Precondition(Some(
+            anyhow::format_err!("the static offsets of every flow must be given."))));
+    }
+
+    for (f, flow) in system.flows.iter().enumerate() {
+        let period = system.step_task(flow.steps[0]).unwrap().period;
+
+        if flow.steps.iter().any(|&step| system.step_task(step).unwrap().period != period) {
+            return Err(SchedError::Precondition(Some(
+                anyhow::format_err!("the steps of flow {f} must share its period."))));
+        } else if offsets.get(f).is_some_and(|offsets| offsets.len() != flow.steps.len()) {
+            return Err(SchedError::Precondition(Some(
+                anyhow::format_err!("the static offset of every step of flow {f} must be given."))));
+        } else if offsets.get(f).is_some_and(|offsets| offsets.iter().any(|offset| *offset < Time::zero())) {
+            return Err(SchedError::Precondition(Some(
+                anyhow::format_err!("the static offsets of flow {f} must be non-negative."))));
+        }
+    }
+
+    Ok(())
+}
+
+// Offsets, jitters and response times of the steps of the transactions, all
+// measured from the activation of their transaction.
+struct State<'a> {
+    system: &'a DistributedSystem,
+    transactions: Vec<Transaction>,
+    phases: Vec<Vec<Time>>,
+    jitters: Vec<Vec<Time>>,
+    precedence: bool,
+}
+
+fn response_times(system: &DistributedSystem, offsets: &[Vec<Time>], precedence: bool) -> Result<HolisticResponseTimes, SchedError> {
+    let transactions = system.transactions(offsets);
+    let zeros: Vec<Vec<Time>> = transactions.iter()
+        .map(|transaction| vec![Time::zero(); transaction.steps.len()])
+        .collect();
+
+    let mut state = State { system, transactions, phases: zeros.clone(), jitters: zeros.clone(), precedence };
+    let mut responses = zeros;
+
+    loop {
+        // Each step is released when its predecessor completes, but never
+        // before its static offset.
+        for (a, transaction) in state.transactions.iter().enumerate() {
+            for b in 0..transaction.steps.len() {
+                let (phase, latest) =
+                    if b == 0 {
+                        (transaction.offsets[0], transaction.offsets[0])
+                    } else {
+                        (transaction.offsets[b].max(state.phases[a][b - 1]), transaction.offsets[b].max(responses[a][b - 1]))
+                    };
+
+                state.phases[a][b] = phase;
+                state.jitters[a][b] = latest - phase;
+            }
+        }
+
+        let next = state.transactions.iter().enumerate()
+            .map(|(a, transaction)| (0..transaction.steps.len())
+                .map(|b| state.response_time(a, b)
+                    .map_err(|err| err.with_context(&step_name(transaction.steps[b]))))
+                .collect::<Result<Vec<_>, _>>())
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if next == responses {
+            break;
+        }
+
+        responses = next;
+    }
+
+    let mut nodes: Vec<_> = system.nodes.iter()
+        .map(|node| vec![Time::zero(); node.tasks.len()])
+        .collect();
+    let mut networks: Vec<_> = system.networks.iter()
+        .map(|network| vec![Time::zero(); network.messages.len()])
+        .collect();
+
+    for (transaction, responses) in state.transactions.iter().zip(&responses) {
+        for (&step, &response) in transaction.steps.iter().zip(responses) {
+            match step {
+                Step::Task { node, task } => nodes[node][task] = response,
+                Step::Message { network, message } => networks[network][message] = response,
+            }
+        }
+    }
+
+    let flows: Vec<_> = system.flows.iter().zip(&responses)
+        .map(|(flow, responses)| ChainLatency { hops: responses.clone(), deadline: flow.deadline })
+        .collect();
+
+    match flows.iter().position(|flow| !flow.is_met()) {
+        Some(f) => Err(SchedError::NonSchedulable(Some(anyhow::format_err!(
+            "flow {f} has an end-to-end latency of {}ns, past its deadline of {}ns.",
+            flows[f].latency().as_nanos(), flows[f].deadline.as_nanos())))),
+        None => Ok(HolisticResponseTimes { nodes, networks, flows }),
+    }
+}
+
+fn step_name(step: Step) -> String {
+    match step {
+        Step::Task { node, task } => format!("task {task} of node {node}"),
+        Step::Message { network, message } => format!("message {message} of network {network}"),
+    }
+}
+
+// Node or network of the step, and its priority on it, lower is higher.
+fn resource(step: Step) -> (bool, usize, usize) {
+    match step {
+        Step::Task { node, task } => (true, node, task),
+        Step::Message { network, message } => (false, network, message),
+    }
+}
+
+// Non-negative remainder of the division.
+fn modulo(time: Time, period: Time) -> Time {
+    let remainder = time % period;
+
+    if remainder < Time::zero() { remainder + period } else { remainder }
+}
+
+impl State<'_> {
+    fn task(&self, i: usize, j: usize) -> &RTTask {
+        self.system.step_task(self.transactions[i].steps[j]).unwrap()
+    }
+
+    // Phase of step j relative to the critical instant started by step k of
+    // the same transaction i: φ_ijk = T_i - (Φ_ik + J_ik - Φ_ij) mod T_i.
+    fn phase(&self, i: usize, j: usize, k: usize) -> Time {
+        let period = self.transactions[i].period;
+
+        period - modulo(self.phases[i][k] + self.jitters[i][k] - self.phases[i][j], period)
+    }
+
+    // Activations of step j in [0, t), including the earlier ones delayed by
+    // their jitter, when step k starts the critical instant:
+    //   floor((J_ij + φ_ijk) / T_i) + ceil((t - φ_ijk) / T_i)
+    // and the index of the first one.
+    fn activations(&self, i: usize, j: usize, k: usize, t: Time) -> (i64, i64) {
+        let period = self.transactions[i].period;
+        let phase = self.phase(i, j, k);
+
+        let first = 1 - ((self.jitters[i][j] + phase) / period).floor() as i64;
+        let last = ((t - phase) / period).ceil() as i64;
+
+        (first, last)
+    }
+
+    // Equation 5 [1]: interference of the steps hp of transaction i in [0, t),
+    // when step k starts the critical instant.
+    fn interference(&self, i: usize, hp: &[usize], k: usize, t: Time) -> Time {
+        hp.iter()
+            .map(|&j| {
+                let (first, last) = self.activations(i, j, k, t);
+                self.task(i, j).wcet * (last - first + 1).max(0) as f64
+            })
+            .sum()
+    }
+
+    // Interference of the steps hp of the transaction a under analysis, when
+    // step c starts the critical instant. With the precedence relations, the
+    // job of each step of the same activation as the p-th job of step b is
+    // excluded [2].
+    fn own_interference(&self, a: usize, b: usize, hp: &[usize], c: usize, p: i64, t: Time) -> Time {
+        let period = self.transactions[a].period;
+        let activation = |j: usize| self.phase(a, j, c) - self.phases[a][j];
+
+        hp.iter()
+            .map(|&j| {
+                let (first, last) = self.activations(a, j, c, t);
+                let mut jobs = (last - first + 1).max(0);
+
+                if self.precedence {
+                    let same = p + ((activation(b) - activation(j)) / period).round() as i64;
+
+                    if first <= same && same <= last {
+                        jobs -= 1;
+                    }
+                }
+
+                self.task(a, j).wcet * jobs as f64
+            })
+            .sum()
+    }
+
+    // Worst-case interference of each other transaction: the largest among
+    // the critical instants started by its higher priority steps.
+    fn others_interference(&self, hp: &[Vec<usize>], a: usize, t: Time) -> Time {
+        hp.iter().enumerate()
+            .filter(|(i, _)| *i != a)
+            .map(|(i, hp)| {
+                hp.iter()
+                    .map(|&k| self.interference(i, hp, k, t))
+                    .max()
+                    .unwrap_or(Time::zero())
+            })
+            .sum()
+    }
+
+    // Equations 6 to 8 [1]: response time of step b of transaction a, from the
+    // activation of the transaction, stopped past its deadline.
+    fn response_time(&self, a: usize, b: usize) -> Result<Time, SchedError> {
+        let step = self.task(a, b);
+        let (is_node, index, priority) = resource(self.transactions[a].steps[b]);
+        let period = self.transactions[a].period;
+
+        // Higher priority steps of each transaction on the same resource.
+        let hp: Vec<Vec<usize>> = self.transactions.iter()
+            .map(|transaction| (0..transaction.steps.len())
+                .filter(|&j| {
+                    let (other_is_node, other_index, other_priority) = resource(transaction.steps[j]);
+                    other_is_node == is_node && other_index == index && other_priority < priority
+                })
+                .collect())
+            .collect();
+
+        let blocking =
+            if is_node {
+                Time::zero()
+            } else {
+                self.system.networks[index].messages.iter()
+                    .map(|message| message.wcet)
+                    .max()
+                    .unwrap_or(Time::zero())
+            };
+
+        // The busy period is bounded for utilizations below one, since the
+        // activations of each step in [0, t) are fewer than (J + t) / T + 2.
+        let (utilization, backlog) =
+            hp.iter().enumerate()
+                .flat_map(|(i, hp)| hp.iter().map(move |&j| (i, j)))
+                .chain(std::iter::once((a, b)))
+                .fold((0.0, blocking), |(utilization, backlog), (i, j)| {
+                    let task = self.task(i, j);
+                    (utilization + task.wcet / task.period,
+                     backlog + task.wcet * (self.jitters[i][j] / task.period + 2.0))
+                });
+
+        if utilization >= 1.0 {
+            return Err(SchedError::NonSchedulable(Some(
+                anyhow::format_err!("the utilization of {utilization} exceeds the resource."))));
+        }
+
+        let busy_period_limit = backlog / (1.0 - utilization);
+        let mut response_time = Time::zero();
+
+        for c in hp[a].iter().copied().chain(std::iter::once(b)) {
+            let mut with_b = hp[a].clone();
+            with_b.push(b);
+
+            let busy_period =
+                fixpoint_search_with_limit(
+                    blocking + step.wcet,
+                    busy_period_limit + Time::one(),
+                    |length: &Time| blocking + self.interference(a, &with_b, c, *length)
+                        + self.others_interference(&hp, a, *length)
+                );
+
+            let phase = self.phase(a, b, c);
+            let (first, _) = self.activations(a, b, c, Time::zero());
+            let last = ((busy_period - phase) / period).ceil() as i64;
+
+            for p in first..=last {
+                let release = phase + period * (p - 1) as f64;
+                let own_jobs = step.wcet * (p - first + 1) as f64;
+
+                let completion =
+                    fixpoint_search_with_limit(
+                        blocking + own_jobs,
+                        step.deadline - self.phases[a][b] + release + Time::one(),
+                        |completion: &Time| blocking + own_jobs
+                            + self.own_interference(a, b, &hp[a], c, p, *completion)
+                            + self.others_interference(&hp, a, *completion)
+                    );
+
+                let response = completion - release + self.phases[a][b];
+                if response > step.deadline {
+                    return Err(SchedError::NonSchedulable(Some(
+                        anyhow::format_err!("the step misses its deadline."))));
+                }
+
+                response_time = response_time.max(response);
+            }
+        }
+
+        Ok(response_time)
+    }
+}
+
+#[test]
+fn offset_analysis() {
+    use super::holistic94;
+
+    let at = |t: u64| Time::nanos(t as f64);
+
+    // Two steps of a transaction on the same node, the first of higher
+    // priority, and a message sent afterwards.
+    let system = DistributedSystem {
+        nodes: vec![
+            Node {
+                tasks: vec![RTTask::new_ns(3, 20, 20), RTTask::new_ns(3, 20, 20)],
+                algorithm: SchedulingAlgorithm::FixedPriority,
+            },
+        ],
+        networks: vec![
+            Network { messages: vec![RTTask::new_ns(1, 20, 20)] },
+        ],
+        flows: vec![
+            Flow {
+                steps: vec![
+                    Step::Task { node: 0, task: 0 },
+                    Step::Task { node: 0, task: 1 },
+                    Step::Message { network: 0, message: 0 },
+                ],
+                deadline: at(20),
+            },
+        ],
+    };
+
+    // The holistic analysis lets the first step interfere with the second one
+    // released at its completion, 3 + 3 + 3.
+    let result = holistic94::Analysis.is_schedulable(&system).unwrap();
+    assert_eq!(result.flows[0].hops, vec![at(3), at(9), at(11)]);
+
+    // The offsets account for the first step releasing the second one, which
+    // then suffers no interference.
+    let result = Analysis { offsets: vec![] }.is_schedulable(&system).unwrap();
+    assert_eq!(result.flows[0].hops, vec![at(3), at(6), at(8)]);
+
+    // A static offset past the completion of the first step removes its
+    // jitter: the second step completes at 5 + 3.
+    let result = Analysis { offsets: vec![vec![at(0), at(5), at(0)]] }.is_schedulable(&system).unwrap();
+    assert_eq!(result.nodes[0], vec![at(3), at(8)]);
+
+    assert!(Analysis { offsets: vec![vec![at(0)]] }.is_schedulable(&system).is_err());
+
+    // The lower priority step releases the higher priority one, which cannot
+    // interfere with it, but only the precedence relations rule it out.
+    let mut system = system;
+    system.flows[0].steps.swap(0, 1);
+
+    let result = Analysis { offsets: vec![] }.is_schedulable(&system).unwrap();
+    assert_eq!(result.flows[0].hops[..2], [at(6), at(9)]);
+
+    let result = AnalysisPrecedence { offsets: vec![] }.is_schedulable(&system).unwrap();
+    assert_eq!(result.flows[0].hops[..2], [at(3), at(6)]);
+}
@@ -0,0 +1,294 @@
+//! ## End-to-End Latency of Task Chains
+//!
+//! A chain of tasks, or transaction, processes data through a sequence of
+//! periodic tasks, each reading the latest output of the previous one from a
+//! shared buffer, and must deliver it within an end-to-end deadline. Since each
+//! task samples its input when released, the data may wait up to a period
+//! before being read, and up to the task's response time before being written,
+//! thus the latency of a chain is bounded by the sum of the periods and the
+//! response times of its tasks \[1\]. The response times are those of any
+//! response time analysis of the whole taskset.
+//!
+//! #### Model:
+//! - Periodic/Sporadic Task model
+//! - Chains communicating through shared buffers (implicit communication)
+//! - The model of the response time analysis
+//!
+//! #### Preconditions:
+//! - Non-empty chains of tasks of the taskset
+//! - The preconditions of the response time analysis
+//!
+//! #### Implements:
+//! - [`AnalysisEndToEnd::is_schedulable`] \
+//!   | the complexity of the response time analysis, plus linear *O(n)* per
+//!   | chain
+//! - [`chain_latencies`] \
+//!   | linear *O(n)* per chain, given the response times
+//! - [`AnalysisEndToEnd::wcet_sensitivity`] \
+//!   | Largest increase of the WCET of each task of the chains which keeps
+//!   | every chain within its deadline, found by bisection. \
+//!   | \
+//!   | the complexity of the analysis, times *log(D)* per task
+//!
+//! ---
+//! #### References:
+//! 1. A. Davare, Q. Zhu, M. Di Natale, C. Pinello, S. Kanajan, and A.
+//!    Sangiovanni-Vincentelli, “Period Optimization for Hard Real-time
+//!    Distributed Automotive Systems,” in 2007 44th ACM/IEEE Design Automation
+//!    Conference, June 2007, pp. 278–283. doi: 10.1145/1278480.1278553.
+
+use crate::prelude::*;
+
+/// Chain of tasks with an end-to-end deadline.
+#[derive(Debug, Clone)]
+pub struct TaskChain {
+    /// Indices of the tasks of the chain, from the first to the last.
+    pub tasks: Vec<usize>,
+    pub deadline: Time,
+}
+
+/// Worst-case latency of a [`TaskChain`], hop by hop.
+#[derive(Debug, Clone)]
+#[derive(PartialEq)]
+pub struct ChainLatency {
+    /// Latency from the start of the chain to the output of each of its tasks.
+    pub hops: Vec<Time>,
+    pub deadline: Time,
+}
+
+impl ChainLatency {
+    /// End-to-end latency of the chain.
+    pub fn latency(&self) -> Time {
+        self.hops.last().copied().unwrap_or(Time::zero())
+    }
+
+    /// Slack left to the end-to-end deadline after each hop.
+    pub fn slack_along(&self) -> impl Iterator<Item = Time> + '_ {
+        self.hops.iter().map(|&latency| self.deadline - latency)
+    }
+
+    /// Slack left to the end-to-end deadline, negative if missed.
+    pub fn slack(&self) -> Time {
+        self.deadline - self.latency()
+    }
+
+    pub fn is_met(&self) -> bool {
+        self.latency() <= self.deadline
+    }
+}
+
+/// End-to-end latencies of the chains, given the response times of the
+/// taskset's tasks. Equation 2 \[1\].
+pub fn chain_latencies(taskset: &[RTTask], response_times: &[Time], chains: &[TaskChain]) -> Vec<ChainLatency> {
+    chains.iter()
+        .map(|chain| {
+            let hops =
+                chain.tasks.iter()
+                    .scan(Time::zero(), |latency, &i| {
+                        *latency = *latency + taskset[i].period + response_times[i];
+                        Some(*latency)
+                    })
+                    .collect();
+
+            ChainLatency { hops, deadline: chain.deadline }
+        })
+        .collect()
+}
+
+/// Sensitivity of the end-to-end latencies to the WCET of a task.
+#[derive(Debug, Clone)]
+#[derive(PartialEq)]
+pub struct WcetSensitivity {
+    pub task: usize,
+    /// Largest increase of the task's WCET, alone, which keeps the taskset
+    /// schedulable and every chain within its deadline, with a resolution of
+    /// [`Time::one`].
+    pub max_increase: Time,
+}
+
+/// End-to-End Latency of Task Chains, Davare et al. 2007 \[1\]
+///
+/// Refer to the [module](`self`) level documentation.
+///
+/// The `analysis` gives the response times of the tasks.
+///
+/// Returns:
+/// - Worst-case latency of each chain.
+pub struct AnalysisEndToEnd<A> {
+    pub chains: Vec<TaskChain>,
+    pub analysis: A,
+}
+
+impl<A> SchedAnalysis<Vec<ChainLatency>, &[RTTask]> for AnalysisEndToEnd<A>
+    where
+        A: for<'a> SchedAnalysis<Vec<Time>, &'a [RTTask]>,
+{
+    fn analyzer_name(&self) -> &str { self.analysis.analyzer_name() }
+
+    fn check_preconditions(&self, taskset: &&[RTTask]) -> Result<(), SchedError> {
+        for (c, chain) in self.chains.iter().enumerate() {
+            if chain.tasks.is_empty() {
+                return Err(SchedError::Precondition(Some(
+                    anyhow::format_err!("chain {c} has no tasks."))));
+            } else if chain.tasks.iter().any(|&i| i >= taskset.len()) {
+                return Err(SchedError::Precondition(Some(
+                    anyhow::format_err!("chain {c} has tasks which are not in the taskset."))));
+            }
+        }
+
+        self.analysis.check_preconditions(taskset)
+    }
+
+    fn run_test(&self, taskset: &[RTTask]) -> Result<Vec<ChainLatency>, SchedError> {
+        let response_times = self.analysis.run_test(taskset)?;
+        let latencies = chain_latencies(taskset, &response_times, &self.chains);
+
+        match latencies.iter().position(|chain| !chain.is_met()) {
+            Some(c) => Err(SchedError::NonSchedulable(Some(anyhow::format_err!(
+                "chain {c} has an end-to-end latency of {}ns, past its deadline of {}ns.",
+                latencies[c].latency().as_nanos(), latencies[c].deadline.as_nanos())))),
+            None => Ok(latencies),
+        }
+    }
+}
+
+impl<A> AnalysisEndToEnd<A>
+    where
+        A: for<'a> SchedAnalysis<Vec<Time>, &'a [RTTask]>,
+{
+    /// Sensitivity of the chains to the WCET of each of their tasks, ranked
+    /// from the most critical one, i.e. the one whose WCET can grow the least.
+    /// The chains must meet their deadlines with the given WCETs.
+    pub fn wcet_sensitivity(&self, taskset: &[RTTask]) -> Result<Vec<WcetSensitivity>, SchedError> {
+        self.check_preconditions(&taskset)?;
+        self.run_test(taskset)?;
+
+        let mut tasks: Vec<usize> = self.chains.iter()
+            .flat_map(|chain| chain.tasks.iter().copied())
+            .collect();
+        tasks.sort_unstable();
+        tasks.dedup();
+
+        let mut sensitivities: Vec<_> = tasks.into_iter()
+            .map(|task| WcetSensitivity { task, max_increase: self.max_wcet_increase(taskset, task) })
+            .collect();
+
+        sensitivities.sort_by_key(|sensitivity| sensitivity.max_increase);
+        Ok(sensitivities)
+    }
+
+    // Bisection on the increase, between a feasible one and an infeasible one:
+    // no WCET beyond the deadline is feasible.
+    fn max_wcet_increase(&self, taskset: &[RTTask], task: usize) -> Time {
+        let is_feasible = |increase: u64| {
+            let mut taskset = taskset.to_vec();
+            taskset[task].wcet = taskset[task].wcet + Time::one() * increase as f64;
+
+            self.check_preconditions(&taskset.as_slice()).is_ok() && self.run_test(&taskset).is_ok()
+        };
+
+        let (mut feasible, mut infeasible) = (0, ((taskset[task].deadline - taskset[task].wcet) / Time::one()).floor() as u64 + 1);
+
+        while infeasible - feasible > 1 {
+            let increase = feasible + (infeasible - feasible) / 2;
+
+            if is_feasible(increase) {
+                feasible = increase;
+            } else {
+                infeasible = increase;
+            }
+        }
+
+        Time::one() * feasible as f64
+    }
+}
+
+impl<A: SchedProcessors> SchedProcessors for AnalysisEndToEnd<A> {
+    fn num_processors(&self) -> u64 { self.analysis.num_processors() }
+}
+
+impl<A: SchedPlatform> SchedPlatform for AnalysisEndToEnd<A> {
+    fn supports(&self, platform: &Platform) -> bool { self.analysis.supports(platform) }
+}
+
+#[test]
+fn end_to_end_latency() {
+    use crate::algorithms::full_preemption::uniprocessor::fixed_priority::rta86;
+
+    let at = |t: u64| Time::nanos(t as f64);
+
+    // Response times 1, 3 and 4.
+    let taskset = [
+        RTTask::new_ns(1, 4, 4),
+        RTTask::new_ns(2, 6, 8),
+        RTTask::new_ns(1, 10, 10),
+    ];
+
+    let short = TaskChain { tasks: vec![0, 2], deadline: at(20) };
+    let long = TaskChain { tasks: vec![0, 1, 2], deadline: at(25) };
+
+    let latencies = chain_latencies(&taskset, &[at(1), at(3), at(4)], &[short.clone(), long.clone()]);
+    assert_eq!(latencies[0].hops, vec![at(5), at(19)]);
+    assert_eq!(latencies[0].slack(), at(1));
+    assert!(latencies[0].is_met());
+
+    assert_eq!(latencies[1].slack_along().collect::<Vec<_>>(), vec![at(20), at(9), -at(5)]);
+    assert!(!latencies[1].is_met());
+
+    let analysis = |chains| AnalysisEndToEnd { chains, analysis: rta86::Analysis };
+    assert_eq!(analysis(vec![short.clone()]).is_schedulable(&taskset).unwrap(), latencies[..1]);
+
+    let err = analysis(vec![short, long]).is_schedulable(&taskset).unwrap_err();
+    assert!(format!("{err:#}").contains("chain 1"));
+
+    // Response times 1, 2 and 4, thus the chain's latency is 17. Task 0 can
+    // grow by 1 before the latency 12 + R_0 + R_2 exceeds 21, while task 2 can
+    // grow by 2 before the processor is fully loaded.
+    let harmonic = [RTTask::new_ns(1, 4, 4), RTTask::new_ns(1, 8, 8), RTTask::new_ns(2, 8, 8)];
+    let chain = TaskChain { tasks: vec![0, 2], deadline: at(21) };
+    let sensitivities = analysis(vec![chain]).wcet_sensitivity(&harmonic).unwrap();
+    assert_eq!(sensitivities, vec![
+        WcetSensitivity { task: 0, max_increase: at(1) },
+        WcetSensitivity { task: 2, max_increase: at(2) },
+    ]);
+
+    let empty = TaskChain { tasks: vec![], deadline: at(10) };
+    assert!(analysis(vec![empty]).is_schedulable(&taskset).is_err());
+}
+
+#[test]
+fn wcet_sensitivity() {
+    use crate::algorithms::full_preemption::uniprocessor::fixed_priority::rta86;
+
+    let at = |t: u64| Time::nanos(t as f64);
+    let analysis = |chains| AnalysisEndToEnd { chains, analysis: rta86::Analysis };
+
+    // Response times 1, 2 and 4. Task 0 is in both chains but ranked once,
+    // while task 1 is in none.
+    let taskset = [RTTask::new_ns(1, 4, 4), RTTask::new_ns(1, 8, 8), RTTask::new_ns(2, 8, 8)];
+    let chains = vec![
+        TaskChain { tasks: vec![2, 0], deadline: at(21) },
+        TaskChain { tasks: vec![0], deadline: at(6) },
+    ];
+    let sensitivities = analysis(chains.clone()).wcet_sensitivity(&taskset).unwrap();
+    assert_eq!(sensitivities.iter().map(|sensitivity| sensitivity.task).collect::<Vec<_>>(), vec![0, 2]);
+
+    // The largest increases keep the chains within their deadlines, one more
+    // nanosecond does not.
+    for sensitivity in sensitivities {
+        let mut taskset = taskset.to_vec();
+        taskset[sensitivity.task].wcet = taskset[sensitivity.task].wcet + sensitivity.max_increase;
+        assert!(analysis(chains.clone()).is_schedulable(&taskset).is_ok());
+
+        taskset[sensitivity.task].wcet = taskset[sensitivity.task].wcet + Time::one();
+        assert!(analysis(chains.clone()).is_schedulable(&taskset).is_err());
+    }
+
+    // The latency of 17 of the chain leaves no room at its deadline.
+    let tight = TaskChain { tasks: vec![0, 2], deadline: at(17) };
+    assert!(analysis(vec![tight]).wcet_sensitivity(&taskset).unwrap().iter()
+        .all(|sensitivity| sensitivity.max_increase == Time::zero()));
+
+    let missed = TaskChain { tasks: vec![0, 2], deadline: at(16) };
+    assert!(analysis(vec![missed]).wcet_sensitivity(&taskset).is_err());
+}
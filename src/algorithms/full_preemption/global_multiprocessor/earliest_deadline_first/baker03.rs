@@ -70,6 +70,16 @@ impl SchedAnalysis<(), &[RTTask]> for Analysis {
     }
 }
 
+impl SchedProcessors for Analysis {
+    fn num_processors(&self) -> u64 { self.num_processors }
+}
+
+impl SchedPlatform for Analysis {
+    fn supports(&self, platform: &Platform) -> bool {
+        platform.matches(PlatformModel::GlobalMultiprocessor, SchedulingAlgorithm::EarliestDeadlineFirst, self.num_processors)
+    }
+}
+
 #[test]
 // Example in Section 3.3 [2]
 fn gfb_bak_example() {
@@ -6,6 +6,7 @@
 //!
 //! #### Preconditions:
 //! - Constrained Deadlines
+//! - At least one processor
 //!
 //! #### Implements:
 //! - [`Analysis::is_schedulable`] \
@@ -14,6 +15,11 @@
 //!   | Worse runtime than [`Analysis::is_schedulable`], checks unnecessary timesteps. \
 //!   | \
 //!   | pseudo-polynomial complexity
+//! - [`AnalysisCapped::is_schedulable`] \
+//!   | Give up when the arrival times to check exceed a given bound. \
+//!   | Quick density and utilization checks before computing the bound. \
+//!   | \
+//!   | pseudo-polynomial complexity
 //!
 //! ---
 //! #### References:
@@ -22,6 +28,12 @@
 //!    Tucson, AZ, USA: IEEE, Dec. 2007, pp. 119–128. doi: 10.1109/RTSS.2007.35.
 
 use crate::prelude::*;
+use crate::algorithms::full_preemption::global_multiprocessor::earliest_deadline_first::gbf03;
+use crate::utils::analysis_points::{dbf_points, merge_points};
+use crate::utils::interference::{Baruah07, InterferenceModel};
+use crate::utils::interference_block::*;
+use crate::utils::numeric_validation::VALIDATION_TOLERANCE;
+use crate::utils::taskset_soa::*;
 
 const ALGORITHM: &str = "Multiprocessor EDF (Baruah 2007)";
 
@@ -36,7 +48,7 @@ impl SchedAnalysis<(), &[RTTask]> for Analysis {
     fn analyzer_name(&self) -> &str { ALGORITHM }
 
     fn check_preconditions(&self, taskset: &&[RTTask]) -> Result<(), SchedError> {
-        check_preconditions(taskset)
+        check_preconditions(taskset, self.num_processors)
     }
 
     fn run_test(&self, taskset: &[RTTask]) -> Result<(), SchedError> {
@@ -48,25 +60,21 @@ impl SchedAnalysis<(), &[RTTask]> for Analysis {
         // D <= T, for task i where to compute the DBFs. The values change in the
         // range [0 + aT, C + aT] and at {D + aT} for all integers a. The union of
         // these ranges is the points where we actually need to perform the test.
-        let schedulable =
-            taskset.iter().enumerate().all(|(k, task_k)| {
-                let ak_upperbound = arrival_k_upperbound(taskset, task_k, self.num_processors).ceil();
-
-                (0 ..= ak_upperbound.ceil().as_nanos() as usize)
-                    .map(|arrival_k| Time::nanos(arrival_k as f64))
-                    .filter(|arrival_k| {
-                        // Perform the test only where DBF/DBF' values change.
-                        taskset.iter().any(|task_i| {
-                            let interval = *arrival_k + task_k.deadline;
-                            let modulus = interval % task_i.period;
-
-                            modulus <= task_i.wcet || modulus == task_i.deadline
-                        })
-                    })
-                    .all(|arrival_k| baruah_test_single(taskset, k, task_k, arrival_k, self.num_processors))
-            });
+        let ak_upperbounds = arrival_k_upperbounds(taskset, self.num_processors);
 
-        SchedError::result_from_schedulable(schedulable)
+        SchedError::result_from_schedulable(
+            baruah_test_filtered(taskset, &ak_upperbounds, self.num_processors)
+        )
+    }
+}
+
+impl SchedProcessors for Analysis {
+    fn num_processors(&self) -> u64 { self.num_processors }
+}
+
+impl SchedPlatform for Analysis {
+    fn supports(&self, platform: &Platform) -> bool {
+        platform.matches(PlatformModel::GlobalMultiprocessor, SchedulingAlgorithm::EarliestDeadlineFirst, self.num_processors)
     }
 }
 
@@ -81,83 +89,210 @@ impl SchedAnalysis<(), &[RTTask]> for AnalysisSimple {
     fn analyzer_name(&self) -> &str { ALGORITHM }
 
     fn check_preconditions(&self, taskset: &&[RTTask]) -> Result<(), SchedError> {
-        check_preconditions(taskset)
+        check_preconditions(taskset, self.num_processors)
     }
 
     fn run_test(&self, taskset: &[RTTask]) -> Result<(), SchedError> {
+        let tasks = TasksetSoA::new(taskset);
 
         let schedulable =
-            taskset.iter().enumerate().all(|(k, task_k)| {
-                let ak_upperbound = arrival_k_upperbound(taskset, task_k, self.num_processors).ceil();
+            arrival_k_upperbounds(taskset, self.num_processors).into_iter().enumerate()
+            .all(|(k, ak_upperbound)| {
+                let arrivals = (0 ..= ak_upperbound.as_nanos() as usize)
+                    .map(|arrival_k| Time::nanos(arrival_k as f64));
 
-                (0 ..= ak_upperbound.ceil().as_nanos() as usize)
-                    .map(|arrival_k| Time::nanos(arrival_k as f64))
-                    .all(|arrival_k| baruah_test_single(taskset, k, task_k, arrival_k, self.num_processors))
+                baruah_test(&tasks, k, arrivals, self.num_processors)
             });
 
         SchedError::result_from_schedulable(schedulable)
     }
 }
 
-fn check_preconditions(taskset: &[RTTask]) -> Result<(), SchedError> {
-    if !RTUtils::constrained_deadlines(taskset) {
-        Err(SchedError::constrained_deadlines())
-    } else {
-        Ok(())
+impl SchedProcessors for AnalysisSimple {
+    fn num_processors(&self) -> u64 { self.num_processors }
+}
+
+impl SchedPlatform for AnalysisSimple {
+    fn supports(&self, platform: &Platform) -> bool {
+        platform.matches(PlatformModel::GlobalMultiprocessor, SchedulingAlgorithm::EarliestDeadlineFirst, self.num_processors)
     }
 }
 
-// Section 5, Theorem 2, Equation 8 [1]
-fn baruah_test_single(taskset: &[RTTask], k: usize, task_k: &RTTask, arrival_k: Time, num_processors: u64) -> bool {
+/// Multiprocessor EDF - Baruah 2007 \[1\] \
+/// Give up when the arrival times to check exceed a given bound.
+///
+/// Refer to the [module](`self`) level documentation.
+///
+/// The arrival time upper bound of Equation 9 grows unboundedly as the total
+/// utilization approaches the number of processors. Rather than scanning
+/// all the arrival times up to the bound, the test returns
+/// [`SchedError::Inconclusive`] when the bound exceeds `max_arrival_time`.
+///
+/// Before computing the bounds, tasksets with a task of density greater than
+/// one or with total utilization greater than the number of processors are
+/// rejected, and tasksets passing the total density test of
+/// [`gbf03::AnalysisSporadic`] are accepted.
+pub struct AnalysisCapped {
+    pub num_processors: u64,
+    pub max_arrival_time: Time,
+}
 
-    let interferences_1: Vec<_> = taskset.iter().enumerate()
-        .map(|(i, task_i)| interference_1(i, task_i, k, task_k, arrival_k))
-        .collect();
+impl SchedAnalysis<(), &[RTTask]> for AnalysisCapped {
+    fn analyzer_name(&self) -> &str { ALGORITHM }
 
-    let mut interferences_diff: Vec<_> = taskset.iter().enumerate()
-        .zip(interferences_1.iter())
-        .map(|((i, task_i), i1)| interference_2(i, task_i, k, task_k, arrival_k) - *i1)
-        .collect();
-    interferences_diff.sort_unstable();
+    fn check_preconditions(&self, taskset: &&[RTTask]) -> Result<(), SchedError> {
+        check_preconditions(taskset, self.num_processors)
+    }
 
-    let i1_sum = interferences_1.into_iter().sum::<Time>();
-    let idiff_sum = interferences_diff.into_iter()
-        .rev().take((num_processors - 1) as usize).sum::<Time>();
+    fn run_test(&self, taskset: &[RTTask]) -> Result<(), SchedError> {
+        if quick_checks(taskset, self.num_processors)? {
+            return Ok(());
+        }
+
+        let ak_upperbounds = arrival_k_upperbounds(taskset, self.num_processors);
+
+        if let Some(ak_upperbound) = ak_upperbounds.iter().find(|ak| **ak > self.max_arrival_time) {
+            return Err(SchedError::Inconclusive(Some(anyhow::format_err!(
+                "arrival times upperbound of {}ns exceeds the cap of {}ns.",
+                ak_upperbound.as_nanos(), self.max_arrival_time.as_nanos()
+            ))));
+        }
+
+        SchedError::result_from_schedulable(
+            baruah_test_filtered(taskset, &ak_upperbounds, self.num_processors)
+        )
+    }
+}
 
-    i1_sum + idiff_sum <= num_processors as f64 * (arrival_k + task_k.deadline - task_k.wcet)
+impl SchedProcessors for AnalysisCapped {
+    fn num_processors(&self) -> u64 { self.num_processors }
 }
 
-// Section 2 [1]
-fn demand_bound_function(task: &RTTask, interval: Time) -> Time {
-    Time::max(Time::zero(), (((interval - task.deadline) / task.period).floor() + 1.0) * task.wcet)
+impl SchedPlatform for AnalysisCapped {
+    fn supports(&self, platform: &Platform) -> bool {
+        platform.matches(PlatformModel::GlobalMultiprocessor, SchedulingAlgorithm::EarliestDeadlineFirst, self.num_processors)
+    }
 }
 
-// Section 6, Equation 3 [1]
-fn interference_1(i: usize, task_i: &RTTask, k: usize, task_k: &RTTask, arrival_k: Time) -> Time {
-    let dbf = demand_bound_function(task_i, arrival_k + task_k.deadline);
-    if i != k {
-        Time::min(dbf, arrival_k + task_k.deadline - task_k.wcet)
+fn check_preconditions(taskset: &[RTTask], num_processors: u64) -> Result<(), SchedError> {
+    if !RTUtils::constrained_deadlines(taskset) {
+        Err(SchedError::constrained_deadlines())
+    } else if num_processors == 0 {
+        Err(SchedError::Precondition(Some(
+            anyhow::format_err!("the platform must have at least one processor."))))
     } else {
-        Time::min(dbf - task_k.wcet, arrival_k)
+        Ok(())
     }
 }
 
-// Section 6, Equation 4 [1]
-fn demand_bound_function_2(task: &RTTask, interval: Time) -> Time {
-    (interval / task.period).floor() * task.wcet + Time::min(task.wcet, interval % task.period)
+// Quick checks on the densities and the total utilization: returns whether the
+// taskset passes the total density test, or an error if it is surely not
+// schedulable. Otherwise, the arrival times upperbound of Equation 9 is finite,
+// unless the total utilization is (up to rounding) the number of processors.
+fn quick_checks(taskset: &[RTTask], num_processors: u64) -> Result<bool, SchedError> {
+    let total_utilization = RTUtils::total_utilization(taskset);
+    let tolerance = VALIDATION_TOLERANCE * num_processors as f64;
+
+    if taskset.iter().any(|task| task.wcet > task.deadline) {
+        Err(SchedError::NonSchedulable(Some(
+            anyhow::format_err!("a task has density greater than one."))))
+    } else if total_utilization > num_processors as f64 + tolerance {
+        Err(SchedError::NonSchedulable(Some(
+            anyhow::format_err!("total utilization exceeds the number of processors."))))
+    } else if (gbf03::AnalysisSporadic { num_processors }).run_test(taskset).is_ok() {
+        Ok(true)
+    } else if total_utilization >= num_processors as f64 - tolerance {
+        Err(SchedError::intractable())
+    } else {
+        Ok(false)
+    }
 }
 
-// Section 6, Equation 5 [1]
-fn interference_2(i: usize, task_i: &RTTask, k: usize, task_k: &RTTask, arrival_k: Time) -> Time {
-    let dbf2 = demand_bound_function_2(task_i, arrival_k + task_k.deadline);
+fn baruah_test_filtered(taskset: &[RTTask], ak_upperbounds: &[Time], num_processors: u64) -> bool {
+    let tasks = TasksetSoA::new(taskset);
 
-    if i != k {
-        Time::min(dbf2, arrival_k + task_k.deadline - task_k.wcet)
-    } else {
-        Time::min(dbf2 - task_k.wcet, arrival_k)
+    ak_upperbounds.iter().zip(taskset).enumerate().all(|(k, (ak_upperbound, task_k))| {
+        // Perform the test only where DBF/DBF' values change.
+        // A negative upperbound still allows the arrival time zero.
+        let ak_upperbound = Time::max(*ak_upperbound, Time::zero());
+        let arrivals = merge_points(
+            taskset.iter().map(|task_i| dbf_points(task_i, task_k.deadline, ak_upperbound))
+        );
+
+        baruah_test(&tasks, k, arrivals, num_processors)
+    })
+}
+
+// Section 5, Theorem 2, Equation 8 [1]
+// The arrival points are evaluated in blocks, see [`InterferenceBlock`].
+fn baruah_test<I>(tasks: &TasksetSoA, k: usize, arrivals: I, num_processors: u64) -> bool
+    where
+        I: Iterator<Item = Time>,
+{
+    let mut block = InterferenceBlock::new(tasks.len());
+    let mut arrivals_block = Vec::with_capacity(ARRIVAL_BLOCK_SIZE);
+    let mut totals = [0.0; ARRIVAL_BLOCK_SIZE];
+    let mut arrivals = arrivals.peekable();
+
+    while arrivals.peek().is_some() {
+        arrivals_block.clear();
+        arrivals_block.extend(
+            arrivals.by_ref().take(ARRIVAL_BLOCK_SIZE).map(|arrival_k| arrival_k.as_nanos())
+        );
+
+        let totals = &mut totals[..arrivals_block.len()];
+        baruah_interference_block(tasks, k, &arrivals_block, &mut block);
+        block.totals((num_processors - 1) as usize, totals);
+
+        let (wcet_k, deadline_k) = (tasks.wcets[k], tasks.deadlines[k]);
+        let schedulable = totals.iter().zip(arrivals_block.iter())
+            .all(|(&interference, &arrival_k)| {
+                interference <= num_processors as f64 * (arrival_k + deadline_k - wcet_k)
+            });
+
+        if !schedulable {
+            return false;
+        }
+    }
+
+    true
+}
+
+// Equations 3, 4, 5 [1] evaluated on a block of arrival points (in nanoseconds).
+fn baruah_interference_block(tasks: &TasksetSoA, k: usize, arrivals: &[f64], block: &mut InterferenceBlock) {
+    let (wcet_k, deadline_k) = (tasks.wcets[k], tasks.deadlines[k]);
+
+    for i in 0..tasks.len() {
+        let task_i = RTTask {
+            wcet: Time::nanos(tasks.wcets[i]),
+            deadline: Time::nanos(tasks.deadlines[i]),
+            period: Time::nanos(tasks.periods[i]),
+        };
+        let (interferences_1, interferences_diff) = block.rows_mut(i);
+
+        for (j, &arrival_k) in arrivals.iter().enumerate() {
+            let interval = arrival_k + deadline_k;
+            let dbf = Baruah07.non_carry_in(&task_i, Time::nanos(interval)).as_nanos();
+            let dbf2 = Baruah07.carry_in(&task_i, task_i.deadline, Time::nanos(interval)).as_nanos();
+
+            let (i1, i2) =
+                if i != k {
+                    (f64::min(dbf, interval - wcet_k), f64::min(dbf2, interval - wcet_k))
+                } else {
+                    (f64::min(dbf - wcet_k, arrival_k), f64::min(dbf2 - wcet_k, arrival_k))
+                };
+
+            interferences_1[j] = i1;
+            interferences_diff[j] = i2 - i1;
+        }
     }
 }
 
+fn arrival_k_upperbounds(taskset: &[RTTask], num_processors: u64) -> Vec<Time> {
+    taskset.iter()
+        .map(|task_k| arrival_k_upperbound(taskset, task_k, num_processors).ceil())
+        .collect()
+}
 
 // Section 6, Equation 9 [1]
 fn arrival_k_upperbound(taskset: &[RTTask], task_k: &RTTask, num_processors: u64) -> Time {
@@ -188,4 +323,185 @@ pub fn simple_vs_optimized() {
         Analysis { num_processors }.is_schedulable(&taskset).is_ok(),
         AnalysisSimple { num_processors }.is_schedulable(&taskset).is_ok()
     );
-}
\ No newline at end of file
+}
+
+#[test]
+pub fn capped_arrivals() {
+    use crate::algorithms::full_preemption::global_multiprocessor::hierarchical::mpr_model09::*;
+    use earliest_deadline_first::shin_easwaran_lee09;
+
+    let taskset = [
+        RTTask::new_ns(35, 90, 160),
+        RTTask::new_ns(70, 115, 160),
+        RTTask::new_ns(30, 50, 75),
+    ];
+
+    let is_error = |result: anyhow::Result<()>, expected: fn(&SchedError) -> bool| {
+        result.unwrap_err().downcast_ref::<SchedError>().is_some_and(expected)
+    };
+    let inconclusive = |err: &SchedError| matches!(err, SchedError::Inconclusive(_));
+    let non_schedulable = |err: &SchedError| matches!(err, SchedError::NonSchedulable(_));
+
+    // Baruah 2007
+    let num_processors = 2;
+
+    assert_eq!(
+        AnalysisCapped { num_processors, max_arrival_time: Time::nanos(1e9) }.is_schedulable(&taskset).is_ok(),
+        Analysis { num_processors }.is_schedulable(&taskset).is_ok(),
+    );
+    assert!(is_error(
+        AnalysisCapped { num_processors, max_arrival_time: Time::zero() }.is_schedulable(&taskset), inconclusive));
+
+    let overloaded = [
+        RTTask::new_ns(90, 100, 100),
+        RTTask::new_ns(90, 100, 100),
+        RTTask::new_ns(90, 100, 100),
+    ];
+    assert!(is_error(
+        AnalysisCapped { num_processors, max_arrival_time: Time::nanos(1e9) }.is_schedulable(&overloaded), non_schedulable));
+
+    // Accepted by the total density test, without computing the bounds.
+    let light = [RTTask::new_ns(10, 100, 100), RTTask::new_ns(20, 50, 100)];
+    assert!(AnalysisCapped { num_processors, max_arrival_time: Time::zero() }.is_schedulable(&light).is_ok());
+
+    // Total utilization equal to the number of processors, up to rounding.
+    let full = [
+        RTTask::new_ns(90, 100, 100),
+        RTTask::new_ns(70, 100, 100),
+        RTTask::new_ns(20, 100, 100),
+        RTTask::new_ns(10, 100, 100),
+        RTTask::new_ns(10, 100, 100),
+    ];
+    assert!(is_error(
+        AnalysisCapped { num_processors, max_arrival_time: Time::nanos(1e9) }.is_schedulable(&full), inconclusive));
+
+    assert!(Analysis { num_processors: 0 }.is_schedulable(&taskset).is_err());
+
+    // MPR Model, EDF Local Scheduler
+    let model = MPRModel {
+        resource: Time::nanos(75.0),
+        period: Time::nanos(50.0),
+        concurrency: 2,
+    };
+
+    assert_eq!(
+        shin_easwaran_lee09::AnalysisCapped { model: model.clone(), max_arrival_time: Time::nanos(1e9) }
+            .is_schedulable(&taskset).is_ok(),
+        shin_easwaran_lee09::Analysis { model: model.clone() }.is_schedulable(&taskset).is_ok(),
+    );
+    assert!(is_error(
+        shin_easwaran_lee09::AnalysisCapped { model: model.clone(), max_arrival_time: Time::zero() }
+            .is_schedulable(&taskset),
+        inconclusive));
+
+    // Overloaded models are rejected before the (intractable) bounds.
+    let small_model = MPRModel { resource: Time::nanos(20.0), ..model };
+    assert!(is_error(
+        shin_easwaran_lee09::AnalysisCapped { model: small_model.clone(), max_arrival_time: Time::nanos(1e9) }
+            .is_schedulable(&taskset),
+        non_schedulable));
+    assert!(is_error(shin_easwaran_lee09::Analysis { model: small_model }.is_schedulable(&taskset), non_schedulable));
+}
+
+// Enumeration of the arrival points against the scan of all the arrival times.
+#[test]
+#[ignore]
+fn bench_arrival_filter() {
+    use crate::utils::bench;
+    use crate::utils::test_tasksets::random_taskset;
+
+    let taskset = random_taskset(16, 0.5, 0);
+    let deadline_k = taskset[0].deadline;
+    let arrivals = 0..1_000_000;
+
+    let (scan_count, scan) = bench::time("scan", || arrivals.clone()
+        .filter(|arrival_k| {
+            let interval = Time::nanos(*arrival_k as f64) + deadline_k;
+
+            taskset.iter().any(|task_i| {
+                let modulus = interval % task_i.period;
+
+                modulus <= task_i.wcet || modulus == task_i.deadline
+            })
+        })
+        .count());
+
+    let end = Time::nanos((arrivals.end - 1) as f64);
+    let (points_count, points) = bench::time("enumeration", ||
+        merge_points(taskset.iter().map(|task_i| dbf_points(task_i, deadline_k, end))).count());
+
+    bench::speedup("arrival points", scan, points);
+    assert_eq!(scan_count, points_count);
+}
+
+// Blocked evaluation of the interference against the per-point one.
+#[test]
+#[ignore]
+fn bench_interference_block() {
+    use crate::utils::bench;
+    use crate::utils::interference::total_interference;
+    use crate::utils::test_tasksets::random_taskset;
+
+    let num_processors = 8;
+
+    let interference = |taskset: &[RTTask], k: usize, arrival_k: Time| {
+        let task_k = &taskset[k];
+        let interval = arrival_k + task_k.deadline;
+
+        let (non_carry_in, carry_in): (Vec<_>, Vec<_>) = taskset.iter().enumerate()
+            .map(|(i, task_i)| {
+                let dbf = Baruah07.non_carry_in(task_i, interval);
+                let dbf2 = Baruah07.carry_in(task_i, task_i.deadline, interval);
+
+                if i != k {
+                    (Time::min(dbf, interval - task_k.wcet), Time::min(dbf2, interval - task_k.wcet))
+                } else {
+                    (Time::min(dbf - task_k.wcet, arrival_k), Time::min(dbf2 - task_k.wcet, arrival_k))
+                }
+            })
+            .unzip();
+
+        total_interference(&non_carry_in, &carry_in, num_processors - 1)
+    };
+
+    for (num_tasksets, num_tasks, num_arrivals) in [(20, 32, 4096), (4, 256, 512)] {
+        let tasksets: Vec<_> = (0..num_tasksets).map(|seed| random_taskset(num_tasks, 6.0, seed)).collect();
+        let arrivals: Vec<_> = (0..num_arrivals).map(|ns| ns as f64).collect();
+
+        let (single_sum, single) = bench::time("per point interference", || {
+            let mut single_sum = 0.0;
+            for taskset in tasksets.iter() {
+                for k in 0..taskset.len() {
+                    for arrival_k in arrivals.iter() {
+                        single_sum += interference(taskset, k, Time::nanos(*arrival_k)).as_nanos();
+                    }
+                }
+            }
+
+            single_sum
+        });
+
+        let (block_sum, blocked) = bench::time("blocked interference", || {
+            let mut block_sum = 0.0;
+            for taskset in tasksets.iter() {
+                let tasks = TasksetSoA::new(taskset);
+                let mut block = InterferenceBlock::new(taskset.len());
+                let mut totals = [0.0; ARRIVAL_BLOCK_SIZE];
+
+                for k in 0..taskset.len() {
+                    for arrivals in arrivals.chunks(ARRIVAL_BLOCK_SIZE) {
+                        let totals = &mut totals[..arrivals.len()];
+                        baruah_interference_block(&tasks, k, arrivals, &mut block);
+                        block.totals(num_processors - 1, totals);
+                        block_sum += totals.iter().sum::<f64>();
+                    }
+                }
+            }
+
+            block_sum
+        });
+
+        bench::speedup(&format!("{num_tasks} tasks"), single, blocked);
+        assert!(f64::abs(single_sum - block_sum) <= 1e-9 * single_sum);
+    }
+}
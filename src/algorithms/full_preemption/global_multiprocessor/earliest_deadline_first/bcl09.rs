@@ -55,6 +55,16 @@ impl SchedAnalysis<(), &[RTTask]> for Analysis {
     }
 }
 
+impl SchedProcessors for Analysis {
+    fn num_processors(&self) -> u64 { self.num_processors }
+}
+
+impl SchedPlatform for Analysis {
+    fn supports(&self, platform: &Platform) -> bool {
+        platform.matches(PlatformModel::GlobalMultiprocessor, SchedulingAlgorithm::EarliestDeadlineFirst, self.num_processors)
+    }
+}
+
 pub fn global_earliest_deadline_first_demand(taskset: &[RTTask], k: usize, task_k: &RTTask) -> Time {
     taskset.iter().enumerate()
         .filter(|(i, _)| *i != k)
@@ -67,22 +77,6 @@ pub fn global_earliest_deadline_first_demand(taskset: &[RTTask], k: usize, task_
         .sum()
 }
 
-// Section 4 Equation 6 [1]
-pub fn workload_upperbound(interval: Time, task: &RTTask) -> Time {
-    jobs_in_interval(interval, task) * task.wcet + carry_out(interval, task)
-}
-
-// Section 4 Equation 5 [1]
-#[inline(always)]
-fn jobs_in_interval(interval: Time, task: &RTTask) -> f64 {
-    ((interval + task.laxity()) / task.period).floor()
-}
-
-#[inline(always)]
-fn carry_out(interval: Time, task: &RTTask) -> Time {
-    Time::min(task.wcet, interval + task.laxity() - jobs_in_interval(interval, task) * task.period)
-}
-
 // Section 4 Equation 8 [1]
 fn interference_edf_upperbound(by_task: &RTTask, to_task: &RTTask) -> Time {
     let task_i = by_task;
@@ -116,8 +110,11 @@ fn example_2() {
         RTTask::new_ns(1, 10, 10),
     ];
 
-    assert_eq!(workload_upperbound(taskset[1].deadline, &taskset[0]), Time::nanos(10.0));
-    assert_eq!(workload_upperbound(taskset[0].deadline, &taskset[1]), Time::nanos(1.0));
+    use crate::utils::interference::{Bcl09, InterferenceModel};
+
+    // Section 4 Equation 6 [1]
+    assert_eq!(Bcl09.carry_in(&taskset[0], taskset[0].deadline, taskset[1].deadline), Time::nanos(10.0));
+    assert_eq!(Bcl09.carry_in(&taskset[1], taskset[1].deadline, taskset[0].deadline), Time::nanos(1.0));
     // it should fail, as says in the paper, but it doesn't. Numbers seem ok
     // assert!(!ibcl_generic_work_conserving(&taskset, 2).unwrap());
 }
\ No newline at end of file
@@ -61,6 +61,31 @@ impl SchedAnalysis<(), &[RTTask]> for AnalysisPeriodic {
     }
 }
 
+impl SchedProcessors for AnalysisPeriodic {
+    fn num_processors(&self) -> u64 { self.num_processors }
+}
+
+impl SchedPlatform for AnalysisPeriodic {
+    fn supports(&self, platform: &Platform) -> bool {
+        platform.matches(PlatformModel::GlobalMultiprocessor, SchedulingAlgorithm::EarliestDeadlineFirst, self.num_processors)
+    }
+}
+
+impl SchedBounds for AnalysisPeriodic {
+    // A feasible taskset has total utilization at most m and largest
+    // utilization at most one.
+    fn speedup_factor(&self) -> Option<f64> {
+        Some(2.0 - 1.0 / self.num_processors as f64)
+    }
+
+    // Theorem 3 [2]
+    fn utilization_bound(&self, taskset: &[RTTask]) -> Option<f64> {
+        let u_max = RTUtils::largest_utilization(taskset);
+
+        Some((self.num_processors as f64) - u_max * (self.num_processors as f64 - 1f64))
+    }
+}
+
 /// Multiprocessor EDF, Sporadic tasks - Goossens, Funk, Baruah 2003 \[1\]
 ///
 /// Refer to the [module](`self`) level documentation.
@@ -89,4 +114,14 @@ impl SchedAnalysis<(), &[RTTask]> for AnalysisSporadic {
 
         SchedError::result_from_schedulable(schedulable)
     }
+}
+
+impl SchedProcessors for AnalysisSporadic {
+    fn num_processors(&self) -> u64 { self.num_processors }
+}
+
+impl SchedPlatform for AnalysisSporadic {
+    fn supports(&self, platform: &Platform) -> bool {
+        platform.matches(PlatformModel::GlobalMultiprocessor, SchedulingAlgorithm::EarliestDeadlineFirst, self.num_processors)
+    }
 }
\ No newline at end of file
@@ -55,18 +55,25 @@ impl SchedAnalysis<(), &[RTTask]> for Analysis {
     }
 }
 
+impl SchedProcessors for Analysis {
+    fn num_processors(&self) -> u64 { self.num_processors }
+}
+
+impl SchedPlatform for Analysis {
+    fn supports(&self, platform: &Platform) -> bool {
+        platform.matches(PlatformModel::GlobalMultiprocessor, SchedulingAlgorithm::FixedPriority, self.num_processors)
+    }
+}
+
 pub fn global_fixed_priority_demand(taskset: &[RTTask], k: usize, task_k: &RTTask) -> Time {
-    use crate::algorithms::full_preemption::global_multiprocessor
-             ::earliest_deadline_first::bcl09::workload_upperbound;
+    use crate::utils::interference::{Bcl09, InterferenceModel};
 
     taskset.iter()
         .enumerate()
         .filter(|(i, _)| *i < k)
+        // Section 4 Equation 6 [1]
         .map(|(_, task_i)| {
-            Time::min(
-                workload_upperbound(task_k.deadline, task_i),
-                task_k.laxity() + Time::one(),
-            )
+            Bcl09.carry_in_bounded(task_i, task_i.deadline, task_k.deadline, task_k.laxity() + Time::one())
         })
         .sum()
 }
\ No newline at end of file
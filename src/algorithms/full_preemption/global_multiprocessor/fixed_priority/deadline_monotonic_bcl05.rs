@@ -54,4 +54,14 @@ impl SchedAnalysis<(), &[RTTask]> for Analysis {
 
         SchedError::result_from_schedulable(schedulable)
     }
+}
+
+impl SchedProcessors for Analysis {
+    fn num_processors(&self) -> u64 { self.num_processors }
+}
+
+impl SchedPlatform for Analysis {
+    fn supports(&self, platform: &Platform) -> bool {
+        platform.matches(PlatformModel::GlobalMultiprocessor, SchedulingAlgorithm::FixedPriority, self.num_processors)
+    }
 }
\ No newline at end of file
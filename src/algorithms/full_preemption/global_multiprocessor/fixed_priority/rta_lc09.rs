@@ -9,7 +9,23 @@
 //!
 //! #### Implements:
 //! - [`Analysis::is_schedulable`] \
+//!   | Fixpoint iterations first jump to the non carry-in fixpoint. \
+//!   | \
 //!   | pseudo-polynomial complexity
+//! - [`AnalysisWarmStart::is_schedulable`] \
+//!   | Start the fixpoint iterations from previously computed response times. \
+//!   | \
+//!   | pseudo-polynomial complexity
+//! - [`AnalysisRefinement::is_schedulable`] \
+//!   | Refine the carry-in response times of all tasks pass after pass, until a \
+//!   | global fixpoint. \
+//!   | \
+//!   | pseudo-polynomial complexity
+//!
+//! The fixpoint iterations of both are bounded by the utilization slack left by
+//! the higher priority tasks: a task without slack is deemed non schedulable
+//! without iterating, and the iterations bail out as soon as they exceed the
+//! closed-form upper bound on the busy window.
 //!
 //! ---
 //! #### References:
@@ -18,6 +34,8 @@
 //!    Symposium, Dec. 2009, pp. 387–397. doi: 10.1109/RTSS.2009.11.
 
 use crate::prelude::*;
+use crate::utils::interference::{self, Guan09, InterferenceModel};
+use crate::utils::warm_start::warm_start_response_time;
 
 const ALGORITHM: &str = "Multiprocessor FP Response Time Analysis (Guan, Stigge, Yi, Yu 2009)";
 
@@ -40,58 +58,182 @@ impl SchedAnalysis<(), &[RTTask]> for Analysis {
     }
 
     fn run_test(&self, taskset: &[RTTask]) -> Result<(), SchedError> {
-        let mut task_rts = vec![Time::zero(); taskset.len()];
-
-        for (k, task_k) in taskset.iter().enumerate() {
-            let task_k_rt = response_time(taskset, k, self.num_processors, &task_rts[0..k]);
-            if task_k_rt > task_k.deadline {
-                return Err(SchedError::NonSchedulable(Some(
-                    anyhow::format_err!("task {k} misses its deadline.")
-                )));
-            }
+        response_times(taskset, self.num_processors, &[])
+            .map(|_| ())
+    }
+}
+
+impl SchedProcessors for Analysis {
+    fn num_processors(&self) -> u64 { self.num_processors }
+}
+
+impl SchedPlatform for Analysis {
+    fn supports(&self, platform: &Platform) -> bool {
+        platform.matches(PlatformModel::GlobalMultiprocessor, SchedulingAlgorithm::FixedPriority, self.num_processors)
+    }
+}
+
+/// Multiprocessor FP Response Time Analysis - Guan, Stigge, Yi, Yu 2009 \[1\] \
+/// Warm-started from previously computed response times.
+///
+/// Refer to the [module](`self`) level documentation.
+///
+/// Refer to [`warm_start_response_time`] for the requirements on the initial
+/// response times.
+///
+/// Returns:
+/// - Response Time upper bounds of each task.
+pub struct AnalysisWarmStart {
+    pub num_processors: u64,
+    pub initial_response_times: Vec<Time>,
+}
+
+impl SchedAnalysis<Vec<Time>, &[RTTask]> for AnalysisWarmStart {
+    fn analyzer_name(&self) -> &str { ALGORITHM }
+
+    fn check_preconditions(&self, taskset: &&[RTTask]) -> Result<(), SchedError> {
+        if !RTUtils::constrained_deadlines(taskset) {
+            Err(SchedError::constrained_deadlines())
+        } else {
+            Ok(())
+        }
+    }
+
+    fn run_test(&self, taskset: &[RTTask]) -> Result<Vec<Time>, SchedError> {
+        response_times(taskset, self.num_processors, &self.initial_response_times)
+    }
+}
+
+impl SchedProcessors for AnalysisWarmStart {
+    fn num_processors(&self) -> u64 { self.num_processors }
+}
+
+impl SchedPlatform for AnalysisWarmStart {
+    fn supports(&self, platform: &Platform) -> bool {
+        platform.matches(PlatformModel::GlobalMultiprocessor, SchedulingAlgorithm::FixedPriority, self.num_processors)
+    }
+}
+
+/// Multiprocessor FP Response Time Analysis - Guan, Stigge, Yi, Yu 2009 \[1\] \
+/// Global refinement of the carry-in response times.
+///
+/// Refer to the [module](`self`) level documentation.
+///
+/// The first pass assumes every task to respond by its deadline. Each pass
+/// computes the response times of all tasks from the carry-in response times of
+/// the previous one, until none changes. The response times never increase from
+/// one pass to the next, and the ones of the first *i* tasks are final after *i*
+/// passes, thus at most *n + 1* passes are run. A task is deemed to miss its
+/// deadline only at the fixpoint.
+///
+/// Since the response time of a task only depends on the higher priority ones,
+/// the fixpoint is the same of the single top-down pass of [`Analysis`]: the
+/// refinement is a cross-check of it, and the only option when the higher
+/// priority tasks are analysed independently, e.g. out of priority order.
+///
+/// Returns:
+/// - Response Time upper bounds of each task.
+pub struct AnalysisRefinement {
+    pub num_processors: u64,
+}
 
-            task_rts[k] = task_k_rt;
+impl SchedAnalysis<Vec<Time>, &[RTTask]> for AnalysisRefinement {
+    fn analyzer_name(&self) -> &str { ALGORITHM }
+
+    fn check_preconditions(&self, taskset: &&[RTTask]) -> Result<(), SchedError> {
+        if !RTUtils::constrained_deadlines(taskset) {
+            Err(SchedError::constrained_deadlines())
+        } else {
+            Ok(())
         }
+    }
 
-        Ok(())
+    fn run_test(&self, taskset: &[RTTask]) -> Result<Vec<Time>, SchedError> {
+        refined_response_times(taskset, self.num_processors)
+            .map(|(task_rts, _)| task_rts)
     }
 }
 
-// Equation 5 [1]
-fn workload_non_carry_in(interval: Time, task: &RTTask) -> Time {
-    (interval / task.period).floor() * task.wcet
-        + Time::min(task.wcet, interval % task.period)
+impl SchedProcessors for AnalysisRefinement {
+    fn num_processors(&self) -> u64 { self.num_processors }
 }
 
-// Equation 6 [1]
-fn workload_carry_in(interval: Time, task: &RTTask, task_rt: Time) -> Time {
-    let work_interval = Time::max(Time::zero(), interval - task.wcet);
+impl SchedPlatform for AnalysisRefinement {
+    fn supports(&self, platform: &Platform) -> bool {
+        platform.matches(PlatformModel::GlobalMultiprocessor, SchedulingAlgorithm::FixedPriority, self.num_processors)
+    }
+}
+
+fn response_times(taskset: &[RTTask], cpus: u64, initial_rts: &[Time]) -> Result<Vec<Time>, SchedError> {
+    let mut task_rts = vec![Time::zero(); taskset.len()];
+
+    for (k, task_k) in taskset.iter().enumerate() {
+        let initial_rt = warm_start_response_time(initial_rts, k, task_k);
+
+        if busy_window_bound(taskset, k, cpus).is_none() {
+            return Err(SchedError::NonSchedulable(Some(
+                anyhow::format_err!("task {k} misses its deadline: the higher priority tasks leave no utilization slack.")
+            )));
+        }
+
+        let task_k_rt = response_time(taskset, k, cpus, &task_rts[0..k], initial_rt);
+        if task_k_rt > task_k.deadline {
+            return Err(SchedError::NonSchedulable(Some(
+                anyhow::format_err!("task {k} misses its deadline.")
+            )));
+        }
+
+        task_rts[k] = task_k_rt;
+    }
+
+    Ok(task_rts)
+}
+
+// Returns the response times at the global fixpoint and the number of passes.
+fn refined_response_times(taskset: &[RTTask], cpus: u64) -> Result<(Vec<Time>, u64), SchedError> {
+    // Unknown response times, just past the deadlines as those of the tasks
+    // which miss them.
+    let mut task_rts: Vec<_> = taskset.iter().map(|task| task.deadline + Time::one()).collect();
+    let mut passes = 0;
+
+    loop {
+        // The carry-in response times are capped at the deadlines: a miss is
+        // only reported at the fixpoint, and keeps the taskset non schedulable.
+        let carry_in_rts: Vec<_> =
+            taskset.iter().zip(&task_rts)
+                .map(|(task, &rt)| Time::min(rt, task.deadline))
+                .collect();
+
+        let refined_rts: Vec<_> =
+            taskset.iter().enumerate()
+                .map(|(k, task_k)| response_time(taskset, k, cpus, &carry_in_rts[0..k], task_k.wcet))
+                .collect();
+        passes += 1;
+
+        if refined_rts == task_rts {
+            break;
+        }
+
+        debug_assert!(refined_rts.iter().zip(&task_rts).all(|(refined, rt)| refined <= rt));
+        task_rts = refined_rts;
+    }
 
-    (work_interval / task.period).floor() * task.wcet
-        + task.wcet
-        + Time::clamp(
-            work_interval % task.period - (task.period - task_rt),
-            Time::zero(),
-            Time::max(task.wcet - Time::one(), Time::zero()),
-        )
+    match taskset.iter().zip(&task_rts).position(|(task, &rt)| rt > task.deadline) {
+        Some(k) => Err(SchedError::NonSchedulable(Some(
+            anyhow::format_err!("task {k} misses its deadline.")
+        ))),
+        None => Ok((task_rts, passes)),
+    }
 }
 
 // Equation 7 [1]
 fn interference_non_carry_in(interval: Time, task_k: &RTTask, task_i: &RTTask) -> Time {
-    Time::clamp(
-        workload_non_carry_in(interval, task_i),
-        Time::zero(),
-        Time::max(interval - task_k.wcet + Time::one(), Time::zero())
-    )
+    Guan09.non_carry_in_bounded(task_i, interval, interval - task_k.wcet + Time::one())
 }
 
 // Equation 8 [1]
 fn interference_carry_in(interval: Time, task_k: &RTTask, task_i: &RTTask, task_i_rt: Time) -> Time {
-    Time::clamp(
-        workload_carry_in(interval, task_i, task_i_rt),
-        Time::zero(),
-        Time::max(interval - task_k.wcet + Time::one(), Time::zero())
-    )
+    Guan09.carry_in_bounded(task_i, task_i_rt, interval, interval - task_k.wcet + Time::one())
 }
 
 // Equation 9 [1]
@@ -110,28 +252,229 @@ fn total_interference(interval: Time, cpus: u64, taskset: &[RTTask], k: usize, t
             .map(|(_, (task_i, &task_i_rt))| interference_carry_in(interval, &taskset[k], task_i, task_i_rt))
             .collect();
 
-    let mut interference_diffs: Vec<_> =
-        interferences_carry_in.into_iter().zip(interferences_non_carry_in.iter())
-            .map(|(itf_ci, &itf_nc)| itf_ci - itf_nc)
-            .collect();
-
-    interference_diffs.sort_unstable();
-
-    interferences_non_carry_in.into_iter().sum::<Time>() +
-        interference_diffs.into_iter().rev().take(cpus as usize - 1).sum::<Time>()
+    interference::total_interference(&interferences_non_carry_in, &interferences_carry_in, cpus as usize - 1)
 }
 
 // Equation 12 [1]
-fn response_time(taskset: &[RTTask], k: usize, cpus: u64, task_rts: &[Time]) -> Time {
-    let mut prev_x = taskset[k].wcet;
-    let mut x;
+// The iteration converges to the least fixpoint from any initial value which
+// is not larger than it. The iterations stop as soon as the response time
+// exceeds the deadline.
+fn response_time(taskset: &[RTTask], k: usize, cpus: u64, task_rts: &[Time], initial_rt: Time) -> Time {
+    response_time_iterations(taskset, k, cpus, task_rts, initial_rt, true).0
+}
+
+// Returns the fixpoint and the number of evaluations of Equation 12. When
+// accelerated, the iterations jump to the lower bounds below.
+fn response_time_iterations(
+    taskset: &[RTTask],
+    k: usize,
+    cpus: u64,
+    task_rts: &[Time],
+    initial_rt: Time,
+    accelerated: bool,
+) -> (Time, u64) {
+    let task_k = &taskset[k];
+
+    // With less higher priority tasks than processors, the interference is at
+    // most (m - 1) * (x - C_k + 1), thus C_k is a fixpoint.
+    if accelerated && k < cpus as usize {
+        return (task_k.wcet, 0);
+    }
+
+    // Without utilization slack the iterations could creep up to the deadline.
+    let limit = task_k.deadline + Time::one();
+    let Some(bound) = busy_window_bound(taskset, k, cpus)
+        else { return (limit, 0); };
+    let bound = Time::min(bound, task_k.deadline);
+
+    let mut prev_x =
+        if accelerated {
+            non_carry_in_response_time(taskset, k, cpus, initial_rt, limit)
+        } else {
+            initial_rt
+        };
+
+    let mut iterations = 0;
     loop {
-        x = Time::floor(total_interference(prev_x, cpus, taskset, k, task_rts) / cpus as f64) + taskset[k].wcet;
-        if x == prev_x {
-            return x;
+        let x = Time::floor(total_interference(prev_x, cpus, taskset, k, task_rts) / cpus as f64) + task_k.wcet;
+        iterations += 1;
+
+        if x > bound {
+            return (limit, iterations);
+        } else if x == prev_x {
+            return (x, iterations);
         }
 
         debug_assert!(x > prev_x);
         prev_x = x;
     }
-}
\ No newline at end of file
+}
+
+// Lower bound on the fixpoint of Equation 12: the carry-in workload of
+// Equation 6 is never smaller than the non carry-in workload of Equation 5,
+// so the total interference of Equation 9 is at least the sum of the non
+// carry-in interferences, and so is its least fixpoint. The non carry-in
+// fixpoint is cheaper to compute, as it needs neither the response times of
+// the higher priority tasks nor the sorting of the interference differences.
+fn non_carry_in_response_time(taskset: &[RTTask], k: usize, cpus: u64, initial_rt: Time, limit: Time) -> Time {
+    fixpoint_search_with_limit(
+        initial_rt,
+        limit,
+        |&x: &Time| {
+            let interference: Time = taskset[0..k].iter()
+                .map(|task_i| interference_non_carry_in(x, &taskset[k], task_i))
+                .sum();
+
+            Time::max(x, Time::floor(interference / cpus as f64) + taskset[k].wcet)
+        }
+    )
+}
+
+// Upper bound on the fixpoint of Equation 12 from the utilization slack of the
+// higher priority tasks, or None if they leave no slack, i.e. their total
+// utilization is at least m. The workloads of Equations 5 and 6 are linearly
+// bounded by W_nc(x) <= U_i x + C_i (1 - U_i) and W_ci(x) <= W_nc(x) + C_i, with
+// at most m - 1 carry-in tasks, thus any fixpoint satisfies:
+//   x <= C_k + (U_hp x + A) / m
+// with A = sum_i C_i (1 - U_i) + the m - 1 largest C_i, and the bound is:
+//   x <= (m C_k + A) / (m - U_hp)
+fn busy_window_bound(taskset: &[RTTask], k: usize, cpus: u64) -> Option<Time> {
+    let higher_priority = &taskset[0..k];
+    let m = cpus as f64;
+
+    let slack = m - RTUtils::total_utilization(higher_priority);
+    if slack <= 0.0 {
+        return None;
+    }
+
+    let mut wcets: Vec<_> = higher_priority.iter().map(|task_i| task_i.wcet).collect();
+    wcets.sort_unstable();
+
+    let offset: Time =
+        higher_priority.iter()
+            .map(|task_i| task_i.wcet * (1.0 - task_i.utilization()))
+            .chain(wcets.into_iter().rev().take(cpus as usize - 1))
+            .sum();
+
+    Some(Time::ceil((taskset[k].wcet * m + offset) / slack))
+}
+
+#[test]
+fn busy_window_pruning() {
+    use crate::utils::test_tasksets::random_taskset;
+
+    // The right-hand side of Equation 12 does not exceed the bound at the
+    // bound, thus the least fixpoint is not larger than it.
+    for seed in 0..50 {
+        let taskset = random_taskset(12, 3.0, seed);
+        let task_rts: Vec<_> = taskset.iter().map(|task| task.deadline).collect();
+
+        for k in 0..taskset.len() {
+            let Some(bound) = busy_window_bound(&taskset, k, 4)
+                else { continue; };
+
+            let x = Time::floor(total_interference(bound, 4, &taskset, k, &task_rts[0..k]) / 4.0) + taskset[k].wcet;
+            assert!(x <= bound);
+        }
+    }
+
+    // The two higher priority tasks saturate both processors: the response
+    // time would otherwise grow by one time unit per iteration.
+    let taskset = [
+        RTTask::new_ns(5, 5, 5),
+        RTTask::new_ns(5, 5, 5),
+        RTTask::new_ns(1, 1_000_000, 1_000_000),
+    ];
+    let task_rts = [Time::nanos(5.0), Time::nanos(5.0)];
+
+    assert!(busy_window_bound(&taskset, 2, 2).is_none());
+    assert_eq!(response_time_iterations(&taskset, 2, 2, &task_rts, Time::one(), false).1, 0);
+    assert!(Analysis { num_processors: 2 }.is_schedulable(&taskset[..]).is_err());
+    assert!(Analysis { num_processors: 3 }.is_schedulable(&taskset[..]).is_ok());
+}
+
+#[test]
+fn global_refinement() {
+    use crate::utils::test_tasksets::random_taskset;
+
+    for seed in 0..50 {
+        let taskset = random_taskset(12, 3.0, seed);
+
+        let top_down = response_times(&taskset, 4, &[]);
+        let refined = refined_response_times(&taskset, 4);
+
+        match (top_down, refined) {
+            (Ok(top_down), Ok((refined, passes))) => {
+                assert_eq!(top_down, refined);
+                assert!(passes <= taskset.len() as u64 + 1);
+            },
+            (Err(_), Err(_)) => {},
+            _ => panic!("the refinement and the top-down pass disagree"),
+        }
+    }
+}
+
+#[test]
+fn accelerated_iterations() {
+    use crate::utils::test_tasksets::random_taskset;
+
+    let (mut plain_iterations, mut accelerated_iterations) = (0, 0);
+
+    for seed in 0..50 {
+        let taskset = random_taskset(12, 3.0, seed);
+        let mut task_rts = Vec::new();
+
+        for k in 0..taskset.len() {
+            let (plain, iterations) = response_time_iterations(&taskset, k, 4, &task_rts, taskset[k].wcet, false);
+            plain_iterations += iterations;
+
+            let (accelerated, iterations) = response_time_iterations(&taskset, k, 4, &task_rts, taskset[k].wcet, true);
+            accelerated_iterations += iterations;
+
+            assert_eq!(plain, accelerated);
+            if plain > taskset[k].deadline {
+                break;
+            }
+
+            task_rts.push(plain);
+        }
+    }
+
+    assert!(accelerated_iterations < plain_iterations);
+}
+
+// Accelerations of the response time iteration on high utilization tasksets.
+#[test]
+#[ignore]
+fn bench_iterations() {
+    use crate::utils::bench;
+    use crate::utils::test_tasksets::random_taskset;
+
+    let tasksets: Vec<_> = (0..200).map(|seed| random_taskset(32, 6.0, seed)).collect();
+
+    let iterations = |accelerated: bool| {
+        let mut iterations = 0;
+
+        for taskset in tasksets.iter() {
+            let mut task_rts = Vec::new();
+
+            for k in 0..taskset.len() {
+                let (response, task_iterations) =
+                    response_time_iterations(taskset, k, 8, &task_rts, taskset[k].wcet, accelerated);
+                iterations += task_iterations;
+
+                if response > taskset[k].deadline {
+                    break;
+                }
+
+                task_rts.push(response);
+            }
+        }
+
+        iterations
+    };
+
+    let (plain, _) = bench::time("plain iterations", || iterations(false));
+    let (accelerated, _) = bench::time("non carry-in jumps iterations", || iterations(true));
+    assert!(accelerated < plain);
+}
@@ -0,0 +1,503 @@
+//! ## Multiprocessor Brute-Force Schedulability - Baker, Cirinei 2007
+//!
+//! Exact schedulability test by explicit exploration of the state space of the
+//! scheduler, for tasksets with (small) integer parameters. It is meant to be
+//! used as ground truth for the sufficient global tests on tiny tasksets, as
+//! the state space grows exponentially with the number of tasks.
+//!
+//! #### Model:
+//! - Sporadic Task model
+//! - Fully-Preemptive global scheduling, Work Conserving policy:
+//!   - Earliest Deadline First
+//!   - Fixed Priority (tasks are sorted by priority, highest first)
+//!   - Earliest Deadline Zero Laxity and Fixed Priority Zero Laxity: jobs
+//!     with zero laxity get the highest priority \[2\] \[3\]. Jobs may be
+//!     promoted earlier, when their laxity drops to a given threshold.
+//! - Discrete time: all the task parameters are integer multiples of the
+//!   given time unit, and scheduling decisions happen at unit boundaries.
+//!
+//! #### Preconditions:
+//! - Constrained Deadlines
+//! - Integer task parameters (in time units)
+//! - At most 63 tasks
+//!
+//! #### Implements:
+//! - [`Analysis::is_schedulable`] \
+//!   | exponential complexity
+//! - [`DesignerZeroLaxity::design`] \
+//!   | Minimal number of processors for the zero laxity policies, and maximum
+//!   | number of simultaneous zero laxity jobs on it. \
+//!   | \
+//!   | exponential complexity
+//! - [`DesignerLaxityThreshold::design`] \
+//!   | Maximum laxity threshold for the zero laxity policies on a given
+//!   | number of processors. \
+//!   | \
+//!   | exponential complexity
+//!
+//! ---
+//! #### References:
+//! 1. T. P. Baker and M. Cirinei, “Brute-Force Determination of Multiprocessor
+//!    Schedulability for Sets of Sporadic Hard-Deadline Tasks,” in Principles
+//!    of Distributed Systems, OPODIS 2007, pp. 62–75,
+//!    doi: 10.1007/978-3-540-77096-1_5.
+//! 2. M. Cirinei and T. P. Baker, “EDZL Scheduling Analysis,” in 19th
+//!    Euromicro Conference on Real-Time Systems (ECRTS'07), July 2007,
+//!    pp. 9–18, doi: 10.1109/ECRTS.2007.14.
+//! 3. R. I. Davis and S. Kato, “FPSL, FPCL and FPZL Schedulability Analysis,”
+//!    Real-Time Syst, vol. 48, no. 6, pp. 750–788, Nov. 2012,
+//!    doi: 10.1007/s11241-012-9149-x.
+
+use crate::prelude::*;
+use std::collections::HashSet;
+
+const ALGORITHM: &str = "Multiprocessor Brute-Force Schedulability (Baker, Cirinei 2007)";
+
+// The subsets of tasks releasing a job at the same instant are enumerated as
+// the bits of an u64.
+const MAX_TASKS: usize = u64::BITS as usize - 1;
+
+#[derive(Debug, Clone, Copy)]
+#[derive(PartialEq, Eq)]
+pub enum Policy {
+    EarliestDeadlineFirst,
+    FixedPriority,
+    EarliestDeadlineZeroLaxity,
+    FixedPriorityZeroLaxity,
+}
+
+impl Policy {
+    fn is_zero_laxity(&self) -> bool {
+        matches!(self, Self::EarliestDeadlineZeroLaxity | Self::FixedPriorityZeroLaxity)
+    }
+}
+
+/// Multiprocessor Brute-Force Schedulability - Baker, Cirinei 2007 \[1\]
+///
+/// Refer to the [module](`self`) level documentation.
+pub struct Analysis {
+    pub num_processors: u64,
+    pub policy: Policy,
+    /// With the zero laxity policies, jobs are promoted to the highest
+    /// priority when their laxity is at most this threshold (zero for plain
+    /// EDZL and FPZL). Ignored by the other policies.
+    pub laxity_threshold: Time,
+    pub time_unit: Time,
+    /// Maximum number of states to explore before giving up.
+    pub max_states: usize,
+}
+
+// Section 3 [1]: each task is described by the remaining execution time of its
+// current job and by the time left before it can release its next job.
+#[derive(Debug, Clone, Copy)]
+#[derive(PartialEq, Eq, Hash)]
+struct TaskState {
+    remaining: u64,
+    next_release: u64,
+}
+
+struct Task {
+    wcet: u64,
+    deadline: u64,
+    period: u64,
+}
+
+impl SchedAnalysis<(), &[RTTask]> for Analysis {
+    fn analyzer_name(&self) -> &str { ALGORITHM }
+
+    fn check_preconditions(&self, taskset: &&[RTTask]) -> Result<(), SchedError> {
+        let is_integer = |time: Time| (time / self.time_unit).fract() == 0.0;
+        let integer_parameters = is_integer(self.laxity_threshold) && taskset.iter()
+            .all(|task| is_integer(task.wcet) && is_integer(task.deadline) && is_integer(task.period));
+
+        if !RTUtils::constrained_deadlines(taskset) {
+            Err(SchedError::constrained_deadlines())
+        } else if taskset.len() > MAX_TASKS {
+            Err(SchedError::Precondition(Some(
+                anyhow::format_err!("the taskset must have at most {MAX_TASKS} tasks.")
+            )))
+        } else if !integer_parameters {
+            Err(SchedError::Precondition(Some(
+                anyhow::format_err!("task parameters must be integer multiples of the time unit.")
+            )))
+        } else {
+            Ok(())
+        }
+    }
+
+    fn run_test(&self, taskset: &[RTTask]) -> Result<(), SchedError> {
+        self.explore(taskset).map(|_| ())
+    }
+}
+
+impl SchedProcessors for Analysis {
+    fn num_processors(&self) -> u64 { self.num_processors }
+}
+
+impl SchedPlatform for Analysis {
+    fn supports(&self, platform: &Platform) -> bool {
+        let algorithm = match self.policy {
+            Policy::EarliestDeadlineFirst => SchedulingAlgorithm::EarliestDeadlineFirst,
+            Policy::FixedPriority => SchedulingAlgorithm::FixedPriority,
+            Policy::EarliestDeadlineZeroLaxity => SchedulingAlgorithm::EarliestDeadlineZeroLaxity,
+            Policy::FixedPriorityZeroLaxity => SchedulingAlgorithm::FixedPriorityZeroLaxity,
+        };
+
+        platform.matches(PlatformModel::GlobalMultiprocessor, algorithm, self.num_processors)
+    }
+}
+
+impl Analysis {
+    // Explore the whole state space, returning the maximum number of zero
+    // laxity jobs ready at the same time.
+    fn explore(&self, taskset: &[RTTask]) -> Result<usize, SchedError> {
+        let to_units = |time: Time| (time / self.time_unit) as u64;
+        let laxity_threshold = to_units(self.laxity_threshold);
+        let tasks: Vec<_> = taskset.iter()
+            .map(|task| Task {
+                wcet: to_units(task.wcet),
+                deadline: to_units(task.deadline),
+                period: to_units(task.period),
+            })
+            .collect();
+
+        let mut max_zero_laxity_jobs = 0;
+
+        let initial_state = vec![TaskState { remaining: 0, next_release: 0 }; tasks.len()];
+
+        let mut visited = HashSet::new();
+        let mut to_visit = vec![initial_state.clone()];
+        visited.insert(initial_state);
+
+        while let Some(state) = to_visit.pop() {
+            // Tasks which may release a new job at this instant.
+            let releasable: Vec<_> = state.iter().enumerate()
+                .filter(|(_, task)| task.remaining == 0 && task.next_release == 0)
+                .map(|(i, _)| i)
+                .collect();
+
+            // Sporadic tasks may or may not release a job: explore every subset.
+            for releases in 0 .. 1u64 << releasable.len() {
+                let mut next_state = state.clone();
+
+                for (bit, &i) in releasable.iter().enumerate() {
+                    if releases & (1 << bit) != 0 {
+                        next_state[i] = TaskState { remaining: tasks[i].wcet, next_release: tasks[i].period };
+                    }
+                }
+
+                max_zero_laxity_jobs = usize::max(
+                    max_zero_laxity_jobs,
+                    (0..tasks.len()).filter(|&i| is_zero_laxity(&tasks[i], &next_state[i])).count()
+                );
+
+                self.schedule_time_unit(&tasks, &mut next_state, laxity_threshold);
+
+                if let Some(k) = missed_deadline(&tasks, &next_state) {
+                    return Err(SchedError::NonSchedulable(Some(
+                        anyhow::format_err!("task {k} misses its deadline.")
+                    )));
+                }
+
+                if visited.insert(next_state.clone()) {
+                    if visited.len() > self.max_states {
+                        return Err(SchedError::Inconclusive(Some(
+                            anyhow::format_err!("state space exceeds {} states.", self.max_states)
+                        )));
+                    }
+
+                    to_visit.push(next_state);
+                }
+            }
+        }
+
+        Ok(max_zero_laxity_jobs)
+    }
+
+    // Run the highest priority ready jobs for one time unit.
+    fn schedule_time_unit(&self, tasks: &[Task], state: &mut [TaskState], laxity_threshold: u64) {
+        let mut ready: Vec<_> = (0..tasks.len())
+            .filter(|&i| state[i].remaining > 0)
+            .collect();
+
+        if matches!(self.policy, Policy::EarliestDeadlineFirst | Policy::EarliestDeadlineZeroLaxity) {
+            ready.sort_by_key(|&i| time_to_deadline(&tasks[i], &state[i]));
+        }
+
+        // Jobs within the laxity threshold are promoted to the highest
+        // priority, keeping the relative order of the base policy (stable sort).
+        if self.policy.is_zero_laxity() {
+            ready.sort_by_key(|&i| laxity(&tasks[i], &state[i]) > laxity_threshold);
+        }
+
+        for &i in ready.iter().take(self.num_processors as usize) {
+            state[i].remaining -= 1;
+        }
+
+        for task in state.iter_mut() {
+            task.next_release = task.next_release.saturating_sub(1);
+        }
+    }
+}
+
+// Time to the absolute deadline of the current job, ties between jobs are
+// broken by task index.
+fn time_to_deadline(task: &Task, state: &TaskState) -> u64 {
+    state.next_release - (task.period - task.deadline)
+}
+
+// Time the job can wait before it must run continuously until its deadline.
+fn laxity(task: &Task, state: &TaskState) -> u64 {
+    time_to_deadline(task, state).saturating_sub(state.remaining)
+}
+
+// The job must run continuously until its deadline to complete in time.
+fn is_zero_laxity(task: &Task, state: &TaskState) -> bool {
+    state.remaining > 0 && laxity(task, state) == 0
+}
+
+/// Number of processors found by the [`DesignerZeroLaxity`].
+#[derive(Debug, Clone, Copy)]
+pub struct ZeroLaxityPlatform {
+    pub num_processors: u64,
+    /// Maximum number of jobs with zero laxity at the same time, over all
+    /// the reachable states on the given number of processors.
+    pub max_zero_laxity_jobs: usize,
+}
+
+/// Multiprocessor Brute-Force Schedulability - Baker, Cirinei 2007 \[1\] \
+/// Find the minimal number of processors for a zero laxity policy.
+///
+/// Refer to the [module](`self`) level documentation.
+///
+/// The search starts from the total utilization of the taskset and stops at
+/// one processor per task, with which every constrained deadline taskset is
+/// schedulable.
+pub struct DesignerZeroLaxity {
+    pub policy: Policy,
+    pub time_unit: Time,
+    /// Maximum number of states to explore for each number of processors.
+    pub max_states: usize,
+}
+
+impl SchedDesign<&[RTTask], ZeroLaxityPlatform> for DesignerZeroLaxity {
+    fn designer_name(&self) -> &str { ALGORITHM }
+
+    fn check_preconditions(&self, taskset: &&[RTTask]) -> Result<(), SchedError> {
+        if !self.policy.is_zero_laxity() {
+            return Err(SchedError::Precondition(Some(
+                anyhow::format_err!("the scheduling policy must be a zero laxity policy.")
+            )));
+        }
+
+        self.analysis(1).check_preconditions(taskset)
+    }
+
+    fn run_designer(&self, taskset: &[RTTask]) -> Result<ZeroLaxityPlatform, SchedError> {
+        let min_processors = u64::max(1, RTUtils::total_utilization(taskset).ceil() as u64);
+        let max_processors = u64::max(min_processors, taskset.len() as u64);
+
+        for num_processors in min_processors ..= max_processors {
+            match self.analysis(num_processors).explore(taskset) {
+                Ok(max_zero_laxity_jobs) =>
+                    return Ok(ZeroLaxityPlatform { num_processors, max_zero_laxity_jobs }),
+                Err(SchedError::NonSchedulable(_)) => continue,
+                Err(err) => return Err(err),
+            }
+        }
+
+        Err(SchedError::NonSchedulable(None))
+    }
+}
+
+impl DesignerZeroLaxity {
+    fn analysis(&self, num_processors: u64) -> Analysis {
+        Analysis {
+            num_processors,
+            policy: self.policy,
+            laxity_threshold: Time::zero(),
+            time_unit: self.time_unit,
+            max_states: self.max_states,
+        }
+    }
+}
+
+/// Multiprocessor Brute-Force Schedulability - Baker, Cirinei 2007 \[1\] \
+/// Find the maximum laxity threshold for a zero laxity policy.
+///
+/// Refer to the [module](`self`) level documentation.
+///
+/// Every threshold from zero up to the largest relative deadline is checked,
+/// as schedulability is not monotone in the threshold: the largest one with
+/// which the taskset is schedulable is returned.
+pub struct DesignerLaxityThreshold {
+    pub num_processors: u64,
+    pub policy: Policy,
+    pub time_unit: Time,
+    /// Maximum number of states to explore for each threshold.
+    pub max_states: usize,
+}
+
+impl SchedDesign<&[RTTask], Time> for DesignerLaxityThreshold {
+    fn designer_name(&self) -> &str { ALGORITHM }
+
+    fn check_preconditions(&self, taskset: &&[RTTask]) -> Result<(), SchedError> {
+        if !self.policy.is_zero_laxity() {
+            return Err(SchedError::Precondition(Some(
+                anyhow::format_err!("the scheduling policy must be a zero laxity policy.")
+            )));
+        }
+
+        self.analysis(Time::zero()).check_preconditions(taskset)
+    }
+
+    fn run_designer(&self, taskset: &[RTTask]) -> Result<Time, SchedError> {
+        let max_deadline = taskset.iter()
+            .map(|task| (task.deadline / self.time_unit) as u64)
+            .max()
+            .unwrap_or(0);
+
+        for threshold in (0 ..= max_deadline).rev() {
+            let laxity_threshold = threshold as f64 * self.time_unit;
+
+            match self.analysis(laxity_threshold).explore(taskset) {
+                Ok(_) => return Ok(laxity_threshold),
+                Err(SchedError::NonSchedulable(_)) => continue,
+                Err(err) => return Err(err),
+            }
+        }
+
+        Err(SchedError::NonSchedulable(None))
+    }
+}
+
+impl DesignerLaxityThreshold {
+    fn analysis(&self, laxity_threshold: Time) -> Analysis {
+        Analysis {
+            num_processors: self.num_processors,
+            policy: self.policy,
+            laxity_threshold,
+            time_unit: self.time_unit,
+            max_states: self.max_states,
+        }
+    }
+}
+
+// A pending job has reached its deadline when the time left before the next
+// release is no longer than the gap between deadline and period.
+fn missed_deadline(tasks: &[Task], state: &[TaskState]) -> Option<usize> {
+    tasks.iter().zip(state.iter())
+        .position(|(task, state)| {
+            state.remaining > 0 && state.next_release <= task.period - task.deadline
+        })
+}
+
+#[test]
+fn brute_force() {
+    let analysis = |policy| Analysis {
+        num_processors: 2,
+        policy,
+        laxity_threshold: Time::zero(),
+        time_unit: Time::one(),
+        max_states: 100_000,
+    };
+
+    for policy in [Policy::EarliestDeadlineFirst, Policy::FixedPriority] {
+        let taskset = [
+            RTTask::new_ns(1, 2, 2),
+            RTTask::new_ns(1, 2, 2),
+            RTTask::new_ns(1, 2, 2),
+        ];
+        assert!(analysis(policy).is_schedulable(&taskset).is_ok());
+
+        let taskset = [
+            RTTask::new_ns(2, 3, 3),
+            RTTask::new_ns(2, 3, 3),
+            RTTask::new_ns(2, 3, 3),
+        ];
+        assert!(matches!(
+            analysis(policy).run_test(&taskset),
+            Err(SchedError::NonSchedulable(_))
+        ));
+    }
+
+    let taskset = [
+        RTTask::new_ns(1, 2, 2),
+        RTTask::new_ns(1, 2, 2),
+    ];
+    let analysis = Analysis { max_states: 1, ..analysis(Policy::FixedPriority) };
+    assert!(matches!(analysis.run_test(&taskset), Err(SchedError::Inconclusive(_))));
+
+    let taskset = vec![RTTask::new_ns(1, 100, 100); 64];
+    assert!(analysis.check_preconditions(&taskset.as_slice()).is_err());
+}
+
+#[test]
+fn zero_laxity() {
+    let analysis = |policy| Analysis {
+        num_processors: 2,
+        policy,
+        laxity_threshold: Time::zero(),
+        time_unit: Time::one(),
+        max_states: 100_000,
+    };
+
+    // The third task misses its deadline under EDF, but is promoted as soon as
+    // it is released under EDZL, as it has zero laxity.
+    let taskset = [
+        RTTask::new_ns(1, 1, 2),
+        RTTask::new_ns(1, 2, 2),
+        RTTask::new_ns(2, 2, 3),
+    ];
+
+    assert!(analysis(Policy::EarliestDeadlineFirst).run_test(&taskset).is_err());
+    assert!(analysis(Policy::EarliestDeadlineZeroLaxity).run_test(&taskset).is_ok());
+
+    let designer = DesignerZeroLaxity {
+        policy: Policy::EarliestDeadlineZeroLaxity,
+        time_unit: Time::one(),
+        max_states: 100_000,
+    };
+
+    let platform = designer.design(&taskset).unwrap();
+    assert_eq!(platform.num_processors, 2);
+    // The first and third tasks have zero laxity as soon as they are released
+    // together, while three zero laxity jobs would not fit on two processors.
+    assert_eq!(platform.max_zero_laxity_jobs, 2);
+}
+
+#[test]
+fn laxity_threshold() {
+    // Not schedulable under EDF, schedulable under EDZL with a laxity
+    // threshold of at most one, while larger thresholds promote too many jobs.
+    let taskset = [
+        RTTask::new_ns(1, 1, 2),
+        RTTask::new_ns(1, 3, 3),
+        RTTask::new_ns(3, 3, 3),
+    ];
+
+    let analysis = |policy, laxity_threshold| Analysis {
+        num_processors: 2,
+        policy,
+        laxity_threshold: Time::nanos(laxity_threshold),
+        time_unit: Time::one(),
+        max_states: 100_000,
+    };
+
+    assert!(analysis(Policy::EarliestDeadlineFirst, 0.0).run_test(&taskset).is_err());
+
+    let designer = DesignerLaxityThreshold {
+        num_processors: 2,
+        policy: Policy::EarliestDeadlineZeroLaxity,
+        time_unit: Time::one(),
+        max_states: 100_000,
+    };
+
+    assert_eq!(designer.design(&taskset).unwrap(), Time::nanos(1.0));
+    for laxity_threshold in [0.0, 1.0] {
+        assert!(analysis(Policy::EarliestDeadlineZeroLaxity, laxity_threshold).run_test(&taskset).is_ok());
+    }
+    for laxity_threshold in [2.0, 3.0] {
+        assert!(analysis(Policy::EarliestDeadlineZeroLaxity, laxity_threshold).run_test(&taskset).is_err());
+    }
+}
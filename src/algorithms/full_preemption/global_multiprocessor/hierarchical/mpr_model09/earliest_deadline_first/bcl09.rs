@@ -131,31 +131,43 @@ pub mod extra {
         }
 
         fn run_designer(&self, taskset: &[RTTask]) -> Result<MPRModel, SchedError> {
-            let min_resource =
-                RTUtils::total_utilization(taskset) * self.period;
-            let max_resource = {
-                let designer = super::DesignerLinear { period: self.period, concurrency: self.concurrency };
+            self.search(taskset).run_designer(taskset)
+        }
 
-                designer.check_preconditions(&taskset)?;
-                designer.run_designer(taskset)?.resource
-            };
+        fn run_designer_with_stats(&self, taskset: &[RTTask]) -> DesignResult<MPRModel> {
+            self.search(taskset).run_designer_with_stats(taskset)
+        }
+    }
 
-            (extra::DesignerPeriodConcurrencyNaive {
+    impl DesignerPeriodConcurrency {
+        fn search<'a>(&'a self, taskset: &'a [RTTask]) -> impl SchedDesign<&'a [RTTask], MPRModel> + 'a {
+            extra::DesignerPeriodConcurrencyNaive {
                 period: self.period,
                 concurrency: self.concurrency,
-                resource_iter_fn: |_, _| Ok(Box::new(time_range_iterator_w_step(min_resource, max_resource, self.resource_step))),
+                resource_iter_fn: move |period, concurrency| {
+                    let min_resource =
+                        RTUtils::total_utilization(taskset) * period;
+                    let max_resource = {
+                        let designer = super::DesignerLinear { period, concurrency };
+
+                        designer.check_preconditions(&taskset)?;
+                        designer.run_designer(taskset)?.resource
+                    };
+
+                    Ok(Box::new(time_range_iterator_w_step(min_resource, max_resource, self.resource_step)))
+                },
                 analysis_gen_fn: |resource, period, concurrency|
                     super::Analysis { model: MPRModel { resource, period, concurrency }},
                 marker: std::marker::PhantomData,
-            })
-            .run_designer(taskset)
+            }
         }
     }
 
     /// MPR Model, EDF Local Scheduler - *Derived from* Bertogna, Cirinei, Lipari 2009 \[1\]
     ///
     /// Generate the best MPRModel for the given taskset. Searches the space of
-    /// possible MPRModels given a range of valid periods.
+    /// possible MPRModels given a range of valid periods, refining the period
+    /// grid around the most promising periods (refer to [`extra::DesignerRefined`]).
     ///
     /// Refer to the [module](`self`) level documentation.
     pub struct DesignerFull {
@@ -175,16 +187,37 @@ pub mod extra {
         }
 
         fn run_designer(&self, taskset: &[RTTask]) -> Result<MPRModel, SchedError> {
+            self.search(taskset).run_designer(taskset)
+        }
+
+        fn run_designer_with_stats(&self, taskset: &[RTTask]) -> DesignResult<MPRModel> {
+            self.search(taskset).run_designer_with_stats(taskset)
+        }
+    }
+
+    impl SchedDesignResumable<&[RTTask], MPRModel, extra::SearchCheckpoint> for DesignerFull {
+        fn run_designer_resumable(
+            &self,
+            taskset: &[RTTask],
+            checkpoint: extra::SearchCheckpoint,
+            on_checkpoint: &mut dyn FnMut(&extra::SearchCheckpoint),
+        ) -> Result<MPRModel, SchedError> {
+            self.search(taskset).run_designer_resumable(taskset, checkpoint, on_checkpoint)
+        }
+    }
+
+    impl DesignerFull {
+        fn search<'a>(&'a self, taskset: &'a [RTTask]) -> impl SchedDesignResumable<&'a [RTTask], MPRModel, extra::SearchCheckpoint> + 'a {
             let min_processors =
                 num_processors_lower_bound(taskset);
 
             let max_processors =
                 num_processors_upper_bound(taskset);
 
-            let designer = extra::DesignerNaive {
-                period_iter_fn: || Ok(Box::new(time_range_iterator_w_step(self.period_range.0, self.period_range.1, self.period_range.2))),
-                concurrency_iter_fn: |_| Ok(Box::new(min_processors ..= max_processors)),
-                resource_iter_fn: |period, concurrency| {
+            extra::DesignerRefined {
+                period_range: self.period_range,
+                concurrency_iter_fn: move |_| Ok(Box::new(min_processors ..= max_processors)),
+                resource_iter_fn: move |period, concurrency| {
                     let min_resource =
                         RTUtils::total_utilization(taskset) * period;
                     let max_resource = {
@@ -199,9 +232,7 @@ pub mod extra {
                 analysis_gen_fn: |resource, period, concurrency|
                     super::Analysis { model: MPRModel { resource, period, concurrency }},
                 marker: std::marker::PhantomData,
-            };
-
-            designer.run_designer(taskset)
+            }
         }
     }
 
@@ -6,10 +6,15 @@
 //!
 //! #### Preconditions:
 //! - Constrained Deadlines
+//! - Concurrency of at least one processor
 //!
 //! #### Implements:
 //! - [`Analysis::is_schedulable`] \
 //!   | pseudo-polynomial complexity
+//! - [`AnalysisCapped::is_schedulable`] \
+//!   | Give up when the arrival times to check exceed a given bound. \
+//!   | \
+//!   | pseudo-polynomial complexity
 //! - [`DesignerLinear::design`] \
 //!   | pseudo-polynomial complexity
 //! - [`extra::DesignerPeriodConcurrency::design`] \
@@ -28,6 +33,11 @@
 
 use crate::prelude::*;
 use crate::algorithms::full_preemption::global_multiprocessor::hierarchical::mpr_model09::*;
+use crate::utils::analysis_points::*;
+use crate::utils::interference::{self, InterferenceModel, Shin08};
+use itertools::Itertools;
+use crate::utils::interference_block::*;
+use crate::utils::taskset_soa::*;
 
 const ALGORITHM: &str = "MPR Model, EDF Local Scheduler (Shin, Easwaran, Lee 2009)";
 
@@ -44,38 +54,72 @@ impl SchedAnalysis<(), &[RTTask]> for Analysis {
     fn check_preconditions(&self, taskset: &&[RTTask]) -> Result<(), SchedError> {
         if !RTUtils::constrained_deadlines(taskset) {
             Err(SchedError::constrained_deadlines())
-        } else if f64::abs(self.model.utilization() - RTUtils::total_utilization(taskset)) < 0.01 {
-            Err(SchedError::Other(
-                anyhow::format_err!("Arrival times upperbound tends to infinity, the computation becomes intractable.")
-            ))
+        } else if self.model.concurrency == 0 {
+            Err(zero_concurrency())
         } else {
             Ok(())
         }
     }
 
     fn run_test(&self, taskset: &[RTTask]) -> Result<(), SchedError> {
-        // Section 4.2, Theorem 1 [1]
-        let schedulable =
-            is_schedulable_demand(
-                taskset,
-                &self.model,
-                |taskset, k, task_k, model, arrival_k|
-                    demand_edf(taskset, k, task_k, model.concurrency, arrival_k),
-                |taskset, _, task_k, model| -> Box<dyn Iterator<Item = Time>> {
-                    let arrival_k_upperbound =
-                        arrival_k_upperbound_edf(taskset, task_k, model);
+        necessary_conditions(taskset, &self.model)?;
 
-                    Box::new(
-                        time_range_iterator(Time::zero(), arrival_k_upperbound)
-                        .filter(|arrival_k| filter_intervals_edf(taskset, task_k, model, *arrival_k))
-                    )
-                }
-            );
+        if f64::abs(self.model.utilization() - RTUtils::total_utilization(taskset)) < 0.01 {
+            return Err(SchedError::intractable());
+        }
 
-        SchedError::result_from_schedulable(schedulable)
+        SchedError::result_from_schedulable(
+            is_schedulable_filtered(taskset, &self.model)
+        )
     }
 }
 
+/// MPR Model, EDF Local Scheduler - Shin, Easwaran, Lee 2009 \[1\] \
+/// Give up when the arrival times to check exceed a given bound.
+///
+/// Refer to the [module](`self`) level documentation.
+///
+/// The arrival time upper bound of Theorem 2 grows unboundedly as the taskset
+/// utilization approaches the model's bandwidth. Rather than scanning all the
+/// arrival times up to the bound, the test returns
+/// [`SchedError::Inconclusive`] when the bound exceeds `max_arrival_time`.
+pub struct AnalysisCapped {
+    pub model: MPRModel,
+    pub max_arrival_time: Time,
+}
+
+impl SchedAnalysis<(), &[RTTask]> for AnalysisCapped {
+    fn analyzer_name(&self) -> &str { ALGORITHM }
+
+    fn check_preconditions(&self, taskset: &&[RTTask]) -> Result<(), SchedError> {
+        if !RTUtils::constrained_deadlines(taskset) {
+            Err(SchedError::constrained_deadlines())
+        } else if self.model.concurrency == 0 {
+            Err(zero_concurrency())
+        } else {
+            Ok(())
+        }
+    }
+
+    fn run_test(&self, taskset: &[RTTask]) -> Result<(), SchedError> {
+        necessary_conditions(taskset, &self.model)?;
+
+        let exceeding_bound = taskset.iter()
+            .map(|task_k| arrival_k_upperbound_edf(taskset, task_k, &self.model))
+            .find(|ak| ak.as_nanos().is_nan() || *ak > self.max_arrival_time);
+
+        if let Some(ak_upperbound) = exceeding_bound {
+            return Err(SchedError::Inconclusive(Some(anyhow::format_err!(
+                "arrival times upperbound of {}ns exceeds the cap of {}ns.",
+                ak_upperbound.as_nanos(), self.max_arrival_time.as_nanos()
+            ))));
+        }
+
+        SchedError::result_from_schedulable(
+            is_schedulable_filtered(taskset, &self.model)
+        )
+    }
+}
 
 /// MPR Model, EDF Local Scheduler - Shin, Easwaran, Lee 2009 \[1\]
 ///
@@ -90,19 +134,25 @@ impl SchedAnalysis<(), &[RTTask]> for AnalysisSimple {
     fn check_preconditions(&self, taskset: &&[RTTask]) -> Result<(), SchedError> {
         if !RTUtils::constrained_deadlines(taskset) {
             Err(SchedError::constrained_deadlines())
+        } else if self.model.concurrency == 0 {
+            Err(zero_concurrency())
         } else {
             Ok(())
         }
     }
 
     fn run_test(&self, taskset: &[RTTask]) -> Result<(), SchedError> {
+        let tasks = TasksetSoA::new(taskset);
+        let mut block = InterferenceBlock::new(taskset.len());
+
         // Section 4.2, Theorem 1 [1]
         let schedulable =
-            is_schedulable_demand(
+            is_schedulable_demand_block(
                 taskset,
                 &self.model,
-                |taskset, k, task_k, model, arrival_k|
-                    demand_edf(taskset, k, task_k, model.concurrency, arrival_k),
+                ARRIVAL_BLOCK_SIZE,
+                |_, k, _, model, arrivals, demands|
+                    demand_edf_block(&tasks, k, model.concurrency, arrivals, demands, &mut block),
                 |taskset, _, task_k, model|  {
                     let arrival_k_upperbound =
                         arrival_k_upperbound_edf(taskset, task_k, model);
@@ -132,6 +182,8 @@ impl SchedDesign<&[RTTask], MPRModel> for DesignerLinear {
     fn check_preconditions(&self, taskset: &&[RTTask]) -> Result<(), SchedError> {
         if !RTUtils::constrained_deadlines(taskset) {
             Err(SchedError::constrained_deadlines())
+        } else if self.concurrency == 0 {
+            Err(zero_concurrency())
         } else {
             Ok(())
         }
@@ -150,71 +202,97 @@ impl SchedDesign<&[RTTask], MPRModel> for DesignerLinear {
                 // (0) and largest (mPi) possible values to bound Ak. [1]
                 let arrival_k_upperbound = concurrency as f64 * period;
 
-                Box::new(
-                    (0 ..= arrival_k_upperbound.as_nanos() as u64)
-                    .map(|time_ns| Time::nanos(time_ns as f64))
-                    .filter(|arrival_k| {
-                        // It is also easy to show that Equation (5) only needs to be
-                        // evaluated at those values of Ak for which at least one  of
-                        // I_hat, I_flat, or sbf change. [1]
-                        //
-                        // Both functions I_hat and I_flat change their value based on
-                        // Wi and CIi, on a periodic basis: their values are the same
-                        // every interval of the form [D_i + aT_i, D_i + T_I + aT_i] for
-                        // all a >= 0. The I_hat function also changes in the interval
-                        // [0, C_i]. The linear supply bound function changes at every
-                        // interval, but we can consider only the intervals where I_hat
-                        // and I_flat change, as it is a monotone function (i.e., if
-                        // it's satisfied between those intervals, it will be also
-                        // satisfied outside because of monotonicity).
-                        let interval = *arrival_k + task_k.deadline;
-
-                        // Perform the test only where I_hat/I_flat values change.
-                        taskset.iter().any(|task_i| {
-                            let modulus = *arrival_k % task_i.period;
-
-                            interval <= task_i.wcet || modulus == Time::zero()
-                        })
-                    })
-                )
+                // It is also easy to show that Equation (5) only needs to be
+                // evaluated at those values of Ak for which at least one  of
+                // I_hat, I_flat, or sbf change. [1]
+                //
+                // The linear supply bound function changes at every interval,
+                // but we can consider only the intervals where I_hat and
+                // I_flat change, as it is a monotone function (i.e., if it's
+                // satisfied between those intervals, it will be also satisfied
+                // outside because of monotonicity).
+                Box::new(task_arrival_points(taskset, task_k, arrival_k_upperbound))
             },
         )
         .ok_or(SchedError::NonSchedulable(None))
     }
 }
 
-fn filter_intervals_edf(
-    taskset: &[RTTask],
+fn zero_concurrency() -> SchedError {
+    SchedError::Precondition(Some(
+        anyhow::format_err!("the MPR Model must have a concurrency of at least one processor.")))
+}
+
+// Quick necessary conditions: no task may demand more than its deadline, and
+// the taskset may not demand more than the model supplies in the long run.
+fn necessary_conditions(taskset: &[RTTask], model: &MPRModel) -> Result<(), SchedError> {
+    if taskset.iter().any(|task| task.wcet > task.deadline) {
+        Err(SchedError::NonSchedulable(Some(
+            anyhow::format_err!("a task has density greater than one."))))
+    } else if RTUtils::total_utilization(taskset) > model.utilization() {
+        Err(SchedError::NonSchedulable(Some(
+            anyhow::format_err!("total utilization exceeds the model's bandwidth."))))
+    } else {
+        Ok(())
+    }
+}
+
+// Section 4.2, Theorem 1 [1]
+fn is_schedulable_filtered(taskset: &[RTTask], model: &MPRModel) -> bool {
+    let tasks = TasksetSoA::new(taskset);
+    let mut block = InterferenceBlock::new(taskset.len());
+
+    is_schedulable_demand_block(
+        taskset,
+        model,
+        ARRIVAL_BLOCK_SIZE,
+        |_, k, _, model, arrivals, demands|
+            demand_edf_block(&tasks, k, model.concurrency, arrivals, demands, &mut block),
+        |taskset, _, task_k, model| -> Box<dyn Iterator<Item = Time>> {
+            let arrival_k_upperbound =
+                arrival_k_upperbound_edf(taskset, task_k, model);
+
+            Box::new(arrival_points_edf(taskset, task_k, model, arrival_k_upperbound))
+        }
+    )
+}
+
+fn arrival_points_edf<'a>(
+    taskset: &'a [RTTask],
     task_k: &RTTask,
     model: &MPRModel,
-    arrival_k: Time
-) -> bool {
+    arrival_k_upperbound: Time,
+) -> impl Iterator<Item = Time> + 'a {
     // It is also easy to show that Equation (5) only needs to be evaluated at
     // those values of Ak for which at least one  of I_hat, I_flat, or sbf
     // change. [1]
     //
-    // Both functions I_hat and I_flat change their value based on Wi and CIi,
-    // on a periodic basis: their values are the same every interval of the form
-    // [D_i + aT_i, D_i + T_I + aT_i] for all a >= 0. The I_hat function also
-    // changes in the interval [0, C_i]. While the linear supply bound function
-    // changes at every interval, the non-linear sbf is constant for values in
-    // the range [-floor(Theta/m) + a*Pi, Pi - 2floor(Theta/m) + a*Pi] for all a
-    // >= 0.
-    let interval = arrival_k + task_k.deadline;
-
-    // Perform the test only where SBF changes
+    // While the linear supply bound function changes at every interval, the
+    // non-linear sbf is constant for values in the range [-floor(Theta/m) +
+    // a*Pi, Pi - 2floor(Theta/m) + a*Pi] for all a >= 0, i.e. for the
+    // floor(Theta/m) before each multiple of Pi, shifted by floor(Theta/m).
     let floor = (model.resource / model.concurrency as f64).floor();
-    let modulus = (interval + floor) % model.period;
-    if modulus >= model.period - floor || modulus == Time::zero() {
-        return true;
-    }
+    let arrival_k_upperbound = Time::max(arrival_k_upperbound, Time::zero());
 
-    // Perform the test only where I_hat/I_flat values change.
-    taskset.iter().any(|task_i| {
-        let modulus = arrival_k % task_i.period;
+    task_arrival_points(taskset, task_k, arrival_k_upperbound)
+        .merge(supply_points(model.period, floor, task_k.deadline + floor, arrival_k_upperbound))
+        .dedup()
+}
 
-        interval <= task_i.wcet || modulus == Time::zero()
-    })
+// Both functions I_hat and I_flat change their value based on Wi and CIi, on a
+// periodic basis: their values are the same every interval of the form [D_i +
+// aT_i, D_i + T_I + aT_i] for all a >= 0. The I_hat function also changes in
+// the interval [0, C_i].
+fn task_arrival_points<'a>(
+    taskset: &'a [RTTask],
+    task_k: &RTTask,
+    arrival_k_upperbound: Time,
+) -> impl Iterator<Item = Time> + 'a {
+    let max_wcet = taskset.iter().map(|task_i| task_i.wcet).max().unwrap_or(Time::zero());
+
+    merge_points(taskset.iter().map(move |task_i| release_points(task_i, arrival_k_upperbound)))
+        .merge(prefix_points(Time::min(max_wcet - task_k.deadline, arrival_k_upperbound)))
+        .dedup()
 }
 
 // Section 4.2, Theorem 2 [1]
@@ -242,6 +320,66 @@ fn arrival_k_upperbound_edf(taskset: &[RTTask], task_k: &RTTask, model: &MPRMode
     )
 }
 
+// Section 4.2, Theorem 1 [1]
+// The arrival points are evaluated in blocks, see [`InterferenceBlock`].
+fn demand_edf_block(
+    tasks: &TasksetSoA,
+    k: usize,
+    concurrency: u64,
+    arrivals: &[Time],
+    demands: &mut [Time],
+    block: &mut InterferenceBlock,
+) {
+    let (wcet_k, deadline_k) = (tasks.wcets[k], tasks.deadlines[k]);
+
+    for i in 0..tasks.len() {
+        let (wcet, deadline, period) = (tasks.wcets[i], tasks.deadlines[i], tasks.periods[i]);
+        let (interferences_hat, interferences_diff) = block.rows_mut(i);
+
+        for (j, arrival_k) in arrivals.iter().enumerate() {
+            let arrival_k = arrival_k.as_nanos();
+            let time = arrival_k + deadline_k;
+
+            // Equation 3 [1], as in Shin08::carry_in on raw values.
+            let activations = floor_block((time + period - deadline) / period);
+            let workload = activations * wcet;
+            let carry_in = f64::min(wcet, f64::max(0.0, time - activations * period));
+            let workload_2 = workload + carry_in;
+
+            let (hat, flat) =
+                if i == k {
+                    (f64::min(workload - wcet_k, arrival_k), f64::min(workload_2 - wcet_k, arrival_k))
+                } else {
+                    (f64::min(workload, time - wcet_k), f64::min(workload_2, time - wcet_k))
+                };
+
+            interferences_hat[j] = hat;
+            interferences_diff[j] = flat - hat;
+        }
+    }
+
+    let mut totals = [0.0; ARRIVAL_BLOCK_SIZE];
+    let totals = &mut totals[..arrivals.len()];
+    block.totals(concurrency as usize - 1, totals);
+
+    for (demand, total) in demands.iter_mut().zip(totals.iter()) {
+        *demand = Time::nanos(*total) + concurrency as f64 * Time::nanos(wcet_k);
+    }
+}
+
+// Same as f64::floor, which is a library call on the baseline x86-64 target,
+// for non-negative values. Adding and subtracting 2^52 rounds to the nearest
+// integer without any conversion, so that the loop over the arrival points
+// is vectorized; values from 2^52 upwards are already integers.
+#[inline(always)]
+fn floor_block(value: f64) -> f64 {
+    const TWO_POW_52: f64 = 4503599627370496.0;
+
+    let rounded = if value < TWO_POW_52 { (value + TWO_POW_52) - TWO_POW_52 } else { value };
+
+    if rounded > value { rounded - 1.0 } else { rounded }
+}
+
 // Section 4.2, Theorem 1 [1]
 fn demand_edf(taskset: &[RTTask], k: usize, task_k: &RTTask, concurrency: u64, arrival_k: Time) -> Time {
     let interference_hat: Vec<_> =
@@ -249,23 +387,18 @@ fn demand_edf(taskset: &[RTTask], k: usize, task_k: &RTTask, concurrency: u64, a
             .map(|(i, task_i)| interference_hat(i, task_i, k, task_k, arrival_k))
             .collect();
 
-    let mut interference_diff: Vec<_> =
+    let interference_flat: Vec<_> =
         taskset.iter().enumerate()
-            .map(|(i, task_i)| interference_flat(i, task_i, k, task_k, arrival_k) - interference_hat[i])
+            .map(|(i, task_i)| interference_flat(i, task_i, k, task_k, arrival_k))
             .collect();
 
-    interference_diff.sort_unstable();
-
-    let sum_interference_hat: Time = interference_hat.into_iter().sum();
-    let sum_interference_diff: Time = interference_diff.into_iter().rev()
-        .take(concurrency as usize - 1).sum();
-
-    sum_interference_hat + sum_interference_diff + concurrency as f64 * task_k.wcet
+    interference::total_interference(&interference_hat, &interference_flat, concurrency as usize - 1)
+        + concurrency as f64 * task_k.wcet
 }
 
 // Section 4.2, Theorem 1 [1]
 fn interference_flat(i: usize, task_i: &RTTask, k: usize, task_k: &RTTask, arrival_k: Time) -> Time {
-    let workload_upperbound = workload_upperbound_2_edf(task_i, arrival_k + task_k.deadline);
+    let workload_upperbound = Shin08.carry_in(task_i, task_i.deadline, arrival_k + task_k.deadline);
 
     if i == k {
         Time::min(workload_upperbound - task_k.wcet, arrival_k)
@@ -276,7 +409,7 @@ fn interference_flat(i: usize, task_i: &RTTask, k: usize, task_k: &RTTask, arriv
 
 // Section 4.2, Theorem 1 [1]
 fn interference_hat(i: usize, task_i: &RTTask, k: usize, task_k: &RTTask, arrival_k: Time) -> Time {
-    let workload_upperbound = workload_upperbound_edf(task_i, arrival_k + task_k.deadline);
+    let workload_upperbound = Shin08.non_carry_in(task_i, arrival_k + task_k.deadline);
 
     if i == k {
         Time::min(workload_upperbound - task_k.wcet, arrival_k)
@@ -285,33 +418,6 @@ fn interference_hat(i: usize, task_i: &RTTask, k: usize, task_k: &RTTask, arriva
     }
 }
 
-// Equation 3 [1]
-fn workload_upperbound_2_edf(task: &RTTask, time: Time) -> Time {
-    activations_in_interval_edf(task, time) * task.wcet + carry_in_edf(task, time)
-}
-
-fn workload_upperbound_edf(task: &RTTask, time: Time) -> Time {
-    activations_in_interval_edf(task, time) * task.wcet
-}
-
-// Equation 3 [1]
-#[inline(always)]
-fn activations_in_interval_edf(task: &RTTask, time: Time) -> f64 {
-    ((time + task.period - task.deadline) / task.period).floor()
-}
-
-// Equation 3 [1]
-#[inline(always)]
-fn carry_in_edf(task: &RTTask, time: Time) -> Time {
-    Time::min(
-        task.wcet,
-        Time::max(
-            Time::zero(),
-            time - activations_in_interval_edf(task, time) * task.period
-        )
-    )
-}
-
 pub mod extra {
     use crate::prelude::*;
     use crate::algorithms::full_preemption::global_multiprocessor::hierarchical::mpr_model09::*;
@@ -334,37 +440,51 @@ pub mod extra {
         fn check_preconditions(&self, taskset: &&[RTTask]) -> Result<(), SchedError> {
             if !RTUtils::constrained_deadlines(taskset) {
                 Err(SchedError::constrained_deadlines())
+            } else if self.concurrency == 0 {
+                Err(super::zero_concurrency())
             } else {
                 Ok(())
             }
         }
 
         fn run_designer(&self, taskset: &[RTTask]) -> Result<MPRModel, SchedError> {
-            let min_resource =
-                RTUtils::total_utilization(taskset) * self.period;
-            let max_resource = {
-                let designer = super::DesignerLinear { period: self.period, concurrency: self.concurrency };
+            self.search(taskset).run_designer(taskset)
+        }
 
-                designer.check_preconditions(&taskset)?;
-                designer.run_designer(taskset)?.resource
-            };
+        fn run_designer_with_stats(&self, taskset: &[RTTask]) -> DesignResult<MPRModel> {
+            self.search(taskset).run_designer_with_stats(taskset)
+        }
+    }
 
-            (extra::DesignerPeriodConcurrencyNaive {
+    impl DesignerPeriodConcurrency {
+        fn search<'a>(&'a self, taskset: &'a [RTTask]) -> impl SchedDesign<&'a [RTTask], MPRModel> + 'a {
+            extra::DesignerPeriodConcurrencyNaive {
                 period: self.period,
                 concurrency: self.concurrency,
-                resource_iter_fn: |_, _| Ok(Box::new(time_range_iterator_w_step(min_resource, max_resource, self.resource_step))),
+                resource_iter_fn: move |period, concurrency| {
+                    let min_resource =
+                        RTUtils::total_utilization(taskset) * period;
+                    let max_resource = {
+                        let designer = super::DesignerLinear { period, concurrency };
+
+                        designer.check_preconditions(&taskset)?;
+                        designer.run_designer(taskset)?.resource
+                    };
+
+                    Ok(Box::new(time_range_iterator_w_step(min_resource, max_resource, self.resource_step)))
+                },
                 analysis_gen_fn: |resource, period, concurrency|
                     super::Analysis { model: MPRModel { resource, period, concurrency }},
                 marker: std::marker::PhantomData,
-            })
-            .run_designer(taskset)
+            }
         }
     }
 
     /// MPR Model, EDF Local Scheduler - Shin, Easwaran, Lee 2009 \[1\]
     ///
     /// Generate the best MPRModel for the given taskset. Searches the space of
-    /// possible MPRModels given a range of valid periods.
+    /// possible MPRModels given a range of valid periods, refining the period
+    /// grid around the most promising periods (refer to [`extra::DesignerRefined`]).
     ///
     /// Refer to the [module](`self`) level documentation.
     pub struct DesignerFull {
@@ -384,16 +504,37 @@ pub mod extra {
         }
 
         fn run_designer(&self, taskset: &[RTTask]) -> Result<MPRModel, SchedError> {
+            self.search(taskset).run_designer(taskset)
+        }
+
+        fn run_designer_with_stats(&self, taskset: &[RTTask]) -> DesignResult<MPRModel> {
+            self.search(taskset).run_designer_with_stats(taskset)
+        }
+    }
+
+    impl SchedDesignResumable<&[RTTask], MPRModel, extra::SearchCheckpoint> for DesignerFull {
+        fn run_designer_resumable(
+            &self,
+            taskset: &[RTTask],
+            checkpoint: extra::SearchCheckpoint,
+            on_checkpoint: &mut dyn FnMut(&extra::SearchCheckpoint),
+        ) -> Result<MPRModel, SchedError> {
+            self.search(taskset).run_designer_resumable(taskset, checkpoint, on_checkpoint)
+        }
+    }
+
+    impl DesignerFull {
+        fn search<'a>(&'a self, taskset: &'a [RTTask]) -> impl SchedDesignResumable<&'a [RTTask], MPRModel, extra::SearchCheckpoint> + 'a {
             let min_processors =
                 num_processors_lower_bound(taskset);
 
             let max_processors =
                 num_processors_upper_bound(taskset);
 
-            let designer = extra::DesignerNaive {
-                period_iter_fn: || Ok(Box::new(time_range_iterator_w_step(self.period_range.0, self.period_range.1, self.period_range.2))),
-                concurrency_iter_fn: |_| Ok(Box::new(min_processors ..= max_processors)),
-                resource_iter_fn: |period, concurrency| {
+            extra::DesignerRefined {
+                period_range: self.period_range,
+                concurrency_iter_fn: move |_| Ok(Box::new(min_processors ..= max_processors)),
+                resource_iter_fn: move |period, concurrency| {
                     let min_resource =
                         RTUtils::total_utilization(taskset) * period;
                     let max_resource = {
@@ -408,14 +549,12 @@ pub mod extra {
                 analysis_gen_fn: |resource, period, concurrency|
                     super::Analysis { model: MPRModel { resource, period, concurrency }},
                 marker: std::marker::PhantomData,
-            };
-
-            designer.run_designer(taskset)
+            }
         }
     }
 
     fn num_processors_lower_bound(taskset: &[RTTask]) -> u64 {
-        f64::ceil(RTUtils::total_utilization(taskset)) as u64
+        u64::max(1, f64::ceil(RTUtils::total_utilization(taskset)) as u64)
     }
 
     // Section 5.1, Lemma 4 [1]
@@ -460,4 +599,81 @@ pub fn simple_vs_optimized() {
     let optimized_test = Analysis { model: model.clone() }.is_schedulable(&taskset);
     let simple_test = AnalysisSimple { model: model }.is_schedulable(&taskset);
     assert_eq!(optimized_test.is_ok(), simple_test.is_ok());
-}
\ No newline at end of file
+}
+
+#[test]
+pub fn block_vs_single() {
+    let taskset = [
+        RTTask::new_ns(35, 90, 160),
+        RTTask::new_ns(70, 115, 160),
+        RTTask::new_ns(30, 50, 75),
+        RTTask::new_ns(20, 60, 60),
+    ];
+
+    let arrivals: Vec<_> = (0..200).map(|ns| Time::nanos(ns as f64)).collect();
+    let tasks = TasksetSoA::new(&taskset);
+    let mut block = InterferenceBlock::new(taskset.len());
+
+    for concurrency in 1..=3 {
+        for (k, task_k) in taskset.iter().enumerate() {
+            for arrivals in arrivals.chunks(ARRIVAL_BLOCK_SIZE) {
+                let mut demands = vec![Time::zero(); arrivals.len()];
+                demand_edf_block(&tasks, k, concurrency, arrivals, &mut demands, &mut block);
+
+                for (arrival_k, demand) in arrivals.iter().zip(demands) {
+                    assert_eq!(demand, demand_edf(&taskset, k, task_k, concurrency, *arrival_k));
+                }
+            }
+        }
+    }
+}
+
+// Blocked evaluation of the demand against the per-point one.
+#[test]
+#[ignore]
+fn bench_demand_block() {
+    use crate::utils::bench;
+    use crate::utils::test_tasksets::random_taskset;
+
+    let concurrency = 8;
+
+    for (num_tasksets, num_tasks, num_arrivals) in [(20, 32, 4096), (4, 256, 512)] {
+        let tasksets: Vec<_> = (0..num_tasksets).map(|seed| random_taskset(num_tasks, 6.0, seed)).collect();
+        let arrivals: Vec<_> = (0..num_arrivals).map(|ns| Time::nanos(ns as f64)).collect();
+
+        let (single_sum, single) = bench::time("per point demand", || {
+            let mut single_sum = 0.0;
+            for taskset in tasksets.iter() {
+                for (k, task_k) in taskset.iter().enumerate() {
+                    for arrival_k in arrivals.iter() {
+                        single_sum += demand_edf(taskset, k, task_k, concurrency, *arrival_k).as_nanos();
+                    }
+                }
+            }
+
+            single_sum
+        });
+
+        let (block_sum, blocked) = bench::time("blocked demand", || {
+            let mut block_sum = 0.0;
+            for taskset in tasksets.iter() {
+                let tasks = TasksetSoA::new(taskset);
+                let mut block = InterferenceBlock::new(taskset.len());
+                let mut demands = vec![Time::zero(); ARRIVAL_BLOCK_SIZE];
+
+                for k in 0..taskset.len() {
+                    for arrivals in arrivals.chunks(ARRIVAL_BLOCK_SIZE) {
+                        let demands = &mut demands[..arrivals.len()];
+                        demand_edf_block(&tasks, k, concurrency, arrivals, demands, &mut block);
+                        block_sum += demands.iter().map(|demand| demand.as_nanos()).sum::<f64>();
+                    }
+                }
+            }
+
+            block_sum
+        });
+
+        bench::speedup(&format!("{num_tasks} tasks"), single, blocked);
+        assert!(f64::abs(single_sum - block_sum) <= 1e-9 * single_sum);
+    }
+}
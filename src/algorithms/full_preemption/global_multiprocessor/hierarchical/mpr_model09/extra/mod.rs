@@ -2,6 +2,85 @@ use crate::prelude::*;
 use super::MPRModel;
 
 use anyhow::Context as _;
+use std::time::{Duration, Instant};
+
+// State of a search: the statistics for [`SchedDesign::run_designer_with_stats`]
+// and the warm start of the resource searches. The time of each dimension is
+// the time spent generating its candidates; the time spent testing them is
+// accounted separately.
+#[derive(Default)]
+struct SearchState {
+    candidates: u64,
+    tightest_failing: Option<MPRModel>,
+    period_time: Duration,
+    concurrency_time: Duration,
+    resource_time: Duration,
+    analysis_time: Duration,
+    // Bandwidth of the last model found for each concurrency.
+    bandwidths: Vec<(u64, f64)>,
+}
+
+impl SearchState {
+    fn bandwidth(&self, concurrency: u64) -> Option<f64> {
+        self.bandwidths.iter()
+            .find(|(c, _)| *c == concurrency)
+            .map(|(_, bandwidth)| *bandwidth)
+    }
+
+    fn set_bandwidth(&mut self, concurrency: u64, bandwidth: f64) {
+        match self.bandwidths.iter_mut().find(|(c, _)| *c == concurrency) {
+            Some((_, last)) => *last = bandwidth,
+            None => self.bandwidths.push((concurrency, bandwidth)),
+        }
+    }
+
+    // The tightest failing candidate is the one of largest utilization, i.e.
+    // the closest to the resource which would make it schedulable.
+    fn record_failing(&mut self, model: MPRModel) {
+        if self.tightest_failing.as_ref().is_none_or(|tightest| model.utilization() > tightest.utilization()) {
+            self.tightest_failing = Some(model);
+        }
+    }
+
+    fn into_result(self, model: Result<MPRModel, SchedError>) -> DesignResult<MPRModel> {
+        DesignResult {
+            model: model.map_err(anyhow::Error::from),
+            candidates: self.candidates,
+            tightest_failing: self.tightest_failing,
+            search_time: vec![
+                ("period", self.period_time),
+                ("concurrency", self.concurrency_time),
+                ("resource", self.resource_time),
+                ("analysis", self.analysis_time),
+            ],
+        }
+    }
+}
+
+/// Progress of a [`DesignerNaive`] or [`DesignerRefined`] search, from which
+/// it can be resumed.
+#[derive(Debug, Clone, Default)]
+pub struct SearchCheckpoint {
+    /// Periods already searched, in search order, with the model of least
+    /// resource found for each, if any.
+    pub searched: Vec<(Time, Option<MPRModel>)>,
+}
+
+impl SearchCheckpoint {
+    /// Best model among the searched periods, i.e. of least utilization.
+    pub fn best(&self) -> Option<&MPRModel> {
+        self.searched.iter()
+            .filter_map(|(_, model)| model.as_ref())
+            .min_by(|l, r| l.utilization().total_cmp(&r.utilization()))
+    }
+}
+
+fn timed<T>(time: &mut Duration, f: impl FnOnce() -> T) -> T {
+    let start = Instant::now();
+    let out = f();
+    *time += start.elapsed();
+    out
+}
 
 pub struct DesignerPeriodConcurrencyNaive<'a, FnA, A, FnR>
     where
@@ -16,6 +95,59 @@ pub struct DesignerPeriodConcurrencyNaive<'a, FnA, A, FnR>
     pub marker: std::marker::PhantomData<&'a [RTTask]>,
 }
 
+impl<'a, FnA, A, FnR> DesignerPeriodConcurrencyNaive<'a, FnA, A, FnR>
+    where
+        A: SchedAnalysis<(), &'a [RTTask]>,
+        FnA: Fn(Time, Time, u64) -> A,
+        FnR: Fn(Time, u64) -> Result<Box<dyn Iterator<Item = Time>>, SchedError>,
+{
+    // The resources are searched from the one of the same bandwidth as the
+    // last model found for the concurrency, as the minimum resource varies
+    // smoothly with the period: downwards while schedulable, otherwise
+    // upwards. Schedulability is assumed to be monotone in the resource.
+    fn search(&self, taskset: &'a [RTTask], stats: &mut SearchState) -> Result<MPRModel, SchedError> {
+        let resources: Vec<Time> = timed(&mut stats.resource_time, || (self.resource_iter_fn)(self.period, self.concurrency))?
+            .collect();
+
+        let start = stats.bandwidth(self.concurrency)
+            .map_or(0, |bandwidth| {
+                resources.partition_point(|resource| *resource < self.period * bandwidth)
+                    .min(resources.len().saturating_sub(1))
+            });
+
+        let mut is_schedulable = |resource: Time| {
+            let model = MPRModel { resource, period: self.period, concurrency: self.concurrency };
+            let analysis = (self.analysis_gen_fn)(resource, self.period, self.concurrency);
+
+            stats.candidates += 1;
+            if timed(&mut stats.analysis_time, || analysis.is_schedulable(taskset)).is_ok() {
+                true
+            } else {
+                stats.record_failing(model);
+                false
+            }
+        };
+
+        let found =
+            if start > 0 && is_schedulable(resources[start]) {
+                let mut lowest = start;
+                while lowest > 0 && is_schedulable(resources[lowest - 1]) {
+                    lowest -= 1;
+                }
+
+                Some(lowest)
+            } else {
+                let first = if start > 0 { start + 1 } else { 0 };
+                (first..resources.len()).find(|&i| is_schedulable(resources[i]))
+            };
+
+        let resource = resources[found.ok_or(SchedError::NonSchedulable(None))?];
+        stats.set_bandwidth(self.concurrency, resource / self.period);
+
+        Ok(MPRModel { resource, period: self.period, concurrency: self.concurrency })
+    }
+}
+
 impl<'a, FnA, A, FnR> SchedDesign<&'a [RTTask], MPRModel> for DesignerPeriodConcurrencyNaive<'a, FnA, A, FnR>
     where
         A: SchedAnalysis<(), &'a [RTTask]>,
@@ -31,23 +163,29 @@ impl<'a, FnA, A, FnR> SchedDesign<&'a [RTTask], MPRModel> for DesignerPeriodConc
     }
 
     fn run_designer(&self, taskset: &'a [RTTask]) -> Result<MPRModel, SchedError> {
-        (self.resource_iter_fn)(self.period, self.concurrency)?
-        .find_map(|resource| {
-            let analysis = (self.analysis_gen_fn)(resource, self.period, self.concurrency);
-
-            if analysis.is_schedulable(taskset).is_ok() {
-                Some(MPRModel { resource, period: self.period, concurrency: self.concurrency })
-            } else {
-                None
-            }
-        })
-        .ok_or(SchedError::NonSchedulable(None))
+        self.search(taskset, &mut SearchState::default())
     }
 
     fn design(&self, taskset: &'a [RTTask]) -> anyhow::Result<MPRModel> {
         self.run_designer(taskset)
             .with_context(|| std::format!("Designer error for \"{}\"", self.designer_name()))
     }
+
+    fn run_designer_with_stats(&self, taskset: &'a [RTTask]) -> DesignResult<MPRModel> {
+        let mut stats = SearchState::default();
+        let model = self.search(taskset, &mut stats);
+
+        stats.into_result(model)
+    }
+
+    fn design_with_stats(&self, taskset: &'a [RTTask]) -> DesignResult<MPRModel> {
+        let result = self.run_designer_with_stats(taskset);
+        DesignResult {
+            model: result.model
+                .with_context(|| std::format!("Designer error for \"{}\"", self.designer_name())),
+            ..result
+        }
+    }
 }
 
 pub struct DesignerPeriodNaive<'a, FnA, A, FnR, FnC>
@@ -64,6 +202,34 @@ pub struct DesignerPeriodNaive<'a, FnA, A, FnR, FnC>
     pub marker: std::marker::PhantomData<&'a [RTTask]>,
 }
 
+impl<'a, FnA, A, FnR, FnC> DesignerPeriodNaive<'a, FnA, A, FnR, FnC>
+    where
+        A: SchedAnalysis<(), &'a [RTTask]>,
+        FnA: Fn(Time, Time, u64) -> A + Clone,
+        FnR: Fn(Time, u64) -> Result<Box<dyn Iterator<Item = Time>>, SchedError> + Clone,
+        FnC: Fn(Time) -> Result<Box<dyn Iterator<Item = u64>>, SchedError>,
+{
+    fn search(&self, taskset: &'a [RTTask], stats: &mut SearchState) -> Result<MPRModel, SchedError> {
+        let mut concurrencies = timed(&mut stats.concurrency_time, || (self.concurrency_iter_fn)(self.period))?;
+
+        while let Some(concurrency) = timed(&mut stats.concurrency_time, || concurrencies.next()) {
+            let designer = DesignerPeriodConcurrencyNaive {
+                period: self.period,
+                concurrency,
+                resource_iter_fn: self.resource_iter_fn.clone(),
+                analysis_gen_fn: self.analysis_gen_fn.clone(),
+                marker: std::marker::PhantomData,
+            };
+
+            if let Ok(model) = designer.search(taskset, stats) {
+                return Ok(model);
+            }
+        }
+
+        Err(SchedError::NonSchedulable(None))
+    }
+}
+
 impl<'a, FnA, A, FnR, FnC> SchedDesign<&'a [RTTask], MPRModel> for DesignerPeriodNaive<'a, FnA, A, FnR, FnC>
     where
         A: SchedAnalysis<(), &'a [RTTask]>,
@@ -80,24 +246,29 @@ impl<'a, FnA, A, FnR, FnC> SchedDesign<&'a [RTTask], MPRModel> for DesignerPerio
     }
 
     fn run_designer(&self, taskset: &'a [RTTask]) -> Result<MPRModel, SchedError> {
-        (self.concurrency_iter_fn)(self.period)?
-        .find_map(|concurrency| {
-            (DesignerPeriodConcurrencyNaive {
-                period: self.period,
-                concurrency: concurrency,
-                resource_iter_fn: self.resource_iter_fn.clone(),
-                analysis_gen_fn: self.analysis_gen_fn.clone(),
-                marker: std::marker::PhantomData,
-            })
-            .run_designer(taskset).ok()
-        })
-        .ok_or(SchedError::NonSchedulable(None))
+        self.search(taskset, &mut SearchState::default())
     }
 
     fn design(&self, taskset: &'a [RTTask]) -> anyhow::Result<MPRModel> {
         self.run_designer(taskset)
             .with_context(|| std::format!("Designer error for \"{}\"", self.designer_name()))
     }
+
+    fn run_designer_with_stats(&self, taskset: &'a [RTTask]) -> DesignResult<MPRModel> {
+        let mut stats = SearchState::default();
+        let model = self.search(taskset, &mut stats);
+
+        stats.into_result(model)
+    }
+
+    fn design_with_stats(&self, taskset: &'a [RTTask]) -> DesignResult<MPRModel> {
+        let result = self.run_designer_with_stats(taskset);
+        DesignResult {
+            model: result.model
+                .with_context(|| std::format!("Designer error for \"{}\"", self.designer_name())),
+            ..result
+        }
+    }
 }
 
 pub struct DesignerNaive<'a, FnA, A, FnR, FnC, FnP>
@@ -115,6 +286,41 @@ pub struct DesignerNaive<'a, FnA, A, FnR, FnC, FnP>
     pub marker: std::marker::PhantomData<&'a [RTTask]>,
 }
 
+impl<'a, FnA, A, FnR, FnC, FnP> DesignerNaive<'a, FnA, A, FnR, FnC, FnP>
+    where
+        A: SchedAnalysis<(), &'a [RTTask]>,
+        FnA: Fn(Time, Time, u64) -> A + Clone,
+        FnR: Fn(Time, u64) -> Result<Box<dyn Iterator<Item = Time>>, SchedError> + Clone,
+        FnC: Fn(Time) -> Result<Box<dyn Iterator<Item = u64>>, SchedError> + Clone,
+        FnP: Fn() -> Result<Box<dyn Iterator<Item = Time>>, SchedError>,
+{
+    fn search(
+        &self,
+        taskset: &'a [RTTask],
+        stats: &mut SearchState,
+        checkpoint: &mut SearchCheckpoint,
+        on_checkpoint: &mut dyn FnMut(&SearchCheckpoint),
+    ) -> Result<MPRModel, SchedError> {
+        let mut periods = timed(&mut stats.period_time, || (self.period_iter_fn)())?
+            .skip(checkpoint.searched.len());
+
+        while let Some(period) = timed(&mut stats.period_time, || periods.next()) {
+            let designer = DesignerPeriodNaive {
+                period,
+                concurrency_iter_fn: self.concurrency_iter_fn.clone(),
+                resource_iter_fn: self.resource_iter_fn.clone(),
+                analysis_gen_fn: self.analysis_gen_fn.clone(),
+                marker: std::marker::PhantomData,
+            };
+
+            checkpoint.searched.push((period, designer.search(taskset, stats).ok()));
+            on_checkpoint(checkpoint);
+        }
+
+        checkpoint.best().cloned().ok_or(SchedError::NonSchedulable(None))
+    }
+}
+
 impl<'a, FnA, A, FnR, FnC, FnP> SchedDesign<&'a [RTTask], MPRModel> for DesignerNaive<'a, FnA, A, FnR, FnC, FnP>
     where
         A: SchedAnalysis<(), &'a [RTTask]>,
@@ -132,23 +338,425 @@ impl<'a, FnA, A, FnR, FnC, FnP> SchedDesign<&'a [RTTask], MPRModel> for Designer
     }
 
     fn run_designer(&self, taskset: &'a [RTTask]) -> Result<MPRModel, SchedError> {
-        (self.period_iter_fn)()?
-        .flat_map(|period| {
-            (DesignerPeriodNaive {
-                period,
-                concurrency_iter_fn: self.concurrency_iter_fn.clone(),
-                resource_iter_fn: self.resource_iter_fn.clone(),
-                analysis_gen_fn: self.analysis_gen_fn.clone(),
-                marker: std::marker::PhantomData,
-            })
-            .run_designer(taskset).ok()
-        })
-        .min_by(|l, r| l.utilization().total_cmp(&r.utilization()))
-        .ok_or(SchedError::NonSchedulable(None))
+        self.run_designer_resumable(taskset, SearchCheckpoint::default(), &mut |_| ())
+    }
+
+    fn design(&self, taskset: &'a [RTTask]) -> anyhow::Result<MPRModel> {
+        self.run_designer(taskset)
+            .with_context(|| std::format!("Designer error for \"{}\"", self.designer_name()))
+    }
+
+    fn run_designer_with_stats(&self, taskset: &'a [RTTask]) -> DesignResult<MPRModel> {
+        let mut stats = SearchState::default();
+        let model = self.search(taskset, &mut stats, &mut SearchCheckpoint::default(), &mut |_| ());
+
+        stats.into_result(model)
+    }
+
+    fn design_with_stats(&self, taskset: &'a [RTTask]) -> DesignResult<MPRModel> {
+        let result = self.run_designer_with_stats(taskset);
+        DesignResult {
+            model: result.model
+                .with_context(|| std::format!("Designer error for \"{}\"", self.designer_name())),
+            ..result
+        }
+    }
+}
+
+// The checkpoint is updated after each period.
+impl<'a, FnA, A, FnR, FnC, FnP> SchedDesignResumable<&'a [RTTask], MPRModel, SearchCheckpoint> for DesignerNaive<'a, FnA, A, FnR, FnC, FnP>
+    where
+        A: SchedAnalysis<(), &'a [RTTask]>,
+        FnA: Fn(Time, Time, u64) -> A + Clone,
+        FnR: Fn(Time, u64) -> Result<Box<dyn Iterator<Item = Time>>, SchedError> + Clone,
+        FnC: Fn(Time) -> Result<Box<dyn Iterator<Item = u64>>, SchedError> + Clone,
+        FnP: Fn() -> Result<Box<dyn Iterator<Item = Time>>, SchedError>,
+{
+    fn run_designer_resumable(
+        &self,
+        taskset: &'a [RTTask],
+        mut checkpoint: SearchCheckpoint,
+        on_checkpoint: &mut dyn FnMut(&SearchCheckpoint),
+    ) -> Result<MPRModel, SchedError> {
+        self.search(taskset, &mut SearchState::default(), &mut checkpoint, on_checkpoint)
+    }
+
+    fn design_resumable(
+        &self,
+        taskset: &'a [RTTask],
+        checkpoint: SearchCheckpoint,
+        on_checkpoint: &mut dyn FnMut(&SearchCheckpoint),
+    ) -> anyhow::Result<MPRModel> {
+        self.run_designer_resumable(taskset, checkpoint, on_checkpoint)
+            .with_context(|| std::format!("Designer error for \"{}\"", self.designer_name()))
+    }
+}
+
+/// Coarse-to-fine period search. The periods of the range are first searched on
+/// a coarse grid of at most [`DesignerRefined::COARSE_POINTS`] periods, whose
+/// step is the final one times a power of two. Then the step is halved until
+/// it reaches the final one, each time searching the periods next to the
+/// valleys of the utilization, i.e. the [`DesignerRefined::VALLEYS`] best local
+/// minima, or next to every searched period while none is schedulable.
+///
+/// Being a heuristic, the search may miss a minimum between two coarse
+/// periods which is lower than the ones it refines, in exchange for searching
+/// much fewer periods than [`DesignerNaive`].
+pub struct DesignerRefined<'a, FnA, A, FnR, FnC>
+    where
+        A: SchedAnalysis<(), &'a [RTTask]>,
+        FnA: Fn(Time, Time, u64) -> A + Clone,
+        FnR: Fn(Time, u64) -> Result<Box<dyn Iterator<Item = Time>>, SchedError> + Clone,
+        FnC: Fn(Time) -> Result<Box<dyn Iterator<Item = u64>>, SchedError> + Clone,
+{
+    /// Minimum period, maximum period and final step.
+    pub period_range: (Time, Time, Time),
+    pub concurrency_iter_fn: FnC,
+    pub resource_iter_fn: FnR,
+    pub analysis_gen_fn: FnA,
+    pub marker: std::marker::PhantomData<&'a [RTTask]>,
+}
+
+impl<'a, FnA, A, FnR, FnC> DesignerRefined<'a, FnA, A, FnR, FnC>
+    where
+        A: SchedAnalysis<(), &'a [RTTask]>,
+        FnA: Fn(Time, Time, u64) -> A + Clone,
+        FnR: Fn(Time, u64) -> Result<Box<dyn Iterator<Item = Time>>, SchedError> + Clone,
+        FnC: Fn(Time) -> Result<Box<dyn Iterator<Item = u64>>, SchedError> + Clone,
+{
+    pub const COARSE_POINTS: f64 = 16.0;
+    pub const VALLEYS: usize = 3;
+
+    fn search(
+        &self,
+        taskset: &'a [RTTask],
+        stats: &mut SearchState,
+        checkpoint: &mut SearchCheckpoint,
+        on_checkpoint: &mut dyn FnMut(&SearchCheckpoint),
+    ) -> Result<MPRModel, SchedError> {
+        let (min_period, max_period, step) = self.period_range;
+        if step <= Time::zero() || min_period > max_period {
+            return Err(SchedError::Other(
+                anyhow::format_err!("invalid period range.")));
+        }
+
+        let mut grid_step = step;
+        while (max_period - min_period) / grid_step > Self::COARSE_POINTS {
+            grid_step = grid_step * 2.0;
+        }
+
+        let coarse: Vec<_> = timed(&mut stats.period_time, ||
+            time_range_iterator_w_step(min_period, max_period, grid_step).collect());
+        for period in coarse {
+            self.search_period(taskset, period, stats, checkpoint, on_checkpoint);
+        }
+
+        while grid_step > step {
+            grid_step = grid_step / 2.0;
+
+            let periods = timed(&mut stats.period_time, || {
+                let mut searched: Vec<_> = checkpoint.searched.iter()
+                    .map(|(period, model)| (*period, model.as_ref().map(MPRModel::utilization)))
+                    .collect();
+                searched.sort_by_key(|(period, _)| *period);
+
+                let mut valleys: Vec<_> = (0..searched.len())
+                    .filter(|&i| {
+                        let Some(utilization) = searched[i].1 else { return false };
+                        let higher = |j: usize| searched[j].1.is_none_or(|other| other >= utilization);
+
+                        (i == 0 || higher(i - 1)) && (i + 1 == searched.len() || higher(i + 1))
+                    })
+                    .collect();
+                valleys.sort_by(|&l, &r| searched[l].1.unwrap().total_cmp(&searched[r].1.unwrap()));
+                valleys.truncate(Self::VALLEYS);
+
+                let centers: Vec<_> =
+                    if valleys.is_empty() {
+                        searched.iter().map(|(period, _)| *period).collect()
+                    } else {
+                        valleys.into_iter().map(|i| searched[i].0).collect()
+                    };
+
+                let mut periods: Vec<_> = centers.into_iter()
+                    .flat_map(|center| [center - grid_step, center + grid_step])
+                    .filter(|period| *period >= min_period && *period <= max_period)
+                    .collect();
+                periods.sort();
+                periods.dedup();
+                periods
+            });
+
+            for period in periods {
+                self.search_period(taskset, period, stats, checkpoint, on_checkpoint);
+            }
+        }
+
+        checkpoint.best().cloned().ok_or(SchedError::NonSchedulable(None))
+    }
+
+    // Periods already in the checkpoint are not searched again, thus a resumed
+    // search replays the same choices.
+    fn search_period(
+        &self,
+        taskset: &'a [RTTask],
+        period: Time,
+        stats: &mut SearchState,
+        checkpoint: &mut SearchCheckpoint,
+        on_checkpoint: &mut dyn FnMut(&SearchCheckpoint),
+    ) {
+        if checkpoint.searched.iter().any(|(searched, _)| *searched == period) {
+            return;
+        }
+
+        let designer = DesignerPeriodNaive {
+            period,
+            concurrency_iter_fn: self.concurrency_iter_fn.clone(),
+            resource_iter_fn: self.resource_iter_fn.clone(),
+            analysis_gen_fn: self.analysis_gen_fn.clone(),
+            marker: std::marker::PhantomData,
+        };
+
+        checkpoint.searched.push((period, designer.search(taskset, stats).ok()));
+        on_checkpoint(checkpoint);
+    }
+}
+
+impl<'a, FnA, A, FnR, FnC> SchedDesign<&'a [RTTask], MPRModel> for DesignerRefined<'a, FnA, A, FnR, FnC>
+    where
+        A: SchedAnalysis<(), &'a [RTTask]>,
+        FnA: Fn(Time, Time, u64) -> A + Clone,
+        FnR: Fn(Time, u64) -> Result<Box<dyn Iterator<Item = Time>>, SchedError> + Clone,
+        FnC: Fn(Time) -> Result<Box<dyn Iterator<Item = u64>>, SchedError> + Clone,
+{
+    fn designer_name(&self) -> &str { "MPR Model designer, refined period search" }
+
+    fn check_preconditions(&self, _: &&'a [RTTask]) -> Result<(), SchedError> {
+        Err(SchedError::Other(
+            anyhow::format_err!("This generic implementor of SchedDesign cannot check for preconditions")
+        ))
+    }
+
+    fn run_designer(&self, taskset: &'a [RTTask]) -> Result<MPRModel, SchedError> {
+        self.run_designer_resumable(taskset, SearchCheckpoint::default(), &mut |_| ())
     }
 
     fn design(&self, taskset: &'a [RTTask]) -> anyhow::Result<MPRModel> {
         self.run_designer(taskset)
             .with_context(|| std::format!("Designer error for \"{}\"", self.designer_name()))
     }
-}
\ No newline at end of file
+
+    fn run_designer_with_stats(&self, taskset: &'a [RTTask]) -> DesignResult<MPRModel> {
+        let mut stats = SearchState::default();
+        let model = self.search(taskset, &mut stats, &mut SearchCheckpoint::default(), &mut |_| ());
+
+        stats.into_result(model)
+    }
+
+    fn design_with_stats(&self, taskset: &'a [RTTask]) -> DesignResult<MPRModel> {
+        let result = self.run_designer_with_stats(taskset);
+        DesignResult {
+            model: result.model
+                .with_context(|| std::format!("Designer error for \"{}\"", self.designer_name())),
+            ..result
+        }
+    }
+}
+
+impl<'a, FnA, A, FnR, FnC> SchedDesignResumable<&'a [RTTask], MPRModel, SearchCheckpoint> for DesignerRefined<'a, FnA, A, FnR, FnC>
+    where
+        A: SchedAnalysis<(), &'a [RTTask]>,
+        FnA: Fn(Time, Time, u64) -> A + Clone,
+        FnR: Fn(Time, u64) -> Result<Box<dyn Iterator<Item = Time>>, SchedError> + Clone,
+        FnC: Fn(Time) -> Result<Box<dyn Iterator<Item = u64>>, SchedError> + Clone,
+{
+    fn run_designer_resumable(
+        &self,
+        taskset: &'a [RTTask],
+        mut checkpoint: SearchCheckpoint,
+        on_checkpoint: &mut dyn FnMut(&SearchCheckpoint),
+    ) -> Result<MPRModel, SchedError> {
+        self.search(taskset, &mut SearchState::default(), &mut checkpoint, on_checkpoint)
+    }
+
+    fn design_resumable(
+        &self,
+        taskset: &'a [RTTask],
+        checkpoint: SearchCheckpoint,
+        on_checkpoint: &mut dyn FnMut(&SearchCheckpoint),
+    ) -> anyhow::Result<MPRModel> {
+        self.run_designer_resumable(taskset, checkpoint, on_checkpoint)
+            .with_context(|| std::format!("Designer error for \"{}\"", self.designer_name()))
+    }
+}
+
+#[test]
+fn design_search_stats() {
+    use super::fixed_priority::bcl09;
+
+    let taskset = [
+        RTTask::new_ns(3, 10, 10),
+        RTTask::new_ns(3, 10, 10),
+        RTTask::new_ns(4, 20, 20),
+    ];
+
+    let designer = |max_resource: u64| DesignerPeriodConcurrencyNaive {
+        period: Time::nanos(10.0),
+        concurrency: 2,
+        resource_iter_fn: move |_, _| Ok(Box::new((1 ..= max_resource).map(|resource| Time::nanos(resource as f64)))),
+        analysis_gen_fn: |resource, period, concurrency|
+            bcl09::Analysis { model: MPRModel { resource, period, concurrency }},
+        marker: std::marker::PhantomData,
+    };
+
+    // Every resource up to the designed one is a candidate, the one just
+    // below it is the tightest failing.
+    let result = designer(20).design_with_stats(&taskset);
+    let model = result.model.unwrap();
+    assert_eq!(model.resource, designer(20).design(&taskset).unwrap().resource);
+    assert_eq!(result.candidates, model.resource.as_nanos() as u64);
+    assert_eq!(result.tightest_failing.unwrap().resource, model.resource - Time::one());
+    assert_eq!(result.search_time.iter().map(|(dimension, _)| *dimension).collect::<Vec<_>>(),
+        vec!["period", "concurrency", "resource", "analysis"]);
+
+    // A search which fails at the boundary reports its last candidate.
+    let max_resource = model.resource.as_nanos() as u64 - 1;
+    let result = designer(max_resource).design_with_stats(&taskset);
+    assert!(result.model.is_err());
+    assert_eq!(result.candidates, max_resource);
+    assert_eq!(result.tightest_failing.unwrap().resource, Time::nanos(max_resource as f64));
+}
+
+#[test]
+fn resumable_search() {
+    use super::fixed_priority::bcl09;
+
+    let taskset = [
+        RTTask::new_ns(3, 10, 10),
+        RTTask::new_ns(3, 10, 10),
+        RTTask::new_ns(4, 20, 20),
+    ];
+
+    let designer = DesignerNaive {
+        period_iter_fn: || Ok(Box::new([4, 5, 8, 10].into_iter().map(|period| Time::nanos(period as f64)))),
+        concurrency_iter_fn: |_| Ok(Box::new(1 ..= 3)),
+        resource_iter_fn: |period: Time, concurrency| Ok(Box::new(
+            (1 ..= (period.as_nanos() as u64) * concurrency).map(|resource| Time::nanos(resource as f64)))),
+        analysis_gen_fn: |resource, period, concurrency|
+            bcl09::Analysis { model: MPRModel { resource, period, concurrency }},
+        marker: std::marker::PhantomData,
+    };
+
+    let model = designer.design(&taskset).unwrap();
+
+    // Interrupted after two periods, then resumed from the saved checkpoint.
+    let mut saved = None;
+    let _ = designer.design_resumable(&taskset, SearchCheckpoint::default(), &mut |checkpoint| {
+        if checkpoint.searched.len() == 2 {
+            saved = Some(checkpoint.clone());
+        }
+    });
+
+    let mut resumed_periods = 0;
+    let resumed = designer.design_resumable(&taskset, saved.unwrap(), &mut |_| resumed_periods += 1).unwrap();
+    assert_eq!(resumed_periods, 2);
+    assert_eq!((resumed.resource, resumed.period, resumed.concurrency), (model.resource, model.period, model.concurrency));
+}
+
+#[test]
+fn refined_period_search() {
+    use super::fixed_priority::bcl09;
+
+    let taskset = [
+        RTTask::new_ns(3, 10, 10),
+        RTTask::new_ns(3, 10, 10),
+        RTTask::new_ns(4, 20, 20),
+    ];
+
+    let (min_period, max_period) = (Time::nanos(1.0), Time::nanos(64.0));
+    let concurrency_iter_fn = |_| Ok(Box::new(1u64 ..= 3) as Box<dyn Iterator<Item = u64>>);
+    let resource_iter_fn = |period: Time, concurrency| Ok(Box::new(
+        (1 ..= (period.as_nanos() as u64) * concurrency).map(|resource| Time::nanos(resource as f64))) as Box<dyn Iterator<Item = Time>>);
+    let analysis_gen_fn = |resource, period, concurrency|
+        bcl09::Analysis { model: MPRModel { resource, period, concurrency }};
+
+    let naive = DesignerNaive {
+        period_iter_fn: || Ok(Box::new(time_range_iterator_w_step(min_period, max_period, Time::one()))),
+        concurrency_iter_fn, resource_iter_fn, analysis_gen_fn,
+        marker: std::marker::PhantomData,
+    }.design_with_stats(&taskset);
+
+    let refined = DesignerRefined {
+        period_range: (min_period, max_period, Time::one()),
+        concurrency_iter_fn, resource_iter_fn, analysis_gen_fn,
+        marker: std::marker::PhantomData,
+    };
+    let result = refined.design_with_stats(&taskset);
+
+    let (naive_model, model) = (naive.model.unwrap(), result.model.unwrap());
+    assert_eq!(model.utilization(), naive_model.utilization());
+    assert!(result.candidates < naive.candidates / 2);
+
+    // A resumed search makes the same choices.
+    let mut saved = None;
+    let mut searched = 0;
+    let _ = refined.design_resumable(&taskset, SearchCheckpoint::default(), &mut |checkpoint| {
+        searched = checkpoint.searched.len();
+        if searched == 5 {
+            saved = Some(checkpoint.clone());
+        }
+    });
+
+    let mut resumed_searched = 0;
+    let resumed = refined.design_resumable(&taskset, saved.unwrap(), &mut |checkpoint| resumed_searched = checkpoint.searched.len()).unwrap();
+    assert_eq!(resumed_searched, searched);
+    assert_eq!(resumed.utilization(), model.utilization());
+}
+
+#[test]
+fn warm_started_resource_search() {
+    use super::fixed_priority::bcl09;
+
+    let taskset = [
+        RTTask::new_ns(30, 100, 100),
+        RTTask::new_ns(30, 100, 100),
+        RTTask::new_ns(40, 200, 200),
+    ];
+
+    let periods = || time_range_iterator_w_step(Time::nanos(20.0), Time::nanos(40.0), Time::one());
+    let concurrency_iter_fn = |_| Ok(Box::new(2u64 ..= 2) as Box<dyn Iterator<Item = u64>>);
+    let resource_iter_fn = |period: Time, concurrency| Ok(Box::new(
+        (1 ..= (period.as_nanos() as u64) * concurrency).map(|resource| Time::nanos(resource as f64))) as Box<dyn Iterator<Item = Time>>);
+    let analysis_gen_fn = |resource, period, concurrency|
+        bcl09::Analysis { model: MPRModel { resource, period, concurrency }};
+
+    let warm = DesignerNaive {
+        period_iter_fn: || Ok(Box::new(periods())),
+        concurrency_iter_fn, resource_iter_fn, analysis_gen_fn,
+        marker: std::marker::PhantomData,
+    };
+
+    // Each period searched on its own starts from the smallest resource.
+    let mut cold_candidates = 0;
+    let mut cold_models = Vec::new();
+    for period in periods() {
+        let result = DesignerPeriodNaive {
+            period, concurrency_iter_fn, resource_iter_fn, analysis_gen_fn,
+            marker: std::marker::PhantomData,
+        }.design_with_stats(&taskset);
+
+        cold_candidates += result.candidates;
+        cold_models.push((period, result.model.ok().map(|model| model.resource)));
+    }
+
+    let mut warm_models = Vec::new();
+    let result = warm.run_designer_with_stats(&taskset);
+    let _ = warm.design_resumable(&taskset, SearchCheckpoint::default(), &mut |checkpoint| {
+        warm_models = checkpoint.searched.iter()
+            .map(|(period, model)| (*period, model.as_ref().map(|model| model.resource)))
+            .collect();
+    });
+
+    assert_eq!(warm_models, cold_models);
+    assert!(result.candidates < cold_candidates / 4);
+}
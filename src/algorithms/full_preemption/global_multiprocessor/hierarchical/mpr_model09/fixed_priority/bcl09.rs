@@ -7,9 +7,22 @@
 //! #### Preconditions:
 //! - Constrained Deadlines
 //!
+//! The response time analysis bounds the response time of each task with the
+//! least interval whose linear supply covers the workload of the higher
+//! priority tasks \[1\] plus *concurrency* times the task's WCET: while the
+//! task is pending but not running, all the supplied processors are busy with
+//! higher priority work, and while it is running, at most *concurrency*
+//! processors are supplied.
+//!
 //! #### Implements:
 //! - [`Analysis::is_schedulable`] \
 //!   | O(*n^2*) complexity
+//! - [`AnalysisResponseTime::is_schedulable`] \
+//!   | pseudo-polynomial complexity
+//! - [`AnalysisWarmStart::is_schedulable`] \
+//!   | Start the fixpoint iterations from previously computed response times. \
+//!   | \
+//!   | pseudo-polynomial complexity
 //! - [`DesignerLinear::design`] \
 //!   | O(*n^2*) complexity
 //! - [`extra::DesignerPeriodConcurrency::design`] \
@@ -23,10 +36,15 @@
 //!    Scheduling Algorithms on Multiprocessor Platforms,” IEEE Transactions on
 //!    Parallel and Distributed Systems, vol. 20, no. 4, pp. 553–566, Apr. 2009,
 //!    doi: 10.1109/TPDS.2008.129.
+//! 2. A. Easwaran, I. Shin, and I. Lee, “Optimal virtual cluster-based
+//!    multiprocessor scheduling,” Real-Time Syst, vol. 43, no. 1, pp. 25–59,
+//!    Sept. 2009, doi: 10.1007/s11241-009-9073-x.
 
 use crate::prelude::*;
 use crate::algorithms::full_preemption::global_multiprocessor::hierarchical::mpr_model09::*;
 use crate::algorithms::full_preemption::global_multiprocessor::fixed_priority::bcl09::global_fixed_priority_demand;
+use crate::utils::interference::{Bcl09, InterferenceModel};
+use crate::utils::warm_start::warm_start_response_time;
 
 const ALGORITHM: &str = "MPR Model, FP Local Scheduler (*Derived from* Bertogna, Cirinei, Lipari 2009)";
 
@@ -62,6 +80,102 @@ impl SchedAnalysis<(), &[RTTask]> for Analysis {
     }
 }
 
+/// MPR Model, FP Local Scheduler - *Derived from* Bertogna, Cirinei, Lipari 2009 \[1\] \
+/// Response Time Analysis.
+///
+/// Refer to the [module](`self`) level documentation.
+///
+/// Returns:
+/// - Response Time upper bounds of each task.
+pub struct AnalysisResponseTime {
+    pub model: MPRModel,
+}
+
+impl SchedAnalysis<Vec<Time>, &[RTTask]> for AnalysisResponseTime {
+    fn analyzer_name(&self) -> &str { ALGORITHM }
+
+    fn check_preconditions(&self, taskset: &&[RTTask]) -> Result<(), SchedError> {
+        check_preconditions_response_time(taskset, &self.model)
+    }
+
+    fn run_test(&self, taskset: &[RTTask]) -> Result<Vec<Time>, SchedError> {
+        response_times(taskset, &self.model, &[])
+    }
+}
+
+/// MPR Model, FP Local Scheduler - *Derived from* Bertogna, Cirinei, Lipari 2009 \[1\] \
+/// Response Time Analysis, warm-started from previously computed response times.
+///
+/// Refer to the [module](`self`) level documentation.
+///
+/// Refer to [`warm_start_response_time`] for the requirements on the initial
+/// response times.
+///
+/// Returns:
+/// - Response Time upper bounds of each task.
+pub struct AnalysisWarmStart {
+    pub model: MPRModel,
+    pub initial_response_times: Vec<Time>,
+}
+
+impl SchedAnalysis<Vec<Time>, &[RTTask]> for AnalysisWarmStart {
+    fn analyzer_name(&self) -> &str { ALGORITHM }
+
+    fn check_preconditions(&self, taskset: &&[RTTask]) -> Result<(), SchedError> {
+        check_preconditions_response_time(taskset, &self.model)
+    }
+
+    fn run_test(&self, taskset: &[RTTask]) -> Result<Vec<Time>, SchedError> {
+        response_times(taskset, &self.model, &self.initial_response_times)
+    }
+}
+
+fn check_preconditions_response_time(taskset: &[RTTask], model: &MPRModel) -> Result<(), SchedError> {
+    if !RTUtils::constrained_deadlines(taskset) {
+        Err(SchedError::constrained_deadlines())
+    } else if !model.is_feasible() || model.resource <= Time::zero() || model.concurrency == 0 {
+        Err(SchedError::Precondition(Some(
+            anyhow::format_err!("the MPR Model must be feasible and provide some supply.")
+        )))
+    } else {
+        Ok(())
+    }
+}
+
+fn response_times(taskset: &[RTTask], model: &MPRModel, initial_response_times: &[Time]) -> Result<Vec<Time>, SchedError> {
+    taskset.iter().enumerate()
+        .map(|(k, task_k)| {
+            let response = fixpoint_search_with_limit(
+                warm_start_response_time(initial_response_times, k, task_k),
+                task_k.deadline + Time::one(),
+                |response: &Time| {
+                    let demand: Time = taskset[0..k].iter()
+                        .map(|task_i| Bcl09.carry_in(task_i, task_i.deadline, *response))
+                        .sum::<Time>()
+                        + model.concurrency as f64 * task_k.wcet;
+
+                    Time::max(*response, Time::ceil(interval_from_supply_linear(model, demand)))
+                }
+            );
+
+            if response > task_k.deadline {
+                Err(SchedError::NonSchedulable(Some(
+                    anyhow::format_err!("task {k} misses its deadline.")
+                )))
+            } else {
+                Ok(response)
+            }
+        })
+        .collect()
+}
+
+// Inverse of the linear supply bound function, Equation 2 [2]
+fn interval_from_supply_linear(model: &MPRModel, supply: Time) -> Time {
+    supply / model.utilization()
+        + 2.0 * (model.period - model.resource / model.concurrency as f64)
+        - Time::nanos(2.0)
+}
+
 /// MPR Model, FP Local Scheduler - *Derived from* Bertogna, Cirinei, Lipari 2009 \[1\]
 ///
 /// Generate the best MPRModel for the given taskset. This requires the model's
@@ -131,31 +245,43 @@ pub mod extra {
         }
 
         fn run_designer(&self, taskset: &[RTTask]) -> Result<MPRModel, SchedError> {
-            let min_resource =
-                RTUtils::total_utilization(taskset) * self.period;
-            let max_resource = {
-                let designer = super::DesignerLinear { period: self.period, concurrency: self.concurrency };
+            self.search(taskset).run_designer(taskset)
+        }
 
-                designer.check_preconditions(&taskset)?;
-                designer.run_designer(taskset)?.resource
-            };
+        fn run_designer_with_stats(&self, taskset: &[RTTask]) -> DesignResult<MPRModel> {
+            self.search(taskset).run_designer_with_stats(taskset)
+        }
+    }
 
-            (extra::DesignerPeriodConcurrencyNaive {
+    impl DesignerPeriodConcurrency {
+        fn search<'a>(&'a self, taskset: &'a [RTTask]) -> impl SchedDesign<&'a [RTTask], MPRModel> + 'a {
+            extra::DesignerPeriodConcurrencyNaive {
                 period: self.period,
                 concurrency: self.concurrency,
-                resource_iter_fn: |_, _| Ok(Box::new(time_range_iterator_w_step(min_resource, max_resource, self.resource_step))),
+                resource_iter_fn: move |period, concurrency| {
+                    let min_resource =
+                        RTUtils::total_utilization(taskset) * period;
+                    let max_resource = {
+                        let designer = super::DesignerLinear { period, concurrency };
+
+                        designer.check_preconditions(&taskset)?;
+                        designer.run_designer(taskset)?.resource
+                    };
+
+                    Ok(Box::new(time_range_iterator_w_step(min_resource, max_resource, self.resource_step)))
+                },
                 analysis_gen_fn: |resource, period, concurrency|
                     super::Analysis { model: MPRModel { resource, period, concurrency }},
                 marker: std::marker::PhantomData,
-            })
-            .run_designer(taskset)
+            }
         }
     }
 
     /// MPR Model, FP Local Scheduler - *Derived from* Bertogna, Cirinei, Lipari 2009 \[1\]
     ///
     /// Generate the best MPRModel for the given taskset. Searches the space of
-    /// possible MPRModels given a range of valid periods.
+    /// possible MPRModels given a range of valid periods, refining the period
+    /// grid around the most promising periods (refer to [`extra::DesignerRefined`]).
     ///
     /// Refer to the [module](`self`) level documentation.
     pub struct DesignerFull {
@@ -175,16 +301,44 @@ pub mod extra {
         }
 
         fn run_designer(&self, taskset: &[RTTask]) -> Result<MPRModel, SchedError> {
+            self.search(taskset).run_designer(taskset)
+                .or_else(|_| Ok(self.fallback_model(taskset)))
+        }
+
+        fn run_designer_with_stats(&self, taskset: &[RTTask]) -> DesignResult<MPRModel> {
+            let result = self.search(taskset).run_designer_with_stats(taskset);
+
+            DesignResult {
+                model: result.model.or_else(|_| Ok(self.fallback_model(taskset))),
+                ..result
+            }
+        }
+    }
+
+    impl SchedDesignResumable<&[RTTask], MPRModel, extra::SearchCheckpoint> for DesignerFull {
+        fn run_designer_resumable(
+            &self,
+            taskset: &[RTTask],
+            checkpoint: extra::SearchCheckpoint,
+            on_checkpoint: &mut dyn FnMut(&extra::SearchCheckpoint),
+        ) -> Result<MPRModel, SchedError> {
+            self.search(taskset).run_designer_resumable(taskset, checkpoint, on_checkpoint)
+                .or_else(|_| Ok(self.fallback_model(taskset)))
+        }
+    }
+
+    impl DesignerFull {
+        fn search<'a>(&'a self, taskset: &'a [RTTask]) -> impl SchedDesignResumable<&'a [RTTask], MPRModel, extra::SearchCheckpoint> + 'a {
             let min_processors =
                 num_processors_lower_bound(taskset);
 
             let max_processors =
                 num_processors_upper_bound(taskset);
 
-            let designer = extra::DesignerNaive {
-                period_iter_fn: || Ok(Box::new(time_range_iterator_w_step(self.period_range.0, self.period_range.1, self.period_range.2))),
-                concurrency_iter_fn: |_| Ok(Box::new(min_processors ..= max_processors)),
-                resource_iter_fn: |period, concurrency| {
+            extra::DesignerRefined {
+                period_range: self.period_range,
+                concurrency_iter_fn: move |_| Ok(Box::new(min_processors ..= max_processors)),
+                resource_iter_fn: move |period, concurrency| {
                     let min_resource =
                         RTUtils::total_utilization(taskset) * period;
                     let max_resource = {
@@ -199,14 +353,19 @@ pub mod extra {
                 analysis_gen_fn: |resource, period, concurrency|
                     super::Analysis { model: MPRModel { resource, period, concurrency }},
                 marker: std::marker::PhantomData,
-            };
-
-            designer.run_designer(taskset)
-                .or_else(|_| Ok(MPRModel {
-                    resource: self.period_range.1 * max_processors as f64,
-                    period: self.period_range.1,
-                    concurrency: max_processors
-                }))
+            }
+        }
+
+        // Full resource on the largest period, when the search fails.
+        fn fallback_model(&self, taskset: &[RTTask]) -> MPRModel {
+            let max_processors =
+                num_processors_upper_bound(taskset);
+
+            MPRModel {
+                resource: self.period_range.1 * max_processors as f64,
+                period: self.period_range.1,
+                concurrency: max_processors
+            }
         }
     }
 
@@ -217,4 +376,30 @@ pub mod extra {
     fn num_processors_upper_bound(taskset: &[RTTask]) -> u64 {
         taskset.len() as u64
     }
-}
\ No newline at end of file
+}
+
+#[test]
+fn response_times_mpr() {
+    let taskset = [
+        RTTask::new_ns(3, 10, 10),
+        RTTask::new_ns(3, 10, 10),
+        RTTask::new_ns(4, 20, 20),
+    ];
+
+    // Two full processors: the first two tasks run in parallel.
+    let model = MPRModel { resource: Time::nanos(20.0), period: Time::nanos(10.0), concurrency: 2 };
+    let response_times = AnalysisResponseTime { model: model.clone() }.is_schedulable(&taskset).unwrap();
+    assert_eq!(response_times[0], Time::nanos(3.0));
+    assert_eq!(response_times[1], Time::nanos(3.0));
+
+    let analysis = AnalysisWarmStart { model, initial_response_times: response_times.clone() };
+    assert_eq!(analysis.is_schedulable(&taskset).unwrap(), response_times);
+
+    // Less supply, longer response times.
+    let model = MPRModel { resource: Time::nanos(18.0), period: Time::nanos(10.0), concurrency: 2 };
+    let slow_response_times = AnalysisResponseTime { model }.is_schedulable(&taskset).unwrap();
+    assert_eq!(slow_response_times, vec![Time::nanos(4.0), Time::nanos(7.0), Time::nanos(12.0)]);
+
+    let model = MPRModel { resource: Time::nanos(4.0), period: Time::nanos(10.0), concurrency: 1 };
+    assert!(AnalysisResponseTime { model }.is_schedulable(&taskset).is_err());
+}
@@ -7,6 +7,8 @@
 //!   | O(*taskset_size*) * O(*arrival_times*) * O(*demand_fn) complexity
 //! - [`generate_model_from_demand_linear`] \
 //!   | O(*taskset_size*) * O(*arrival_times*) * O(*demand_fn) complexity
+//! - [`partitioning`] \
+//!   | Pin the server tasks of the models onto physical CPUs
 //!
 //! ---
 //! #### References:
@@ -30,6 +32,7 @@ pub mod fixed_priority {
 }
 
 pub mod extra;
+pub mod partitioning;
 
 /// Multiprocessor Periodic Resource Model - Shin, Easwaran, Lee 2009
 ///
@@ -191,6 +194,54 @@ pub fn is_schedulable_demand<'a, 'b, 'c, FDem, FAk>(
     })
 }
 
+/// Multiprocessor Periodic Resource Model - Shin, Easwaran, Lee 2009
+///
+/// Generic implementation for the MPRModel schedulability test, evaluating the
+/// demand on blocks of arrival times at once.
+/// Requires:
+/// - A demand function, which writes the workload demand of the taskset at each
+///   of the given timepoints.
+/// - A function which provides the set of arrival times of a task to check.
+///
+/// Refer to the [module](`self`) level documentation.
+pub fn is_schedulable_demand_block<'a, 'b, 'c, FDem, FAk>(
+    taskset: &'a [RTTask],
+    model: &'b MPRModel,
+    block_size: usize,
+    mut demand_fn: FDem,
+    mut arrival_times_fn: FAk,
+) -> bool
+    where
+        'a: 'c, 'b: 'c,
+        FDem: FnMut(&'a [RTTask], usize, &'a RTTask, &'b MPRModel, &[Time], &mut [Time]),
+        FAk: FnMut(&'a [RTTask], usize, &'a RTTask, &'b MPRModel) -> Box<dyn Iterator<Item = Time> + 'c> ,
+{
+    let mut arrivals_block = Vec::with_capacity(block_size);
+    let mut demands = vec![Time::zero(); block_size];
+
+    taskset.iter().enumerate()
+    .all(|(k, task_k)| {
+        let mut arrival_times = arrival_times_fn(taskset, k, task_k, model).peekable();
+
+        while arrival_times.peek().is_some() {
+            arrivals_block.clear();
+            arrivals_block.extend(arrival_times.by_ref().take(block_size));
+
+            let demands = &mut demands[..arrivals_block.len()];
+            demand_fn(taskset, k, task_k, model, &arrivals_block, demands);
+
+            let schedulable = demands.iter().zip(arrivals_block.iter())
+                .all(|(demand, arrival_k)| *demand <= model.get_supply(*arrival_k + task_k.deadline));
+
+            if !schedulable {
+                return false;
+            }
+        }
+
+        true
+    })
+}
+
 /// Multiprocessor Periodic Resource Model - Shin, Easwaran, Lee 2009
 ///
 /// Generic implementation for the generation of the MPRModel.
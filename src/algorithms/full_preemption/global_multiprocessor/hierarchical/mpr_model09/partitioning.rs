@@ -0,0 +1,279 @@
+//! ## Partitioning of MPR Model Servers onto Physical CPUs
+//!
+//! Each component interface, once transformed into periodic server tasks with
+//! [`MPRModel::to_periodic_tasks`], must be pinned onto the physical CPUs
+//! together with the servers of the other components. The servers of all the
+//! components are partitioned with a First-Fit Decreasing (by utilization)
+//! heuristic, and a server is admitted on a CPU only if the CPU passes a
+//! uniprocessor schedulability test with the server added.
+//!
+//! Since the server tasks have implicit deadlines, the uniprocessor tests are
+//! exact \[2\], and a server fits on a CPU if and only if it does not miss its
+//! deadline. Servers of the same component are never pinned onto the same CPU:
+//! the supply bound function of the MPR Model assumes that the component may
+//! be supplied by all its servers at the same time \[1\], which does not hold
+//! once they are serialized on a single CPU.
+//!
+//! #### Model:
+//! - Periodic server tasks, one set per component
+//! - Partitioned, Fully-Preemptive EDF or Rate Monotonic scheduling of the
+//!   servers on each CPU
+//!
+//! #### Preconditions:
+//! - Feasible MPR Models
+//! - No more servers per component than CPUs
+//!
+//! #### Implements:
+//! - [`DesignerPartitioned::design`] \
+//!   | Produce the per-CPU server table for deployment. \
+//!   | \
+//!   | O(*servers* * *num_processors*) uniprocessor tests
+//! - [`AnalysisDeployed::is_schedulable`] \
+//!   | Analyze a component on its MPR Model, and check that the model's
+//!   | servers can be partitioned onto the physical CPUs. \
+//!   | \
+//!   | The server transformation and partitioning are shared across the
+//!   | tasksets of a batch.
+//!
+//! ---
+//! #### References:
+//! 1. A. Easwaran, I. Shin, and I. Lee, “Optimal virtual cluster-based
+//!    multiprocessor scheduling,” Real-Time Syst, vol. 43, no. 1, pp. 25–59,
+//!    Sept. 2009, doi: 10.1007/s11241-009-9073-x.
+//! 2. G. C. Buttazzo, “Hard Real-Time Computing Systems: Predictable Scheduling
+//!    Algorithms and Applications,” 3rd ed., Springer, 2011,
+//!    doi: 10.1007/978-1-4614-0676-1.
+
+use crate::prelude::*;
+use crate::algorithms::full_preemption::uniprocessor::earliest_deadline_first::edf73;
+use crate::algorithms::full_preemption::uniprocessor::fixed_priority::rta86;
+use super::MPRModel;
+
+const ALGORITHM: &str = "Partitioning of MPR Model Servers (First-Fit Decreasing)";
+
+/// Scheduler of the server tasks on each physical CPU.
+#[derive(Debug, Clone, Copy)]
+#[derive(PartialEq, Eq)]
+pub enum ServerScheduler {
+    EarliestDeadlineFirst,
+    RateMonotonic,
+}
+
+/// Server task pinned onto a physical CPU.
+#[derive(Debug, Clone)]
+pub struct ServerPlacement {
+    /// Index of the component (i.e. its MPR Model).
+    pub component: usize,
+    /// Index of the server among the periodic tasks of the component.
+    pub server: usize,
+    pub task: RTTask,
+}
+
+/// Servers pinned onto each physical CPU.
+///
+/// With Rate Monotonic scheduling, the servers of each CPU are sorted by
+/// priority, highest first.
+#[derive(Debug, Clone)]
+pub struct ServerTable {
+    pub cpus: Vec<Vec<ServerPlacement>>,
+}
+
+impl ServerTable {
+    pub fn cpu_utilization(&self, cpu: usize) -> f64 {
+        self.cpus[cpu].iter()
+            .map(|placement| placement.task.utilization())
+            .sum()
+    }
+}
+
+/// Partitioning of MPR Model Servers onto Physical CPUs
+///
+/// Refer to the [module](`self`) level documentation.
+pub struct DesignerPartitioned {
+    pub num_processors: u64,
+    pub scheduler: ServerScheduler,
+}
+
+impl DesignerPartitioned {
+    fn fits(&self, cpu: &[ServerPlacement], server: &ServerPlacement) -> bool {
+        if cpu.iter().any(|placement| placement.component == server.component) {
+            return false;
+        }
+
+        let mut taskset: Vec<_> = cpu.iter()
+            .chain(std::iter::once(server))
+            .map(|placement| placement.task.clone())
+            .collect();
+
+        match self.scheduler {
+            ServerScheduler::EarliestDeadlineFirst =>
+                edf73::Analysis.is_schedulable(&taskset).is_ok(),
+            ServerScheduler::RateMonotonic => {
+                taskset.sort_by_key(|task| task.period);
+                rta86::Analysis.is_schedulable(&taskset).is_ok()
+            },
+        }
+    }
+}
+
+impl SchedDesign<&[MPRModel], ServerTable> for DesignerPartitioned {
+    fn designer_name(&self) -> &str { ALGORITHM }
+
+    fn check_preconditions(&self, models: &&[MPRModel]) -> Result<(), SchedError> {
+        if let Some(c) = models.iter().position(|model| !model.is_feasible()) {
+            Err(SchedError::Precondition(Some(
+                anyhow::format_err!("the MPR Model of component {c} is not feasible."))))
+        } else if let Some(c) = models.iter().position(|model| model.concurrency > self.num_processors) {
+            Err(SchedError::Precondition(Some(
+                anyhow::format_err!("component {c} has more servers than CPUs."))))
+        } else {
+            Ok(())
+        }
+    }
+
+    fn run_designer(&self, models: &[MPRModel]) -> Result<ServerTable, SchedError> {
+        let mut servers: Vec<_> = models.iter().enumerate()
+            .flat_map(|(component, model)| {
+                model.to_periodic_tasks().into_iter().enumerate()
+                    .map(move |(server, task)| ServerPlacement { component, server, task })
+            })
+            .filter(|placement| placement.task.wcet > Time::zero())
+            .collect();
+
+        servers.sort_by(|a, b| b.task.utilization().total_cmp(&a.task.utilization()));
+
+        let mut cpus: Vec<Vec<ServerPlacement>> = vec![Vec::new(); self.num_processors as usize];
+        for server in servers {
+            let cpu = cpus.iter()
+                .position(|cpu| self.fits(cpu, &server))
+                .ok_or_else(|| SchedError::NonSchedulable(Some(
+                    anyhow::format_err!("server {} of component {} does not fit on any CPU.",
+                        server.server, server.component)
+                )))?;
+
+            cpus[cpu].push(server);
+        }
+
+        if self.scheduler == ServerScheduler::RateMonotonic {
+            cpus.iter_mut()
+                .for_each(|cpu| cpu.sort_by_key(|placement| placement.task.period));
+        }
+
+        Ok(ServerTable { cpus })
+    }
+}
+
+/// Partitioning of MPR Model Servers onto Physical CPUs \
+/// Analyze a component deployed on the physical CPUs.
+///
+/// Refer to the [module](`self`) level documentation.
+///
+/// The taskset must be schedulable by the analysis generated for the model,
+/// and the model's servers must be partitioned onto the physical CPUs by the
+/// given designer. The latter does not depend on the taskset: when analyzing a
+/// batch of tasksets, the servers are generated and partitioned only once.
+pub struct AnalysisDeployed<FnA> {
+    pub model: MPRModel,
+    pub partitioning: DesignerPartitioned,
+    pub analysis_gen_fn: FnA,
+}
+
+impl<FnA> AnalysisDeployed<FnA> {
+    fn deploy(&self) -> Result<ServerTable, SchedError> {
+        let models = std::slice::from_ref(&self.model);
+
+        self.partitioning.check_preconditions(&models)?;
+        self.partitioning.run_designer(models)
+    }
+}
+
+impl<'a, T, FnA, A> SchedAnalysis<T, &'a [RTTask]> for AnalysisDeployed<FnA>
+    where
+        A: SchedAnalysis<T, &'a [RTTask]>,
+        FnA: Fn(&MPRModel) -> A,
+{
+    fn analyzer_name(&self) -> &str { ALGORITHM }
+
+    fn check_preconditions(&self, taskset: &&'a [RTTask]) -> Result<(), SchedError> {
+        self.partitioning.check_preconditions(&std::slice::from_ref(&self.model))?;
+        (self.analysis_gen_fn)(&self.model).check_preconditions(taskset)
+    }
+
+    fn run_test(&self, taskset: &'a [RTTask]) -> Result<T, SchedError> {
+        self.deploy()?;
+        (self.analysis_gen_fn)(&self.model).run_test(taskset)
+    }
+
+    fn run_batch(&self, tasksets: &[&'a [RTTask]]) -> Vec<anyhow::Result<T>> {
+        match self.deploy() {
+            Ok(_) => (self.analysis_gen_fn)(&self.model).run_batch(tasksets),
+            Err(err) => tasksets.iter()
+                .map(|_| Err(anyhow::Error::from(err.duplicate())
+                    .context(format!("Schedulability test error for \"{}\"", self.analyzer_name()))))
+                .collect(),
+        }
+    }
+}
+
+#[test]
+fn partitioned_servers() {
+    let models = [
+        MPRModel { resource: Time::nanos(15.0), period: Time::nanos(10.0), concurrency: 2 },
+        MPRModel { resource: Time::nanos(5.0), period: Time::nanos(20.0), concurrency: 1 },
+    ];
+
+    // Component 0 is served by (8, 10) and (7, 10), component 1 by (5, 20),
+    // which only fits next to the smaller server of component 0.
+    for scheduler in [ServerScheduler::EarliestDeadlineFirst, ServerScheduler::RateMonotonic] {
+        let table = DesignerPartitioned { num_processors: 2, scheduler }
+            .design(&models).unwrap();
+
+        assert_eq!(table.cpus[0].len(), 1);
+        assert_eq!(table.cpus[0][0].task.wcet, Time::nanos(8.0));
+        assert_eq!(table.cpus[1].len(), 2);
+        assert_eq!(table.cpus[1][0].task.wcet, Time::nanos(7.0));
+        assert_eq!(table.cpus[1][1].component, 1);
+        assert!(f64::abs(table.cpu_utilization(1) - 0.95) < 1e-9);
+    }
+
+    // The servers (3, 10) and (2, 10) of the same component never share a CPU.
+    let models = [
+        MPRModel { resource: Time::nanos(5.0), period: Time::nanos(10.0), concurrency: 2 },
+    ];
+
+    let table = DesignerPartitioned { num_processors: 2, scheduler: ServerScheduler::EarliestDeadlineFirst }
+        .design(&models).unwrap();
+    assert!(table.cpus.iter().all(|cpu| cpu.len() == 1));
+
+    assert!(DesignerPartitioned { num_processors: 1, scheduler: ServerScheduler::EarliestDeadlineFirst }
+        .design(&models).is_err());
+}
+
+#[test]
+fn deployed_batch() {
+    use crate::algorithms::full_preemption::global_multiprocessor::hierarchical::mpr_model09::fixed_priority::bcl09;
+
+    let tasksets: [&[RTTask]; 2] = [
+        &[RTTask::new_ns(2, 10, 10), RTTask::new_ns(2, 10, 10)],
+        &[RTTask::new_ns(9, 10, 10), RTTask::new_ns(9, 10, 10)],
+    ];
+
+    let analysis = |num_processors| AnalysisDeployed {
+        model: MPRModel { resource: Time::nanos(15.0), period: Time::nanos(10.0), concurrency: 2 },
+        partitioning: DesignerPartitioned { num_processors, scheduler: ServerScheduler::EarliestDeadlineFirst },
+        analysis_gen_fn: |model: &MPRModel| bcl09::Analysis { model: model.clone() },
+    };
+
+    let results = analysis(2).run_batch(&tasksets);
+    for (taskset, result) in tasksets.iter().zip(results.iter()) {
+        assert_eq!(result.is_ok(), analysis(2).is_schedulable(taskset).is_ok());
+    }
+    assert!(results[0].is_ok());
+    assert!(results[1].is_err());
+
+    // The two servers of the model do not fit on a single CPU.
+    let results = analysis(1).run_batch(&tasksets);
+    assert!(results.iter().all(|result| {
+        matches!(SchedOutcome::from_result(result), SchedOutcome::Error)
+    }));
+}
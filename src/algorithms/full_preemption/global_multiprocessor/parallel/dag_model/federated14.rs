@@ -0,0 +1,182 @@
+//! ## Federated Scheduling of DAG Tasks - Li et al. 2014
+//!
+//! Each high-utilization task (*C > T*) is assigned a cluster of dedicated
+//! processors, on which it is scheduled by a greedy work-conserving scheduler.
+//! A greedy schedule of a DAG on *n* processors completes within
+//! *L + (C - L) / n*, thus the task meets its deadline on \[1\]:
+//!
+//! *n = ceil((C - L) / (D - L))*
+//!
+//! processors. The low-utilization tasks are executed sequentially on the
+//! remaining processors, partitioned with a First-Fit Decreasing (by
+//! utilization) heuristic and scheduled by EDF on each processor, which is
+//! exact for implicit deadlines \[2\].
+//!
+//! #### Model:
+//! - Sporadic DAG Task model (see [`DAGTask`])
+//! - Federated scheduling: dedicated clusters for the high-utilization tasks,
+//!   partitioned Fully-Preemptive EDF for the others
+//!
+//! #### Preconditions:
+//! - Implicit Deadlines
+//! - Acyclic graphs, with positive node WCETs
+//!
+//! #### Implements:
+//! - [`Analysis::is_schedulable`] \
+//!   | Returns the processors allocated to each task. \
+//!   | \
+//!   | O(*n* * *num_processors*) complexity, after the spans
+//!
+//! ---
+//! #### References:
+//! 1. J. Li, J. J. Chen, K. Agrawal, C. Lu, C. Gill, and A. Saifullah,
+//!    “Analysis of Federated and Global Scheduling for Parallel Real-Time
+//!    Tasks,” in 2014 26th Euromicro Conference on Real-Time Systems, July
+//!    2014, pp. 85–96. doi: 10.1109/ECRTS.2014.23.
+//! 2. C. L. Liu and J. W. Layland, “Scheduling Algorithms for Multiprogramming
+//!    in a Hard-Real-Time Environment,” J. ACM, vol. 20, no. 1, pp. 46–61,
+//!    Jan. 1973, doi: 10.1145/321738.321743.
+
+use crate::prelude::*;
+use super::DAGTask;
+
+const ALGORITHM: &str = "Federated Scheduling of DAG Tasks (Li et al. 2014)";
+
+/// Processors allocated to a DAG task.
+#[derive(Debug, Clone, Copy)]
+#[derive(PartialEq, Eq)]
+pub enum CoreAllocation {
+    /// Cluster of dedicated processors, `first..first + count`.
+    Dedicated { first: u64, count: u64 },
+    /// Processor shared with other low-utilization tasks.
+    Shared { core: u64 },
+}
+
+/// Federated Scheduling of DAG Tasks - Li et al. 2014 \[1\]
+///
+/// Refer to the [module](`self`) level documentation.
+///
+/// Returns:
+/// - The processors allocated to each task. Dedicated clusters come first.
+pub struct Analysis {
+    pub num_processors: u64,
+}
+
+impl SchedAnalysis<Vec<CoreAllocation>, &[DAGTask]> for Analysis {
+    fn analyzer_name(&self) -> &str { ALGORITHM }
+
+    fn check_preconditions(&self, taskset: &&[DAGTask]) -> Result<(), SchedError> {
+        for (i, task) in taskset.iter().enumerate() {
+            task.check_preconditions()
+                .map_err(|err| err.with_context(&format!("task {i}")))?;
+
+            if task.deadline != task.period {
+                return Err(SchedError::implicit_deadlines());
+            }
+        }
+
+        Ok(())
+    }
+
+    fn run_test(&self, taskset: &[DAGTask]) -> Result<Vec<CoreAllocation>, SchedError> {
+        let mut allocations = vec![None; taskset.len()];
+        let mut next_core = 0;
+
+        // High-utilization tasks, Section 3 [1].
+        for (i, task) in taskset.iter().enumerate() {
+            let (work, span) = (task.work(), task.span());
+
+            if work <= task.deadline {
+                continue;
+            } else if span >= task.deadline {
+                return Err(SchedError::NonSchedulable(Some(
+                    anyhow::format_err!("the span of task {i} is not shorter than its deadline."))));
+            }
+
+            let count = ((work - span) / (task.deadline - span)).ceil() as u64;
+            allocations[i] = Some(CoreAllocation::Dedicated { first: next_core, count });
+            next_core += count;
+        }
+
+        if next_core > self.num_processors {
+            return Err(SchedError::NonSchedulable(Some(anyhow::format_err!(
+                "the high-utilization tasks need {next_core} dedicated processors."))));
+        }
+
+        // Low-utilization tasks, First-Fit Decreasing on the remaining
+        // processors.
+        let mut low_tasks: Vec<_> = (0..taskset.len())
+            .filter(|i| allocations[*i].is_none())
+            .collect();
+        low_tasks.sort_by(|l, r| taskset[*r].utilization().total_cmp(&taskset[*l].utilization()));
+
+        let mut core_utilizations = vec![0.0; (self.num_processors - next_core) as usize];
+
+        for i in low_tasks {
+            let utilization = taskset[i].utilization();
+
+            let Some(core) = core_utilizations.iter()
+                .position(|core_utilization| core_utilization + utilization <= 1.0)
+                else {
+                    return Err(SchedError::NonSchedulable(Some(anyhow::format_err!(
+                        "task {i} does not fit on the processors shared by the low-utilization tasks."))));
+                };
+
+            core_utilizations[core] += utilization;
+            allocations[i] = Some(CoreAllocation::Shared { core: next_core + core as u64 });
+        }
+
+        Ok(allocations.into_iter().flatten().collect())
+    }
+}
+
+impl SchedProcessors for Analysis {
+    fn num_processors(&self) -> u64 { self.num_processors }
+}
+
+#[test]
+fn federated_allocation() {
+    let at = Time::nanos;
+
+    // Work 14, span 6: greedily scheduled on two processors it completes
+    // within 6 + 8 / 2 = 10.
+    let fork_join = DAGTask {
+        wcets: vec![at(1.0), at(4.0), at(4.0), at(4.0), at(1.0)],
+        edges: vec![(0, 1), (0, 2), (0, 3), (1, 4), (2, 4), (3, 4)],
+        deadline: at(10.0),
+        period: at(10.0),
+    };
+
+    let chain = DAGTask {
+        wcets: vec![at(2.0), at(2.0)],
+        edges: vec![(0, 1)],
+        deadline: at(10.0),
+        period: at(10.0),
+    };
+
+    let single = DAGTask {
+        wcets: vec![at(5.0)],
+        edges: Vec::new(),
+        deadline: at(10.0),
+        period: at(10.0),
+    };
+
+    let taskset = [fork_join.clone(), chain, single];
+
+    assert_eq!(
+        Analysis { num_processors: 3 }.is_schedulable(&taskset).unwrap(),
+        vec![
+            CoreAllocation::Dedicated { first: 0, count: 2 },
+            CoreAllocation::Shared { core: 2 },
+            CoreAllocation::Shared { core: 2 },
+        ]
+    );
+    assert!(Analysis { num_processors: 2 }.is_schedulable(&taskset).is_err());
+
+    // The span alone misses the deadline, on any number of processors.
+    let long_span = DAGTask { deadline: at(6.0), period: at(6.0), ..fork_join.clone() };
+    assert!(Analysis { num_processors: 16 }.is_schedulable(&[long_span]).is_err());
+
+    let constrained = DAGTask { deadline: at(9.0), ..fork_join };
+    assert!(Analysis { num_processors: 16 }.is_schedulable(&[constrained]).is_err());
+}
@@ -0,0 +1,173 @@
+//! ## DAG Parallel Task Model
+//!
+//! Each task is a Directed Acyclic Graph of sequential nodes, released together
+//! every period and sharing a relative deadline. A node may execute only once
+//! all its predecessors have completed, and nodes which do not depend on each
+//! other may execute in parallel on different processors \[1\].
+//!
+//! A DAG task is characterized by its *work* *C*, the total WCET of its nodes,
+//! i.e. its execution time on a single processor, and by its *span* *L*, the
+//! WCET of its longest path, i.e. its execution time on infinitely many
+//! processors.
+//!
+//! #### Implements:
+//! - [`DAGTask`] \
+//!   | Sporadic DAG task
+//! - [`DAGTask::work`], [`DAGTask::span`] \
+//!   | linear *O(nodes + edges)* complexity
+//! - [`federated14`] \
+//!   | Federated scheduling of DAG tasks
+//!
+//! ---
+//! #### References:
+//! 1. S. Baruah, V. Bonifaci, A. Marchetti-Spaccamela, L. Stougie, and A.
+//!    Wiese, “A Generalized Parallel Task Model for Recurrent Real-time
+//!    Processes,” in 2012 IEEE 33rd Real-Time Systems Symposium, Dec. 2012,
+//!    pp. 63–72. doi: 10.1109/RTSS.2012.59.
+
+use crate::prelude::*;
+
+pub mod federated14;
+
+/// Sporadic DAG task
+///
+/// Refer to the [module](`self`) level documentation.
+#[derive(Debug, Clone)]
+pub struct DAGTask {
+    /// WCET of each node.
+    pub wcets: Vec<Time>,
+    /// Precedence constraints, as (predecessor, successor) node indices.
+    pub edges: Vec<(usize, usize)>,
+    pub deadline: Time,
+    pub period: Time,
+}
+
+impl DAGTask {
+    /// Total WCET of the nodes.
+    pub fn work(&self) -> Time {
+        self.wcets.iter().copied().sum()
+    }
+
+    /// WCET of the longest path.
+    ///
+    /// Panics if the graph has a cycle, refer to [`DAGTask::check_preconditions`].
+    pub fn span(&self) -> Time {
+        let successors = self.successors();
+        let order = topological_order(&successors)
+            .expect("the graph of a DAG task must be acyclic");
+
+        // Release of each node, when all the nodes run as soon as their
+        // predecessors complete, propagated along the edges in topological
+        // order.
+        let mut releases = vec![Time::zero(); self.wcets.len()];
+        let mut span = Time::zero();
+        for node in order {
+            let completion = releases[node] + self.wcets[node];
+
+            for &successor in successors[node].iter() {
+                releases[successor] = Time::max(releases[successor], completion);
+            }
+
+            span = Time::max(span, completion);
+        }
+
+        span
+    }
+
+    /// work / period
+    pub fn utilization(&self) -> f64 {
+        self.work() / self.period
+    }
+
+    /// work / deadline
+    pub fn density(&self) -> f64 {
+        self.work() / self.deadline
+    }
+
+    /// Sequential sporadic task which executes all the nodes on one processor.
+    pub fn to_sequential(&self) -> RTTask {
+        RTTask {
+            wcet: self.work(),
+            deadline: self.deadline,
+            period: self.period,
+        }
+    }
+
+    pub fn check_preconditions(&self) -> Result<(), SchedError> {
+        if self.wcets.is_empty() || self.wcets.iter().any(|wcet| *wcet <= Time::zero()) {
+            Err(SchedError::Precondition(Some(
+                anyhow::format_err!("DAG tasks must have nodes with positive WCETs."))))
+        } else if self.edges.iter().any(|&(from, to)| from >= self.wcets.len() || to >= self.wcets.len()) {
+            Err(SchedError::Precondition(Some(
+                anyhow::format_err!("DAG edges must connect existing nodes."))))
+        } else if topological_order(&self.successors()).is_none() {
+            Err(SchedError::Precondition(Some(
+                anyhow::format_err!("the graph of a DAG task must be acyclic."))))
+        } else {
+            Ok(())
+        }
+    }
+
+    // Successors of each node, built once so that the graph is visited in
+    // linear time.
+    fn successors(&self) -> Vec<Vec<usize>> {
+        let mut successors = vec![Vec::new(); self.wcets.len()];
+        for &(from, to) in self.edges.iter() {
+            successors[from].push(to);
+        }
+
+        successors
+    }
+}
+
+// Kahn's algorithm, None if the graph has a cycle.
+fn topological_order(successors: &[Vec<usize>]) -> Option<Vec<usize>> {
+    let mut in_degree = vec![0usize; successors.len()];
+    for &to in successors.iter().flatten() {
+        in_degree[to] += 1;
+    }
+
+    let mut order: Vec<usize> = (0..successors.len())
+        .filter(|node| in_degree[*node] == 0)
+        .collect();
+
+    let mut next = 0;
+    while next < order.len() {
+        let node = order[next];
+        next += 1;
+
+        for &to in successors[node].iter() {
+            in_degree[to] -= 1;
+            if in_degree[to] == 0 {
+                order.push(to);
+            }
+        }
+    }
+
+    (order.len() == successors.len()).then_some(order)
+}
+
+#[test]
+fn work_and_span() {
+    let at = Time::nanos;
+
+    // Fork-join: a source, three parallel nodes and a sink.
+    let task = DAGTask {
+        wcets: vec![at(1.0), at(4.0), at(2.0), at(3.0), at(1.0)],
+        edges: vec![(0, 1), (0, 2), (0, 3), (1, 4), (2, 4), (3, 4)],
+        deadline: at(10.0),
+        period: at(10.0),
+    };
+
+    assert!(task.check_preconditions().is_ok());
+    assert_eq!(task.work(), at(11.0));
+    assert_eq!(task.span(), at(6.0));
+
+    // Nodes listed out of topological order.
+    let chain = DAGTask { edges: vec![(4, 3), (3, 2), (2, 1), (1, 0)], ..task.clone() };
+    assert_eq!(chain.span(), chain.work());
+
+    let cycle = DAGTask { edges: vec![(0, 1), (1, 2), (2, 0)], ..task.clone() };
+    assert!(cycle.check_preconditions().is_err());
+    assert!(DAGTask { edges: vec![(0, 5)], ..task }.check_preconditions().is_err());
+}
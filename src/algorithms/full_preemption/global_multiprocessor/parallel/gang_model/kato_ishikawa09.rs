@@ -0,0 +1,179 @@
+//! ## Gang EDF - Kato & Ishikawa 2009
+//!
+//! A job of task *k*, executing on *m_k* processors, is blocked only when
+//! fewer than *m_k* processors are left by the higher priority jobs, i.e. when
+//! at least *m - m_k + 1* processors execute higher priority jobs. If the job
+//! misses its deadline, it is blocked for more than *D_k - C_k*, thus the
+//! higher priority tasks occupy more than *(m - m_k + 1)(D_k - C_k)* processor
+//! time in its window, counting for each task *i* at most *D_k - C_k* of its
+//! workload *W_i*, on each of its *m_i* processors. The taskset is schedulable
+//! if for every task \[1\]:
+//!
+//! *sum_(i != k) m_i min(W_i, D_k - C_k) < (m - m_k + 1)(D_k - C_k)*
+//!
+//! Under EDF, the workload of each task in the window is bounded by the jobs
+//! with deadline in it, plus a carry-in job \[2\]. The same argument applies to
+//! gang FP scheduling, with the higher priority tasks only and the workload
+//! bound of the jobs which meet their deadlines \[3\].
+//!
+//! #### Model:
+//! - Periodic/Sporadic rigid Gang Task model (see [`GangTask`])
+//! - Fully-Preemptive Gang EDF or FP scheduling
+//!
+//! #### Preconditions:
+//! - Constrained Deadlines
+//! - No task needs more processors than the platform's
+//!
+//! #### Implements:
+//! - [`Analysis::is_schedulable`] \
+//!   | Gang EDF scheduling \
+//!   | O(*n^2*) complexity
+//! - [`AnalysisFixedPriority::is_schedulable`] \
+//!   | Gang FP scheduling (tasks sorted by priority, highest first) \
+//!   | O(*n^2*) complexity
+//!
+//! ---
+//! #### References:
+//! 1. S. Kato and Y. Ishikawa, “Gang EDF Scheduling of Parallel Task Systems,”
+//!    in 2009 30th IEEE Real-Time Systems Symposium, Dec. 2009, pp. 459–468.
+//!    doi: 10.1109/RTSS.2009.42.
+//! 2. M. Bertogna, M. Cirinei, and G. Lipari, “Improved schedulability analysis
+//!    of EDF on multiprocessor platforms,” in 17th Euromicro Conference on
+//!    Real-Time Systems (ECRTS’05), July 2005, pp. 209–218.
+//!    doi: 10.1109/ECRTS.2005.18.
+//! 3. M. Bertogna, M. Cirinei, and G. Lipari, “Schedulability Analysis of
+//!    Global Scheduling Algorithms on Multiprocessor Platforms,” IEEE
+//!    Transactions on Parallel and Distributed Systems, vol. 20, no. 4, pp.
+//!    553–566, Apr. 2009, doi: 10.1109/TPDS.2008.129.
+
+use crate::prelude::*;
+use crate::utils::interference::{Bcl09, InterferenceModel, Shin08};
+use super::GangTask;
+
+const ALGORITHM: &str = "Gang EDF (Kato & Ishikawa 2009)";
+const ALGORITHM_FP: &str = "Gang FP (Kato & Ishikawa 2009)";
+
+/// Gang EDF - Kato & Ishikawa 2009 \[1\]
+///
+/// Refer to the [module](`self`) level documentation.
+pub struct Analysis {
+    pub num_processors: u64,
+}
+
+impl SchedAnalysis<(), &[GangTask]> for Analysis {
+    fn analyzer_name(&self) -> &str { ALGORITHM }
+
+    fn check_preconditions(&self, taskset: &&[GangTask]) -> Result<(), SchedError> {
+        check_preconditions(taskset, self.num_processors)
+    }
+
+    fn run_test(&self, taskset: &[GangTask]) -> Result<(), SchedError> {
+        // Jobs with deadline in the window plus a carry-in job, as in the
+        // Theorem 7 of [2].
+        gang_test(taskset, self.num_processors, |i, k| i != k, |gang, window| Shin08.carry_in(&gang.task, gang.task.deadline, window))
+    }
+}
+
+impl SchedProcessors for Analysis {
+    fn num_processors(&self) -> u64 { self.num_processors }
+}
+
+/// Gang FP - Kato & Ishikawa 2009 \[1\], workload bounds from Bertogna,
+/// Cirinei, Lipari 2009 \[3\]
+///
+/// Refer to the [module](`self`) level documentation.
+pub struct AnalysisFixedPriority {
+    pub num_processors: u64,
+}
+
+impl SchedAnalysis<(), &[GangTask]> for AnalysisFixedPriority {
+    fn analyzer_name(&self) -> &str { ALGORITHM_FP }
+
+    fn check_preconditions(&self, taskset: &&[GangTask]) -> Result<(), SchedError> {
+        check_preconditions(taskset, self.num_processors)
+    }
+
+    fn run_test(&self, taskset: &[GangTask]) -> Result<(), SchedError> {
+        // The tasks are checked in priority order, thus the higher priority
+        // ones meet their deadlines and respond within them.
+        gang_test(taskset, self.num_processors, |i, k| i < k, |gang, window| Bcl09.carry_in(&gang.task, gang.task.deadline, window))
+    }
+}
+
+impl SchedProcessors for AnalysisFixedPriority {
+    fn num_processors(&self) -> u64 { self.num_processors }
+}
+
+fn check_preconditions(taskset: &[GangTask], num_processors: u64) -> Result<(), SchedError> {
+    if taskset.iter().any(|gang| gang.task.deadline > gang.task.period) {
+        Err(SchedError::constrained_deadlines())
+    } else if taskset.iter().any(|gang| gang.processors == 0 || gang.processors > num_processors) {
+        Err(SchedError::Precondition(Some(
+            anyhow::format_err!("gang tasks must need between one and {num_processors} processors."))))
+    } else {
+        Ok(())
+    }
+}
+
+// Theorem 2 [1]: for each task k, the interference of the tasks i for which
+// `interferes(i, k)`, i.e. all the others under EDF, and the higher priority
+// ones under FP.
+fn gang_test<I, W>(taskset: &[GangTask], num_processors: u64, interferes: I, workload: W) -> Result<(), SchedError>
+    where
+        I: Fn(usize, usize) -> bool,
+        W: Fn(&GangTask, Time) -> Time,
+{
+    for (k, gang_k) in taskset.iter().enumerate() {
+        let gap = gang_k.task.deadline - gang_k.task.wcet;
+
+        let interference: Time = taskset.iter().enumerate()
+            .filter(|(i, _)| interferes(*i, k))
+            .map(|(_, gang_i)| Time::min(workload(gang_i, gang_k.task.deadline), gap) * gang_i.processors as f64)
+            .sum();
+
+        let capacity = gap * (num_processors - gang_k.processors + 1) as f64;
+
+        if interference >= capacity {
+            return Err(SchedError::NonSchedulable(Some(
+                anyhow::format_err!("task {k} may miss its deadline."))));
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+fn gang_tasks() {
+    let gang = |wcet, period, processors| GangTask { task: RTTask::new_ns(wcet, period, period), processors };
+    let taskset = [gang(3, 10, 3), gang(2, 10, 2), gang(2, 10, 2)];
+
+    // Task 0 needs three processors: with four, it is blocked whenever one of
+    // the other tasks executes, for at most 2 + 2 < 7 in its window.
+    assert!(Analysis { num_processors: 4 }.is_schedulable(&taskset).is_ok());
+    assert!(Analysis { num_processors: 3 }.is_schedulable(&taskset).is_err());
+
+    // Under FP, the lowest priority task suffers the whole interference of
+    // the higher priority ones.
+    assert!(AnalysisFixedPriority { num_processors: 4 }.is_schedulable(&taskset).is_err());
+    assert!(AnalysisFixedPriority { num_processors: 5 }.is_schedulable(&taskset).is_ok());
+
+    assert!(Analysis { num_processors: 2 }.is_schedulable(&taskset).is_err());
+}
+
+#[test]
+// Sequential tasks are gang tasks on one processor: the test is the one of
+// Bertogna, Cirinei, Lipari 2005.
+fn sequential_tasks() {
+    use crate::algorithms::full_preemption::global_multiprocessor::earliest_deadline_first::bcl05;
+    use crate::utils::test_tasksets::random_taskset;
+
+    for seed in 0..20 {
+        let taskset = random_taskset(6, 2.5, seed);
+        let gangs: Vec<_> = taskset.iter().cloned().map(GangTask::sequential).collect();
+
+        assert_eq!(
+            Analysis { num_processors: 4 }.is_schedulable(&gangs).is_ok(),
+            bcl05::Analysis { num_processors: 4 }.is_schedulable(&taskset).is_ok()
+        );
+    }
+}
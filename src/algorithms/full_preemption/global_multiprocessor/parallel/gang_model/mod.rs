@@ -0,0 +1,50 @@
+//! ## Rigid Gang Task Model
+//!
+//! Each job of a gang task executes on a fixed number of processors at the
+//! same time, for its whole execution: it is scheduled only when that many
+//! processors are available to it, and it is preempted on all of them at once
+//! \[1\]. A sequential task is a gang task on a single processor.
+//!
+//! #### Implements:
+//! - [`GangTask`] \
+//!   | Sporadic rigid gang task
+//! - [`kato_ishikawa09`] \
+//!   | Gang EDF and FP scheduling tests
+//!
+//! ---
+//! #### References:
+//! 1. S. Kato and Y. Ishikawa, “Gang EDF Scheduling of Parallel Task Systems,”
+//!    in 2009 30th IEEE Real-Time Systems Symposium, Dec. 2009, pp. 459–468.
+//!    doi: 10.1109/RTSS.2009.42.
+
+use crate::prelude::*;
+
+pub mod kato_ishikawa09;
+
+/// Sporadic rigid gang task
+///
+/// Refer to the [module](`self`) level documentation.
+#[derive(Debug, Clone)]
+pub struct GangTask {
+    /// Timing parameters, the WCET being the execution time on each of the
+    /// task's processors.
+    pub task: RTTask,
+    /// Number of processors each job executes on at the same time.
+    pub processors: u64,
+}
+
+impl GangTask {
+    pub fn sequential(task: RTTask) -> Self {
+        Self { task, processors: 1 }
+    }
+
+    /// Processor time demanded by each job, *m C*.
+    pub fn work(&self) -> Time {
+        self.task.wcet * self.processors as f64
+    }
+
+    /// work / period
+    pub fn utilization(&self) -> f64 {
+        self.work() / self.task.period
+    }
+}
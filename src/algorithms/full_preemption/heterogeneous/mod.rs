@@ -0,0 +1,71 @@
+//! ## Heterogeneous Multiprocessors
+//!
+//! On heterogeneous (unrelated) multiprocessors, such as big.LITTLE platforms,
+//! the processors are of different types, and the WCET of a task depends on
+//! the type of the processor it runs on \[1\]. The core type of each processor
+//! is given by the [`Platform`], and each task declares its WCET on each core
+//! type. A task may also be unable to run on some core types.
+//!
+//! #### Implements:
+//! - [`HeterogeneousTask`] \
+//!   | Sporadic task with per-core-type WCETs
+//! - [`partitioned_edf`] \
+//!   | Partitioning onto heterogeneous processors, EDF on each processor
+//!
+//! ---
+//! #### References:
+//! 1. S. Baruah, “Partitioning real-time tasks among heterogeneous
+//!    multiprocessors,” in International Conference on Parallel Processing,
+//!    2004. ICPP 2004., Aug. 2004, pp. 467–474 vol.1,
+//!    doi: 10.1109/ICPP.2004.1327956.
+
+use crate::prelude::*;
+
+pub mod partitioned_edf;
+
+/// Sporadic task with per-core-type WCETs
+///
+/// Refer to the [module](`self`) level documentation.
+#[derive(Debug, Clone)]
+pub struct HeterogeneousTask {
+    /// WCET on each core type, None if the task cannot run on it.
+    pub wcets: Vec<Option<Time>>,
+    pub deadline: Time,
+    pub period: Time,
+}
+
+impl HeterogeneousTask {
+    /// The task as it runs on the given core type, if it can.
+    pub fn on_core_type(&self, core_type: usize) -> Option<RTTask> {
+        self.wcets.get(core_type).copied().flatten()
+            .map(|wcet| RTTask { wcet, deadline: self.deadline, period: self.period })
+    }
+
+    /// Check that the task declares its WCET on each core type of the
+    /// platform, and that it can run on at least one of them.
+    pub fn check_preconditions(&self, platform: &Platform) -> Result<(), SchedError> {
+        if self.wcets.len() != platform.num_core_types() {
+            Err(SchedError::Precondition(Some(anyhow::format_err!(
+                "the task must declare its WCET on each of the {} core types.", platform.num_core_types()))))
+        } else if !platform.core_types.iter().any(|&core_type| self.wcets[core_type].is_some()) {
+            Err(SchedError::Precondition(Some(
+                anyhow::format_err!("the task cannot run on any processor of the platform."))))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Check the preconditions of every task of the taskset.
+pub(crate) fn check_preconditions(taskset: &[HeterogeneousTask], platform: &Platform) -> Result<(), SchedError> {
+    platform.check_preconditions()?;
+
+    if platform.model != PlatformModel::HeterogeneousMultiprocessor {
+        return Err(SchedError::Precondition(Some(
+            anyhow::format_err!("the platform must be heterogeneous."))));
+    }
+
+    taskset.iter().enumerate()
+        .try_for_each(|(i, task)| task.check_preconditions(platform)
+            .map_err(|err| err.with_context(&format!("task {i}"))))
+}
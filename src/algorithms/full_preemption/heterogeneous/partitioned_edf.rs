@@ -0,0 +1,131 @@
+//! ## Partitioned EDF on Heterogeneous Multiprocessors - *Derived from* Baruah 2004
+//!
+//! Each task is pinned onto one processor, which schedules its tasks by EDF,
+//! thus a processor is feasible if the total utilization of its tasks, with
+//! their WCETs on its core type, does not exceed one. Finding a feasible
+//! partitioning is NP-hard \[1\]: the tasks are assigned by a greedy
+//! heuristic, from the one with the largest minimum utilization, each onto the
+//! processor where it fits with the smallest utilization, ties broken towards
+//! the least loaded processor. The test is sufficient.
+//!
+//! #### Model:
+//! - Periodic/Sporadic Task model with per-core-type WCETs (refer to
+//!   [`HeterogeneousTask`])
+//! - Partitioned EDF scheduling on heterogeneous processors
+//!
+//! #### Preconditions:
+//! - Implicit Deadlines
+//! - A WCET for each core type of the platform
+//!
+//! #### Implements:
+//! - [`Analysis::is_schedulable`] \
+//!   | *O(n·log(n) + n·m)* complexity
+//!
+//! ---
+//! #### References:
+//! 1. S. Baruah, “Partitioning real-time tasks among heterogeneous
+//!    multiprocessors,” in International Conference on Parallel Processing,
+//!    2004. ICPP 2004., Aug. 2004, pp. 467–474 vol.1,
+//!    doi: 10.1109/ICPP.2004.1327956.
+
+use crate::prelude::*;
+use super::*;
+
+const ALGORITHM: &str = "Partitioned EDF on Heterogeneous Multiprocessors (derived from Baruah 2004)";
+
+/// Partitioned EDF on Heterogeneous Multiprocessors - *Derived from* Baruah 2004 \[1\]
+///
+/// Refer to the [module](`self`) level documentation.
+///
+/// Returns:
+/// - The processor of each task.
+pub struct Analysis {
+    pub platform: Platform,
+}
+
+impl SchedAnalysis<Vec<usize>, &[HeterogeneousTask]> for Analysis {
+    fn analyzer_name(&self) -> &str { ALGORITHM }
+
+    fn check_preconditions(&self, taskset: &&[HeterogeneousTask]) -> Result<(), SchedError> {
+        check_preconditions(taskset, &self.platform)?;
+
+        if taskset.iter().any(|task| task.deadline != task.period) {
+            Err(SchedError::implicit_deadlines())
+        } else {
+            Ok(())
+        }
+    }
+
+    fn run_test(&self, taskset: &[HeterogeneousTask]) -> Result<Vec<usize>, SchedError> {
+        let utilization = |task: &HeterogeneousTask, cpu: usize| {
+            task.on_core_type(self.platform.core_types[cpu])
+                .map(|task| task.utilization())
+        };
+
+        let min_utilization = |task: &HeterogeneousTask| {
+            (0..self.platform.core_types.len())
+                .filter_map(|cpu| utilization(task, cpu))
+                .fold(f64::INFINITY, f64::min)
+        };
+
+        let mut order: Vec<usize> = (0..taskset.len()).collect();
+        order.sort_by(|&l, &r| min_utilization(&taskset[r]).total_cmp(&min_utilization(&taskset[l])));
+
+        let mut loads = vec![0.0; self.platform.core_types.len()];
+        let mut assignment = vec![0; taskset.len()];
+        for i in order {
+            let cpu = (0..loads.len())
+                .filter_map(|cpu| utilization(&taskset[i], cpu).map(|utilization| (cpu, utilization)))
+                .filter(|&(cpu, utilization)| loads[cpu] + utilization <= 1.0)
+                .min_by(|&(l, l_util), &(r, r_util)| l_util.total_cmp(&r_util).then(loads[l].total_cmp(&loads[r])))
+                .map(|(cpu, _)| cpu)
+                .ok_or_else(|| SchedError::NonSchedulable(Some(
+                    anyhow::format_err!("task {i} does not fit on any processor."))))?;
+
+            loads[cpu] += utilization(&taskset[i], cpu).unwrap();
+            assignment[i] = cpu;
+        }
+
+        Ok(assignment)
+    }
+}
+
+impl SchedProcessors for Analysis {
+    fn num_processors(&self) -> u64 { self.platform.cpus }
+}
+
+impl SchedPlatform for Analysis {
+    fn supports(&self, platform: &Platform) -> bool {
+        *platform == self.platform && platform.algorithm == SchedulingAlgorithm::EarliestDeadlineFirst
+    }
+}
+
+#[test]
+fn heterogeneous_partitioning() {
+    let at = |t: u64| Time::nanos(t as f64);
+    let task = |big: Option<u64>, little: Option<u64>, period: u64| HeterogeneousTask {
+        wcets: vec![big.map(at), little.map(at)],
+        deadline: at(period),
+        period: at(period),
+    };
+
+    // One big and one LITTLE core, on which the tasks run twice as slow.
+    let platform = Platform::heterogeneous(vec![0, 1], SchedulingAlgorithm::EarliestDeadlineFirst);
+    let analysis = Analysis { platform: platform.clone() };
+
+    // The tasks prefer the big core, until it is full.
+    let taskset = [task(Some(7), Some(14), 10), task(Some(2), Some(4), 10), task(Some(2), Some(4), 10)];
+    assert_eq!(analysis.is_schedulable(&taskset).unwrap(), vec![0, 0, 1]);
+
+    // A task which can only run on the LITTLE core.
+    let taskset = [task(Some(6), Some(12), 10), task(None, Some(9), 10), task(Some(3), Some(6), 10)];
+    assert_eq!(analysis.is_schedulable(&taskset).unwrap(), vec![0, 1, 0]);
+
+    let taskset = [task(Some(6), Some(12), 10), task(None, Some(9), 10), task(Some(5), Some(10), 10)];
+    assert!(analysis.is_schedulable(&taskset).is_err());
+
+    assert!(analysis.supports(&platform));
+    assert!(analysis.is_schedulable(&[task(Some(1), None, 10), task(None, None, 10)]).is_err());
+    assert!(analysis.is_schedulable(&[task(Some(1), Some(2), 10)][..]).is_ok());
+    assert!(analysis.is_schedulable(&[HeterogeneousTask { wcets: vec![Some(at(1))], ..task(None, None, 10) }]).is_err());
+}
@@ -0,0 +1,232 @@
+//! ## Upper Bounds on Job Migrations
+//!
+//! Under global job-level fixed priority scheduling a job can only migrate
+//! when it resumes after a preemption, and it can only be preempted by the
+//! release of a higher priority job \[1\]. Counting the higher priority
+//! releases which may happen while each job is pending gives an upper bound on
+//! its migrations, which is then summed over all the jobs of a hyperperiod.
+//!
+//! Under semi-partitioned scheduling most tasks are pinned to a processor and
+//! never migrate, while the jobs of the split tasks execute a fixed portion of
+//! their WCET on each of their processors in sequence \[2\]: each job migrates
+//! exactly once per processor change.
+//!
+//! #### Model:
+//! - Periodic/Sporadic Task model
+//! - Global Earliest Deadline First or Fixed Priority scheduling
+//! - Semi-partitioned scheduling, with split tasks migrating at fixed points
+//!
+//! #### Implements:
+//! - [`MigrationBound::global_edf`] \
+//!   | Global Earliest Deadline First
+//! - [`MigrationBound::global_fp`] \
+//!   | Global Fixed Priority (tasks are sorted by priority, highest first)
+//! - [`MigrationBound::semi_partitioned`] \
+//!   | Semi-partitioned scheduling with split tasks
+//! - [`WithMigrationBound`] \
+//!   | Attach the migration bound to the results of any global analysis
+//!
+//! ---
+//! #### References:
+//! 1. G. C. Buttazzo, “Hard Real-Time Computing Systems: Predictable Scheduling
+//!    Algorithms and Applications,” 3rd ed., Springer, 2011,
+//!    doi: 10.1007/978-1-4614-0676-1.
+//! 2. S. Kato, N. Yamasaki, and Y. Ishikawa, “Semi-partitioned Scheduling of
+//!    Sporadic Task Systems on Multiprocessors,” in 21st Euromicro Conference
+//!    on Real-Time Systems (ECRTS'09), July 2009, pp. 249–258,
+//!    doi: 10.1109/ECRTS.2009.22.
+
+use crate::prelude::*;
+
+#[derive(Debug, Clone, Copy)]
+#[derive(PartialEq, Eq)]
+pub enum MigrationPolicy {
+    EarliestDeadlineFirst,
+    FixedPriority,
+}
+
+/// Processors on which the jobs of each task execute, in order. Pinned tasks
+/// have a single processor.
+#[derive(Debug, Clone)]
+pub struct SemiPartitioning {
+    pub processors: Vec<Vec<usize>>,
+}
+
+/// Upper bound on the number of job migrations in a hyperperiod.
+///
+/// Refer to the [module](`self`) level documentation.
+#[derive(Debug, Clone, Copy)]
+#[derive(PartialEq)]
+pub struct MigrationBound {
+    pub hyperperiod: Time,
+    pub jobs_per_hyperperiod: f64,
+    pub migrations_per_hyperperiod: f64,
+}
+
+impl MigrationBound {
+    pub fn new(taskset: &[RTTask], num_processors: u64, policy: MigrationPolicy) -> Self {
+        match policy {
+            MigrationPolicy::EarliestDeadlineFirst => Self::global_edf(taskset, num_processors),
+            MigrationPolicy::FixedPriority => Self::global_fp(taskset, num_processors),
+        }
+    }
+
+    /// A job of task *i* released at *r* can only be preempted by the jobs of
+    /// task *j* released in *(r, r + D_i - D_j)*, which have an earlier
+    /// absolute deadline.
+    pub fn global_edf(taskset: &[RTTask], num_processors: u64) -> Self {
+        Self::from_preemptions(taskset, num_processors, |i, task_i| {
+            taskset.iter().enumerate()
+                .filter(|(j, task_j)| *j != i && task_j.deadline < task_i.deadline)
+                .map(|(_, task_j)| ((task_i.deadline - task_j.deadline) / task_j.period).ceil())
+                .sum()
+        })
+    }
+
+    /// A job of task *i* can only be preempted by the releases of the higher
+    /// priority tasks within its deadline.
+    pub fn global_fp(taskset: &[RTTask], num_processors: u64) -> Self {
+        Self::from_preemptions(taskset, num_processors, |i, task_i| {
+            taskset[0..i].iter()
+                .map(|task_j| (task_i.deadline / task_j.period).ceil())
+                .sum()
+        })
+    }
+
+    /// Each job of a task split over *k* processors migrates *k - 1* times.
+    pub fn semi_partitioned(taskset: &[RTTask], partitioning: &SemiPartitioning) -> Self {
+        let hyperperiod = RTUtils::hyperperiod(taskset);
+        let jobs = |task: &RTTask| (hyperperiod / task.period).ceil();
+
+        let jobs_per_hyperperiod = taskset.iter().map(jobs).sum();
+        let migrations_per_hyperperiod = taskset.iter().zip(partitioning.processors.iter())
+            .map(|(task, processors)| jobs(task) * processors.len().saturating_sub(1) as f64)
+            .sum();
+
+        Self { hyperperiod, jobs_per_hyperperiod, migrations_per_hyperperiod }
+    }
+
+    fn from_preemptions<F>(taskset: &[RTTask], num_processors: u64, preemptions_per_job: F) -> Self
+        where
+            F: Fn(usize, &RTTask) -> f64,
+    {
+        let hyperperiod = RTUtils::hyperperiod(taskset);
+        let jobs = |task: &RTTask| (hyperperiod / task.period).ceil();
+
+        let jobs_per_hyperperiod = taskset.iter().map(jobs).sum();
+
+        // Jobs are never preempted when there are no more tasks than
+        // processors, and never migrate on a single processor.
+        let migrations_per_hyperperiod =
+            if taskset.len() as u64 <= num_processors || num_processors <= 1 {
+                0.0
+            } else {
+                let by_job: f64 = taskset.iter().enumerate()
+                    .map(|(i, task)| jobs(task) * preemptions_per_job(i, task))
+                    .sum();
+
+                // Each release preempts at most one job.
+                f64::min(by_job, jobs_per_hyperperiod)
+            };
+
+        Self { hyperperiod, jobs_per_hyperperiod, migrations_per_hyperperiod }
+    }
+
+    /// Migrations per second.
+    pub fn migration_rate(&self) -> f64 {
+        self.migrations_per_hyperperiod / (self.hyperperiod / Time::nanos(1e9))
+    }
+}
+
+impl SemiPartitioning {
+    pub fn check_preconditions(&self, taskset: &[RTTask], num_processors: u64) -> Result<(), SchedError> {
+        if self.processors.len() != taskset.len() {
+            Err(SchedError::Precondition(Some(
+                anyhow::format_err!("the partitioning must list the processors of every task."))))
+        } else if self.processors.iter().any(|processors| processors.is_empty()) {
+            Err(SchedError::Precondition(Some(
+                anyhow::format_err!("every task must run on at least one processor."))))
+        } else if self.processors.iter().flatten().any(|cpu| *cpu as u64 >= num_processors) {
+            Err(SchedError::Precondition(Some(
+                anyhow::format_err!("the partitioning uses more processors than available."))))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Run the given global analysis and attach the [`MigrationBound`] to its
+/// result. The number of processors is the one of the analysis.
+///
+/// Refer to the [module](`self`) level documentation.
+pub struct WithMigrationBound<A> {
+    pub policy: MigrationPolicy,
+    pub analysis: A,
+}
+
+impl<T, A> SchedAnalysis<(T, MigrationBound), &[RTTask]> for WithMigrationBound<A>
+    where
+        A: for<'a> SchedAnalysis<T, &'a [RTTask]> + SchedProcessors,
+{
+    fn analyzer_name(&self) -> &str { self.analysis.analyzer_name() }
+
+    fn check_preconditions(&self, taskset: &&[RTTask]) -> Result<(), SchedError> {
+        self.analysis.check_preconditions(taskset)
+    }
+
+    fn run_test(&self, taskset: &[RTTask]) -> Result<(T, MigrationBound), SchedError> {
+        let result = self.analysis.run_test(taskset)?;
+
+        Ok((result, MigrationBound::new(taskset, self.analysis.num_processors(), self.policy)))
+    }
+}
+
+#[test]
+fn migration_bounds() {
+    // Hyperperiod of 8 with 2 + 1 + 1 jobs.
+    let taskset = [
+        RTTask::new_ns(1, 4, 4),
+        RTTask::new_ns(2, 6, 8),
+        RTTask::new_ns(4, 8, 8),
+    ];
+
+    // FP: the job of task 1 may be preempted twice by task 0 within its
+    // deadline of 6, the job of task 2 twice by task 0 and once by task 1:
+    // 5 preemptions, but only 4 releases may cause one.
+    let bound = MigrationBound::global_fp(&taskset, 2);
+    assert_eq!(bound.hyperperiod, Time::nanos(8.0));
+    assert_eq!(bound.jobs_per_hyperperiod, 4.0);
+    assert_eq!(bound.migrations_per_hyperperiod, 4.0);
+
+    // EDF: task 0 preempts the job of task 1 only if released in (0, 2), and
+    // the one of task 2 if released in (0, 4); task 1 preempts the job of
+    // task 2 if released in (0, 2).
+    let bound = MigrationBound::global_edf(&taskset, 2);
+    assert_eq!(bound.migrations_per_hyperperiod, 3.0);
+    assert_eq!(bound.migration_rate(), 3.0 / 8e-9);
+
+    assert_eq!(MigrationBound::global_edf(&taskset, 1).migrations_per_hyperperiod, 0.0);
+    assert_eq!(MigrationBound::global_fp(&taskset, 3).migrations_per_hyperperiod, 0.0);
+}
+
+#[test]
+fn semi_partitioned_migrations() {
+    let taskset = [
+        RTTask::new_ns(1, 4, 4),
+        RTTask::new_ns(2, 6, 8),
+        RTTask::new_ns(4, 8, 8),
+    ];
+
+    // Task 0 is split over the two processors and migrates once per job, its
+    // two jobs per hyperperiod. Task 2 is split over three processors.
+    let partitioning = SemiPartitioning { processors: vec![vec![0, 1], vec![1], vec![2, 0, 1]] };
+    assert!(partitioning.check_preconditions(&taskset, 3).is_ok());
+    assert!(partitioning.check_preconditions(&taskset, 2).is_err());
+
+    let bound = MigrationBound::semi_partitioned(&taskset, &partitioning);
+    assert_eq!(bound.jobs_per_hyperperiod, 4.0);
+    assert_eq!(bound.migrations_per_hyperperiod, 2.0 + 2.0);
+
+    let pinned = SemiPartitioning { processors: vec![vec![0], vec![1], vec![1]] };
+    assert_eq!(MigrationBound::semi_partitioned(&taskset, &pinned).migrations_per_hyperperiod, 0.0);
+}
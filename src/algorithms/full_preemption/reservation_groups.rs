@@ -0,0 +1,220 @@
+//! ## Reservation Groups on Virtual Processors
+//!
+//! The tasks are partitioned in groups, and each group is served by a
+//! reservation, i.e. a virtual processor supplying a budget *Θ* every period
+//! *Π*, pinned onto a physical CPU. Rather than deriving the interfaces of the
+//! groups, as a full hierarchical analysis would, the reservations are given,
+//! and the analysis is split in two independent steps:
+//! - each group is analyzed on the [`PRModel`] of its reservation, under its
+//!   local scheduler \[1\];
+//! - the reservations pinned onto each physical CPU are analyzed as periodic
+//!   servers, which must supply their budget within each period regardless of
+//!   the other reservations (refer to [`isolation`]).
+//!
+//! #### Model:
+//! - Periodic/Sporadic Task model
+//! - Fully-Preemptive EDF or Fixed Priority local scheduling (the tasks of
+//!   each group are sorted by priority, highest first)
+//! - Partitioned, Fully-Preemptive EDF or Rate Monotonic scheduling of the
+//!   reservations on each CPU
+//!
+//! #### Preconditions:
+//! - Every task belongs to exactly one group
+//! - Feasible reservations, pinned onto the platform's CPUs
+//! - The preconditions of the local scheduler's test
+//!
+//! #### Implements:
+//! - [`Analysis::is_schedulable`] \
+//!   | pseudo-polynomial complexity
+//!
+//! ---
+//! #### References:
+//! 1. Shin and I. Lee, “Periodic resource model for compositional real-time
+//!    guarantees,” in RTSS 2003. 24th IEEE Real-Time Systems Symposium, 2003,
+//!    Dec. 2003, pp. 2–13. doi: 10.1109/REAL.2003.1253249.
+
+use crate::prelude::*;
+use crate::algorithms::full_preemption::global_multiprocessor::hierarchical::mpr_model09::partitioning::ServerScheduler;
+use crate::algorithms::full_preemption::uniprocessor::hierarchical::pr_model03::*;
+use crate::algorithms::full_preemption::uniprocessor::hierarchical::pr_model03::isolation::AnalysisIsolation;
+
+const ALGORITHM: &str = "Reservation Groups on Virtual Processors";
+
+/// Scheduler of the tasks within each reservation.
+#[derive(Debug, Clone, Copy)]
+#[derive(PartialEq, Eq)]
+pub enum LocalScheduler {
+    EarliestDeadlineFirst,
+    FixedPriority,
+}
+
+/// Group of tasks served by a reservation.
+#[derive(Debug, Clone)]
+pub struct ReservationGroup {
+    /// Indices of the tasks of the group. With Fixed Priority local scheduling,
+    /// they are sorted by priority, highest first.
+    pub tasks: Vec<usize>,
+    pub reservation: PRModel,
+    /// Physical CPU the reservation is pinned onto.
+    pub cpu: usize,
+}
+
+/// Reservation Groups on Virtual Processors
+///
+/// Refer to the [module](`self`) level documentation.
+pub struct Analysis {
+    pub groups: Vec<ReservationGroup>,
+    pub local_scheduler: LocalScheduler,
+    pub server_scheduler: ServerScheduler,
+    pub num_processors: u64,
+}
+
+impl Analysis {
+    fn group_taskset(&self, taskset: &[RTTask], group: &ReservationGroup) -> Vec<RTTask> {
+        group.tasks.iter()
+            .map(|&i| taskset[i].clone())
+            .collect()
+    }
+
+    fn local_preconditions(&self, group: &ReservationGroup, taskset: &[RTTask]) -> Result<(), SchedError> {
+        let model = group.reservation.clone();
+
+        match self.local_scheduler {
+            LocalScheduler::EarliestDeadlineFirst =>
+                earliest_deadline_first::shin_lee03::Analysis { model }.check_preconditions(&taskset),
+            LocalScheduler::FixedPriority =>
+                fixed_priority::shin_lee03::Analysis { model }.check_preconditions(&taskset),
+        }
+    }
+
+    fn local_test(&self, group: &ReservationGroup, taskset: &[RTTask]) -> Result<(), SchedError> {
+        let model = group.reservation.clone();
+
+        match self.local_scheduler {
+            LocalScheduler::EarliestDeadlineFirst =>
+                earliest_deadline_first::shin_lee03::Analysis { model }.run_test(taskset),
+            LocalScheduler::FixedPriority =>
+                fixed_priority::shin_lee03::Analysis { model }.run_test(taskset).map(|_| ()),
+        }
+    }
+}
+
+impl SchedAnalysis<(), &[RTTask]> for Analysis {
+    fn analyzer_name(&self) -> &str { ALGORITHM }
+
+    fn check_preconditions(&self, taskset: &&[RTTask]) -> Result<(), SchedError> {
+        let mut groups_of_task = vec![0; taskset.len()];
+        for group in self.groups.iter() {
+            for &i in group.tasks.iter() {
+                if i >= taskset.len() {
+                    return Err(SchedError::Precondition(Some(
+                        anyhow::format_err!("task {i} of the groups is not in the taskset."))));
+                }
+
+                groups_of_task[i] += 1;
+            }
+        }
+
+        if let Some(i) = groups_of_task.iter().position(|&groups| groups != 1) {
+            return Err(SchedError::Precondition(Some(
+                anyhow::format_err!("task {i} must belong to exactly one group."))));
+        }
+
+        for (g, group) in self.groups.iter().enumerate() {
+            if !group.reservation.is_feasible() || group.reservation.resource <= Time::zero() {
+                return Err(SchedError::Precondition(Some(
+                    anyhow::format_err!("the reservation of group {g} is not feasible."))));
+            } else if group.cpu as u64 >= self.num_processors {
+                return Err(SchedError::Precondition(Some(
+                    anyhow::format_err!("the reservation of group {g} is pinned onto a missing CPU."))));
+            }
+
+            let group_taskset = self.group_taskset(taskset, group);
+            self.local_preconditions(group, &group_taskset)
+                .map_err(|err| err.with_context(&format!("group {g}")))?;
+        }
+
+        Ok(())
+    }
+
+    fn run_test(&self, taskset: &[RTTask]) -> Result<(), SchedError> {
+        // Each group on its virtual processor.
+        for (g, group) in self.groups.iter().enumerate() {
+            let group_taskset = self.group_taskset(taskset, group);
+
+            self.local_test(group, &group_taskset)
+                .map_err(|err| err.with_context(&format!("group {g} on its reservation")))?;
+        }
+
+        // The reservations on each physical CPU.
+        let isolation = AnalysisIsolation { scheduler: self.server_scheduler };
+
+        for cpu in 0..self.num_processors as usize {
+            let mut reservations: Vec<_> = self.groups.iter()
+                .filter(|group| group.cpu == cpu)
+                .map(|group| group.reservation.clone())
+                .collect();
+
+            if self.server_scheduler == ServerScheduler::RateMonotonic {
+                reservations.sort_by_key(|reservation| reservation.period);
+            }
+
+            isolation.run_test(&reservations)
+                .map_err(|err| err.with_context(&format!("reservations of CPU {cpu}")))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl SchedProcessors for Analysis {
+    fn num_processors(&self) -> u64 { self.num_processors }
+}
+
+#[test]
+fn reservation_groups() {
+    let taskset = [
+        RTTask::new_ns(1, 10, 10),
+        RTTask::new_ns(2, 20, 20),
+        RTTask::new_ns(1, 10, 10),
+        RTTask::new_ns(4, 40, 40),
+    ];
+
+    let group = |tasks: Vec<usize>, resource: f64, period: f64, cpu: usize| ReservationGroup {
+        tasks,
+        reservation: PRModel { resource: Time::nanos(resource), period: Time::nanos(period) },
+        cpu,
+    };
+
+    let analysis = |groups, num_processors| Analysis {
+        groups,
+        local_scheduler: LocalScheduler::FixedPriority,
+        server_scheduler: ServerScheduler::RateMonotonic,
+        num_processors,
+    };
+
+    // Each group demands a bandwidth of 0.2, with a delay of 2(5 - 2) = 6.
+    let groups = vec![group(vec![0, 1], 2.0, 5.0, 0), group(vec![2, 3], 2.0, 5.0, 0)];
+    assert!(analysis(groups.clone(), 1).is_schedulable(&taskset).is_ok());
+
+    // A reservation too small for its group.
+    let small = vec![group(vec![0, 1], 1.0, 5.0, 0), group(vec![2, 3], 2.0, 5.0, 0)];
+    let err = analysis(small, 1).is_schedulable(&taskset).unwrap_err();
+    assert!(format!("{err:#}").contains("group 0 on its reservation"));
+
+    // The reservations overload the CPU, unless split across two.
+    let large = vec![group(vec![0, 1], 3.0, 5.0, 0), group(vec![2, 3], 3.0, 5.0, 0)];
+    let err = analysis(large.clone(), 1).is_schedulable(&taskset).unwrap_err();
+    assert!(format!("{err:#}").contains("reservations of CPU 0"));
+
+    let mut split = large;
+    split[1].cpu = 1;
+    assert!(analysis(split.clone(), 2).is_schedulable(&taskset).is_ok());
+    assert!(analysis(split, 1).is_schedulable(&taskset).is_err());
+
+    // Every task in exactly one group.
+    let missing = vec![group(vec![0, 1], 2.0, 5.0, 0), group(vec![3], 2.0, 5.0, 0)];
+    assert!(analysis(missing, 1).is_schedulable(&taskset).is_err());
+    let duplicate = vec![group(vec![0, 1, 2], 2.0, 5.0, 0), group(vec![2, 3], 2.0, 5.0, 0)];
+    assert!(analysis(duplicate, 1).is_schedulable(&taskset).is_err());
+}
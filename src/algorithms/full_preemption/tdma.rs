@@ -0,0 +1,391 @@
+//! ## TDMA Network Slot Design
+//!
+//! Time Division Multiple Access networks, such as SpaceWire links or
+//! time-triggered backplanes, repeat a static schedule every cycle: each
+//! message stream owns a slot of the cycle, within which only its frames are
+//! transmitted. The streams are periodic, with their own deadlines, and the
+//! transmission time of a message may span the slots of several cycles.
+//!
+//! In the worst case a message is queued right after its slot ends, and it
+//! waits for the rest of the cycle before each of the slots it needs \[1\]:
+//!   R = (T_c - s) + (ceil(C / s) - 1) T_c + C - (ceil(C / s) - 1) s
+//! for a cycle *T_c*, a slot *s* and a transmission time *C*. With constrained
+//! deadlines met, each message is transmitted before the next one is queued.
+//!
+//! The designer assigns each stream the smallest slot, a multiple of the time
+//! quantum, which meets its deadline, and lays out the slots back to back. To
+//! co-design the network with the processor, the messages can also be hops of
+//! the [task chains](super::end_to_end), each sampling the latest output of
+//! the previous hop: the spare time of the cycle is then assigned, one quantum
+//! at a time, to the slots of the messages of the chains which miss their
+//! end-to-end deadlines.
+//!
+//! #### Model:
+//! - Periodic message streams, one slot per stream in each cycle
+//! - Chains communicating through shared buffers (implicit communication)
+//!
+//! #### Preconditions:
+//! - Constrained Deadlines
+//! - Non-overlapping slots within the cycle, one per message stream
+//!
+//! #### Implements:
+//! - [`Analysis::is_schedulable`] \
+//!   | Response times of the messages. \
+//!   | \
+//!   | linear *O(n)* complexity
+//! - [`Designer::design`] \
+//!   | Slots meeting the deadlines of the messages. \
+//!   | \
+//!   | O(*n* log(*cycle* / *quantum*)) complexity
+//! - [`DesignerEndToEnd::design`] \
+//!   | Slots meeting the deadlines of the messages and of the task chains. \
+//!   | \
+//!   | the complexity of the response time analysis, plus
+//!   | O(*cycle* / *quantum*) chain latency evaluations
+//!
+//! ---
+//! #### References:
+//! 1. H. Kopetz, “Real-Time Systems: Design Principles for Distributed
+//!    Embedded Applications,” 2nd ed., Springer, 2011,
+//!    doi: 10.1007/978-1-4419-8237-7.
+
+use crate::prelude::*;
+use super::end_to_end::{TaskChain, ChainLatency, chain_latencies};
+
+const ALGORITHM: &str = "TDMA Network Slot Design";
+
+/// Slot of the TDMA cycle owned by a message stream.
+#[derive(Debug, Clone)]
+#[derive(PartialEq)]
+pub struct Slot {
+    pub message: usize,
+    /// Offset from the start of the cycle.
+    pub offset: Time,
+    pub duration: Time,
+}
+
+impl Slot {
+    pub fn end(&self) -> Time {
+        self.offset + self.duration
+    }
+}
+
+/// TDMA schedule, repeated every cycle.
+#[derive(Debug, Clone)]
+pub struct TdmaSchedule {
+    pub cycle: Time,
+    /// Slots sorted by offset.
+    pub slots: Vec<Slot>,
+}
+
+impl TdmaSchedule {
+    pub fn slot(&self, message: usize) -> Option<&Slot> {
+        self.slots.iter().find(|slot| slot.message == message)
+    }
+
+    /// Worst-case response time of a message with the given transmission time
+    /// and slot, refer to the [module](`self`) level documentation.
+    pub fn response_time(&self, transmission: Time, slot: Time) -> Time {
+        response_time(self.cycle, transmission, slot)
+    }
+
+    /// Worst-case response times of the messages.
+    pub fn response_times(&self, messages: &[RTTask]) -> Vec<Time> {
+        messages.iter().enumerate()
+            .map(|(m, message)| {
+                let slot = self.slot(m).map_or(Time::zero(), |slot| slot.duration);
+                self.response_time(message.wcet, slot)
+            })
+            .collect()
+    }
+}
+
+// Section 5.5 [1]
+fn response_time(cycle: Time, transmission: Time, slot: Time) -> Time {
+    if slot <= Time::zero() {
+        return Time::nanos(f64::INFINITY);
+    }
+
+    let full_cycles = ((transmission / slot).ceil() - 1.0).max(0.0);
+
+    cycle - slot + cycle * full_cycles + transmission - slot * full_cycles
+}
+
+/// TDMA Network Slot Design \
+/// Analysis of the messages on a given schedule.
+///
+/// Refer to the [module](`self`) level documentation.
+///
+/// Returns:
+/// - The response time of each message.
+pub struct Analysis {
+    pub schedule: TdmaSchedule,
+}
+
+impl SchedAnalysis<Vec<Time>, &[RTTask]> for Analysis {
+    fn analyzer_name(&self) -> &str { ALGORITHM }
+
+    fn check_preconditions(&self, messages: &&[RTTask]) -> Result<(), SchedError> {
+        if !RTUtils::constrained_deadlines(messages) {
+            return Err(SchedError::constrained_deadlines());
+        } else if let Some(m) = (0..messages.len())
+            .find(|&m| self.schedule.slots.iter().filter(|slot| slot.message == m).count() != 1)
+        {
+            return Err(SchedError::Precondition(Some(
+                anyhow::format_err!("message {m} must own exactly one slot."))));
+        }
+
+        let mut busy_until = Time::zero();
+        for slot in self.schedule.slots.iter() {
+            if slot.message >= messages.len() {
+                return Err(SchedError::Precondition(Some(anyhow::format_err!(
+                    "the slot at {}ns belongs to missing message {}.", slot.offset.as_nanos(), slot.message))));
+            } else if slot.offset < busy_until || slot.end() > self.schedule.cycle {
+                return Err(SchedError::Precondition(Some(anyhow::format_err!(
+                    "the slot at {}ns overlaps the previous one or exceeds the cycle.", slot.offset.as_nanos()))));
+            }
+
+            busy_until = slot.end();
+        }
+
+        Ok(())
+    }
+
+    fn run_test(&self, messages: &[RTTask]) -> Result<Vec<Time>, SchedError> {
+        let response_times = self.schedule.response_times(messages);
+
+        match messages.iter().zip(&response_times).position(|(message, response)| *response > message.deadline) {
+            Some(m) => Err(SchedError::NonSchedulable(Some(anyhow::format_err!(
+                "message {m} has a response time of {}ns, past its deadline of {}ns.",
+                response_times[m].as_nanos(), messages[m].deadline.as_nanos())))),
+            None => Ok(response_times),
+        }
+    }
+}
+
+/// TDMA Network Slot Design \
+/// Design the slots meeting the deadlines of the messages.
+///
+/// Refer to the [module](`self`) level documentation.
+pub struct Designer {
+    pub cycle: Time,
+    pub quantization: Quantization,
+}
+
+impl Designer {
+    fn max_quanta(&self) -> u64 {
+        (self.cycle / self.quantization.quantum).floor() as u64
+    }
+
+    // Smallest slot, in quanta, for which the message meets its deadline: the
+    // response time decreases with the slot.
+    fn slot_quanta(&self, message: &RTTask) -> Option<u64> {
+        let meets_deadline = |quanta: u64|
+            response_time(self.cycle, message.wcet, self.quantization.quantum * quanta as f64) <= message.deadline;
+
+        let (mut left, mut right) = (1, self.max_quanta());
+        if right == 0 || !meets_deadline(right) {
+            return None;
+        }
+
+        while left < right {
+            let mid = left + (right - left) / 2;
+
+            if meets_deadline(mid) {
+                right = mid;
+            } else {
+                left = mid + 1;
+            }
+        }
+
+        Some(left)
+    }
+
+    fn min_slots(&self, messages: &[RTTask]) -> Result<Vec<u64>, SchedError> {
+        let slots = messages.iter().enumerate()
+            .map(|(m, message)| self.slot_quanta(message)
+                .ok_or_else(|| SchedError::NonSchedulable(Some(anyhow::format_err!(
+                    "message {m} misses its deadline even with the whole cycle.")))))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let total: u64 = slots.iter().sum();
+        if total > self.max_quanta() {
+            return Err(SchedError::NonSchedulable(Some(anyhow::format_err!(
+                "the slots take {}ns, exceeding the cycle of {}ns.",
+                (self.quantization.quantum * total as f64).as_nanos(), self.cycle.as_nanos()))));
+        }
+
+        Ok(slots)
+    }
+
+    fn schedule(&self, slots: &[u64]) -> TdmaSchedule {
+        let mut offset = Time::zero();
+
+        let slots = slots.iter().enumerate()
+            .map(|(message, &quanta)| {
+                let slot = Slot { message, offset, duration: self.quantization.quantum * quanta as f64 };
+                offset = slot.end();
+                slot
+            })
+            .collect();
+
+        TdmaSchedule { cycle: self.cycle, slots }
+    }
+
+    fn check_messages(&self, messages: &[RTTask]) -> Result<(), SchedError> {
+        self.quantization.check_preconditions(messages)?;
+
+        if !RTUtils::constrained_deadlines(messages) {
+            Err(SchedError::constrained_deadlines())
+        } else if self.cycle < self.quantization.quantum {
+            Err(SchedError::Precondition(Some(
+                anyhow::format_err!("the cycle must be at least a time quantum."))))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl SchedDesign<&[RTTask], TdmaSchedule> for Designer {
+    fn designer_name(&self) -> &str { ALGORITHM }
+
+    fn check_preconditions(&self, messages: &&[RTTask]) -> Result<(), SchedError> {
+        self.check_messages(messages)
+    }
+
+    fn run_designer(&self, messages: &[RTTask]) -> Result<TdmaSchedule, SchedError> {
+        Ok(self.schedule(&self.min_slots(messages)?))
+    }
+}
+
+/// TDMA Network Slot Design \
+/// Design the slots meeting the deadlines of the messages and of the task
+/// chains, given the tasks and the messages.
+///
+/// Refer to the [module](`self`) level documentation.
+///
+/// The chains index the tasks first, and then the messages: message *m* is
+/// hop *n + m* of the chains, for *n* tasks. The `analysis` gives the response
+/// times of the tasks.
+pub struct DesignerEndToEnd<A> {
+    pub designer: Designer,
+    pub chains: Vec<TaskChain>,
+    pub analysis: A,
+}
+
+impl<A> DesignerEndToEnd<A> {
+    fn chain_latencies(&self, hops: &[RTTask], task_response_times: &[Time], schedule: &TdmaSchedule) -> Vec<ChainLatency> {
+        let messages = &hops[task_response_times.len()..];
+
+        let response_times: Vec<_> = task_response_times.iter().copied()
+            .chain(schedule.response_times(messages))
+            .collect();
+
+        chain_latencies(hops, &response_times, &self.chains)
+    }
+}
+
+impl<'a, A> SchedDesign<(&'a [RTTask], &'a [RTTask]), TdmaSchedule> for DesignerEndToEnd<A>
+    where
+        A: for<'b> SchedAnalysis<Vec<Time>, &'b [RTTask]>,
+{
+    fn designer_name(&self) -> &str { ALGORITHM }
+
+    fn check_preconditions(&self, (tasks, messages): &(&'a [RTTask], &'a [RTTask])) -> Result<(), SchedError> {
+        self.designer.check_messages(messages)?;
+
+        for (c, chain) in self.chains.iter().enumerate() {
+            if chain.tasks.is_empty() {
+                return Err(SchedError::Precondition(Some(
+                    anyhow::format_err!("chain {c} has no hops."))));
+            } else if chain.tasks.iter().any(|&i| i >= tasks.len() + messages.len()) {
+                return Err(SchedError::Precondition(Some(
+                    anyhow::format_err!("chain {c} has hops which are neither tasks nor messages."))));
+            }
+        }
+
+        self.analysis.check_preconditions(tasks)
+    }
+
+    fn run_designer(&self, (tasks, messages): (&'a [RTTask], &'a [RTTask])) -> Result<TdmaSchedule, SchedError> {
+        let task_response_times = self.analysis.run_test(tasks)?;
+        let hops: Vec<_> = tasks.iter().chain(messages).cloned().collect();
+
+        let mut slots = self.designer.min_slots(messages)?;
+        let mut spare = self.designer.max_quanta() - slots.iter().sum::<u64>();
+
+        loop {
+            let schedule = self.designer.schedule(&slots);
+            let latencies = self.chain_latencies(&hops, &task_response_times, &schedule);
+
+            let Some(c) = latencies.iter().position(|chain| !chain.is_met()) else {
+                return Ok(schedule);
+            };
+
+            // Grow the slot of the chain's message which gains the most from
+            // one more quantum, or the slowest one if none gains yet.
+            let response = |m: usize, quanta: u64|
+                response_time(self.designer.cycle, messages[m].wcet, self.designer.quantization.quantum * quanta as f64);
+
+            let grown = self.chains[c].tasks.iter()
+                .filter_map(|&i| i.checked_sub(tasks.len()))
+                .filter(|_| spare > 0)
+                .max_by_key(|&m| (response(m, slots[m]) - response(m, slots[m] + 1), response(m, slots[m])));
+
+            let Some(m) = grown else {
+                return Err(SchedError::NonSchedulable(Some(anyhow::format_err!(
+                    "chain {c} has an end-to-end latency of {}ns, past its deadline of {}ns, with no spare time in the cycle for its messages.",
+                    latencies[c].latency().as_nanos(), latencies[c].deadline.as_nanos()))));
+            };
+
+            slots[m] += 1;
+            spare -= 1;
+        }
+    }
+}
+
+#[test]
+fn tdma_slots() {
+    use crate::algorithms::full_preemption::uniprocessor::fixed_priority::rta86;
+
+    let at = |t: u64| Time::nanos(t as f64);
+
+    // A message of 3 in a slot of 2: it waits 10 - 2 for the slot, and then
+    // for another cycle to complete.
+    assert_eq!(response_time(at(10), at(3), at(2)), at(19));
+    assert_eq!(response_time(at(10), at(2), at(2)), at(10));
+
+    let messages = [RTTask::new_ns(2, 20, 40), RTTask::new_ns(1, 15, 20)];
+    let designer = Designer { cycle: at(10), quantization: Quantization::nanos() };
+    let schedule = designer.design(&messages[..]).unwrap();
+
+    // R_0 = 10 - 1 + 10 + 1 with a slot of 1, R_1 = 10 with a slot of 1.
+    assert_eq!(schedule.slots, vec![
+        Slot { message: 0, offset: at(0), duration: at(1) },
+        Slot { message: 1, offset: at(1), duration: at(1) },
+    ]);
+    assert_eq!(Analysis { schedule: schedule.clone() }.is_schedulable(&messages[..]).unwrap(), vec![at(20), at(10)]);
+
+    // Each message needs a slot of 6 to be transmitted within 6.
+    let tight = [RTTask::new_ns(2, 6, 40), RTTask::new_ns(2, 6, 40)];
+    assert!(designer.design(&tight[..1]).is_ok());
+    let err = designer.design(&tight[..]).unwrap_err();
+    assert!(format!("{err:#}").contains("exceeding the cycle"));
+
+    // Task 0 sends message 0 to task 1: the chain's latency is
+    // (4 + 1) + (40 + R_m) + (8 + 2), within 70 only if R_m <= 15, i.e.
+    // with a slot of 2.
+    let tasks = [RTTask::new_ns(1, 4, 4), RTTask::new_ns(1, 8, 8)];
+    let chain = TaskChain { tasks: vec![0, 2, 1], deadline: at(70) };
+    let designer = |deadline| DesignerEndToEnd {
+        designer: Designer { cycle: at(10), quantization: Quantization::nanos() },
+        chains: vec![TaskChain { deadline, ..chain.clone() }],
+        analysis: rta86::Analysis,
+    };
+
+    let schedule = designer(at(70)).design((&tasks[..], &messages[..1])).unwrap();
+    assert_eq!(schedule.slots[0].duration, at(2));
+
+    let err = designer(at(50)).design((&tasks[..], &messages[..1])).unwrap_err();
+    assert!(format!("{err:#}").contains("chain 0"));
+}
@@ -0,0 +1,91 @@
+//! ## Asynchronous EDF - Leung & Merrill 1980
+//!
+//! #### Model:
+//! - Periodic Task model, with release offsets (see [`ReleaseOffsets`])
+//! - Fully-Preemptive EDF scheduling
+//!
+//! #### Preconditions:
+//! - Constrained Deadlines
+//! - Task parameters and offsets are integer nanoseconds
+//!
+//! #### Implements:
+//! - [`Analysis::is_schedulable`] \
+//!   | Simulates the schedule over the feasibility interval. \
+//!   | \
+//!   | exponential complexity
+//!
+//! ---
+//! #### References:
+//! 1. J. Y.-T. Leung and M. L. Merrill, “A note on preemptive scheduling of
+//!    periodic, real-time tasks,” Information Processing Letters, vol. 11,
+//!    no. 3, pp. 115–118, Nov. 1980, doi: 10.1016/0020-0190(80)90123-4.
+
+use crate::prelude::*;
+use crate::algorithms::full_preemption::uniprocessor::asynchronous::*;
+use crate::simulator::schedulers::EarliestDeadlineFirst;
+
+const ALGORITHM: &str = "Asynchronous EDF (Leung & Merrill 1980)";
+
+/// Asynchronous EDF - Leung & Merrill 1980 \[1\]
+///
+/// Refer to the [module](`self`) level documentation.
+pub struct Analysis {
+    pub offsets: ReleaseOffsets,
+}
+
+impl SchedAnalysis<(), &[RTTask]> for Analysis {
+    fn analyzer_name(&self) -> &str { ALGORITHM }
+
+    fn check_preconditions(&self, taskset: &&[RTTask]) -> Result<(), SchedError> {
+        if !RTUtils::constrained_deadlines(taskset) {
+            Err(SchedError::constrained_deadlines())
+        } else {
+            self.offsets.check_preconditions(taskset)
+        }
+    }
+
+    fn run_test(&self, taskset: &[RTTask]) -> Result<(), SchedError> {
+        // Theorem 1 [1]: the schedule must not be overloaded in the long run,
+        // and meet all the deadlines in the feasibility interval.
+        if RTUtils::total_utilization(taskset) > 1.0 {
+            return Err(SchedError::NonSchedulable(Some(
+                anyhow::format_err!("total utilization exceeds one."))));
+        }
+
+        let result = self.offsets.simulate(taskset, &mut EarliestDeadlineFirst)?;
+        first_deadline_miss(&result)
+    }
+}
+
+impl SchedProcessors for Analysis {
+    fn num_processors(&self) -> u64 { 1 }
+}
+
+impl SchedPlatform for Analysis {
+    fn supports(&self, platform: &Platform) -> bool {
+        platform.matches(PlatformModel::Uniprocessor, SchedulingAlgorithm::EarliestDeadlineFirst, 1)
+    }
+}
+
+#[test]
+fn phased_taskset() {
+    // Each task has density 2/3: synchronously released, the two jobs
+    // arriving at zero demand 4 within 3.
+    let taskset = [
+        RTTask::new_ns(2, 3, 6),
+        RTTask::new_ns(2, 3, 6),
+    ];
+
+    let synchronous = Analysis { offsets: ReleaseOffsets::synchronous(2) };
+    assert!(synchronous.is_schedulable(&taskset).is_err());
+
+    // Phased by half a period, the jobs never overlap.
+    let phased = Analysis { offsets: ReleaseOffsets { offsets: vec![Time::zero(), Time::nanos(3.0)] } };
+    assert!(phased.is_schedulable(&taskset).is_ok());
+
+    // Overloaded in the long run.
+    let overloaded = [RTTask::new_ns(3, 6, 6), RTTask::new_ns(3, 6, 6), RTTask::new_ns(1, 6, 6)];
+    assert!(Analysis { offsets: ReleaseOffsets::synchronous(3) }.is_schedulable(&overloaded).is_err());
+
+    assert!(Analysis { offsets: ReleaseOffsets::synchronous(1) }.is_schedulable(&taskset).is_err());
+}
@@ -0,0 +1,107 @@
+//! ## Asynchronous FP - Leung & Whitehead 1982
+//!
+//! #### Model:
+//! - Periodic Task model, with release offsets (see [`ReleaseOffsets`])
+//! - Fully-Preemptive Fixed-Priority scheduling
+//!
+//! #### Preconditions:
+//! - Constrained Deadlines
+//! - Task parameters and offsets are integer nanoseconds
+//!
+//! #### Implements:
+//! - [`Analysis::is_schedulable`] \
+//!   | Simulates the schedule over the feasibility interval. \
+//!   | \
+//!   | exponential complexity
+//!
+//! ---
+//! #### References:
+//! 1. J. Y.-T. Leung and J. Whitehead, “On the complexity of fixed-priority
+//!    scheduling of periodic, real-time tasks,” Performance Evaluation, vol. 2,
+//!    no. 4, pp. 237–250, Dec. 1982, doi: 10.1016/0166-5316(82)90024-4.
+
+use crate::prelude::*;
+use crate::algorithms::full_preemption::uniprocessor::asynchronous::*;
+use crate::simulator::schedulers::FixedPriority;
+
+const ALGORITHM: &str = "Asynchronous FP (Leung & Whitehead 1982)";
+
+/// Asynchronous FP - Leung & Whitehead 1982 \[1\]
+///
+/// Refer to the [module](`self`) level documentation.
+///
+/// Returns:
+/// - Worst-Case Response Times of each task, over all its jobs.
+pub struct Analysis {
+    pub offsets: ReleaseOffsets,
+}
+
+impl SchedAnalysis<Vec<Time>, &[RTTask]> for Analysis {
+    fn analyzer_name(&self) -> &str { ALGORITHM }
+
+    fn check_preconditions(&self, taskset: &&[RTTask]) -> Result<(), SchedError> {
+        if !RTUtils::constrained_deadlines(taskset) {
+            Err(SchedError::constrained_deadlines())
+        } else {
+            self.offsets.check_preconditions(taskset)
+        }
+    }
+
+    fn run_test(&self, taskset: &[RTTask]) -> Result<Vec<Time>, SchedError> {
+        // Theorem 3.5 [1]: the schedule must not be overloaded in the long run,
+        // and meet all the deadlines in the feasibility interval, after which
+        // it repeats with the hyperperiod.
+        if RTUtils::total_utilization(taskset) > 1.0 {
+            return Err(SchedError::NonSchedulable(Some(
+                anyhow::format_err!("total utilization exceeds one."))));
+        }
+
+        let result = self.offsets.simulate(taskset, &mut FixedPriority)?;
+        first_deadline_miss(&result)?;
+
+        // Without deadline misses, the first job of every task completes
+        // within the interval.
+        Ok(result.max_response_times.into_iter().flatten().collect())
+    }
+}
+
+impl SchedProcessors for Analysis {
+    fn num_processors(&self) -> u64 { 1 }
+}
+
+impl SchedPlatform for Analysis {
+    fn supports(&self, platform: &Platform) -> bool {
+        platform.matches(PlatformModel::Uniprocessor, SchedulingAlgorithm::FixedPriority, 1)
+    }
+}
+
+#[test]
+fn phased_taskset() {
+    use crate::algorithms::full_preemption::uniprocessor::fixed_priority::rta86;
+
+    let taskset = [
+        RTTask::new_ns(2, 8, 8),
+        RTTask::new_ns(4, 5, 8),
+    ];
+
+    // At the critical instant, task 1 completes at 6.
+    assert!(rta86::Analysis.is_schedulable(&taskset).is_err());
+    assert!(Analysis { offsets: ReleaseOffsets::synchronous(2) }.is_schedulable(&taskset).is_err());
+
+    // Released when task 0 completes, its jobs are never preempted.
+    let offsets = ReleaseOffsets { offsets: vec![Time::zero(), Time::nanos(2.0)] };
+    assert_eq!(
+        Analysis { offsets }.is_schedulable(&taskset).unwrap(),
+        vec![Time::nanos(2.0), Time::nanos(4.0)]
+    );
+
+    // Synchronous releases give the response times at the critical instant.
+    let taskset = [
+        RTTask::new_ns(1, 4, 4),
+        RTTask::new_ns(2, 6, 8),
+    ];
+    assert_eq!(
+        Analysis { offsets: ReleaseOffsets::synchronous(2) }.is_schedulable(&taskset).unwrap(),
+        rta86::Analysis.is_schedulable(&taskset).unwrap()
+    );
+}
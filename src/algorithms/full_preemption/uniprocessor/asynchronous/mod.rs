@@ -0,0 +1,113 @@
+//! ## Asynchronous Periodic Tasks - Leung & Merrill 1980
+//!
+//! Model of periodic tasks whose first job is released at a given offset,
+//! rather than all at time zero. The tests based on the critical instant, the
+//! synchronous release of all the tasks, are pessimistic for such phased
+//! tasksets. Instead, the schedule of asynchronous periodic tasks repeats
+//! itself after a finite prefix: a uniprocessor FP or EDF schedule meets all
+//! the deadlines if and only if it does so over the feasibility interval
+//! *[0, O_max + 2H]* \[1\] \[2\].
+//!
+//! #### Implements:
+//! - [`ReleaseOffsets`] \
+//!   | Release offsets of periodic tasks
+//! - [`ReleaseOffsets::feasibility_interval`] \
+//!   | Length of the schedule to check. \
+//!   | \
+//!   | linear *O(n)* complexity
+//!
+//! ---
+//! #### References:
+//! 1. J. Y.-T. Leung and M. L. Merrill, “A note on preemptive scheduling of
+//!    periodic, real-time tasks,” Information Processing Letters, vol. 11,
+//!    no. 3, pp. 115–118, Nov. 1980, doi: 10.1016/0020-0190(80)90123-4.
+//! 2. J. Y.-T. Leung and J. Whitehead, “On the complexity of fixed-priority
+//!    scheduling of periodic, real-time tasks,” Performance Evaluation, vol. 2,
+//!    no. 4, pp. 237–250, Dec. 1982, doi: 10.1016/0166-5316(82)90024-4.
+
+use crate::prelude::*;
+use crate::simulator::*;
+use crate::simulator::releases::PeriodicWithOffsets;
+
+// Scheduling Algorithms
+pub mod earliest_deadline_first {
+    pub mod leung_merrill80;
+}
+
+pub mod fixed_priority {
+    pub mod leung_whitehead82;
+}
+
+/// Release offsets of periodic tasks - Leung & Merrill 1980 \[1\]
+///
+/// Refer to the [module](`self`) level documentation.
+#[derive(Debug, Clone)]
+pub struct ReleaseOffsets {
+    /// Release time of the first job of each task.
+    pub offsets: Vec<Time>,
+}
+
+impl ReleaseOffsets {
+    /// Synchronous release of the given number of tasks at time zero.
+    pub fn synchronous(num_tasks: usize) -> Self {
+        Self { offsets: vec![Time::zero(); num_tasks] }
+    }
+
+    /// Length of the feasibility interval, *O_max + 2H*, after which the
+    /// schedule repeats itself \[1\].
+    pub fn feasibility_interval(&self, taskset: &[RTTask]) -> Time {
+        let max_offset = self.offsets.iter().copied().max().unwrap_or(Time::zero());
+
+        max_offset + RTUtils::hyperperiod(taskset) * 2.0
+    }
+
+    pub(crate) fn check_preconditions(&self, taskset: &[RTTask]) -> Result<(), SchedError> {
+        if self.offsets.len() != taskset.len() {
+            Err(SchedError::Precondition(Some(
+                anyhow::format_err!("the release offset of every task must be given."))))
+        } else if self.offsets.iter().any(|offset| *offset < Time::zero()) {
+            Err(SchedError::Precondition(Some(
+                anyhow::format_err!("release offsets must be non-negative."))))
+        } else {
+            Ok(())
+        }
+    }
+
+    // Simulate the schedule over the feasibility interval, in the largest time
+    // unit which divides all the task parameters and offsets.
+    pub(crate) fn simulate<S>(&self, taskset: &[RTTask], scheduler: &mut S) -> Result<SimulationResult, SchedError>
+        where
+            S: Scheduler,
+    {
+        let rescaling = TimeRescaling::from_times(
+            taskset.iter()
+                .flat_map(|task| [task.wcet, task.deadline, task.period])
+                .chain(self.offsets.iter().copied())
+        );
+
+        let simulator = Simulator {
+            num_processors: 1,
+            time_unit: rescaling.unit,
+            horizon: self.feasibility_interval(taskset),
+        };
+
+        let offsets = self.offsets.iter()
+            .map(|offset| (*offset / rescaling.unit) as u64)
+            .collect();
+
+        simulator.simulate_with_releases(taskset, scheduler, &mut PeriodicWithOffsets { offsets })
+    }
+}
+
+// Report the first deadline miss of the simulation, if any.
+pub(crate) fn first_deadline_miss(result: &SimulationResult) -> Result<(), SchedError> {
+    match result.deadline_misses.first() {
+        Some(miss) =>
+            Err(SchedError::NonSchedulable(Some(anyhow::format_err!(
+                "the job of task {} arriving at {}ns misses its deadline.",
+                miss.task, miss.arrival.as_nanos()
+            )))),
+        None =>
+            Ok(()),
+    }
+}
@@ -0,0 +1,214 @@
+//! ## Digraph Real-Time Task Model
+//!
+//! The jobs of a digraph real-time (DRT) task follow the paths of a directed
+//! graph \[1\]. Each vertex is a job type, with its WCET and relative deadline,
+//! and each edge is labelled with the minimum separation between the releases
+//! of its source and target jobs. Any path of the graph is a valid sequence of
+//! jobs, thus the task can switch between different modes of execution. GMF
+//! tasks, whose graph is a single cycle, are a special case of DRT tasks.
+//!
+//! The demand bound function of a task is computed by exploring the paths of
+//! its graph as *demand tuples*, the execution and deadline span of the jobs of
+//! a path, pruning the paths which are dominated by another one ending in the
+//! same vertex, released earlier and with a larger execution \[1\].
+//!
+//! #### Implements:
+//! - [`DRTTask`] \
+//!   | Digraph real-time task
+//! - [`DRTTask::demand_tuples`], [`DRTTask::dbf`] \
+//!   | pseudo-polynomial complexity in the length of the interval
+//! - [`DRTTask::utilization`] \
+//!   | exponential complexity in the number of vertices, in the worst case
+//! - [`stigge11`] \
+//!   | Demand based EDF test
+//!
+//! ---
+//! #### References:
+//! 1. M. Stigge, P. Ekberg, N. Guan, and W. Yi, “The Digraph Real-Time Task
+//!    Model,” in 2011 17th IEEE Real-Time and Embedded Technology and
+//!    Applications Symposium, Apr. 2011, pp. 71–80. doi: 10.1109/RTAS.2011.15.
+
+use crate::prelude::*;
+use super::gmf_model::GMFTask;
+
+pub mod stigge11;
+
+/// Job type of a [`DRTTask`], i.e. a vertex of its graph.
+#[derive(Debug, Clone)]
+#[derive(PartialEq)]
+pub struct JobType {
+    pub wcet: Time,
+    pub deadline: Time,
+}
+
+/// Digraph real-time task
+///
+/// Refer to the [module](`self`) level documentation.
+#[derive(Debug, Clone)]
+pub struct DRTTask {
+    pub jobs: Vec<JobType>,
+    /// Edges, as (source, target, minimum separation) of the job indices.
+    pub edges: Vec<(usize, usize, Time)>,
+}
+
+impl DRTTask {
+    /// DRT task whose graph is the cycle of the frames of the GMF task.
+    pub fn from_gmf(task: &GMFTask) -> Self {
+        let jobs = task.frames.iter()
+            .map(|frame| JobType { wcet: frame.wcet, deadline: frame.deadline })
+            .collect();
+
+        let edges = task.frames.iter().enumerate()
+            .map(|(f, frame)| (f, (f + 1) % task.frames.len(), frame.period))
+            .collect();
+
+        Self { jobs, edges }
+    }
+
+    /// Total WCET of the job types.
+    pub fn total_wcet(&self) -> Time {
+        self.jobs.iter().map(|job| job.wcet).sum()
+    }
+
+    /// Long-run utilization, the largest ratio between the total WCET and
+    /// the total separation of the simple cycles of the graph. Zero if the
+    /// graph is acyclic.
+    pub fn utilization(&self) -> f64 {
+        let mut utilization = 0f64;
+
+        // Each simple cycle is enumerated from its smallest vertex.
+        for start in 0..self.jobs.len() {
+            let mut stack = vec![(start, self.jobs[start].wcet, Time::zero(), vec![start])];
+
+            while let Some((vertex, wcet, separation, path)) = stack.pop() {
+                for &(_, to, edge) in self.edges.iter().filter(|(from, _, _)| *from == vertex) {
+                    if to == start {
+                        utilization = utilization.max(wcet / (separation + edge));
+                    } else if to > start && !path.contains(&to) {
+                        let mut path = path.clone();
+                        path.push(to);
+                        stack.push((to, wcet + self.jobs[to].wcet, separation + edge, path));
+                    }
+                }
+            }
+        }
+
+        utilization
+    }
+
+    /// Demand tuples of the paths of the graph whose jobs have both release
+    /// and absolute deadline within the horizon, as (deadline span, execution),
+    /// in increasing deadline span and execution. Paths dominated by other
+    /// ones are pruned.
+    pub fn demand_tuples(&self, horizon: Time) -> Vec<(Time, Time)> {
+        // Non-dominated paths ending in each vertex, as (release of the last
+        // job, execution).
+        let mut frontier: Vec<Vec<(Time, Time)>> = vec![Vec::new(); self.jobs.len()];
+        let mut worklist = Vec::new();
+
+        for (vertex, job) in self.jobs.iter().enumerate() {
+            worklist.push((vertex, Time::zero(), job.wcet));
+        }
+
+        let mut tuples = Vec::new();
+        while let Some((vertex, release, execution)) = worklist.pop() {
+            // With frame separation, the deadline span never decreases along
+            // a path, thus the paths past the horizon are discarded.
+            if release + self.jobs[vertex].deadline > horizon {
+                continue;
+            }
+
+            let paths = &mut frontier[vertex];
+            if paths.iter().any(|&(r, e)| r <= release && e >= execution) {
+                continue;
+            }
+
+            paths.retain(|&(r, e)| !(release <= r && execution >= e));
+            paths.push((release, execution));
+            tuples.push((release + self.jobs[vertex].deadline, execution));
+
+            for &(_, to, separation) in self.edges.iter().filter(|(from, _, _)| *from == vertex) {
+                worklist.push((to, release + separation, execution + self.jobs[to].wcet));
+            }
+        }
+
+        // Keep the tuples which increase the demand bound function.
+        tuples.sort_by(|l, r| l.0.cmp(&r.0).then(r.1.cmp(&l.1)));
+        let mut max_execution = Time::zero();
+        tuples.retain(|&(_, execution)| {
+            let increases = execution > max_execution;
+            max_execution = max_execution.max(execution);
+            increases
+        });
+
+        tuples
+    }
+
+    /// Maximum cumulative execution of the jobs of the task with both release
+    /// and absolute deadline in any interval of the given length.
+    pub fn dbf(&self, interval: Time) -> Time {
+        self.demand_tuples(interval).last()
+            .map(|&(_, execution)| execution)
+            .unwrap_or(Time::zero())
+    }
+
+    /// Check that the task has job types and valid edges with positive
+    /// separations, and that the deadline of each job does not exceed the
+    /// separation from any of its successors (frame separation).
+    pub fn check_preconditions(&self) -> Result<(), SchedError> {
+        if self.jobs.is_empty() {
+            Err(SchedError::Precondition(Some(
+                anyhow::format_err!("a DRT task must have at least one job type."))))
+        } else if self.edges.iter().any(|&(from, to, _)| from >= self.jobs.len() || to >= self.jobs.len()) {
+            Err(SchedError::Precondition(Some(
+                anyhow::format_err!("DRT edges must connect existing job types."))))
+        } else if self.edges.iter().any(|&(_, _, separation)| separation <= Time::zero()) {
+            Err(SchedError::Precondition(Some(
+                anyhow::format_err!("the separations of the edges must be positive."))))
+        } else if self.edges.iter().any(|&(from, _, separation)| self.jobs[from].deadline > separation) {
+            Err(SchedError::Precondition(Some(
+                anyhow::format_err!("the deadline of a job must not exceed the separation from its successors."))))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Check the preconditions of every task of the taskset.
+pub(crate) fn check_preconditions(taskset: &[DRTTask]) -> Result<(), SchedError> {
+    taskset.iter().enumerate()
+        .try_for_each(|(i, task)| task.check_preconditions()
+            .map_err(|err| err.with_context(&format!("task {i}"))))
+}
+
+#[test]
+fn digraph_demand() {
+    let at = |t: u64| Time::nanos(t as f64);
+    let job = |wcet: u64, deadline: u64| JobType { wcet: at(wcet), deadline: at(deadline) };
+
+    // A light job which may repeat, or switch to a heavy job further apart.
+    let task = DRTTask {
+        jobs: vec![job(1, 5), job(3, 5)],
+        edges: vec![(0, 0, at(5)), (0, 1, at(10)), (1, 0, at(5))],
+    };
+
+    assert!(task.check_preconditions().is_ok());
+    assert_eq!(task.utilization(), 4.0 / 15.0);
+
+    assert_eq!(task.dbf(at(4)), at(0));
+    assert_eq!(task.dbf(at(5)), at(3));
+    assert_eq!(task.dbf(at(10)), at(4));
+    assert_eq!(task.dbf(at(15)), at(5));
+    assert_eq!(task.demand_tuples(at(15)), vec![(at(5), at(3)), (at(10), at(4)), (at(15), at(5))]);
+
+    // A GMF task is a single cycle.
+    let gmf = GMFTask { frames: vec![RTTask::new_ns(3, 5, 5), RTTask::new_ns(1, 4, 7)] };
+    let drt = DRTTask::from_gmf(&gmf);
+    assert_eq!(drt.utilization(), gmf.utilization());
+    for t in 0..40 {
+        assert_eq!(drt.dbf(at(t)), gmf.dbf(at(t)));
+    }
+
+    let invalid = DRTTask { jobs: vec![job(1, 6)], edges: vec![(0, 0, at(5))] };
+    assert!(invalid.check_preconditions().is_err());
+}
@@ -0,0 +1,129 @@
+//! ## DRT Tasks, EDF Demand Test - Stigge et al. 2011
+//!
+//! The taskset is schedulable by EDF if and only if the total demand bound
+//! function of the tasks never exceeds the length of the interval \[1\]. Since
+//! any path of a graph is a simple path plus a set of cycles, the demand of a
+//! task is bounded by *U·t* plus the total WCET of its job types, thus only the
+//! intervals shorter than *ΣC / (1 - U)* need to be checked, at the deadline
+//! spans of the demand tuples of the tasks.
+//!
+//! #### Model:
+//! - Digraph Real-Time Task model (refer to [`DRTTask`])
+//! - Fully-Preemptive EDF scheduling
+//!
+//! #### Preconditions:
+//! - Positive edge separations
+//! - Frame separation: job deadlines not greater than the separation from
+//!   their successors
+//!
+//! #### Implements:
+//! - [`Analysis::is_schedulable`] \
+//!   | pseudo-polynomial complexity
+//!
+//! ---
+//! #### References:
+//! 1. M. Stigge, P. Ekberg, N. Guan, and W. Yi, “The Digraph Real-Time Task
+//!    Model,” in 2011 17th IEEE Real-Time and Embedded Technology and
+//!    Applications Symposium, Apr. 2011, pp. 71–80. doi: 10.1109/RTAS.2011.15.
+
+use crate::prelude::*;
+use super::*;
+
+const ALGORITHM: &str = "DRT EDF Demand Test (Stigge et al. 2011)";
+
+/// DRT Tasks, EDF Demand Test - Stigge et al. 2011 \[1\]
+///
+/// Refer to the [module](`self`) level documentation.
+pub struct Analysis;
+
+impl SchedAnalysis<(), &[DRTTask]> for Analysis {
+    fn analyzer_name(&self) -> &str { ALGORITHM }
+
+    fn check_preconditions(&self, taskset: &&[DRTTask]) -> Result<(), SchedError> {
+        check_preconditions(taskset)
+    }
+
+    fn run_test(&self, taskset: &[DRTTask]) -> Result<(), SchedError> {
+        let utilization: f64 = taskset.iter().map(DRTTask::utilization).sum();
+
+        if utilization > 1.0 {
+            return Err(SchedError::NonSchedulable(Some(
+                anyhow::format_err!("the utilization of {utilization} exceeds the processor."))));
+        } else if utilization == 1.0 {
+            return Err(SchedError::intractable());
+        }
+
+        let total_wcet: Time = taskset.iter().map(DRTTask::total_wcet).sum();
+        let max_interval = total_wcet / (1.0 - utilization);
+
+        let tuples: Vec<_> = taskset.iter()
+            .map(|task| task.demand_tuples(max_interval))
+            .collect();
+
+        let mut points: Vec<_> = tuples.iter().flatten().map(|&(span, _)| span).collect();
+        points.sort();
+        points.dedup();
+
+        match points.into_iter().find(|&t| total_dbf(&tuples, t) > t) {
+            Some(t) => Err(SchedError::NonSchedulable(Some(
+                anyhow::format_err!("the demand in an interval of {}ns exceeds its length.", t.as_nanos())))),
+            None => Ok(()),
+        }
+    }
+}
+
+impl SchedProcessors for Analysis {
+    fn num_processors(&self) -> u64 { 1 }
+}
+
+impl SchedPlatform for Analysis {
+    fn supports(&self, platform: &Platform) -> bool {
+        platform.matches(PlatformModel::Uniprocessor, SchedulingAlgorithm::EarliestDeadlineFirst, 1)
+    }
+}
+
+// Sum of the demand bound functions, given by the demand tuples of each task.
+fn total_dbf(tuples: &[Vec<(Time, Time)>], interval: Time) -> Time {
+    tuples.iter()
+        .map(|tuples| {
+            tuples.iter()
+                .take_while(|(span, _)| *span <= interval)
+                .last()
+                .map(|&(_, execution)| execution)
+                .unwrap_or(Time::zero())
+        })
+        .sum()
+}
+
+#[test]
+fn drt_edf() {
+    use crate::algorithms::full_preemption::uniprocessor::gmf_model::baruah99;
+
+    let at = |t: u64| Time::nanos(t as f64);
+    let job = |wcet: u64, deadline: u64| JobType { wcet: at(wcet), deadline: at(deadline) };
+    let sporadic = |task: RTTask| DRTTask::from_gmf(&GMFTask::sporadic(task));
+
+    let modes = DRTTask {
+        jobs: vec![job(1, 5), job(3, 5)],
+        edges: vec![(0, 0, at(5)), (0, 1, at(10)), (1, 0, at(5))],
+    };
+
+    // The heavy job fits next to the sporadic task, but not a heavier one.
+    let mut taskset = vec![modes, sporadic(RTTask::new_ns(2, 5, 5))];
+    assert!(Analysis.is_schedulable(&taskset).is_ok());
+
+    taskset[1] = sporadic(RTTask::new_ns(3, 5, 5));
+    let err = Analysis.is_schedulable(&taskset).unwrap_err();
+    assert!(format!("{err:#}").contains("5ns"));
+
+    // Same verdicts as the GMF test on GMF tasks.
+    for wcet in 1..=4 {
+        let gmf_taskset = [
+            GMFTask { frames: vec![RTTask::new_ns(wcet, 5, 5), RTTask::new_ns(1, 5, 5)] },
+            GMFTask::sporadic(RTTask::new_ns(2, 4, 5)),
+        ];
+        let drt_taskset: Vec<_> = gmf_taskset.iter().map(DRTTask::from_gmf).collect();
+
+        assert_eq!(Analysis.is_schedulable(&drt_taskset).is_ok(), baruah99::Analysis.is_schedulable(&gmf_taskset).is_ok());
+    }
+}
@@ -0,0 +1,150 @@
+//! ## Processor Demand Criterion - Baruah, Rosier, Howell 1990
+//!
+//! The taskset is schedulable by EDF if and only if the total demand bound
+//! function of the tasks never exceeds the length of the interval \[1\]. The
+//! demand only increases at the absolute deadlines of the synchronous jobs,
+//! thus only those need to be checked, up to the smallest of two bounds:
+//! - the length of the synchronous busy period, when the processor is never
+//!   idle since all the tasks release a job at once \[2\];
+//! - if the utilization is less than one, the instant after which the demand
+//!   of the tasks cannot exceed the length of the interval anymore,
+//!   *max(D_max, sum((T_i - D_i) U_i) / (1 - U))* \[3\].
+//!
+//! #### Model:
+//! - Periodic/Sporadic Task model
+//! - Fully-Preemptive EDF scheduling
+//!
+//! #### Preconditions:
+//! - None, any deadline model
+//!
+//! #### Implements:
+//! - [`Analysis::is_schedulable`] \
+//!   | pseudo-polynomial complexity
+//!
+//! ---
+//! #### References:
+//! 1. S. K. Baruah, L. E. Rosier, and R. R. Howell, “Algorithms and complexity
+//!    concerning the preemptive scheduling of periodic, real-time tasks on one
+//!    processor,” Real-Time Systems, vol. 2, no. 4, pp. 301–324, Nov. 1990,
+//!    doi: 10.1007/BF01995675.
+//! 2. I. Ripoll, A. Crespo, and A. K. Mok, “Improvement in feasibility testing
+//!    for real-time tasks,” Real-Time Systems, vol. 11, no. 1, pp. 19–39, July
+//!    1996, doi: 10.1007/BF00365519.
+//! 3. F. Zhang and A. Burns, “Schedulability Analysis for Real-Time Systems
+//!    with EDF Scheduling,” IEEE Transactions on Computers, vol. 58, no. 9,
+//!    pp. 1250–1258, Sept. 2009, doi: 10.1109/TC.2009.58.
+
+use crate::prelude::*;
+use crate::utils::{analysis_points, dbf};
+
+const ALGORITHM: &str = "EDF Processor Demand Criterion (Baruah, Rosier, Howell 1990)";
+
+/// Processor Demand Criterion - Baruah, Rosier, Howell 1990 \[1\]
+///
+/// Refer to the [module](`self`) level documentation.
+pub struct Analysis;
+
+impl SchedAnalysis<(), &[RTTask]> for Analysis {
+    fn analyzer_name(&self) -> &str { ALGORITHM }
+
+    fn check_preconditions(&self, _: &&[RTTask]) -> Result<(), SchedError> {
+        Ok(())
+    }
+
+    fn run_test(&self, taskset: &[RTTask]) -> Result<(), SchedError> {
+        let utilization = RTUtils::total_utilization(taskset);
+
+        if utilization > 1.0 {
+            return Err(SchedError::NonSchedulable(Some(
+                anyhow::format_err!("the utilization of {utilization} exceeds the processor."))));
+        }
+
+        let max_interval = max_interval(taskset, utilization);
+
+        let deadlines = analysis_points::merge_points(taskset.iter()
+            .map(|task| analysis_points::periodic_points(task.period, Time::zero(), -task.deadline, max_interval)));
+
+        for interval in deadlines {
+            let demand = dbf::total_dbf(taskset, interval);
+
+            if demand > interval {
+                return Err(SchedError::NonSchedulable(Some(anyhow::format_err!(
+                    "the demand of {}ns in an interval of {}ns exceeds its length.",
+                    demand.as_nanos(), interval.as_nanos()))));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl SchedProcessors for Analysis {
+    fn num_processors(&self) -> u64 { 1 }
+}
+
+impl SchedPlatform for Analysis {
+    fn supports(&self, platform: &Platform) -> bool {
+        platform.matches(PlatformModel::Uniprocessor, SchedulingAlgorithm::EarliestDeadlineFirst, 1)
+    }
+}
+
+impl SchedBounds for Analysis {
+    // Exact test for an optimal algorithm [1]
+    fn speedup_factor(&self) -> Option<f64> { Some(1.0) }
+}
+
+/// Length of the longest interval whose demand must be checked, for
+/// utilizations up to one, refer to the [module](`self`) level documentation.
+pub fn max_interval(taskset: &[RTTask], utilization: f64) -> Time {
+    Time::min(busy_period(taskset), demand_bound(taskset, utilization))
+}
+
+// Length of the synchronous busy period [2], which ends within the
+// hyperperiod for utilizations up to one.
+fn busy_period(taskset: &[RTTask]) -> Time {
+    fixpoint_search_with_limit(
+        taskset.iter().map(|task| task.wcet).sum(),
+        RTUtils::hyperperiod(taskset),
+        |busy_period: &Time| dbf::total_rbf(taskset, *busy_period)
+    )
+}
+
+// Instant after which the demand cannot exceed the length of the interval
+// [3], unbounded for a full utilization.
+fn demand_bound(taskset: &[RTTask], utilization: f64) -> Time {
+    if utilization >= 1.0 {
+        return Time::nanos(f64::INFINITY);
+    }
+
+    let max_deadline = taskset.iter().map(|task| task.deadline).fold(Time::zero(), Time::max);
+    let slack: Time = taskset.iter()
+        .map(|task| (task.period - task.deadline) * task.utilization())
+        .sum();
+
+    Time::max(max_deadline, slack / (1.0 - utilization))
+}
+
+#[test]
+fn processor_demand() {
+    // Constrained deadlines: U = 0.9, but the demand at 3 is 2 + 2 > 3.
+    let taskset = [RTTask::new_ns(2, 3, 5), RTTask::new_ns(2, 3, 5)];
+    assert!(Analysis.is_schedulable(&taskset[..]).is_err());
+
+    let taskset = [RTTask::new_ns(2, 4, 5), RTTask::new_ns(2, 4, 5)];
+    assert!(Analysis.is_schedulable(&taskset[..]).is_ok());
+
+    // Implicit deadlines, equivalent to the utilization bound.
+    let taskset = [RTTask::new_ns(2, 4, 4), RTTask::new_ns(4, 8, 8)];
+    assert!(Analysis.is_schedulable(&taskset[..]).is_ok());
+    let taskset = [RTTask::new_ns(3, 4, 4), RTTask::new_ns(3, 8, 8)];
+    assert!(Analysis.is_schedulable(&taskset[..]).is_err());
+
+    // Arbitrary deadlines, with a full utilization in the last case: the
+    // demand at 9 is 4 + 2 + 4.
+    let taskset = [RTTask::new_ns(2, 4, 4), RTTask::new_ns(3, 10, 8)];
+    assert!(Analysis.is_schedulable(&taskset[..]).is_ok());
+    let taskset = [RTTask::new_ns(2, 3, 4), RTTask::new_ns(1, 2, 4), RTTask::new_ns(1, 10, 8)];
+    assert!(Analysis.is_schedulable(&taskset[..]).is_ok());
+    let taskset = [RTTask::new_ns(2, 3, 4), RTTask::new_ns(1, 2, 4), RTTask::new_ns(4, 9, 16)];
+    assert!(Analysis.is_schedulable(&taskset[..]).is_err());
+}
@@ -0,0 +1,117 @@
+//! ## EDF with Constant Bandwidth Servers - Abeni & Buttazzo 1998
+//!
+//! Hard real-time tasks share the processor with soft tasks, each of which is
+//! served by a Constant Bandwidth Server (CBS). A CBS with budget *Q* and
+//! period *T* never demands more than a periodic task (*Q*, *T*, *T*),
+//! whatever the behaviour of the soft tasks it serves, thus the hard tasks
+//! are analysed treating the servers as such tasks.
+//!
+//! #### Model:
+//! - Periodic/Sporadic Task model for the hard tasks
+//! - Constant Bandwidth Servers for the soft tasks
+//! - Fully-Preemptive EDF scheduling
+//!
+//! #### Preconditions:
+//! - Implicit Deadlines
+//!
+//! #### Implements:
+//! - [`Analysis::is_schedulable`] \
+//!   | Returns the residual bandwidth available for further soft work. \
+//!   | \
+//!   | linear *O(n)* complexity
+//!
+//! ---
+//! #### References:
+//! 1. L. Abeni and G. Buttazzo, “Integrating multimedia applications in hard
+//!    real-time systems,” in Proceedings 19th IEEE Real-Time Systems Symposium,
+//!    Dec. 1998, pp. 4–13. doi: 10.1109/REAL.1998.739726.
+
+use crate::prelude::*;
+
+const ALGORITHM: &str = "EDF with Constant Bandwidth Servers (Abeni & Buttazzo 1998)";
+
+/// Constant Bandwidth Server reserved for soft tasks.
+#[derive(Debug, Clone)]
+pub struct CBSServer {
+    pub budget: Time,
+    pub period: Time,
+}
+
+impl CBSServer {
+    pub fn bandwidth(&self) -> f64 {
+        self.budget / self.period
+    }
+
+    // Lemma 1 [1]: the server demands no more than a periodic task with its
+    // budget and period, i.e. its bandwidth.
+    pub fn as_task(&self) -> RTTask {
+        RTTask {
+            wcet: self.budget,
+            deadline: self.period,
+            period: self.period,
+        }
+    }
+}
+
+/// EDF with Constant Bandwidth Servers - Abeni & Buttazzo 1998 \[1\]
+///
+/// Refer to the [module](`self`) level documentation.
+///
+/// Returns:
+/// - Residual bandwidth left after the hard tasks and the servers.
+pub struct Analysis {
+    pub servers: Vec<CBSServer>,
+}
+
+impl SchedAnalysis<f64, &[RTTask]> for Analysis {
+    fn analyzer_name(&self) -> &str { ALGORITHM }
+
+    fn check_preconditions(&self, taskset: &&[RTTask]) -> Result<(), SchedError> {
+        if !RTUtils::implicit_deadlines(taskset) {
+            Err(SchedError::implicit_deadlines())
+        } else if self.servers.iter().any(|server| server.budget > server.period) {
+            Err(SchedError::Precondition(Some(
+                anyhow::format_err!("server budgets must not exceed their periods."))))
+        } else {
+            Ok(())
+        }
+    }
+
+    fn run_test(&self, taskset: &[RTTask]) -> Result<f64, SchedError> {
+        // Theorem 1 [1]: the hard tasks meet their deadlines if the total
+        // utilization of tasks and servers does not exceed one.
+        let total_bandwidth =
+            RTUtils::total_utilization(taskset)
+            + self.servers.iter().map(CBSServer::bandwidth).sum::<f64>();
+
+        if total_bandwidth <= 1.0 {
+            Ok(1.0 - total_bandwidth)
+        } else {
+            Err(SchedError::NonSchedulable(Some(
+                anyhow::format_err!("hard tasks and servers demand a bandwidth of {total_bandwidth}.")
+            )))
+        }
+    }
+}
+
+#[test]
+fn residual_bandwidth() {
+    let taskset = [
+        RTTask::new_ns(20, 100, 100),
+        RTTask::new_ns(50, 200, 200),
+    ];
+
+    let servers = vec![
+        CBSServer { budget: Time::nanos(10.0), period: Time::nanos(50.0) },
+    ];
+
+    let residual = Analysis { servers: servers.clone() }.is_schedulable(&taskset).unwrap();
+    assert!(f64::abs(residual - 0.35) < 1e-9);
+
+    let servers = vec![
+        servers[0].clone(),
+        CBSServer { budget: Time::nanos(40.0), period: Time::nanos(100.0) },
+    ];
+
+    assert!(Analysis { servers }.is_schedulable(&taskset).is_err());
+}
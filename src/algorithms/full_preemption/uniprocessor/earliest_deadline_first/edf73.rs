@@ -10,6 +10,10 @@
 //! #### Implements:
 //! - [`Analysis::is_schedulable`] \
 //!   | linear *O(n)* complexity
+//! - [`AnalysisStreaming::is_schedulable`] \
+//!   | Read the tasks one at a time, without storing the taskset. \
+//!   | \
+//!   | linear *O(n)* complexity, constant memory
 //!
 //! ---
 //! #### References:
@@ -18,6 +22,7 @@
 //!    Jan. 1973, doi: 10.1145/321738.321743.
 
 use crate::prelude::*;
+use crate::utils::numeric_validation::{check_inequality, compensated_sum};
 
 const ALGORITHM: &str = "Earliest Deadline First (Liu & Layland 1973)";
 
@@ -44,4 +49,78 @@ impl SchedAnalysis<(), &[RTTask]> for Analysis {
             total_utilization <= 1f64
         )
     }
-}
\ No newline at end of file
+}
+
+impl SchedProcessors for Analysis {
+    fn num_processors(&self) -> u64 { 1 }
+}
+
+impl SchedPlatform for Analysis {
+    fn supports(&self, platform: &Platform) -> bool {
+        platform.matches(PlatformModel::Uniprocessor, SchedulingAlgorithm::EarliestDeadlineFirst, 1)
+    }
+}
+
+impl SchedValidation for Analysis {
+    fn validate(&self, taskset: &[RTTask]) -> Vec<NumericWarning> {
+        let careful_utilization = compensated_sum(taskset.iter().map(|task| task.utilization()));
+
+        check_inequality("U <= 1", RTUtils::total_utilization(taskset), careful_utilization, 1.0)
+            .into_iter().collect()
+    }
+}
+
+impl SchedBounds for Analysis {
+    // EDF is optimal on uniprocessors [1]
+    fn speedup_factor(&self) -> Option<f64> { Some(1.0) }
+
+    fn utilization_bound(&self, _: &[RTTask]) -> Option<f64> { Some(1.0) }
+}
+
+/// Earliest Deadline First, Liu & Layland 1973 \[1\] \
+/// Streaming version, for tasksets too large to be stored in memory.
+///
+/// Refer to the [module](`self`) level documentation.
+///
+/// The preconditions are checked while reading the tasks, and errors in the
+/// input stream are reported as [`SchedError::Other`].
+pub struct AnalysisStreaming;
+
+impl<I> SchedAnalysis<(), I> for AnalysisStreaming
+    where
+        I: Iterator<Item = anyhow::Result<RTTask>>,
+{
+    fn analyzer_name(&self) -> &str { ALGORITHM }
+
+    fn check_preconditions(&self, _: &I) -> Result<(), SchedError> {
+        Ok(())
+    }
+
+    fn run_test(&self, tasks: I) -> Result<(), SchedError> {
+        let mut total_utilization = 0f64;
+
+        for task in tasks {
+            let task = task.map_err(SchedError::Other)?;
+
+            if task.deadline != task.period {
+                return Err(SchedError::implicit_deadlines());
+            }
+
+            total_utilization += task.utilization();
+        }
+
+        SchedError::result_from_schedulable(
+            total_utilization <= 1f64
+        )
+    }
+}
+
+#[test]
+fn validation() {
+    let taskset = [RTTask::new_ns(50, 100, 100), RTTask::new_ns(40, 100, 100)];
+    assert!(Analysis.validate(&taskset).is_empty());
+
+    // 0.1 + 0.2 + 0.7 is not exactly one in floating point.
+    let taskset = [RTTask::new_ns(10, 100, 100), RTTask::new_ns(20, 100, 100), RTTask::new_ns(70, 100, 100)];
+    assert_eq!(Analysis.validate(&taskset).len(), 1);
+}
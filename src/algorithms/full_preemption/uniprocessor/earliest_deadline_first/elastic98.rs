@@ -0,0 +1,203 @@
+//! ## Elastic Task Model - Buttazzo, Lipari, Abeni 1998
+//!
+//! Each task behaves as a spring: its utilization can be compressed, by
+//! enlarging its period from the nominal one up to a maximum period, in
+//! proportion to its elastic coefficient \[1\]. On overload, the tasks are
+//! compressed until the total utilization fits the desired one: tasks whose
+//! utilization would fall below the minimum are fixed at their maximum period,
+//! and the remaining compression is spread among the other tasks \[2\]. Tasks
+//! with a null elastic coefficient are not compressed.
+//!
+//! #### Model:
+//! - Elastic Periodic/Sporadic Task model (refer to [`ElasticTask`])
+//! - Fully-Preemptive EDF scheduling
+//!
+//! #### Preconditions:
+//! - Implicit Deadlines
+//! - Nominal period not greater than the maximum period
+//! - Non-negative elastic coefficients
+//! - Desired utilization in *(0, 1]*
+//!
+//! #### Implements:
+//! - [`Designer::design`] \
+//!   | Compressed periods, whose total utilization does not exceed the desired
+//!   | one. \
+//!   | \
+//!   | quadratic *O(n^2)* complexity
+//!
+//! ---
+//! #### References:
+//! 1. G. C. Buttazzo, G. Lipari, and L. Abeni, “Elastic task model for
+//!    adaptive rate control,” in Proceedings 19th IEEE Real-Time Systems
+//!    Symposium, Dec. 1998, pp. 286–295. doi: 10.1109/REAL.1998.739754.
+//! 2. G. C. Buttazzo, G. Lipari, M. Caccamo, and L. Abeni, “Elastic scheduling
+//!    for flexible workload management,” IEEE Transactions on Computers,
+//!    vol. 51, no. 3, pp. 289–302, Mar. 2002, doi: 10.1109/12.990127.
+
+use crate::prelude::*;
+
+const ALGORITHM: &str = "Elastic Compression (Buttazzo, Lipari, Abeni 1998)";
+
+/// Elastic task
+///
+/// Refer to the [module](`self`) level documentation.
+#[derive(Debug, Clone)]
+pub struct ElasticTask {
+    /// Task with its nominal period.
+    pub task: RTTask,
+    pub max_period: Time,
+    /// Elastic coefficient, the larger the more the task is compressed.
+    pub elasticity: f64,
+}
+
+impl ElasticTask {
+    /// Utilization at the maximum period.
+    pub fn min_utilization(&self) -> f64 {
+        self.task.wcet / self.max_period
+    }
+}
+
+/// Elastic Compression - Buttazzo, Lipari, Abeni 1998 \[1\]
+///
+/// Refer to the [module](`self`) level documentation.
+///
+/// Returns:
+/// - The period of each task, such that the taskset is schedulable by EDF.
+pub struct Designer {
+    /// Desired total utilization, at most 1.
+    pub max_utilization: f64,
+}
+
+impl SchedDesign<&[ElasticTask], Vec<Time>> for Designer {
+    fn designer_name(&self) -> &str { ALGORITHM }
+
+    fn check_preconditions(&self, taskset: &&[ElasticTask]) -> Result<(), SchedError> {
+        if taskset.iter().any(|task| task.task.deadline != task.task.period) {
+            Err(SchedError::implicit_deadlines())
+        } else if taskset.iter().any(|task| task.task.period > task.max_period) {
+            Err(SchedError::Precondition(Some(
+                anyhow::format_err!("the nominal period of a task must not exceed its maximum period."))))
+        } else if taskset.iter().any(|task| task.elasticity.is_nan() || task.elasticity < 0.0) {
+            Err(SchedError::Precondition(Some(
+                anyhow::format_err!("the elastic coefficients must be non-negative."))))
+        } else if !(self.max_utilization > 0.0 && self.max_utilization <= 1.0) {
+            Err(SchedError::Precondition(Some(
+                anyhow::format_err!("the desired utilization must be in (0, 1]."))))
+        } else {
+            Ok(())
+        }
+    }
+
+    fn run_designer(&self, taskset: &[ElasticTask]) -> Result<Vec<Time>, SchedError> {
+        compress(taskset, self.max_utilization)
+            .map(|utilizations| {
+                taskset.iter().zip(utilizations)
+                    .map(|(task, utilization)| {
+                        if utilization <= task.min_utilization() {
+                            task.max_period
+                        } else {
+                            // Rounded up, not to exceed the desired utilization.
+                            (task.task.wcet / utilization).ceil().min(task.max_period)
+                        }
+                    })
+                    .collect()
+            })
+    }
+}
+
+// Section 4 [2]: the compressed utilization of each task, fixing at their
+// minimum utilization the tasks which would be compressed below it.
+fn compress(taskset: &[ElasticTask], max_utilization: f64) -> Result<Vec<f64>, SchedError> {
+    let nominal: Vec<f64> = taskset.iter().map(|task| task.task.utilization()).collect();
+
+    if nominal.iter().sum::<f64>() <= max_utilization {
+        return Ok(nominal);
+    }
+
+    let inelastic: f64 = taskset.iter().zip(&nominal)
+        .filter(|(task, _)| task.elasticity == 0.0)
+        .map(|(_, utilization)| utilization)
+        .sum();
+    let minimum: f64 = taskset.iter()
+        .map(|task| if task.elasticity == 0.0 { task.task.utilization() } else { task.min_utilization() })
+        .sum();
+
+    if minimum > max_utilization {
+        return Err(SchedError::NonSchedulable(Some(anyhow::format_err!(
+            "the minimum utilization of {minimum} exceeds the desired one of {max_utilization}."))));
+    }
+
+    let mut fixed: Vec<bool> = taskset.iter().map(|task| task.elasticity == 0.0).collect();
+    loop {
+        let fixed_utilization: f64 = inelastic + taskset.iter().zip(&fixed)
+            .filter(|(task, fixed)| **fixed && task.elasticity > 0.0)
+            .map(|(task, _)| task.min_utilization())
+            .sum::<f64>();
+        let (variable_utilization, variable_elasticity) = taskset.iter().zip(&nominal).zip(&fixed)
+            .filter(|(_, fixed)| !**fixed)
+            .fold((0.0, 0.0), |(utilization, elasticity), ((task, nominal), _)|
+                (utilization + nominal, elasticity + task.elasticity));
+
+        let excess = variable_utilization + fixed_utilization - max_utilization;
+        let utilizations: Vec<f64> = taskset.iter().zip(&nominal).zip(&fixed)
+            .map(|((task, nominal), fixed)| {
+                if !*fixed {
+                    nominal - excess * task.elasticity / variable_elasticity
+                } else if task.elasticity == 0.0 {
+                    *nominal
+                } else {
+                    task.min_utilization()
+                }
+            })
+            .collect();
+
+        // Fix the tasks compressed below their minimum, and retry.
+        let mut feasible = true;
+        for (i, task) in taskset.iter().enumerate() {
+            if !fixed[i] && utilizations[i] < task.min_utilization() {
+                fixed[i] = true;
+                feasible = false;
+            }
+        }
+
+        if feasible {
+            return Ok(utilizations);
+        }
+    }
+}
+
+#[test]
+fn elastic_compression() {
+    let at = |t: u64| Time::micros(t as f64);
+    let elastic = |wcet: u64, period: u64, max_period: u64, elasticity: f64| ElasticTask {
+        task: RTTask { wcet: at(wcet), deadline: at(period), period: at(period) },
+        max_period: at(max_period),
+        elasticity,
+    };
+
+    // Not overloaded, the nominal periods are kept.
+    let taskset = [elastic(1, 4, 8, 1.0), elastic(1, 4, 8, 1.0)];
+    assert_eq!(Designer { max_utilization: 1.0 }.design(&taskset).unwrap(), vec![at(4), at(4)]);
+
+    // U = 1.25, the excess is shared evenly: each utilization decreases by 1/12.
+    let taskset = [elastic(3, 4, 20, 1.0), elastic(3, 8, 20, 1.0), elastic(1, 8, 20, 1.0)];
+    let periods = Designer { max_utilization: 1.0 }.design(&taskset).unwrap();
+    let utilization: f64 = taskset.iter().zip(&periods).map(|(task, period)| task.task.wcet / *period).sum();
+    assert!(utilization <= 1.0);
+
+    // The third task would fall below its minimum utilization of 1/10, thus it
+    // is fixed at its maximum period and the others absorb the rest.
+    let taskset = [elastic(3, 4, 20, 1.0), elastic(3, 8, 20, 1.0), elastic(1, 8, 10, 1.0)];
+    let periods = Designer { max_utilization: 1.0 }.design(&taskset).unwrap();
+    assert_eq!(periods[2], at(10));
+    let utilization: f64 = taskset.iter().zip(&periods).map(|(task, period)| task.task.wcet / *period).sum();
+    assert!(utilization <= 1.0 && utilization > 0.99);
+
+    // Inelastic tasks are not compressed.
+    let taskset = [elastic(2, 4, 4, 0.0), elastic(2, 4, 8, 1.0)];
+    assert_eq!(Designer { max_utilization: 1.0 }.design(&taskset).unwrap(), vec![at(4), at(4)]);
+    let taskset = [elastic(3, 4, 4, 0.0), elastic(2, 4, 8, 1.0)];
+    assert_eq!(Designer { max_utilization: 1.0 }.design(&taskset).unwrap(), vec![at(4), at(8)]);
+    let taskset = [elastic(3, 4, 4, 0.0), elastic(2, 4, 6, 1.0)];
+    assert!(Designer { max_utilization: 1.0 }.design(&taskset).is_err());
+}
@@ -0,0 +1,136 @@
+//! ## Quick Processor-demand Analysis - Zhang & Burns 2009
+//!
+//! Exact EDF test, equivalent to the [Processor Demand Criterion](super::brh90)
+//! but checking far fewer intervals \[1\]. Rather than checking the demand at
+//! every deadline up to the bound of the interval length, the search starts
+//! from the last deadline within the bound and moves backwards:
+//! - if the demand *h(t)* exceeds *t*, the taskset is not schedulable;
+//! - if *h(t) < t*, no interval in *\[h(t), t)* can overflow, and the search
+//!   jumps to *h(t)*;
+//! - if *h(t) = t*, the search moves to the previous deadline;
+//!
+//! until the demand is at most the shortest relative deadline, when the
+//! taskset is schedulable. Since the demand decreases quickly when the
+//! utilization is high, the search converges in few steps exactly for the
+//! tasksets whose bound is the longest.
+//!
+//! #### Model:
+//! - Periodic/Sporadic Task model
+//! - Fully-Preemptive EDF scheduling
+//!
+//! #### Preconditions:
+//! - None, any deadline model
+//!
+//! #### Implements:
+//! - [`Analysis::is_schedulable`] \
+//!   | pseudo-polynomial complexity
+//!
+//! ---
+//! #### References:
+//! 1. F. Zhang and A. Burns, “Schedulability Analysis for Real-Time Systems
+//!    with EDF Scheduling,” IEEE Transactions on Computers, vol. 58, no. 9,
+//!    pp. 1250–1258, Sept. 2009, doi: 10.1109/TC.2009.58.
+
+use crate::prelude::*;
+use crate::utils::dbf;
+use super::brh90;
+
+const ALGORITHM: &str = "EDF Quick Processor-demand Analysis (Zhang & Burns 2009)";
+
+/// Quick Processor-demand Analysis - Zhang & Burns 2009 \[1\]
+///
+/// Refer to the [module](`self`) level documentation.
+pub struct Analysis;
+
+impl SchedAnalysis<(), &[RTTask]> for Analysis {
+    fn analyzer_name(&self) -> &str { ALGORITHM }
+
+    fn check_preconditions(&self, _: &&[RTTask]) -> Result<(), SchedError> {
+        Ok(())
+    }
+
+    fn run_test(&self, taskset: &[RTTask]) -> Result<(), SchedError> {
+        let utilization = RTUtils::total_utilization(taskset);
+
+        if utilization > 1.0 {
+            return Err(SchedError::NonSchedulable(Some(
+                anyhow::format_err!("the utilization of {utilization} exceeds the processor."))));
+        }
+
+        let min_deadline = taskset.iter().map(|task| task.deadline).fold(Time::nanos(f64::INFINITY), Time::min);
+        let mut interval = last_deadline(taskset, brh90::max_interval(taskset, utilization), true);
+
+        while let Some(length) = interval {
+            let demand = dbf::total_dbf(taskset, length);
+
+            if demand > length {
+                return Err(SchedError::NonSchedulable(Some(anyhow::format_err!(
+                    "the demand of {}ns in an interval of {}ns exceeds its length.",
+                    demand.as_nanos(), length.as_nanos()))));
+            } else if demand <= min_deadline {
+                break;
+            }
+
+            interval =
+                if demand < length { Some(demand) }
+                else { last_deadline(taskset, length, false) };
+        }
+
+        Ok(())
+    }
+}
+
+impl SchedProcessors for Analysis {
+    fn num_processors(&self) -> u64 { 1 }
+}
+
+impl SchedPlatform for Analysis {
+    fn supports(&self, platform: &Platform) -> bool {
+        platform.matches(PlatformModel::Uniprocessor, SchedulingAlgorithm::EarliestDeadlineFirst, 1)
+    }
+}
+
+impl SchedBounds for Analysis {
+    // Exact test for an optimal algorithm [1]
+    fn speedup_factor(&self) -> Option<f64> { Some(1.0) }
+}
+
+// Last absolute deadline of the synchronous jobs before the given instant, or
+// up to it if inclusive.
+fn last_deadline(taskset: &[RTTask], time: Time, inclusive: bool) -> Option<Time> {
+    taskset.iter()
+        .filter(|task| task.deadline < time || (inclusive && task.deadline == time))
+        .map(|task| {
+            let jobs = (time - task.deadline) / task.period;
+            let job = if inclusive { jobs.floor() } else { jobs.ceil() - 1.0 };
+
+            task.deadline + task.period * job
+        })
+        .max()
+}
+
+#[test]
+fn quick_processor_demand() {
+    let tasksets = [
+        vec![RTTask::new_ns(2, 3, 5), RTTask::new_ns(2, 3, 5)],
+        vec![RTTask::new_ns(2, 4, 5), RTTask::new_ns(2, 4, 5)],
+        vec![RTTask::new_ns(2, 4, 4), RTTask::new_ns(4, 8, 8)],
+        vec![RTTask::new_ns(3, 4, 4), RTTask::new_ns(3, 8, 8)],
+        vec![RTTask::new_ns(2, 4, 4), RTTask::new_ns(3, 10, 8)],
+        vec![RTTask::new_ns(2, 3, 4), RTTask::new_ns(1, 2, 4), RTTask::new_ns(1, 10, 8)],
+        vec![RTTask::new_ns(2, 3, 4), RTTask::new_ns(1, 2, 4), RTTask::new_ns(4, 9, 16)],
+    ];
+
+    for taskset in tasksets.iter() {
+        assert_eq!(Analysis.is_schedulable(taskset).is_ok(), brh90::Analysis.is_schedulable(taskset).is_ok());
+    }
+
+    // Same verdict as the processor demand criterion, on every taskset of
+    // two harmonic tasks with small parameters.
+    for (c1, d1, c2, d2) in itertools::iproduct!(1..=4, 1..=8, 1..=8, 1..=24) {
+        let taskset = [RTTask::new_ns(c1, d1, 4), RTTask::new_ns(c2, d2, 16)];
+
+        assert_eq!(Analysis.is_schedulable(&taskset[..]).is_ok(), brh90::Analysis.is_schedulable(&taskset[..]).is_ok(),
+            "{taskset:?}");
+    }
+}
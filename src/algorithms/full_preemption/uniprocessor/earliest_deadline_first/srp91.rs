@@ -0,0 +1,298 @@
+//! ## Stack Resource Policy - Baker 1991
+//!
+//! Under the Stack Resource Policy, each task has a static preemption level,
+//! which for EDF decreases with the relative deadline, and each resource a
+//! ceiling, the highest preemption level of the tasks which use it. A job
+//! starts executing only when its preemption level is higher than the ceilings
+//! of all the resources locked by the other jobs, and then is never blocked
+//! \[1\]. Thus, a job is blocked at most once, before it starts, for the
+//! duration of a single critical section of a task with a lower preemption
+//! level on a resource whose ceiling is at least its preemption level:
+//!   B_i = max { |z_j,r| : π_j < π_i, ceiling(r) >= π_i }
+//!
+//! The taskset is schedulable by EDF if, with the tasks sorted by relative
+//! deadline \[1\]:
+//!   for all k: sum_{i <= k} C_i / D_i + B_k / D_k <= 1
+//!
+//! Rather than the densities, the demand test accounts in each interval of
+//! length *t* the longest critical section of the tasks whose relative
+//! deadline is longer than *t* on a resource whose ceiling is the preemption
+//! level of a task whose deadline is at most *t* \[2\]:
+//!   for all t: dbf(t) + B(t) <= t
+//! checked at the absolute deadlines of the synchronous jobs, up to the bound
+//! of the [Processor Demand Criterion](super::brh90) or the longest relative
+//! deadline, after which no job is blocked anymore.
+//!
+//! #### Model:
+//! - Periodic/Sporadic Task model
+//! - Fully-Preemptive EDF scheduling
+//! - Shared resources accessed in non-nested critical sections, refer to
+//!   [`resources`](crate::utils::resources)
+//!
+//! #### Preconditions:
+//! - Constrained Deadlines, for [`Analysis`]
+//! - Critical sections within the WCETs of the tasks
+//!
+//! #### Implements:
+//! - [`Analysis::is_schedulable`] \
+//!   | *O(n log n + s)* complexity, for *s* critical sections in total
+//! - [`AnalysisDemand::is_schedulable`] \
+//!   | Demand test, for any deadline model. \
+//!   | \
+//!   | pseudo-polynomial complexity
+//! - [`preemption_levels`] \
+//!   | Preemption level of each task. \
+//!   | \
+//!   | *O(n log n)* complexity
+//! - [`blocking_times`] \
+//!   | Blocking time of each task under the protocol. \
+//!   | \
+//!   | *O(n \* s)* complexity
+//!
+//! ---
+//! #### References:
+//! 1. T. P. Baker, “Stack-based scheduling of realtime processes,” Real-Time
+//!    Systems, vol. 3, no. 1, pp. 67–99, Mar. 1991, doi: 10.1007/BF00365393.
+//! 2. S. Baruah, “Resource Sharing in EDF-Scheduled Systems: A Closer Look,”
+//!    in 2006 27th IEEE International Real-Time Systems Symposium (RTSS'06),
+//!    Dec. 2006, pp. 379–387. doi: 10.1109/RTSS.2006.41.
+
+use crate::prelude::*;
+use crate::utils::resources::CriticalSections;
+use crate::utils::{analysis_points, dbf};
+use super::brh90;
+
+const ALGORITHM: &str = "EDF Stack Resource Policy (Baker 1991)";
+const ALGORITHM_DEMAND: &str = "EDF Stack Resource Policy, demand test (Baker 1991, Baruah 2006)";
+
+/// Stack Resource Policy - Baker 1991 \[1\]
+///
+/// Refer to the [module](`self`) level documentation.
+pub struct Analysis {
+    pub critical_sections: CriticalSections,
+}
+
+impl SchedAnalysis<(), &[RTTask]> for Analysis {
+    fn analyzer_name(&self) -> &str { ALGORITHM }
+
+    fn check_preconditions(&self, taskset: &&[RTTask]) -> Result<(), SchedError> {
+        if !RTUtils::constrained_deadlines(taskset) {
+            return Err(SchedError::constrained_deadlines());
+        }
+
+        self.critical_sections.check_preconditions(taskset)
+    }
+
+    fn run_test(&self, taskset: &[RTTask]) -> Result<(), SchedError> {
+        let blocking = blocking_times(taskset, &self.critical_sections);
+
+        let mut order: Vec<_> = (0..taskset.len()).collect();
+        order.sort_by_key(|&i| taskset[i].deadline);
+
+        // Tasks with the same deadline share their preemption level, and are
+        // accounted together.
+        let mut density = 0.0;
+        for group in order.chunk_by(|&i, &j| taskset[i].deadline == taskset[j].deadline) {
+            density += group.iter().map(|&i| taskset[i].density()).sum::<f64>();
+
+            for &k in group {
+                let load = density + blocking[k] / taskset[k].deadline;
+
+                if load > 1.0 {
+                    return Err(SchedError::NonSchedulable(Some(anyhow::format_err!(
+                        "the load of {load} at the preemption level of task {k} exceeds the processor."))));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl SchedProcessors for Analysis {
+    fn num_processors(&self) -> u64 { 1 }
+}
+
+impl SchedPlatform for Analysis {
+    fn supports(&self, platform: &Platform) -> bool {
+        platform.matches(PlatformModel::Uniprocessor, SchedulingAlgorithm::EarliestDeadlineFirst, 1)
+    }
+}
+
+/// Stack Resource Policy, demand test - Baker 1991 \[1\], Baruah 2006 \[2\]
+///
+/// Refer to the [module](`self`) level documentation.
+pub struct AnalysisDemand {
+    pub critical_sections: CriticalSections,
+}
+
+impl SchedAnalysis<(), &[RTTask]> for AnalysisDemand {
+    fn analyzer_name(&self) -> &str { ALGORITHM_DEMAND }
+
+    fn check_preconditions(&self, taskset: &&[RTTask]) -> Result<(), SchedError> {
+        self.critical_sections.check_preconditions(taskset)
+    }
+
+    fn run_test(&self, taskset: &[RTTask]) -> Result<(), SchedError> {
+        let utilization = RTUtils::total_utilization(taskset);
+
+        if utilization > 1.0 {
+            return Err(SchedError::NonSchedulable(Some(
+                anyhow::format_err!("the utilization of {utilization} exceeds the processor."))));
+        }
+
+        let max_deadline = taskset.iter().map(|task| task.deadline).fold(Time::zero(), Time::max);
+        let max_interval = Time::max(brh90::max_interval(taskset, utilization), max_deadline);
+
+        let deadlines = analysis_points::merge_points(taskset.iter()
+            .map(|task| analysis_points::periodic_points(task.period, Time::zero(), -task.deadline, max_interval)));
+
+        for interval in deadlines {
+            let demand = dbf::total_dbf(taskset, interval) + blocking_in_interval(taskset, &self.critical_sections, interval);
+
+            if demand > interval {
+                return Err(SchedError::NonSchedulable(Some(anyhow::format_err!(
+                    "the demand of {}ns, with blocking, in an interval of {}ns exceeds its length.",
+                    demand.as_nanos(), interval.as_nanos()))));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl SchedProcessors for AnalysisDemand {
+    fn num_processors(&self) -> u64 { 1 }
+}
+
+impl SchedPlatform for AnalysisDemand {
+    fn supports(&self, platform: &Platform) -> bool {
+        platform.matches(PlatformModel::Uniprocessor, SchedulingAlgorithm::EarliestDeadlineFirst, 1)
+    }
+}
+
+/// Preemption level of each task, in the order of the taskset: the number of
+/// tasks with a longer relative deadline, so that the tasks with the shortest
+/// deadline have the highest level, and tasks with the same deadline share it.
+pub fn preemption_levels(taskset: &[RTTask]) -> Vec<usize> {
+    let mut deadlines: Vec<_> = taskset.iter().map(|task| task.deadline).collect();
+    deadlines.sort();
+
+    taskset.iter()
+        .map(|task| deadlines.len() - deadlines.partition_point(|&deadline| deadline <= task.deadline))
+        .collect()
+}
+
+/// Blocking time of each task under the Stack Resource Policy, in the order
+/// of the taskset, refer to the [module](`self`) level documentation.
+pub fn blocking_times(taskset: &[RTTask], critical_sections: &CriticalSections) -> Vec<Time> {
+    let levels = preemption_levels(taskset);
+    let ceilings = ceilings(critical_sections, |task| levels[task]);
+
+    (0..taskset.len())
+        .map(|i| {
+            critical_sections.sections.iter().enumerate()
+                .filter(|&(j, _)| levels[j] < levels[i])
+                .flat_map(|(_, sections)| sections)
+                .filter(|section| ceilings[&section.resource] >= levels[i])
+                .map(|section| section.duration)
+                .max()
+                .unwrap_or(Time::zero())
+        })
+        .collect()
+}
+
+// Longest critical section of the tasks whose deadline is longer than the
+// interval, on the resources used by a task whose deadline is within it [2].
+fn blocking_in_interval(taskset: &[RTTask], critical_sections: &CriticalSections, interval: Time) -> Time {
+    // The ceilings as the shortest deadline of the tasks using the resources.
+    let ceilings = ceilings(critical_sections, |task| std::cmp::Reverse(taskset[task].deadline));
+
+    critical_sections.sections.iter().enumerate()
+        .filter(|&(j, _)| taskset[j].deadline > interval)
+        .flat_map(|(_, sections)| sections)
+        .filter(|section| ceilings[&section.resource].0 <= interval)
+        .map(|section| section.duration)
+        .max()
+        .unwrap_or(Time::zero())
+}
+
+// Highest preemption level of the tasks using each resource.
+fn ceilings<L: Ord>(critical_sections: &CriticalSections, level: impl Fn(usize) -> L) -> std::collections::HashMap<usize, L> {
+    let mut ceilings = std::collections::HashMap::new();
+    for (task, sections) in critical_sections.sections.iter().enumerate() {
+        for section in sections {
+            let level = level(task);
+
+            match ceilings.get(&section.resource) {
+                Some(ceiling) if *ceiling >= level => {},
+                _ => { ceilings.insert(section.resource, level); },
+            }
+        }
+    }
+
+    ceilings
+}
+
+#[test]
+fn stack_resource_policy() {
+    use crate::utils::resources::CriticalSection;
+
+    let section = |resource, duration| CriticalSection { resource, duration: Time::nanos(duration) };
+
+    // Not sorted by deadline: the preemption levels are 1, 2 and 0.
+    let taskset = [
+        RTTask::new_ns(3, 15, 15),
+        RTTask::new_ns(2, 10, 10),
+        RTTask::new_ns(4, 30, 30),
+    ];
+    assert_eq!(preemption_levels(&taskset), vec![1, 2, 0]);
+    assert_eq!(preemption_levels(&[RTTask::new_ns(1, 5, 5), RTTask::new_ns(1, 5, 5)]), vec![0, 0]);
+
+    // The ceilings of resources 0, 1 and 2 are the levels 2, 1 and 1.
+    let critical_sections = CriticalSections { sections: vec![
+        vec![section(1, 2.0), section(2, 1.0)],
+        vec![section(0, 1.0)],
+        vec![section(0, 2.0), section(1, 1.0), section(2, 3.0)],
+    ] };
+
+    // Task 1 is only blocked on resource 0, while task 0 is blocked on any of
+    // them, once. The task with the longest deadline is never blocked.
+    assert_eq!(blocking_times(&taskset, &critical_sections), vec![Time::nanos(3.0), Time::nanos(2.0), Time::zero()]);
+
+    // 2/10 + 2/10 = 0.4, 2/10 + 3/15 + 3/15 = 0.6, 2/10 + 3/15 + 4/30 <= 1
+    let analysis = Analysis { critical_sections: critical_sections.clone() };
+    assert!(analysis.is_schedulable(&taskset[..]).is_ok());
+
+    // Demand at 10: 2 + 2, and at 15: 2 + 3 + 3.
+    let demand = AnalysisDemand { critical_sections: critical_sections.clone() };
+    assert_eq!(blocking_in_interval(&taskset, &critical_sections, Time::nanos(10.0)), Time::nanos(2.0));
+    assert_eq!(blocking_in_interval(&taskset, &critical_sections, Time::nanos(15.0)), Time::nanos(3.0));
+    assert_eq!(blocking_in_interval(&taskset, &critical_sections, Time::nanos(30.0)), Time::zero());
+    assert!(demand.is_schedulable(&taskset[..]).is_ok());
+
+    // Task 1 misses its deadline if blocked for 9 by task 2: 2/10 + 9/10 > 1,
+    // and the demand at 10 is 2 + 9.
+    let heavy = [RTTask::new_ns(3, 15, 15), RTTask::new_ns(2, 10, 10), RTTask::new_ns(9, 30, 30)];
+    let mut critical_sections = critical_sections;
+    critical_sections.sections[2][0].duration = Time::nanos(9.0);
+    assert!(Analysis { critical_sections: critical_sections.clone() }.is_schedulable(&heavy[..]).is_err());
+    assert!(AnalysisDemand { critical_sections: critical_sections.clone() }.is_schedulable(&heavy[..]).is_err());
+
+    // The demand test is less pessimistic: the load at the preemption level
+    // of task 2 is 2/4 + 2/6 + 1.5/6 > 1, while the demand at 6 is 2 + 2 + 1.5
+    // and at 10 is 2 + 2 + 3.
+    let taskset = [RTTask::new_ns(2, 4, 20), RTTask::new_ns(3, 10, 10), RTTask::new_ns(2, 6, 20)];
+    let critical_sections = CriticalSections { sections: vec![
+        vec![],
+        vec![section(0, 1.5)],
+        vec![section(0, 1.5)],
+    ] };
+    assert!(Analysis { critical_sections: critical_sections.clone() }.is_schedulable(&taskset[..]).is_err());
+    assert!(AnalysisDemand { critical_sections: critical_sections.clone() }.is_schedulable(&taskset[..]).is_ok());
+
+    // Without critical sections, the plain Processor Demand Criterion.
+    let taskset = [RTTask::new_ns(2, 3, 5), RTTask::new_ns(2, 3, 5)];
+    assert!(AnalysisDemand { critical_sections: CriticalSections::none(2) }.is_schedulable(&taskset[..]).is_err());
+    assert!(brh90::Analysis.is_schedulable(&taskset[..]).is_err());
+}
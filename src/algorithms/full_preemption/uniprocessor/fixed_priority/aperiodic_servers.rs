@@ -0,0 +1,299 @@
+//! ## Aperiodic Jobs under Fixed Priority Servers
+//!
+//! Hard periodic tasks share the processor with aperiodic jobs, which are
+//! served in FIFO order by one of the following policies:
+//! - **Background**: the aperiodic jobs run only when no periodic task is
+//!   ready, i.e. at a priority lower than any periodic task.
+//! - **Polling Server** \[1\]: a periodic task with budget *Cs* and period
+//!   *Ps*, which serves the pending aperiodic jobs at each activation and
+//!   loses its budget when there are none.
+//! - **Deferrable Server** \[2\]: the budget is preserved until the end of
+//!   the period. The server may thus execute back to back across the end of a
+//!   period, and interferes with the lower priority tasks as a periodic task
+//!   with release jitter *Ps - Cs*.
+//! - **Sporadic Server** \[3\]: the consumed budget is replenished one period
+//!   after it started being consumed. The server interferes as a periodic task.
+//!
+//! The periodic tasks must remain schedulable with the server at its priority,
+//! after which the response times of the aperiodic jobs are bounded as
+//! follows. Jobs are grouped in sequences, each starting with a job which
+//! arrives after the previous jobs have completed, and the demand *W* of the
+//! jobs of a sequence up to the analyzed one is assumed to arrive at the start
+//! *b* of the sequence.
+//! - Under background scheduling, the jobs are served as the lowest priority
+//!   task, thus they complete within the least fixpoint of *t = W +
+//!   sum_j rbf_j(t)* after *b* \[4\].
+//! - Under a server, *b* may just miss a replenishment (or polling instant),
+//!   after which the server needs *n = ceil(W / Cs)* activations, the last for
+//!   the residual demand *W - (n - 1) Cs*, which completes within its response
+//!   time *R* at the server's priority: the jobs complete within
+//!   *n Ps + R* after *b*. The three servers share this worst-case bound: they
+//!   differ in the interference they cause to the periodic tasks, and in
+//!   their average responsiveness.
+//!
+//! #### Model:
+//! - Periodic/Sporadic Task model for the hard tasks
+//! - Aperiodic jobs, served in FIFO order
+//! - Fully-Preemptive Fixed-Priority scheduling
+//!
+//! #### Preconditions:
+//! - Constrained Deadlines
+//! - Aperiodic jobs sorted by arrival
+//!
+//! #### Implements:
+//! - [`Analysis::is_schedulable`] \
+//!   | Returns the response-time bounds of the aperiodic jobs. \
+//!   | \
+//!   | pseudo-polynomial complexity
+//!
+//! ---
+//! #### References:
+//! 1. J. P. Lehoczky, L. Sha, and J. K. Strosnider, “Enhanced aperiodic
+//!    responsiveness in hard real-time environments,” in Proceedings 8th IEEE
+//!    Real-Time Systems Symposium, Dec. 1987, pp. 261–270.
+//! 2. J. K. Strosnider, J. P. Lehoczky, and L. Sha, “The deferrable server
+//!    algorithm for enhanced aperiodic responsiveness in hard real-time
+//!    environments,” IEEE Transactions on Computers, vol. 44, no. 1,
+//!    pp. 73–91, Jan. 1995, doi: 10.1109/12.368008.
+//! 3. B. Sprunt, L. Sha, and J. Lehoczky, “Aperiodic task scheduling for
+//!    Hard-Real-Time systems,” Real-Time Syst, vol. 1, no. 1, pp. 27–60,
+//!    June 1989, doi: 10.1007/BF02341920.
+//! 4. C. L. Liu and J. W. Layland, “Scheduling Algorithms for Multiprogramming
+//!    in a Hard-Real-Time Environment,” J. ACM, vol. 20, no. 1, pp. 46–61,
+//!    Jan. 1973, doi: 10.1145/321738.321743.
+
+use crate::prelude::*;
+use crate::utils::dbf;
+use super::rta86;
+
+const ALGORITHM: &str = "Aperiodic Jobs under FP Servers";
+
+/// Aperiodic job, with its arrival time and WCET.
+#[derive(Debug, Clone)]
+pub struct AperiodicJob {
+    pub arrival: Time,
+    pub wcet: Time,
+}
+
+/// Periodic server of the aperiodic jobs.
+#[derive(Debug, Clone)]
+pub struct AperiodicServer {
+    pub budget: Time,
+    pub period: Time,
+    /// Position of the server in the taskset sorted by priority, i.e. the
+    /// number of periodic tasks with higher priority.
+    pub priority: usize,
+}
+
+impl AperiodicServer {
+    pub fn as_task(&self) -> RTTask {
+        RTTask {
+            wcet: self.budget,
+            deadline: self.period,
+            period: self.period,
+        }
+    }
+}
+
+/// Policy serving the aperiodic jobs.
+#[derive(Debug, Clone)]
+pub enum AperiodicPolicy {
+    Background,
+    PollingServer(AperiodicServer),
+    DeferrableServer(AperiodicServer),
+    SporadicServer(AperiodicServer),
+}
+
+/// Aperiodic Jobs under Fixed Priority Servers
+///
+/// Refer to the [module](`self`) level documentation.
+///
+/// Returns:
+/// - Response-time bounds of the aperiodic jobs.
+pub struct Analysis {
+    pub policy: AperiodicPolicy,
+    pub aperiodic_jobs: Vec<AperiodicJob>,
+}
+
+impl Analysis {
+    fn server(&self) -> Option<&AperiodicServer> {
+        match &self.policy {
+            AperiodicPolicy::Background => None,
+            AperiodicPolicy::PollingServer(server)
+            | AperiodicPolicy::DeferrableServer(server)
+            | AperiodicPolicy::SporadicServer(server) => Some(server),
+        }
+    }
+
+    // Periodic tasks with the server inserted at its priority.
+    fn periodic_taskset(&self, taskset: &[RTTask]) -> Vec<RTTask> {
+        let mut periodic_taskset = taskset.to_vec();
+
+        if let Some(server) = self.server() {
+            periodic_taskset.insert(server.priority, server.as_task());
+        }
+
+        periodic_taskset
+    }
+
+    // Check that the periodic tasks meet their deadlines with the server.
+    fn check_periodic_tasks(&self, taskset: &[RTTask]) -> Result<(), SchedError> {
+        let periodic_taskset = self.periodic_taskset(taskset);
+
+        match &self.policy {
+            AperiodicPolicy::DeferrableServer(server) => {
+                let mut jittered_taskset: Vec<_> =
+                    periodic_taskset.into_iter().map(rta86::JitteredTask::new).collect();
+                jittered_taskset[server.priority].release_jitter = server.period - server.budget;
+
+                rta86::AnalysisJitter.run_test(&jittered_taskset)?;
+            },
+            _ => {
+                rta86::Analysis.run_test(&periodic_taskset)?;
+            },
+        }
+
+        Ok(())
+    }
+
+    // Completion of the given demand, arrived at the start of the sequence.
+    fn completion(&self, taskset: &[RTTask], start: Time, demand: Time) -> Time {
+        match self.server() {
+            None => {
+                // Least fixpoint of t = W + sum_j rbf_j(t), which is at most
+                // (W + sum_j C_j) / (1 - U).
+                let utilization = RTUtils::total_utilization(taskset);
+                let wcets: Time = taskset.iter().map(|task| task.wcet).sum();
+                let limit = Time::ceil((demand + wcets) / (1.0 - utilization)) + Time::one();
+
+                start + fixpoint_search_with_limit(
+                    demand,
+                    limit,
+                    |time: &Time| demand + dbf::total_rbf(taskset, *time)
+                )
+            },
+            Some(server) => {
+                let activations = (demand / server.budget).ceil();
+                let residual = demand - server.budget * (activations - 1.0);
+
+                // The server's activations meet their deadlines, thus the
+                // residual demand completes within the period.
+                let hp_tasks = &taskset[0..server.priority];
+                let response = fixpoint_search_with_limit(
+                    residual,
+                    server.period,
+                    |time: &Time| residual + dbf::total_rbf(hp_tasks, *time)
+                );
+
+                start + server.period * activations + response
+            },
+        }
+    }
+}
+
+impl SchedAnalysis<Vec<Time>, &[RTTask]> for Analysis {
+    fn analyzer_name(&self) -> &str { ALGORITHM }
+
+    fn check_preconditions(&self, taskset: &&[RTTask]) -> Result<(), SchedError> {
+        if let Some(server) = self.server() {
+            if server.priority > taskset.len() {
+                return Err(SchedError::Precondition(Some(
+                    anyhow::format_err!("the server's priority is below the lowest priority task."))));
+            } else if server.budget <= Time::zero() || server.budget > server.period {
+                return Err(SchedError::Precondition(Some(
+                    anyhow::format_err!("the server's budget must be positive and at most its period."))));
+            }
+        }
+
+        if self.aperiodic_jobs.iter().any(|job| job.arrival < Time::zero() || job.wcet <= Time::zero()) {
+            return Err(SchedError::Precondition(Some(
+                anyhow::format_err!("aperiodic jobs must arrive at non-negative times, with positive WCETs."))));
+        } else if self.aperiodic_jobs.windows(2).any(|pair| pair[0].arrival > pair[1].arrival) {
+            return Err(SchedError::Precondition(Some(
+                anyhow::format_err!("aperiodic jobs must be sorted by arrival."))));
+        }
+
+        let periodic_taskset = self.periodic_taskset(taskset);
+        rta86::Analysis.check_preconditions(&periodic_taskset.as_slice())
+            .map_err(|err| err.with_context("periodic tasks"))
+    }
+
+    fn run_test(&self, taskset: &[RTTask]) -> Result<Vec<Time>, SchedError> {
+        self.check_periodic_tasks(taskset)
+            .map_err(|err| err.with_context("periodic tasks"))?;
+
+        if self.server().is_none() && RTUtils::total_utilization(taskset) >= 1.0 {
+            return Err(SchedError::NonSchedulable(Some(
+                anyhow::format_err!("the periodic tasks leave no time to background jobs."))));
+        }
+
+        let mut start = Time::zero();
+        let mut demand = Time::zero();
+        let mut previous_completion = None;
+
+        Ok(self.aperiodic_jobs.iter()
+            .map(|job| {
+                // A job arriving after the previous ones have completed starts
+                // a new sequence.
+                if previous_completion.is_none_or(|completion| job.arrival >= completion) {
+                    start = job.arrival;
+                    demand = Time::zero();
+                }
+
+                demand = demand + job.wcet;
+
+                let completion = self.completion(taskset, start, demand);
+                previous_completion = Some(completion);
+
+                completion - job.arrival
+            })
+            .collect())
+    }
+}
+
+impl SchedProcessors for Analysis {
+    fn num_processors(&self) -> u64 { 1 }
+}
+
+#[test]
+fn servicing_strategies() {
+    let taskset = [
+        RTTask::new_ns(1, 4, 4),
+        RTTask::new_ns(2, 8, 8),
+    ];
+
+    let at = Time::nanos;
+    let aperiodic_jobs = vec![
+        AperiodicJob { arrival: at(0.0), wcet: at(2.0) },
+        AperiodicJob { arrival: at(1.0), wcet: at(1.0) },
+        AperiodicJob { arrival: at(20.0), wcet: at(1.0) },
+    ];
+
+    let analysis = |policy| Analysis { policy, aperiodic_jobs: aperiodic_jobs.clone() };
+    let server = |budget| AperiodicServer { budget: at(budget), period: at(4.0), priority: 0 };
+
+    // The first two jobs form a sequence of demand 2, then 3, completing
+    // at 6 and 7 in the idle time of the periodic tasks.
+    assert_eq!(
+        analysis(AperiodicPolicy::Background).is_schedulable(&taskset).unwrap(),
+        vec![at(6.0), at(6.0), at(4.0)]
+    );
+
+    // Two activations of the server for the first job, three for both,
+    // after missing the first replenishment.
+    let expected = vec![at(9.0), at(12.0), at(5.0)];
+    assert_eq!(analysis(AperiodicPolicy::PollingServer(server(1.0))).is_schedulable(&taskset).unwrap(), expected);
+    assert_eq!(analysis(AperiodicPolicy::DeferrableServer(server(1.0))).is_schedulable(&taskset).unwrap(), expected);
+    assert_eq!(analysis(AperiodicPolicy::SporadicServer(server(1.0))).is_schedulable(&taskset).unwrap(), expected);
+
+    // Executing back to back, the deferrable server makes the second task
+    // miss its deadline.
+    assert_eq!(
+        analysis(AperiodicPolicy::PollingServer(server(1.5))).is_schedulable(&taskset).unwrap(),
+        vec![at(8.5), at(8.5), at(5.0)]
+    );
+    assert!(analysis(AperiodicPolicy::DeferrableServer(server(1.5))).is_schedulable(&taskset).is_err());
+
+    let overloaded = [RTTask::new_ns(2, 4, 4), RTTask::new_ns(4, 8, 8)];
+    assert!(analysis(AperiodicPolicy::Background).is_schedulable(&overloaded).is_err());
+}
@@ -0,0 +1,129 @@
+//! ## Fixed Priority RM Period Spread - Burchard, Liebeherr, Oh & Son 1995
+//!
+//! The closer the periods are to being powers of two of each other, the higher
+//! the utilization bound. Let *S_i = log2(T_i) - floor(log2(T_i))* and let
+//! *β = max_i S_i - min_i S_i*, then the tasks are schedulable if \[1\]:
+//!   U <= (n - 1)(2^(β / (n - 1)) - 1) + 2^(1 - β) - 1,   if β < 1 - 1/n
+//!   U <= n(2^(1/n) - 1),                                 otherwise
+//!
+//! The bound is never lower than the Liu & Layland one, and it is one when all
+//! the periods are powers of two of each other. Refer to
+//! [`utilization_bounds::burchard`] for the computation of *β*.
+//!
+//! #### Model:
+//! - Periodic/Sporadic Task model
+//! - Fully-Preemptive Fixed-Priority scheduling
+//!
+//! #### Preconditions:
+//! - Implicit Deadlines
+//! - Rate Monotonic priority assigment
+//!
+//! #### Implements:
+//! - [`Analysis::is_schedulable`] \
+//!   | *O(n log(n))* complexity
+//!
+//! ---
+//! #### References:
+//! 1. A. Burchard, J. Liebeherr, Y. Oh, and S. H. Son, “New strategies for
+//!    assigning real-time tasks to multiprocessor systems,” IEEE Transactions
+//!    on Computers, vol. 44, no. 12, pp. 1429–1442, Dec. 1995,
+//!    doi: 10.1109/12.477248.
+
+use crate::prelude::*;
+use crate::utils::numeric_validation::{check_inequality, compensated_sum};
+use crate::utils::utilization_bounds;
+
+const ALGORITHM: &str = "Fixed Priority RM Period Spread (Burchard, Liebeherr, Oh & Son 1995)";
+
+/// Fixed Priority RM Period Spread, Burchard, Liebeherr, Oh & Son 1995 \[1\]
+///
+/// Refer to the [module](`self`) level documentation.
+pub struct Analysis;
+
+impl SchedAnalysis<(), &[RTTask]> for Analysis {
+    fn analyzer_name(&self) -> &str { ALGORITHM }
+
+    fn check_preconditions(&self, taskset: &&[RTTask]) -> Result<(), SchedError> {
+        if !RTUtils::implicit_deadlines(taskset) {
+            Err(SchedError::implicit_deadlines())
+        } else if !RTUtils::is_taskset_sorted_by_period(taskset) {
+            Err(SchedError::rate_monotonic())
+        } else {
+            Ok(())
+        }
+    }
+
+    fn run_test(&self, taskset: &[RTTask]) -> Result<(), SchedError> {
+        // Theorem 3 [1]
+        let total_utilization = RTUtils::total_utilization(taskset);
+
+        SchedError::result_from_schedulable(total_utilization <= period_spread_lub(taskset))
+    }
+}
+
+impl SchedProcessors for Analysis {
+    fn num_processors(&self) -> u64 { 1 }
+}
+
+impl SchedPlatform for Analysis {
+    fn supports(&self, platform: &Platform) -> bool {
+        platform.matches(PlatformModel::Uniprocessor, SchedulingAlgorithm::FixedPriority, 1)
+    }
+}
+
+impl SchedBounds for Analysis {
+    // Never below the Liu & Layland bound.
+    fn speedup_factor(&self) -> Option<f64> { Some(1.0 / f64::ln(2f64)) }
+
+    fn utilization_bound(&self, taskset: &[RTTask]) -> Option<f64> {
+        Some(period_spread_lub(taskset))
+    }
+}
+
+impl SchedValidation for Analysis {
+    fn validate(&self, taskset: &[RTTask]) -> Vec<NumericWarning> {
+        let careful_utilization = compensated_sum(taskset.iter().map(|task| task.utilization()));
+
+        check_inequality(
+            "U <= (n - 1)(2^(β/(n - 1)) - 1) + 2^(1 - β) - 1",
+            RTUtils::total_utilization(taskset),
+            careful_utilization,
+            period_spread_lub(taskset)
+        )
+        .into_iter().collect()
+    }
+}
+
+// Theorem 3 [1]
+fn period_spread_lub(taskset: &[RTTask]) -> f64 {
+    let periods: Vec<_> = taskset.iter().map(|task| task.period).collect();
+
+    utilization_bounds::burchard(&periods)
+}
+
+#[test]
+fn period_spread_bound() {
+    use super::{rate_monotonic73, rta86};
+
+    // β = log2(4/3), bound 5/6 > 0.8284.
+    let taskset = [RTTask::new_ns(2, 4, 4), RTTask::new_ns(2, 6, 6)];
+    assert!(Analysis.is_schedulable(&taskset[..]).is_ok());
+    assert!(rate_monotonic73::Analysis.is_schedulable(&taskset[..]).is_err());
+
+    let taskset = [RTTask::new_ns(2, 4, 4), RTTask::new_ns(3, 6, 6)];
+    assert!(Analysis.is_schedulable(&taskset[..]).is_err());
+
+    // Sufficient, never more than the response time analysis.
+    // The response time analysis requires U < 1 with constrained deadlines.
+    for (c1, c2, c3, t2, t3) in itertools::iproduct!(1..=4, 1..=6, 1..=10, [12, 16, 20], [24, 32, 40]) {
+        if c1 * t2 * t3 + 8 * c2 * t3 + 8 * c3 * t2 >= 8 * t2 * t3 {
+            continue;
+        }
+
+        let taskset = [RTTask::new_ns(c1, 8, 8), RTTask::new_ns(c2, t2, t2), RTTask::new_ns(c3, t3, t3)];
+
+        if Analysis.is_schedulable(&taskset[..]).is_ok() {
+            assert!(rta86::Analysis.is_schedulable(&taskset[..]).is_ok(), "{taskset:?}");
+        }
+    }
+}
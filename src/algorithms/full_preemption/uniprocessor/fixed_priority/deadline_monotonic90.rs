@@ -17,6 +17,7 @@
 //! 1. N. C. Audsley, “Deadline Monotonic Scheduling,” Sept. 1990.
 
 use crate::prelude::*;
+use crate::utils::dbf;
 
 const ALGORITHM: &str = "Fixed Priority DM (Audsley 1990)";
 
@@ -50,7 +51,7 @@ impl SchedAnalysis<(), &[RTTask]> for Analysis {
 
             taskset.iter()
                 .take(taskset.len() - 1)
-                .map(|task| (last_task.deadline / task.period).ceil() * task.wcet)
+                .map(|task| dbf::rbf(task, last_task.deadline))
                 .sum()
         }
 
@@ -63,4 +64,14 @@ impl SchedAnalysis<(), &[RTTask]> for Analysis {
 
         SchedError::result_from_schedulable(schedulable)
     }
+}
+
+impl SchedProcessors for Analysis {
+    fn num_processors(&self) -> u64 { 1 }
+}
+
+impl SchedPlatform for Analysis {
+    fn supports(&self, platform: &Platform) -> bool {
+        platform.matches(PlatformModel::Uniprocessor, SchedulingAlgorithm::FixedPriority, 1)
+    }
 }
\ No newline at end of file
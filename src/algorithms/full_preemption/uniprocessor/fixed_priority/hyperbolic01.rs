@@ -11,6 +11,10 @@
 //! #### Implements:
 //! - [`Analysis::is_schedulable`] \
 //!   | linear *O(n)* complexity
+//! - [`AnalysisStreaming::is_schedulable`] \
+//!   | Read the tasks one at a time, without storing the taskset. \
+//!   | \
+//!   | linear *O(n)* complexity, constant memory
 //!
 //! ---
 //! #### References:
@@ -19,6 +23,8 @@
 //!    Systems, June 2001, pp. 59–66. doi: 10.1109/EMRTS.2001.934000.
 
 use crate::prelude::*;
+use crate::utils::numeric_validation::{check_inequality, compensated_sum};
+use crate::utils::utilization_bounds;
 
 const ALGORITHM: &str = "Fixed Priority RM Hyperbolic (Bini, Buttazzo, Buttazzo 2001)";
 
@@ -49,4 +55,78 @@ impl SchedAnalysis<(), &[RTTask]> for Analysis {
 
         SchedError::result_from_schedulable(bound <= 2.0)
     }
-}
\ No newline at end of file
+}
+
+impl SchedProcessors for Analysis {
+    fn num_processors(&self) -> u64 { 1 }
+}
+
+impl SchedPlatform for Analysis {
+    fn supports(&self, platform: &Platform) -> bool {
+        platform.matches(PlatformModel::Uniprocessor, SchedulingAlgorithm::FixedPriority, 1)
+    }
+}
+
+/// Fixed Priority RM Hyperbolic, Bini, Buttazzo, Buttazzo 2001 \[1\] \
+/// Streaming version, for tasksets too large to be stored in memory.
+///
+/// Refer to the [module](`self`) level documentation.
+///
+/// The preconditions are checked while reading the tasks, and errors in the
+/// input stream are reported as [`SchedError::Other`].
+pub struct AnalysisStreaming;
+
+impl<I> SchedAnalysis<(), I> for AnalysisStreaming
+    where
+        I: Iterator<Item = anyhow::Result<RTTask>>,
+{
+    fn analyzer_name(&self) -> &str { ALGORITHM }
+
+    fn check_preconditions(&self, _: &I) -> Result<(), SchedError> {
+        Ok(())
+    }
+
+    fn run_test(&self, tasks: I) -> Result<(), SchedError> {
+        let mut bound = 1f64;
+        let mut last_period = None;
+
+        for task in tasks {
+            let task = task.map_err(SchedError::Other)?;
+
+            if task.deadline > task.period {
+                return Err(SchedError::constrained_deadlines());
+            } else if last_period.is_some_and(|period| period > task.period) {
+                return Err(SchedError::rate_monotonic());
+            }
+
+            // Theorem 1 [1]
+            bound *= task.utilization() + 1.0;
+            last_period = Some(task.period);
+        }
+
+        SchedError::result_from_schedulable(bound <= 2.0)
+    }
+}
+
+impl SchedValidation for Analysis {
+    fn validate(&self, taskset: &[RTTask]) -> Vec<NumericWarning> {
+        let bound: f64 = taskset.iter().map(|task| task.utilization() + 1.0).product();
+
+        // Multiply in the logarithmic domain, avoiding the rounding of 1 + U_i.
+        let careful_bound =
+            f64::exp(compensated_sum(taskset.iter().map(|task| f64::ln_1p(task.utilization()))));
+
+        check_inequality("prod(U_i + 1) <= 2", bound, careful_bound, 2.0)
+            .into_iter().collect()
+    }
+}
+
+impl SchedBounds for Analysis {
+    // For total utilization U at most one: prod(1 + U_i) <= e^U <= 2 if U <= ln(2)
+    fn speedup_factor(&self) -> Option<f64> { Some(1.0 / f64::ln(2f64)) }
+
+    // The hyperbolic bound dominates the Liu & Layland bound [1]
+    fn utilization_bound(&self, taskset: &[RTTask]) -> Option<f64> {
+        Some(utilization_bounds::liu_layland(taskset.len() as u64))
+    }
+}
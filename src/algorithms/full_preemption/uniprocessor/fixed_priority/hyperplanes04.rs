@@ -0,0 +1,124 @@
+//! ## Hyperplanes Exact Test - Bini & Buttazzo 2004
+//!
+//! Task *i* is schedulable if and only if its job and the jobs of the higher
+//! priority tasks released in *\[0, t)* fit in *t*, for some *t* in the
+//! scheduling points of the task \[2\]. The scheduling points of \[2\] are all
+//! the multiples of the higher priority periods up to *D_i*, while \[1\]
+//! reduces them to the set *P_(i-1)(D_i)*, where:
+//! - *P_0(t) = {t}*
+//! - *P_j(t) = P_(j-1)(floor(t / T_j) T_j) ∪ P_(j-1)(t)*
+//!
+//! i.e. the test *C_i + sum_(j < i) ceil(t / T_j) C_j <= t* holds for some *t*
+//! in the region of the task space bounded by the hyperplanes of these
+//! points. The points are visited depth first, and the visit stops at the
+//! first point at which the test holds. Unlike the response time analysis,
+//! the number of evaluated points does not depend on the magnitude of the
+//! periods, but on the number of tasks.
+//!
+//! #### Model:
+//! - Periodic/Sporadic Task model
+//! - Fully-Preemptive Fixed-Priority scheduling
+//!
+//! #### Preconditions:
+//! - Constrained Deadlines
+//!
+//! #### Implements:
+//! - [`Analysis::is_schedulable`] \
+//!   | pseudo-polynomial complexity, at most *2^(i-1)* points for task *i*
+//!
+//! ---
+//! #### References:
+//! 1. E. Bini and G. C. Buttazzo, “Schedulability analysis of periodic fixed
+//!    priority systems,” IEEE Transactions on Computers, vol. 53, no. 11,
+//!    pp. 1462–1473, Nov. 2004, doi: 10.1109/TC.2004.103.
+//! 2. J. Lehoczky, L. Sha, and Y. Ding, “The rate monotonic scheduling
+//!    algorithm: exact characterization and average case behavior,” in
+//!    Proceedings Real-Time Systems Symposium, Dec. 1989, pp. 166–171,
+//!    doi: 10.1109/REAL.1989.63567.
+
+use crate::prelude::*;
+use crate::utils::dbf;
+
+const ALGORITHM: &str = "Fixed Priority Hyperplanes Exact Test (Bini & Buttazzo 2004)";
+
+/// Hyperplanes Exact Test - Bini & Buttazzo 2004 \[1\]
+///
+/// Refer to the [module](`self`) level documentation.
+///
+/// The tasks must be sorted by priority, highest first.
+pub struct Analysis;
+
+impl SchedAnalysis<(), &[RTTask]> for Analysis {
+    fn analyzer_name(&self) -> &str { ALGORITHM }
+
+    fn check_preconditions(&self, taskset: &&[RTTask]) -> Result<(), SchedError> {
+        if !RTUtils::constrained_deadlines(taskset) {
+            Err(SchedError::constrained_deadlines())
+        } else {
+            Ok(())
+        }
+    }
+
+    fn run_test(&self, taskset: &[RTTask]) -> Result<(), SchedError> {
+        for (i, task) in taskset.iter().enumerate() {
+            let higher_priority = &taskset[0..i];
+            let fits = |interval: Time|
+                task.wcet + dbf::total_rbf(higher_priority, interval) <= interval;
+
+            if !any_scheduling_point(higher_priority, task.deadline, &fits) {
+                return Err(SchedError::NonSchedulable(Some(anyhow::format_err!(
+                    "task {i} does not fit at any of its scheduling points."))));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl SchedProcessors for Analysis {
+    fn num_processors(&self) -> u64 { 1 }
+}
+
+impl SchedPlatform for Analysis {
+    fn supports(&self, platform: &Platform) -> bool {
+        platform.matches(PlatformModel::Uniprocessor, SchedulingAlgorithm::FixedPriority, 1)
+    }
+}
+
+impl SchedBounds for Analysis {
+    // Exact test [1]
+    fn speedup_factor(&self) -> Option<f64> { Some(1.0) }
+}
+
+// Depth first visit of the points of P_j(t), j being the number of the
+// given higher priority tasks, until the test holds.
+fn any_scheduling_point<F>(higher_priority: &[RTTask], interval: Time, fits: &F) -> bool
+    where
+        F: Fn(Time) -> bool,
+{
+    let Some((last, others)) = higher_priority.split_last() else {
+        return fits(interval);
+    };
+
+    let last_release = (interval / last.period).floor() * last.period;
+
+    any_scheduling_point(others, interval, fits)
+        || (last_release > Time::zero() && last_release < interval
+            && any_scheduling_point(others, last_release, fits))
+}
+
+#[test]
+fn hyperplanes() {
+    use crate::utils::test_tasksets::assert_same_verdict_as_rta;
+
+    // Liu & Layland's worst case for two tasks, exactly schedulable at 4.
+    let taskset = [RTTask::new_ns(2, 4, 4), RTTask::new_ns(2, 6, 6)];
+    assert!(Analysis.is_schedulable(&taskset[..]).is_ok());
+    let taskset = [RTTask::new_ns(2, 4, 4), RTTask::new_ns(3, 6, 6)];
+    assert!(Analysis.is_schedulable(&taskset[..]).is_err());
+
+    assert!(Analysis.is_schedulable(&[RTTask::new_ns(1, 8, 4)][..]).is_err());
+
+    // Same verdict as the response time analysis, for any priority order.
+    assert_same_verdict_as_rta(&Analysis, &[[0, 1, 2], [2, 0, 1], [1, 2, 0]]);
+}
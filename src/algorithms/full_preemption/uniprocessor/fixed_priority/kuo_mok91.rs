@@ -0,0 +1,120 @@
+//! ## Fixed Priority RM Harmonic Chains - Kuo & Mok 1991
+//!
+//! The periods are partitioned in harmonic chains, i.e. chains of periods each
+//! dividing the next. The tasks of each chain behave as a single task with
+//! their total utilization, thus the Liu & Layland bound applies to the number
+//! of chains *K* rather than to the number of tasks \[1\]:
+//!   U <= K(2^(1/K) - 1)
+//!
+//! The minimum number of chains is computed, which gives the largest bound.
+//!
+//! #### Model:
+//! - Periodic/Sporadic Task model
+//! - Fully-Preemptive Fixed-Priority scheduling
+//!
+//! #### Preconditions:
+//! - Implicit Deadlines
+//! - Rate Monotonic priority assigment
+//!
+//! #### Implements:
+//! - [`Analysis::is_schedulable`] \
+//!   | *O(n^3)* complexity, to find the minimum number of harmonic chains
+//!
+//! ---
+//! #### References:
+//! 1. T.-W. Kuo and A. K. Mok, “Load adjustment in adaptive real-time systems,”
+//!    in Proceedings Twelfth Real-Time Systems Symposium, Dec. 1991, pp. 160–170.
+//!    doi: 10.1109/REAL.1991.160369.
+
+use crate::prelude::*;
+use crate::utils::numeric_validation::{check_inequality, compensated_sum};
+use crate::utils::utilization_bounds;
+
+const ALGORITHM: &str = "Fixed Priority RM Harmonic Chains (Kuo & Mok 1991)";
+
+/// Fixed Priority RM Harmonic Chains, Kuo & Mok 1991 \[1\]
+///
+/// Refer to the [module](`self`) level documentation.
+pub struct Analysis;
+
+impl SchedAnalysis<(), &[RTTask]> for Analysis {
+    fn analyzer_name(&self) -> &str { ALGORITHM }
+
+    fn check_preconditions(&self, taskset: &&[RTTask]) -> Result<(), SchedError> {
+        if !RTUtils::implicit_deadlines(taskset) {
+            Err(SchedError::implicit_deadlines())
+        } else if !RTUtils::is_taskset_sorted_by_period(taskset) {
+            Err(SchedError::rate_monotonic())
+        } else {
+            Ok(())
+        }
+    }
+
+    fn run_test(&self, taskset: &[RTTask]) -> Result<(), SchedError> {
+        // Theorem 3 [1]
+        let total_utilization = RTUtils::total_utilization(taskset);
+
+        SchedError::result_from_schedulable(total_utilization <= harmonic_chains_lub(taskset))
+    }
+}
+
+impl SchedProcessors for Analysis {
+    fn num_processors(&self) -> u64 { 1 }
+}
+
+impl SchedPlatform for Analysis {
+    fn supports(&self, platform: &Platform) -> bool {
+        platform.matches(PlatformModel::Uniprocessor, SchedulingAlgorithm::FixedPriority, 1)
+    }
+}
+
+impl SchedBounds for Analysis {
+    // Never below the Liu & Layland bound, as there are at most n chains.
+    fn speedup_factor(&self) -> Option<f64> { Some(1.0 / f64::ln(2f64)) }
+
+    fn utilization_bound(&self, taskset: &[RTTask]) -> Option<f64> {
+        Some(harmonic_chains_lub(taskset))
+    }
+}
+
+impl SchedValidation for Analysis {
+    fn validate(&self, taskset: &[RTTask]) -> Vec<NumericWarning> {
+        let careful_utilization = compensated_sum(taskset.iter().map(|task| task.utilization()));
+
+        check_inequality(
+            "U <= K(2^(1/K) - 1)",
+            RTUtils::total_utilization(taskset),
+            careful_utilization,
+            harmonic_chains_lub(taskset)
+        )
+        .into_iter().collect()
+    }
+}
+
+// Theorem 3 [1]
+fn harmonic_chains_lub(taskset: &[RTTask]) -> f64 {
+    let periods: Vec<_> = taskset.iter().map(|task| task.period).collect();
+
+    utilization_bounds::kuo_mok(utilization_bounds::harmonic_chains(&periods))
+}
+
+#[test]
+fn harmonic_chains_bound() {
+    use super::rate_monotonic73;
+
+    // One chain: schedulable up to full utilization.
+    let taskset = [RTTask::new_ns(2, 4, 4), RTTask::new_ns(2, 8, 8), RTTask::new_ns(4, 16, 16)];
+    assert!(Analysis.is_schedulable(&taskset[..]).is_ok());
+    assert!(rate_monotonic73::Analysis.is_schedulable(&taskset[..]).is_err());
+
+    // Two chains, 10 | 20 and 15 | 30, with U = 0.8 <= 0.8284.
+    let taskset = [RTTask::new_ns(2, 10, 10), RTTask::new_ns(3, 15, 15), RTTask::new_ns(4, 20, 20), RTTask::new_ns(6, 30, 30)];
+    assert!(Analysis.is_schedulable(&taskset[..]).is_ok());
+    assert_eq!(Analysis.utilization_bound(&taskset), Some(utilization_bounds::liu_layland(2)));
+
+    let taskset = [RTTask::new_ns(2, 10, 10), RTTask::new_ns(3, 15, 15), RTTask::new_ns(5, 20, 20), RTTask::new_ns(6, 30, 30)];
+    assert!(Analysis.is_schedulable(&taskset[..]).is_err());
+
+    let taskset = [RTTask::new_ns(1, 8, 10), RTTask::new_ns(1, 20, 20)];
+    assert!(Analysis.is_schedulable(&taskset[..]).is_err());
+}
@@ -0,0 +1,152 @@
+//! ## Priority Ceiling Protocol - Sha, Rajkumar & Lehoczky 1990
+//!
+//! Under the Priority Ceiling Protocol, a job may lock a resource only if its
+//! priority is higher than the ceilings of all the resources locked by the
+//! other jobs, and the job holding a resource inherits the priority of the
+//! jobs it blocks \[1\]. Thus, a job is blocked at most once, for the duration
+//! of a single critical section of a lower priority task on a resource whose
+//! ceiling is at least its priority:
+//!   B_i = max { |z_j,r| : j > i, ceiling(r) <= i }
+//! where the tasks are sorted by priority, highest first (refer to
+//! [`CriticalSections::ceiling`]). The blocking times are then accounted in the
+//! [response time analysis](super::rta86::AnalysisBlocking) \[2\].
+//!
+//! #### Model:
+//! - Periodic/Sporadic Task model
+//! - Fully-Preemptive Fixed-Priority scheduling
+//! - Shared resources accessed in non-nested critical sections, refer to
+//!   [`resources`](crate::utils::resources)
+//!
+//! #### Preconditions:
+//! - Constrained Deadlines
+//! - Critical sections within the WCETs of the tasks
+//!
+//! #### Implements:
+//! - [`Analysis::is_schedulable`] \
+//!   | pseudo-polynomial complexity
+//! - [`blocking_times`] \
+//!   | Blocking time of each task under the protocol. \
+//!   | \
+//!   | *O(n \* s)* complexity, for *s* critical sections in total
+//!
+//! ---
+//! #### References:
+//! 1. L. Sha, R. Rajkumar, and J. P. Lehoczky, “Priority inheritance protocols:
+//!    an approach to real-time synchronization,” IEEE Transactions on
+//!    Computers, vol. 39, no. 9, pp. 1175–1185, Sept. 1990,
+//!    doi: 10.1109/12.57058.
+//! 2. N. Audsley, A. Burns, M. Richardson, K. Tindell, and A. J. Wellings,
+//!    “Applying new scheduling theory to static priority pre-emptive
+//!    scheduling,” Software Engineering Journal, vol. 8, no. 5, pp. 284–292,
+//!    Sept. 1993, doi: 10.1049/sej.1993.0034.
+
+use crate::prelude::*;
+use crate::utils::resources::CriticalSections;
+use super::rta86;
+
+const ALGORITHM: &str = "Priority Ceiling Protocol RTA (Sha, Rajkumar & Lehoczky 1990)";
+
+/// Priority Ceiling Protocol - Sha, Rajkumar & Lehoczky 1990 \[1\]
+///
+/// Refer to the [module](`self`) level documentation.
+///
+/// The tasks must be sorted by priority, highest first.
+///
+/// Returns:
+/// - Worst-Case Response Times of each task.
+pub struct Analysis {
+    pub critical_sections: CriticalSections,
+}
+
+impl SchedAnalysis<Vec<Time>, &[RTTask]> for Analysis {
+    fn analyzer_name(&self) -> &str { ALGORITHM }
+
+    fn check_preconditions(&self, taskset: &&[RTTask]) -> Result<(), SchedError> {
+        self.critical_sections.check_preconditions(taskset)?;
+        self.blocking_analysis().check_preconditions(taskset)
+    }
+
+    fn run_test(&self, taskset: &[RTTask]) -> Result<Vec<Time>, SchedError> {
+        self.blocking_analysis().run_test(taskset)
+    }
+}
+
+impl Analysis {
+    fn blocking_analysis(&self) -> rta86::AnalysisBlocking {
+        rta86::AnalysisBlocking { blocking: blocking_times(&self.critical_sections) }
+    }
+}
+
+impl SchedProcessors for Analysis {
+    fn num_processors(&self) -> u64 { 1 }
+}
+
+impl SchedPlatform for Analysis {
+    fn supports(&self, platform: &Platform) -> bool {
+        platform.matches(PlatformModel::Uniprocessor, SchedulingAlgorithm::FixedPriority, 1)
+    }
+}
+
+/// Blocking time of each task under the Priority Ceiling Protocol, refer to
+/// the [module](`self`) level documentation. The tasks are sorted by priority,
+/// highest first.
+pub fn blocking_times(critical_sections: &CriticalSections) -> Vec<Time> {
+    (0..critical_sections.sections.len())
+        .map(|i| {
+            critical_sections.sections[i + 1..].iter()
+                .flatten()
+                .filter(|section| critical_sections.ceiling(section.resource).is_some_and(|ceiling| ceiling <= i))
+                .map(|section| section.duration)
+                .max()
+                .unwrap_or(Time::zero())
+        })
+        .collect()
+}
+
+#[test]
+fn priority_ceiling_blocking() {
+    use crate::utils::resources::CriticalSection;
+
+    let section = |resource, duration| CriticalSection { resource, duration: Time::nanos(duration) };
+
+    let taskset = [
+        RTTask::new_ns(2, 10, 10),
+        RTTask::new_ns(3, 15, 15),
+        RTTask::new_ns(4, 30, 30),
+    ];
+
+    // The ceilings of resources 0, 1 and 2 are tasks 0, 1 and 1.
+    let critical_sections = CriticalSections { sections: vec![
+        vec![section(0, 1.0)],
+        vec![section(1, 2.0), section(2, 1.0)],
+        vec![section(0, 2.0), section(1, 1.0), section(2, 3.0)],
+    ] };
+    assert_eq!(critical_sections.ceiling(2), Some(1));
+    assert_eq!(critical_sections.ceiling(3), None);
+
+    // Task 0 is only blocked on resource 0, whose ceiling is its priority,
+    // while task 1 is blocked on any of them, once. The lowest priority task
+    // is never blocked.
+    assert_eq!(blocking_times(&critical_sections), vec![Time::nanos(2.0), Time::nanos(3.0), Time::zero()]);
+
+    // Task 1: R = 3 + 3 + ceil(R / 10) * 2 = 8
+    let analysis = Analysis { critical_sections };
+    assert_eq!(analysis.is_schedulable(&taskset[..]).unwrap(), vec![Time::nanos(4.0), Time::nanos(8.0), Time::nanos(9.0)]);
+
+    // Task 1 misses a deadline of 8 if blocked for 4 by task 2.
+    let mut critical_sections = analysis.critical_sections.clone();
+    critical_sections.sections[2][2].duration = Time::nanos(4.0);
+    assert_eq!(blocking_times(&critical_sections)[1], Time::nanos(4.0));
+
+    let constrained = [RTTask::new_ns(2, 10, 10), RTTask::new_ns(3, 8, 15), RTTask::new_ns(4, 30, 30)];
+    assert!(Analysis { critical_sections: critical_sections.clone() }.is_schedulable(&constrained[..]).is_err());
+    assert!(Analysis { critical_sections: critical_sections.clone() }.is_schedulable(&taskset[..]).is_ok());
+
+    // Critical sections longer than the WCET.
+    critical_sections.sections[0][0].duration = Time::nanos(3.0);
+    assert!(Analysis { critical_sections }.is_schedulable(&taskset[..]).is_err());
+
+    // Without critical sections, the plain RTA.
+    assert_eq!(Analysis { critical_sections: CriticalSections::none(3) }.is_schedulable(&taskset[..]).unwrap(),
+        rta86::Analysis.is_schedulable(&taskset[..]).unwrap());
+}
@@ -14,6 +14,10 @@
 //! - [`AnalysisSimple::is_schedulable`] \
 //!   | limit approximation
 //!   | linear *O(n)* complexity
+//! - [`AnalysisStreaming::is_schedulable`] \
+//!   | Read the tasks one at a time, without storing the taskset. \
+//!   | \
+//!   | linear *O(n)* complexity, constant memory
 //!
 //! ---
 //! #### References:
@@ -22,6 +26,8 @@
 //!    Jan. 1973, doi: 10.1145/321738.321743.
 
 use crate::prelude::*;
+use crate::utils::numeric_validation::{check_inequality, compensated_sum};
+use crate::utils::utilization_bounds;
 
 const ALGORITHM: &str = "Fixed Priority RM (Liu & Layland 1973)";
 
@@ -46,13 +52,45 @@ impl SchedAnalysis<(), &[RTTask]> for Analysis {
     fn run_test(&self, taskset: &[RTTask]) -> Result<(), SchedError> {
         // Theorem 5: let m = #Tasks, lub(Utilization) = m * (2^(1/m) - 1) [1]
         let total_utilization = RTUtils::total_utilization(taskset);
-        let rate_monotonic_lub =
-            (taskset.len() as f64) * (f64::powf(2.0, 1.0 / taskset.len() as f64) - 1.0);
+        let rate_monotonic_lub = rate_monotonic_lub(taskset.len());
 
         SchedError::result_from_schedulable(total_utilization <= rate_monotonic_lub)
     }
 }
 
+impl SchedProcessors for Analysis {
+    fn num_processors(&self) -> u64 { 1 }
+}
+
+impl SchedPlatform for Analysis {
+    fn supports(&self, platform: &Platform) -> bool {
+        platform.matches(PlatformModel::Uniprocessor, SchedulingAlgorithm::FixedPriority, 1)
+    }
+}
+
+impl SchedBounds for Analysis {
+    // A feasible taskset has total utilization at most one.
+    fn speedup_factor(&self) -> Option<f64> { Some(1.0 / f64::ln(2f64)) }
+
+    fn utilization_bound(&self, taskset: &[RTTask]) -> Option<f64> {
+        Some(rate_monotonic_lub(taskset.len()))
+    }
+}
+
+impl SchedValidation for Analysis {
+    fn validate(&self, taskset: &[RTTask]) -> Vec<NumericWarning> {
+        let careful_utilization = compensated_sum(taskset.iter().map(|task| task.utilization()));
+
+        check_inequality(
+            "U <= n(2^(1/n) - 1)",
+            RTUtils::total_utilization(taskset),
+            careful_utilization,
+            rate_monotonic_lub(taskset.len())
+        )
+        .into_iter().collect()
+    }
+}
+
 /// Fixed Priority Rate Monotonic, Liu & Layland 1973 \[1\]
 ///
 /// Refer to the [module](`self`) level documentation.
@@ -79,6 +117,79 @@ impl SchedAnalysis<(), &[RTTask]> for AnalysisSimple {
     }
 }
 
+impl SchedProcessors for AnalysisSimple {
+    fn num_processors(&self) -> u64 { 1 }
+}
+
+impl SchedPlatform for AnalysisSimple {
+    fn supports(&self, platform: &Platform) -> bool {
+        platform.matches(PlatformModel::Uniprocessor, SchedulingAlgorithm::FixedPriority, 1)
+    }
+}
+
+impl SchedValidation for AnalysisSimple {
+    fn validate(&self, taskset: &[RTTask]) -> Vec<NumericWarning> {
+        let careful_utilization = compensated_sum(taskset.iter().map(|task| task.utilization()));
+
+        check_inequality("U <= ln(2)", RTUtils::total_utilization(taskset), careful_utilization, f64::ln(2f64))
+            .into_iter().collect()
+    }
+}
+
+impl SchedBounds for AnalysisSimple {
+    fn speedup_factor(&self) -> Option<f64> { Some(1.0 / f64::ln(2f64)) }
+
+    fn utilization_bound(&self, _: &[RTTask]) -> Option<f64> { Some(f64::ln(2f64)) }
+}
+
+/// Fixed Priority Rate Monotonic, Liu & Layland 1973 \[1\] \
+/// Streaming version, for tasksets too large to be stored in memory.
+///
+/// Refer to the [module](`self`) level documentation.
+///
+/// The preconditions are checked while reading the tasks, and errors in the
+/// input stream are reported as [`SchedError::Other`].
+pub struct AnalysisStreaming;
+
+impl<I> SchedAnalysis<(), I> for AnalysisStreaming
+    where
+        I: Iterator<Item = anyhow::Result<RTTask>>,
+{
+    fn analyzer_name(&self) -> &str { ALGORITHM }
+
+    fn check_preconditions(&self, _: &I) -> Result<(), SchedError> {
+        Ok(())
+    }
+
+    fn run_test(&self, tasks: I) -> Result<(), SchedError> {
+        let mut total_utilization = 0f64;
+        let mut num_tasks = 0;
+        let mut last_period = None;
+
+        for task in tasks {
+            let task = task.map_err(SchedError::Other)?;
+
+            if task.deadline != task.period {
+                return Err(SchedError::implicit_deadlines());
+            } else if last_period.is_some_and(|period| period > task.period) {
+                return Err(SchedError::rate_monotonic());
+            }
+
+            total_utilization += task.utilization();
+            num_tasks += 1;
+            last_period = Some(task.period);
+        }
+
+        // Theorem 5 [1]
+        SchedError::result_from_schedulable(total_utilization <= rate_monotonic_lub(num_tasks))
+    }
+}
+
+// Theorem 5 [1]
+fn rate_monotonic_lub(num_tasks: usize) -> f64 {
+    utilization_bounds::liu_layland(num_tasks as u64)
+}
+
 fn check_preconditions(taskset: &&[RTTask]) -> Result<(), SchedError> {
     if !RTUtils::implicit_deadlines(taskset) {
         Err(SchedError::implicit_deadlines())
@@ -87,4 +198,4 @@ fn check_preconditions(taskset: &&[RTTask]) -> Result<(), SchedError> {
     } else {
         Ok(())
     }
-}
\ No newline at end of file
+}
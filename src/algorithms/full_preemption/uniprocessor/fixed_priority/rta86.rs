@@ -5,18 +5,86 @@
 //! - Fully-Preemptive Fixed-Priority scheduling
 //!
 //! #### Preconditions:
-//! - Constrained Deadlines
+//! - Constrained Deadlines, except for [`Analysis`]
 //!
 //! #### Implements:
 //! - [`Analysis::is_schedulable`] \
+//!   | Fixpoint iterations start from the lower bounds in \[4\] and \[5\]. \
+//!   | Tasksets with arbitrary deadlines are analysed over the level-i busy
+//!   | periods \[7\]. \
+//!   | \
 //!   | pseudo-polynomial complexity
+//! - [`AnalysisWarmStart::is_schedulable`] \
+//!   | Start the fixpoint iterations from previously computed response times. \
+//!   | \
+//!   | pseudo-polynomial complexity
+//! - [`AnalysisKernelLatencies::is_schedulable`] \
+//!   | Accounts for the platform's [`KernelLatencies`] as release jitter \[2\]. \
+//!   | \
+//!   | pseudo-polynomial complexity
+//! - [`AnalysisJitter::is_schedulable`] \
+//!   | Accounts for the release jitter of each task \[2\], refer to
+//!   | [`JitteredTask`]. \
+//!   | \
+//!   | pseudo-polynomial complexity
+//! - [`AnalysisBlocking::is_schedulable`] \
+//!   | Accounts for the blocking time of each task on the resources shared
+//!   | with lower priority tasks \[2\]. \
+//!   | \
+//!   | pseudo-polynomial complexity
+//! - [`AnalysisOverheads::is_schedulable`] \
+//!   | Accounts for the platform's [`SchedulingOverheads`] as explicit
+//!   | interference terms \[9\]. \
+//!   | \
+//!   | pseudo-polynomial complexity
+//! - [`AnalysisRoundRobin::is_schedulable`] \
+//!   | Tasks at equal priority are scheduled round-robin with a given quantum,
+//!   | as POSIX `SCHED_RR` threads \[8\]. \
+//!   | \
+//!   | pseudo-polynomial complexity
+//! - [`AnalysisBracketing::is_schedulable`] \
+//!   | Brackets each response time between cheap lower and upper bounds \[4\]
+//!   | \[5\] \[6\], and runs the fixpoint iterations only for the tasks whose
+//!   | brackets do not decide schedulability. \
+//!   | \
+//!   | pseudo-polynomial complexity, linear *O(n)* per decided task
 //!
 //! ---
 //! #### References:
 //! 1. M. Joseph and P. Pandya, “Finding Response Times in a Real-Time System,”
 //!    Comput J, vol. 29, no. 5, pp. 390–395, 1986, doi: 10.1093/comjnl/29.5.390.
+//! 2. N. Audsley, A. Burns, M. Richardson, K. Tindell, and A. J. Wellings,
+//!    “Applying new scheduling theory to static priority pre-emptive
+//!    scheduling,” Software Engineering Journal, vol. 8, no. 5, pp. 284–292,
+//!    Sept. 1993, doi: 10.1049/sej.1993.0034.
+//! 3. R. I. Davis, T. Rothvoß, S. K. Baruah, and A. Burns, “Exact quantification
+//!    of the sub-optimality of uniprocessor fixed priority pre-emptive
+//!    scheduling,” Real-Time Syst, vol. 43, no. 3, pp. 211–258, Nov. 2009,
+//!    doi: 10.1007/s11241-009-9079-4.
+//! 4. M. Sjödin and H. Hansson, “Improved response-time analysis calculations,”
+//!    in Proceedings 19th IEEE Real-Time Systems Symposium, Dec. 1998,
+//!    pp. 399–408, doi: 10.1109/REAL.1998.739773.
+//! 5. R. I. Davis, A. Zabos, and A. Burns, “Efficient Exact Schedulability Tests
+//!    for Fixed Priority Real-Time Systems,” IEEE Transactions on Computers,
+//!    vol. 57, no. 9, pp. 1261–1276, Sept. 2008, doi: 10.1109/TC.2008.66.
+//! 6. E. Bini and S. K. Baruah, “Efficient computation of response time bounds
+//!    under fixed-priority scheduling,” in 15th International Conference on
+//!    Real-Time and Network Systems, Mar. 2007, pp. 95–104.
+//! 7. J. P. Lehoczky, “Fixed priority scheduling of periodic task sets with
+//!    arbitrary deadlines,” in Proceedings 11th Real-Time Systems Symposium,
+//!    Dec. 1990, pp. 201–209, doi: 10.1109/REAL.1990.128748.
+//! 8. J. Migge, S. Jean, and N. Navet, “Timing analysis of compound scheduling
+//!    policies: Application to Posix1003.1b,” Journal of Scheduling, vol. 6,
+//!    no. 5, pp. 457–482, Sept. 2003, doi: 10.1023/A:1024806501339.
+//! 9. A. Burns, K. Tindell, and A. Wellings, “Effective analysis for engineering
+//!    real-time fixed priority schedulers,” IEEE Transactions on Software
+//!    Engineering, vol. 21, no. 5, pp. 475–480, May 1995,
+//!    doi: 10.1109/32.387477.
 
 use crate::prelude::*;
+use crate::utils::dbf;
+use crate::utils::numeric_validation::exact_nanos;
+use crate::utils::warm_start::warm_start_response_time;
 
 const ALGORITHM: &str = "RTA (Joseph & Pandya 1986)";
 
@@ -32,11 +100,216 @@ impl SchedAnalysis<Vec<Time>, &[RTTask]> for Analysis {
     fn analyzer_name(&self) -> &str { ALGORITHM }
 
     fn check_preconditions(&self, taskset: &&[RTTask]) -> Result<(), SchedError> {
-        if !RTUtils::constrained_deadlines(taskset) {
-            Err(SchedError::constrained_deadlines())
-        } else if !avg_processing_load_is_met(taskset) {
+        match DeadlineModel::of(taskset) {
+            // The busy periods are bounded if the utilization is at most one.
+            DeadlineModel::Arbitrary if RTUtils::total_utilization(taskset) > 1.0 =>
+                Err(SchedError::Precondition(Some(
+                    anyhow::format_err!("average processing load is not met.")))),
+            DeadlineModel::Arbitrary =>
+                Ok(()),
+            _ =>
+                check_preconditions_constrained(taskset),
+        }
+    }
+
+    fn run_test(&self, taskset: &[RTTask]) -> Result<Vec<Time>, SchedError> {
+        match DeadlineModel::of(taskset) {
+            DeadlineModel::Arbitrary => busy_period_response_times(taskset),
+            _ => response_times(taskset, &[]),
+        }
+    }
+}
+
+impl SchedProcessors for Analysis {
+    fn num_processors(&self) -> u64 { 1 }
+}
+
+impl SchedPlatform for Analysis {
+    fn supports(&self, platform: &Platform) -> bool {
+        platform.matches(PlatformModel::Uniprocessor, SchedulingAlgorithm::FixedPriority, 1)
+    }
+}
+
+impl SchedBounds for Analysis {
+    // Exact test, speedup of Deadline Monotonic priority assignment for
+    // constrained deadlines tasksets, 1 / Omega [3]
+    fn speedup_factor(&self) -> Option<f64> { Some(1.76322) }
+}
+
+impl SchedValidation for Analysis {
+    // Recompute the response times in exact integer arithmetic, when all the
+    // task parameters are integer nanoseconds, and compare them with the ones
+    // of the fast path, up to its first deadline miss.
+    fn validate(&self, taskset: &[RTTask]) -> Vec<NumericWarning> {
+        if check_preconditions_constrained(taskset).is_err() {
+            return Vec::new();
+        }
+
+        let Some(tasks) = taskset.iter()
+            .map(|task| Some((exact_nanos(task.wcet)?, exact_nanos(task.deadline)?, exact_nanos(task.period)?)))
+            .collect::<Option<Vec<_>>>()
+            else { return Vec::new(); };
+
+        let fast_response_times = response_times_until_miss(taskset, &[]);
+
+        fast_response_times.iter().enumerate()
+            .filter_map(|(i, fast_response)| {
+                let (wcet, deadline, _) = tasks[i];
+                let exact_response = exact_response_time(&tasks[0..i], wcet, deadline);
+                let fast_response = fast_response.as_nanos();
+
+                // Past the deadline, only the verdict is compared.
+                let fast_miss = fast_response > deadline as f64;
+                let differs =
+                    fast_miss != (exact_response > deadline)
+                    || (!fast_miss && fast_response != exact_response as f64);
+
+                differs.then(|| NumericWarning {
+                    inequality: format!("R_{i} <= D_{i}"),
+                    fast_lhs: fast_response,
+                    careful_lhs: exact_response as f64,
+                    rhs: deadline as f64,
+                })
+            })
+            .collect()
+    }
+}
+
+/// Response Time Analysis, Joseph & Pandya 1986 \[1\] \
+/// Warm-started from previously computed response times.
+///
+/// Refer to the [module](`self`) level documentation.
+///
+/// Refer to [`warm_start_response_time`] for the requirements on the initial
+/// response times.
+///
+/// Returns:
+/// - Worst-Case Response Times of each task.
+pub struct AnalysisWarmStart {
+    pub initial_response_times: Vec<Time>,
+}
+
+impl SchedAnalysis<Vec<Time>, &[RTTask]> for AnalysisWarmStart {
+    fn analyzer_name(&self) -> &str { ALGORITHM }
+
+    fn check_preconditions(&self, taskset: &&[RTTask]) -> Result<(), SchedError> {
+        check_preconditions_constrained(taskset)
+    }
+
+    fn run_test(&self, taskset: &[RTTask]) -> Result<Vec<Time>, SchedError> {
+        response_times(taskset, &self.initial_response_times)
+    }
+}
+
+/// Response Time Analysis, Joseph & Pandya 1986 \[1\] \
+/// Kernel latencies are accounted as release jitter, Audsley et al. 1993 \[2\]
+///
+/// Refer to the [module](`self`) level documentation.
+///
+/// Returns:
+/// - Worst-Case Response Times of each task, measured from the job's arrival.
+pub struct AnalysisKernelLatencies {
+    pub latencies: KernelLatencies,
+}
+
+impl SchedAnalysis<Vec<Time>, &[RTTask]> for AnalysisKernelLatencies {
+    fn analyzer_name(&self) -> &str { ALGORITHM }
+
+    fn check_preconditions(&self, taskset: &&[RTTask]) -> Result<(), SchedError> {
+        check_preconditions_constrained(taskset)?;
+        self.latencies.check_preconditions(taskset)
+    }
+
+    fn run_test(&self, taskset: &[RTTask]) -> Result<Vec<Time>, SchedError> {
+        response_times_with_jitter(taskset, &vec![self.latencies.release_jitter(); taskset.len()])
+    }
+}
+
+/// Task with its release jitter, the maximum delay between the arrival and
+/// the release of its jobs.
+///
+/// Refer to the [module](`self`) level documentation.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct JitteredTask {
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub task: RTTask,
+    #[cfg_attr(feature = "serde", serde(default = "Time::zero"))]
+    pub release_jitter: Time,
+}
+
+impl JitteredTask {
+    /// Task without release jitter.
+    pub fn new(task: RTTask) -> Self {
+        Self { task, release_jitter: Time::zero() }
+    }
+}
+
+/// Response Time Analysis, Joseph & Pandya 1986 \[1\] \
+/// Release jitter, Audsley et al. 1993 \[2\]
+///
+/// Refer to the [module](`self`) level documentation.
+///
+/// Returns:
+/// - Worst-Case Response Times of each task, measured from the job's arrival.
+pub struct AnalysisJitter;
+
+impl SchedAnalysis<Vec<Time>, &[JitteredTask]> for AnalysisJitter {
+    fn analyzer_name(&self) -> &str { ALGORITHM }
+
+    fn check_preconditions(&self, taskset: &&[JitteredTask]) -> Result<(), SchedError> {
+        let (tasks, _) = split_jittered_taskset(taskset);
+        check_preconditions_constrained(&tasks)?;
+
+        if taskset.iter().any(|task| task.release_jitter < Time::zero()) {
             Err(SchedError::Precondition(Some(
-                anyhow::format_err!("average processing load is not met."))))
+                anyhow::format_err!("release jitters must be non-negative."))))
+        } else {
+            Ok(())
+        }
+    }
+
+    fn run_test(&self, taskset: &[JitteredTask]) -> Result<Vec<Time>, SchedError> {
+        let (tasks, jitters) = split_jittered_taskset(taskset);
+
+        response_times_with_jitter(&tasks, &jitters)
+    }
+}
+
+fn split_jittered_taskset(taskset: &[JitteredTask]) -> (Vec<RTTask>, Vec<Time>) {
+    taskset.iter()
+        .map(|task| (task.task.clone(), task.release_jitter))
+        .unzip()
+}
+
+/// Response Time Analysis, Joseph & Pandya 1986 \[1\] \
+/// Blocking on shared resources, Audsley et al. 1993 \[2\]
+///
+/// Refer to the [module](`self`) level documentation.
+///
+/// The `blocking` times are the longest intervals during which a job of each
+/// task may wait for lower priority tasks, e.g. holding the resources it
+/// needs, as bounded by the resource access protocol:
+///   R_i = C_i + B_i + sum_(j < i) ceil(R_i / T_j) C_j
+///
+/// Returns:
+/// - Worst-Case Response Times of each task.
+pub struct AnalysisBlocking {
+    pub blocking: Vec<Time>,
+}
+
+impl SchedAnalysis<Vec<Time>, &[RTTask]> for AnalysisBlocking {
+    fn analyzer_name(&self) -> &str { ALGORITHM }
+
+    fn check_preconditions(&self, taskset: &&[RTTask]) -> Result<(), SchedError> {
+        check_preconditions_constrained(taskset)?;
+
+        if self.blocking.len() != taskset.len() {
+            Err(SchedError::Precondition(Some(
+                anyhow::format_err!("the blocking time of every task must be given."))))
+        } else if self.blocking.iter().any(|blocking| *blocking < Time::zero()) {
+            Err(SchedError::Precondition(Some(
+                anyhow::format_err!("blocking times must be non-negative."))))
         } else {
             Ok(())
         }
@@ -45,7 +318,119 @@ impl SchedAnalysis<Vec<Time>, &[RTTask]> for Analysis {
     fn run_test(&self, taskset: &[RTTask]) -> Result<Vec<Time>, SchedError> {
         taskset.iter().enumerate()
             .map(|(i, task)| {
-                let response_time = response_time(&taskset[0..=i]);
+                let response_time = response_time_with_blocking(&taskset[0..=i], self.blocking[i]);
+
+                if response_time > task.deadline {
+                    Err(SchedError::NonSchedulable(Some(
+                        anyhow::format_err!("task {i} misses its deadline, blocked for up to {}ns.", self.blocking[i].as_nanos())
+                    )))
+                } else {
+                    Ok(response_time)
+                }
+            })
+            .collect()
+    }
+}
+
+impl SchedProcessors for AnalysisBlocking {
+    fn num_processors(&self) -> u64 { 1 }
+}
+
+/// Response Time Analysis, Joseph & Pandya 1986 \[1\] \
+/// Scheduling overheads as explicit terms, Burns, Tindell & Wellings 1995 \[9\]
+///
+/// Refer to the [module](`self`) level documentation.
+///
+/// Each job is charged two context switches, and the periodic interrupt
+/// interferes as the highest priority task:
+///   R_i = C_i + 2 cs + ceil(R_i / T_irq) O_irq + sum_(j < i) ceil(R_i / T_j) (C_j + 2 cs)
+///
+/// Returns:
+/// - Worst-Case Response Times of each task.
+pub struct AnalysisOverheads {
+    pub overheads: SchedulingOverheads,
+}
+
+impl SchedAnalysis<Vec<Time>, &[RTTask]> for AnalysisOverheads {
+    fn analyzer_name(&self) -> &str { ALGORITHM }
+
+    fn check_preconditions(&self, taskset: &&[RTTask]) -> Result<(), SchedError> {
+        self.overheads.check_preconditions()?;
+        check_preconditions_constrained(&self.interfered_taskset(taskset))
+    }
+
+    fn run_test(&self, taskset: &[RTTask]) -> Result<Vec<Time>, SchedError> {
+        let interfered = self.interfered_taskset(taskset);
+        let first = interfered.len() - taskset.len();
+
+        let response_times = response_times_until_miss(&interfered, &[]);
+        match response_times.iter().zip(&interfered).skip(first).position(|(response_time, task)| *response_time > task.deadline) {
+            Some(i) => Err(SchedError::NonSchedulable(Some(
+                anyhow::format_err!("task {i} misses its deadline.")
+            ))),
+            None => Ok(response_times[first..].to_vec()),
+        }
+    }
+}
+
+impl AnalysisOverheads {
+    // The interrupt task, if any, followed by the tasks with the context
+    // switches added to their WCETs.
+    fn interfered_taskset(&self, taskset: &[RTTask]) -> Vec<RTTask> {
+        self.overheads.interrupt_task().into_iter()
+            .chain(taskset.iter().map(|task| RTTask {
+                wcet: self.overheads.with_context_switches(task.wcet),
+                deadline: task.deadline,
+                period: task.period,
+            }))
+            .collect()
+    }
+}
+
+impl SchedProcessors for AnalysisOverheads {
+    fn num_processors(&self) -> u64 { 1 }
+}
+
+/// Response Time Analysis, Joseph & Pandya 1986 \[1\] \
+/// Round-robin groups of equal priority tasks, Migge et al. 2003 \[8\]
+///
+/// Refer to the [module](`self`) level documentation.
+///
+/// The `priorities` of the tasks are non-increasing (higher values are higher
+/// priorities, as in POSIX), and the tasks sharing a priority run round-robin,
+/// each for up to a `quantum` at a time.
+///
+/// Returns:
+/// - Worst-Case Response Times of each task.
+pub struct AnalysisRoundRobin {
+    pub priorities: Vec<u64>,
+    pub quantum: Time,
+}
+
+impl SchedAnalysis<Vec<Time>, &[RTTask]> for AnalysisRoundRobin {
+    fn analyzer_name(&self) -> &str { ALGORITHM }
+
+    fn check_preconditions(&self, taskset: &&[RTTask]) -> Result<(), SchedError> {
+        check_preconditions_constrained(taskset)?;
+
+        if self.priorities.len() != taskset.len() {
+            Err(SchedError::Precondition(Some(
+                anyhow::format_err!("the priority of every task must be given."))))
+        } else if self.priorities.windows(2).any(|pair| pair[0] < pair[1]) {
+            Err(SchedError::Precondition(Some(
+                anyhow::format_err!("tasks must be sorted by priority, highest first."))))
+        } else if self.quantum <= Time::zero() {
+            Err(SchedError::Precondition(Some(
+                anyhow::format_err!("the round-robin quantum must be positive."))))
+        } else {
+            Ok(())
+        }
+    }
+
+    fn run_test(&self, taskset: &[RTTask]) -> Result<Vec<Time>, SchedError> {
+        taskset.iter().enumerate()
+            .map(|(i, task)| {
+                let response_time = response_time_round_robin(taskset, &self.priorities, self.quantum, i);
 
                 if response_time > task.deadline {
                     Err(SchedError::NonSchedulable(Some(
@@ -59,40 +444,367 @@ impl SchedAnalysis<Vec<Time>, &[RTTask]> for Analysis {
     }
 }
 
+/// Response Time Analysis, Joseph & Pandya 1986 \[1\] \
+/// Response times bracketed by cheap bounds, for fast triage of large tasksets.
+///
+/// Refer to the [module](`self`) level documentation.
+///
+/// Returns:
+/// - Lower and upper bounds on the Worst-Case Response Time of each task, which
+///   coincide for the tasks whose response time has been computed.
+pub struct AnalysisBracketing;
+
+/// Lower and upper bounds on a Worst-Case Response Time.
+#[derive(Debug, Clone, Copy)]
+#[derive(PartialEq)]
+pub struct ResponseBracket {
+    pub lower: Time,
+    pub upper: Time,
+}
+
+impl ResponseBracket {
+    pub fn is_exact(&self) -> bool {
+        self.lower == self.upper
+    }
+}
+
+impl SchedAnalysis<Vec<ResponseBracket>, &[RTTask]> for AnalysisBracketing {
+    fn analyzer_name(&self) -> &str { ALGORITHM }
+
+    // The load condition is checked on the utilization rather than on the
+    // hyperperiod, which overflows for large tasksets.
+    fn check_preconditions(&self, taskset: &&[RTTask]) -> Result<(), SchedError> {
+        if !RTUtils::constrained_deadlines(taskset) {
+            Err(SchedError::constrained_deadlines())
+        } else if RTUtils::total_utilization(taskset) > 1.0 {
+            Err(SchedError::Precondition(Some(
+                anyhow::format_err!("average processing load is not met."))))
+        } else {
+            Ok(())
+        }
+    }
+
+    fn run_test(&self, taskset: &[RTTask]) -> Result<Vec<ResponseBracket>, SchedError> {
+        let mut hp_wcets = Time::zero();
+        let mut hp_utilization = 0.0;
+        let mut hp_linear_demand = Time::zero();
+
+        taskset.iter().enumerate()
+            .map(|(i, task)| {
+                let lower = Time::max(
+                    // Section 4 [4]: every higher priority job released at the
+                    // critical instant completes before the task.
+                    task.wcet + hp_wcets,
+                    // Section 4 [5]
+                    if hp_utilization < 1.0 { Time::floor(task.wcet / (1.0 - hp_utilization)) } else { task.wcet },
+                );
+
+                // Theorem 3 [6], rounded up to the time quantum.
+                let upper =
+                    if hp_utilization < 1.0 {
+                        Some(Time::ceil((task.wcet + hp_linear_demand) / (1.0 - hp_utilization)))
+                    } else {
+                        None
+                    };
+
+                hp_wcets = hp_wcets + task.wcet;
+                hp_utilization += task.utilization();
+                hp_linear_demand = hp_linear_demand + task.wcet * (1.0 - task.utilization());
+
+                let bracket =
+                    match upper {
+                        _ if lower > task.deadline =>
+                            ResponseBracket { lower, upper: upper.unwrap_or(lower) },
+                        Some(upper) if upper <= task.deadline =>
+                            ResponseBracket { lower, upper },
+                        _ => {
+                            let response = response_time_from(&taskset[0..=i], lower);
+                            ResponseBracket { lower: response, upper: response }
+                        },
+                    };
+
+                if bracket.lower > task.deadline {
+                    Err(SchedError::NonSchedulable(Some(
+                        anyhow::format_err!("task {i} misses its deadline.")
+                    )))
+                } else {
+                    Ok(bracket)
+                }
+            })
+            .collect()
+    }
+}
+
+fn check_preconditions_constrained(taskset: &[RTTask]) -> Result<(), SchedError> {
+    if !RTUtils::constrained_deadlines(taskset) {
+        Err(SchedError::constrained_deadlines())
+    } else if !avg_processing_load_is_met(taskset) {
+        Err(SchedError::Precondition(Some(
+            anyhow::format_err!("average processing load is not met."))))
+    } else {
+        Ok(())
+    }
+}
+
 // Condition 4 [1]
 fn avg_processing_load_is_met(taskset: &[RTTask]) -> bool {
     let hyperperiod = RTUtils::hyperperiod(taskset);
 
-    required_resources_over_interval(taskset, hyperperiod) < hyperperiod
+    // Function 3 + Function 2 [1]
+    dbf::total_rbf(taskset, hyperperiod) < hyperperiod
 }
 
-// Function 3 + Function 2 [1]
-fn required_resources_over_interval(taskset: &[RTTask], interval: Time) -> Time {
-    taskset.iter()
-        .map(|task| f64::ceil(interval / task.period) * task.wcet)
-        .sum()
+fn response_times(taskset: &[RTTask], initial_response_times: &[Time]) -> Result<Vec<Time>, SchedError> {
+    let response_times = response_times_until_miss(taskset, initial_response_times);
+
+    match response_times.iter().zip(taskset).position(|(response_time, task)| *response_time > task.deadline) {
+        Some(i) => Err(SchedError::NonSchedulable(Some(
+            anyhow::format_err!("task {i} misses its deadline.")
+        ))),
+        None => Ok(response_times),
+    }
 }
 
-// Equation 6 + Function 5 [1]
-fn response_time(taskset: &[RTTask]) -> Time {
-    if taskset.is_empty() {
-        return Time::zero();
+// Response times of the tasks, up to and including the first one which misses
+// its deadline.
+fn response_times_until_miss(taskset: &[RTTask], initial_response_times: &[Time]) -> Vec<Time> {
+    let mut response_times = Vec::with_capacity(taskset.len());
+
+    for (i, task) in taskset.iter().enumerate() {
+        let previous_response = response_times.last().copied().unwrap_or(Time::zero());
+        let initial_response = Time::max(
+            initial_response_time(&taskset[0..=i], previous_response),
+            warm_start_response_time(initial_response_times, i, task),
+        );
+
+        let response_time = response_time_from(&taskset[0..=i], initial_response);
+        response_times.push(response_time);
+
+        if response_time > task.deadline {
+            break;
+        }
     }
 
+    response_times
+}
+
+// Equation 6 + Function 5 [1], in exact integer arithmetic (nanoseconds). The
+// iterations stop at the deadline plus one: past the deadline, only the
+// verdict matters.
+fn exact_response_time(hp_tasks: &[(u64, u64, u64)], wcet: u64, deadline: u64) -> u64 {
+    let mut response = wcet as u128;
+
+    loop {
+        let new_response = wcet as u128 + hp_tasks.iter()
+            .map(|&(wcet_j, _, period_j)| response.div_ceil(period_j as u128) * wcet_j as u128)
+            .sum::<u128>();
+
+        if new_response > deadline as u128 {
+            return deadline + 1;
+        } else if new_response == response {
+            return new_response as u64;
+        }
+
+        response = new_response;
+    }
+}
+
+// Lower bound on the response time of the lowest priority task, given the
+// response time of the task immediately above it.
+// - Section 4 [4]: the processor is busy with higher priority work until the
+//   previous task completes, thus R_i >= R_{i-1} + C_i.
+// - Section 4 [5]: since ceil(t / T_j) >= t / T_j, the fixpoint satisfies
+//   R_i >= C_i + R_i * U_hp, thus R_i >= C_i / (1 - U_hp).
+// The utilization bound is rounded down to the time quantum: computed in
+// floating point, it may otherwise exceed the fixpoint by a rounding error.
+fn initial_response_time(taskset: &[RTTask], previous_response: Time) -> Time {
+    let task = taskset.last().unwrap();
+    let hp_utilization = RTUtils::total_utilization(&taskset[0..taskset.len() - 1]);
+
+    let utilization_bound =
+        if hp_utilization < 1.0 {
+            Time::floor(task.wcet / (1.0 - hp_utilization))
+        } else {
+            task.wcet
+        };
+
+    Time::max(previous_response + task.wcet, utilization_bound)
+}
+
+// Equation 6 + Function 5 [1]
+// The iteration converges to the least fixpoint from any initial value which
+// is not larger than it.
+fn response_time_from(taskset: &[RTTask], initial_response: Time) -> Time {
+    response_time_iterations(taskset, initial_response).0
+}
+
+// Returns the fixpoint and the number of evaluations of the fixpoint equation.
+fn response_time_iterations(taskset: &[RTTask], initial_response: Time) -> (Time, u64) {
     let task = taskset.last().unwrap();
     let hp_tasks = &taskset[0..taskset.len() - 1];
 
-    let mut response = task.wcet;
+    let mut response = initial_response;
+    let mut iterations = 1;
     loop {
-        let new_response = required_resources_over_interval(hp_tasks, response) + task.wcet;
+        let new_response = dbf::total_rbf(hp_tasks, response) + task.wcet;
         if new_response == response {
-            return response;
+            return (response, iterations);
         }
 
         response = new_response;
+        iterations += 1;
     }
 }
 
+fn response_times_with_jitter(taskset: &[RTTask], jitters: &[Time]) -> Result<Vec<Time>, SchedError> {
+    taskset.iter().enumerate()
+        .map(|(i, task)| {
+            let response_time = response_time_with_jitter(&taskset[0..=i], &jitters[0..=i]);
+
+            if response_time > task.deadline {
+                Err(SchedError::NonSchedulable(Some(
+                    anyhow::format_err!("task {i} misses its deadline.")
+                )))
+            } else {
+                Ok(response_time)
+            }
+        })
+        .collect()
+}
+
+// Section 3 [2]: the jobs of the higher priority tasks released with their
+// largest jitter interfere as if they arrived earlier:
+//   w = C_i + sum_(j < i) ceil((w + J_j) / T_j) C_j
+// and the response time from the arrival is w + J_i. The busy window is
+// stopped as soon as it exceeds the deadline minus the jitter, in which case
+// the returned response time is greater than the deadline.
+fn response_time_with_jitter(taskset: &[RTTask], jitters: &[Time]) -> Time {
+    let task = taskset.last().unwrap();
+    let hp_tasks = &taskset[0..taskset.len() - 1];
+    let jitter = *jitters.last().unwrap();
+
+    let busy_window =
+        fixpoint_search_with_limit(
+            task.wcet,
+            task.deadline - jitter + Time::one(),
+            |window: &Time| {
+                let interference: Time =
+                    hp_tasks.iter().zip(jitters)
+                        .map(|(task_j, jitter_j)| dbf::rbf_jitter(task_j, *jitter_j, *window))
+                        .sum();
+
+                interference + task.wcet
+            }
+        );
+
+    busy_window + jitter
+}
+
+// Section 3 [2]: a job is blocked by the lower priority tasks at most once, for
+// at most B_i, thus the blocking adds to its own execution:
+//   R_i = C_i + B_i + sum_(j < i) ceil(R_i / T_j) C_j
+// The iterations stop past the deadline.
+fn response_time_with_blocking(taskset: &[RTTask], blocking: Time) -> Time {
+    let task = taskset.last().unwrap();
+    let hp_tasks = &taskset[0..taskset.len() - 1];
+
+    fixpoint_search_with_limit(
+        task.wcet + blocking,
+        task.deadline + Time::one(),
+        |response: &Time| dbf::total_rbf(hp_tasks, *response) + task.wcet + blocking
+    )
+}
+
+// Section 4 [8]: a job needs ceil(C_i / q) quanta, and before each of them
+// every other task at the same priority runs for at most one quantum. Thus,
+// besides the usual interference of the higher priority tasks, each task of
+// the round-robin group interferes for at most min(rbf_j(w), ceil(C_i / q) q):
+//   w = C_i + sum_(hp j) rbf_j(w) + sum_(rr j) min(rbf_j(w), ceil(C_i / q) q)
+// Lower priority tasks never interfere. The iterations stop past the deadline.
+fn response_time_round_robin(taskset: &[RTTask], priorities: &[u64], quantum: Time, i: usize) -> Time {
+    let task = &taskset[i];
+    let round_robin_budget = quantum * (task.wcet / quantum).ceil();
+
+    fixpoint_search_with_limit(
+        task.wcet,
+        task.deadline + Time::one(),
+        |window: &Time| {
+            let interference: Time =
+                taskset.iter().zip(priorities).enumerate()
+                    .filter(|(j, _)| *j != i)
+                    .map(|(_, (task_j, priority_j))| {
+                        if *priority_j > priorities[i] {
+                            dbf::rbf(task_j, *window)
+                        } else if *priority_j == priorities[i] {
+                            Time::min(dbf::rbf(task_j, *window), round_robin_budget)
+                        } else {
+                            Time::zero()
+                        }
+                    })
+                    .sum();
+
+            interference + task.wcet
+        }
+    )
+}
+
+// Section 3 [7]: with arbitrary deadlines, the jobs of a task may be delayed
+// by its own previous jobs. The worst-case response time is the largest among
+// the jobs released in the level-i busy period which starts at the critical
+// instant. The q-th job (from zero) completes at the least fixpoint of:
+//   w = (q + 1) C_i + sum_(j < i) ceil(w / T_j) C_j
+// and responds in w - q T_i.
+fn busy_period_response_times(taskset: &[RTTask]) -> Result<Vec<Time>, SchedError> {
+    taskset.iter().enumerate()
+        .map(|(i, task)| {
+            let level_tasks = &taskset[0..=i];
+            let hp_tasks = &taskset[0..i];
+
+            // The level-i busy period is bounded if the level-i utilization
+            // is less than one, otherwise it ends within the hyperperiod.
+            let utilization = RTUtils::total_utilization(level_tasks);
+            let busy_period_bound =
+                if utilization < 1.0 {
+                    (level_tasks.iter().map(|task| task.wcet).sum::<Time>() / (1.0 - utilization)).ceil()
+                } else {
+                    RTUtils::hyperperiod(level_tasks)
+                };
+
+            let busy_period = fixpoint_search_with_limit(
+                task.wcet,
+                busy_period_bound,
+                |busy_period: &Time| dbf::total_rbf(level_tasks, *busy_period)
+            );
+
+            let mut response_time = Time::zero();
+            let mut completion = Time::zero();
+            let mut job = 0.0;
+
+            while task.period * job < busy_period {
+                let deadline = task.period * job + task.deadline;
+
+                // The q-th job completes at least C_i after the previous one.
+                completion = fixpoint_search_with_limit(
+                    completion + task.wcet,
+                    deadline + Time::one(),
+                    |completion: &Time| task.wcet * (job + 1.0) + dbf::total_rbf(hp_tasks, *completion)
+                );
+
+                if completion > deadline {
+                    return Err(SchedError::NonSchedulable(Some(
+                        anyhow::format_err!("job {job} of task {i} misses its deadline.")
+                    )));
+                }
+
+                response_time = Time::max(response_time, completion - task.period * job);
+                job += 1.0;
+            }
+
+            Ok(response_time)
+        })
+        .collect()
+}
+
 #[test]
 // Example 2 [1]
 fn example_2() {
@@ -104,15 +816,387 @@ fn example_2() {
         RTTask::new_ns(1, 1000, 1000),
     ];
 
-    assert_eq!(response_time(&taskset[0..=0]), Time::nanos(40.0));
-    assert_eq!(response_time(&taskset[0..=1]), Time::nanos(100.0));
+    assert_eq!(response_time_from(&taskset[0..=0], taskset[0].wcet), Time::nanos(40.0));
+    assert_eq!(response_time_from(&taskset[0..=1], taskset[1].wcet), Time::nanos(100.0));
 
     // Response Times (computed with this algorithm) greater than the period are
     // not the Worst Case Response Times of the given task, as we do not account
     // for self-interference.
-    assert_eq!(response_time(&taskset[0..=2]), Time::nanos(560.0));
-    assert_eq!(response_time(&taskset[0..=3]), Time::nanos(2490.0));
-    assert_eq!(response_time(&taskset[0..=4]), Time::nanos(6991.0));
+    assert_eq!(response_time_from(&taskset[0..=2], taskset[2].wcet), Time::nanos(560.0));
+    assert_eq!(response_time_from(&taskset[0..=3], taskset[3].wcet), Time::nanos(2490.0));
+    assert_eq!(response_time_from(&taskset[0..=4], taskset[4].wcet), Time::nanos(6991.0));
 
     assert!(Analysis.is_schedulable(&taskset).is_err());
-}
\ No newline at end of file
+}
+
+#[test]
+fn arbitrary_deadlines() {
+    use crate::utils::test_tasksets::random_taskset;
+
+    // The level-2 busy period lasts 694: the fifth job of task 1 has the
+    // longest response time, (5 * 62 + 8 * 26) - 4 * 100.
+    let taskset = |deadline| [
+        RTTask::new_ns(26, 70, 70),
+        RTTask::new_ns(62, deadline, 100),
+    ];
+
+    assert_eq!(Analysis.is_schedulable(&taskset(120)).unwrap(), vec![Time::nanos(26.0), Time::nanos(118.0)]);
+    assert_eq!(Analysis.is_schedulable(&taskset(118)).unwrap(), vec![Time::nanos(26.0), Time::nanos(118.0)]);
+    assert!(Analysis.is_schedulable(&taskset(117)).is_err());
+
+    // The variants require constrained deadlines.
+    assert!(AnalysisWarmStart { initial_response_times: vec![] }.is_schedulable(&taskset(120)).is_err());
+
+    // With constrained deadlines, the busy periods give the same response times.
+    for seed in 0..20 {
+        let taskset = random_taskset(8, 0.9, seed);
+
+        match (response_times(&taskset, &[]), busy_period_response_times(&taskset)) {
+            (Ok(response_times), Ok(busy_period_response_times)) =>
+                assert_eq!(response_times, busy_period_response_times),
+            (Err(_), Err(_)) => {},
+            _ => panic!("the busy period analysis disagrees with the RTA"),
+        }
+    }
+}
+
+#[test]
+fn kernel_latencies() {
+    let taskset = [
+        RTTask::new_ns(40, 100, 100),
+        RTTask::new_ns(20, 100, 100),
+    ];
+
+    let latencies = KernelLatencies {
+        irq_to_release: Time::nanos(30.0),
+        scheduling: Time::nanos(10.0),
+    };
+
+    // Task 1: w = 20 + ceil((w + 40) / 100) * 40 = 60, R = w + J
+    let response_times =
+        AnalysisKernelLatencies { latencies }.is_schedulable(&taskset).unwrap();
+    assert_eq!(response_times, vec![Time::nanos(80.0), Time::nanos(100.0)]);
+
+    // Task 1: w = 30 + ceil(w / 100) * 80 = 110 > 100, it misses its
+    // deadline even without latencies.
+    let taskset = [
+        RTTask::new_ns(80, 100, 100),
+        RTTask::new_ns(30, 100, 200),
+    ];
+
+    let latencies = KernelLatencies::zero();
+    assert!(AnalysisKernelLatencies { latencies }.is_schedulable(&taskset).is_err());
+    assert!(Analysis.is_schedulable(&taskset).is_err());
+}
+
+#[test]
+fn scheduling_overheads() {
+    let taskset = [
+        RTTask::new_ns(20, 50, 50),
+        RTTask::new_ns(30, 100, 100),
+    ];
+
+    let overheads = SchedulingOverheads {
+        context_switch: Time::nanos(1.0),
+        interrupt_period: Time::nanos(10.0),
+        interrupt_overhead: Time::nanos(1.0),
+    };
+
+    // Task 0: R = 22 + ceil(R / 10) = 25
+    // Task 1: R = 32 + ceil(R / 10) + ceil(R / 50) * 22 = 85
+    let response_times = AnalysisOverheads { overheads: overheads.clone() }.is_schedulable(&taskset).unwrap();
+    assert_eq!(response_times, vec![Time::nanos(25.0), Time::nanos(85.0)]);
+
+    // The inflation is more pessimistic: ceil((22 + 1) / 0.9) = 26 and
+    // ceil((32 + 1) / 0.9) = 37, thus R_1 = 37 + 2 * 26 = 89.
+    let inflated = WithSchedulingOverheads { overheads: overheads.clone(), analysis: Analysis };
+    assert_eq!(inflated.is_schedulable(&taskset).unwrap(), vec![Time::nanos(26.0), Time::nanos(89.0)]);
+
+    // Task 1: R = 36 + ceil(R / 10) + ceil(R / 50) * 26 = 98
+    let analysis = AnalysisOverheads { overheads: SchedulingOverheads { context_switch: Time::nanos(3.0), ..overheads } };
+    assert_eq!(analysis.is_schedulable(&taskset).unwrap()[1], Time::nanos(98.0));
+
+    let err = analysis.is_schedulable(&[taskset[0].clone(), RTTask::new_ns(30, 95, 100)]).unwrap_err();
+    assert!(format!("{err:#}").contains("task 1"));
+
+    let overheads = SchedulingOverheads { interrupt_overhead: Time::nanos(10.0), ..SchedulingOverheads::zero() };
+    assert!(AnalysisOverheads { overheads }.is_schedulable(&taskset).is_err());
+    assert_eq!(AnalysisOverheads { overheads: SchedulingOverheads::zero() }.is_schedulable(&taskset).unwrap(),
+        Analysis.is_schedulable(&taskset).unwrap());
+}
+
+#[test]
+fn release_jitter() {
+    let taskset = [
+        RTTask::new_ns(4, 10, 10),
+        RTTask::new_ns(3, 12, 12),
+    ];
+
+    let jittered = |jitter_1| vec![
+        JitteredTask { task: taskset[0].clone(), release_jitter: Time::nanos(5.0) },
+        JitteredTask { task: taskset[1].clone(), release_jitter: Time::nanos(jitter_1) },
+    ];
+
+    // Task 1: w = 3 + ceil((w + 5) / 10) * 4 = 11, R = w + J_1
+    let response_times = AnalysisJitter.is_schedulable(&jittered(1.0)).unwrap();
+    assert_eq!(response_times, vec![Time::nanos(9.0), Time::nanos(12.0)]);
+
+    assert!(AnalysisJitter.is_schedulable(&jittered(2.0)).is_err());
+    assert!(AnalysisJitter.is_schedulable(&jittered(-1.0)).is_err());
+
+    // Without jitter, the plain RTA.
+    let unjittered: Vec<_> = taskset.iter().cloned().map(JitteredTask::new).collect();
+    let response_times = AnalysisJitter.is_schedulable(&unjittered).unwrap();
+    assert_eq!(response_times, Analysis.is_schedulable(&taskset).unwrap());
+}
+
+#[test]
+fn blocking() {
+    let taskset = [
+        RTTask::new_ns(4, 10, 10),
+        RTTask::new_ns(3, 12, 12),
+        RTTask::new_ns(2, 20, 20),
+    ];
+
+    let blocking = |blocking: &[f64]| AnalysisBlocking { blocking: blocking.iter().map(|b| Time::nanos(*b)).collect() };
+
+    // Task 1: R = 3 + 1 + ceil(R / 10) * 4 = 8. The lowest priority task is
+    // never blocked.
+    assert_eq!(blocking(&[2.0, 1.0, 0.0]).is_schedulable(&taskset[..]).unwrap(),
+        vec![Time::nanos(6.0), Time::nanos(8.0), Time::nanos(9.0)]);
+
+    // Task 1: R = 3 + 5 + ceil(R / 10) * 4 exceeds its deadline.
+    let err = blocking(&[2.0, 5.0, 0.0]).is_schedulable(&taskset[..]).unwrap_err();
+    assert!(format!("{err:#}").contains("task 1 misses its deadline"));
+
+    assert!(blocking(&[2.0, 1.0]).is_schedulable(&taskset[..]).is_err());
+    assert!(blocking(&[2.0, -1.0, 0.0]).is_schedulable(&taskset[..]).is_err());
+
+    // Without blocking, the plain RTA.
+    assert_eq!(blocking(&[0.0; 3]).is_schedulable(&taskset[..]).unwrap(), Analysis.is_schedulable(&taskset[..]).unwrap());
+}
+
+#[test]
+fn round_robin() {
+    let taskset = [
+        RTTask::new_ns(1, 10, 10),
+        RTTask::new_ns(1, 4, 20),
+        RTTask::new_ns(6, 20, 20),
+    ];
+
+    let analysis = |priorities: Vec<u64>, quantum| AnalysisRoundRobin { priorities, quantum: Time::nanos(quantum) };
+
+    // Task 1 waits for at most one quantum of task 2, which would otherwise
+    // delay it for its whole WCET.
+    assert_eq!(
+        analysis(vec![2, 1, 1], 1.0).is_schedulable(&taskset).unwrap(),
+        vec![Time::nanos(1.0), Time::nanos(3.0), Time::nanos(8.0)]
+    );
+    assert!(analysis(vec![2, 1, 1], 6.0).is_schedulable(&taskset).is_err());
+
+    // Distinct priorities give the plain RTA.
+    assert_eq!(
+        analysis(vec![3, 2, 1], 1.0).is_schedulable(&taskset).unwrap(),
+        Analysis.is_schedulable(&taskset).unwrap()
+    );
+
+    assert!(analysis(vec![1, 1, 2], 1.0).is_schedulable(&taskset).is_err());
+    assert!(analysis(vec![2, 1, 1], 0.0).is_schedulable(&taskset).is_err());
+}
+
+#[test]
+fn warm_start() {
+    let taskset = [
+        RTTask::new_ns(40, 100, 100),
+        RTTask::new_ns(40, 150, 150),
+        RTTask::new_ns(20, 350, 350),
+    ];
+
+    let response_times = Analysis.is_schedulable(&taskset).unwrap();
+
+    // Increase the WCET of the last task: previous results are lower bounds.
+    let new_taskset = [
+        RTTask::new_ns(40, 100, 100),
+        RTTask::new_ns(40, 150, 150),
+        RTTask::new_ns(30, 350, 350),
+    ];
+
+    let analysis = AnalysisWarmStart { initial_response_times: response_times };
+    assert_eq!(
+        analysis.is_schedulable(&new_taskset).unwrap(),
+        Analysis.is_schedulable(&new_taskset).unwrap(),
+    );
+}
+
+#[test]
+fn bracketing() {
+    let taskset = |deadline| [
+        RTTask::new_ns(1, 4, 4),
+        RTTask::new_ns(2, 6, 6),
+        RTTask::new_ns(3, deadline, 20),
+    ];
+
+    // Task 2: C + sum(C_hp) = 6, C / (1 - U_hp) = 7.2, the linear bound is
+    // (3 + 1 * 0.75 + 2 * 0.67) / 0.42 = 12.2, while R = 10.
+    let brackets = AnalysisBracketing.is_schedulable(&taskset(20)).unwrap();
+    assert_eq!(brackets, vec![
+        ResponseBracket { lower: Time::nanos(1.0), upper: Time::nanos(1.0) },
+        ResponseBracket { lower: Time::nanos(3.0), upper: Time::nanos(4.0) },
+        ResponseBracket { lower: Time::nanos(7.0), upper: Time::nanos(13.0) },
+    ]);
+
+    let response_times = Analysis.is_schedulable(&taskset(20)).unwrap();
+    assert!(brackets.iter().zip(&response_times).all(|(bracket, response)|
+        bracket.lower <= *response && *response <= bracket.upper));
+
+    // The brackets do not decide, the response time is computed.
+    let brackets = AnalysisBracketing.is_schedulable(&taskset(11)).unwrap();
+    assert_eq!(brackets[2], ResponseBracket { lower: Time::nanos(10.0), upper: Time::nanos(10.0) });
+    assert!(brackets[2].is_exact());
+
+    assert!(AnalysisBracketing.is_schedulable(&taskset(8)).is_err());
+
+    // The lower bound alone decides.
+    assert!(AnalysisBracketing.is_schedulable(&taskset(6)).is_err());
+}
+
+#[test]
+fn bracketing_random() {
+    use crate::utils::test_tasksets::random_taskset;
+
+    for seed in 0..50 {
+        let taskset = random_taskset(10, 0.9, seed);
+
+        // The plain analysis checks its preconditions on the hyperperiod of the
+        // random periods, which overflows: its response times are computed
+        // directly.
+        match (Analysis.run_test(&taskset), AnalysisBracketing.is_schedulable(&taskset)) {
+            (Ok(response_times), Ok(brackets)) =>
+                assert!(brackets.iter().zip(&response_times).all(|(bracket, response)|
+                    bracket.lower <= *response && *response <= bracket.upper)),
+            (Err(_), Err(_)) => {},
+            _ => panic!("the verdicts of the bracketing analysis differ, seed {seed}"),
+        }
+    }
+}
+
+#[test]
+fn initial_values() {
+    let taskset = [
+        RTTask::new_ns(3, 10, 10),
+        RTTask::new_ns(4, 13, 13),
+        RTTask::new_ns(5, 29, 29),
+        RTTask::new_ns(7, 97, 97),
+        RTTask::new_ns(11, 601, 601),
+    ];
+
+    let mut previous_response = Time::zero();
+    let (mut plain_iterations, mut fast_iterations) = (0, 0);
+
+    for i in 0..taskset.len() {
+        let (plain, iterations) = response_time_iterations(&taskset[0..=i], taskset[i].wcet);
+        plain_iterations += iterations;
+
+        let initial = initial_response_time(&taskset[0..=i], previous_response);
+        let (fast, iterations) = response_time_iterations(&taskset[0..=i], initial);
+        fast_iterations += iterations;
+
+        assert!(initial <= plain);
+        assert_eq!(plain, fast);
+        previous_response = fast;
+    }
+
+    assert!(fast_iterations < plain_iterations);
+}
+
+#[test]
+fn validation() {
+    let taskset = [
+        RTTask::new_ns(1, 3, 3),
+        RTTask::new_ns(1, 4, 4),
+        RTTask::new_ns(1, 6, 6),
+    ];
+
+    assert!(Analysis.validate(&taskset).is_empty());
+
+    // Both the fast path and the exact recomputation see the deadline miss.
+    let taskset = [
+        RTTask::new_ns(2, 4, 4),
+        RTTask::new_ns(3, 5, 10),
+        RTTask::new_ns(1, 20, 20),
+    ];
+
+    assert!(Analysis.is_schedulable(&taskset).is_err());
+    assert!(Analysis.validate(&taskset).is_empty());
+}
+
+#[test]
+fn initial_value_rounding() {
+    // C / (1 - U_hp) = 7 / 0.7 evaluates to 10.000000000000002 in floating
+    // point, above the fixpoint R = 10.
+    let taskset = [
+        RTTask::new_ns(1, 10, 10),
+        RTTask::new_ns(2, 10, 10),
+        RTTask::new_ns(7, 10, 10),
+    ];
+
+    let initial = initial_response_time(&taskset, Time::nanos(3.0));
+    assert_eq!(initial, Time::nanos(10.0));
+    assert_eq!(response_time_from(&taskset, initial), Time::nanos(10.0));
+}
+
+#[test]
+fn initial_values_random() {
+    use crate::utils::test_tasksets::random_taskset;
+
+    for seed in 0..50 {
+        let taskset = random_taskset(10, 0.9, seed);
+        let mut previous_response = Time::zero();
+
+        for i in 0..taskset.len() {
+            let plain = response_time_iterations(&taskset[0..=i], taskset[i].wcet).0;
+            let initial = initial_response_time(&taskset[0..=i], previous_response);
+
+            assert!(initial <= plain);
+            assert_eq!(response_time_from(&taskset[0..=i], initial), plain);
+            previous_response = plain;
+        }
+    }
+}
+
+// Initial values of the response time iteration on high utilization tasksets.
+#[test]
+#[ignore]
+fn bench_iterations() {
+    use crate::utils::bench;
+    use crate::utils::test_tasksets::random_taskset;
+
+    let tasksets: Vec<_> = (0..1000).map(|seed| random_taskset(20, 0.98, seed)).collect();
+
+    let iterations = |accelerated: bool| {
+        let mut iterations = 0;
+
+        for taskset in tasksets.iter() {
+            let mut previous_response = Time::zero();
+
+            for i in 0..taskset.len() {
+                let initial =
+                    if accelerated {
+                        initial_response_time(&taskset[0..=i], previous_response)
+                    } else {
+                        taskset[i].wcet
+                    };
+
+                let (response, task_iterations) = response_time_iterations(&taskset[0..=i], initial);
+                iterations += task_iterations;
+                previous_response = response;
+            }
+        }
+
+        iterations
+    };
+
+    let (plain, _) = bench::time("plain iterations", || iterations(false));
+    let (accelerated, _) = bench::time("initial values iterations", || iterations(true));
+    assert!(accelerated < plain);
+}
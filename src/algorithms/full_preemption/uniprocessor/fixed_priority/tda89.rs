@@ -0,0 +1,142 @@
+//! ## Time Demand Analysis - Lehoczky, Sha & Ding 1989
+//!
+//! The workload of task *i* in *\[0, t)*, i.e. its job and the jobs of the
+//! higher priority tasks released before *t*, is
+//!   W_i(t) = C_i + sum_(j < i) ceil(t / T_j) C_j
+//! and the task is schedulable if and only if *W_i(t) <= t* for some *t* in
+//! *(0, D_i]* \[1\]. Since *W_i* only changes at the releases of the higher
+//! priority jobs, it is enough to check the scheduling points
+//!   S_i = { k T_j | j < i, k = 1, ..., floor(D_i / T_j) } ∪ { D_i }
+//! and the task is schedulable if and only if its load
+//!   L_i = min_(t in S_i) W_i(t) / t
+//! is at most one. The original test assumes implicit deadlines, while the
+//! points are the same with constrained deadlines \[2\].
+//!
+//! This is the same condition checked by the [response time
+//! analysis](super::rta86), where the fixpoint iterations find the first *t*
+//! at which the workload fits, rather than scanning the points.
+//!
+//! #### Model:
+//! - Periodic/Sporadic Task model
+//! - Fully-Preemptive Fixed-Priority scheduling
+//!
+//! #### Preconditions:
+//! - Constrained Deadlines
+//!
+//! #### Implements:
+//! - [`Analysis::is_schedulable`] \
+//!   | pseudo-polynomial complexity, *sum_(j < i) floor(D_i / T_j) + 1* points
+//!   | for task *i*
+//! - [`scheduling_points`] \
+//!   | Scheduling points of a task
+//!
+//! ---
+//! #### References:
+//! 1. J. Lehoczky, L. Sha, and Y. Ding, “The rate monotonic scheduling
+//!    algorithm: exact characterization and average case behavior,” in
+//!    Proceedings Real-Time Systems Symposium, Dec. 1989, pp. 166–171,
+//!    doi: 10.1109/REAL.1989.63567.
+//! 2. J. P. Lehoczky, “Fixed priority scheduling of periodic task sets with
+//!    arbitrary deadlines,” in Proceedings 11th Real-Time Systems Symposium,
+//!    Dec. 1990, pp. 201–209, doi: 10.1109/REAL.1990.128748.
+
+use crate::prelude::*;
+use crate::utils::dbf;
+
+const ALGORITHM: &str = "Fixed Priority Time Demand Analysis (Lehoczky, Sha & Ding 1989)";
+
+/// Time Demand Analysis - Lehoczky, Sha & Ding 1989 \[1\]
+///
+/// Refer to the [module](`self`) level documentation.
+///
+/// The tasks must be sorted by priority, highest first.
+///
+/// Returns:
+/// - The load *L_i* of each task, at most one.
+pub struct Analysis;
+
+impl SchedAnalysis<Vec<f64>, &[RTTask]> for Analysis {
+    fn analyzer_name(&self) -> &str { ALGORITHM }
+
+    fn check_preconditions(&self, taskset: &&[RTTask]) -> Result<(), SchedError> {
+        if !RTUtils::constrained_deadlines(taskset) {
+            Err(SchedError::constrained_deadlines())
+        } else {
+            Ok(())
+        }
+    }
+
+    fn run_test(&self, taskset: &[RTTask]) -> Result<Vec<f64>, SchedError> {
+        let mut loads = Vec::with_capacity(taskset.len());
+
+        for (i, task) in taskset.iter().enumerate() {
+            let higher_priority = &taskset[0..i];
+
+            let load = scheduling_points(higher_priority, task.deadline).into_iter()
+                .map(|point| (task.wcet + dbf::total_rbf(higher_priority, point)) / point)
+                .fold(f64::INFINITY, f64::min);
+
+            if load > 1.0 {
+                return Err(SchedError::NonSchedulable(Some(anyhow::format_err!(
+                    "task {i} has a load of {load}, its workload exceeds every scheduling point."))));
+            }
+
+            loads.push(load);
+        }
+
+        Ok(loads)
+    }
+}
+
+impl SchedProcessors for Analysis {
+    fn num_processors(&self) -> u64 { 1 }
+}
+
+impl SchedPlatform for Analysis {
+    fn supports(&self, platform: &Platform) -> bool {
+        platform.matches(PlatformModel::Uniprocessor, SchedulingAlgorithm::FixedPriority, 1)
+    }
+}
+
+impl SchedBounds for Analysis {
+    // Exact test [1]
+    fn speedup_factor(&self) -> Option<f64> { Some(1.0) }
+}
+
+/// Scheduling points of a task with the given deadline and higher priority
+/// tasks, sorted and without duplicates, refer to the [module](`self`) level
+/// documentation.
+pub fn scheduling_points(higher_priority: &[RTTask], deadline: Time) -> Vec<Time> {
+    let mut points: Vec<Time> = higher_priority.iter()
+        .flat_map(|task| (1..=(deadline / task.period).floor() as u64)
+            .map(|k| task.period * k as f64))
+        .chain(std::iter::once(deadline))
+        .collect();
+
+    points.sort();
+    points.dedup();
+    points
+}
+
+#[test]
+fn time_demand_analysis() {
+    use crate::utils::test_tasksets::assert_same_verdict_as_rta;
+
+    let at = |t: u64| Time::nanos(t as f64);
+
+    // Example of [1]: the third task fits exactly at its deadline.
+    let taskset = [RTTask::new_ns(40, 100, 100), RTTask::new_ns(40, 150, 150), RTTask::new_ns(100, 350, 350)];
+    assert_eq!(scheduling_points(&taskset[0..2], at(350)),
+        vec![at(100), at(150), at(200), at(300), at(350)]);
+
+    let loads = Analysis.is_schedulable(&taskset[..]).unwrap();
+    assert_eq!(loads[0], 0.4);
+    assert_eq!(loads[1], 0.8);
+    assert_eq!(loads[2], 1.0);
+
+    let taskset = [RTTask::new_ns(40, 100, 100), RTTask::new_ns(40, 150, 150), RTTask::new_ns(101, 350, 350)];
+    assert!(Analysis.is_schedulable(&taskset[..]).is_err());
+
+    // Same verdict as the response time analysis.
+    assert_same_verdict_as_rta(&Analysis, &[[0, 1, 2]]);
+}
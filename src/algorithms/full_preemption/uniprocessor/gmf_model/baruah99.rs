@@ -0,0 +1,124 @@
+//! ## GMF Tasks, EDF Demand Test - Baruah et al. 1999
+//!
+//! The taskset is schedulable by EDF if and only if the total demand bound
+//! function of the tasks never exceeds the length of the interval \[1\]. Since
+//! the demand of a task is bounded by *U·t* plus the total WCET of its frames,
+//! only the intervals shorter than *ΣC / (1 - U)* need to be checked, at the
+//! absolute deadlines of the frames.
+//!
+//! #### Model:
+//! - Generalized Multiframe Task model (refer to [`GMFTask`])
+//! - Fully-Preemptive EDF scheduling
+//!
+//! #### Preconditions:
+//! - Positive frame periods
+//!
+//! #### Implements:
+//! - [`Analysis::is_schedulable`] \
+//!   | pseudo-polynomial complexity
+//!
+//! ---
+//! #### References:
+//! 1. S. Baruah, D. Chen, S. Gorinsky, and A. Mok, “Generalized Multiframe
+//!    Tasks,” Real-Time Systems, vol. 17, no. 1, pp. 5–22, July 1999,
+//!    doi: 10.1023/A:1008030427220.
+
+use crate::prelude::*;
+use super::*;
+
+const ALGORITHM: &str = "GMF EDF Demand Test (Baruah et al. 1999)";
+
+/// GMF Tasks, EDF Demand Test - Baruah et al. 1999 \[1\]
+///
+/// Refer to the [module](`self`) level documentation.
+pub struct Analysis;
+
+impl SchedAnalysis<(), &[GMFTask]> for Analysis {
+    fn analyzer_name(&self) -> &str { ALGORITHM }
+
+    fn check_preconditions(&self, taskset: &&[GMFTask]) -> Result<(), SchedError> {
+        check_preconditions(taskset)
+    }
+
+    fn run_test(&self, taskset: &[GMFTask]) -> Result<(), SchedError> {
+        let utilization: f64 = taskset.iter().map(GMFTask::utilization).sum();
+
+        if utilization > 1.0 {
+            return Err(SchedError::NonSchedulable(Some(
+                anyhow::format_err!("the utilization of {utilization} exceeds the processor."))));
+        } else if utilization == 1.0 {
+            return Err(SchedError::intractable());
+        }
+
+        let cycle_wcet: Time = taskset.iter().map(GMFTask::cycle_wcet).sum();
+        let max_interval = cycle_wcet / (1.0 - utilization);
+
+        match deadline_points(taskset, max_interval).find(|&t| total_dbf(taskset, t) > t) {
+            Some(t) => Err(SchedError::NonSchedulable(Some(
+                anyhow::format_err!("the demand in an interval of {}ns exceeds its length.", t.as_nanos())))),
+            None => Ok(()),
+        }
+    }
+}
+
+impl SchedProcessors for Analysis {
+    fn num_processors(&self) -> u64 { 1 }
+}
+
+impl SchedPlatform for Analysis {
+    fn supports(&self, platform: &Platform) -> bool {
+        platform.matches(PlatformModel::Uniprocessor, SchedulingAlgorithm::EarliestDeadlineFirst, 1)
+    }
+}
+
+fn total_dbf(taskset: &[GMFTask], interval: Time) -> Time {
+    taskset.iter()
+        .map(|task| task.dbf(interval))
+        .sum()
+}
+
+// Absolute deadlines up to the given instant of the jobs of every task,
+// starting from any of its frames, in increasing order.
+fn deadline_points(taskset: &[GMFTask], end: Time) -> impl Iterator<Item = Time> {
+    let mut points = Vec::new();
+
+    for task in taskset {
+        for first in 0..task.frames.len() {
+            let mut release = Time::zero();
+
+            for frame in task.frames.iter().cycle().skip(first) {
+                if release > end {
+                    break;
+                } else if release + frame.deadline <= end {
+                    points.push(release + frame.deadline);
+                }
+
+                release = release + frame.period;
+            }
+        }
+    }
+
+    points.sort();
+    points.dedup();
+    points.into_iter()
+}
+
+#[test]
+fn gmf_edf() {
+    // A heavy frame followed by a light one, with a sporadic task: the GMF
+    // demand fits, while a sporadic task with the heavy WCET only would not.
+    let mut taskset = vec![
+        GMFTask { frames: vec![RTTask::new_ns(3, 5, 5), RTTask::new_ns(1, 5, 5)] },
+        GMFTask::sporadic(RTTask::new_ns(2, 4, 5)),
+    ];
+
+    assert!(Analysis.is_schedulable(&taskset).is_ok());
+
+    // The heavy frame and the sporadic task overload the first 5ns.
+    taskset[0].frames[0].wcet = Time::nanos(4.0);
+    let err = Analysis.is_schedulable(&taskset).unwrap_err();
+    assert!(format!("{err:#}").contains("5ns"));
+
+    taskset[0].frames[0].period = Time::zero();
+    assert!(Analysis.is_schedulable(&taskset).is_err());
+}
@@ -0,0 +1,93 @@
+//! ## Generalized Multiframe Task Model
+//!
+//! The consecutive jobs of a generalized multiframe (GMF) task cyclically
+//! follow a sequence of frames \[1\]. Each frame has its own WCET and relative
+//! deadline, and its period is the minimum separation between the release of
+//! its job and the release of the next one. Multiframe tasks \[2\], in which
+//! only the WCETs vary, and sporadic tasks, of a single frame, are special
+//! cases of GMF tasks.
+//!
+//! #### Implements:
+//! - [`GMFTask`] \
+//!   | Generalized multiframe task
+//! - [`baruah99`] \
+//!   | Demand based EDF test
+//! - [`takada97`] \
+//!   | Fixed Priority response time analysis
+//!
+//! ---
+//! #### References:
+//! 1. S. Baruah, D. Chen, S. Gorinsky, and A. Mok, “Generalized Multiframe
+//!    Tasks,” Real-Time Systems, vol. 17, no. 1, pp. 5–22, July 1999,
+//!    doi: 10.1023/A:1008030427220.
+//! 2. A. K. Mok and D. Chen, “A multiframe model for real-time tasks,” IEEE
+//!    Transactions on Software Engineering, vol. 23, no. 10, pp. 635–645,
+//!    Oct. 1997, doi: 10.1109/32.637146.
+
+use crate::prelude::*;
+use crate::utils::dbf;
+
+pub mod baruah99;
+pub mod takada97;
+
+/// Generalized multiframe task
+///
+/// Refer to the [module](`self`) level documentation.
+#[derive(Debug, Clone)]
+pub struct GMFTask {
+    /// Frames of the task, in release order. The period of a frame is the
+    /// minimum separation from the release of the next one.
+    pub frames: Vec<RTTask>,
+}
+
+impl GMFTask {
+    /// Sporadic task, as a GMF task of a single frame.
+    pub fn sporadic(task: RTTask) -> Self {
+        Self { frames: vec![task] }
+    }
+
+    /// Minimum separation between two releases of the same frame.
+    pub fn cycle(&self) -> Time {
+        self.frames.iter().map(|frame| frame.period).sum()
+    }
+
+    /// Total WCET of the frames.
+    pub fn cycle_wcet(&self) -> Time {
+        self.frames.iter().map(|frame| frame.wcet).sum()
+    }
+
+    /// Long-run utilization, cycle_wcet / cycle.
+    pub fn utilization(&self) -> f64 {
+        self.cycle_wcet() / self.cycle()
+    }
+
+    /// Demand bound function, refer to [`dbf::dbf_gmf`].
+    pub fn dbf(&self, interval: Time) -> Time {
+        dbf::dbf_gmf(&self.frames, interval)
+    }
+
+    /// Request bound function, refer to [`dbf::rbf_gmf`].
+    pub fn rbf(&self, interval: Time) -> Time {
+        dbf::rbf_gmf(&self.frames, interval)
+    }
+
+    /// Check that the task has frames, all with positive periods.
+    pub fn check_preconditions(&self) -> Result<(), SchedError> {
+        if self.frames.is_empty() {
+            Err(SchedError::Precondition(Some(
+                anyhow::format_err!("a GMF task must have at least one frame."))))
+        } else if self.frames.iter().any(|frame| frame.period <= Time::zero()) {
+            Err(SchedError::Precondition(Some(
+                anyhow::format_err!("the periods of the frames must be positive."))))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Check the preconditions of every task of the taskset.
+pub(crate) fn check_preconditions(taskset: &[GMFTask]) -> Result<(), SchedError> {
+    taskset.iter().enumerate()
+        .try_for_each(|(i, task)| task.check_preconditions()
+            .map_err(|err| err.with_context(&format!("task {i}"))))
+}
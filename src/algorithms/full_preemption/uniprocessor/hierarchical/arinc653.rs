@@ -0,0 +1,371 @@
+//! ## Time-Partitioned Windows (ARINC 653)
+//!
+//! The processor is statically time-partitioned: a schedule, repeated every
+//! major frame, reserves to each partition a set of windows, within which only
+//! the tasks of that partition run \[1\]. The major frame is split in minor
+//! frames of equal length, and the supply of each partition is abstracted by
+//! the [`PRModel`] whose period is the minor frame, and whose budget is the
+//! least execution time reserved to the partition in any minor frame \[2\].
+//! Each partition is then analyzed under its local scheduler on its model.
+//!
+//! The model makes no assumption on the placement of the windows within each
+//! minor frame, thus it is pessimistic for the designed schedules, in which
+//! the windows of a partition are at the same offset in every minor frame:
+//! the longest interval without supply is assumed to be twice the actual one.
+//!
+//! The designer sizes the window of each partition to the smallest multiple
+//! of the time quantum for which the partition is schedulable, and lays out
+//! the windows back to back, in partition order, at the start of each minor
+//! frame.
+//!
+//! #### Model:
+//! - Periodic/Sporadic Task model
+//! - Static partition windows, repeated every major frame
+//! - Fully-Preemptive EDF or Fixed Priority local scheduling (the tasks of
+//!   each partition are sorted by priority, highest first)
+//!
+//! #### Preconditions:
+//! - Every task belongs to exactly one partition
+//! - The major frame is a multiple of the minor frame
+//! - Non-overlapping windows within the major frame
+//! - The preconditions of the local scheduler's test
+//!
+//! #### Implements:
+//! - [`Analysis::is_schedulable`] \
+//!   | pseudo-polynomial complexity
+//! - [`Designer::design`] \
+//!   | Design the window offsets and durations of the partitions. \
+//!   | \
+//!   | O(log(*minor_frame* / *quantum*)) local tests per partition
+//!
+//! ---
+//! #### References:
+//! 1. Aeronautical Radio Inc., “ARINC Specification 653: Avionics Application
+//!    Software Standard Interface, Part 1,” 2019.
+//! 2. Shin and I. Lee, “Periodic resource model for compositional real-time
+//!    guarantees,” in RTSS 2003. 24th IEEE Real-Time Systems Symposium, 2003,
+//!    Dec. 2003, pp. 2–13. doi: 10.1109/REAL.2003.1253249.
+
+use crate::prelude::*;
+use crate::algorithms::full_preemption::reservation_groups::LocalScheduler;
+use super::pr_model03::*;
+
+const ALGORITHM: &str = "Time-Partitioned Windows (ARINC 653)";
+
+/// Execution window reserved to a partition.
+#[derive(Debug, Clone)]
+#[derive(PartialEq)]
+pub struct Window {
+    pub partition: usize,
+    /// Offset from the start of the major frame.
+    pub offset: Time,
+    pub duration: Time,
+}
+
+impl Window {
+    pub fn end(&self) -> Time {
+        self.offset + self.duration
+    }
+}
+
+/// Partition schedule, repeated every major frame.
+#[derive(Debug, Clone)]
+pub struct PartitionSchedule {
+    pub major_frame: Time,
+    pub minor_frame: Time,
+    /// Windows sorted by offset.
+    pub windows: Vec<Window>,
+}
+
+impl PartitionSchedule {
+    pub fn num_minor_frames(&self) -> u64 {
+        (self.major_frame / self.minor_frame).round() as u64
+    }
+
+    /// Execution time reserved to the partition within the given interval of
+    /// the major frame.
+    pub fn supply(&self, partition: usize, start: Time, end: Time) -> Time {
+        self.windows.iter()
+            .filter(|window| window.partition == partition)
+            .map(|window| Time::max(window.end().min(end) - window.offset.max(start), Time::zero()))
+            .sum()
+    }
+
+    /// Periodic resource model of the partition, refer to the
+    /// [module](`self`) level documentation.
+    pub fn model(&self, partition: usize) -> PRModel {
+        let resource = (0..self.num_minor_frames())
+            .map(|frame| {
+                let start = self.minor_frame * frame as f64;
+                self.supply(partition, start, start + self.minor_frame)
+            })
+            .min()
+            .unwrap_or(Time::zero());
+
+        PRModel { resource, period: self.minor_frame }
+    }
+}
+
+/// Time-Partitioned Windows (ARINC 653)
+///
+/// Refer to the [module](`self`) level documentation.
+pub struct Analysis {
+    /// Indices of the tasks of each partition. With Fixed Priority local
+    /// scheduling, they are sorted by priority, highest first.
+    pub partitions: Vec<Vec<usize>>,
+    pub schedule: PartitionSchedule,
+    pub local_scheduler: LocalScheduler,
+}
+
+impl SchedAnalysis<(), &[RTTask]> for Analysis {
+    fn analyzer_name(&self) -> &str { ALGORITHM }
+
+    fn check_preconditions(&self, taskset: &&[RTTask]) -> Result<(), SchedError> {
+        check_partitions(taskset, &self.partitions)?;
+        check_schedule(&self.schedule, self.partitions.len())?;
+
+        for (p, tasks) in self.partitions.iter().enumerate() {
+            local_preconditions(self.local_scheduler, &partition_taskset(taskset, tasks))
+                .map_err(|err| err.with_context(&format!("partition {p}")))?;
+        }
+
+        Ok(())
+    }
+
+    fn run_test(&self, taskset: &[RTTask]) -> Result<(), SchedError> {
+        for (p, tasks) in self.partitions.iter().enumerate() {
+            let model = self.schedule.model(p);
+
+            local_test(self.local_scheduler, model, &partition_taskset(taskset, tasks))
+                .map_err(|err| err.with_context(&format!("partition {p} on its windows")))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl SchedProcessors for Analysis {
+    fn num_processors(&self) -> u64 { 1 }
+}
+
+/// Time-Partitioned Windows (ARINC 653) \
+/// Design the partition schedule.
+///
+/// Refer to the [module](`self`) level documentation.
+pub struct Designer {
+    /// Indices of the tasks of each partition. With Fixed Priority local
+    /// scheduling, they are sorted by priority, highest first.
+    pub partitions: Vec<Vec<usize>>,
+    pub local_scheduler: LocalScheduler,
+    pub major_frame: Time,
+    pub minor_frames: u64,
+    pub quantization: Quantization,
+}
+
+impl Designer {
+    fn minor_frame(&self) -> Time {
+        self.major_frame / self.minor_frames as f64
+    }
+
+    // Smallest window, in quanta, for which the partition is schedulable.
+    fn window_quanta(&self, taskset: &[RTTask]) -> Option<u64> {
+        if taskset.is_empty() {
+            return Some(0);
+        }
+
+        let minor_frame = self.minor_frame();
+        let is_schedulable = |quanta: u64| {
+            let model = PRModel { resource: self.quantization.quantum * quanta as f64, period: minor_frame };
+            local_test(self.local_scheduler, model, taskset).is_ok()
+        };
+
+        // The supply, thus the schedulability, is monotone in the budget.
+        let (mut left, mut right) = (1, (minor_frame / self.quantization.quantum).floor() as u64);
+        if right == 0 || !is_schedulable(right) {
+            return None;
+        }
+
+        while left < right {
+            let mid = left + (right - left) / 2;
+
+            if is_schedulable(mid) {
+                right = mid;
+            } else {
+                left = mid + 1;
+            }
+        }
+
+        Some(left)
+    }
+}
+
+impl SchedDesign<&[RTTask], PartitionSchedule> for Designer {
+    fn designer_name(&self) -> &str { ALGORITHM }
+
+    fn check_preconditions(&self, taskset: &&[RTTask]) -> Result<(), SchedError> {
+        self.quantization.check_preconditions(taskset)?;
+        check_partitions(taskset, &self.partitions)?;
+
+        if self.major_frame <= Time::zero() || self.minor_frames == 0 {
+            return Err(SchedError::Precondition(Some(
+                anyhow::format_err!("the major frame must be positive, with at least one minor frame."))));
+        }
+
+        for (p, tasks) in self.partitions.iter().enumerate() {
+            local_preconditions(self.local_scheduler, &partition_taskset(taskset, tasks))
+                .map_err(|err| err.with_context(&format!("partition {p}")))?;
+        }
+
+        Ok(())
+    }
+
+    fn run_designer(&self, taskset: &[RTTask]) -> Result<PartitionSchedule, SchedError> {
+        let minor_frame = self.minor_frame();
+
+        let durations = self.partitions.iter().enumerate()
+            .map(|(p, tasks)| {
+                self.window_quanta(&partition_taskset(taskset, tasks))
+                    .map(|quanta| self.quantization.quantum * quanta as f64)
+                    .ok_or_else(|| SchedError::NonSchedulable(Some(anyhow::format_err!(
+                        "partition {p} is not schedulable even with the whole minor frame."))))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let total: Time = durations.iter().copied().sum();
+        if total > minor_frame {
+            return Err(SchedError::NonSchedulable(Some(anyhow::format_err!(
+                "the windows take {}ns, exceeding the minor frame of {}ns.", total.as_nanos(), minor_frame.as_nanos()))));
+        }
+
+        let mut windows = Vec::new();
+        for frame in 0..self.minor_frames {
+            let mut offset = minor_frame * frame as f64;
+
+            for (partition, &duration) in durations.iter().enumerate() {
+                if duration > Time::zero() {
+                    windows.push(Window { partition, offset, duration });
+                    offset = offset + duration;
+                }
+            }
+        }
+
+        Ok(PartitionSchedule { major_frame: self.major_frame, minor_frame, windows })
+    }
+}
+
+fn partition_taskset(taskset: &[RTTask], tasks: &[usize]) -> Vec<RTTask> {
+    tasks.iter()
+        .map(|&i| taskset[i].clone())
+        .collect()
+}
+
+fn check_partitions(taskset: &[RTTask], partitions: &[Vec<usize>]) -> Result<(), SchedError> {
+    let mut partitions_of_task = vec![0; taskset.len()];
+    for &i in partitions.iter().flatten() {
+        if i >= taskset.len() {
+            return Err(SchedError::Precondition(Some(
+                anyhow::format_err!("task {i} of the partitions is not in the taskset."))));
+        }
+
+        partitions_of_task[i] += 1;
+    }
+
+    if let Some(i) = partitions_of_task.iter().position(|&partitions| partitions != 1) {
+        Err(SchedError::Precondition(Some(
+            anyhow::format_err!("task {i} must belong to exactly one partition."))))
+    } else {
+        Ok(())
+    }
+}
+
+fn check_schedule(schedule: &PartitionSchedule, num_partitions: usize) -> Result<(), SchedError> {
+    let frames = schedule.major_frame / schedule.minor_frame;
+
+    if schedule.minor_frame <= Time::zero() || frames < 1.0 || (frames - frames.round()).abs() > 1e-9 {
+        return Err(SchedError::Precondition(Some(anyhow::format_err!(
+            "the major frame of {}ns is not a multiple of the minor frame.", schedule.major_frame.as_nanos()))));
+    }
+
+    let mut busy_until = Time::zero();
+    for window in schedule.windows.iter() {
+        if window.partition >= num_partitions {
+            return Err(SchedError::Precondition(Some(anyhow::format_err!(
+                "the window at {}ns belongs to missing partition {}.", window.offset.as_nanos(), window.partition))));
+        } else if window.offset < busy_until || window.duration < Time::zero() || window.end() > schedule.major_frame {
+            return Err(SchedError::Precondition(Some(anyhow::format_err!(
+                "the window at {}ns overlaps the previous one or exceeds the major frame.", window.offset.as_nanos()))));
+        }
+
+        busy_until = window.end();
+    }
+
+    Ok(())
+}
+
+fn local_preconditions(scheduler: LocalScheduler, taskset: &[RTTask]) -> Result<(), SchedError> {
+    let model = PRModel { resource: Time::zero(), period: Time::one() };
+
+    match scheduler {
+        LocalScheduler::EarliestDeadlineFirst =>
+            earliest_deadline_first::shin_lee03::Analysis { model }.check_preconditions(&taskset),
+        LocalScheduler::FixedPriority =>
+            fixed_priority::shin_lee03::Analysis { model }.check_preconditions(&taskset),
+    }
+}
+
+fn local_test(scheduler: LocalScheduler, model: PRModel, taskset: &[RTTask]) -> Result<(), SchedError> {
+    match scheduler {
+        LocalScheduler::EarliestDeadlineFirst =>
+            earliest_deadline_first::shin_lee03::Analysis { model }.run_test(taskset),
+        LocalScheduler::FixedPriority =>
+            fixed_priority::shin_lee03::Analysis { model }.run_test(taskset).map(|_| ()),
+    }
+}
+
+#[test]
+fn partition_windows() {
+    let taskset = [
+        RTTask::new_ns(1, 20, 20),
+        RTTask::new_ns(2, 40, 40),
+        RTTask::new_ns(1, 20, 20),
+        RTTask::new_ns(4, 40, 40),
+    ];
+
+    let designer = |partitions: Vec<Vec<usize>>, local_scheduler, minor_frames| Designer {
+        partitions, local_scheduler, minor_frames,
+        major_frame: Time::nanos(20.0),
+        quantization: Quantization::nanos(),
+    };
+
+    for local_scheduler in [LocalScheduler::EarliestDeadlineFirst, LocalScheduler::FixedPriority] {
+        let designer = designer(vec![vec![0, 1], vec![2, 3]], local_scheduler, 2);
+        let schedule = designer.design(&taskset[..]).unwrap();
+
+        assert_eq!(schedule.minor_frame, Time::nanos(10.0));
+        assert_eq!(schedule.windows.len(), 4);
+        assert_eq!(schedule.windows[2].offset, Time::nanos(10.0));
+        assert!(schedule.windows.windows(2).all(|pair| pair[0].end() <= pair[1].offset));
+
+        let analysis = |schedule| Analysis { partitions: designer.partitions.clone(), schedule, local_scheduler };
+        assert!(analysis(schedule.clone()).is_schedulable(&taskset[..]).is_ok());
+
+        // The windows are the smallest ones.
+        let mut shrunk = schedule.clone();
+        shrunk.windows[0].duration = shrunk.windows[0].duration - Time::one();
+        let err = analysis(shrunk).is_schedulable(&taskset[..]).unwrap_err();
+        assert!(format!("{err:#}").contains("partition 0 on its windows"));
+
+        // Overlapping windows.
+        let mut overlapping = schedule;
+        overlapping.windows[1].offset = overlapping.windows[1].offset - Time::one();
+        assert!(analysis(overlapping).is_schedulable(&taskset[..]).is_err());
+    }
+
+    // A single minor frame delays the tasks too much.
+    let err = designer(vec![vec![0, 1], vec![2, 3]], LocalScheduler::FixedPriority, 1)
+        .design(&taskset[..]).unwrap_err();
+    assert!(format!("{err:#}").contains("exceeding the minor frame"));
+
+    // Every task in exactly one partition.
+    assert!(designer(vec![vec![0, 1], vec![3]], LocalScheduler::FixedPriority, 2).design(&taskset[..]).is_err());
+}
@@ -0,0 +1,181 @@
+//! ## Hierarchical Fixed Priority over Fixed Priority - Davis & Burns 2005
+//!
+//! Two-level hierarchical scheduling: the parent level schedules periodic
+//! servers with fixed priorities, and each server runs its own taskset with
+//! fixed priorities. Rather than abstracting each server with its periodic
+//! resource model, the analysis accounts for the actual worst-case response
+//! time of the servers at the parent level: the budget of each server period
+//! is supplied within the server's response time, thus shortening the
+//! worst-case blackout interval of the children.
+//!
+//! #### Model:
+//! - Periodic/Sporadic Task model
+//! - Periodic servers, Fully-Preemptive Fixed Priority parent scheduling
+//!   (servers are sorted by priority, highest first)
+//! - Fully-Preemptive Fixed Priority local scheduling (tasks are sorted by
+//!   priority, highest first)
+//!
+//! #### Preconditions:
+//! - Constrained Deadlines
+//! - Positive server budgets, not greater than their periods
+//!
+//! #### Implements:
+//! - [`Analysis::is_schedulable`] \
+//!   | pseudo-polynomial complexity
+//!
+//! ---
+//! #### References:
+//! 1. R. I. Davis and A. Burns, “Hierarchical fixed priority pre-emptive
+//!    scheduling,” in 26th IEEE International Real-Time Systems Symposium
+//!    (RTSS'05), Dec. 2005, pp. 389–398. doi: 10.1109/RTSS.2005.25.
+
+use crate::prelude::*;
+use crate::utils::dbf;
+
+const ALGORITHM: &str = "Hierarchical FP over FP (Davis & Burns 2005)";
+
+// Upper limit on the iterations of each response time computation.
+const MAX_ITERATIONS: usize = 1_000_000;
+
+/// Periodic server scheduled at the parent level.
+#[derive(Debug, Clone)]
+pub struct PeriodicServer {
+    pub budget: Time,
+    pub period: Time,
+}
+
+/// Hierarchical Fixed Priority over Fixed Priority - Davis & Burns 2005 \[1\]
+///
+/// Refer to the [module](`self`) level documentation.
+///
+/// The i-th taskset is served by the i-th server.
+///
+/// Returns:
+/// - Worst-Case Response Times of the tasks of each server.
+pub struct Analysis {
+    pub servers: Vec<PeriodicServer>,
+}
+
+impl SchedAnalysis<Vec<Vec<Time>>, &[&[RTTask]]> for Analysis {
+    fn analyzer_name(&self) -> &str { ALGORITHM }
+
+    fn check_preconditions(&self, tasksets: &&[&[RTTask]]) -> Result<(), SchedError> {
+        if tasksets.len() != self.servers.len() {
+            Err(SchedError::Precondition(Some(
+                anyhow::format_err!("each server must serve exactly one taskset."))))
+        } else if self.servers.iter().any(|server| server.budget <= Time::zero()) {
+            Err(SchedError::Precondition(Some(
+                anyhow::format_err!("server budgets must be positive."))))
+        } else if self.servers.iter().any(|server| server.budget > server.period) {
+            Err(SchedError::Precondition(Some(
+                anyhow::format_err!("server budgets must not exceed their periods."))))
+        } else if !tasksets.iter().all(|taskset| RTUtils::constrained_deadlines(taskset)) {
+            Err(SchedError::constrained_deadlines())
+        } else {
+            Ok(())
+        }
+    }
+
+    fn run_test(&self, tasksets: &[&[RTTask]]) -> Result<Vec<Vec<Time>>, SchedError> {
+        let server_tasks: Vec<_> = self.servers.iter()
+            .map(|server| RTTask { wcet: server.budget, deadline: server.period, period: server.period })
+            .collect();
+
+        self.servers.iter().enumerate()
+            .zip(tasksets.iter())
+            .map(|((s, server), taskset)| {
+                // Parent level: the server behaves as a periodic task.
+                let server_response = response_time(&server_tasks[0..=s], |demand| demand)?
+                    .ok_or_else(|| SchedError::NonSchedulable(Some(
+                        anyhow::format_err!("server {s} does not supply its budget within its period.")
+                    )))?;
+
+                // Child level: each task is served by the server's supply.
+                (0..taskset.len())
+                    .map(|i| {
+                        response_time(&taskset[0..=i], |demand| supply_time(server, server_response, demand))?
+                            .ok_or_else(|| SchedError::NonSchedulable(Some(
+                                anyhow::format_err!("task {i} of server {s} misses its deadline.")
+                            )))
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+// Section 4 [1]: the budget of each server period is supplied at some point
+// between the server's release and its worst-case response time. In the worst
+// case the budget is supplied at the start of a period and at the end of the
+// response time in the following one, so there may be no supply for
+// T + R - 2Q, after which Q is supplied every T.
+//
+// Returns the time needed to receive the given supply.
+fn supply_time(server: &PeriodicServer, server_response: Time, supply: Time) -> Time {
+    let blackout = server.period + server_response - 2.0 * server.budget;
+    let full_periods = (supply / server.budget).ceil() - 1.0;
+
+    blackout + full_periods * server.period + (supply - full_periods * server.budget)
+}
+
+// Fixed priority response time of the lowest priority task, given the time
+// needed to receive the demanded processor time. Returns None on deadline miss.
+fn response_time<F>(taskset: &[RTTask], time_to_serve: F) -> Result<Option<Time>, SchedError>
+    where
+        F: Fn(Time) -> Time,
+{
+    let task = taskset.last().unwrap();
+    let hp_tasks = &taskset[0..taskset.len() - 1];
+
+    let mut response = time_to_serve(task.wcet);
+    for _ in 0..MAX_ITERATIONS {
+        if response > task.deadline {
+            return Ok(None);
+        }
+
+        let demand = dbf::total_rbf(hp_tasks, response) + task.wcet;
+
+        let new_response = time_to_serve(demand);
+        if new_response == response {
+            return Ok(Some(response));
+        }
+
+        response = new_response;
+    }
+
+    Err(SchedError::Inconclusive(Some(
+        anyhow::format_err!("the response time did not converge within {MAX_ITERATIONS} iterations.")
+    )))
+}
+
+#[test]
+fn two_levels() {
+    let servers = vec![
+        PeriodicServer { budget: Time::nanos(2.0), period: Time::nanos(5.0) },
+        PeriodicServer { budget: Time::nanos(4.0), period: Time::nanos(10.0) },
+    ];
+
+    let taskset_0 = [RTTask::new_ns(1, 10, 10), RTTask::new_ns(1, 20, 20)];
+    let taskset_1 = [RTTask::new_ns(2, 40, 40)];
+    let tasksets: [&[RTTask]; 2] = [&taskset_0, &taskset_1];
+
+    let response_times = Analysis { servers: servers.clone() }.is_schedulable(&tasksets).unwrap();
+
+    // Server 0 has the highest priority, R = Q: blackout 5 - 2 = 3.
+    assert_eq!(response_times[0], vec![Time::nanos(4.0), Time::nanos(5.0)]);
+    // Server 1 has R = 8, blackout 10 + 8 - 8 = 10.
+    assert_eq!(response_times[1], vec![Time::nanos(12.0)]);
+
+    // The periodic resource model abstraction has blackout 2(T - Q) = 12.
+    assert!(response_times[1][0] < Time::nanos(14.0));
+
+    let taskset_1 = [RTTask::new_ns(2, 11, 40)];
+    let tasksets: [&[RTTask]; 2] = [&taskset_0, &taskset_1];
+    assert!(Analysis { servers: servers.clone() }.is_schedulable(&tasksets).is_err());
+
+    // A server without budget never supplies its tasks.
+    let mut servers = servers;
+    servers[1].budget = Time::zero();
+    let analysis = Analysis { servers };
+    assert!(analysis.check_preconditions(&&tasksets[..]).is_err());
+}
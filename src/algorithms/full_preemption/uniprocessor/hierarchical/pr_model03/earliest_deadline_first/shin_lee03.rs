@@ -14,6 +14,10 @@
 //!   | Generate the suitable interface given the taskset and the [`PRModel`]'s period. \
 //!   | \
 //!   | pseudo-polynomial complexity
+//! - [`DesignerBandwidthDelay::design`] \
+//!   | Generate the interface minimizing bandwidth times delay over a range of periods. \
+//!   | \
+//!   | pseudo-polynomial complexity
 //!
 //! ---
 //! #### References:
@@ -23,6 +27,7 @@
 
 use crate::prelude::*;
 use crate::algorithms::full_preemption::uniprocessor::hierarchical::pr_model03::*;
+use crate::utils::dbf;
 
 const ALGORITHM: &str = "Periodic Resource Model, EDF Local Scheduling (Shin & Lee 2003)";
 
@@ -89,11 +94,41 @@ impl SchedDesign<&[RTTask], PRModel> for DesignerLinear {
     }
 }
 
+/// Periodic Resource Model, EDF Local Scheduling - Shin & Lee 2003 \[1\] \
+/// Derive the [`PRModel`] minimizing [`PRModel::bandwidth_delay`] among the
+/// periods in the given range.
+///
+/// Refer to the [module](`self`) level documentation.
+pub struct DesignerBandwidthDelay {
+    pub min_period: Time,
+    pub max_period: Time,
+    pub period_step: Time,
+}
+
+impl SchedDesign<&[RTTask], PRModel> for DesignerBandwidthDelay {
+    fn designer_name(&self) -> &str { ALGORITHM }
+
+    fn check_preconditions(&self, taskset: &&[RTTask]) -> Result<(), SchedError> {
+        if !RTUtils::implicit_deadlines(taskset) {
+            Err(SchedError::implicit_deadlines())
+        } else {
+            Ok(())
+        }
+    }
+
+    fn run_designer(&self, taskset: &[RTTask]) -> Result<PRModel, SchedError> {
+        // Equation 16 [1]
+        generate_model_min_bandwidth_delay(
+            time_range_iterator_w_step(self.min_period, self.max_period, self.period_step),
+            |period| generate_model_from_demand_linear(taskset, period, demand, time_intervals),
+        )
+        .ok_or(SchedError::NonSchedulable(None))
+    }
+}
+
 // Section 4.1 [1]
 fn demand(taskset: &[RTTask], interval: Time) -> Time {
-    taskset.iter()
-        .map(|task| (interval / task.period).floor() * task.wcet)
-        .sum()
+    dbf::total_dbf(taskset, interval)
 }
 
 // Theorem 1 [1]
@@ -104,4 +139,28 @@ fn time_intervals(taskset: &[RTTask]) -> Box<dyn Iterator<Item = Time>> {
         (0 ..= max_time.as_nanos() as u64)
             .map(|time_ns| Time::nanos(time_ns as f64))
     )
+}
+
+#[test]
+fn bandwidth_delay_designer() {
+    let taskset = [
+        RTTask::new_ns(10, 100, 100),
+        RTTask::new_ns(20, 200, 200),
+        RTTask::new_ns(40, 400, 400),
+    ];
+
+    let designer = DesignerBandwidthDelay {
+        min_period: Time::nanos(10.0),
+        max_period: Time::nanos(100.0),
+        period_step: Time::nanos(10.0),
+    };
+
+    let model = designer.design(&taskset).unwrap();
+    assert!(Analysis { model: model.clone() }.is_schedulable(&taskset).is_ok());
+
+    for period in time_range_iterator_w_step(designer.min_period, designer.max_period, designer.period_step) {
+        if let Ok(other) = (DesignerLinear { period }).design(&taskset) {
+            assert!(model.bandwidth_delay() <= other.bandwidth_delay());
+        }
+    }
 }
\ No newline at end of file
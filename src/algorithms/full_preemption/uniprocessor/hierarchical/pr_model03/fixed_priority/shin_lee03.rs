@@ -10,10 +10,18 @@
 //! #### Implements:
 //! - [`Analysis::is_schedulable`] \
 //!   | pseudo-polynomial complexity
+//! - [`AnalysisWarmStart::is_schedulable`] \
+//!   | Start the fixpoint iterations from previously computed response times. \
+//!   | \
+//!   | pseudo-polynomial complexity
 //! - [`DesignerLinear::design`] \
 //!   | Generate the suitable interface given the taskset and the [`PRModel`]'s period. \
 //!   | \
 //!   | O(*n*) complexity
+//! - [`DesignerBandwidthDelay::design`] \
+//!   | Generate the interface minimizing bandwidth times delay over a range of periods. \
+//!   | \
+//!   | O(*n*) complexity per period
 //!
 //! ---
 //! #### References:
@@ -23,6 +31,7 @@
 
 use crate::prelude::*;
 use crate::algorithms::full_preemption::uniprocessor::hierarchical::pr_model03::*;
+use crate::utils::dbf;
 
 const ALGORITHM: &str = "Periodic Resource Model, Fixed Priority Local Scheduling (Shin & Lee 2003)";
 
@@ -55,6 +64,41 @@ impl SchedAnalysis<Vec<Time>, &[RTTask]> for Analysis {
     }
 }
 
+/// Periodic Resource Model, Fixed Priority Local Scheduling - Shin & Lee 2003 \[1\] \
+/// Warm-started from previously computed response times.
+///
+/// Refer to the [module](`self`) level documentation.
+///
+/// Refer to [`warm_start_response_time`](crate::utils::warm_start::warm_start_response_time)
+/// for the requirements on the initial response times.
+pub struct AnalysisWarmStart {
+    pub model: PRModel,
+    pub initial_response_times: Vec<Time>,
+}
+
+impl SchedAnalysis<Vec<Time>, &[RTTask]> for AnalysisWarmStart {
+    fn analyzer_name(&self) -> &str { ALGORITHM }
+
+    fn check_preconditions(&self, taskset: &&[RTTask]) -> Result<(), SchedError> {
+        if !RTUtils::constrained_deadlines(taskset) {
+            Err(SchedError::constrained_deadlines())
+        } else {
+            Ok(())
+        }
+    }
+
+    fn run_test(&self, taskset: &[RTTask]) -> Result<Vec<Time>, SchedError> {
+        // Equation 14 [1]
+        is_schedulable_response_warm_start(
+            taskset,
+            &self.model,
+            &self.initial_response_times,
+            rta,
+        )
+        .map_err(|err| SchedError::NonSchedulable(Some(err)))
+    }
+}
+
 /// Periodic Resource Model, Fixed Priority Local Scheduling - Shin & Lee 2003 \[1\] \
 /// Derive the best [`PRModel`] using demand analysis.
 ///
@@ -85,12 +129,62 @@ impl SchedDesign<&[RTTask], PRModel> for DesignerLinear {
     }
 }
 
+/// Periodic Resource Model, Fixed Priority Local Scheduling - Shin & Lee 2003 \[1\] \
+/// Derive the [`PRModel`] minimizing [`PRModel::bandwidth_delay`] among the
+/// periods in the given range.
+///
+/// Refer to the [module](`self`) level documentation.
+pub struct DesignerBandwidthDelay {
+    pub min_period: Time,
+    pub max_period: Time,
+    pub period_step: Time,
+}
+
+impl SchedDesign<&[RTTask], PRModel> for DesignerBandwidthDelay {
+    fn designer_name(&self) -> &str { ALGORITHM }
+
+    fn check_preconditions(&self, taskset: &&[RTTask]) -> Result<(), SchedError> {
+        if !RTUtils::constrained_deadlines(taskset) {
+            Err(SchedError::constrained_deadlines())
+        } else {
+            Ok(())
+        }
+    }
+
+    fn run_designer(&self, taskset: &[RTTask]) -> Result<PRModel, SchedError> {
+        // Equations 23, 24 [1]
+        generate_model_min_bandwidth_delay(
+            time_range_iterator_w_step(self.min_period, self.max_period, self.period_step),
+            |period| generate_model_from_response_linear(taskset, period, rta),
+        )
+        .ok_or(SchedError::NonSchedulable(None))
+    }
+}
+
 // Equation 10 [1]
 fn rta(taskset: &[RTTask], k: usize, task_k: &RTTask, response: Time) -> Time {
-    taskset.iter()
-        .take(k)
-        .map(|task_i| (response / task_i.period).ceil() * task_i.wcet)
-        .sum::<Time>()
-    +
-        task_k.wcet
-}
\ No newline at end of file
+    dbf::total_rbf(&taskset[0..k], response) + task_k.wcet
+}
+
+#[test]
+fn bandwidth_delay_designer() {
+    let taskset = [
+        RTTask::new_ns(10, 100, 100),
+        RTTask::new_ns(20, 200, 200),
+        RTTask::new_ns(40, 400, 400),
+    ];
+
+    let designer = DesignerBandwidthDelay {
+        min_period: Time::nanos(10.0),
+        max_period: Time::nanos(100.0),
+        period_step: Time::nanos(10.0),
+    };
+
+    let model = designer.design(&taskset).unwrap();
+
+    for period in time_range_iterator_w_step(designer.min_period, designer.max_period, designer.period_step) {
+        if let Ok(other) = (DesignerLinear { period }).design(&taskset) {
+            assert!(model.bandwidth_delay() <= other.bandwidth_delay());
+        }
+    }
+}
@@ -0,0 +1,155 @@
+//! ## Isolation of Periodic Resource Model Components
+//!
+//! The components sharing a processor are each abstracted by their
+//! [`PRModel`] interface, and each interface is implemented by a periodic
+//! server with budget *Θ* and period *Π*. The supply bound function of the
+//! model only assumes that the budget is supplied at some point within each
+//! period \[1\], thus the components are composable if the servers never miss
+//! their periods.
+//!
+//! A component's guaranteed supply must not depend on the other components
+//! under-running their budgets: the servers are analyzed assuming that every
+//! component consumes its whole budget in every period. When the composition
+//! fails, the error reports the binding constraint, i.e. either the platform's
+//! capacity or the component whose supply would depend on the other ones.
+//!
+//! #### Model:
+//! - Periodic servers, one per component
+//! - Fully-Preemptive EDF or Fixed Priority scheduling of the servers
+//!   (servers are sorted by priority, highest first)
+//!
+//! #### Preconditions:
+//! - Feasible PR Models
+//!
+//! #### Implements:
+//! - [`AnalysisIsolation::is_schedulable`] \
+//!   | pseudo-polynomial complexity
+//!
+//! ---
+//! #### References:
+//! 1. Shin and I. Lee, “Periodic resource model for compositional real-time
+//!    guarantees,” in RTSS 2003. 24th IEEE Real-Time Systems Symposium, 2003,
+//!    Dec. 2003, pp. 2–13. doi: 10.1109/REAL.2003.1253249.
+//! 2. M. Joseph and P. Pandya, “Finding Response Times in a Real-Time System,”
+//!    Comput J, vol. 29, no. 5, pp. 390–395, 1986, doi: 10.1093/comjnl/29.5.390.
+
+use crate::prelude::*;
+use crate::algorithms::full_preemption::global_multiprocessor::hierarchical::mpr_model09::partitioning::ServerScheduler;
+use super::PRModel;
+
+const ALGORITHM: &str = "Isolation of PR Model Components";
+
+/// Isolation of Periodic Resource Model Components
+///
+/// Refer to the [module](`self`) level documentation.
+pub struct AnalysisIsolation {
+    pub scheduler: ServerScheduler,
+}
+
+impl SchedAnalysis<(), &[PRModel]> for AnalysisIsolation {
+    fn analyzer_name(&self) -> &str { ALGORITHM }
+
+    fn check_preconditions(&self, models: &&[PRModel]) -> Result<(), SchedError> {
+        if let Some(c) = models.iter().position(|model| !model.is_feasible() || model.resource < Time::zero()) {
+            Err(SchedError::Precondition(Some(
+                anyhow::format_err!("the PR Model of component {c} is not feasible."))))
+        } else {
+            Ok(())
+        }
+    }
+
+    fn run_test(&self, models: &[PRModel]) -> Result<(), SchedError> {
+        let bandwidth: f64 = models.iter().map(PRModel::capacity).sum();
+
+        if bandwidth > 1.0 {
+            let largest = models.iter()
+                .map(PRModel::capacity)
+                .enumerate()
+                .max_by(|(_, l), (_, r)| l.total_cmp(r))
+                .map_or(0, |(c, _)| c);
+
+            return Err(SchedError::NonSchedulable(Some(anyhow::format_err!(
+                "the components demand a total bandwidth of {bandwidth}, exceeding the platform's capacity (component {largest} demands the most)."
+            ))));
+        }
+
+        match self.scheduler {
+            // EDF scheduling of implicit deadline servers is exact on the
+            // bandwidth.
+            ServerScheduler::EarliestDeadlineFirst => Ok(()),
+            ServerScheduler::RateMonotonic => {
+                for (c, model) in models.iter().enumerate() {
+                    let response = server_response_time(&models[0..c], model);
+
+                    if response > model.period {
+                        let interfering: Vec<_> = (0..c)
+                            .filter(|&j| models[j].resource > Time::zero())
+                            .collect();
+
+                        return Err(SchedError::NonSchedulable(Some(anyhow::format_err!(
+                            "the supply of component {c} depends on components {interfering:?} under-running their budgets: its budget is not supplied within its period when they consume theirs in full."
+                        ))));
+                    }
+                }
+
+                Ok(())
+            },
+        }
+    }
+}
+
+// Response time of the server, assuming the higher priority servers consume
+// their whole budget in every period [2].
+fn server_response_time(hp_models: &[PRModel], model: &PRModel) -> Time {
+    fixpoint_search_with_limit(
+        model.resource,
+        model.period + Time::nanos(1.0),
+        |response: &Time| {
+            hp_models.iter()
+                .map(|hp| (*response / hp.period).ceil() * hp.resource)
+                .sum::<Time>()
+            + model.resource
+        }
+    )
+}
+
+#[test]
+fn isolation() {
+    let models = [
+        PRModel { resource: Time::nanos(2.0), period: Time::nanos(5.0) },
+        PRModel { resource: Time::nanos(4.0), period: Time::nanos(10.0) },
+        PRModel { resource: Time::nanos(3.0), period: Time::nanos(20.0) },
+    ];
+
+    // Bandwidth of 0.95: under FP the last server completes at 19 with all the
+    // budgets consumed in full.
+    for scheduler in [ServerScheduler::EarliestDeadlineFirst, ServerScheduler::RateMonotonic] {
+        assert!(AnalysisIsolation { scheduler }.is_schedulable(&models).is_ok());
+    }
+
+    // Bandwidth of 0.97: the EDF servers are composable, but under FP the last
+    // server completes at 8 > 7, and within 7 only if the first under-runs.
+    let models = [
+        PRModel { resource: Time::nanos(2.0), period: Time::nanos(5.0) },
+        PRModel { resource: Time::nanos(4.0), period: Time::nanos(7.0) },
+    ];
+
+    let analysis = AnalysisIsolation { scheduler: ServerScheduler::EarliestDeadlineFirst };
+    assert!(analysis.is_schedulable(&models).is_ok());
+
+    let analysis = AnalysisIsolation { scheduler: ServerScheduler::RateMonotonic };
+    let err = analysis.is_schedulable(&models).unwrap_err();
+    assert!(format!("{err:#}").contains("component 1 depends on components [0]"));
+
+    // The platform's capacity is the binding constraint.
+    let models = [
+        PRModel { resource: Time::nanos(3.0), period: Time::nanos(5.0) },
+        PRModel { resource: Time::nanos(5.0), period: Time::nanos(10.0) },
+    ];
+
+    let err = analysis.is_schedulable(&models).unwrap_err();
+    assert!(format!("{err:#}").contains("platform's capacity (component 0"));
+
+    let models = [PRModel { resource: Time::nanos(6.0), period: Time::nanos(5.0) }];
+    assert!(analysis.check_preconditions(&&models[..]).is_err());
+}
@@ -31,6 +31,12 @@
 //!     approximation functions to derive the resource requirements. \
 //!   | \
 //!   | O(*n*) * O(rta_fn) complexity
+//! - [`generate_model_min_bandwidth_delay`] \
+//!   | Generic implementation for choosing the model's period, minimizing bandwidth times delay. \
+//!   | \
+//!   | O(*periods*) \* O(*design_fn*) complexity
+//! - [`isolation`] \
+//!   | Check that the components sharing a processor are isolated
 //!
 //! ---
 //! #### References:
@@ -39,6 +45,7 @@
 //!    Dec. 2003, pp. 2–13. doi: 10.1109/REAL.2003.1253249.
 
 use crate::prelude::*;
+use crate::utils::warm_start::warm_start_response_time;
 
 // Local Scheduling Algorithms
 pub mod earliest_deadline_first {
@@ -49,6 +56,8 @@ pub mod fixed_priority {
     pub mod shin_lee03;
 }
 
+pub mod isolation;
+
 /// Periodic Resource Model - Shin & Lee 2003 \[1\]
 ///
 /// Refer to the [module](`self`) level documentation.
@@ -67,15 +76,31 @@ impl PRModel {
         self.resource / self.period
     }
 
+    /// Longest interval with no supply, i.e. the delay of the model.
+    pub fn delay(&self) -> Time {
+        // Section 3 [1]
+        2.0 * (self.period - self.resource)
+    }
+
+    /// Product of the model's bandwidth and delay: lower values are better
+    /// interfaces, which waste less bandwidth and delay the tasks less.
+    pub fn bandwidth_delay(&self) -> Time {
+        self.capacity() * self.delay()
+    }
+
     pub fn get_supply(&self, interval: Time) -> Time {
         // Equation 1 [1]
         let diff = self.period - self.resource;
 
         let base = ((interval - diff) / self.period).floor();
 
-        base * self.resource
-            +
-        Time::max(interval - 2.0 * diff - self.period * base, Time::zero())
+        // No supply within the blackout interval, where the formula is negative.
+        Time::max(
+            base * self.resource
+                +
+            Time::max(interval - 2.0 * diff - self.period * base, Time::zero()),
+            Time::zero()
+        )
     }
 
     pub fn get_supply_linear(&self, interval: Time) -> Time {
@@ -143,6 +168,26 @@ pub fn is_schedulable_demand<FDem, FTime>(
 pub fn is_schedulable_response<FRTA>(
     taskset: &[RTTask],
     model: &PRModel,
+    rta_fn: FRTA,
+) -> Result<Vec<Time>, anyhow::Error>
+    where
+        FRTA: FnMut(&[RTTask], usize, &RTTask, Time) -> Time,
+{
+    is_schedulable_response_warm_start(taskset, model, &[], rta_fn)
+}
+
+/// Periodic Resource Model - Shin & Lee 2003 \[1\] \
+/// Generic implementation for response time based analysis, warm-started from
+/// previously computed response times.
+///
+/// Refer to [`warm_start_response_time`] for the requirements on the initial
+/// response times.
+///
+/// Refer to the [module](`self`) level documentation.
+pub fn is_schedulable_response_warm_start<FRTA>(
+    taskset: &[RTTask],
+    model: &PRModel,
+    initial_response_times: &[Time],
     mut rta_fn: FRTA,
 ) -> Result<Vec<Time>, anyhow::Error>
     where
@@ -150,9 +195,11 @@ pub fn is_schedulable_response<FRTA>(
 {
     taskset.iter().enumerate()
         .map(|(k, task_k)| {
+            let initial_response = warm_start_response_time(initial_response_times, k, task_k);
+
             let response =
                 fixpoint_search_with_limit(
-                    task_k.wcet,
+                    initial_response,
                     task_k.deadline + Time::nanos(1.0),
                     |response: &Time|
                         model.get_interval_from_supply(
@@ -235,4 +282,25 @@ pub fn generate_model_from_response_linear<FRTA>(
     } else {
         None
     }
-}
\ No newline at end of file
+}
+
+/// Periodic Resource Model - Shin & Lee 2003 \[1\] \
+/// Generic implementation choosing the [`PRModel`]'s period which minimizes
+/// [`PRModel::bandwidth_delay`].
+///
+/// The `design_fn` function derives the model for the given period, e.g. by
+/// means of [`generate_model_from_demand_linear`].
+///
+/// Refer to the [module](`self`) level documentation.
+pub fn generate_model_min_bandwidth_delay<I, FDes>(
+    periods: I,
+    design_fn: FDes,
+) -> Option<PRModel>
+    where
+        I: Iterator<Item = Time>,
+        FDes: FnMut(Time) -> Option<PRModel>,
+{
+    periods
+        .filter_map(design_fn)
+        .min_by(|l, r| l.bandwidth_delay().cmp(&r.bandwidth_delay()))
+}
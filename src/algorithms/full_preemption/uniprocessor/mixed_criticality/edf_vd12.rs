@@ -0,0 +1,166 @@
+//! ## EDF with Virtual Deadlines - Baruah et al. 2012
+//!
+//! In LO mode, the HI tasks are scheduled by EDF with their deadlines scaled
+//! by a factor *x ∈ (0, 1]*, i.e. with virtual deadlines *x·T*, so that they
+//! are left with enough time to complete their *C(HI)* should the system
+//! switch to HI mode. In HI mode, the LO tasks are dropped and the HI tasks
+//! are scheduled by EDF with their real deadlines.
+//!
+//! #### Model:
+//! - Dual-criticality Periodic/Sporadic Task model (refer to [`MCTask`])
+//! - Fully-Preemptive EDF with Virtual Deadlines scheduling
+//!
+//! #### Preconditions:
+//! - Implicit Deadlines
+//! - *C(LO) ≤ C(HI)* for the HI tasks
+//!
+//! #### Implements:
+//! - [`Analysis::is_schedulable`] \
+//!   | linear *O(n)* complexity
+//! - [`Designer::design`] \
+//!   | Smallest virtual deadline scaling factor *x* \
+//!   | \
+//!   | linear *O(n)* complexity
+//!
+//! ---
+//! #### References:
+//! 1. S. Baruah, V. Bonifaci, G. D'Angelo, H. Li, A. Marchetti-Spaccamela,
+//!    S. van der Ster, and L. Stougie, “The Preemptive Uniprocessor Scheduling
+//!    of Mixed-Criticality Implicit-Deadline Sporadic Task Systems,” in 2012
+//!    24th Euromicro Conference on Real-Time Systems, July 2012, pp. 145–154.
+//!    doi: 10.1109/ECRTS.2012.42.
+
+use crate::prelude::*;
+use super::*;
+
+const ALGORITHM: &str = "EDF-VD (Baruah et al. 2012)";
+
+/// EDF with Virtual Deadlines - Baruah et al. 2012 \[1\]
+///
+/// Refer to the [module](`self`) level documentation.
+pub struct Analysis;
+
+impl SchedAnalysis<(), &[MCTask]> for Analysis {
+    fn analyzer_name(&self) -> &str { ALGORITHM }
+
+    fn check_preconditions(&self, taskset: &&[MCTask]) -> Result<(), SchedError> {
+        check_preconditions(taskset)
+    }
+
+    fn run_test(&self, taskset: &[MCTask]) -> Result<(), SchedError> {
+        scaling_factor(taskset).map(|_| ())
+    }
+}
+
+impl SchedProcessors for Analysis {
+    fn num_processors(&self) -> u64 { 1 }
+}
+
+impl SchedPlatform for Analysis {
+    fn supports(&self, platform: &Platform) -> bool {
+        platform.matches(PlatformModel::Uniprocessor, SchedulingAlgorithm::EarliestDeadlineFirst, 1)
+    }
+}
+
+/// EDF with Virtual Deadlines - Baruah et al. 2012 \[1\] \
+/// Derive the smallest virtual deadline scaling factor *x* which satisfies
+/// the LO mode, if it also satisfies the HI mode. The virtual deadlines are
+/// given by [`virtual_deadline`].
+///
+/// Refer to the [module](`self`) level documentation.
+pub struct Designer;
+
+impl SchedDesign<&[MCTask], f64> for Designer {
+    fn designer_name(&self) -> &str { ALGORITHM }
+
+    fn check_preconditions(&self, taskset: &&[MCTask]) -> Result<(), SchedError> {
+        check_preconditions(taskset)
+    }
+
+    fn run_designer(&self, taskset: &[MCTask]) -> Result<f64, SchedError> {
+        scaling_factor(taskset)
+    }
+}
+
+/// Virtual deadline of the task in LO mode, given the scaling factor.
+pub fn virtual_deadline(task: &MCTask, scaling_factor: f64) -> Time {
+    match task.criticality {
+        Criticality::Lo => task.task.deadline,
+        Criticality::Hi => task.task.deadline * scaling_factor,
+    }
+}
+
+fn check_preconditions(taskset: &[MCTask]) -> Result<(), SchedError> {
+    if taskset.iter().any(|task| task.task.deadline != task.task.period) {
+        Err(SchedError::implicit_deadlines())
+    } else if taskset.iter().any(|task| task.criticality == Criticality::Hi && task.wcet_hi < task.task.wcet) {
+        Err(SchedError::Precondition(Some(
+            anyhow::format_err!("the HI criticality WCET of the HI tasks must not be smaller than the LO one."))))
+    } else {
+        Ok(())
+    }
+}
+
+fn scaling_factor(taskset: &[MCTask]) -> Result<f64, SchedError> {
+    let lo_lo = utilization_lo(taskset, Criticality::Lo);
+    let hi_lo = utilization_lo(taskset, Criticality::Hi);
+    let hi_hi = utilization_hi(taskset);
+
+    // Plain EDF, no virtual deadlines needed.
+    if lo_lo + hi_hi <= 1.0 {
+        return Ok(1.0);
+    }
+
+    if lo_lo >= 1.0 || lo_lo + hi_lo > 1.0 {
+        return Err(SchedError::NonSchedulable(Some(
+            anyhow::format_err!("the taskset is not schedulable in LO mode."))));
+    }
+
+    // Theorem 1 [1]: the smallest x satisfying the LO mode,
+    //   U_LO(LO) + U_HI(LO) / x <= 1,
+    // must also satisfy the HI mode,
+    //   x U_LO(LO) + U_HI(HI) <= 1.
+    let scaling_factor = hi_lo / (1.0 - lo_lo);
+
+    if scaling_factor * lo_lo + hi_hi <= 1.0 {
+        Ok(scaling_factor)
+    } else {
+        Err(SchedError::NonSchedulable(Some(
+            anyhow::format_err!("the taskset is not schedulable in HI mode with scaling factor {scaling_factor}."))))
+    }
+}
+
+#[test]
+fn edf_vd() {
+    let task = |wcet: u64, wcet_hi: u64, criticality| MCTask {
+        task: RTTask::new_ns(wcet, 10, 10),
+        wcet_hi: Time::nanos(wcet_hi as f64),
+        criticality,
+    };
+
+    // U_LO(LO) = 0.3, U_HI(LO) = 0.3, U_HI(HI) = 0.8: plain EDF fails, while
+    // x = 0.3 / 0.7 gives 0.3 x + 0.8 < 1.
+    let mut taskset = vec![
+        task(3, 3, Criticality::Lo),
+        task(1, 4, Criticality::Hi),
+        task(2, 4, Criticality::Hi),
+    ];
+
+    let scaling_factor = Designer.design(&taskset).unwrap();
+    assert!((scaling_factor - 3.0 / 7.0).abs() < 1e-9);
+    assert!(Analysis.is_schedulable(&taskset).is_ok());
+    assert_eq!(virtual_deadline(&taskset[0], scaling_factor), Time::nanos(10.0));
+    assert!(virtual_deadline(&taskset[1], scaling_factor) < Time::nanos(5.0));
+
+    // Plain EDF suffices.
+    taskset[2].wcet_hi = Time::nanos(2.0);
+    assert_eq!(Designer.design(&taskset).unwrap(), 1.0);
+
+    // U_LO(LO) = 0.5: x = 0.6 gives 0.3 + 0.8 > 1.
+    taskset[0].task.wcet = Time::nanos(5.0);
+    taskset[2].wcet_hi = Time::nanos(4.0);
+    assert!(Analysis.is_schedulable(&taskset).is_err());
+
+    taskset[1].wcet_hi = Time::zero();
+    assert!(Analysis.is_schedulable(&taskset).is_err());
+}
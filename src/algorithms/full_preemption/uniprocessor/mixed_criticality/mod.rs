@@ -0,0 +1,76 @@
+//! ## Mixed-Criticality Task Model
+//!
+//! Dual-criticality task model \[1\]: each task is either of LO or HI
+//! criticality, and HI tasks have a second, more pessimistic, WCET *C(HI)*,
+//! besides the *C(LO)* of every task. The system starts in LO mode, in which
+//! every task must meet its deadlines. Once a HI task executes beyond its
+//! *C(LO)*, the system switches to HI mode, the LO tasks are dropped, and the
+//! HI tasks must meet their deadlines with their *C(HI)*.
+//!
+//! #### Implements:
+//! - [`MCTask`] \
+//!   | Dual-criticality sporadic task
+//! - [`edf_vd12`] \
+//!   | EDF with Virtual Deadlines
+//!
+//! ---
+//! #### References:
+//! 1. S. Vestal, “Preemptive Scheduling of Multi-criticality Systems with
+//!    Varying Degrees of Execution Time Assurance,” in 28th IEEE International
+//!    Real-Time Systems Symposium (RTSS 2007), Dec. 2007, pp. 239–243.
+//!    doi: 10.1109/RTSS.2007.47.
+
+use crate::prelude::*;
+
+pub mod edf_vd12;
+
+/// Criticality level of a task.
+#[derive(Debug, Clone, Copy)]
+#[derive(PartialEq, Eq)]
+pub enum Criticality {
+    Lo,
+    Hi,
+}
+
+/// Dual-criticality sporadic task
+///
+/// Refer to the [module](`self`) level documentation.
+#[derive(Debug, Clone)]
+pub struct MCTask {
+    /// Task parameters, with the LO criticality WCET *C(LO)*.
+    pub task: RTTask,
+    /// HI criticality WCET *C(HI)*, not smaller than *C(LO)*. Ignored for the
+    /// LO tasks.
+    pub wcet_hi: Time,
+    pub criticality: Criticality,
+}
+
+impl MCTask {
+    /// Utilization in LO mode.
+    pub fn utilization_lo(&self) -> f64 {
+        self.task.utilization()
+    }
+
+    /// Utilization in HI mode, zero for the LO tasks.
+    pub fn utilization_hi(&self) -> f64 {
+        match self.criticality {
+            Criticality::Lo => 0.0,
+            Criticality::Hi => self.wcet_hi / self.task.period,
+        }
+    }
+}
+
+/// Total LO mode utilization of the tasks of the given criticality.
+pub fn utilization_lo(taskset: &[MCTask], criticality: Criticality) -> f64 {
+    taskset.iter()
+        .filter(|task| task.criticality == criticality)
+        .map(MCTask::utilization_lo)
+        .sum()
+}
+
+/// Total HI mode utilization of the HI tasks.
+pub fn utilization_hi(taskset: &[MCTask]) -> f64 {
+    taskset.iter()
+        .map(MCTask::utilization_hi)
+        .sum()
+}
@@ -0,0 +1,55 @@
+//! ## Tick-Driven EDF - *Derived from* Liu & Layland 1973
+//!
+//! #### Model:
+//! - Periodic/Sporadic Task model
+//! - Fully-Preemptive EDF scheduling
+//! - Tick-Driven scheduler (see [`TickModel`])
+//!
+//! #### Preconditions:
+//! - Constrained Deadlines
+//! - Deadlines greater than the scheduler's tick
+//!
+//! #### Implements:
+//! - [`Analysis::is_schedulable`] \
+//!   | linear *O(n)* complexity
+//!
+//! ---
+//! #### References:
+//! 1. C. L. Liu and J. W. Layland, “Scheduling Algorithms for Multiprogramming
+//!    in a Hard-Real-Time Environment,” J. ACM, vol. 20, no. 1, pp. 46–61,
+//!    Jan. 1973, doi: 10.1145/321738.321743.
+//! 2. K. Tindell, A. Burns, and A. J. Wellings, “An extendible approach for
+//!    analyzing fixed priority hard real-time tasks,” Real-Time Syst, vol. 6,
+//!    no. 2, pp. 133–151, Mar. 1994, doi: 10.1007/BF01088593.
+
+use crate::prelude::*;
+use crate::algorithms::full_preemption::uniprocessor::tick_driven::*;
+
+const ALGORITHM: &str = "Tick-Driven EDF (*Derived from* Liu & Layland 1973)";
+
+/// Tick-Driven EDF - *Derived from* Liu & Layland 1973 \[1\]
+///
+/// Refer to the [module](`self`) level documentation.
+pub struct Analysis {
+    pub model: TickModel,
+}
+
+impl SchedAnalysis<(), &[RTTask]> for Analysis {
+    fn analyzer_name(&self) -> &str { ALGORITHM }
+
+    fn check_preconditions(&self, taskset: &&[RTTask]) -> Result<(), SchedError> {
+        if !RTUtils::constrained_deadlines(taskset) {
+            Err(SchedError::constrained_deadlines())
+        } else {
+            self.model.check_preconditions(taskset)
+        }
+    }
+
+    fn run_test(&self, taskset: &[RTTask]) -> Result<(), SchedError> {
+        // Density bound on the transformed sporadic taskset [1, 2].
+        let sporadic_taskset = self.model.to_sporadic_taskset(taskset);
+        let total_density = RTUtils::total_density(&sporadic_taskset);
+
+        SchedError::result_from_schedulable(total_density <= 1f64)
+    }
+}
@@ -0,0 +1,117 @@
+//! ## Tick-Driven Response Time Analysis - Tindell, Burns, Wellings 1994
+//!
+//! #### Model:
+//! - Periodic/Sporadic Task model
+//! - Fully-Preemptive Fixed-Priority scheduling
+//! - Tick-Driven scheduler (see [`TickModel`])
+//!
+//! #### Preconditions:
+//! - Constrained Deadlines
+//! - Deadlines greater than the scheduler's tick
+//!
+//! #### Implements:
+//! - [`Analysis::is_schedulable`] \
+//!   | pseudo-polynomial complexity
+//!
+//! ---
+//! #### References:
+//! 1. K. Tindell, A. Burns, and A. J. Wellings, “An extendible approach for
+//!    analyzing fixed priority hard real-time tasks,” Real-Time Syst, vol. 6,
+//!    no. 2, pp. 133–151, Mar. 1994, doi: 10.1007/BF01088593.
+
+use crate::prelude::*;
+use crate::algorithms::full_preemption::uniprocessor::tick_driven::*;
+use crate::utils::dbf;
+
+const ALGORITHM: &str = "Tick-Driven RTA (Tindell, Burns, Wellings 1994)";
+
+/// Tick-Driven Response Time Analysis, Tindell, Burns, Wellings 1994 \[1\]
+///
+/// Refer to the [module](`self`) level documentation.
+///
+/// Returns:
+/// - Worst-Case Response Times of each task.
+pub struct Analysis {
+    pub model: TickModel,
+}
+
+impl SchedAnalysis<Vec<Time>, &[RTTask]> for Analysis {
+    fn analyzer_name(&self) -> &str { ALGORITHM }
+
+    fn check_preconditions(&self, taskset: &&[RTTask]) -> Result<(), SchedError> {
+        if !RTUtils::constrained_deadlines(taskset) {
+            Err(SchedError::constrained_deadlines())
+        } else {
+            self.model.check_preconditions(taskset)
+        }
+    }
+
+    fn run_test(&self, taskset: &[RTTask]) -> Result<Vec<Time>, SchedError> {
+        taskset.iter().enumerate()
+            .map(|(i, task)| {
+                let response_time = response_time(&taskset[0..=i], &self.model);
+
+                if response_time > task.deadline {
+                    Err(SchedError::NonSchedulable(Some(
+                        anyhow::format_err!("task {i} misses its deadline.")
+                    )))
+                } else {
+                    Ok(response_time)
+                }
+            })
+            .collect()
+    }
+}
+
+// Section 4 [1]: every job may be released up to one tick late, and the tick
+// handler interferes as the highest priority periodic task.
+fn response_time(taskset: &[RTTask], model: &TickModel) -> Time {
+    let task = taskset.last().unwrap();
+    let hp_tasks = &taskset[0..taskset.len() - 1];
+    let jitter = model.release_jitter();
+
+    let busy_window =
+        fixpoint_search_with_limit(
+            task.wcet,
+            task.deadline,
+            |window: &Time| {
+                let interference: Time =
+                    hp_tasks.iter()
+                    .map(|task_j| dbf::rbf_jitter(task_j, jitter, *window))
+                    .sum();
+
+                let overhead = (*window / model.tick).ceil() * model.overhead;
+
+                task.wcet + interference + overhead
+            }
+        );
+
+    busy_window + jitter
+}
+
+#[test]
+fn zero_overhead_adds_one_tick() {
+    let taskset = [
+        RTTask::new_ns(40, 100, 100),
+        RTTask::new_ns(60, 200, 200),
+    ];
+
+    let model = TickModel { tick: Time::nanos(5.0), overhead: Time::zero() };
+
+    // Task 1: w = 60 + ceil((w + 5) / 100) * 40 = 140, R = w + Q
+    assert_eq!(response_time(&taskset[0..=0], &model), Time::nanos(45.0));
+    assert_eq!(response_time(&taskset[0..=1], &model), Time::nanos(145.0));
+    assert!(Analysis { model }.is_schedulable(&taskset).is_ok());
+}
+
+#[test]
+fn tick_overhead_interference() {
+    let taskset = [
+        RTTask::new_ns(40, 100, 100),
+    ];
+
+    let model = TickModel { tick: Time::nanos(10.0), overhead: Time::nanos(2.0) };
+
+    // w = 40 + ceil(w / 10) * 2 = 50, R = w + Q
+    assert_eq!(response_time(&taskset, &model), Time::nanos(60.0));
+}
@@ -0,0 +1,99 @@
+//! ## Tick-Driven Scheduling - Tindell, Burns, Wellings 1994
+//!
+//! Model of a scheduler which is not event-driven, but instead is invoked
+//! periodically by a timer interrupt (the *tick*) of period *Q*. Jobs arriving
+//! between two ticks are only released at the next tick, thus suffering a
+//! release delay of at most *Q*. Every tick also charges the scheduler's
+//! overhead, which behaves as a highest priority periodic workload.
+//!
+//! #### Implements:
+//! - [`TickModel`] \
+//!   | Tick-Driven scheduler model
+//! - [`TickModel::to_sporadic_taskset`] \
+//!   | Transforms a taskset into an equivalent (pessimistic) sporadic taskset, \
+//!   | which can be analyzed by any event-driven schedulability test. \
+//!   | \
+//!   | linear *O(n)* complexity
+//!
+//! ---
+//! #### References:
+//! 1. K. Tindell, A. Burns, and A. J. Wellings, “An extendible approach for
+//!    analyzing fixed priority hard real-time tasks,” Real-Time Syst, vol. 6,
+//!    no. 2, pp. 133–151, Mar. 1994, doi: 10.1007/BF01088593.
+
+use crate::prelude::*;
+
+// Scheduling Algorithms
+pub mod earliest_deadline_first {
+    pub mod edf73;
+}
+
+pub mod fixed_priority {
+    pub mod rta94;
+}
+
+/// Tick-Driven scheduler model - Tindell, Burns, Wellings 1994 \[1\]
+///
+/// Refer to the [module](`self`) level documentation.
+#[derive(Debug, Clone)]
+pub struct TickModel {
+    /// Period of the scheduler's tick.
+    pub tick: Time,
+    /// Scheduler's overhead charged on every tick.
+    pub overhead: Time,
+}
+
+impl TickModel {
+    pub fn is_feasible(&self) -> bool {
+        self.tick > Time::zero() && self.overhead < self.tick
+    }
+
+    /// Maximum delay between the arrival of a job and its release.
+    pub fn release_jitter(&self) -> Time {
+        self.tick
+    }
+
+    /// Fraction of the processor consumed by the tick handler.
+    pub fn overhead_utilization(&self) -> f64 {
+        self.overhead / self.tick
+    }
+
+    /// Periodic task modelling the tick handler's overhead.
+    pub fn overhead_task(&self) -> RTTask {
+        RTTask {
+            wcet: self.overhead,
+            deadline: self.tick,
+            period: self.tick,
+        }
+    }
+
+    /// Transform the taskset into a sporadic taskset which accounts for the tick
+    /// scheduler. The tick handler is prepended as the highest priority task.
+    ///
+    /// A release delay of at most one tick shortens the relative deadline of
+    /// each job by *Q* and makes consecutive releases up to *Q* closer, thus
+    /// each task (*C*, *D*, *T*) is modelled as (*C*, *D - Q*, *T - Q*).
+    pub fn to_sporadic_taskset(&self, taskset: &[RTTask]) -> Vec<RTTask> {
+        let jitter = self.release_jitter();
+
+        std::iter::once(self.overhead_task())
+            .chain(taskset.iter().map(|task| RTTask {
+                wcet: task.wcet,
+                deadline: task.deadline - jitter,
+                period: task.period - jitter,
+            }))
+            .collect()
+    }
+
+    pub(crate) fn check_preconditions(&self, taskset: &[RTTask]) -> Result<(), SchedError> {
+        if !self.is_feasible() {
+            Err(SchedError::Precondition(Some(
+                anyhow::format_err!("tick model must have a positive tick greater than its overhead."))))
+        } else if taskset.iter().any(|task| task.deadline <= self.tick) {
+            Err(SchedError::Precondition(Some(
+                anyhow::format_err!("task deadlines must be greater than the scheduler's tick."))))
+        } else {
+            Ok(())
+        }
+    }
+}
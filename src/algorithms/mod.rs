@@ -1,9 +1,21 @@
 //! Root Module containing all the implemented analyses
 
 pub mod full_preemption {
+    pub mod migrations;
+    pub mod reservation_groups;
+    pub mod end_to_end;
+    pub mod tdma;
+    pub mod distributed;
+    pub mod heterogeneous;
+
     pub mod uniprocessor {
         pub mod earliest_deadline_first {
             pub mod edf73;
+            pub mod cbs98;
+            pub mod elastic98;
+            pub mod brh90;
+            pub mod qpa09;
+            pub mod srp91;
         }
 
         pub mod fixed_priority {
@@ -11,11 +23,25 @@ pub mod full_preemption {
             pub mod rta86;
             pub mod deadline_monotonic90;
             pub mod hyperbolic01;
+            pub mod hyperplanes04;
+            pub mod kuo_mok91;
+            pub mod burchard95;
+            pub mod tda89;
+            pub mod pcp90;
+            pub mod aperiodic_servers;
         }
 
         pub mod hierarchical {
             pub mod pr_model03;
+            pub mod fp_over_fp05;
+            pub mod arinc653;
         }
+
+        pub mod tick_driven;
+        pub mod asynchronous;
+        pub mod mixed_criticality;
+        pub mod gmf_model;
+        pub mod drt_model;
     }
 
     pub mod global_multiprocessor {
@@ -35,10 +61,33 @@ pub mod full_preemption {
 
         pub mod generic_work_conserving {
             pub mod bcl09;
+            pub mod bc07;
         }
 
         pub mod hierarchical {
             pub mod mpr_model09;
         }
+
+        pub mod parallel {
+            pub mod dag_model;
+            pub mod gang_model;
+        }
     }
-}
\ No newline at end of file
+}
+
+pub mod non_preemption {
+    pub mod uniprocessor {
+        pub mod earliest_deadline_first {
+            pub mod jsm91;
+        }
+
+        pub mod fixed_priority {
+            pub mod dbbl07;
+        }
+    }
+}
+
+pub mod cyclic_executive;
+pub mod task_dropping;
+pub mod wcet_budgets;
+pub mod registry;
\ No newline at end of file
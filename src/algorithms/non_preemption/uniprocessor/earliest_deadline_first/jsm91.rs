@@ -0,0 +1,118 @@
+//! ## Non-Preemptive EDF - Jeffay, Stanat & Martel 1991
+//!
+//! Once started, a job runs to completion, thus a job may be blocked by a
+//! later deadline job which started just before its release. With the tasks
+//! sorted by period, the taskset is schedulable if and only if the utilization
+//! is at most one and, for every task *i* and every *L* in *(T_1, T_i)*:
+//!   L >= C_i + sum_(j < i) floor((L - 1) / T_j) C_j
+//! i.e. the jobs of the shorter period tasks released in *[0, L - 1)* and a
+//! job of task *i*, started just before them, fit in *L* \[1\]. Time is
+//! discrete with a resolution of [`Time::one`].
+//!
+//! #### Model:
+//! - Periodic/Sporadic Task model
+//! - Non-Preemptive EDF scheduling
+//!
+//! #### Preconditions:
+//! - Implicit Deadlines
+//!
+//! #### Implements:
+//! - [`Analysis::is_schedulable`] \
+//!   | The inequality is checked only where its right hand side changes. \
+//!   | \
+//!   | pseudo-polynomial complexity
+//!
+//! ---
+//! #### References:
+//! 1. K. Jeffay, D. F. Stanat, and C. U. Martel, “On non-preemptive scheduling
+//!    of periodic and sporadic tasks,” in Proceedings Twelfth Real-Time
+//!    Systems Symposium, Dec. 1991, pp. 129–139. doi: 10.1109/REAL.1991.160366.
+
+use crate::prelude::*;
+use crate::utils::analysis_points::{merge_points, periodic_points};
+
+const ALGORITHM: &str = "Non-Preemptive EDF (Jeffay, Stanat & Martel 1991)";
+
+/// Non-Preemptive EDF, Jeffay, Stanat & Martel 1991 \[1\]
+///
+/// Refer to the [module](`self`) level documentation.
+///
+/// The tasks may be given in any order.
+pub struct Analysis;
+
+impl SchedAnalysis<(), &[RTTask]> for Analysis {
+    fn analyzer_name(&self) -> &str { ALGORITHM }
+
+    fn check_preconditions(&self, taskset: &&[RTTask]) -> Result<(), SchedError> {
+        if !RTUtils::implicit_deadlines(taskset) {
+            Err(SchedError::implicit_deadlines())
+        } else {
+            Ok(())
+        }
+    }
+
+    fn run_test(&self, taskset: &[RTTask]) -> Result<(), SchedError> {
+        let utilization = RTUtils::total_utilization(taskset);
+        if utilization > 1.0 {
+            return Err(SchedError::NonSchedulable(Some(
+                anyhow::format_err!("the utilization of {utilization} exceeds the processor."))));
+        }
+
+        let mut sorted = taskset.to_vec();
+        sorted.sort_by_key(|task| task.period);
+
+        for (i, task) in sorted.iter().enumerate().skip(1) {
+            if let Some(interval) = blocking_interval(&sorted[0..i], task) {
+                return Err(SchedError::NonSchedulable(Some(anyhow::format_err!(
+                    "a job of period {}ns, started just before the other jobs, overloads an interval of {}ns.",
+                    task.period.as_nanos(), interval.as_nanos()))));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl SchedProcessors for Analysis {
+    fn num_processors(&self) -> u64 { 1 }
+}
+
+// Condition 2 of Theorem 5.1 [1]: the first L in (T_1, T_i) at which the
+// demand exceeds L, if any. The demand only grows at the instants L such that
+// L - 1 is a multiple of a shorter period, so the first violation is at one
+// of them or at T_1 + 1.
+fn blocking_interval(shorter: &[RTTask], task: &RTTask) -> Option<Time> {
+    let first_period = shorter[0].period;
+    let demand = |interval: Time| {
+        task.wcet + shorter.iter()
+            .map(|other| ((interval - Time::one()) / other.period).floor() * other.wcet)
+            .sum::<Time>()
+    };
+
+    merge_points(shorter.iter().map(|other|
+            periodic_points(other.period, Time::zero(), -Time::one(), task.period - Time::one())))
+        .chain(std::iter::once(first_period + Time::one()))
+        .filter(|&interval| interval > first_period && interval < task.period)
+        .find(|&interval| demand(interval) > interval)
+}
+
+#[test]
+fn non_preemptive_edf() {
+    let taskset = |wcet| [RTTask::new_ns(wcet, 10, 10), RTTask::new_ns(2, 5, 5)];
+
+    // A job of the short task released just after one of the long task
+    // started completes at 4 + 2 = 6, right at its deadline.
+    assert!(Analysis.is_schedulable(&taskset(4)).is_ok());
+
+    // With a longer one it misses, even if preemptive EDF would not.
+    let err = Analysis.is_schedulable(&taskset(5)).unwrap_err();
+    assert!(format!("{err:#}").contains("6ns"));
+    assert!(crate::algorithms::full_preemption::uniprocessor::earliest_deadline_first::edf73::Analysis
+        .is_schedulable(&taskset(5)).is_ok());
+
+    // Equal periods never block each other past their deadlines.
+    assert!(Analysis.is_schedulable(&[RTTask::new_ns(3, 6, 6), RTTask::new_ns(3, 6, 6)]).is_ok());
+
+    assert!(Analysis.is_schedulable(&[RTTask::new_ns(4, 5, 5), RTTask::new_ns(3, 10, 10)]).is_err());
+    assert!(Analysis.is_schedulable(&[RTTask::new_ns(1, 4, 5)]).is_err());
+}
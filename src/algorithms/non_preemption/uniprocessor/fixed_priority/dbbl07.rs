@@ -0,0 +1,164 @@
+//! ## Non-Preemptive Response Time Analysis - Davis, Burns, Bril & Lukkien 2007
+//!
+//! Once started, a job runs to completion, thus each job may be blocked by the
+//! longest lower priority job, started just before its release, and is not
+//! preempted after it starts. The worst-case start time of the q-th job (from
+//! zero) of task *i* in its level-i busy period is the least fixpoint of:
+//!   w = B_i + q C_i + sum_(j < i) (floor(w / T_j) + 1) C_j
+//! with *B_i = max_(k > i) C_k - 1*, and its response time is
+//! *w + C_i - q T_i* \[1\]. The later jobs of the busy period may respond later
+//! than the first one, even with constrained deadlines, since the execution of
+//! a job defers the higher priority jobs into the next one. Time is discrete
+//! with a resolution of [`Time::one`].
+//!
+//! #### Model:
+//! - Periodic/Sporadic Task model
+//! - Non-Preemptive Fixed-Priority scheduling
+//!
+//! #### Preconditions:
+//! - Total utilization at most one
+//!
+//! #### Implements:
+//! - [`Analysis::is_schedulable`] \
+//!   | pseudo-polynomial complexity
+//!
+//! ---
+//! #### References:
+//! 1. R. I. Davis, A. Burns, R. J. Bril, and J. J. Lukkien, “Controller Area
+//!    Network (CAN) schedulability analysis: Refuted, revisited and revised,”
+//!    Real-Time Systems, vol. 35, no. 3, pp. 239–272, Apr. 2007,
+//!    doi: 10.1007/s11241-007-9012-7.
+
+use crate::prelude::*;
+use crate::utils::dbf;
+
+const ALGORITHM: &str = "Non-Preemptive RTA (Davis, Burns, Bril & Lukkien 2007)";
+
+/// Non-Preemptive Response Time Analysis, Davis, Burns, Bril & Lukkien 2007
+/// \[1\]
+///
+/// Refer to the [module](`self`) level documentation.
+///
+/// The tasks are sorted by priority, highest first.
+///
+/// Returns:
+/// - Worst-Case Response Times of each task.
+pub struct Analysis;
+
+impl SchedAnalysis<Vec<Time>, &[RTTask]> for Analysis {
+    fn analyzer_name(&self) -> &str { ALGORITHM }
+
+    fn check_preconditions(&self, taskset: &&[RTTask]) -> Result<(), SchedError> {
+        if RTUtils::total_utilization(taskset) > 1.0 {
+            Err(SchedError::Precondition(Some(
+                anyhow::format_err!("average processing load is not met."))))
+        } else {
+            Ok(())
+        }
+    }
+
+    fn run_test(&self, taskset: &[RTTask]) -> Result<Vec<Time>, SchedError> {
+        (0..taskset.len())
+            .map(|i| response_time(taskset, i))
+            .collect()
+    }
+}
+
+impl SchedProcessors for Analysis {
+    fn num_processors(&self) -> u64 { 1 }
+}
+
+// Blocking of task i, from the longest lower priority job which started one
+// instant before the critical instant.
+fn blocking(taskset: &[RTTask], i: usize) -> Time {
+    taskset[i + 1..].iter()
+        .map(|task| task.wcet - Time::one())
+        .fold(Time::zero(), Time::max)
+}
+
+// Revised analysis [1]: the level-i busy period includes the blocking, and
+// every job of task i released in it is checked in turn.
+fn response_time(taskset: &[RTTask], i: usize) -> Result<Time, SchedError> {
+    let task = &taskset[i];
+    let level_tasks = &taskset[0..=i];
+    let hp_tasks = &taskset[0..i];
+    let blocking = blocking(taskset, i);
+
+    // The level-i busy period is bounded if the level-i utilization is less
+    // than one, otherwise it ends within the hyperperiod unless blocked.
+    let utilization = RTUtils::total_utilization(level_tasks);
+    let busy_period_bound =
+        if utilization < 1.0 {
+            ((blocking + level_tasks.iter().map(|task| task.wcet).sum::<Time>()) / (1.0 - utilization)).ceil()
+        } else if blocking == Time::zero() {
+            RTUtils::hyperperiod(level_tasks)
+        } else {
+            return Err(SchedError::intractable());
+        };
+
+    let busy_period = fixpoint_search_with_limit(
+        blocking + task.wcet,
+        busy_period_bound,
+        |busy_period: &Time| blocking + dbf::total_rbf(level_tasks, *busy_period)
+    );
+
+    let mut response_time = Time::zero();
+    let mut start = blocking;
+    let mut job = 0.0;
+
+    while task.period * job < busy_period {
+        let deadline = task.period * job + task.deadline;
+
+        // The higher priority jobs released up to the start, included, run
+        // before it. The q-th job starts at least C_i after the previous one.
+        start = fixpoint_search_with_limit(
+            Time::max(start, blocking + task.wcet * job),
+            deadline - task.wcet + Time::one(),
+            |start: &Time| {
+                let interference: Time = hp_tasks.iter()
+                    .map(|hp_task| hp_task.wcet * ((*start / hp_task.period).floor() + 1.0))
+                    .sum();
+
+                blocking + task.wcet * job + interference
+            }
+        );
+
+        if start + task.wcet > deadline {
+            return Err(SchedError::NonSchedulable(Some(
+                anyhow::format_err!("job {job} of task {i} misses its deadline."))));
+        }
+
+        response_time = Time::max(response_time, start + task.wcet - task.period * job);
+        job += 1.0;
+    }
+
+    Ok(response_time)
+}
+
+#[test]
+fn non_preemptive_fp() {
+    let at = |t: u64| Time::nanos(t as f64);
+
+    // The first task is blocked by a job of the second one, started one
+    // instant before: 3 + 2.
+    let taskset = |wcet| [RTTask::new_ns(2, 5, 5), RTTask::new_ns(wcet, 10, 10)];
+    assert_eq!(Analysis.is_schedulable(&taskset(4)).unwrap(), vec![at(5), at(6)]);
+
+    let err = Analysis.is_schedulable(&taskset(5)).unwrap_err();
+    assert!(format!("{err:#}").contains("task 0"));
+
+    // The first job of the last task responds in 8, while the second one,
+    // released at 11, finds the processor busy with the jobs which the first
+    // one deferred, then waits for the ones released at 12 and 14: 20 - 11.
+    let taskset = |deadline| [
+        RTTask::new_ns(2, 6, 6),
+        RTTask::new_ns(2, 7, 7),
+        RTTask::new_ns(4, deadline, 11),
+    ];
+
+    assert_eq!(Analysis.is_schedulable(&taskset(9)).unwrap(), vec![at(5), at(7), at(9)]);
+    let err = Analysis.is_schedulable(&taskset(8)).unwrap_err();
+    assert!(format!("{err:#}").contains("job 1 of task 2"));
+
+    assert!(Analysis.is_schedulable(&[RTTask::new_ns(3, 4, 4), RTTask::new_ns(2, 5, 5)]).is_err());
+}
@@ -0,0 +1,627 @@
+//! ## Automatic Analyzer Matching
+//!
+//! Registry of the schedulability tests for the sporadic task model, and
+//! resolver of the tests applicable to a given taskset and [`Platform`]. A test
+//! is applicable if it supports the platform (refer to [`SchedPlatform`]) and
+//! the taskset meets its preconditions. Each excluded test is reported
+//! together with the reason of its exclusion, pointing to the offending task
+//! for the common taskset preconditions (e.g. constrained deadlines).
+//!
+//! The analysis [`Profile`] further selects the tests to run by their
+//! complexity and exactness: sufficient-only profiles skip the exact tests of
+//! pseudo-polynomial complexity, while escalating profiles run them only if
+//! none of the other tests deems the taskset schedulable.
+//!
+//! Each test is identified by a stable hierarchical ID, independent of its
+//! display name: `<platform>.<algorithm>.<test>`, where the platform is `up`
+//! (uniprocessor) or `smp` (global multiprocessor), the algorithm is `edf`,
+//! `fp` or `gwc` (any work-conserving one), and the test is the name of its
+//! module, e.g. `up.fp.rta86` or `smp.edf.baruah07`. The tests to run can be
+//! selected by their IDs or by any prefix of their components, e.g. `smp.edf`.
+//! When tests are reorganized, the [`ALIASES`] map their former IDs, or
+//! prefixes of them, to the new ones, so that the configurations written for
+//! older releases keep working, with a deprecation warning.
+//!
+//! Each test declares the model [`Assumption`]s under which its verdicts hold:
+//! the ones of the sporadic task model, and for the global multiprocessor tests
+//! identical processors with free migration. The tests whose bounds count
+//! time in discrete steps (e.g. *D - C + 1*) further assume integer times.
+//!
+//! The fixed priority tests run on the taskset sorted by the [`PriorityOrder`]
+//! of the platform, refer to [`Prioritized`], so that the tasksets need not be
+//! given sorted by priority.
+//!
+//! #### Implements:
+//! - [`analyzers`] \
+//!   | All the registered tests, instantiated for the given platform
+//! - [`test_assumptions`] \
+//!   | Model assumptions of the registered test with the given ID
+//! - [`resolve`] \
+//!   | Applicable tests for the given taskset, platform and profile, and the
+//!   | reasons of the exclusions. \
+//!   | \
+//!   | O(*analyzers*) precondition checks
+//! - [`Resolution::select`] \
+//!   | Tests selected by their IDs
+
+use crate::prelude::*;
+use super::full_preemption::uniprocessor::earliest_deadline_first::{edf73, brh90, qpa09};
+use super::full_preemption::uniprocessor::fixed_priority::{
+    rate_monotonic73, rta86, deadline_monotonic90, hyperbolic01, hyperplanes04, kuo_mok91, burchard95, tda89,
+};
+use super::full_preemption::global_multiprocessor::earliest_deadline_first::{
+    gbf03, baker03, bcl05, baruah07, bcl09 as edf_bcl09,
+};
+use super::full_preemption::global_multiprocessor::fixed_priority::{
+    deadline_monotonic_bcl05, rta_lc09, bcl09 as fp_bcl09,
+};
+use super::full_preemption::global_multiprocessor::generic_work_conserving::bcl09 as gwc_bcl09;
+
+/// Schedulability test of the registry, reduced to its verdict.
+pub trait RegisteredAnalysis: for<'a> SchedAnalysis<(), &'a [RTTask]> + SchedPlatform + Send + Sync { }
+
+impl<A> RegisteredAnalysis for A
+    where A: for<'a> SchedAnalysis<(), &'a [RTTask]> + SchedPlatform + Send + Sync { }
+
+/// Complexity class of a test, in the size of the taskset.
+#[derive(Debug, Clone, Copy)]
+#[derive(PartialEq, Eq, PartialOrd, Ord)]
+pub enum Complexity {
+    Polynomial,
+    PseudoPolynomial,
+}
+
+/// Schedulability test of the registry, with its complexity and exactness.
+pub struct Registered {
+    /// Stable ID of the test, refer to the [module](`self`) level
+    /// documentation.
+    pub id: &'static str,
+    /// Version of the test's implementation, increased whenever its verdicts
+    /// may change.
+    pub version: u32,
+    pub analysis: Box<dyn RegisteredAnalysis>,
+    pub complexity: Complexity,
+    /// Whether the test is necessary and sufficient for the platforms it
+    /// supports.
+    pub exact: bool,
+    /// Model assumptions under which the verdicts hold, refer to the
+    /// [module](`self`) level documentation.
+    pub assumptions: Vec<Assumption>,
+}
+
+impl Registered {
+    fn new<A: RegisteredAnalysis + 'static>(id: &'static str, analysis: A, complexity: Complexity, exact: bool) -> Self {
+        Self { id, version: 1, analysis: Box::new(analysis), complexity, exact, assumptions: Assumption::SPORADIC_MODEL.to_vec() }
+    }
+
+    fn assuming(mut self, assumptions: &[Assumption]) -> Self {
+        self.assumptions.extend_from_slice(assumptions);
+        self
+    }
+}
+
+// Assumptions of the global multiprocessor tests, besides the ones of the
+// sporadic task model.
+const GLOBAL: &[Assumption] = &[Assumption::IdenticalProcessors, Assumption::FreeMigration];
+const GLOBAL_INTEGER: &[Assumption] = &[Assumption::IdenticalProcessors, Assumption::FreeMigration, Assumption::IntegerTimes];
+
+/// Named analysis profile, selecting the tests to run.
+///
+/// Refer to the [module](`self`) level documentation.
+#[derive(Debug, Clone, Copy)]
+#[derive(PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum Profile {
+    /// Sufficient polynomial tests only, with a short timeout.
+    Quick,
+    /// Every sufficient test, with a longer timeout.
+    Thorough,
+    /// Every sufficient test, escalating to the exact tests, without timeout.
+    Exhaustive,
+}
+
+impl Profile {
+    /// Most complex tests run by the profile.
+    pub fn max_complexity(&self) -> Complexity {
+        match self {
+            Profile::Quick => Complexity::Polynomial,
+            Profile::Thorough | Profile::Exhaustive => Complexity::PseudoPolynomial,
+        }
+    }
+
+    /// Time limit of each test, if any.
+    pub fn timeout(&self) -> Option<std::time::Duration> {
+        match self {
+            Profile::Quick => Some(std::time::Duration::from_secs(1)),
+            Profile::Thorough => Some(std::time::Duration::from_secs(30)),
+            Profile::Exhaustive => None,
+        }
+    }
+
+    /// Whether the exact pseudo-polynomial tests are run when no other test
+    /// deems the taskset schedulable.
+    pub fn escalates_to_exact(&self) -> bool {
+        matches!(self, Profile::Exhaustive)
+    }
+}
+
+// Discard the results of the test other than its verdict, e.g. response times.
+struct Verdict<A, T>(A, std::marker::PhantomData<T>);
+
+impl<T, A> SchedAnalysis<(), &[RTTask]> for Verdict<A, T>
+    where
+        A: for<'a> SchedAnalysis<T, &'a [RTTask]>,
+{
+    fn analyzer_name(&self) -> &str { self.0.analyzer_name() }
+
+    fn check_preconditions(&self, taskset: &&[RTTask]) -> Result<(), SchedError> {
+        self.0.check_preconditions(taskset)
+    }
+
+    fn run_test(&self, taskset: &[RTTask]) -> Result<(), SchedError> {
+        self.0.run_test(taskset).map(|_| ())
+    }
+}
+
+impl<A: SchedPlatform, T> SchedPlatform for Verdict<A, T> {
+    fn supports(&self, platform: &Platform) -> bool { self.0.supports(platform) }
+}
+
+/// All the registered tests, instantiated for the given platform.
+///
+/// Refer to the [module](`self`) level documentation.
+pub fn analyzers(platform: &Platform) -> Vec<Registered> {
+    use Complexity::*;
+
+    let num_processors = platform.cpus;
+
+    let analyzers = vec![
+        Registered::new("up.edf.edf73", edf73::Analysis, Polynomial, true),
+        Registered::new("up.edf.brh90", brh90::Analysis, PseudoPolynomial, true),
+        Registered::new("up.edf.qpa09", qpa09::Analysis, PseudoPolynomial, true),
+        Registered::new("up.fp.rate_monotonic73", rate_monotonic73::Analysis, Polynomial, false),
+        Registered::new("up.fp.hyperbolic01", hyperbolic01::Analysis, Polynomial, false),
+        Registered::new("up.fp.kuo_mok91", kuo_mok91::Analysis, Polynomial, false),
+        Registered::new("up.fp.burchard95", burchard95::Analysis, Polynomial, false),
+        Registered::new("up.fp.deadline_monotonic90", deadline_monotonic90::Analysis, Polynomial, false),
+        Registered::new("up.fp.rta86", Verdict(rta86::Analysis, std::marker::PhantomData), PseudoPolynomial, true),
+        Registered::new("up.fp.hyperplanes04", hyperplanes04::Analysis, PseudoPolynomial, true),
+        Registered::new("up.fp.tda89", Verdict(tda89::Analysis, std::marker::PhantomData), PseudoPolynomial, true),
+        Registered::new("smp.edf.gbf03", gbf03::AnalysisSporadic { num_processors }, Polynomial, false).assuming(GLOBAL),
+        Registered::new("smp.edf.baker03", baker03::Analysis { num_processors }, Polynomial, false).assuming(GLOBAL),
+        Registered::new("smp.edf.bcl05", bcl05::Analysis { num_processors }, Polynomial, false).assuming(GLOBAL),
+        Registered::new("smp.edf.baruah07", baruah07::Analysis { num_processors }, PseudoPolynomial, false).assuming(GLOBAL),
+        Registered::new("smp.edf.bcl09", edf_bcl09::Analysis { num_processors }, Polynomial, false).assuming(GLOBAL_INTEGER),
+        Registered::new("smp.fp.deadline_monotonic_bcl05", deadline_monotonic_bcl05::Analysis { num_processors }, Polynomial, false)
+            .assuming(GLOBAL),
+        Registered::new("smp.fp.rta_lc09", rta_lc09::Analysis { num_processors }, PseudoPolynomial, false).assuming(GLOBAL_INTEGER),
+        Registered::new("smp.fp.bcl09", fp_bcl09::Analysis { num_processors }, Polynomial, false).assuming(GLOBAL_INTEGER),
+        Registered::new("smp.gwc.bcl09", gwc_bcl09::Analysis { num_processors }, Polynomial, false).assuming(GLOBAL_INTEGER),
+    ];
+
+    // The interrupt service routines interfere with every task, refer to
+    // [`WithInterruptServiceRoutines`].
+    let analyzers: Vec<_> =
+        if platform.isrs.is_empty() {
+            analyzers
+        } else {
+            analyzers.into_iter()
+                .map(|registered| Registered {
+                    analysis: Box::new(WithInterruptServiceRoutines { isrs: platform.isrs.clone(), analysis: registered.analysis }),
+                    ..registered
+                })
+                .collect()
+        };
+
+    // The tasks are sorted before the routines are added, which keep the
+    // highest priorities.
+    if platform.priority_order == PriorityOrder::Given {
+        return analyzers;
+    }
+
+    analyzers.into_iter()
+        .map(|registered| match registered.id.split('.').nth(1) {
+            Some("fp") => Registered {
+                analysis: Box::new(Prioritized { order: platform.priority_order.clone(), analysis: registered.analysis }),
+                ..registered
+            },
+            _ => registered,
+        })
+        .collect()
+}
+
+/// Test excluded by the [`resolve`]r.
+#[derive(Debug, Clone)]
+pub struct Exclusion {
+    pub id: &'static str,
+    pub analyzer: String,
+    pub reason: String,
+}
+
+/// Applicable and excluded tests for a taskset, platform and profile.
+pub struct Resolution {
+    pub applicable: Vec<Registered>,
+    /// Exact tests to run only if none of the applicable ones deems the taskset
+    /// schedulable.
+    pub escalation: Vec<Registered>,
+    pub excluded: Vec<Exclusion>,
+}
+
+/// Applicable tests for the given taskset, platform and profile, and the
+/// reasons of the exclusions.
+///
+/// Refer to the [module](`self`) level documentation.
+pub fn resolve(taskset: &[RTTask], platform: &Platform, profile: Profile) -> Result<Resolution, SchedError> {
+    platform.check_preconditions()?;
+
+    let mut resolution = Resolution { applicable: Vec::new(), escalation: Vec::new(), excluded: Vec::new() };
+
+    for registered in analyzers(platform) {
+        let analysis = &registered.analysis;
+        let escalation = registered.exact && registered.complexity == Complexity::PseudoPolynomial;
+
+        let reason =
+            if registered.complexity > profile.max_complexity() {
+                Some(format!("excluded by the {profile:?} profile: {:?} complexity.", registered.complexity))
+            } else if escalation && !profile.escalates_to_exact() {
+                Some(format!("excluded by the {profile:?} profile: exact tests are only run when escalating."))
+            } else if !analysis.supports(platform) {
+                Some(format!("does not support {:?} scheduling on a {:?} platform with {} processor(s).",
+                    platform.algorithm, platform.model, platform.cpus))
+            } else if let Err(err) = analysis.check_preconditions(&taskset) {
+                Some(explain_precondition(taskset, &err))
+            } else {
+                None
+            };
+
+        match reason {
+            Some(reason) =>
+                resolution.excluded.push(Exclusion { id: registered.id, analyzer: analysis.analyzer_name().to_owned(), reason }),
+            None if escalation =>
+                resolution.escalation.push(registered),
+            None =>
+                resolution.applicable.push(registered),
+        }
+    }
+
+    Ok(resolution)
+}
+
+/// Version of the registered test with the given ID, if any.
+pub fn test_version(id: &str) -> Option<u32> {
+    analyzers(&Platform::uniprocessor(SchedulingAlgorithm::FixedPriority)).into_iter()
+        .find(|registered| registered.id == id)
+        .map(|registered| registered.version)
+}
+
+/// Model assumptions of the registered test with the given ID, if any.
+pub fn test_assumptions(id: &str) -> Option<Vec<Assumption>> {
+    analyzers(&Platform::uniprocessor(SchedulingAlgorithm::FixedPriority)).into_iter()
+        .find(|registered| registered.id == id)
+        .map(|registered| registered.assumptions)
+}
+
+/// Former ID of a test, or prefix of IDs, accepted in place of the new one
+/// with a deprecation warning.
+#[derive(Debug, Clone, Copy)]
+pub struct Alias {
+    pub old: &'static str,
+    pub new: &'static str,
+    /// Release deprecating the alias.
+    pub since: &'static str,
+}
+
+/// Aliases of the renamed tests, refer to [`Alias`].
+///
+/// Before the hierarchical IDs, the tests were named after their modules
+/// alone. The `bcl09` modules of the global EDF, FP and work-conserving tests
+/// share their name, which thus has no alias.
+pub const ALIASES: &[Alias] = &[
+    Alias { old: "edf73", new: "up.edf.edf73", since: "0.1.0" },
+    Alias { old: "rate_monotonic73", new: "up.fp.rate_monotonic73", since: "0.1.0" },
+    Alias { old: "hyperbolic01", new: "up.fp.hyperbolic01", since: "0.1.0" },
+    Alias { old: "deadline_monotonic90", new: "up.fp.deadline_monotonic90", since: "0.1.0" },
+    Alias { old: "rta86", new: "up.fp.rta86", since: "0.1.0" },
+    Alias { old: "gbf03", new: "smp.edf.gbf03", since: "0.1.0" },
+    Alias { old: "baker03", new: "smp.edf.baker03", since: "0.1.0" },
+    Alias { old: "bcl05", new: "smp.edf.bcl05", since: "0.1.0" },
+    Alias { old: "baruah07", new: "smp.edf.baruah07", since: "0.1.0" },
+    Alias { old: "deadline_monotonic_bcl05", new: "smp.fp.deadline_monotonic_bcl05", since: "0.1.0" },
+    Alias { old: "rta_lc09", new: "smp.fp.rta_lc09", since: "0.1.0" },
+];
+
+/// The pattern with its deprecated prefix, if any, replaced by the current one,
+/// together with the deprecation warning.
+pub fn resolve_alias(pattern: &str) -> (String, Option<String>) {
+    resolve_alias_in(ALIASES, pattern)
+}
+
+fn resolve_alias_in(aliases: &[Alias], pattern: &str) -> (String, Option<String>) {
+    match aliases.iter().find(|alias| matches_id(pattern, alias.old)) {
+        Some(alias) => {
+            let new = format!("{}{}", alias.new, &pattern[alias.old.len()..]);
+            let warning = format!("test ID \"{pattern}\" is deprecated since {}, use \"{new}\" instead.", alias.since);
+            (new, Some(warning))
+        },
+        None => (pattern.to_owned(), None),
+    }
+}
+
+/// Whether the test ID is selected by the pattern: the ID itself or a prefix
+/// of its components.
+pub fn matches_id(id: &str, pattern: &str) -> bool {
+    id.strip_prefix(pattern)
+        .is_some_and(|rest| rest.is_empty() || rest.starts_with('.'))
+}
+
+impl Resolution {
+    /// Keep only the tests selected by any of the patterns, if any, excluding
+    /// the others. Returns the deprecation warnings of the aliases in use, and
+    /// fails if a pattern selects no registered test.
+    ///
+    /// Refer to the [module](`self`) level documentation.
+    pub fn select(&mut self, patterns: &[String]) -> anyhow::Result<Vec<String>> {
+        self.select_with_aliases(ALIASES, patterns)
+    }
+
+    fn select_with_aliases(&mut self, aliases: &[Alias], patterns: &[String]) -> anyhow::Result<Vec<String>> {
+        if patterns.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let (patterns, warnings): (Vec<_>, Vec<_>) = patterns.iter()
+            .map(|pattern| resolve_alias_in(aliases, pattern))
+            .unzip();
+
+        let ids: Vec<_> = analyzers(&Platform::uniprocessor(SchedulingAlgorithm::FixedPriority)).into_iter()
+            .map(|registered| registered.id)
+            .collect();
+
+        if let Some(pattern) = patterns.iter().find(|pattern| !ids.iter().any(|id| matches_id(id, pattern))) {
+            return Err(anyhow::format_err!("unknown test ID \"{pattern}\", expected one of: {}", ids.join(", ")));
+        }
+
+        let is_selected = |registered: &Registered| patterns.iter().any(|pattern| matches_id(registered.id, pattern));
+
+        for stage in [&mut self.applicable, &mut self.escalation] {
+            let (selected, unselected) = std::mem::take(stage).into_iter().partition(is_selected);
+            *stage = selected;
+
+            self.excluded.extend(unselected.into_iter().map(|registered: Registered| Exclusion {
+                id: registered.id,
+                analyzer: registered.analysis.analyzer_name().to_owned(),
+                reason: "not selected.".to_owned(),
+            }));
+        }
+
+        Ok(warnings.into_iter().flatten().collect())
+    }
+}
+
+// Point to the first offending task for the common taskset preconditions.
+fn explain_precondition(taskset: &[RTTask], err: &SchedError) -> String {
+    let is = |expected: SchedError| err.to_string() == expected.to_string();
+
+    let witness =
+        if is(SchedError::constrained_deadlines()) {
+            taskset.iter().position(|task| task.deadline > task.period)
+                .map(|i| format!("requires constrained deadlines, task {i} has D > T"))
+        } else if is(SchedError::implicit_deadlines()) {
+            taskset.iter().position(|task| task.deadline != task.period)
+                .map(|i| format!("requires implicit deadlines, task {i} has D != T"))
+        } else if is(SchedError::rate_monotonic()) {
+            (1..taskset.len()).find(|&i| taskset[i].period < taskset[i - 1].period)
+                .map(|i| format!("requires tasks sorted by period, task {i} has a shorter period than task {}", i - 1))
+        } else if is(SchedError::deadline_monotonic()) {
+            (1..taskset.len()).find(|&i| taskset[i].deadline < taskset[i - 1].deadline)
+                .map(|i| format!("requires tasks sorted by deadline, task {i} has a shorter deadline than task {}", i - 1))
+        } else {
+            None
+        };
+
+    witness.map_or_else(|| err.to_string(), |witness| format!("{witness}."))
+}
+
+#[test]
+fn resolve_analyzers() {
+    use SchedulingAlgorithm as Alg;
+
+    let names = |analyses: &[Registered]| -> Vec<String> {
+        analyses.iter().map(|registered| registered.analysis.analyzer_name().to_owned()).collect()
+    };
+
+    let taskset = [
+        RTTask::new_ns(1, 4, 4),
+        RTTask::new_ns(1, 6, 8),
+        RTTask::new_ns(2, 12, 10),
+    ];
+
+    let reason = |resolution: &Resolution, name: &str| resolution.excluded.iter()
+        .find(|exclusion| exclusion.analyzer == name)
+        .map(|exclusion| exclusion.reason.clone())
+        .unwrap();
+
+    // The utilization bound of uniprocessor EDF requires implicit deadlines,
+    // only the exact processor demand tests apply.
+    let resolution = resolve(&taskset, &Platform::uniprocessor(Alg::EarliestDeadlineFirst), Profile::Exhaustive).unwrap();
+    assert!(resolution.applicable.is_empty());
+    assert_eq!(names(&resolution.escalation), vec![brh90::Analysis.analyzer_name(), qpa09::Analysis.analyzer_name()]);
+    assert_eq!(reason(&resolution, edf73::Analysis.analyzer_name()),
+        "requires implicit deadlines, task 1 has D != T.");
+
+    let resolution = resolve(&taskset, &Platform::uniprocessor(Alg::FixedPriority), Profile::Exhaustive).unwrap();
+    // Only the exact RTA supports arbitrary deadlines.
+    assert!(resolution.applicable.is_empty());
+    assert_eq!(names(&resolution.escalation), vec![rta86::Analysis.analyzer_name()]);
+    assert_eq!(reason(&resolution, hyperplanes04::Analysis.analyzer_name()),
+        "requires constrained deadlines, task 2 has D > T.");
+    assert_eq!(reason(&resolution, tda89::Analysis.analyzer_name()),
+        "requires constrained deadlines, task 2 has D > T.");
+    assert_eq!(reason(&resolution, deadline_monotonic90::Analysis.analyzer_name()),
+        "requires constrained deadlines, task 2 has D > T.");
+    assert!(reason(&resolution, gbf03::AnalysisSporadic { num_processors: 1 }.analyzer_name())
+        .starts_with("does not support FixedPriority scheduling on a Uniprocessor platform"));
+
+    // Every uniprocessor FP test applies, as long as the tasks are sorted by
+    // priority as the Rate Monotonic tests expect. The exact tests are only
+    // run when escalating.
+    let taskset = [RTTask::new_ns(1, 4, 4), RTTask::new_ns(1, 6, 8)];
+    let resolution = resolve(&taskset, &Platform::uniprocessor(Alg::FixedPriority), Profile::Exhaustive).unwrap();
+    assert_eq!(names(&resolution.applicable), vec![
+        rate_monotonic73::Analysis.analyzer_name(),
+        hyperbolic01::Analysis.analyzer_name(),
+        deadline_monotonic90::Analysis.analyzer_name(),
+    ]);
+    assert_eq!(names(&resolution.escalation), vec![
+        rta86::Analysis.analyzer_name(),
+        hyperplanes04::Analysis.analyzer_name(),
+        tda89::Analysis.analyzer_name(),
+    ]);
+
+    // The harmonic chains and period spread bounds need implicit deadlines.
+    let implicit = [RTTask::new_ns(1, 4, 4), RTTask::new_ns(1, 8, 8)];
+    let resolution = resolve(&implicit, &Platform::uniprocessor(Alg::FixedPriority), Profile::Exhaustive).unwrap();
+    assert_eq!(names(&resolution.applicable), vec![
+        rate_monotonic73::Analysis.analyzer_name(),
+        hyperbolic01::Analysis.analyzer_name(),
+        kuo_mok91::Analysis.analyzer_name(),
+        burchard95::Analysis.analyzer_name(),
+        deadline_monotonic90::Analysis.analyzer_name(),
+    ]);
+
+    let resolution = resolve(&taskset, &Platform::uniprocessor(Alg::FixedPriority), Profile::Thorough).unwrap();
+    assert!(resolution.escalation.is_empty());
+    assert_eq!(reason(&resolution, rta86::Analysis.analyzer_name()),
+        "excluded by the Thorough profile: exact tests are only run when escalating.");
+
+    // The quick profile only runs polynomial tests.
+    let resolution = resolve(&taskset, &Platform::global(2, Alg::FixedPriority), Profile::Quick).unwrap();
+    assert_eq!(reason(&resolution, rta_lc09::Analysis { num_processors: 2 }.analyzer_name()),
+        "excluded by the Quick profile: PseudoPolynomial complexity.");
+
+    let taskset = [RTTask::new_ns(1, 8, 8), RTTask::new_ns(1, 4, 4)];
+    let resolution = resolve(&taskset, &Platform::uniprocessor(Alg::FixedPriority), Profile::Exhaustive).unwrap();
+    assert_eq!(reason(&resolution, rate_monotonic73::Analysis.analyzer_name()),
+        "requires tasks sorted by period, task 1 has a shorter period than task 0.");
+
+    // Under the Rate Monotonic priority order, the fixed priority tests sort
+    // the tasks themselves.
+    let platform = Platform { priority_order: PriorityOrder::RateMonotonic, ..Platform::uniprocessor(Alg::FixedPriority) };
+    let resolution = resolve(&taskset, &platform, Profile::Exhaustive).unwrap();
+    assert_eq!(names(&resolution.applicable), names(&resolve(&[taskset[1].clone(), taskset[0].clone()],
+        &Platform::uniprocessor(Alg::FixedPriority), Profile::Exhaustive).unwrap().applicable));
+    assert!(resolution.applicable.iter().all(|registered| registered.analysis.is_schedulable(&taskset).is_ok()));
+
+    let platform = Platform { priority_order: PriorityOrder::Custom(vec![1]), ..Platform::uniprocessor(Alg::FixedPriority) };
+    let resolution = resolve(&taskset, &platform, Profile::Exhaustive).unwrap();
+    assert!(resolution.applicable.is_empty() && resolution.escalation.is_empty());
+    assert_eq!(reason(&resolution, rta86::Analysis.analyzer_name()), "Precondition error: the priority of every task must be given.");
+
+    // The generic work-conserving test applies to any global algorithm.
+    let resolution = resolve(&taskset, &Platform::global(2, Alg::EarliestDeadlineZeroLaxity), Profile::Exhaustive).unwrap();
+    assert_eq!(names(&resolution.applicable), vec![(gwc_bcl09::Analysis { num_processors: 2 }).analyzer_name()]);
+    assert_eq!(resolution.applicable.len() + resolution.escalation.len() + resolution.excluded.len(), analyzers(&Platform::global(2, Alg::FixedPriority)).len());
+
+    assert!(resolve(&taskset, &Platform::global(0, Alg::FixedPriority), Profile::Quick).is_err());
+
+    // The tests are selected by their IDs, or by a prefix of their components.
+    let taskset = [RTTask::new_ns(1, 4, 4), RTTask::new_ns(1, 6, 8)];
+    let mut resolution = resolve(&taskset, &Platform::uniprocessor(Alg::FixedPriority), Profile::Exhaustive).unwrap();
+    resolution.select(&["up.fp.hyperbolic01".to_owned(), "up.fp.rta86".to_owned()]).unwrap();
+    assert_eq!(names(&resolution.applicable), vec![hyperbolic01::Analysis.analyzer_name()]);
+    assert_eq!(names(&resolution.escalation), vec![rta86::Analysis.analyzer_name()]);
+    assert_eq!(reason(&resolution, rate_monotonic73::Analysis.analyzer_name()), "not selected.");
+
+    assert!(matches_id("smp.edf.bcl09", "smp.edf") && !matches_id("smp.edf.bcl09", "smp.ed"));
+    assert!(resolution.select(&["up.fp.rta".to_owned()]).is_err());
+
+    // The former IDs keep working, with a deprecation warning.
+    let aliases = [Alias { old: "uni", new: "up", since: "0.2.0" }];
+    let mut resolution = resolve(&taskset, &Platform::uniprocessor(Alg::FixedPriority), Profile::Exhaustive).unwrap();
+    let warnings = resolution.select_with_aliases(&aliases, &["uni.fp.hyperbolic01".to_owned(), "up.fp.rta86".to_owned()]).unwrap();
+    assert_eq!(names(&resolution.applicable), vec![hyperbolic01::Analysis.analyzer_name()]);
+    assert_eq!(warnings, vec!["test ID \"uni.fp.hyperbolic01\" is deprecated since 0.2.0, use \"up.fp.hyperbolic01\" instead."]);
+
+    let ids: Vec<_> = analyzers(&Platform::uniprocessor(Alg::FixedPriority)).iter().map(|registered| registered.id).collect();
+    assert!(ids.iter().enumerate().all(|(i, id)| !ids[..i].contains(id)));
+
+    // The aliases point to registered tests, and do not shadow any of them.
+    assert!(ALIASES.iter().all(|alias| ids.iter().any(|id| matches_id(id, alias.new))));
+    assert!(ALIASES.iter().all(|alias| !ids.iter().any(|id| matches_id(id, alias.old))));
+
+    // The former flat module names select the same tests, with a warning.
+    let mut resolution = resolve(&taskset, &Platform::uniprocessor(Alg::FixedPriority), Profile::Exhaustive).unwrap();
+    let warnings = resolution.select(&["hyperbolic01".to_owned(), "rta86".to_owned()]).unwrap();
+    assert_eq!(names(&resolution.applicable), vec![hyperbolic01::Analysis.analyzer_name()]);
+    assert_eq!(names(&resolution.escalation), vec![rta86::Analysis.analyzer_name()]);
+    assert_eq!(warnings, vec![
+        "test ID \"hyperbolic01\" is deprecated since 0.1.0, use \"up.fp.hyperbolic01\" instead.",
+        "test ID \"rta86\" is deprecated since 0.1.0, use \"up.fp.rta86\" instead.",
+    ]);
+    assert_eq!(resolve_alias("baruah07").0, "smp.edf.baruah07");
+    assert_eq!(resolve_alias("up.fp.rta86"), ("up.fp.rta86".to_owned(), None));
+
+    let mut resolution = resolve(&taskset, &Platform::global(2, Alg::FixedPriority), Profile::Exhaustive).unwrap();
+    assert!(resolution.select(&["bcl09".to_owned()]).is_err());
+
+    // Every test holds under the sporadic task model, the global ones on
+    // identical processors.
+    for registered in analyzers(&Platform::global(2, Alg::FixedPriority)) {
+        assert!(Assumption::SPORADIC_MODEL.iter().all(|assumption| registered.assumptions.contains(assumption)));
+        assert_eq!(registered.assumptions.contains(&Assumption::FreeMigration), registered.id.starts_with("smp."), "{}", registered.id);
+    }
+
+    assert!(test_assumptions("smp.fp.rta_lc09").unwrap().contains(&Assumption::IntegerTimes));
+    assert!(!test_assumptions("up.fp.rta86").unwrap().contains(&Assumption::IntegerTimes));
+    assert_eq!(test_assumptions("up.fp.unknown"), None);
+}
+
+#[test]
+fn profiles() {
+    use SchedulingAlgorithm as Alg;
+    use std::time::Duration;
+
+    let taskset = [RTTask::new_ns(1, 4, 4), RTTask::new_ns(1, 8, 8)];
+
+    // The profiles are increasingly thorough, and slower.
+    assert!(Profile::Quick.timeout() < Profile::Thorough.timeout());
+    assert_eq!(Profile::Thorough.timeout(), Some(Duration::from_secs(30)));
+    assert_eq!(Profile::Exhaustive.timeout(), None);
+
+    for algorithm in [Alg::FixedPriority, Alg::EarliestDeadlineFirst] {
+        let platform = Platform::uniprocessor(algorithm);
+
+        let resolutions: Vec<_> = [Profile::Quick, Profile::Thorough, Profile::Exhaustive].into_iter()
+            .map(|profile| (profile, resolve(&taskset, &platform, profile).unwrap()))
+            .collect();
+
+        for (profile, resolution) in &resolutions {
+            // Every registered test is either run or excluded, once.
+            let total = resolution.applicable.len() + resolution.escalation.len() + resolution.excluded.len();
+            assert_eq!(total, analyzers(&platform).len());
+
+            assert!(resolution.applicable.iter().chain(&resolution.escalation)
+                .all(|registered| registered.complexity <= profile.max_complexity()));
+            assert!(resolution.applicable.iter()
+                .all(|registered| !(registered.exact && registered.complexity == Complexity::PseudoPolynomial)));
+            assert_eq!(resolution.escalation.is_empty(), !profile.escalates_to_exact(), "{profile:?}");
+        }
+
+        // The thorough and exhaustive profiles run the same sufficient tests,
+        // the quick one a subset of them.
+        let ids = |resolution: &Resolution| -> Vec<_> { resolution.applicable.iter().map(|registered| registered.id).collect() };
+        assert_eq!(ids(&resolutions[1].1), ids(&resolutions[2].1));
+        assert!(ids(&resolutions[0].1).iter().all(|id| ids(&resolutions[1].1).contains(id)));
+    }
+
+    // Global multiprocessor pseudo-polynomial sufficient tests only run from
+    // the thorough profile.
+    let platform = Platform::global(2, Alg::EarliestDeadlineFirst);
+    let is_applicable = |profile| resolve(&taskset, &platform, profile).unwrap().applicable.iter()
+        .any(|registered| registered.id == "smp.edf.baruah07");
+    assert!(!is_applicable(Profile::Quick));
+    assert!(is_applicable(Profile::Thorough));
+    assert!(is_applicable(Profile::Exhaustive));
+}
@@ -0,0 +1,160 @@
+//! ## Graceful Degradation by Task Dropping
+//!
+//! On overload, the least important tasks are dropped (or demoted to
+//! best-effort work, which is not guaranteed any progress) until the rest of
+//! the taskset is deemed schedulable by the given analysis, as in the
+//! best-effort scheduling policies of \[1\]. The tasks are dropped in order of
+//! increasing importance, the larger utilization first among the tasks of
+//! equal importance, and then the dropped tasks are readmitted, in order of
+//! decreasing importance, as long as the taskset stays schedulable: the
+//! resulting set of dropped tasks is minimal, i.e. readmitting any of them
+//! makes the taskset not schedulable.
+//!
+//! The analysis is run on the kept tasks only, in their original order, thus
+//! the demoted tasks must not interfere with the kept ones, e.g. they must run
+//! below every kept task in fixed priority scheduling, or in the background of
+//! EDF. The search assumes the analysis to be sustainable with respect to the
+//! removal of tasks, which holds for the tests of this crate.
+//!
+//! #### Model:
+//! - Periodic/Sporadic Task model, with per-task importance (refer to
+//!   [`WeightedTask`])
+//! - Any scheduling algorithm, as modeled by the given analysis, e.g. one of
+//!   the [`registry`](super::registry)
+//!
+//! #### Preconditions:
+//! - Non-negative importance of the tasks
+//!
+//! #### Implements:
+//! - [`Designer::design`] \
+//!   | Indices of the tasks to drop, such that the others are schedulable. \
+//!   | \
+//!   | linear *O(n)* number of runs of the analysis
+//!
+//! ---
+//! #### References:
+//! 1. C. D. Locke, “Best-Effort Decision Making for Real-Time Scheduling,”
+//!    Ph.D. dissertation, Carnegie Mellon University, 1986.
+
+use crate::prelude::*;
+
+const ALGORITHM: &str = "Graceful Degradation by Task Dropping";
+
+/// Task with its importance
+///
+/// Refer to the [module](`self`) level documentation.
+#[derive(Debug, Clone)]
+pub struct WeightedTask {
+    pub task: RTTask,
+    /// Importance of the task, the larger the later it is dropped.
+    pub importance: f64,
+}
+
+/// Graceful Degradation by Task Dropping
+///
+/// Refer to the [module](`self`) level documentation.
+///
+/// Returns:
+/// - The indices of the tasks to drop, in increasing order, such that the
+///   other tasks are schedulable according to the analysis.
+pub struct Designer<A> {
+    pub analysis: A,
+}
+
+impl<A> SchedDesign<&[WeightedTask], Vec<usize>> for Designer<A>
+    where
+        A: for<'a> SchedAnalysis<(), &'a [RTTask]>,
+{
+    fn designer_name(&self) -> &str { ALGORITHM }
+
+    fn check_preconditions(&self, taskset: &&[WeightedTask]) -> Result<(), SchedError> {
+        if taskset.iter().any(|task| task.importance.is_nan() || task.importance < 0.0) {
+            Err(SchedError::Precondition(Some(
+                anyhow::format_err!("the importance of the tasks must be non-negative."))))
+        } else {
+            Ok(())
+        }
+    }
+
+    fn run_designer(&self, taskset: &[WeightedTask]) -> Result<Vec<usize>, SchedError> {
+        let mut dropped = vec![false; taskset.len()];
+
+        // Least important first, then largest utilization first.
+        let mut order: Vec<usize> = (0..taskset.len()).collect();
+        order.sort_by(|&l, &r| {
+            taskset[l].importance.total_cmp(&taskset[r].importance)
+                .then(taskset[r].task.utilization().total_cmp(&taskset[l].task.utilization()))
+        });
+
+        let mut last_error = None;
+        for &i in order.iter() {
+            match self.is_schedulable_without(taskset, &dropped) {
+                Ok(()) => break,
+                Err(err) => { last_error = Some(err); dropped[i] = true; },
+            }
+        }
+
+        if dropped.iter().all(|dropped| *dropped) {
+            return Err(SchedError::NonSchedulable(Some(anyhow::format_err!(
+                "no task is schedulable on its own, last error: {:#}", last_error.unwrap()))));
+        }
+
+        // Readmit the dropped tasks which fit, most important first.
+        for &i in order.iter().rev() {
+            if dropped[i] {
+                dropped[i] = false;
+                dropped[i] = self.is_schedulable_without(taskset, &dropped).is_err();
+            }
+        }
+
+        Ok((0..taskset.len()).filter(|&i| dropped[i]).collect())
+    }
+}
+
+impl<A> Designer<A>
+    where
+        A: for<'a> SchedAnalysis<(), &'a [RTTask]>,
+{
+    // The empty taskset is schedulable.
+    fn is_schedulable_without(&self, taskset: &[WeightedTask], dropped: &[bool]) -> anyhow::Result<()> {
+        let kept: Vec<RTTask> = taskset.iter().zip(dropped)
+            .filter(|(_, dropped)| !**dropped)
+            .map(|(task, _)| task.task.clone())
+            .collect();
+
+        if kept.is_empty() {
+            return Ok(());
+        }
+
+        self.analysis.is_schedulable(&kept)
+    }
+}
+
+#[test]
+fn task_dropping() {
+    use crate::algorithms::full_preemption::uniprocessor::earliest_deadline_first::edf73;
+
+    let weighted = |wcet: u64, period: u64, importance: f64|
+        WeightedTask { task: RTTask::new_ns(wcet, period, period), importance };
+    let designer = Designer { analysis: edf73::Analysis };
+
+    // Already schedulable.
+    let taskset = [weighted(2, 4, 1.0), weighted(2, 8, 1.0)];
+    assert_eq!(designer.design(&taskset).unwrap(), Vec::<usize>::new());
+
+    // Dropping the least important task is enough.
+    let taskset = [weighted(2, 4, 10.0), weighted(2, 8, 1.0), weighted(3, 8, 5.0), weighted(1, 16, 2.0)];
+    assert_eq!(designer.design(&taskset).unwrap(), vec![1]);
+
+    // Both the least important tasks are dropped, but the first one is then
+    // readmitted.
+    let taskset = [weighted(2, 4, 10.0), weighted(1, 16, 1.0), weighted(5, 8, 2.0)];
+    assert_eq!(designer.design(&taskset).unwrap(), vec![2]);
+
+    // Equal importance, the heavier task is dropped.
+    let taskset = [weighted(2, 4, 1.0), weighted(3, 8, 1.0), weighted(5, 8, 1.0)];
+    assert_eq!(designer.design(&taskset).unwrap(), vec![2]);
+
+    assert!(designer.design(&[weighted(5, 4, 1.0)]).is_err());
+    assert!(designer.design(&[weighted(1, 4, -1.0)]).is_err());
+}
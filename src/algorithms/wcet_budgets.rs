@@ -0,0 +1,214 @@
+//! ## WCET Budget Allocation
+//!
+//! Inverse of the schedulability problem: the periods and deadlines of the
+//! tasks are fixed, e.g. by the control requirements, while their software is
+//! still to be written, and the capacity of the platform is to be split into
+//! per-task WCET budgets, the largest ones the given analysis deems
+//! schedulable. The budgets maximize, heuristically, the weighted utilization
+//! *sum(w_i * C_i / T_i)*:
+//! 1. the budgets are scaled together, each proportionally to the weight of
+//!    its task times its period, i.e. the utilization is split among the tasks
+//!    proportionally to their weights, up to the largest scale deemed
+//!    schedulable, as in the sensitivity analysis of the WCETs of \[1\];
+//! 2. then the budget of each task, in order of decreasing weight, is enlarged
+//!    as much as possible, keeping the others fixed, to claim the capacity
+//!    left by the tasks whose budgets are limited by their deadlines or by the
+//!    interference they suffer.
+//!
+//! The budgets are multiples of the quantum of the given quantization, at least
+//! one quantum and at most the deadline of their task. The search assumes the
+//! analysis to be sustainable with respect to the WCETs.
+//!
+//! #### Model:
+//! - Periodic/Sporadic Task model, with per-task weights (refer to
+//!   [`BudgetedTask`])
+//! - Any scheduling algorithm, as modeled by the given analysis, e.g. one of
+//!   the [`registry`](super::registry)
+//!
+//! #### Preconditions:
+//! - Non-negative weights
+//! - Positive time quantum, not greater than the deadlines and periods
+//!
+//! #### Implements:
+//! - [`Designer::design`] \
+//!   | WCET budget of each task, such that the taskset is schedulable. \
+//!   | \
+//!   | *O(n log(D_max / q))* runs of the analysis
+//!
+//! ---
+//! #### References:
+//! 1. E. Bini, M. Di Natale, and G. Buttazzo, “Sensitivity analysis for
+//!    fixed-priority real-time systems,” Real-Time Systems, vol. 39, no. 1,
+//!    pp. 5–30, Aug. 2008, doi: 10.1007/s11241-007-9039-9.
+
+use crate::prelude::*;
+
+const ALGORITHM: &str = "WCET Budget Allocation";
+
+// Iterations of the bisection on the scale of the budgets, enough to reach
+// the precision of f64.
+const SCALE_ITERATIONS: usize = 64;
+
+/// Task whose WCET budget is to be designed
+///
+/// Refer to the [module](`self`) level documentation.
+#[derive(Debug, Clone)]
+pub struct BudgetedTask {
+    pub deadline: Time,
+    pub period: Time,
+    /// Weight of the utilization of the task in the objective.
+    pub weight: f64,
+}
+
+/// WCET Budget Allocation
+///
+/// Refer to the [module](`self`) level documentation.
+///
+/// Returns:
+/// - The WCET budget of each task, such that the taskset is schedulable
+///   according to the analysis.
+pub struct Designer<A> {
+    pub analysis: A,
+    pub quantization: Quantization,
+}
+
+impl<A> SchedDesign<&[BudgetedTask], Vec<Time>> for Designer<A>
+    where
+        A: for<'a> SchedAnalysis<(), &'a [RTTask]>,
+{
+    fn designer_name(&self) -> &str { ALGORITHM }
+
+    fn check_preconditions(&self, taskset: &&[BudgetedTask]) -> Result<(), SchedError> {
+        if taskset.iter().any(|task| task.weight.is_nan() || task.weight < 0.0) {
+            Err(SchedError::Precondition(Some(
+                anyhow::format_err!("the weights of the tasks must be non-negative."))))
+        } else {
+            self.quantization.check_preconditions(&self.taskset(taskset, &self.min_budgets(taskset)))
+        }
+    }
+
+    fn run_designer(&self, taskset: &[BudgetedTask]) -> Result<Vec<Time>, SchedError> {
+        let min_budgets = self.min_budgets(taskset);
+        if let Err(err) = self.is_schedulable(taskset, &min_budgets) {
+            return Err(SchedError::NonSchedulable(Some(anyhow::format_err!(
+                "not schedulable with budgets of one quantum, error: {err:#}"))));
+        }
+
+        // Step 1: the largest schedulable scale of the proportional budgets.
+        let total_weight: f64 = taskset.iter().map(|task| task.weight).sum();
+        let max_scale = taskset.iter()
+            .filter(|task| task.weight > 0.0)
+            .map(|task| task.deadline / task.period * total_weight / task.weight)
+            .fold(0.0, f64::max);
+
+        let (mut low, mut high) = (0.0, max_scale);
+        let mut budgets = min_budgets;
+        if self.is_schedulable(taskset, &self.scaled_budgets(taskset, max_scale)).is_ok() {
+            budgets = self.scaled_budgets(taskset, max_scale);
+        } else {
+            for _ in 0..SCALE_ITERATIONS {
+                let mid = low + (high - low) / 2.0;
+                let candidate = self.scaled_budgets(taskset, mid);
+
+                if self.is_schedulable(taskset, &candidate).is_ok() {
+                    (low, budgets) = (mid, candidate);
+                } else {
+                    high = mid;
+                }
+            }
+        }
+
+        // Step 2: enlarge each budget in turn, heaviest task first, searching
+        // the largest schedulable number of quanta.
+        let mut order: Vec<usize> = (0..taskset.len()).collect();
+        order.sort_by(|&l, &r| taskset[r].weight.total_cmp(&taskset[l].weight));
+
+        for i in order {
+            let (mut low, mut high) = (
+                (budgets[i] / self.quantization.quantum).round() as u64,
+                (self.quantization.round_down(taskset[i].deadline) / self.quantization.quantum).round() as u64,
+            );
+
+            while low < high {
+                let mid = low + (high - low).div_ceil(2);
+                budgets[i] = self.quantization.quantum * mid as f64;
+
+                if self.is_schedulable(taskset, &budgets).is_ok() {
+                    low = mid;
+                } else {
+                    high = mid - 1;
+                }
+            }
+
+            budgets[i] = self.quantization.quantum * low as f64;
+        }
+
+        Ok(budgets)
+    }
+}
+
+impl<A> Designer<A>
+    where
+        A: for<'a> SchedAnalysis<(), &'a [RTTask]>,
+{
+    fn taskset(&self, taskset: &[BudgetedTask], budgets: &[Time]) -> Vec<RTTask> {
+        taskset.iter().zip(budgets)
+            .map(|(task, budget)| RTTask { wcet: *budget, deadline: task.deadline, period: task.period })
+            .collect()
+    }
+
+    fn is_schedulable(&self, taskset: &[BudgetedTask], budgets: &[Time]) -> anyhow::Result<()> {
+        self.analysis.is_schedulable(&self.taskset(taskset, budgets))
+    }
+
+    fn min_budgets(&self, taskset: &[BudgetedTask]) -> Vec<Time> {
+        vec![self.quantization.quantum; taskset.len()]
+    }
+
+    // Budgets whose utilizations are the given scale split proportionally to
+    // the weights, within one quantum and the deadlines.
+    fn scaled_budgets(&self, taskset: &[BudgetedTask], scale: f64) -> Vec<Time> {
+        let total_weight: f64 = taskset.iter().map(|task| task.weight).sum();
+
+        taskset.iter()
+            .map(|task| {
+                let share = if total_weight > 0.0 { task.weight / total_weight } else { 0.0 };
+
+                self.quantization.round_down(task.period * (scale * share))
+                    .min(self.quantization.round_down(task.deadline))
+                    .max(self.quantization.quantum)
+            })
+            .collect()
+    }
+}
+
+#[test]
+fn wcet_budgets() {
+    use crate::algorithms::full_preemption::uniprocessor::earliest_deadline_first::edf73;
+
+    let at = |t: u64| Time::nanos(t as f64);
+    let budgeted = |period: u64, weight: f64| BudgetedTask { deadline: at(period), period: at(period), weight };
+    let designer = Designer { analysis: edf73::Analysis, quantization: Quantization::nanos() };
+
+    // Equal weights, equal utilizations.
+    let budgets = designer.design(&[budgeted(10, 1.0), budgeted(20, 1.0)]).unwrap();
+    assert_eq!(budgets, vec![at(5), at(10)]);
+
+    // The heavier task takes the capacity left by the rounding.
+    let budgets = designer.design(&[budgeted(10, 3.0), budgeted(10, 1.0)]).unwrap();
+    assert_eq!(budgets, vec![at(8), at(2)]);
+
+    // A task without weight gets the leftover capacity.
+    let budgets = designer.design(&[budgeted(10, 1.0), budgeted(10, 0.0)]).unwrap();
+    assert_eq!(budgets, vec![at(9), at(1)]);
+
+    // Budgets are limited by the deadlines.
+    let budgets = designer.design(&[budgeted(4, 1.0), budgeted(100, 100.0)]).unwrap();
+    assert!(budgets[0] >= at(1) && budgets[1] <= at(100));
+    let utilization: f64 = budgets[0] / at(4) + budgets[1] / at(100);
+    assert!(utilization <= 1.0 && utilization > 0.98);
+
+    // Not even one quantum per task fits.
+    assert!(designer.design(&[budgeted(1, 1.0), budgeted(1, 1.0)]).is_err());
+    assert!(designer.design(&[budgeted(10, -1.0)]).is_err());
+}
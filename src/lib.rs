@@ -17,23 +17,59 @@ pub mod prelude {
     pub use eva_rt_common::prelude::*;
     pub use eva_rt_common::utils::prelude::*;
     pub use super::utils::{
+        assumptions::*,
         binary_search::*,
+        deadline_model::*,
         fixpoint_search::*,
+        instrumentation::{Measurement, MemoryUsage, measure, measured_is_schedulable},
+        numeric_validation::{NumericWarning, SchedValidation},
+        priority_order::*,
+        quantization::*,
         sched_error::*,
         sched_analysis::*,
+        sched_bounds::*,
         sched_design::*,
+        sched_stats::*,
         time_iterators::*,
+        time_rescaling::*,
+        wcet_margin::*,
     };
+    pub use super::platform::*;
 }
 
 pub mod algorithms;
+pub mod platform;
+pub mod simulator;
 
 /// Utility Functions
 pub mod utils {
+    pub mod analysis_points;
+    pub mod assumptions;
     pub mod binary_search;
+    #[cfg(test)]
+    pub(crate) mod bench;
+    pub mod dbf;
+    pub mod deadline_model;
     pub mod fixpoint_search;
+    pub mod instrumentation;
+    pub mod interference;
+    pub(crate) mod interference_block;
+    pub mod numeric_validation;
+    pub mod parameter_ranges;
+    pub mod priority_order;
+    pub mod quantization;
+    pub mod resources;
     pub mod sched_error;
     pub mod sched_analysis;
+    pub mod sched_bounds;
     pub mod sched_design;
+    pub mod sched_stats;
+    pub(crate) mod taskset_soa;
+    #[cfg(test)]
+    pub(crate) mod test_tasksets;
     pub mod time_iterators;
+    pub mod time_rescaling;
+    pub mod utilization_bounds;
+    pub mod warm_start;
+    pub mod wcet_margin;
 }
\ No newline at end of file
@@ -0,0 +1,254 @@
+//! ## Platform Description
+//!
+//! Typed description of the target platform and of its scheduling algorithm,
+//! shared by the library and the command line tools. Analyses declare the
+//! platforms they apply to by implementing [`SchedPlatform`], so that callers
+//! can match them against a [`Platform`] automatically.
+//!
+//! With the `serde` feature, the description can be (de)serialized, e.g. from
+//! the JSON file describing the target machine. With the `schemars` feature,
+//! its JSON Schema can be generated as well.
+//!
+//! #### Implements:
+//! - [`Platform`] \
+//!   | Platform model, scheduling algorithm, processors, clusters, speeds,
+//!   | core types, interrupt service routines and priority order
+//! - [`InterruptServiceRoutine`] \
+//!   | Sporadic interrupt served at higher priority than any task
+
+use crate::prelude::*;
+
+/// Organization of the processors of the platform.
+#[derive(Debug, Clone, Copy)]
+#[derive(PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum PlatformModel {
+    Uniprocessor,
+    /// Identical processors, jobs may migrate among all of them.
+    GlobalMultiprocessor,
+    /// Identical processors, tasks are pinned onto a single processor.
+    PartitionedMultiprocessor,
+    /// Identical processors grouped in clusters, jobs may migrate only within
+    /// the cluster of their task.
+    ClusteredMultiprocessor,
+    /// Processors with different speeds, jobs may migrate among all of them.
+    UniformMultiprocessor,
+    /// Processors of different types, e.g. big.LITTLE cores, on which the
+    /// tasks have different WCETs. Tasks are pinned onto a single processor.
+    HeterogeneousMultiprocessor,
+}
+
+/// Scheduling algorithm of the platform.
+#[derive(Debug, Clone, Copy)]
+#[derive(PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum SchedulingAlgorithm {
+    EarliestDeadlineFirst,
+    /// Tasks are sorted by priority, highest first.
+    FixedPriority,
+    EarliestDeadlineZeroLaxity,
+    FixedPriorityZeroLaxity,
+}
+
+/// Description of the target platform.
+///
+/// Refer to the [module](`self`) level documentation.
+#[derive(Debug, Clone)]
+#[derive(PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Platform {
+    pub model: PlatformModel,
+    pub algorithm: SchedulingAlgorithm,
+    pub cpus: u64,
+    /// Number of processors of each cluster, empty unless the platform is
+    /// clustered.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub clusters: Vec<u64>,
+    /// Speed of each processor, empty unless the platform is uniform.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub speeds: Vec<f64>,
+    /// Core type of each processor, as an index into the per-type WCETs of the
+    /// tasks, empty unless the platform is heterogeneous.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub core_types: Vec<usize>,
+    /// Interrupt service routines, served at higher priority than any task,
+    /// refer to [`WithInterruptServiceRoutines`].
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub isrs: Vec<InterruptServiceRoutine>,
+    /// Priority assignment of the tasks under fixed priority scheduling,
+    /// refer to [`PriorityOrder`].
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub priority_order: PriorityOrder,
+}
+
+/// Interrupt service routine, triggered by a sporadic interrupt and served at
+/// higher priority than any task.
+#[derive(Debug, Clone)]
+#[derive(PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct InterruptServiceRoutine {
+    /// Minimum inter-arrival time of the interrupt, in microseconds.
+    pub min_interarrival_us: f64,
+    /// Worst-case execution time of the routine, in microseconds.
+    pub wcet_us: f64,
+}
+
+impl InterruptServiceRoutine {
+    /// Sporadic task modelling the routine.
+    pub fn task(&self) -> RTTask {
+        RTTask {
+            wcet: Time::micros(self.wcet_us),
+            deadline: Time::micros(self.min_interarrival_us),
+            period: Time::micros(self.min_interarrival_us),
+        }
+    }
+}
+
+impl Platform {
+    pub fn uniprocessor(algorithm: SchedulingAlgorithm) -> Self {
+        Self { model: PlatformModel::Uniprocessor, algorithm, cpus: 1, clusters: Vec::new(), speeds: Vec::new(), core_types: Vec::new(), isrs: Vec::new(), priority_order: PriorityOrder::Given }
+    }
+
+    pub fn global(cpus: u64, algorithm: SchedulingAlgorithm) -> Self {
+        Self { model: PlatformModel::GlobalMultiprocessor, algorithm, cpus, clusters: Vec::new(), speeds: Vec::new(), core_types: Vec::new(), isrs: Vec::new(), priority_order: PriorityOrder::Given }
+    }
+
+    /// Heterogeneous platform, given the core type of each processor.
+    pub fn heterogeneous(core_types: Vec<usize>, algorithm: SchedulingAlgorithm) -> Self {
+        Self {
+            model: PlatformModel::HeterogeneousMultiprocessor,
+            cpus: core_types.len() as u64,
+            core_types,
+            ..Self::global(0, algorithm)
+        }
+    }
+
+    /// Number of core types, i.e. of per-type WCETs the tasks must declare.
+    pub fn num_core_types(&self) -> usize {
+        self.core_types.iter().max().map_or(0, |max| max + 1)
+    }
+
+    pub fn check_preconditions(&self) -> Result<(), SchedError> {
+        let error = |msg: &str| Err(SchedError::Precondition(Some(anyhow::format_err!("{msg}"))));
+
+        if self.cpus == 0 {
+            error("the platform must have at least one processor.")
+        } else if self.model == PlatformModel::Uniprocessor && self.cpus != 1 {
+            error("a uniprocessor platform must have exactly one processor.")
+        } else if self.model == PlatformModel::ClusteredMultiprocessor
+            && (self.clusters.contains(&0) || self.clusters.iter().sum::<u64>() != self.cpus)
+        {
+            error("the clusters must be non empty and cover all the processors.")
+        } else if self.model != PlatformModel::ClusteredMultiprocessor && !self.clusters.is_empty() {
+            error("only clustered platforms have clusters.")
+        } else if self.model == PlatformModel::UniformMultiprocessor
+            && (self.speeds.len() as u64 != self.cpus || self.speeds.iter().any(|speed| *speed <= 0.0))
+        {
+            error("a uniform platform must have a positive speed for each processor.")
+        } else if self.model != PlatformModel::UniformMultiprocessor && !self.speeds.is_empty() {
+            error("only uniform platforms have processor speeds.")
+        } else if self.model == PlatformModel::HeterogeneousMultiprocessor && self.core_types.len() as u64 != self.cpus {
+            error("a heterogeneous platform must have a core type for each processor.")
+        } else if self.model != PlatformModel::HeterogeneousMultiprocessor && !self.core_types.is_empty() {
+            error("only heterogeneous platforms have core types.")
+        } else if self.isrs.iter().any(|isr| !(isr.wcet_us >= 0.0 && isr.wcet_us < isr.min_interarrival_us)) {
+            error("interrupt service routines must have a non-negative WCET less than their inter-arrival time.")
+        } else if self.priority_order != PriorityOrder::Given
+            && !matches!(self.algorithm, SchedulingAlgorithm::FixedPriority | SchedulingAlgorithm::FixedPriorityZeroLaxity)
+        {
+            error("only fixed priority platforms have a priority order.")
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Sporadic tasks modelling the interrupt service routines.
+    pub fn isr_tasks(&self) -> Vec<RTTask> {
+        self.isrs.iter().map(InterruptServiceRoutine::task).collect()
+    }
+
+    /// Whether this is the given platform model and scheduling algorithm, with
+    /// the given number of processors.
+    pub fn matches(&self, model: PlatformModel, algorithm: SchedulingAlgorithm, cpus: u64) -> bool {
+        self.model == model && self.algorithm == algorithm && self.cpus == cpus
+    }
+}
+
+#[test]
+fn platform_preconditions() {
+    use SchedulingAlgorithm as Alg;
+
+    assert!(Platform::uniprocessor(Alg::FixedPriority).check_preconditions().is_ok());
+    assert!(Platform::global(4, Alg::EarliestDeadlineFirst).check_preconditions().is_ok());
+    assert!(Platform::global(0, Alg::EarliestDeadlineFirst).check_preconditions().is_err());
+
+    let clustered = Platform {
+        model: PlatformModel::ClusteredMultiprocessor,
+        clusters: vec![2, 2],
+        ..Platform::global(4, Alg::EarliestDeadlineFirst)
+    };
+    assert!(clustered.check_preconditions().is_ok());
+    assert!(Platform { clusters: vec![2, 1], ..clustered.clone() }.check_preconditions().is_err());
+    assert!(Platform { model: PlatformModel::GlobalMultiprocessor, ..clustered }.check_preconditions().is_err());
+
+    let uniform = Platform {
+        model: PlatformModel::UniformMultiprocessor,
+        speeds: vec![1.0, 0.5],
+        ..Platform::global(2, Alg::FixedPriority)
+    };
+    assert!(uniform.check_preconditions().is_ok());
+    assert!(Platform { speeds: vec![1.0, 0.0], ..uniform }.check_preconditions().is_err());
+
+    let big_little = Platform::heterogeneous(vec![0, 0, 1, 1], Alg::EarliestDeadlineFirst);
+    assert!(big_little.check_preconditions().is_ok());
+    assert_eq!(big_little.num_core_types(), 2);
+    assert!(Platform { cpus: 2, ..big_little }.check_preconditions().is_err());
+
+    let isr = |min_interarrival_us, wcet_us| InterruptServiceRoutine { min_interarrival_us, wcet_us };
+    let with_isrs = |isrs| Platform { isrs, ..Platform::uniprocessor(Alg::FixedPriority) };
+    assert!(with_isrs(vec![isr(1000.0, 20.0)]).check_preconditions().is_ok());
+    assert!(with_isrs(vec![isr(1000.0, 1000.0)]).check_preconditions().is_err());
+    assert!(with_isrs(vec![isr(1000.0, f64::NAN)]).check_preconditions().is_err());
+
+    let with_order = |algorithm| Platform { priority_order: PriorityOrder::RateMonotonic, ..Platform::uniprocessor(algorithm) };
+    assert!(with_order(Alg::FixedPriority).check_preconditions().is_ok());
+    assert!(with_order(Alg::EarliestDeadlineFirst).check_preconditions().is_err());
+}
+
+#[test]
+fn supported_platforms() {
+    use crate::algorithms::full_preemption::uniprocessor::earliest_deadline_first::edf73;
+    use crate::algorithms::full_preemption::global_multiprocessor::earliest_deadline_first::gbf03;
+    use crate::algorithms::full_preemption::global_multiprocessor::generic_work_conserving::bcl09;
+    use SchedulingAlgorithm as Alg;
+
+    assert!(edf73::Analysis.supports(&Platform::uniprocessor(Alg::EarliestDeadlineFirst)));
+    assert!(!edf73::Analysis.supports(&Platform::uniprocessor(Alg::FixedPriority)));
+
+    let analysis = gbf03::AnalysisSporadic { num_processors: 2 };
+    assert!(analysis.supports(&Platform::global(2, Alg::EarliestDeadlineFirst)));
+    assert!(!analysis.supports(&Platform::global(4, Alg::EarliestDeadlineFirst)));
+
+    // Work-conserving analyses apply to any global algorithm.
+    let analysis = bcl09::Analysis { num_processors: 2 };
+    assert!(analysis.supports(&Platform::global(2, Alg::FixedPriorityZeroLaxity)));
+    assert!(!analysis.supports(&Platform::uniprocessor(Alg::FixedPriority)));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn platform_serde() {
+    let data = r#"{ "model": "ClusteredMultiprocessor", "algorithm": "EarliestDeadlineFirst", "cpus": 4, "clusters": [2, 2] }"#;
+
+    let platform: Platform = serde_json::from_str(data).unwrap();
+    assert_eq!(platform.clusters, vec![2, 2]);
+    assert!(platform.speeds.is_empty());
+    assert!(platform.check_preconditions().is_ok());
+
+    let roundtrip: Platform = serde_json::from_str(&serde_json::to_string(&platform).unwrap()).unwrap();
+    assert_eq!(roundtrip, platform);
+}
@@ -0,0 +1,690 @@
+//! Description of the target platform.
+//!
+//! #### Implements:
+//! - [`KernelLatencies`] \
+//!   | IRQ-to-release and scheduling latencies of the target kernel
+//! - [`WithKernelLatencies`] \
+//!   | Apply the kernel latencies to any taskset analysis
+//! - [`WithInterruptServiceRoutines`] \
+//!   | Apply the platform's interrupt service routines to any fixed priority
+//!   | taskset analysis, as the highest priority tasks
+//! - [`BackgroundLoad`] \
+//!   | Best-effort load the real-time tasks must leave room for on each CPU
+//! - [`WithBackgroundLoad`] \
+//!   | Apply the background load of a CPU to any uniprocessor analysis
+//! - [`PreemptionBudget`] \
+//!   | Maximum preemption and migration rates sustainable by the platform
+//! - [`WithPreemptionBudget`] \
+//!   | Check the estimated preemption and migration rates of any global or
+//!   | uniprocessor job-level fixed priority analysis against the budget
+//! - [`SchedulingOverheads`] \
+//!   | Context switch and periodic interrupt (e.g. tick) overheads
+//! - [`WithSchedulingOverheads`] \
+//!   | Apply the scheduling overheads to any taskset analysis, inflating the
+//!   | WCETs \[3\]
+//! - [`description`] \
+//!   | Typed description of the platform and its scheduling algorithm
+//! - [`preempt_rt`] \
+//!   | Import the platform description of a Linux PREEMPT_RT machine
+//!
+//! ---
+//! #### References:
+//! 1. N. Audsley, A. Burns, M. Richardson, K. Tindell, and A. J. Wellings,
+//!    “Applying new scheduling theory to static priority pre-emptive
+//!    scheduling,” Software Engineering Journal, vol. 8, no. 5, pp. 284–292,
+//!    Sept. 1993, doi: 10.1049/sej.1993.0034.
+//! 2. G. C. Buttazzo, “Hard Real-Time Computing Systems: Predictable Scheduling
+//!    Algorithms and Applications,” 3rd ed., Springer, 2011,
+//!    doi: 10.1007/978-1-4614-0676-1.
+//! 3. B. B. Brandenburg, “Scheduling and Locking in Multiprocessor Real-Time
+//!    Operating Systems,” Ph.D. dissertation, The University of North Carolina
+//!    at Chapel Hill, 2011.
+
+use crate::prelude::*;
+use crate::algorithms::full_preemption::migrations::{MigrationBound, MigrationPolicy};
+use preempt_rt::RTBandwidth;
+
+pub mod description;
+pub mod preempt_rt;
+
+pub use description::*;
+
+/// Kernel latencies of the target platform, as measured on real systems (e.g.
+/// Linux PREEMPT_RT with `cyclictest`).
+///
+/// Refer to the [module](`self`) level documentation.
+#[derive(Debug, Clone)]
+pub struct KernelLatencies {
+    /// Maximum delay between the interrupt signalling a job's arrival and the
+    /// release of the job.
+    pub irq_to_release: Time,
+    /// Maximum delay between the release of a job and its dispatch, when it is
+    /// the highest priority ready job.
+    pub scheduling: Time,
+}
+
+impl KernelLatencies {
+    pub fn zero() -> Self {
+        Self {
+            irq_to_release: Time::zero(),
+            scheduling: Time::zero(),
+        }
+    }
+
+    /// Maximum delay between the arrival of a job and the instant it becomes
+    /// eligible for execution. It acts as release jitter for every task [1].
+    pub fn release_jitter(&self) -> Time {
+        self.irq_to_release + self.scheduling
+    }
+
+    /// Transform the taskset into a sporadic taskset which accounts for the
+    /// kernel latencies.
+    ///
+    /// A release jitter *J* shortens the relative deadline of each job by *J*
+    /// and makes consecutive releases up to *J* closer, thus each task (*C*,
+    /// *D*, *T*) is modelled as (*C*, *D - J*, *T - J*).
+    pub fn to_sporadic_taskset(&self, taskset: &[RTTask]) -> Vec<RTTask> {
+        let jitter = self.release_jitter();
+
+        taskset.iter()
+            .map(|task| RTTask {
+                wcet: task.wcet,
+                deadline: task.deadline - jitter,
+                period: task.period - jitter,
+            })
+            .collect()
+    }
+
+    pub fn check_preconditions(&self, taskset: &[RTTask]) -> Result<(), SchedError> {
+        if self.irq_to_release < Time::zero() || self.scheduling < Time::zero() {
+            Err(SchedError::Precondition(Some(
+                anyhow::format_err!("kernel latencies must be non-negative."))))
+        } else if taskset.iter().any(|task| task.deadline <= self.release_jitter()) {
+            Err(SchedError::Precondition(Some(
+                anyhow::format_err!("task deadlines must be greater than the kernel latencies."))))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Run the given analysis on the taskset transformed by the [`KernelLatencies`].
+///
+/// Results are relative to the release of the transformed tasks, e.g. response
+/// times do not include the release jitter.
+///
+/// Refer to the [module](`self`) level documentation.
+pub struct WithKernelLatencies<A> {
+    pub latencies: KernelLatencies,
+    pub analysis: A,
+}
+
+impl<T, A> SchedAnalysis<T, &[RTTask]> for WithKernelLatencies<A>
+    where
+        A: for<'a> SchedAnalysis<T, &'a [RTTask]>,
+{
+    fn analyzer_name(&self) -> &str { self.analysis.analyzer_name() }
+
+    fn check_preconditions(&self, taskset: &&[RTTask]) -> Result<(), SchedError> {
+        self.latencies.check_preconditions(taskset)?;
+
+        let taskset = self.latencies.to_sporadic_taskset(taskset);
+        self.analysis.check_preconditions(&taskset.as_slice())
+    }
+
+    fn run_test(&self, taskset: &[RTTask]) -> Result<T, SchedError> {
+        let taskset = self.latencies.to_sporadic_taskset(taskset);
+        self.analysis.run_test(&taskset)
+    }
+
+    // Transform each taskset only once, and let the wrapped analysis share its
+    // own precomputations across the batch.
+    fn run_batch(&self, tasksets: &[&[RTTask]]) -> Vec<anyhow::Result<T>> {
+        let transformed: Vec<_> = tasksets.iter()
+            .map(|taskset| {
+                self.latencies.check_preconditions(taskset)
+                    .map(|_| self.latencies.to_sporadic_taskset(taskset))
+            })
+            .collect();
+
+        let valid_tasksets: Vec<&[RTTask]> = transformed.iter()
+            .filter_map(|taskset| taskset.as_ref().ok())
+            .map(Vec::as_slice)
+            .collect();
+
+        let mut results = self.analysis.run_batch(&valid_tasksets).into_iter();
+
+        transformed.into_iter()
+            .map(|taskset| match taskset {
+                Ok(_) => results.next().unwrap(),
+                Err(err) => Err(anyhow::Error::from(err)
+                    .context(format!("Precondition check error for \"{}\"", self.analyzer_name()))),
+            })
+            .collect()
+    }
+}
+
+impl<A: SchedProcessors> SchedProcessors for WithKernelLatencies<A> {
+    fn num_processors(&self) -> u64 { self.analysis.num_processors() }
+}
+
+impl<A: SchedPlatform> SchedPlatform for WithKernelLatencies<A> {
+    fn supports(&self, platform: &Platform) -> bool { self.analysis.supports(platform) }
+}
+
+/// Run the given fixed priority analysis on the taskset preceded by the
+/// [`InterruptServiceRoutine`]s, which are thus served at higher priority than
+/// any task. Their WCETs include the interrupt entry and exit costs.
+///
+/// Analyses of other scheduling algorithms are not supported if any routine
+/// is given. The results include the routines first, as the taskset analysed.
+///
+/// Refer to the [module](`self`) level documentation.
+pub struct WithInterruptServiceRoutines<A> {
+    pub isrs: Vec<InterruptServiceRoutine>,
+    pub analysis: A,
+}
+
+impl<A> WithInterruptServiceRoutines<A> {
+    fn interfered_taskset(&self, taskset: &[RTTask]) -> Vec<RTTask> {
+        self.isrs.iter()
+            .map(InterruptServiceRoutine::task)
+            .chain(taskset.iter().cloned())
+            .collect()
+    }
+}
+
+impl<T, A> SchedAnalysis<T, &[RTTask]> for WithInterruptServiceRoutines<A>
+    where
+        A: for<'a> SchedAnalysis<T, &'a [RTTask]>,
+{
+    fn analyzer_name(&self) -> &str { self.analysis.analyzer_name() }
+
+    fn check_preconditions(&self, taskset: &&[RTTask]) -> Result<(), SchedError> {
+        self.analysis.check_preconditions(&self.interfered_taskset(taskset).as_slice())
+    }
+
+    fn run_test(&self, taskset: &[RTTask]) -> Result<T, SchedError> {
+        self.analysis.run_test(&self.interfered_taskset(taskset))
+    }
+}
+
+impl<A: SchedProcessors> SchedProcessors for WithInterruptServiceRoutines<A> {
+    fn num_processors(&self) -> u64 { self.analysis.num_processors() }
+}
+
+impl<A: SchedPlatform> SchedPlatform for WithInterruptServiceRoutines<A> {
+    fn supports(&self, platform: &Platform) -> bool {
+        self.analysis.supports(platform)
+            && (self.isrs.is_empty() || platform.algorithm == SchedulingAlgorithm::FixedPriority)
+    }
+}
+
+/// Best-effort load running in the background of the real-time tasks, e.g.
+/// `SCHED_OTHER` threads, given as the fraction of each CPU it needs in the
+/// long run.
+///
+/// Refer to the [module](`self`) level documentation.
+#[derive(Debug, Clone)]
+pub struct BackgroundLoad {
+    /// Utilization of the background load on each CPU.
+    pub utilization: Vec<f64>,
+    pub policy: BackgroundPolicy,
+}
+
+/// How the real-time tasks make room for the [`BackgroundLoad`].
+#[derive(Debug, Clone)]
+pub enum BackgroundPolicy {
+    /// The background load runs at lower priority than any real-time task: it
+    /// never delays the real-time tasks, which must however leave it enough
+    /// idle time.
+    Priority,
+    /// RT throttling reserves the background share of every period to the
+    /// background load, i.e. the real-time tasks may run for at most a fraction
+    /// *1 - U* of each period (`sched_rt_runtime_us` / `sched_rt_period_us`).
+    Throttling { period: Time },
+}
+
+impl BackgroundLoad {
+    pub fn check_preconditions(&self) -> Result<(), SchedError> {
+        if self.utilization.iter().any(|utilization| !(0.0..=1.0).contains(utilization)) {
+            Err(SchedError::Precondition(Some(
+                anyhow::format_err!("background utilizations must be between zero and one."))))
+        } else if matches!(self.policy, BackgroundPolicy::Throttling { period } if period <= Time::zero()) {
+            Err(SchedError::Precondition(Some(
+                anyhow::format_err!("the throttling period must be positive."))))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// RT throttling parameters which enforce the background load, `None` if
+    /// throttling is disabled.
+    ///
+    /// Linux applies the same RT bandwidth on every CPU, thus the runtime is
+    /// sized on the CPU with the largest background load, and rounded down to
+    /// the microsecond.
+    pub fn rt_bandwidth(&self) -> Option<RTBandwidth> {
+        let BackgroundPolicy::Throttling { period } = self.policy
+            else { return None; };
+
+        let max_utilization = self.utilization.iter().copied().fold(0.0, f64::max);
+        let runtime_us = (period * (1.0 - max_utilization)).as_nanos() / Time::MICRO_TO_NANO;
+
+        Some(RTBandwidth {
+            runtime: Time::micros(runtime_us.floor()),
+            period,
+        })
+    }
+
+    /// Settings of the `kernel.sched_rt_runtime_us` and
+    /// `kernel.sched_rt_period_us` sysctls, in `sysctl.conf` format.
+    pub fn to_sysctl(&self) -> String {
+        match self.rt_bandwidth() {
+            Some(bandwidth) => format!(
+                "kernel.sched_rt_period_us = {}\nkernel.sched_rt_runtime_us = {}\n",
+                (bandwidth.period.as_nanos() / Time::MICRO_TO_NANO).round() as u64,
+                (bandwidth.runtime.as_nanos() / Time::MICRO_TO_NANO).round() as u64,
+            ),
+            None => "kernel.sched_rt_runtime_us = -1\n".to_owned(),
+        }
+    }
+
+    /// Check that the real-time tasks on the given CPU leave the background
+    /// load its share of the CPU in the long run.
+    pub fn check_capacity(&self, cpu: usize, taskset: &[RTTask]) -> Result<(), SchedError> {
+        let utilization = RTUtils::total_utilization(taskset);
+        let background = self.utilization[cpu];
+
+        if utilization + background > 1.0 {
+            Err(SchedError::NonSchedulable(Some(anyhow::format_err!(
+                "real-time utilization of {utilization} leaves less than the background utilization of {background} on CPU {cpu}."))))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Run the given uniprocessor analysis on a CPU shared with the
+/// [`BackgroundLoad`].
+///
+/// With [`BackgroundPolicy::Priority`] the taskset is analyzed as is, and it
+/// must leave enough idle time to the background load. With
+/// [`BackgroundPolicy::Throttling`] the time reserved to the background load is
+/// modelled as a periodic task with higher priority than any real-time task
+/// (see [`RTBandwidth::throttling_task`]), thus only fixed priority analyses are
+/// supported, and their results include the throttling task first.
+///
+/// Refer to the [module](`self`) level documentation.
+pub struct WithBackgroundLoad<A> {
+    pub load: BackgroundLoad,
+    pub cpu: usize,
+    pub analysis: A,
+}
+
+impl<A> WithBackgroundLoad<A> {
+    fn throttled_taskset(&self, taskset: &[RTTask]) -> Vec<RTTask> {
+        self.load.rt_bandwidth()
+            .and_then(|bandwidth| bandwidth.throttling_task())
+            .into_iter()
+            .chain(taskset.iter().cloned())
+            .collect()
+    }
+}
+
+impl<T, A> SchedAnalysis<T, &[RTTask]> for WithBackgroundLoad<A>
+    where
+        A: for<'a> SchedAnalysis<T, &'a [RTTask]> + SchedPlatform,
+{
+    fn analyzer_name(&self) -> &str { self.analysis.analyzer_name() }
+
+    fn check_preconditions(&self, taskset: &&[RTTask]) -> Result<(), SchedError> {
+        self.load.check_preconditions()?;
+
+        if self.cpu >= self.load.utilization.len() {
+            return Err(SchedError::Precondition(Some(
+                anyhow::format_err!("the background utilization of CPU {} is not given.", self.cpu))));
+        }
+
+        if matches!(self.load.policy, BackgroundPolicy::Throttling { .. })
+            && !self.analysis.supports(&Platform::uniprocessor(SchedulingAlgorithm::FixedPriority))
+        {
+            return Err(SchedError::Precondition(Some(
+                anyhow::format_err!("RT throttling requires a uniprocessor fixed priority analysis."))));
+        }
+
+        let taskset = self.throttled_taskset(taskset);
+        self.analysis.check_preconditions(&taskset.as_slice())
+    }
+
+    fn run_test(&self, taskset: &[RTTask]) -> Result<T, SchedError> {
+        self.load.check_capacity(self.cpu, taskset)?;
+
+        let taskset = self.throttled_taskset(taskset);
+        self.analysis.run_test(&taskset)
+    }
+}
+
+impl<A: SchedProcessors> SchedProcessors for WithBackgroundLoad<A> {
+    fn num_processors(&self) -> u64 { self.analysis.num_processors() }
+}
+
+impl<A: SchedPlatform> SchedPlatform for WithBackgroundLoad<A> {
+    fn supports(&self, platform: &Platform) -> bool { self.analysis.supports(platform) }
+}
+
+/// Maximum rates of preemptions and migrations that the platform can sustain,
+/// e.g. because of cache-related overheads, expressed in events per second.
+///
+/// Refer to the [module](`self`) level documentation.
+#[derive(Debug, Clone)]
+pub struct PreemptionBudget {
+    pub max_preemption_rate: f64,
+    pub max_migration_rate: f64,
+}
+
+/// Upper bounds on the preemption and migration rates of a taskset, in events
+/// per second.
+#[derive(Debug, Clone, Copy)]
+#[derive(PartialEq)]
+pub struct OverheadEstimate {
+    pub preemption_rate: f64,
+    pub migration_rate: f64,
+}
+
+impl OverheadEstimate {
+    /// Estimate the overheads of a job-level fixed priority scheduler (e.g. EDF
+    /// or FP) on the given number of processors.
+    ///
+    /// Under job-level fixed priority scheduling a job may be preempted only
+    /// by the release of a higher priority job, thus there are at most as many
+    /// preemptions as job releases [2], and jobs never need to wait when there
+    /// are no more tasks than processors. Migrations are bounded by the
+    /// [`MigrationBound`] of the given policy.
+    pub fn job_level_fixed_priority(taskset: &[RTTask], num_processors: u64, policy: MigrationPolicy) -> Self {
+        let bound = MigrationBound::new(taskset, num_processors, policy);
+
+        let preemption_rate =
+            if taskset.len() as u64 <= num_processors {
+                0.0
+            } else {
+                bound.jobs_per_hyperperiod / (bound.hyperperiod / Time::nanos(1e9))
+            };
+
+        Self { preemption_rate, migration_rate: bound.migration_rate() }
+    }
+}
+
+impl PreemptionBudget {
+    pub fn check(&self, estimate: &OverheadEstimate) -> Result<(), SchedError> {
+        if estimate.preemption_rate > self.max_preemption_rate {
+            Err(SchedError::NonSchedulable(Some(anyhow::format_err!(
+                "estimated preemption rate of {}/s exceeds the budget of {}/s.",
+                estimate.preemption_rate, self.max_preemption_rate))))
+        } else if estimate.migration_rate > self.max_migration_rate {
+            Err(SchedError::NonSchedulable(Some(anyhow::format_err!(
+                "estimated migration rate of {}/s exceeds the budget of {}/s.",
+                estimate.migration_rate, self.max_migration_rate))))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Run the given job-level fixed priority analysis and check the estimated
+/// overheads against the [`PreemptionBudget`]. The number of processors is the
+/// one of the analysis.
+///
+/// Returns the result of the analysis together with the [`OverheadEstimate`].
+/// Tasksets which meet their deadlines but exceed the budget are deemed non
+/// schedulable.
+///
+/// Refer to the [module](`self`) level documentation.
+pub struct WithPreemptionBudget<A> {
+    pub budget: PreemptionBudget,
+    pub policy: MigrationPolicy,
+    pub analysis: A,
+}
+
+impl<T, A> SchedAnalysis<(T, OverheadEstimate), &[RTTask]> for WithPreemptionBudget<A>
+    where
+        A: for<'a> SchedAnalysis<T, &'a [RTTask]> + SchedProcessors,
+{
+    fn analyzer_name(&self) -> &str { self.analysis.analyzer_name() }
+
+    fn check_preconditions(&self, taskset: &&[RTTask]) -> Result<(), SchedError> {
+        self.analysis.check_preconditions(taskset)
+    }
+
+    fn run_test(&self, taskset: &[RTTask]) -> Result<(T, OverheadEstimate), SchedError> {
+        let result = self.analysis.run_test(taskset)?;
+
+        let estimate = OverheadEstimate::job_level_fixed_priority(taskset, self.analysis.num_processors(), self.policy);
+        self.budget.check(&estimate)?;
+
+        Ok((result, estimate))
+    }
+}
+
+/// Scheduling overheads of the target platform, as measured on real systems
+/// (e.g. with `feather-trace` or `ftrace`).
+///
+/// Refer to the [module](`self`) level documentation.
+#[derive(Debug, Clone)]
+#[derive(PartialEq)]
+pub struct SchedulingOverheads {
+    /// Cost of a context switch, including the scheduling decision.
+    pub context_switch: Time,
+    /// Period of the periodic interrupt, e.g. the scheduler's tick, zero if
+    /// there is none.
+    pub interrupt_period: Time,
+    /// Cost of each periodic interrupt.
+    pub interrupt_overhead: Time,
+}
+
+impl SchedulingOverheads {
+    pub fn zero() -> Self {
+        Self {
+            context_switch: Time::zero(),
+            interrupt_period: Time::zero(),
+            interrupt_overhead: Time::zero(),
+        }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        *self == Self::zero()
+    }
+
+    pub fn check_preconditions(&self) -> Result<(), SchedError> {
+        if self.context_switch < Time::zero() || self.interrupt_period < Time::zero() || self.interrupt_overhead < Time::zero() {
+            Err(SchedError::Precondition(Some(
+                anyhow::format_err!("scheduling overheads must be non-negative."))))
+        } else if self.interrupt_overhead >= self.interrupt_period && self.interrupt_overhead > Time::zero() {
+            Err(SchedError::Precondition(Some(
+                anyhow::format_err!("the interrupt overhead must be less than its period."))))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Task with higher priority than any other modelling the periodic
+    /// interrupt, if any.
+    pub fn interrupt_task(&self) -> Option<RTTask> {
+        (self.interrupt_period > Time::zero()).then_some(RTTask {
+            wcet: self.interrupt_overhead,
+            deadline: self.interrupt_period,
+            period: self.interrupt_period,
+        })
+    }
+
+    /// WCET increased by the two context switches of each job, to and from it,
+    /// which also account for the preemptions the job causes \[2\].
+    pub fn with_context_switches(&self, wcet: Time) -> Time {
+        wcet + self.context_switch * 2.0
+    }
+
+    /// WCET inflated by the context switches and by the periodic interrupts,
+    /// rounded up to [`Time::one`]. The processor capacity left by the
+    /// interrupts is *1 - U_irq*, plus one interrupt which may delay the job
+    /// at its start \[3\]:
+    ///   C' = (C + 2 cs + O_irq) / (1 - O_irq / T_irq)
+    pub fn inflate_wcet(&self, wcet: Time) -> Time {
+        let wcet = self.with_context_switches(wcet);
+
+        match self.interrupt_task() {
+            Some(interrupt) => ((wcet + interrupt.wcet) / (1.0 - interrupt.utilization())).ceil(),
+            None => wcet,
+        }
+    }
+
+    /// Tasks with their WCETs inflated by the overheads.
+    pub fn inflate_taskset(&self, taskset: &[RTTask]) -> Vec<RTTask> {
+        taskset.iter()
+            .map(|task| RTTask {
+                wcet: self.inflate_wcet(task.wcet),
+                deadline: task.deadline,
+                period: task.period,
+            })
+            .collect()
+    }
+}
+
+/// Run the given analysis on the taskset with the WCETs inflated by the
+/// [`SchedulingOverheads`], refer to [`SchedulingOverheads::inflate_wcet`].
+///
+/// The inflation applies to any analysis, at the price of some pessimism.
+/// Response time analyses may account for the overheads as explicit terms of
+/// their recurrences instead, e.g. [`rta86::AnalysisOverheads`].
+///
+/// Refer to the [module](`self`) level documentation.
+///
+/// [`rta86::AnalysisOverheads`]: crate::algorithms::full_preemption::uniprocessor::fixed_priority::rta86::AnalysisOverheads
+pub struct WithSchedulingOverheads<A> {
+    pub overheads: SchedulingOverheads,
+    pub analysis: A,
+}
+
+impl<T, A> SchedAnalysis<T, &[RTTask]> for WithSchedulingOverheads<A>
+    where
+        A: for<'a> SchedAnalysis<T, &'a [RTTask]>,
+{
+    fn analyzer_name(&self) -> &str { self.analysis.analyzer_name() }
+
+    fn check_preconditions(&self, taskset: &&[RTTask]) -> Result<(), SchedError> {
+        self.overheads.check_preconditions()?;
+        self.analysis.check_preconditions(&self.overheads.inflate_taskset(taskset).as_slice())
+    }
+
+    fn run_test(&self, taskset: &[RTTask]) -> Result<T, SchedError> {
+        self.analysis.run_test(&self.overheads.inflate_taskset(taskset))
+    }
+}
+
+impl<A: SchedProcessors> SchedProcessors for WithSchedulingOverheads<A> {
+    fn num_processors(&self) -> u64 { self.analysis.num_processors() }
+}
+
+impl<A: SchedPlatform> SchedPlatform for WithSchedulingOverheads<A> {
+    fn supports(&self, platform: &Platform) -> bool { self.analysis.supports(platform) }
+}
+
+#[test]
+fn preemption_budget() {
+    use crate::algorithms::full_preemption::global_multiprocessor::earliest_deadline_first::gbf03;
+
+    let taskset = [
+        RTTask::new_ns(1_000_000, 10_000_000, 10_000_000),
+        RTTask::new_ns(1_000_000, 10_000_000, 10_000_000),
+        RTTask::new_ns(2_000_000, 20_000_000, 20_000_000),
+    ];
+
+    // Hyperperiod of 20ms with 5 jobs. Under EDF only the job of the last task
+    // may be preempted, once by each of the other tasks.
+    let estimate = |num_processors, policy| OverheadEstimate::job_level_fixed_priority(&taskset, num_processors, policy);
+    assert_eq!(estimate(2, MigrationPolicy::EarliestDeadlineFirst), OverheadEstimate { preemption_rate: 250.0, migration_rate: 100.0 });
+    assert_eq!(estimate(2, MigrationPolicy::FixedPriority), OverheadEstimate { preemption_rate: 250.0, migration_rate: 250.0 });
+    assert_eq!(estimate(1, MigrationPolicy::FixedPriority).migration_rate, 0.0);
+    assert_eq!(estimate(3, MigrationPolicy::FixedPriority).preemption_rate, 0.0);
+
+    let analysis = |max_migration_rate| WithPreemptionBudget {
+        budget: PreemptionBudget { max_preemption_rate: 1000.0, max_migration_rate },
+        policy: MigrationPolicy::EarliestDeadlineFirst,
+        analysis: gbf03::AnalysisSporadic { num_processors: 2 },
+    };
+
+    assert!(analysis(100.0).is_schedulable(&taskset).is_ok());
+    assert!(analysis(50.0).is_schedulable(&taskset).is_err());
+}
+
+#[test]
+fn background_load() {
+    use crate::algorithms::full_preemption::uniprocessor::earliest_deadline_first::edf73;
+    use crate::algorithms::full_preemption::uniprocessor::fixed_priority::rta86;
+
+    let taskset = [
+        RTTask::new_ns(1_000, 5_000, 20_000),
+        RTTask::new_ns(2_000, 20_000, 20_000),
+    ];
+
+    let load = |utilization: f64, policy| BackgroundLoad { utilization: vec![0.0, utilization], policy };
+    let throttling = BackgroundPolicy::Throttling { period: Time::micros(10.0) };
+
+    // At lower priority, the background load never delays the tasks, but
+    // needs its share of the CPU.
+    let analysis = |load| WithBackgroundLoad { load, cpu: 1, analysis: rta86::Analysis };
+    assert_eq!(
+        analysis(load(0.5, BackgroundPolicy::Priority)).is_schedulable(&taskset).unwrap(),
+        vec![Time::micros(1.0), Time::micros(3.0)]
+    );
+    assert!(analysis(load(0.9, BackgroundPolicy::Priority)).is_schedulable(&taskset).is_err());
+
+    // Throttling may delay the first task past its deadline.
+    assert_eq!(
+        analysis(load(0.3, throttling.clone())).is_schedulable(&taskset).unwrap(),
+        vec![Time::micros(3.0), Time::micros(4.0), Time::micros(6.0)]
+    );
+    assert!(analysis(load(0.5, throttling.clone())).is_schedulable(&taskset).is_err());
+
+    let edf = WithBackgroundLoad { load: load(0.3, throttling.clone()), cpu: 1, analysis: edf73::Analysis };
+    assert!(edf.is_schedulable(&taskset).is_err());
+
+    // Linux throttles all the CPUs alike.
+    let load = BackgroundLoad { utilization: vec![0.05, 0.25], policy: BackgroundPolicy::Throttling { period: Time::millis(1000.0) } };
+    assert_eq!(load.to_sysctl(), "kernel.sched_rt_period_us = 1000000\nkernel.sched_rt_runtime_us = 750000\n");
+    assert_eq!(BackgroundLoad { policy: BackgroundPolicy::Priority, ..load }.to_sysctl(), "kernel.sched_rt_runtime_us = -1\n");
+}
+
+#[test]
+fn interrupt_service_routines() {
+    use crate::algorithms::full_preemption::uniprocessor::fixed_priority::rta86;
+    use crate::algorithms::registry::{resolve, Profile};
+    use SchedulingAlgorithm as Alg;
+
+    let taskset = [
+        RTTask::new_ns(2_000, 8_000, 8_000),
+        RTTask::new_ns(3_000, 16_000, 16_000),
+    ];
+
+    let isrs = vec![InterruptServiceRoutine { min_interarrival_us: 4.0, wcet_us: 1.0 }];
+
+    // Task 0: R = 2 + ceil(R / 4) = 3, task 1: R = 3 + ceil(R / 4) + ceil(R / 8) * 2 = 7
+    let analysis = WithInterruptServiceRoutines { isrs: isrs.clone(), analysis: rta86::Analysis };
+    assert_eq!(analysis.is_schedulable(&taskset).unwrap(),
+        vec![Time::micros(1.0), Time::micros(3.0), Time::micros(7.0)]);
+
+    // The registered tests of FP platforms account for the routines, the ones
+    // of other algorithms do not apply.
+    let platform = Platform { isrs: isrs.clone(), ..Platform::uniprocessor(Alg::FixedPriority) };
+    let resolution = resolve(&taskset, &platform, Profile::Exhaustive).unwrap();
+    let rta = resolution.escalation.iter().find(|registered| registered.id == "up.fp.rta86").unwrap();
+    assert!(rta.analysis.is_schedulable(&taskset[..]).is_ok());
+
+    let taskset = [RTTask::new_ns(2_000, 2_000, 8_000), RTTask::new_ns(3_000, 16_000, 16_000)];
+    assert!(rta.analysis.is_schedulable(&taskset[..]).is_err());
+    assert!(resolve(&taskset, &Platform::uniprocessor(Alg::FixedPriority), Profile::Exhaustive).unwrap()
+        .escalation[0].analysis.is_schedulable(&taskset[..]).is_ok());
+
+    let platform = Platform { isrs, ..Platform::uniprocessor(Alg::EarliestDeadlineFirst) };
+    let resolution = resolve(&[RTTask::new_ns(1_000, 5_000, 5_000)], &platform, Profile::Exhaustive).unwrap();
+    assert!(resolution.applicable.is_empty() && resolution.escalation.is_empty());
+}
@@ -0,0 +1,397 @@
+//! ## Linux PREEMPT_RT System Profile
+//!
+//! Import the description of a Linux PREEMPT_RT machine from a dump of its
+//! `/proc` and `/sys` filesystems, i.e. a directory which contains the
+//! following files at the same relative paths as on the target machine:
+//! - `sys/devices/system/cpu/online`
+//! - `sys/devices/system/cpu/isolated` (optional)
+//! - `proc/sys/kernel/sched_rt_runtime_us` (optional)
+//! - `proc/sys/kernel/sched_rt_period_us` (optional)
+//! - `proc/<pid>/comm` and `proc/<pid>/status` of the threaded IRQs (optional)
+//! - `proc/<pid>/schedstat` and `proc/uptime` (optional)
+//! - `cyclictest.txt`, the output of `cyclictest` on the target (optional)
+//!
+//! The profile describes the processors available to real-time tasks, the
+//! kernel latencies measured by `cyclictest`, and the background interference
+//! the tasks suffer from RT throttling and threaded IRQs. The timing of each
+//! IRQ thread is estimated from its scheduler statistics: its activations
+//! over the uptime give its average inter-arrival time, and its runtime per
+//! activation its execution time. These are measured averages, not bounds,
+//! thus they should be reviewed (or overridden) before trusting the results.
+//!
+//! #### Implements:
+//! - [`SystemProfile`] \
+//!   | CPUs, RT bandwidth and threaded IRQs of the target machine
+//! - [`SystemProfile::from_dump`] \
+//!   | Import a profile from a `/proc` and `/sys` dump
+//! - [`SystemProfile::interference_tasks`] \
+//!   | Background interference suffered on a given CPU, as a set of periodic \
+//!   | tasks with higher priority than any real-time task.
+//! - [`SystemProfile::system_model`] \
+//!   | Processors, kernel latencies and background interference for analysis
+
+use crate::prelude::*;
+use anyhow::Context as _;
+use std::path::Path;
+
+/// Linux PREEMPT_RT System Profile
+///
+/// Refer to the [module](`self`) level documentation.
+#[derive(Debug, Clone)]
+pub struct SystemProfile {
+    pub online_cpus: Vec<u64>,
+    pub isolated_cpus: Vec<u64>,
+    /// RT throttling parameters, `None` if throttling is disabled.
+    pub rt_bandwidth: Option<RTBandwidth>,
+    pub irq_threads: Vec<IrqThread>,
+    /// Time since boot, when the dump was taken.
+    pub uptime: Option<Time>,
+    /// Worst latencies measured by `cyclictest`, if available.
+    pub kernel_latencies: Option<KernelLatencies>,
+}
+
+/// Platform and background interference model of the target machine.
+#[derive(Debug, Clone)]
+pub struct SystemModel {
+    pub num_processors: u64,
+    pub latencies: KernelLatencies,
+    /// Background interference on each real-time CPU, in the order given by
+    /// [`SystemProfile::rt_cpus`].
+    pub interference: Vec<Vec<RTTask>>,
+}
+
+/// Global RT throttling parameters (`sched_rt_runtime_us` / `sched_rt_period_us`).
+#[derive(Debug, Clone)]
+pub struct RTBandwidth {
+    pub runtime: Time,
+    pub period: Time,
+}
+
+impl RTBandwidth {
+    /// Periodic task modelling the CPU time reserved to non real-time tasks,
+    /// with higher priority than any real-time task. `None` if the real-time
+    /// tasks may use the whole period.
+    pub fn throttling_task(&self) -> Option<RTTask> {
+        if self.runtime >= self.period {
+            return None;
+        }
+
+        Some(RTTask {
+            wcet: self.period - self.runtime,
+            deadline: self.period,
+            period: self.period,
+        })
+    }
+
+}
+
+/// Kernel thread serving a threaded interrupt (`irq/<n>-<name>`).
+#[derive(Debug, Clone)]
+pub struct IrqThread {
+    pub pid: u64,
+    pub irq: u64,
+    pub name: String,
+    /// CPUs the thread is allowed to run on.
+    pub cpus: Vec<u64>,
+    /// Scheduler statistics, if available.
+    pub stats: Option<ThreadStats>,
+}
+
+/// Scheduler statistics of a thread (`/proc/<pid>/schedstat`).
+#[derive(Debug, Clone)]
+pub struct ThreadStats {
+    pub runtime: Time,
+    pub activations: u64,
+}
+
+impl IrqThread {
+    /// Periodic task estimated from the thread's scheduler statistics, refer
+    /// to the [module](`self`) level documentation.
+    pub fn estimated_task(&self, uptime: Time) -> Option<RTTask> {
+        let stats = self.stats.as_ref()?;
+
+        if stats.activations == 0 {
+            return None;
+        }
+
+        let period = uptime / stats.activations as f64;
+
+        Some(RTTask {
+            wcet: stats.runtime / stats.activations as f64,
+            deadline: period,
+            period,
+        })
+    }
+}
+
+impl SystemProfile {
+    /// Import the system profile from the given dump directory.
+    pub fn from_dump<P: AsRef<Path>>(root: P) -> anyhow::Result<Self> {
+        let root = root.as_ref();
+
+        let online_cpus = parse_cpu_list(
+            &std::fs::read_to_string(root.join("sys/devices/system/cpu/online"))
+                .context("Failed to read the online CPUs")?
+        )?;
+
+        let isolated_cpus =
+            match std::fs::read_to_string(root.join("sys/devices/system/cpu/isolated")) {
+                Ok(data) => parse_cpu_list(&data)?,
+                Err(_) => Vec::new(),
+            };
+
+        let rt_bandwidth = read_rt_bandwidth(root)?;
+        let irq_threads = read_irq_threads(root)?;
+
+        let uptime =
+            match std::fs::read_to_string(root.join("proc/uptime")) {
+                Ok(data) => Some(parse_uptime(&data)?),
+                Err(_) => None,
+            };
+
+        let kernel_latencies =
+            match std::fs::read_to_string(root.join("cyclictest.txt")) {
+                Ok(data) => Some(parse_cyclictest(&data)?),
+                Err(_) => None,
+            };
+
+        Ok(Self { online_cpus, isolated_cpus, rt_bandwidth, irq_threads, uptime, kernel_latencies })
+    }
+
+    /// CPUs dedicated to real-time tasks: the isolated CPUs if any, otherwise
+    /// all the online CPUs.
+    pub fn rt_cpus(&self) -> &[u64] {
+        if self.isolated_cpus.is_empty() {
+            &self.online_cpus
+        } else {
+            &self.isolated_cpus
+        }
+    }
+
+    pub fn num_processors(&self) -> u64 {
+        self.rt_cpus().len() as u64
+    }
+
+    /// Periodic task modelling the CPU time reserved to non real-time tasks by
+    /// RT throttling.
+    pub fn throttling_task(&self) -> Option<RTTask> {
+        self.rt_bandwidth.as_ref()?.throttling_task()
+    }
+
+    /// Background interference suffered by real-time tasks on the given CPU.
+    ///
+    /// The timing parameters of the threaded IRQs are given by `irq_task_fn`,
+    /// e.g. [`IrqThread::estimated_task`] or values from the device drivers'
+    /// specifications. IRQ threads for which no parameters are given are
+    /// ignored.
+    pub fn interference_tasks<F>(&self, cpu: u64, irq_task_fn: F) -> Vec<RTTask>
+        where
+            F: Fn(&IrqThread) -> Option<RTTask>,
+    {
+        self.throttling_task().into_iter()
+            .chain(
+                self.irq_threads.iter()
+                    .filter(|thread| thread.cpus.contains(&cpu))
+                    .filter_map(irq_task_fn)
+            )
+            .collect()
+    }
+
+    /// System model of the real-time CPUs, with the IRQ threads' timing
+    /// estimated from their scheduler statistics.
+    pub fn system_model(&self) -> SystemModel {
+        let interference = self.rt_cpus().iter()
+            .map(|&cpu| self.interference_tasks(cpu, |thread| thread.estimated_task(self.uptime?)))
+            .collect();
+
+        SystemModel {
+            num_processors: self.num_processors(),
+            latencies: self.kernel_latencies.clone().unwrap_or(KernelLatencies::zero()),
+            interference,
+        }
+    }
+}
+
+/// Parse a Linux CPU list (e.g. `0-3,6,8-9`).
+pub fn parse_cpu_list(data: &str) -> anyhow::Result<Vec<u64>> {
+    let mut cpus = Vec::new();
+
+    for range in data.trim_ascii().split(',').filter(|range| !range.is_empty()) {
+        let parse = |cpu: &str| cpu.trim_ascii().parse::<u64>()
+            .map_err(|err| anyhow::format_err!("Failed to parse CPU list '{data}': {err}"));
+
+        match range.split_once('-') {
+            Some((first, last)) => cpus.extend(parse(first)? ..= parse(last)?),
+            None => cpus.push(parse(range)?),
+        }
+    }
+
+    Ok(cpus)
+}
+
+fn read_rt_bandwidth(root: &Path) -> anyhow::Result<Option<RTBandwidth>> {
+    let read = |file: &str| -> anyhow::Result<Option<f64>> {
+        match std::fs::read_to_string(root.join("proc/sys/kernel").join(file)) {
+            Ok(data) => Ok(Some(
+                data.trim_ascii().parse::<f64>()
+                    .map_err(|err| anyhow::format_err!("Failed to parse '{file}': {err}"))?
+            )),
+            Err(_) => Ok(None),
+        }
+    };
+
+    let (Some(runtime_us), Some(period_us)) =
+        (read("sched_rt_runtime_us")?, read("sched_rt_period_us")?)
+        else { return Ok(None); };
+
+    // A negative runtime disables RT throttling.
+    if runtime_us < 0.0 {
+        return Ok(None);
+    }
+
+    Ok(Some(RTBandwidth {
+        runtime: Time::nanos(runtime_us * Time::MICRO_TO_NANO),
+        period: Time::nanos(period_us * Time::MICRO_TO_NANO),
+    }))
+}
+
+fn read_irq_threads(root: &Path) -> anyhow::Result<Vec<IrqThread>> {
+    let Ok(entries) = std::fs::read_dir(root.join("proc"))
+        else { return Ok(Vec::new()); };
+
+    let mut threads = Vec::new();
+
+    for entry in entries {
+        let entry = entry?;
+        let Some(pid) = entry.file_name().to_str().and_then(|pid| pid.parse::<u64>().ok())
+            else { continue; };
+
+        let Ok(comm) = std::fs::read_to_string(entry.path().join("comm"))
+            else { continue; };
+        let name = comm.trim_ascii().to_owned();
+
+        // Threaded IRQs are named irq/<irq number>-<device name>
+        let Some(irq) = name.strip_prefix("irq/")
+            .and_then(|name| name.split('-').next())
+            .and_then(|irq| irq.parse::<u64>().ok())
+            else { continue; };
+
+        let status = std::fs::read_to_string(entry.path().join("status"))
+            .with_context(|| format!("Failed to read the status of thread {pid}"))?;
+
+        let cpus =
+            match status.lines().find_map(|line| line.strip_prefix("Cpus_allowed_list:")) {
+                Some(list) => parse_cpu_list(list)?,
+                None => Vec::new(),
+            };
+
+        let stats =
+            match std::fs::read_to_string(entry.path().join("schedstat")) {
+                Ok(data) => Some(parse_schedstat(&data)?),
+                Err(_) => None,
+            };
+
+        threads.push(IrqThread { pid, irq, name, cpus, stats });
+    }
+
+    threads.sort_unstable_by_key(|thread| thread.pid);
+
+    Ok(threads)
+}
+
+// /proc/<pid>/schedstat: runtime (ns), wait time (ns), number of timeslices.
+fn parse_schedstat(data: &str) -> anyhow::Result<ThreadStats> {
+    let fields: Vec<_> = data.split_ascii_whitespace().collect();
+
+    let (Some(runtime), Some(activations)) = (fields.first(), fields.get(2))
+        else { anyhow::bail!("Failed to parse schedstat '{data}'"); };
+
+    Ok(ThreadStats {
+        runtime: Time::nanos(runtime.parse::<f64>()?),
+        activations: activations.parse::<u64>()?,
+    })
+}
+
+// /proc/uptime: uptime and idle time, in seconds.
+fn parse_uptime(data: &str) -> anyhow::Result<Time> {
+    let uptime = data.split_ascii_whitespace().next()
+        .ok_or_else(|| anyhow::format_err!("Failed to parse uptime '{data}'"))?;
+
+    Ok(Time::millis(uptime.parse::<f64>()? * 1000.0))
+}
+
+// cyclictest prints, for each measuring thread, a line such as:
+//   T: 0 ( 1234) P:99 I:1000 C: 100000 Min: 1 Act: 2 Avg: 2 Max: 15
+// with latencies in microseconds. The latency measured from the timer's
+// expiration to the thread's wake-up is accounted as scheduling latency.
+fn parse_cyclictest(data: &str) -> anyhow::Result<KernelLatencies> {
+    let max_latency_us = data.lines()
+        .filter(|line| line.trim_ascii_start().starts_with("T:"))
+        .map(|line| {
+            line.split_once("Max:")
+                .and_then(|(_, max)| max.split_ascii_whitespace().next())
+                .and_then(|max| max.parse::<f64>().ok())
+                .ok_or_else(|| anyhow::format_err!("Failed to parse cyclictest line '{line}'"))
+        })
+        .try_fold(None, |max: Option<f64>, latency| {
+            Ok::<_, anyhow::Error>(Some(f64::max(max.unwrap_or(0.0), latency?)))
+        })?
+        .ok_or_else(|| anyhow::format_err!("No measurements in the cyclictest output"))?;
+
+    Ok(KernelLatencies {
+        irq_to_release: Time::zero(),
+        scheduling: Time::micros(max_latency_us),
+    })
+}
+
+#[test]
+fn cpu_list() {
+    assert_eq!(parse_cpu_list("0-3,6,8-9\n").unwrap(), vec![0, 1, 2, 3, 6, 8, 9]);
+    assert_eq!(parse_cpu_list("\n").unwrap(), Vec::<u64>::new());
+    assert!(parse_cpu_list("0-a").is_err());
+}
+
+#[test]
+fn dump_fixture() {
+    let root = std::env::temp_dir().join(format!("eva-preempt-rt-dump-{}", std::process::id()));
+    let write = |path: &str, data: &str| {
+        let path = root.join(path);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, data).unwrap();
+    };
+
+    write("sys/devices/system/cpu/online", "0-3\n");
+    write("sys/devices/system/cpu/isolated", "2-3\n");
+    write("proc/sys/kernel/sched_rt_runtime_us", "950000\n");
+    write("proc/sys/kernel/sched_rt_period_us", "1000000\n");
+    write("proc/uptime", "100.00 350.00\n");
+    write("proc/42/comm", "irq/24-eth0\n");
+    write("proc/42/status", "Name:\tirq/24-eth0\nCpus_allowed_list:\t2\n");
+    write("proc/42/schedstat", "500000000 1000 10000\n");
+    write("proc/43/comm", "kworker/0:1\n");
+    write("cyclictest.txt", "T: 0 ( 1234) P:99 I:1000 C: 100000 Min: 1 Act: 2 Avg: 2 Max: 15\n\
+                             T: 1 ( 1235) P:99 I:1500 C:  66666 Min: 1 Act: 3 Avg: 2 Max: 21\n");
+
+    let profile = SystemProfile::from_dump(&root);
+    std::fs::remove_dir_all(&root).unwrap();
+    let profile = profile.unwrap();
+
+    assert_eq!(profile.rt_cpus(), &[2, 3]);
+    assert_eq!(profile.irq_threads.len(), 1);
+    assert_eq!(profile.irq_threads[0].irq, 24);
+
+    let system = profile.system_model();
+    assert_eq!(system.num_processors, 2);
+    assert_eq!(system.latencies.release_jitter(), Time::micros(21.0));
+
+    // Throttling leaves 50ms every second to non real-time tasks, the IRQ
+    // thread runs for 50us every 10ms on average on CPU 2.
+    let parameters = |tasks: &[RTTask]| -> Vec<_> {
+        tasks.iter().map(|task| (task.wcet, task.deadline, task.period)).collect()
+    };
+
+    let throttling = (Time::millis(50.0), Time::millis(1000.0), Time::millis(1000.0));
+    let irq = (Time::micros(50.0), Time::millis(10.0), Time::millis(10.0));
+    assert_eq!(parameters(&system.interference[0]), vec![throttling, irq]);
+    assert_eq!(parameters(&system.interference[1]), vec![throttling]);
+}
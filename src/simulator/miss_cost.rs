@@ -0,0 +1,123 @@
+//! Expected cost of the deadline misses.
+//!
+//! Borderline designs, which the analyses deem not schedulable or barely
+//! schedulable, may still be acceptable if their deadline misses are rare or
+//! cheap. Each task is assigned a cost per deadline miss, e.g. the degradation
+//! of the control loop it implements, and the expected cost of a design is
+//! estimated by Monte Carlo simulation of its schedule, over runs with
+//! different (seeded) release patterns, refer to [`releases`].
+//!
+//! The cost is normalized per hyperperiod of the taskset, so that the designs
+//! which differ only in their WCETs or priorities are compared on the same
+//! time frame, and also given per second for designs with different periods.
+//!
+//! #### Implements:
+//! - [`expected_cost`] \
+//!   | Expected cost of the deadline misses per hyperperiod, with its
+//!   | standard error
+
+use super::*;
+
+/// Estimate of the expected cost of the deadline misses of a taskset.
+#[derive(Debug, Clone)]
+pub struct CostEstimate {
+    pub hyperperiod: Time,
+    /// Expected cost of the misses of each task, per hyperperiod.
+    pub per_task: Vec<f64>,
+    /// Standard error of the total expected cost, zero with a single run.
+    pub standard_error: f64,
+    pub runs: u64,
+}
+
+impl CostEstimate {
+    /// Total expected cost per hyperperiod.
+    pub fn expected(&self) -> f64 {
+        self.per_task.iter().sum()
+    }
+
+    /// Total expected cost per second.
+    pub fn per_second(&self) -> f64 {
+        self.expected() / (self.hyperperiod.as_nanos() / 1_000_000_000.0)
+    }
+}
+
+/// Estimate the expected cost of the deadline misses per hyperperiod, given
+/// the cost of a miss of each task, over the given simulations of the taskset.
+pub fn expected_cost<S>(
+    simulator: &Simulator,
+    taskset: &[RTTask],
+    costs: &[f64],
+    scheduler: &mut S,
+    releases: &mut [Box<dyn ReleaseModel>],
+) -> anyhow::Result<CostEstimate>
+    where
+        S: Scheduler + ?Sized,
+{
+    anyhow::ensure!(costs.len() == taskset.len(), "expected a miss cost for each of the {} tasks, got {}",
+        taskset.len(), costs.len());
+    anyhow::ensure!(costs.iter().all(|cost| *cost >= 0.0), "the miss costs must be non-negative");
+    anyhow::ensure!(!releases.is_empty(), "at least one simulation is required");
+
+    let hyperperiod = RTUtils::hyperperiod(taskset);
+    let hyperperiods = simulator.horizon / hyperperiod;
+
+    let mut per_task = vec![0.0; taskset.len()];
+    let mut totals = Vec::with_capacity(releases.len());
+
+    for release_model in releases.iter_mut() {
+        let result = simulator.simulate_with_releases(taskset, scheduler, release_model.as_mut())?;
+
+        let mut run_cost = vec![0.0; taskset.len()];
+        for miss in result.deadline_misses.iter() {
+            run_cost[miss.task] += costs[miss.task] / hyperperiods;
+        }
+
+        for (total, cost) in per_task.iter_mut().zip(&run_cost) {
+            *total += cost;
+        }
+        totals.push(run_cost.iter().sum::<f64>());
+    }
+
+    let runs = totals.len() as f64;
+    let mean = totals.iter().sum::<f64>() / runs;
+    let standard_error =
+        if totals.len() > 1 {
+            let variance = totals.iter().map(|total| (total - mean).powi(2)).sum::<f64>() / (runs - 1.0);
+            (variance / runs).sqrt()
+        } else {
+            0.0
+        };
+
+    Ok(CostEstimate {
+        hyperperiod,
+        per_task: per_task.into_iter().map(|total| total / runs).collect(),
+        standard_error,
+        runs: totals.len() as u64,
+    })
+}
+
+#[test]
+fn deadline_miss_cost() {
+    use schedulers::FixedPriority;
+
+    // The second task misses every deadline: it gets 4 units of the 5 it needs
+    // in each period.
+    let simulator = Simulator { num_processors: 1, time_unit: Time::one(), horizon: Time::nanos(32.0) };
+    let taskset = [RTTask::new_ns(2, 4, 4), RTTask::new_ns(5, 8, 8)];
+
+    let estimate = expected_cost(&simulator, &taskset, &[100.0, 10.0], &mut FixedPriority,
+        &mut [Box::new(Periodic), Box::new(Periodic)]).unwrap();
+
+    assert_eq!(estimate.hyperperiod, Time::nanos(8.0));
+    assert_eq!(estimate.per_task, vec![0.0, 10.0]);
+    assert_eq!(estimate.expected(), 10.0);
+    assert_eq!(estimate.standard_error, 0.0);
+    assert_eq!(estimate.runs, 2);
+
+    // Schedulable, no cost.
+    let taskset = [RTTask::new_ns(2, 4, 4), RTTask::new_ns(3, 8, 8)];
+    let estimate = expected_cost(&simulator, &taskset, &[100.0, 10.0], &mut FixedPriority, &mut [Box::new(Periodic)]).unwrap();
+    assert_eq!(estimate.expected(), 0.0);
+
+    assert!(expected_cost(&simulator, &taskset, &[1.0], &mut FixedPriority, &mut [Box::new(Periodic)]).is_err());
+}
@@ -0,0 +1,404 @@
+//! Discrete-time schedule simulator.
+//!
+//! Simulate the schedule of a taskset on a (global) multiprocessor platform
+//! under a pluggable scheduling policy, to compare policies empirically beyond
+//! what the analytical tests cover. Time advances in integer quanta of the
+//! given time unit, and scheduling decisions are taken at quantum boundaries.
+//!
+//! A simulation can only show that a taskset is *not* schedulable: the absence
+//! of deadline misses over a finite horizon and a single release pattern is a
+//! necessary condition for schedulability, not a sufficient one.
+//!
+//! #### Implements:
+//! - [`Simulator`] \
+//!   | Simulate the schedule over a finite horizon
+//! - [`Scheduler`] \
+//!   | Scheduling policy interface
+//! - [`schedulers`] \
+//!   | FP, EDF, EDZL, LLF and Pfair (EPDF) policies
+//! - [`releases`] \
+//!   | Periodic, jittered, sporadic and bursty release models
+//! - [`resources`] \
+//!   | Shared resources, blocking and priority inversion tracking
+//! - [`response_times`] \
+//!   | Analytic vs. simulated response times
+//! - [`miss_cost`] \
+//!   | Expected cost of the deadline misses
+//! - [`weakly_hard`] \
+//!   | Verification of (m, k)-firm constraints
+
+use crate::prelude::*;
+
+pub mod miss_cost;
+pub mod releases;
+pub mod resources;
+pub mod response_times;
+pub mod schedulers;
+pub mod weakly_hard;
+
+use releases::*;
+use resources::*;
+use std::collections::HashMap;
+
+/// Task parameters, in time quanta.
+#[derive(Debug, Clone)]
+pub struct SimTask {
+    pub wcet: u64,
+    pub deadline: u64,
+    pub period: u64,
+}
+
+/// Pending job, with absolute times in time quanta.
+#[derive(Debug, Clone)]
+pub struct Job {
+    /// Sequence number of the job, unique in the simulation even among the
+    /// jobs of a task which arrive at the same time.
+    pub id: u64,
+    pub task: usize,
+    pub arrival: u64,
+    pub release: u64,
+    pub deadline: u64,
+    pub remaining: u64,
+}
+
+impl Job {
+    /// Laxity of the job at the given time. Negative if the job cannot meet its
+    /// deadline anymore.
+    pub fn laxity(&self, time: u64) -> i64 {
+        self.deadline as i64 - time as i64 - self.remaining as i64
+    }
+}
+
+/// Scheduling policy.
+pub trait Scheduler {
+    fn scheduler_name(&self) -> &str;
+
+    /// Select the pending jobs (by index in `jobs`) to run in the time quantum
+    /// starting at `time`. At most `num_processors` distinct jobs can be
+    /// selected. When some of the selected jobs are blocked on shared
+    /// resources, the scheduler is invoked again in the same quantum without
+    /// them.
+    fn schedule(
+        &mut self,
+        time: u64,
+        tasks: &[SimTask],
+        jobs: &[Job],
+        num_processors: usize,
+    ) -> Vec<usize>;
+}
+
+/// Discrete-time schedule simulator.
+///
+/// Refer to the [module](`self`) level documentation.
+pub struct Simulator {
+    pub num_processors: u64,
+    pub time_unit: Time,
+    /// Length of the simulation. The hyperperiod plus the largest deadline is a
+    /// reasonable choice for synchronous periodic releases.
+    pub horizon: Time,
+}
+
+#[derive(Debug, Clone)]
+pub struct DeadlineMiss {
+    pub task: usize,
+    pub arrival: Time,
+    pub deadline: Time,
+}
+
+#[derive(Debug, Clone)]
+pub struct SimulationResult {
+    pub deadline_misses: Vec<DeadlineMiss>,
+    /// Largest observed response time (from the arrival) of each task, `None`
+    /// if no job of the task completed.
+    pub max_response_times: Vec<Option<Time>>,
+    /// Largest time a job of each task has been blocked on shared resources.
+    pub max_blocking_times: Vec<Time>,
+    /// Largest time a job of each task has been blocked while the holder of
+    /// the resource was preempted, refer to the [`resources`] documentation.
+    pub max_unbounded_inversions: Vec<Time>,
+    /// Whether each job of each task met its deadline, in order of arrival.
+    /// The jobs still pending at the end of the simulation are not included.
+    pub job_outcomes: Vec<Vec<bool>>,
+}
+
+impl SimulationResult {
+    pub fn no_deadline_misses(&self) -> bool {
+        self.deadline_misses.is_empty()
+    }
+
+    /// Whether any task suffered an unbounded priority inversion.
+    pub fn has_unbounded_inversions(&self) -> bool {
+        self.max_unbounded_inversions.iter().any(|&inversion| inversion > Time::zero())
+    }
+}
+
+// Quanta a job has been blocked on shared resources, and the ones of those in
+// which the holder of the resource did not execute.
+#[derive(Debug, Clone, Copy, Default)]
+struct JobBlocking {
+    blocked: u64,
+    unbounded: u64,
+}
+
+impl Simulator {
+    /// Simulate the schedule of the taskset, with synchronous periodic releases
+    /// starting at time zero. Jobs which miss their deadline are aborted.
+    pub fn simulate<S>(&self, taskset: &[RTTask], scheduler: &mut S) -> Result<SimulationResult, SchedError>
+        where
+            S: Scheduler + ?Sized,
+    {
+        self.simulate_with_releases(taskset, scheduler, &mut Periodic)
+    }
+
+    /// Simulate the schedule of the taskset, with jobs released according to
+    /// the given release model. Jobs which miss their deadline are aborted.
+    pub fn simulate_with_releases<S, R>(
+        &self,
+        taskset: &[RTTask],
+        scheduler: &mut S,
+        releases: &mut R,
+    ) -> Result<SimulationResult, SchedError>
+        where
+            S: Scheduler + ?Sized,
+            R: ReleaseModel + ?Sized,
+    {
+        self.simulate_with_resources(taskset, scheduler, releases, &ResourceModel::none())
+    }
+
+    /// Simulate the schedule of the taskset, with jobs released according to
+    /// the given release model and sharing the resources of the given model.
+    /// Jobs which miss their deadline are aborted, releasing their resources.
+    pub fn simulate_with_resources<S, R>(
+        &self,
+        taskset: &[RTTask],
+        scheduler: &mut S,
+        releases: &mut R,
+        resources: &ResourceModel,
+    ) -> Result<SimulationResult, SchedError>
+        where
+            S: Scheduler + ?Sized,
+            R: ReleaseModel + ?Sized,
+    {
+        let tasks = self.to_sim_tasks(taskset)?;
+        let sections = resources.to_sim_sections(&tasks, self.time_unit)?;
+        let horizon = (self.horizon / self.time_unit).ceil() as u64;
+
+        let needs = |job: &Job| needed_resource(&sections[job.task], &tasks[job.task], job);
+
+        let mut jobs: Vec<Job> = Vec::new();
+        let mut holders: HashMap<usize, u64> = HashMap::new();
+        let mut blocking: HashMap<u64, JobBlocking> = HashMap::new();
+        let mut next_job_id = 0;
+        let mut result = SimulationResult {
+            deadline_misses: Vec::new(),
+            max_response_times: vec![None; tasks.len()],
+            max_blocking_times: vec![Time::zero(); tasks.len()],
+            max_unbounded_inversions: vec![Time::zero(); tasks.len()],
+            job_outcomes: vec![Vec::new(); tasks.len()],
+        };
+        let mut job_outcomes: Vec<Vec<(u64, bool)>> = vec![Vec::new(); tasks.len()];
+
+        let mut next_releases: Vec<_> = tasks.iter().enumerate()
+            .map(|(i, task)| releases.next_release(i, task, None))
+            .collect();
+
+        for time in 0..horizon {
+            for (i, task) in tasks.iter().enumerate() {
+                while next_releases[i].release <= time {
+                    let Release { arrival, release } = next_releases[i];
+
+                    jobs.push(Job {
+                        id: next_job_id,
+                        task: i,
+                        arrival,
+                        release,
+                        deadline: arrival + task.deadline,
+                        remaining: task.wcet,
+                    });
+
+                    next_releases[i] = releases.next_release(i, task, Some(arrival));
+                    next_job_id += 1;
+                }
+            }
+
+            // Jobs selected by the scheduler which are blocked on a resource
+            // are suspended, and the scheduler is invoked again on the other
+            // jobs. With priority inheritance, the holder of the resource runs
+            // in place of the blocked job instead, unless already selected.
+            let mut suspended: Vec<usize> = Vec::new();
+            let mut blocked: Vec<(usize, u64)> = Vec::new();
+
+            let running = loop {
+                let candidates: Vec<_> = (0..jobs.len()).filter(|j| !suspended.contains(j)).collect();
+                let candidate_jobs: Vec<_> = candidates.iter().map(|&j| jobs[j].clone()).collect();
+
+                let selected = scheduler.schedule(time, &tasks, &candidate_jobs, self.num_processors as usize);
+                self.check_selection(scheduler, &selected, &candidate_jobs)?;
+                let selected: Vec<_> = selected.iter().map(|&c| candidates[c]).collect();
+
+                let mut acquired = holders.clone();
+                let mut running = Vec::with_capacity(selected.len());
+                let mut inheriting = Vec::new();
+                let mut newly_suspended = false;
+
+                for &j in selected.iter() {
+                    let Some(resource) = needs(&jobs[j])
+                        else { running.push(j); continue; };
+
+                    match acquired.get(&resource) {
+                        Some(&holder) if holder != jobs[j].id => {
+                            let h = jobs.iter().position(|job| job.id == holder).unwrap();
+
+                            if resources.protocol == LockingProtocol::PriorityInheritance
+                                && !selected.contains(&h) && !inheriting.iter().any(|&(_, i)| i == h)
+                            {
+                                inheriting.push((j, h));
+                            } else {
+                                suspended.push(j);
+                                blocked.push((j, holder));
+                                newly_suspended = true;
+                            }
+                        },
+                        _ => {
+                            acquired.insert(resource, jobs[j].id);
+                            running.push(j);
+                        },
+                    }
+                }
+
+                if !newly_suspended {
+                    for (j, h) in inheriting {
+                        blocked.push((j, jobs[h].id));
+                        running.push(h);
+                    }
+
+                    holders = acquired;
+                    break running;
+                }
+            };
+
+            for &(j, holder) in blocked.iter() {
+                let job_blocking = blocking.entry(jobs[j].id).or_default();
+                job_blocking.blocked += 1;
+
+                if !running.iter().any(|&h| jobs[h].id == holder) {
+                    job_blocking.unbounded += 1;
+                }
+            }
+
+            for &j in running.iter() {
+                jobs[j].remaining -= 1;
+            }
+
+            let end = time + 1;
+            jobs.retain(|job| {
+                if let Some(job_blocking) = blocking.get(&job.id)
+                    && (job.remaining == 0 || job.deadline <= end)
+                {
+                    self.record_blocking(&mut result, job.task, job_blocking);
+                }
+
+                if job.remaining == 0 {
+                    job_outcomes[job.task].push((job.arrival, true));
+
+                    let response_time = self.time_unit * (end - job.arrival) as f64;
+                    let max_response_time = &mut result.max_response_times[job.task];
+                    *max_response_time = Some(max_response_time.map_or(response_time, |rt| Time::max(rt, response_time)));
+
+                    false
+                } else if job.deadline <= end {
+                    job_outcomes[job.task].push((job.arrival, false));
+                    result.deadline_misses.push(DeadlineMiss {
+                        task: job.task,
+                        arrival: self.time_unit * job.arrival as f64,
+                        deadline: self.time_unit * job.deadline as f64,
+                    });
+
+                    false
+                } else {
+                    true
+                }
+            });
+
+            // Resources are released at the end of the critical sections, or
+            // when the holder is aborted.
+            holders.retain(|&resource, holder| {
+                jobs.iter()
+                    .find(|job| job.id == *holder)
+                    .is_some_and(|job| needs(job) == Some(resource))
+            });
+            blocking.retain(|id, _| jobs.iter().any(|job| job.id == *id));
+        }
+
+        for job in jobs.iter() {
+            if let Some(job_blocking) = blocking.get(&job.id) {
+                self.record_blocking(&mut result, job.task, job_blocking);
+            }
+        }
+
+        // Jobs of the same task may complete out of order, if their deadlines
+        // exceed their periods.
+        for (outcomes, mut task_outcomes) in result.job_outcomes.iter_mut().zip(job_outcomes) {
+            task_outcomes.sort_by_key(|&(arrival, _)| arrival);
+            *outcomes = task_outcomes.into_iter().map(|(_, met)| met).collect();
+        }
+
+        Ok(result)
+    }
+
+    fn record_blocking(&self, result: &mut SimulationResult, task: usize, job_blocking: &JobBlocking) {
+        let blocked = self.time_unit * job_blocking.blocked as f64;
+        let unbounded = self.time_unit * job_blocking.unbounded as f64;
+
+        result.max_blocking_times[task] = Time::max(result.max_blocking_times[task], blocked);
+        result.max_unbounded_inversions[task] = Time::max(result.max_unbounded_inversions[task], unbounded);
+    }
+
+    fn to_sim_tasks(&self, taskset: &[RTTask]) -> Result<Vec<SimTask>, SchedError> {
+        let to_units = |time: Time| -> Result<u64, SchedError> {
+            let units = time / self.time_unit;
+
+            if units.fract() != 0.0 {
+                Err(SchedError::Precondition(Some(
+                    anyhow::format_err!("task parameters must be integer multiples of the time unit.")
+                )))
+            } else {
+                Ok(units as u64)
+            }
+        };
+
+        if taskset.iter().any(|task| task.wcet <= Time::zero() || task.period <= Time::zero()) {
+            return Err(SchedError::Precondition(Some(
+                anyhow::format_err!("tasks must have positive WCETs and periods.")
+            )));
+        }
+
+        taskset.iter()
+            .map(|task| Ok(SimTask {
+                wcet: to_units(task.wcet)?,
+                deadline: to_units(task.deadline)?,
+                period: to_units(task.period)?,
+            }))
+            .collect()
+    }
+
+    fn check_selection<S>(&self, scheduler: &S, selected: &[usize], jobs: &[Job]) -> Result<(), SchedError>
+        where
+            S: Scheduler + ?Sized,
+    {
+        let mut sorted = selected.to_vec();
+        sorted.sort_unstable();
+        sorted.dedup();
+
+        if sorted.len() != selected.len()
+            || selected.len() > self.num_processors as usize
+            || selected.iter().any(|&j| j >= jobs.len() || jobs[j].remaining == 0)
+        {
+            return Err(SchedError::Other(
+                anyhow::format_err!("scheduler '{}' selected an invalid set of jobs.", scheduler.scheduler_name())
+            ));
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,195 @@
+//! Job release models for the [simulator](`super`).
+//!
+//! All the parameters are expressed in time quanta, as the [`SimTask`]s. The
+//! random models are seeded, so that simulations are reproducible and repeated
+//! runs with different seeds can be used for Monte Carlo experiments.
+//!
+//! #### Implements:
+//! - [`Periodic`] \
+//!   | Strictly periodic releases, synchronous at time zero
+//! - [`PeriodicWithOffsets`] \
+//!   | Strictly periodic releases, the first job of each task at its offset
+//! - [`PeriodicWithJitter`] \
+//!   | Periodic arrivals, each released after a random jitter
+//! - [`Sporadic`] \
+//!   | Random inter-arrival times no shorter than the period
+//! - [`Bursty`] \
+//!   | Bursts of closely spaced jobs, with the long-term rate of the period
+
+use super::*;
+
+/// Arrival and release time of a job. The absolute deadline of the job is
+/// relative to its arrival, while it can only execute after its release.
+#[derive(Debug, Clone, Copy)]
+#[derive(PartialEq, Eq)]
+pub struct Release {
+    pub arrival: u64,
+    pub release: u64,
+}
+
+/// Job release model.
+pub trait ReleaseModel {
+    /// Release of the next job of the given task, following the job which
+    /// arrived at `previous_arrival` (`None` for the first job).
+    fn next_release(&mut self, task_index: usize, task: &SimTask, previous_arrival: Option<u64>) -> Release;
+}
+
+pub struct Periodic;
+
+impl ReleaseModel for Periodic {
+    fn next_release(&mut self, _: usize, task: &SimTask, previous_arrival: Option<u64>) -> Release {
+        let arrival = previous_arrival.map_or(0, |arrival| arrival + task.period);
+
+        Release { arrival, release: arrival }
+    }
+}
+
+/// Periodic arrivals, the first job of each task arriving at the task's offset.
+pub struct PeriodicWithOffsets {
+    pub offsets: Vec<u64>,
+}
+
+impl ReleaseModel for PeriodicWithOffsets {
+    fn next_release(&mut self, task_index: usize, task: &SimTask, previous_arrival: Option<u64>) -> Release {
+        let arrival = previous_arrival.map_or(self.offsets[task_index], |arrival| arrival + task.period);
+
+        Release { arrival, release: arrival }
+    }
+}
+
+/// Periodic arrivals, each job released after a uniformly distributed random
+/// delay up to the task's jitter. Jitters must be no longer than the periods.
+pub struct PeriodicWithJitter {
+    pub jitters: Vec<u64>,
+    rng: SplitMix64,
+}
+
+impl PeriodicWithJitter {
+    pub fn new(jitters: Vec<u64>, seed: u64) -> Self {
+        Self { jitters, rng: SplitMix64(seed) }
+    }
+}
+
+impl ReleaseModel for PeriodicWithJitter {
+    fn next_release(&mut self, task_index: usize, task: &SimTask, previous_arrival: Option<u64>) -> Release {
+        let arrival = previous_arrival.map_or(0, |arrival| arrival + task.period);
+        let jitter = self.rng.next_below(self.jitters[task_index] + 1);
+
+        Release { arrival, release: arrival + jitter }
+    }
+}
+
+/// Sporadic arrivals: the first job arrives at a random time within the first
+/// period, and the inter-arrival times are uniformly distributed between the
+/// period and `(1 + max_delay_ratio)` times the period.
+pub struct Sporadic {
+    pub max_delay_ratio: f64,
+    rng: SplitMix64,
+}
+
+impl Sporadic {
+    pub fn new(max_delay_ratio: f64, seed: u64) -> Self {
+        Self { max_delay_ratio, rng: SplitMix64(seed) }
+    }
+}
+
+impl ReleaseModel for Sporadic {
+    fn next_release(&mut self, _: usize, task: &SimTask, previous_arrival: Option<u64>) -> Release {
+        let arrival = match previous_arrival {
+            None => self.rng.next_below(task.period),
+            Some(arrival) => {
+                let max_delay = (task.period as f64 * self.max_delay_ratio).floor() as u64;
+                arrival + task.period + self.rng.next_below(max_delay + 1)
+            },
+        };
+
+        Release { arrival, release: arrival }
+    }
+}
+
+/// Bursty arrivals: jobs arrive in bursts of `burst_size` jobs spaced by
+/// `separation`, and each burst starts `burst_size` periods after the previous
+/// one. At most `burst_size` jobs arrive in any window shorter than the
+/// period, while the long-term arrival rate is one job per period.
+pub struct Bursty {
+    pub burst_size: u64,
+    pub separation: u64,
+    released: Vec<u64>,
+}
+
+impl Bursty {
+    pub fn new(burst_size: u64, separation: u64) -> Self {
+        Self { burst_size: u64::max(burst_size, 1), separation, released: Vec::new() }
+    }
+}
+
+impl ReleaseModel for Bursty {
+    fn next_release(&mut self, task_index: usize, task: &SimTask, _: Option<u64>) -> Release {
+        if self.released.len() <= task_index {
+            self.released.resize(task_index + 1, 0);
+        }
+
+        let job = self.released[task_index];
+        self.released[task_index] += 1;
+
+        let burst_start = (job / self.burst_size) * self.burst_size * task.period;
+        let arrival = burst_start + (job % self.burst_size) * self.separation;
+
+        Release { arrival, release: arrival }
+    }
+}
+
+// SplitMix64 pseudo-random number generator: small, fast and good enough for
+// sampling release times.
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E3779B97F4A7C15);
+
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+        z ^ (z >> 31)
+    }
+
+    // Uniformly distributed in [0, bound), zero if the bound is zero.
+    fn next_below(&mut self, bound: u64) -> u64 {
+        if bound == 0 {
+            return 0;
+        }
+
+        ((self.next_u64() as u128 * bound as u128) >> 64) as u64
+    }
+}
+
+#[test]
+fn release_models() {
+    let task = SimTask { wcet: 1, deadline: 10, period: 10 };
+
+    let mut model = PeriodicWithJitter::new(vec![3], 42);
+    let mut previous = None;
+    for k in 0..100 {
+        let release = model.next_release(0, &task, previous);
+        assert_eq!(release.arrival, k * 10);
+        assert!(release.release >= release.arrival && release.release <= release.arrival + 3);
+        previous = Some(release.arrival);
+    }
+
+    let mut model = PeriodicWithOffsets { offsets: vec![0, 4] };
+    let first = model.next_release(1, &task, None);
+    assert_eq!(first, Release { arrival: 4, release: 4 });
+    assert_eq!(model.next_release(1, &task, Some(first.arrival)).arrival, 14);
+
+    let mut model = Sporadic::new(0.5, 42);
+    let mut previous = model.next_release(0, &task, None).arrival;
+    for _ in 0..100 {
+        let arrival = model.next_release(0, &task, Some(previous)).arrival;
+        assert!(arrival - previous >= 10 && arrival - previous <= 15);
+        previous = arrival;
+    }
+
+    let mut model = Bursty::new(3, 1);
+    let arrivals: Vec<_> = (0..6).map(|_| model.next_release(0, &task, None).arrival).collect();
+    assert_eq!(arrivals, vec![0, 1, 2, 30, 31, 32]);
+}
@@ -0,0 +1,186 @@
+//! Shared resources for the [simulator](`super`).
+//!
+//! Each job of a task executes the task's critical sections at fixed offsets of
+//! its execution, and each critical section holds a mutually exclusive
+//! resource. A job selected by the scheduler which needs a resource held by
+//! another job is *blocked*: it does not execute, and the quantum is accounted
+//! as blocking time. When the holder of the resource does not execute either,
+//! because it has been preempted by other jobs, the blocked job suffers an
+//! *unbounded* priority inversion, whose length depends on the workload of
+//! the preempting jobs instead of the length of the critical section \[1\].
+//!
+//! With the priority inheritance protocol, the holder of a resource executes
+//! in place of the blocked job with the highest priority, thus preventing
+//! unbounded inversions.
+//!
+//! #### Implements:
+//! - [`ResourceModel`] \
+//!   | Critical sections of each task and locking protocol
+//!
+//! ---
+//! #### References:
+//! 1. L. Sha, R. Rajkumar, and J. P. Lehoczky, “Priority inheritance protocols:
+//!    an approach to real-time synchronization,” IEEE Transactions on
+//!    Computers, vol. 39, no. 9, pp. 1175–1185, Sept. 1990,
+//!    doi: 10.1109/12.57058.
+
+use super::*;
+
+#[derive(Debug, Clone, Copy)]
+#[derive(PartialEq, Eq)]
+pub enum LockingProtocol {
+    /// Blocked jobs wait for the holder, which keeps its own priority.
+    NoInheritance,
+    /// The holder inherits the priority of the jobs it blocks.
+    PriorityInheritance,
+}
+
+/// Critical section, with the offset of its start relative to the execution
+/// of the job (not to its release).
+#[derive(Debug, Clone)]
+pub struct CriticalSection {
+    pub resource: usize,
+    pub start: Time,
+    pub length: Time,
+}
+
+/// Critical sections of each task and locking protocol.
+///
+/// Refer to the [module](`self`) level documentation.
+#[derive(Debug, Clone)]
+pub struct ResourceModel {
+    /// Critical sections of each task, which must not overlap.
+    pub critical_sections: Vec<Vec<CriticalSection>>,
+    pub protocol: LockingProtocol,
+}
+
+impl ResourceModel {
+    /// Model without shared resources.
+    pub fn none() -> Self {
+        Self { critical_sections: Vec::new(), protocol: LockingProtocol::NoInheritance }
+    }
+}
+
+/// Critical section in time quanta.
+#[derive(Debug, Clone)]
+pub(super) struct SimSection {
+    pub resource: usize,
+    pub start: u64,
+    pub end: u64,
+}
+
+impl ResourceModel {
+    // Critical sections of each task in time quanta, sorted by start time.
+    pub(super) fn to_sim_sections(&self, tasks: &[SimTask], time_unit: Time) -> Result<Vec<Vec<SimSection>>, SchedError> {
+        if self.critical_sections.is_empty() {
+            return Ok(vec![Vec::new(); tasks.len()]);
+        } else if self.critical_sections.len() != tasks.len() {
+            return Err(SchedError::Precondition(Some(
+                anyhow::format_err!("the resource model must list the critical sections of every task.")
+            )));
+        }
+
+        let to_units = |time: Time| -> Result<u64, SchedError> {
+            let units = time / time_unit;
+
+            if units.fract() != 0.0 || units < 0.0 {
+                Err(SchedError::Precondition(Some(
+                    anyhow::format_err!("critical sections must be integer multiples of the time unit.")
+                )))
+            } else {
+                Ok(units as u64)
+            }
+        };
+
+        tasks.iter().zip(self.critical_sections.iter()).enumerate()
+            .map(|(i, (task, sections))| {
+                let mut sim_sections = sections.iter()
+                    .map(|section| {
+                        let start = to_units(section.start)?;
+
+                        Ok(SimSection { resource: section.resource, start, end: start + to_units(section.length)? })
+                    })
+                    .collect::<Result<Vec<_>, SchedError>>()?;
+
+                sim_sections.sort_by_key(|section| section.start);
+
+                let overlapping = sim_sections.windows(2).any(|pair| pair[0].end > pair[1].start);
+                if overlapping || sim_sections.last().is_some_and(|section| section.end > task.wcet) {
+                    return Err(SchedError::Precondition(Some(
+                        anyhow::format_err!("the critical sections of task {i} must not overlap and must fit in its WCET.")
+                    )));
+                }
+
+                Ok(sim_sections)
+            })
+            .collect()
+    }
+}
+
+// Resource needed by the next quantum of execution of a job.
+pub(super) fn needed_resource(sections: &[SimSection], task: &SimTask, job: &Job) -> Option<usize> {
+    let executed = task.wcet - job.remaining;
+
+    sections.iter()
+        .find(|section| section.start <= executed && executed < section.end)
+        .map(|section| section.resource)
+}
+
+#[test]
+fn priority_inversion() {
+    use super::releases::PeriodicWithOffsets;
+    use super::schedulers::FixedPriority;
+
+    // The low priority task locks the resource at time 0, the high priority
+    // task needs it at time 1 and the medium priority task arrives at time 2.
+    let taskset = [
+        RTTask::new_ns(2, 10, 20),
+        RTTask::new_ns(3, 20, 20),
+        RTTask::new_ns(5, 20, 20),
+    ];
+
+    let section = |length| CriticalSection { resource: 0, start: Time::zero(), length: Time::nanos(length) };
+    let resources = |protocol| ResourceModel {
+        critical_sections: vec![vec![section(1.0)], vec![], vec![section(4.0)]],
+        protocol,
+    };
+
+    let simulator = Simulator { num_processors: 1, time_unit: Time::one(), horizon: Time::nanos(20.0) };
+    let simulate = |protocol| simulator.simulate_with_resources(
+        &taskset, &mut FixedPriority, &mut PeriodicWithOffsets { offsets: vec![1, 2, 0] }, &resources(protocol)).unwrap();
+
+    // The medium priority task preempts the holder for its whole WCET.
+    let result = simulate(LockingProtocol::NoInheritance);
+    assert_eq!(result.max_blocking_times[0], Time::nanos(6.0));
+    assert_eq!(result.max_unbounded_inversions[0], Time::nanos(3.0));
+    assert_eq!(result.max_response_times[0], Some(Time::nanos(8.0)));
+    assert!(result.has_unbounded_inversions());
+
+    // The holder inherits the priority of the blocked task.
+    let result = simulate(LockingProtocol::PriorityInheritance);
+    assert_eq!(result.max_blocking_times[0], Time::nanos(3.0));
+    assert_eq!(result.max_response_times[0], Some(Time::nanos(5.0)));
+    assert!(!result.has_unbounded_inversions());
+}
+
+#[test]
+fn simultaneous_jobs() {
+    use super::releases::Bursty;
+    use super::schedulers::FixedPriority;
+
+    // Two jobs of the task arrive together, and both need the resource for
+    // their whole WCET: they must hold it one after the other, even with a
+    // processor each.
+    let taskset = [RTTask::new_ns(2, 10, 10)];
+    let resources = ResourceModel {
+        critical_sections: vec![vec![CriticalSection { resource: 0, start: Time::zero(), length: Time::nanos(2.0) }]],
+        protocol: LockingProtocol::NoInheritance,
+    };
+
+    let simulator = Simulator { num_processors: 2, time_unit: Time::one(), horizon: Time::nanos(20.0) };
+    let result = simulator.simulate_with_resources(&taskset, &mut FixedPriority, &mut Bursty::new(2, 0), &resources).unwrap();
+
+    assert_eq!(result.max_blocking_times[0], Time::nanos(2.0));
+    assert_eq!(result.max_response_times[0], Some(Time::nanos(4.0)));
+    assert!(!result.has_unbounded_inversions());
+}
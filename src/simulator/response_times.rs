@@ -0,0 +1,115 @@
+//! Comparison of analytic and simulated response times.
+//!
+//! The Worst-Case Response Time given by a sound analysis upper bounds the
+//! response time of every job in every schedule, thus a larger response time
+//! observed in a simulation of the same model points to a bug in either of
+//! them. Conversely, a bound much larger than any observed response time is
+//! either pessimistic or tight only for release patterns which the simulation
+//! did not exercise.
+//!
+//! #### Implements:
+//! - [`compare`] \
+//!   | Simulate the schedule and compare with the analysis, per task
+//! - [`TaskComparison::classify`] \
+//!   | Flag violations and suspiciously large gaps
+
+use super::*;
+
+/// Analytic and observed response times of a task.
+#[derive(Debug, Clone)]
+pub struct TaskComparison {
+    pub analytic: Time,
+    /// Largest observed response time, `None` if no job of the task completed.
+    pub observed: Option<Time>,
+}
+
+/// Classification of a [`TaskComparison`].
+#[derive(Debug, Clone, Copy)]
+#[derive(PartialEq, Eq)]
+pub enum Discrepancy {
+    Consistent,
+    /// The gap between the bound and the observed response time is larger than
+    /// the given fraction of the bound: possible pessimism.
+    Pessimistic,
+    /// The observed response time exceeds the bound: possible bug.
+    Violation,
+    /// No job of the task completed.
+    Unobserved,
+}
+
+impl TaskComparison {
+    /// Gap between the bound and the observed response time, as a fraction of
+    /// the bound. Negative on violations.
+    pub fn gap(&self) -> Option<f64> {
+        self.observed.map(|observed| (self.analytic - observed) / self.analytic)
+    }
+
+    pub fn classify(&self, pessimism_threshold: f64) -> Discrepancy {
+        match self.observed {
+            None => Discrepancy::Unobserved,
+            Some(observed) if observed > self.analytic => Discrepancy::Violation,
+            Some(_) if self.gap().unwrap() > pessimism_threshold => Discrepancy::Pessimistic,
+            Some(_) => Discrepancy::Consistent,
+        }
+    }
+}
+
+/// Response times of the analysis and largest ones observed over the given
+/// simulations of the taskset, per task. Fails if the analysis does not give
+/// the response times, e.g. since the taskset is deemed not schedulable.
+pub fn compare<A, S>(
+    analysis: A,
+    simulator: &Simulator,
+    taskset: &[RTTask],
+    scheduler: &mut S,
+    releases: &mut [Box<dyn ReleaseModel>],
+) -> anyhow::Result<Vec<TaskComparison>>
+    where
+        A: for<'a> SchedAnalysis<Vec<Time>, &'a [RTTask]>,
+        S: Scheduler + ?Sized,
+{
+    let analytic = analysis.is_schedulable(taskset)?;
+    let mut observed: Vec<Option<Time>> = vec![None; taskset.len()];
+
+    for release_model in releases.iter_mut() {
+        let result = simulator.simulate_with_releases(taskset, scheduler, release_model.as_mut())?;
+
+        for (max_response_time, response_time) in observed.iter_mut().zip(result.max_response_times) {
+            *max_response_time = Option::max(*max_response_time, response_time);
+        }
+    }
+
+    Ok(analytic.into_iter().zip(observed)
+        .map(|(analytic, observed)| TaskComparison { analytic, observed })
+        .collect())
+}
+
+#[test]
+fn analytic_vs_simulated() {
+    use crate::algorithms::full_preemption::uniprocessor::fixed_priority::rta86;
+    use schedulers::FixedPriority;
+
+    let at = |t: u64| Time::nanos(t as f64);
+    let simulator = Simulator { num_processors: 1, time_unit: Time::one(), horizon: Time::nanos(400.0) };
+
+    // The synchronous periodic releases are the critical instant of
+    // uniprocessor FP, thus the simulation attains the bounds.
+    let taskset = [RTTask::new_ns(2, 5, 5), RTTask::new_ns(2, 9, 10), RTTask::new_ns(3, 20, 20)];
+    let comparison = compare(rta86::Analysis, &simulator, &taskset, &mut FixedPriority, &mut [Box::new(Periodic)]).unwrap();
+
+    let observed: Vec<_> = comparison.iter().map(|task| task.observed.unwrap()).collect();
+    assert_eq!(observed, vec![at(2), at(4), at(9)]);
+    assert!(comparison.iter().all(|task| task.classify(0.0) == Discrepancy::Consistent));
+
+    // Jittered releases only, which miss the critical instant.
+    let comparison = compare(rta86::Analysis, &simulator, &taskset, &mut FixedPriority,
+        &mut [Box::new(PeriodicWithOffsets { offsets: vec![0, 2, 4] })]).unwrap();
+    assert_eq!(comparison[2].classify(0.3), Discrepancy::Pessimistic);
+
+    // A bound below the observed response time.
+    let task = TaskComparison { analytic: at(8), observed: Some(at(9)) };
+    assert_eq!(task.classify(0.5), Discrepancy::Violation);
+    assert!(task.gap().unwrap() < 0.0);
+
+    assert_eq!(TaskComparison { analytic: at(8), observed: None }.classify(0.5), Discrepancy::Unobserved);
+}
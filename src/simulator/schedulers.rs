@@ -0,0 +1,232 @@
+//! Scheduling policies for the [simulator](`super`).
+//!
+//! #### Implements:
+//! - [`FixedPriority`] \
+//!   | Global FP, tasks are sorted by priority (highest first)
+//! - [`EarliestDeadlineFirst`] \
+//!   | Global EDF
+//! - [`EarliestDeadlineZeroLaxity`] \
+//!   | Global EDZL [1]
+//! - [`LeastLaxityFirst`] \
+//!   | Global LLF
+//! - [`Pfair`] \
+//!   | Quantum-based proportionate fair scheduling [2], EPDF variant [3]
+//!
+//! ---
+//! #### References:
+//! 1. S. K. Lee, “On-line multiprocessor scheduling algorithms for real-time
+//!    tasks,” in Proceedings of TENCON'94, Aug. 1994, pp. 607–611,
+//!    doi: 10.1109/TENCON.1994.369266.
+//! 2. S. K. Baruah, N. K. Cohen, C. G. Plaxton, and D. A. Varvel,
+//!    “Proportionate progress: A notion of fairness in resource allocation,”
+//!    Algorithmica, vol. 15, no. 6, pp. 600–625, June 1996,
+//!    doi: 10.1007/BF01940883.
+//! 3. J. H. Anderson and A. Srinivasan, “Early-release fair scheduling,” in
+//!    Proceedings 12th Euromicro Conference on Real-Time Systems, June 2000,
+//!    pp. 35–43, doi: 10.1109/EMRTS.2000.853990.
+
+use super::*;
+
+/// Run the `num_processors` highest priority jobs, where a lower key means a
+/// higher priority. Ties are broken by task index.
+fn schedule_by_key<K, F>(jobs: &[Job], num_processors: usize, key: F) -> Vec<usize>
+    where
+        K: Ord,
+        F: Fn(&Job) -> K,
+{
+    let mut ready: Vec<_> = (0..jobs.len()).collect();
+    ready.sort_by_key(|&j| (key(&jobs[j]), jobs[j].task));
+    ready.truncate(num_processors);
+
+    ready
+}
+
+pub struct FixedPriority;
+
+impl Scheduler for FixedPriority {
+    fn scheduler_name(&self) -> &str { "Global FP" }
+
+    fn schedule(&mut self, _: u64, _: &[SimTask], jobs: &[Job], num_processors: usize) -> Vec<usize> {
+        schedule_by_key(jobs, num_processors, |job| job.task)
+    }
+}
+
+pub struct EarliestDeadlineFirst;
+
+impl Scheduler for EarliestDeadlineFirst {
+    fn scheduler_name(&self) -> &str { "Global EDF" }
+
+    fn schedule(&mut self, _: u64, _: &[SimTask], jobs: &[Job], num_processors: usize) -> Vec<usize> {
+        schedule_by_key(jobs, num_processors, |job| job.deadline)
+    }
+}
+
+pub struct EarliestDeadlineZeroLaxity;
+
+impl Scheduler for EarliestDeadlineZeroLaxity {
+    fn scheduler_name(&self) -> &str { "Global EDZL" }
+
+    // Jobs with zero laxity get the highest priority, the others are scheduled
+    // by EDF.
+    fn schedule(&mut self, time: u64, _: &[SimTask], jobs: &[Job], num_processors: usize) -> Vec<usize> {
+        schedule_by_key(jobs, num_processors, |job| (job.laxity(time) > 0, job.deadline))
+    }
+}
+
+pub struct LeastLaxityFirst;
+
+impl Scheduler for LeastLaxityFirst {
+    fn scheduler_name(&self) -> &str { "Global LLF" }
+
+    fn schedule(&mut self, time: u64, _: &[SimTask], jobs: &[Job], num_processors: usize) -> Vec<usize> {
+        schedule_by_key(jobs, num_processors, |job| job.laxity(time))
+    }
+}
+
+/// Pfair scheduling for tasks with implicit deadlines.
+///
+/// Each job of a task of weight *w = C / T* is split into unit subtasks, where
+/// the *j*-th subtask (from zero) of the job has pseudo-release *floor(j / w)*
+/// and pseudo-deadline *ceil((j + 1) / w)*, relative to the release of the job
+/// [2]. Subtasks can only run after their pseudo-release, and are prioritized
+/// by pseudo-deadline (EPDF) [3]. With synchronous periodic releases, these
+/// are the subtask windows of the whole task; late releases shift the windows
+/// of their job, as in the intra-sporadic model. EPDF is optimal on up to two
+/// processors.
+pub struct Pfair;
+
+impl Pfair {
+    // Index of the next subtask of the job.
+    fn subtask(task: &SimTask, job: &Job) -> u64 {
+        task.wcet - job.remaining
+    }
+
+    fn pseudo_release(task: &SimTask, job: &Job) -> u64 {
+        job.release + (Self::subtask(task, job) * task.period) / task.wcet
+    }
+
+    fn pseudo_deadline(task: &SimTask, job: &Job) -> u64 {
+        job.release + ((Self::subtask(task, job) + 1) * task.period).div_ceil(task.wcet)
+    }
+}
+
+impl Scheduler for Pfair {
+    fn scheduler_name(&self) -> &str { "Pfair (EPDF)" }
+
+    fn schedule(&mut self, time: u64, tasks: &[SimTask], jobs: &[Job], num_processors: usize) -> Vec<usize> {
+        let mut eligible: Vec<_> = (0..jobs.len())
+            .filter(|&j| {
+                let task = &tasks[jobs[j].task];
+                Self::pseudo_release(task, &jobs[j]) <= time
+            })
+            .collect();
+
+        eligible.sort_by_key(|&j| {
+            let task = &tasks[jobs[j].task];
+            (Self::pseudo_deadline(task, &jobs[j]), jobs[j].task)
+        });
+        eligible.truncate(num_processors);
+
+        eligible
+    }
+}
+
+#[test]
+fn policies() {
+    let simulator = |num_processors, horizon| Simulator {
+        num_processors,
+        time_unit: Time::one(),
+        horizon: Time::nanos(horizon),
+    };
+
+    // Rate Monotonic misses the second task's deadline, EDF does not.
+    let taskset = [
+        RTTask::new_ns(2, 5, 5),
+        RTTask::new_ns(4, 7, 7),
+    ];
+
+    let result = simulator(1, 35.0).simulate(&taskset, &mut FixedPriority).unwrap();
+    assert!(!result.no_deadline_misses());
+    assert_eq!(result.deadline_misses[0].task, 1);
+
+    let result = simulator(1, 35.0).simulate(&taskset, &mut EarliestDeadlineFirst).unwrap();
+    assert!(result.no_deadline_misses());
+    assert!(result.max_response_times[1].is_some_and(|rt| rt <= Time::nanos(7.0)));
+
+    // Full utilization on two processors: global EDF misses, EDZL, LLF and
+    // Pfair do not.
+    let taskset = [
+        RTTask::new_ns(2, 3, 3),
+        RTTask::new_ns(2, 3, 3),
+        RTTask::new_ns(2, 3, 3),
+    ];
+
+    let result = simulator(2, 6.0).simulate(&taskset, &mut EarliestDeadlineFirst).unwrap();
+    assert!(!result.no_deadline_misses());
+
+    let schedulers: [Box<dyn Scheduler>; 3] =
+        [Box::new(EarliestDeadlineZeroLaxity), Box::new(LeastLaxityFirst), Box::new(Pfair)];
+
+    for mut scheduler in schedulers {
+        let result = simulator(2, 6.0).simulate(&taskset, scheduler.as_mut()).unwrap();
+        assert!(result.no_deadline_misses(), "{}", scheduler.scheduler_name());
+    }
+}
+
+#[test]
+fn sporadic_releases() {
+    let simulator = Simulator {
+        num_processors: 1,
+        time_unit: Time::one(),
+        horizon: Time::nanos(1000.0),
+    };
+
+    // Sporadic releases are no worse than the synchronous periodic case for
+    // uniprocessor FP.
+    let taskset = [
+        RTTask::new_ns(2, 5, 5),
+        RTTask::new_ns(2, 7, 7),
+    ];
+
+    for seed in 0..10 {
+        let result = simulator
+            .simulate_with_releases(&taskset, &mut FixedPriority, &mut Sporadic::new(0.5, seed))
+            .unwrap();
+        assert!(result.no_deadline_misses());
+        assert!(result.max_response_times[1].is_some_and(|rt| rt <= Time::nanos(4.0)));
+    }
+
+    // A burst of two jobs overloads the second task.
+    let result = simulator
+        .simulate_with_releases(&taskset, &mut FixedPriority, &mut Bursty::new(2, 0))
+        .unwrap();
+    assert!(!result.no_deadline_misses());
+}
+
+#[test]
+fn pfair_sporadic_releases() {
+    let simulator = |horizon| Simulator {
+        num_processors: 2,
+        time_unit: Time::one(),
+        horizon: Time::nanos(horizon),
+    };
+
+    // The subtask windows follow the release of each job, so late releases
+    // do not cause misses at full utilization.
+    let taskset = [
+        RTTask::new_ns(2, 3, 3),
+        RTTask::new_ns(2, 3, 3),
+        RTTask::new_ns(2, 3, 3),
+    ];
+
+    for seed in 0..10 {
+        let result = simulator(300.0)
+            .simulate_with_releases(&taskset, &mut Pfair, &mut Sporadic::new(0.5, seed))
+            .unwrap();
+        assert!(result.no_deadline_misses());
+    }
+
+    // Zero WCETs have no subtasks.
+    let taskset = [RTTask::new_ns(0, 3, 3)];
+    assert!(simulator(6.0).simulate(&taskset, &mut Pfair).is_err());
+}
@@ -0,0 +1,157 @@
+//! Verification of weakly-hard constraints.
+//!
+//! A task with an *(m, k)*-firm constraint tolerates deadline misses, as long
+//! as at least *m* jobs in any window of *k* consecutive jobs meet their
+//! deadlines \[1\], while the jobs which miss them are aborted, as in the
+//! [simulator](`super`). Hard real-time tasks are *(1, 1)*-firm.
+//!
+//! The constraints are verified on simulations of the schedule: a window with
+//! too many misses is a counterexample, while the absence of such windows
+//! holds only for the simulated release patterns. In general, the synchronous
+//! periodic releases are not the worst case for weakly-hard constraints \[2\],
+//! though they are the only releases of strictly periodic synchronous tasks,
+//! whose verdict is exact when the horizon covers the repeating part of the
+//! schedule.
+//!
+//! #### Implements:
+//! - [`verify`] \
+//!   | Simulate the schedule and check the constraint of each task
+//! - [`WeaklyHard::min_met`] \
+//!   | Fewest deadlines met in any window of a sequence of jobs
+//!
+//! ---
+//! #### References:
+//! 1. M. Hamdaoui and P. Ramanathan, “A dynamic priority assignment technique
+//!    for streams with (m, k)-firm deadlines,” IEEE Transactions on Computers,
+//!    vol. 44, no. 12, pp. 1443–1451, Dec. 1995, doi: 10.1109/12.477249.
+//! 2. G. Bernat, A. Burns, and A. Llamosí, “Weakly hard real-time systems,”
+//!    IEEE Transactions on Computers, vol. 50, no. 4, pp. 308–321, Apr. 2001,
+//!    doi: 10.1109/12.919277.
+
+use super::*;
+
+/// *(m, k)*-firm constraint: at least `m` jobs in any `k` consecutive jobs
+/// meet their deadlines.
+#[derive(Debug, Clone, Copy)]
+#[derive(PartialEq, Eq)]
+pub struct WeaklyHard {
+    pub m: u64,
+    pub k: u64,
+}
+
+impl WeaklyHard {
+    /// Every job meets its deadline.
+    pub fn hard() -> Self {
+        Self { m: 1, k: 1 }
+    }
+
+    pub fn check_preconditions(&self) -> Result<(), SchedError> {
+        if self.k == 0 || self.m > self.k {
+            Err(SchedError::Precondition(Some(
+                anyhow::format_err!("(m, k)-firm constraints must have 0 <= m <= k and k > 0."))))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Fewest deadlines met in any window of `k` consecutive jobs of the
+    /// sequence, counting as met the jobs of the windows which exceed a
+    /// sequence shorter than `k`.
+    pub fn min_met(&self, outcomes: &[bool]) -> u64 {
+        let k = self.k as usize;
+        let misses = |window: &[bool]| window.iter().filter(|met| !**met).count() as u64;
+
+        if outcomes.len() < k {
+            return self.k - misses(outcomes);
+        }
+
+        let max_misses = outcomes.windows(k).map(misses).max().unwrap_or(0);
+        self.k - max_misses
+    }
+
+    pub fn is_satisfied_by(&self, outcomes: &[bool]) -> bool {
+        self.min_met(outcomes) >= self.m
+    }
+}
+
+/// Outcome of the verification of the constraint of a task.
+#[derive(Debug, Clone)]
+pub struct WeaklyHardVerdict {
+    pub constraint: WeaklyHard,
+    /// Completed or aborted jobs, over all the simulations.
+    pub jobs: u64,
+    pub misses: u64,
+    /// Fewest deadlines met in any window of `k` jobs, over all the
+    /// simulations.
+    pub min_met: u64,
+}
+
+impl WeaklyHardVerdict {
+    pub fn is_satisfied(&self) -> bool {
+        self.min_met >= self.constraint.m
+    }
+}
+
+/// Verify the constraint of each task over the given simulations of the
+/// taskset, refer to the [module](`self`) level documentation.
+pub fn verify<S>(
+    simulator: &Simulator,
+    taskset: &[RTTask],
+    constraints: &[WeaklyHard],
+    scheduler: &mut S,
+    releases: &mut [Box<dyn ReleaseModel>],
+) -> anyhow::Result<Vec<WeaklyHardVerdict>>
+    where
+        S: Scheduler + ?Sized,
+{
+    anyhow::ensure!(constraints.len() == taskset.len(), "expected a constraint for each of the {} tasks, got {}",
+        taskset.len(), constraints.len());
+
+    for constraint in constraints {
+        constraint.check_preconditions()?;
+    }
+
+    let mut verdicts: Vec<_> = constraints.iter()
+        .map(|&constraint| WeaklyHardVerdict { constraint, jobs: 0, misses: 0, min_met: constraint.k })
+        .collect();
+
+    for release_model in releases.iter_mut() {
+        let result = simulator.simulate_with_releases(taskset, scheduler, release_model.as_mut())?;
+
+        for (verdict, outcomes) in verdicts.iter_mut().zip(result.job_outcomes) {
+            verdict.jobs += outcomes.len() as u64;
+            verdict.misses += outcomes.iter().filter(|met| !**met).count() as u64;
+            verdict.min_met = u64::min(verdict.min_met, verdict.constraint.min_met(&outcomes));
+        }
+    }
+
+    Ok(verdicts)
+}
+
+#[test]
+fn weakly_hard_constraints() {
+    use schedulers::FixedPriority;
+
+    let constraint = WeaklyHard { m: 2, k: 3 };
+    assert_eq!(constraint.min_met(&[true, false, true, true, false, true]), 2);
+    assert_eq!(constraint.min_met(&[true, false, false, true]), 1);
+    assert_eq!(constraint.min_met(&[true, false]), 2);
+    assert!(WeaklyHard { m: 4, k: 3 }.check_preconditions().is_err());
+
+    // The second task misses every other deadline: it has 2 units of the 3 it
+    // needs in [0, 6), and completes at 11 in [6, 12).
+    let simulator = Simulator { num_processors: 1, time_unit: Time::one(), horizon: Time::nanos(48.0) };
+    let taskset = [RTTask::new_ns(2, 4, 4), RTTask::new_ns(3, 6, 6)];
+
+    let verify_with = |constraints: &[WeaklyHard]|
+        verify(&simulator, &taskset, constraints, &mut FixedPriority, &mut [Box::new(Periodic)]).unwrap();
+
+    let verdicts = verify_with(&[WeaklyHard::hard(), WeaklyHard { m: 1, k: 2 }]);
+    assert!(verdicts.iter().all(WeaklyHardVerdict::is_satisfied));
+    assert_eq!((verdicts[0].jobs, verdicts[0].misses), (12, 0));
+    assert_eq!((verdicts[1].jobs, verdicts[1].misses), (8, 4));
+
+    let verdicts = verify_with(&[WeaklyHard::hard(), WeaklyHard { m: 2, k: 3 }]);
+    assert!(!verdicts[1].is_satisfied());
+    assert_eq!(verdicts[1].min_met, 1);
+}
@@ -0,0 +1,183 @@
+//! Candidate instants of the pseudo-polynomial tests.
+//!
+//! Tests which check an inequality at every (integer) instant up to a bound
+//! only need to check it where one of its terms changes, e.g. the demand bound
+//! function of a task or the supply bound function of a resource model. Such
+//! instants recur periodically, in windows of fixed width. The iterators of
+//! this module enumerate them in increasing order, without scanning the
+//! instants in between.
+//!
+//! #### Implements:
+//! - [`periodic_points`] \
+//!   | Instants in a window after every multiple of a period
+//! - [`prefix_points`] \
+//!   | Instants from zero up to a given one
+//! - [`merge_points`] \
+//!   | Sorted union of sorted instants
+//! - [`dbf_points`] \
+//!   | Instants at which the demand of a task changes \[1\]
+//! - [`release_points`] \
+//!   | Job releases of a task \[2\]
+//! - [`supply_points`] \
+//!   | Instants at which the supply of a periodic resource with a blackout
+//!   | changes \[2\]
+//!
+//! ---
+//! #### References:
+//! 1. S. Baruah, “Techniques for Multiprocessor Global Schedulability Analysis,”
+//!    in 28th IEEE International Real-Time Systems Symposium (RTSS 2007),
+//!    Tucson, AZ, USA: IEEE, Dec. 2007, pp. 119–128. doi: 10.1109/RTSS.2007.35.
+//! 2. I. Shin, A. Easwaran, and I. Lee, “Hierarchical Scheduling Framework for
+//!    Virtual Clustering of Multiprocessors,” in 2008 Euromicro Conference on
+//!    Real-Time Systems, July 2008, pp. 181–190. doi: 10.1109/ECRTS.2008.28.
+
+use crate::prelude::*;
+use itertools::Itertools;
+
+/// Integer instants *t* in *[0, end]* such that *(t + shift) mod period* is in
+/// *[0, width]*, in increasing order.
+pub fn periodic_points(period: Time, width: Time, shift: Time, end: Time) -> impl Iterator<Item = Time> {
+    let (period, width, shift, end) =
+        (period.as_nanos(), width.as_nanos(), shift.as_nanos(), end.as_nanos());
+
+    // Windows [a * period - shift, a * period - shift + width], starting from
+    // the first one which ends after zero.
+    let mut window = ((shift - width) / period).ceil();
+    let mut next = 0.0;
+
+    std::iter::from_fn(move || {
+        loop {
+            let start = f64::max(window * period - shift, next).ceil();
+            let stop = f64::min(window * period - shift + width, end).floor();
+
+            if start > end {
+                return None;
+            } else if start <= stop {
+                next = start + 1.0;
+                return Some(Time::nanos(start));
+            }
+
+            window += 1.0;
+        }
+    })
+}
+
+/// Integer instants in *[0, end]*, in increasing order.
+pub fn prefix_points(end: Time) -> impl Iterator<Item = Time> {
+    (0..)
+        .map(|time_ns| Time::nanos(time_ns as f64))
+        .take_while(move |time| *time <= end)
+}
+
+/// Union of the given sorted instants, in increasing order and without
+/// duplicates.
+pub fn merge_points<I>(points: impl IntoIterator<Item = I>) -> impl Iterator<Item = Time>
+    where
+        I: Iterator<Item = Time>,
+{
+    points.into_iter().kmerge().dedup()
+}
+
+/// Instants *t* in *[0, end]* at which the demand of the task in a window of
+/// length *t + shift* may change, i.e. the demand bound function, and the
+/// demand of a job released at the start of the window \[1\].
+///
+/// Both change on a periodic pattern: for *C <= D <= T*, in the windows
+/// *[aT, aT + C]* and at *aT + D* for all integers *a*.
+pub fn dbf_points(task: &RTTask, shift: Time, end: Time) -> impl Iterator<Item = Time> {
+    merge_points([
+        periodic_points(task.period, task.wcet, shift, end),
+        periodic_points(task.period, Time::zero(), shift - task.deadline, end),
+    ])
+}
+
+/// Instants in *[0, end]* at which a job of the task is released, when the
+/// first one is released at zero \[2\].
+pub fn release_points(task: &RTTask, end: Time) -> impl Iterator<Item = Time> {
+    periodic_points(task.period, Time::zero(), Time::zero(), end)
+}
+
+/// Instants *t* in *[0, end]* at which the supply of a periodic resource in a
+/// window of length *t + shift* may change, when the supply is constant for
+/// the `blackout` before each multiple of the period \[2\], i.e. *(t + shift)
+/// mod period* is zero or in *[period - blackout, period)*.
+pub fn supply_points(period: Time, blackout: Time, shift: Time, end: Time) -> impl Iterator<Item = Time> {
+    periodic_points(period, blackout, shift + blackout, end)
+}
+
+#[cfg(test)]
+fn scan(end: u64, predicate: impl Fn(Time) -> bool) -> Vec<Time> {
+    (0..=end)
+        .map(|time_ns| Time::nanos(time_ns as f64))
+        .filter(|time| predicate(*time))
+        .collect()
+}
+
+#[test]
+fn periodic_windows() {
+    let at = |t: u64| Time::nanos(t as f64);
+    let points = |period, width, shift, end| -> Vec<_> {
+        periodic_points(at(period), at(width), Time::nanos(shift), at(end)).collect()
+    };
+
+    assert_eq!(points(10, 2, 0.0, 25), vec![at(0), at(1), at(2), at(10), at(11), at(12), at(20), at(21), at(22)]);
+    assert_eq!(points(10, 2, 9.0, 15), vec![at(1), at(2), at(3), at(11), at(12), at(13)]);
+    assert_eq!(points(10, 0, -3.0, 25), vec![at(3), at(13), at(23)]);
+
+    // Overlapping windows cover every instant once.
+    assert_eq!(points(3, 5, 1.0, 6), (0..=6).map(at).collect::<Vec<_>>());
+
+    assert!(periodic_points(at(10), at(2), Time::zero(), -at(1)).next().is_none());
+    assert!(prefix_points(-at(1)).next().is_none());
+    assert_eq!(prefix_points(at(2)).collect::<Vec<_>>(), vec![at(0), at(1), at(2)]);
+}
+
+#[test]
+// Every instant accepted by the scans of the tests is enumerated, and no other.
+fn no_point_skipped() {
+    use crate::utils::test_tasksets::random_taskset;
+
+    for seed in 0..5 {
+        let taskset = random_taskset(6, 2.5, seed);
+        let end = 3 * taskset.last().unwrap().period.as_nanos() as u64;
+
+        for task_k in taskset.iter() {
+            let shift = task_k.deadline;
+
+            // Baruah 2007 filter.
+            let points: Vec<_> = merge_points(taskset.iter().map(|task_i| dbf_points(task_i, shift, Time::nanos(end as f64)))).collect();
+            let expected = scan(end, |arrival_k| {
+                taskset.iter().any(|task_i| {
+                    let modulus = (arrival_k + shift) % task_i.period;
+
+                    modulus <= task_i.wcet || modulus == task_i.deadline
+                })
+            });
+            assert_eq!(points, expected);
+
+            // MPR EDF filter.
+            for (resource, period, concurrency) in [(75.0, 50.0, 2), (30.0, 40.0, 3), (120.0, 40.0, 3), (0.0, 40.0, 1)] {
+                let blackout = Time::nanos(resource / concurrency as f64).floor();
+                let period = Time::nanos(period);
+
+                let max_wcet = taskset.iter().map(|task_i| task_i.wcet).max().unwrap();
+
+                let points: Vec<_> =
+                    merge_points(taskset.iter().map(|task_i| release_points(task_i, Time::nanos(end as f64))))
+                        .merge(prefix_points(max_wcet - shift))
+                        .merge(supply_points(period, blackout, shift + blackout, Time::nanos(end as f64)))
+                        .dedup()
+                        .collect();
+
+                let expected = scan(end, |arrival_k| {
+                    let interval = arrival_k + shift;
+                    let modulus = (interval + blackout) % period;
+
+                    modulus >= period - blackout || modulus == Time::zero()
+                    || taskset.iter().any(|task_i| interval <= task_i.wcet || arrival_k % task_i.period == Time::zero())
+                });
+                assert_eq!(points, expected);
+            }
+        }
+    }
+}
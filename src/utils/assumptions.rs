@@ -0,0 +1,120 @@
+//! Model assumptions under which the verdicts of the schedulability tests
+//! hold.
+//!
+//! Unlike the preconditions of a test, which are checked on the taskset (e.g.
+//! constrained deadlines), the assumptions are about the system the taskset
+//! models, e.g. the arrival pattern of the jobs or the cost of the context
+//! switches, and cannot be checked by the engine: they must be justified by
+//! whoever relies on the verdict. Each assumption has a stable ID, so that the
+//! documentation of a system can be generated from the results of its
+//! analysis, refer to [`registry`](crate::algorithms::registry).
+
+/// Model assumption of a schedulability test, refer to the
+/// [module](`self`) level documentation.
+#[derive(Debug, Clone, Copy)]
+#[derive(PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Assumption {
+    /// The jobs of each task are released at least one period apart, periodic
+    /// releases being a special case.
+    SporadicArrivals,
+    /// The tasks share no resources besides the processors, have no precedence
+    /// constraints and do not self-suspend.
+    IndependentTasks,
+    /// No job executes for longer than the WCET of its task.
+    WcetUpperBounds,
+    /// Jobs are preempted at any time, at no cost.
+    FullPreemption,
+    /// Context switches, scheduling decisions and job releases take no time,
+    /// besides the overheads accounted for in the WCETs and kernel latencies.
+    ZeroOverheads,
+    /// The task parameters are integer nanoseconds, and the scheduling
+    /// decisions are taken at integer times.
+    IntegerTimes,
+    /// The processors are identical, i.e. run every job at the same speed.
+    IdenticalProcessors,
+    /// Jobs migrate between processors at any time, at no cost.
+    FreeMigration,
+}
+
+impl Assumption {
+    /// Every assumption, in the order of the reports.
+    pub const ALL: &[Assumption] = &[
+        Assumption::SporadicArrivals,
+        Assumption::IndependentTasks,
+        Assumption::WcetUpperBounds,
+        Assumption::FullPreemption,
+        Assumption::ZeroOverheads,
+        Assumption::IntegerTimes,
+        Assumption::IdenticalProcessors,
+        Assumption::FreeMigration,
+    ];
+
+    /// Assumptions of every fully-preemptive test of the sporadic task model.
+    pub const SPORADIC_MODEL: &[Assumption] = &[
+        Assumption::SporadicArrivals,
+        Assumption::IndependentTasks,
+        Assumption::WcetUpperBounds,
+        Assumption::FullPreemption,
+        Assumption::ZeroOverheads,
+    ];
+
+    /// Stable ID of the assumption.
+    pub fn id(&self) -> &'static str {
+        match self {
+            Assumption::SporadicArrivals => "sporadic_arrivals",
+            Assumption::IndependentTasks => "independent_tasks",
+            Assumption::WcetUpperBounds => "wcet_upper_bounds",
+            Assumption::FullPreemption => "full_preemption",
+            Assumption::ZeroOverheads => "zero_overheads",
+            Assumption::IntegerTimes => "integer_times",
+            Assumption::IdenticalProcessors => "identical_processors",
+            Assumption::FreeMigration => "free_migration",
+        }
+    }
+
+    /// Assumption with the given stable ID, if any.
+    pub fn from_id(id: &str) -> Option<Assumption> {
+        Self::ALL.iter().copied().find(|assumption| assumption.id() == id)
+    }
+
+    /// Statement of the assumption, for the documentation of the results.
+    pub fn description(&self) -> &'static str {
+        match self {
+            Assumption::SporadicArrivals =>
+                "The jobs of each task are released at least one period apart.",
+            Assumption::IndependentTasks =>
+                "The tasks share no resources besides the processors, have no precedence constraints and do not self-suspend.",
+            Assumption::WcetUpperBounds =>
+                "No job executes for longer than the WCET of its task.",
+            Assumption::FullPreemption =>
+                "Jobs are preempted at any time, at no cost.",
+            Assumption::ZeroOverheads =>
+                "Context switches, scheduling decisions and job releases take no time, besides the accounted overheads.",
+            Assumption::IntegerTimes =>
+                "The task parameters are integer nanoseconds, and the scheduling decisions are taken at integer times.",
+            Assumption::IdenticalProcessors =>
+                "The processors are identical, running every job at the same speed.",
+            Assumption::FreeMigration =>
+                "Jobs migrate between processors at any time, at no cost.",
+        }
+    }
+}
+
+impl std::fmt::Display for Assumption {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.id())
+    }
+}
+
+#[test]
+fn assumption_ids() {
+    let ids: Vec<_> = Assumption::ALL.iter().map(Assumption::id).collect();
+    assert!(ids.iter().enumerate().all(|(i, id)| !ids[..i].contains(id)));
+
+    assert!(Assumption::ALL.iter().all(|&assumption| Assumption::from_id(assumption.id()) == Some(assumption)));
+    assert!(Assumption::SPORADIC_MODEL.iter().all(|assumption| Assumption::ALL.contains(assumption)));
+    assert_eq!(Assumption::from_id("periodic_arrivals"), None);
+}
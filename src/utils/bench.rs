@@ -0,0 +1,25 @@
+//! Timing helpers for the benchmarks.
+//!
+//! The benchmarks are ignored tests named `bench_*`, placed next to the code
+//! they measure so that they can reach its private functions. Run them with:
+//! `cargo test --release -- --ignored --nocapture bench_`
+
+use std::fmt::Debug;
+use std::time::{Duration, Instant};
+
+/// Run the given closure once, printing its result and elapsed time.
+pub(crate) fn time<R: Debug>(label: &str, run: impl FnOnce() -> R) -> (R, Duration) {
+    let start = Instant::now();
+    let result = run();
+    let elapsed = start.elapsed();
+
+    println!("{label}: {result:?} in {elapsed:?}");
+    (result, elapsed)
+}
+
+/// Print the ratio of the baseline time to the improved time as a speedup.
+pub(crate) fn speedup(label: &str, baseline: Duration, improved: Duration) {
+    let speedup = baseline.as_secs_f64() / improved.as_secs_f64();
+
+    println!("{label}: {speedup:.1}x speedup");
+}
@@ -0,0 +1,396 @@
+//! Demand and request bound functions.
+//!
+//! Pure functions bounding the execution demand of a task over an interval,
+//! shared by the analyses. Intervals are measured in the same unit as the
+//! tasks' parameters, and time is discrete with a resolution of
+//! [`Time::one`].
+//!
+//! #### Implements:
+//! - [`dbf`], [`total_dbf`] \
+//!   | Demand bound function of sporadic tasks \[1\]
+//! - [`dbf_approx`] \
+//!   | Linear upper approximation of the demand bound function, *dbf′* \[2\]
+//! - [`rbf`], [`total_rbf`] \
+//!   | Request bound function of sporadic tasks \[3\]
+//! - [`workload`], [`workload_carry_in`] \
+//!   | Workload of a task without and with a carry-in job \[4\]
+//! - [`dbf_jitter`], [`rbf_jitter`] \
+//!   | Demand and request bound functions with release jitter \[5\]
+//! - [`dbf_offset`] \
+//!   | Demand of a periodic task with offset over a given interval \[6\]
+//! - [`dbf_multiframe`], [`rbf_multiframe`] \
+//!   | Demand and request bound functions of multiframe tasks \[7\]
+//! - [`dbf_gmf`], [`rbf_gmf`] \
+//!   | Demand and request bound functions of generalized multiframe tasks \[9\]
+//! - [`dbf_arrival_curve`], [`rbf_arrival_curve`] \
+//!   | Demand and request bound functions of arbitrary activation patterns,
+//!   | given by their upper arrival curve \[8\]
+//!
+//! ---
+//! #### References:
+//! 1. S. K. Baruah, A. K. Mok, and L. E. Rosier, “Preemptively scheduling
+//!    hard-real-time sporadic tasks on one processor,” in Proceedings 11th
+//!    Real-Time Systems Symposium, Dec. 1990, pp. 182–190.
+//!    doi: 10.1109/REAL.1990.128746.
+//! 2. K. Albers and F. Slomka, “An event stream driven approximation for the
+//!    analysis of real-time systems,” in Proceedings 16th Euromicro Conference
+//!    on Real-Time Systems, July 2004, pp. 187–195.
+//! 3. M. Joseph and P. Pandya, “Finding Response Times in a Real-Time System,”
+//!    The Computer Journal, vol. 29, no. 5, pp. 390–395, Jan. 1986,
+//!    doi: 10.1093/comjnl/29.5.390.
+//! 4. N. Guan, M. Stigge, W. Yi, and G. Yu, “New Response Time Bounds for Fixed
+//!    Priority Multiprocessor Scheduling,” in 2009 30th IEEE Real-Time Systems
+//!    Symposium, Dec. 2009, pp. 387–397. doi: 10.1109/RTSS.2009.11.
+//! 5. N. Audsley, A. Burns, M. Richardson, K. Tindell, and A. J. Wellings,
+//!    “Applying new scheduling theory to static priority pre-emptive
+//!    scheduling,” Software Engineering Journal, vol. 8, no. 5, pp. 284–292,
+//!    Sept. 1993, doi: 10.1049/sej.1993.0034.
+//! 6. S. K. Baruah, L. E. Rosier, and R. R. Howell, “Algorithms and complexity
+//!    concerning the preemptive scheduling of periodic, real-time tasks on one
+//!    processor,” Real-Time Systems, vol. 2, no. 4, pp. 301–324, Nov. 1990,
+//!    doi: 10.1007/BF01995675.
+//! 7. A. K. Mok and D. Chen, “A multiframe model for real-time tasks,” IEEE
+//!    Transactions on Software Engineering, vol. 23, no. 10, pp. 635–645,
+//!    Oct. 1997, doi: 10.1109/32.637146.
+//! 8. L. Thiele, S. Chakraborty, and M. Naedele, “Real-time calculus for
+//!    scheduling hard real-time systems,” in 2000 IEEE International Symposium
+//!    on Circuits and Systems, May 2000, vol. 4, pp. 101–104.
+//! 9. S. Baruah, D. Chen, S. Gorinsky, and A. Mok, “Generalized Multiframe
+//!    Tasks,” Real-Time Systems, vol. 17, no. 1, pp. 5–22, July 1999,
+//!    doi: 10.1023/A:1008030427220.
+
+use crate::prelude::*;
+
+/// Maximum cumulative execution of the jobs of the task with both release and
+/// absolute deadline in any interval of the given length \[1\].
+pub fn dbf(task: &RTTask, interval: Time) -> Time {
+    dbf_jitter(task, Time::zero(), interval)
+}
+
+/// Sum of the [`dbf`] of the tasks.
+pub fn total_dbf(taskset: &[RTTask], interval: Time) -> Time {
+    taskset.iter()
+        .map(|task| dbf(task, interval))
+        .sum()
+}
+
+/// Linear upper bound on the [`dbf`], *C + U (t - D)* from the first deadline
+/// on \[2\]. It is exact at the deadlines of the synchronous jobs.
+pub fn dbf_approx(task: &RTTask, interval: Time) -> Time {
+    if interval < task.deadline {
+        Time::zero()
+    } else {
+        task.wcet + (interval - task.deadline) * task.utilization()
+    }
+}
+
+/// Maximum cumulative execution of the jobs of the task released in any
+/// half-open interval of the given length \[3\].
+pub fn rbf(task: &RTTask, interval: Time) -> Time {
+    rbf_jitter(task, Time::zero(), interval)
+}
+
+/// Sum of the [`rbf`] of the tasks.
+pub fn total_rbf(taskset: &[RTTask], interval: Time) -> Time {
+    taskset.iter()
+        .map(|task| rbf(task, interval))
+        .sum()
+}
+
+/// Maximum execution of the task in any interval of the given length which
+/// starts with a job release, i.e. without a carry-in job \[4\].
+pub fn workload(task: &RTTask, interval: Time) -> Time {
+    // Equation 5 [4]
+    (interval / task.period).floor() * task.wcet
+        + Time::min(task.wcet, interval % task.period)
+}
+
+/// Maximum execution of the task in any interval of the given length, with a
+/// carry-in job which completes by the task's `response_time` \[4\].
+pub fn workload_carry_in(task: &RTTask, interval: Time, response_time: Time) -> Time {
+    let work_interval = Time::max(Time::zero(), interval - task.wcet);
+
+    // Equation 6 [4]
+    (work_interval / task.period).floor() * task.wcet
+        + task.wcet
+        + Time::clamp(
+            work_interval % task.period - (task.period - response_time),
+            Time::zero(),
+            Time::max(task.wcet - Time::one(), Time::zero()),
+        )
+}
+
+/// [`dbf`] of a task whose jobs may be released up to `jitter` after their
+/// arrival, their deadlines being relative to the arrival \[5\].
+pub fn dbf_jitter(task: &RTTask, jitter: Time, interval: Time) -> Time {
+    let jobs = ((interval + jitter - task.deadline) / task.period).floor() + 1.0;
+
+    f64::max(0.0, jobs) * task.wcet
+}
+
+/// [`rbf`] of a task whose jobs may be released up to `jitter` after their
+/// arrival \[5\].
+pub fn rbf_jitter(task: &RTTask, jitter: Time, interval: Time) -> Time {
+    if interval <= Time::zero() {
+        return Time::zero();
+    }
+
+    ((interval + jitter) / task.period).ceil() * task.wcet
+}
+
+/// Cumulative execution of the jobs of a periodic task, released at `offset +
+/// k T`, with both release and absolute deadline in `[start, end]` \[6\].
+pub fn dbf_offset(task: &RTTask, offset: Time, start: Time, end: Time) -> Time {
+    // Index of the first job released at or after the start of the interval,
+    // and of the last job with deadline by its end.
+    let first = f64::max(0.0, ((start - offset) / task.period).ceil());
+    let last = ((end - offset - task.deadline) / task.period).floor();
+
+    f64::max(0.0, last - first + 1.0) * task.wcet
+}
+
+/// [`dbf`] of a multiframe task, whose consecutive jobs cyclically execute for
+/// the given `wcets`, released at least `period` apart \[7\]. Maximized over
+/// the frame of the first job.
+pub fn dbf_multiframe(wcets: &[Time], deadline: Time, period: Time, interval: Time) -> Time {
+    let jobs = ((interval - deadline) / period).floor() + 1.0;
+
+    multiframe_max_execution(wcets, f64::max(0.0, jobs) as usize)
+}
+
+/// [`rbf`] of a multiframe task, refer to [`dbf_multiframe`] \[7\].
+pub fn rbf_multiframe(wcets: &[Time], period: Time, interval: Time) -> Time {
+    if interval <= Time::zero() {
+        return Time::zero();
+    }
+
+    multiframe_max_execution(wcets, (interval / period).ceil() as usize)
+}
+
+// Maximum execution of the given number of consecutive frames: the full cycles
+// plus the heaviest run of the remaining frames.
+fn multiframe_max_execution(wcets: &[Time], jobs: usize) -> Time {
+    if wcets.is_empty() {
+        return Time::zero();
+    }
+
+    let cycle = wcets.iter().copied().sum::<Time>();
+    let remaining = jobs % wcets.len();
+
+    let heaviest_run =
+        (0..wcets.len())
+            .map(|first| (0..remaining).map(|j| wcets[(first + j) % wcets.len()]).sum::<Time>())
+            .max()
+            .unwrap_or(Time::zero());
+
+    cycle * (jobs / wcets.len()) as f64 + heaviest_run
+}
+
+/// [`dbf`] of a generalized multiframe task, whose consecutive jobs cyclically
+/// follow the given `frames`, each with its own WCET and relative deadline,
+/// and released at least the frame's period before the next one \[9\].
+/// Maximized over the frame of the first job. The periods must be positive.
+pub fn dbf_gmf(frames: &[RTTask], interval: Time) -> Time {
+    (0..frames.len())
+        .map(|first| {
+            let mut demand = Time::zero();
+            let mut release = Time::zero();
+
+            for frame in frames.iter().cycle().skip(first) {
+                if release > interval {
+                    break;
+                } else if release + frame.deadline <= interval {
+                    demand = demand + frame.wcet;
+                }
+
+                release = release + frame.period;
+            }
+
+            demand
+        })
+        .max()
+        .unwrap_or(Time::zero())
+}
+
+/// [`rbf`] of a generalized multiframe task, refer to [`dbf_gmf`] \[9\].
+pub fn rbf_gmf(frames: &[RTTask], interval: Time) -> Time {
+    (0..frames.len())
+        .map(|first| {
+            let mut request = Time::zero();
+            let mut release = Time::zero();
+
+            for frame in frames.iter().cycle().skip(first) {
+                if release >= interval {
+                    break;
+                }
+
+                request = request + frame.wcet;
+                release = release + frame.period;
+            }
+
+            request
+        })
+        .max()
+        .unwrap_or(Time::zero())
+}
+
+/// [`dbf`] of a task activated according to its upper arrival curve, i.e. the
+/// maximum number of activations in any half-open window of the given length
+/// \[8\].
+pub fn dbf_arrival_curve<F>(wcet: Time, deadline: Time, arrivals: F, interval: Time) -> Time
+    where F: Fn(Time) -> f64
+{
+    // The jobs are released in the closed window [0, t - D].
+    let window = interval - deadline + Time::one();
+    if window <= Time::zero() {
+        return Time::zero();
+    }
+
+    arrivals(window) * wcet
+}
+
+/// [`rbf`] of a task activated according to its upper arrival curve, refer to
+/// [`dbf_arrival_curve`] \[8\].
+pub fn rbf_arrival_curve<F>(wcet: Time, arrivals: F, interval: Time) -> Time
+    where F: Fn(Time) -> f64
+{
+    if interval <= Time::zero() {
+        return Time::zero();
+    }
+
+    arrivals(interval) * wcet
+}
+
+/// Upper arrival curve of sporadic activations.
+pub fn sporadic_arrivals(period: Time) -> impl Fn(Time) -> f64 {
+    pjd_arrivals(period, Time::zero(), Time::zero())
+}
+
+/// Upper arrival curve of periodic activations with `jitter`, at least
+/// `min_distance` apart, if not zero (the *PJd* event model).
+pub fn pjd_arrivals(period: Time, jitter: Time, min_distance: Time) -> impl Fn(Time) -> f64 {
+    move |window: Time| {
+        if window <= Time::zero() {
+            return 0.0;
+        }
+
+        let periodic = ((window + jitter) / period).ceil();
+
+        if min_distance > Time::zero() {
+            f64::min(periodic, (window / min_distance).ceil())
+        } else {
+            periodic
+        }
+    }
+}
+
+#[test]
+fn sporadic_bounds() {
+    let task = RTTask::new_ns(2, 5, 7);
+    let at = |t: u64| Time::nanos(t as f64);
+
+    let dbfs: Vec<_> = [0, 4, 5, 11, 12, 19].map(|t| dbf(&task, at(t)).as_nanos()).to_vec();
+    assert_eq!(dbfs, vec![0.0, 0.0, 2.0, 2.0, 4.0, 6.0]);
+
+    let rbfs: Vec<_> = [0, 1, 7, 8, 14].map(|t| rbf(&task, at(t)).as_nanos()).to_vec();
+    assert_eq!(rbfs, vec![0.0, 2.0, 2.0, 4.0, 4.0]);
+
+    for t in 0..100 {
+        assert!(dbf_approx(&task, at(t)) >= dbf(&task, at(t)));
+        assert!(workload(&task, at(t)) <= workload_carry_in(&task, at(t), task.deadline));
+    }
+
+    assert_eq!(dbf_approx(&task, at(5)), dbf(&task, at(5)));
+
+    // A task of WCET 3 below these responds at 10 = 3 + 3 * 1 + 2 * 2.
+    let taskset = [RTTask::new_ns(1, 4, 4), RTTask::new_ns(2, 6, 6)];
+    assert_eq!(total_rbf(&taskset, at(10)) + at(3), at(10));
+    assert_eq!(total_rbf(&taskset, at(9)) + at(3), at(10));
+    assert_eq!(total_dbf(&taskset, at(12)), at(7));
+}
+
+#[test]
+fn jitter_and_offsets() {
+    let task = RTTask::new_ns(2, 5, 7);
+    let at = |t: u64| Time::nanos(t as f64);
+
+    assert_eq!(dbf_jitter(&task, at(0), at(12)), dbf(&task, at(12)));
+    assert_eq!(dbf_jitter(&task, at(2), at(3)), at(2));
+    assert_eq!(rbf_jitter(&task, at(3), at(4)), at(2));
+    assert_eq!(rbf_jitter(&task, at(3), at(5)), at(4));
+
+    // Jobs released at 3, 10, 17, ..., with deadlines 8, 15, 22, ...
+    assert_eq!(dbf_offset(&task, at(3), at(0), at(14)), at(2));
+    assert_eq!(dbf_offset(&task, at(3), at(0), at(15)), at(4));
+    assert_eq!(dbf_offset(&task, at(3), at(4), at(15)), at(2));
+    assert_eq!(dbf_offset(&task, at(3), at(4), at(8)), at(0));
+
+    // With zero offset and a synchronous interval, the dbf.
+    for t in 0..50 {
+        assert_eq!(dbf_offset(&task, at(0), at(0), at(t)), dbf(&task, at(t)));
+    }
+}
+
+#[test]
+fn multiframe_bounds() {
+    // Frames of 3 and 1, period 3.
+    let wcets = [Time::nanos(3.0), Time::nanos(1.0)];
+    let at = |t: u64| Time::nanos(t as f64);
+
+    let rbfs: Vec<_> = [0, 1, 3, 4, 6, 7].map(|t| rbf_multiframe(&wcets, at(3), at(t)).as_nanos()).to_vec();
+    assert_eq!(rbfs, vec![0.0, 3.0, 3.0, 4.0, 4.0, 7.0]);
+
+    let dbfs: Vec<_> = [2, 3, 6, 9].map(|t| dbf_multiframe(&wcets, at(3), at(3), at(t)).as_nanos()).to_vec();
+    assert_eq!(dbfs, vec![0.0, 3.0, 4.0, 7.0]);
+
+    // A single frame is a sporadic task.
+    let task = RTTask::new_ns(2, 5, 7);
+    for t in 0..50 {
+        assert_eq!(dbf_multiframe(&[task.wcet], task.deadline, task.period, at(t)), dbf(&task, at(t)));
+        assert_eq!(rbf_multiframe(&[task.wcet], task.period, at(t)), rbf(&task, at(t)));
+    }
+}
+
+#[test]
+fn generalized_multiframe_bounds() {
+    let at = |t: u64| Time::nanos(t as f64);
+
+    // Frames sharing deadline and period are a multiframe task.
+    let wcets = [at(3), at(1), at(2)];
+    let frames = wcets.map(|wcet| RTTask { wcet, deadline: at(4), period: at(5) });
+    for t in 0..50 {
+        assert_eq!(dbf_gmf(&frames, at(t)), dbf_multiframe(&wcets, at(4), at(5), at(t)));
+        assert_eq!(rbf_gmf(&frames, at(t)), rbf_multiframe(&wcets, at(5), at(t)));
+    }
+
+    // A short frame followed by a long one: the heavy frame with the longer
+    // deadline completes its demand later.
+    let frames = [RTTask::new_ns(1, 2, 3), RTTask::new_ns(4, 8, 10)];
+    let dbfs: Vec<_> = [2, 8, 11, 13].map(|t| dbf_gmf(&frames, at(t)).as_nanos()).to_vec();
+    assert_eq!(dbfs, vec![1.0, 4.0, 5.0, 5.0]);
+    assert_eq!(rbf_gmf(&frames, at(4)), at(5));
+}
+
+#[test]
+fn arrival_curves() {
+    let task = RTTask::new_ns(2, 5, 7);
+    let at = |t: u64| Time::nanos(t as f64);
+
+    // Sporadic arrivals give the sporadic bounds.
+    for t in 0..50 {
+        let arrivals = sporadic_arrivals(task.period);
+        assert_eq!(dbf_arrival_curve(task.wcet, task.deadline, &arrivals, at(t)), dbf(&task, at(t)));
+        assert_eq!(rbf_arrival_curve(task.wcet, &arrivals, at(t)), rbf(&task, at(t)));
+
+        let arrivals = pjd_arrivals(task.period, at(3), Time::zero());
+        assert_eq!(rbf_arrival_curve(task.wcet, &arrivals, at(t)), rbf_jitter(&task, at(3), at(t)));
+    }
+
+    // The bursts due to the jitter are limited by the minimum distance.
+    let arrivals = pjd_arrivals(at(10), at(25), at(2));
+    assert_eq!(arrivals(at(1)), 1.0);
+    assert_eq!(arrivals(at(3)), 2.0);
+    assert_eq!(arrivals(at(5)), 3.0);
+    assert_eq!(arrivals(at(7)), 4.0);
+    assert_eq!(arrivals(at(20)), 5.0);
+}
@@ -0,0 +1,38 @@
+//! Relation between the deadlines and the periods of a taskset.
+
+use crate::prelude::*;
+
+/// Most general relation between the relative deadlines and the periods of the
+/// tasks. Ordered from the most to the least restrictive model.
+#[derive(Debug, Clone, Copy)]
+#[derive(PartialEq, Eq, PartialOrd, Ord)]
+pub enum DeadlineModel {
+    /// *D = T* for every task.
+    Implicit,
+    /// *D <= T* for every task.
+    Constrained,
+    /// Some task has *D > T*, thus its jobs may overlap.
+    Arbitrary,
+}
+
+impl DeadlineModel {
+    pub fn of(taskset: &[RTTask]) -> Self {
+        if RTUtils::implicit_deadlines(taskset) {
+            Self::Implicit
+        } else if RTUtils::constrained_deadlines(taskset) {
+            Self::Constrained
+        } else {
+            Self::Arbitrary
+        }
+    }
+}
+
+#[test]
+fn deadline_models() {
+    assert_eq!(DeadlineModel::of(&[]), DeadlineModel::Implicit);
+    assert_eq!(DeadlineModel::of(&[RTTask::new_ns(1, 4, 4), RTTask::new_ns(1, 5, 5)]), DeadlineModel::Implicit);
+    assert_eq!(DeadlineModel::of(&[RTTask::new_ns(1, 4, 4), RTTask::new_ns(1, 3, 5)]), DeadlineModel::Constrained);
+    assert_eq!(DeadlineModel::of(&[RTTask::new_ns(1, 3, 4), RTTask::new_ns(1, 8, 5)]), DeadlineModel::Arbitrary);
+
+    assert!(DeadlineModel::Constrained <= DeadlineModel::Arbitrary);
+}
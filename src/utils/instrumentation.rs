@@ -0,0 +1,214 @@
+//! Runtime and memory instrumentation of schedulability tests.
+//!
+//! Every test run can be measured with [`measure`], which records its
+//! wall-clock runtime and, when the `alloc-stats` feature is enabled and the
+//! [`CountingAllocator`] is the global allocator of the program, the peak
+//! memory allocated and the number of allocations performed during the run.
+//! The allocations are counted per thread, thus concurrent runs on different
+//! threads do not disturb each other, but the allocations of the threads
+//! spawned by a test are not accounted to it.
+//!
+//! The counting allocator wraps the system allocator, and is installed by the
+//! program with:
+//! ```ignore
+//! #[global_allocator]
+//! static ALLOCATOR: eva_rt_engine::utils::instrumentation::CountingAllocator =
+//!     eva_rt_engine::utils::instrumentation::CountingAllocator;
+//! ```
+//!
+//! #### Implements:
+//! - [`measure`] \
+//!   | Runtime and memory usage of a closure
+//! - [`measured_is_schedulable`] \
+//!   | Runtime and memory usage of a schedulability test
+//! - [`CountingAllocator`] \
+//!   | Global allocator counting the allocations of each thread (`alloc-stats` feature)
+
+use crate::prelude::*;
+use std::time::{Duration, Instant};
+
+/// Cost of a single run.
+#[derive(Debug, Clone, Copy)]
+#[derive(PartialEq, Eq)]
+pub struct Measurement {
+    pub runtime: Duration,
+    /// Memory usage, if the [`CountingAllocator`] is installed.
+    pub memory: Option<MemoryUsage>,
+}
+
+/// Memory allocated by the current thread during a run.
+#[derive(Debug, Clone, Copy, Default)]
+#[derive(PartialEq, Eq)]
+pub struct MemoryUsage {
+    /// Peak of the allocated bytes, above the ones allocated before the run.
+    pub peak_bytes: usize,
+    /// Number of allocations, reallocations included.
+    pub allocations: u64,
+}
+
+/// Run the closure, measuring its wall-clock runtime and its memory usage.
+pub fn measure<R>(f: impl FnOnce() -> R) -> (R, Measurement) {
+    let counters = counting::start();
+    let start = Instant::now();
+    let result = f();
+    let runtime = start.elapsed();
+
+    (result, Measurement { runtime, memory: counting::stop(counters) })
+}
+
+/// Run the given analysis, measuring its wall-clock runtime and its memory
+/// usage.
+pub fn measured_is_schedulable<A, T, Taskset>(analysis: &A, taskset: Taskset) -> (anyhow::Result<T>, Measurement)
+    where
+        A: SchedAnalysis<T, Taskset> + ?Sized,
+{
+    measure(|| analysis.is_schedulable(taskset))
+}
+
+#[cfg(feature = "alloc-stats")]
+pub use counting::CountingAllocator;
+
+#[cfg(feature = "alloc-stats")]
+mod counting {
+    use super::MemoryUsage;
+    use std::alloc::{GlobalAlloc, Layout, System};
+    use std::cell::Cell;
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    /// Global allocator which counts the allocated bytes and the allocations of
+    /// each thread, refer to the [module](`super`) level documentation.
+    pub struct CountingAllocator;
+
+    static INSTALLED: AtomicBool = AtomicBool::new(false);
+
+    #[derive(Clone, Copy)]
+    pub struct Counters {
+        // Memory freed by a thread may have been allocated by another, thus
+        // the allocated bytes of a thread may be negative.
+        current: isize,
+        peak: isize,
+        allocations: u64,
+    }
+
+    thread_local! {
+        static COUNTERS: Cell<Counters> = const { Cell::new(Counters { current: 0, peak: 0, allocations: 0 }) };
+    }
+
+    fn update(delta: isize, allocations: u64) {
+        if !INSTALLED.load(Ordering::Relaxed) {
+            INSTALLED.store(true, Ordering::Relaxed);
+        }
+
+        // The counters are gone while the thread exits.
+        let _ = COUNTERS.try_with(|counters| {
+            let mut value = counters.get();
+            value.current += delta;
+            value.peak = isize::max(value.peak, value.current);
+            value.allocations += allocations;
+            counters.set(value);
+        });
+    }
+
+    unsafe impl GlobalAlloc for CountingAllocator {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            let ptr = unsafe { System.alloc(layout) };
+            if !ptr.is_null() {
+                update(layout.size() as isize, 1);
+            }
+
+            ptr
+        }
+
+        unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+            let ptr = unsafe { System.alloc_zeroed(layout) };
+            if !ptr.is_null() {
+                update(layout.size() as isize, 1);
+            }
+
+            ptr
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            unsafe { System.dealloc(ptr, layout) };
+            update(-(layout.size() as isize), 0);
+        }
+
+        unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+            let new_ptr = unsafe { System.realloc(ptr, layout, new_size) };
+            if !new_ptr.is_null() {
+                update(new_size as isize - layout.size() as isize, 1);
+            }
+
+            new_ptr
+        }
+    }
+
+    // Reset the peak and the allocations of the thread, returning the
+    // previous counters.
+    pub fn start() -> Counters {
+        COUNTERS.with(|counters| {
+            let previous = counters.get();
+            counters.set(Counters { current: previous.current, peak: previous.current, allocations: 0 });
+            previous
+        })
+    }
+
+    // Usage since the matching call to start, restoring the counters of
+    // enclosing measurements. The allocator is installed if anything was
+    // ever allocated through it.
+    pub fn stop(previous: Counters) -> Option<MemoryUsage> {
+        let now = COUNTERS.with(|counters| {
+            let now = counters.get();
+            counters.set(Counters {
+                current: now.current,
+                peak: isize::max(previous.peak, now.peak),
+                allocations: previous.allocations + now.allocations,
+            });
+
+            now
+        });
+
+        INSTALLED.load(Ordering::Relaxed).then(|| MemoryUsage {
+            peak_bytes: (now.peak - previous.current).max(0) as usize,
+            allocations: now.allocations,
+        })
+    }
+}
+
+#[cfg(not(feature = "alloc-stats"))]
+mod counting {
+    use super::MemoryUsage;
+
+    pub struct Counters;
+
+    pub fn start() -> Counters { Counters }
+
+    pub fn stop(_: Counters) -> Option<MemoryUsage> { None }
+}
+
+#[cfg(all(test, feature = "alloc-stats"))]
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+#[test]
+fn measured_runs() {
+    let (sum, measurement) = measure(|| {
+        let mut buffer: Vec<u64> = Vec::with_capacity(1 << 16);
+        buffer.extend(0 .. 1 << 16);
+        buffer.iter().sum::<u64>()
+    });
+
+    assert_eq!(sum, (0 .. 1 << 16).sum::<u64>());
+
+    assert_eq!(measurement.memory.is_some(), cfg!(feature = "alloc-stats"));
+    if let Some(memory) = measurement.memory {
+        assert!(memory.peak_bytes >= 8 << 16);
+        assert!(memory.allocations >= 1);
+    }
+
+    // Nothing allocated.
+    let (_, measurement) = measure(|| 1 + 1);
+    if let Some(memory) = measurement.memory {
+        assert_eq!(memory, MemoryUsage::default());
+    }
+}
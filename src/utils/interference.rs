@@ -0,0 +1,189 @@
+//! Workload and interference bounds of the global multiprocessor tests.
+//!
+//! Global tests bound the interference a task suffers in a window by the
+//! workload of every other task in it, either without a carry-in job, i.e. a
+//! job released before the window and still executing in it, or with one. The
+//! workload is then bounded by the gap, i.e. the part of the window in which
+//! the task under analysis does not execute. The [`InterferenceModel`] trait
+//! captures these variants, implemented once per published bound.
+//!
+//! #### Implements:
+//! - [`Bcl09`] \
+//!   | Densest packing of the jobs given the response time of the task \[1\]
+//! - [`Baruah07`] \
+//!   | Demand in a window ending at a deadline, EDF scheduling \[2\]
+//! - [`Guan09`] \
+//!   | Workload in a busy window, FP scheduling \[3\]
+//! - [`Shin08`] \
+//!   | Demand in a window ending at a deadline, carry-in by deadline, EDF
+//!   | scheduling \[4\]
+//! - [`total_interference`] \
+//!   | Non carry-in interference of all tasks plus the largest carry-in
+//!   | increments.
+//!
+//! The blocked evaluation of the total interference on many windows at once,
+//! in `interference_block`, inlines the same bounds.
+//!
+//! ---
+//! #### References:
+//! 1. M. Bertogna, M. Cirinei, and G. Lipari, “Schedulability Analysis of
+//!    Global Scheduling Algorithms on Multiprocessor Platforms,” IEEE
+//!    Transactions on Parallel and Distributed Systems, vol. 20, no. 4, pp.
+//!    553–566, Apr. 2009, doi: 10.1109/TPDS.2008.129.
+//! 2. S. Baruah, “Techniques for Multiprocessor Global Schedulability Analysis,”
+//!    in 28th IEEE International Real-Time Systems Symposium (RTSS 2007),
+//!    Tucson, AZ, USA: IEEE, Dec. 2007, pp. 119–128. doi: 10.1109/RTSS.2007.35.
+//! 3. N. Guan, M. Stigge, W. Yi, and G. Yu, “New Response Time Bounds for Fixed
+//!    Priority Multiprocessor Scheduling,” in 2009 30th IEEE Real-Time Systems
+//!    Symposium, Dec. 2009, pp. 387–397. doi: 10.1109/RTSS.2009.11.
+//! 4. I. Shin, A. Easwaran, and I. Lee, “Hierarchical Scheduling Framework for
+//!    Virtual Clustering of Multiprocessors,” in 2008 Euromicro Conference on
+//!    Real-Time Systems, July 2008, pp. 181–190. doi: 10.1109/ECRTS.2008.28.
+
+use crate::prelude::*;
+use crate::utils::dbf;
+
+/// Workload of an interfering task in a window of the given length.
+pub trait InterferenceModel {
+    /// Workload of the task without a carry-in job.
+    fn non_carry_in(&self, task: &RTTask, window: Time) -> Time;
+
+    /// Workload of the task with a carry-in job, which completes by the task's
+    /// `response_time`. Not smaller than the non carry-in workload.
+    fn carry_in(&self, task: &RTTask, response_time: Time, window: Time) -> Time;
+
+    /// Non carry-in workload, bounded by the gap.
+    fn non_carry_in_bounded(&self, task: &RTTask, window: Time, gap: Time) -> Time {
+        bounded_by_gap(self.non_carry_in(task, window), gap)
+    }
+
+    /// Carry-in workload, bounded by the gap.
+    fn carry_in_bounded(&self, task: &RTTask, response_time: Time, window: Time, gap: Time) -> Time {
+        bounded_by_gap(self.carry_in(task, response_time, window), gap)
+    }
+}
+
+fn bounded_by_gap(workload: Time, gap: Time) -> Time {
+    Time::clamp(workload, Time::zero(), Time::max(gap, Time::zero()))
+}
+
+/// Bertogna, Cirinei, Lipari 2009 \[1\]: the first job of the window completes
+/// by the response time, and the following ones execute as soon as released.
+pub struct Bcl09;
+
+impl InterferenceModel for Bcl09 {
+    fn non_carry_in(&self, task: &RTTask, window: Time) -> Time {
+        self.carry_in(task, task.wcet, window)
+    }
+
+    fn carry_in(&self, task: &RTTask, response_time: Time, window: Time) -> Time {
+        // Section 4 Equations 5, 6 [1], with the slack D - R in place of the
+        // laxity of the task.
+        let extended_window = window + response_time - task.wcet;
+        let jobs = (extended_window / task.period).floor();
+
+        jobs * task.wcet + Time::min(task.wcet, extended_window - jobs * task.period)
+    }
+}
+
+/// Baruah 2007 \[2\]: the demand bound function without carry-in, and the
+/// workload of a job released at the start of the window with carry-in.
+pub struct Baruah07;
+
+impl InterferenceModel for Baruah07 {
+    fn non_carry_in(&self, task: &RTTask, window: Time) -> Time {
+        // DBF [2]
+        dbf::dbf(task, window)
+    }
+
+    fn carry_in(&self, task: &RTTask, _: Time, window: Time) -> Time {
+        // DBF' [2]
+        dbf::workload(task, window)
+    }
+}
+
+/// Guan, Stigge, Yi, Yu 2009 \[3\]: workload in a busy window, whose start is
+/// a job release without carry-in.
+pub struct Guan09;
+
+impl InterferenceModel for Guan09 {
+    fn non_carry_in(&self, task: &RTTask, window: Time) -> Time {
+        // Equation 5 [3]
+        dbf::workload(task, window)
+    }
+
+    fn carry_in(&self, task: &RTTask, response_time: Time, window: Time) -> Time {
+        // Equation 6 [3]
+        dbf::workload_carry_in(task, window, response_time)
+    }
+}
+
+/// Shin, Easwaran, Lee 2008 \[4\]: the demand bound function without carry-in,
+/// and the jobs with deadline in the window plus a carry-in job executing up to
+/// the start of the window with carry-in.
+pub struct Shin08;
+
+impl InterferenceModel for Shin08 {
+    fn non_carry_in(&self, task: &RTTask, window: Time) -> Time {
+        dbf::dbf(task, window)
+    }
+
+    fn carry_in(&self, task: &RTTask, _: Time, window: Time) -> Time {
+        // Equation 3 [4]
+        let jobs = ((window + task.period - task.deadline) / task.period).floor();
+
+        jobs * task.wcet
+            + Time::min(task.wcet, Time::max(Time::zero(), window - jobs * task.period))
+    }
+}
+
+/// Total interference: the sum of the non carry-in interferences of all tasks,
+/// plus the `num_carry_in` largest differences between the carry-in and the non
+/// carry-in interference. The interferences are paired by task.
+pub fn total_interference(non_carry_in: &[Time], carry_in: &[Time], num_carry_in: usize) -> Time {
+    let mut differences: Vec<_> =
+        carry_in.iter().zip(non_carry_in)
+            .map(|(&itf_ci, &itf_nc)| itf_ci - itf_nc)
+            .collect();
+
+    differences.sort_unstable();
+
+    non_carry_in.iter().copied().sum::<Time>()
+        + differences.into_iter().rev().take(num_carry_in).sum::<Time>()
+}
+
+#[test]
+fn interference_models() {
+    let task = RTTask::new_ns(2, 5, 7);
+    let at = |t: u64| Time::nanos(t as f64);
+
+    // Example 2 [1]: a task of laxity 0 fully occupies any window.
+    let tight = RTTask::new_ns(1, 1, 1);
+    assert_eq!(Bcl09.carry_in(&tight, tight.deadline, at(10)), at(10));
+    assert_eq!(Bcl09.carry_in(&RTTask::new_ns(1, 10, 10), at(10), at(1)), at(1));
+
+    for t in 0..50 {
+        let window = at(t);
+
+        assert_eq!(Bcl09.non_carry_in(&task, window), Guan09.non_carry_in(&task, window));
+
+        for model in [&Bcl09 as &dyn InterferenceModel, &Baruah07, &Guan09, &Shin08] {
+            assert!(model.non_carry_in(&task, window) <= model.carry_in(&task, task.deadline, window));
+            assert!(model.carry_in_bounded(&task, task.deadline, window, at(3)) <= at(3));
+            assert_eq!(model.non_carry_in_bounded(&task, window, -at(1)), Time::zero());
+        }
+    }
+}
+
+#[test]
+fn total_interference_largest_increments() {
+    let at = |t: u64| Time::nanos(t as f64);
+
+    let non_carry_in = [at(1), at(2), at(3)];
+    let carry_in = [at(5), at(3), at(6)];
+
+    assert_eq!(total_interference(&non_carry_in, &carry_in, 0), at(6));
+    assert_eq!(total_interference(&non_carry_in, &carry_in, 1), at(10));
+    assert_eq!(total_interference(&non_carry_in, &carry_in, 2), at(13));
+    assert_eq!(total_interference(&non_carry_in, &carry_in, 5), at(14));
+}
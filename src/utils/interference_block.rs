@@ -0,0 +1,152 @@
+//! Blocked evaluation of global interference bounds.
+//!
+//! Many global tests bound the interference suffered by a task at a given
+//! arrival point as the sum of the non carry-in interference of every task,
+//! plus the *(m - 1)* largest differences between the carry-in and the non
+//! carry-in interference. Instead of evaluating each arrival point on its own,
+//! the interference values of a whole block of arrival points are stored in
+//! flat per-task rows, so that the inner loops run over contiguous arrays.
+
+/// Number of arrival points evaluated at once.
+pub(crate) const ARRIVAL_BLOCK_SIZE: usize = 64;
+
+// Above this many carry-in tasks, the largest differences are selected column
+// by column, as the compare-and-swap chain costs O(num_carry_in) per value.
+const MAX_CARRY_IN_CHAIN: usize = 16;
+
+pub(crate) struct InterferenceBlock {
+    num_tasks: usize,
+    // Row-major: the values of task i are at [i * ARRIVAL_BLOCK_SIZE ..].
+    non_carry_in: Vec<f64>,
+    carry_in_diff: Vec<f64>,
+    column: Vec<f64>,
+    // Row-major: the s-th largest carry-in difference of each arrival point
+    // is at [s * ARRIVAL_BLOCK_SIZE ..].
+    largest: Vec<f64>,
+}
+
+impl InterferenceBlock {
+    pub fn new(num_tasks: usize) -> Self {
+        Self {
+            num_tasks,
+            non_carry_in: vec![0.0; num_tasks * ARRIVAL_BLOCK_SIZE],
+            carry_in_diff: vec![0.0; num_tasks * ARRIVAL_BLOCK_SIZE],
+            column: Vec::with_capacity(num_tasks),
+            largest: Vec::new(),
+        }
+    }
+
+    /// Rows of the i-th task: non carry-in interference and difference between
+    /// carry-in and non carry-in interference, one entry per arrival point.
+    pub fn rows_mut(&mut self, i: usize) -> (&mut [f64], &mut [f64]) {
+        let row = i * ARRIVAL_BLOCK_SIZE .. (i + 1) * ARRIVAL_BLOCK_SIZE;
+
+        (&mut self.non_carry_in[row.clone()], &mut self.carry_in_diff[row])
+    }
+
+    /// Total interference at each of the first `totals.len()` arrival points:
+    /// the sum of the non carry-in interferences plus the `num_carry_in`
+    /// largest carry-in differences.
+    pub fn totals(&mut self, num_carry_in: usize, totals: &mut [f64]) {
+        let num_points = totals.len();
+        debug_assert!(num_points <= ARRIVAL_BLOCK_SIZE);
+
+        totals.fill(0.0);
+        for i in 0..self.num_tasks {
+            let row = &self.non_carry_in[i * ARRIVAL_BLOCK_SIZE ..][..num_points];
+
+            for (total, interference) in totals.iter_mut().zip(row) {
+                *total += interference;
+            }
+        }
+
+        if num_carry_in == 0 {
+            return;
+        } else if num_carry_in >= self.num_tasks {
+            for i in 0..self.num_tasks {
+                let row = &self.carry_in_diff[i * ARRIVAL_BLOCK_SIZE ..][..num_points];
+
+                for (total, diff) in totals.iter_mut().zip(row) {
+                    *total += diff;
+                }
+            }
+
+            return;
+        }
+
+        if num_carry_in > MAX_CARRY_IN_CHAIN {
+            for (j, total) in totals.iter_mut().enumerate() {
+                self.column.clear();
+                self.column.extend(
+                    (0..self.num_tasks).map(|i| self.carry_in_diff[i * ARRIVAL_BLOCK_SIZE + j])
+                );
+
+                self.column.select_nth_unstable_by(num_carry_in - 1, |l, r| r.total_cmp(l));
+                *total += self.column[..num_carry_in].iter().sum::<f64>();
+            }
+
+            return;
+        }
+
+        // Only the largest differences are needed: stream the rows and keep
+        // the num_carry_in largest values of each arrival point, sorted
+        // descending, with a chain of compare-and-swap that runs on all the
+        // arrival points at once.
+        self.largest.clear();
+        self.largest.resize(num_carry_in * ARRIVAL_BLOCK_SIZE, f64::NEG_INFINITY);
+        for i in 0..self.num_tasks {
+            let mut carried = [0.0; ARRIVAL_BLOCK_SIZE];
+            carried.copy_from_slice(&self.carry_in_diff[i * ARRIVAL_BLOCK_SIZE ..][..ARRIVAL_BLOCK_SIZE]);
+
+            for largest in self.largest.chunks_exact_mut(ARRIVAL_BLOCK_SIZE) {
+                for (largest, carried) in largest.iter_mut().zip(carried.iter_mut()) {
+                    let (max, min) =
+                        if *carried > *largest { (*carried, *largest) } else { (*largest, *carried) };
+
+                    *largest = max;
+                    *carried = min;
+                }
+            }
+        }
+
+        for largest in self.largest.chunks_exact(ARRIVAL_BLOCK_SIZE) {
+            for (total, largest) in totals.iter_mut().zip(largest) {
+                *total += largest;
+            }
+        }
+    }
+}
+
+#[test]
+fn largest_differences() {
+    let num_tasks = 40;
+    let mut block = InterferenceBlock::new(num_tasks);
+
+    // Arbitrary values, with ties and negative differences.
+    for i in 0..num_tasks {
+        let (non_carry_in, carry_in_diff) = block.rows_mut(i);
+
+        for j in 0..ARRIVAL_BLOCK_SIZE {
+            non_carry_in[j] = ((i * 7 + j) % 5) as f64;
+            carry_in_diff[j] = ((i * 13 + j * 29) % 17) as f64 - 3.0;
+        }
+    }
+
+    for num_carry_in in [0, 1, 7, 16, 17, 39, 40, 64] {
+        let mut totals = [0.0; ARRIVAL_BLOCK_SIZE - 3];
+        block.totals(num_carry_in, &mut totals);
+
+        for (j, total) in totals.iter().enumerate() {
+            let mut diffs: Vec<_> = (0..num_tasks)
+                .map(|i| block.carry_in_diff[i * ARRIVAL_BLOCK_SIZE + j])
+                .collect();
+            diffs.sort_by(|l, r| r.total_cmp(l));
+
+            let expected: f64 =
+                (0..num_tasks).map(|i| block.non_carry_in[i * ARRIVAL_BLOCK_SIZE + j]).sum::<f64>()
+                + diffs.iter().take(num_carry_in).sum::<f64>();
+
+            assert_eq!(*total, expected);
+        }
+    }
+}
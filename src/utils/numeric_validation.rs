@@ -0,0 +1,103 @@
+//! Numeric self-checks of schedulability tests.
+//!
+//! The tests evaluate their inequalities in floating point arithmetic, which
+//! may flip a verdict when the two sides are very close. In validation mode,
+//! the key inequalities are re-evaluated with a slower, careful implementation
+//! (compensated summation, exact integer arithmetic where possible), and a
+//! warning is reported whenever the verdict of the fast path differs from the
+//! careful one or depends on values within the floating point tolerance.
+
+use crate::prelude::*;
+
+/// Relative tolerance under which the two sides of an inequality are
+/// considered indistinguishable.
+pub const VALIDATION_TOLERANCE: f64 = 1e-9;
+
+/// An inequality whose verdict may not be trusted.
+#[derive(Debug, Clone)]
+pub struct NumericWarning {
+    pub inequality: String,
+    /// Left-hand side as computed by the fast path.
+    pub fast_lhs: f64,
+    /// Left-hand side as computed by the careful implementation.
+    pub careful_lhs: f64,
+    pub rhs: f64,
+}
+
+impl std::fmt::Display for NumericWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: fast {:e}, careful {:e}, bound {:e}",
+            self.inequality, self.fast_lhs, self.careful_lhs, self.rhs)
+    }
+}
+
+/// Schedulability tests which can re-check their verdict with careful numerics.
+pub trait SchedValidation {
+    /// Re-evaluate the key inequalities of the test on the given taskset,
+    /// returning the ones whose verdict may not be trusted.
+    fn validate(&self, taskset: &[RTTask]) -> Vec<NumericWarning>;
+}
+
+/// Kahan-Babuška compensated summation.
+pub fn compensated_sum<I>(values: I) -> f64
+    where
+        I: IntoIterator<Item = f64>,
+{
+    let mut sum = 0.0;
+    let mut compensation = 0.0;
+
+    for value in values {
+        let total = sum + value;
+
+        if f64::abs(sum) >= f64::abs(value) {
+            compensation += (sum - total) + value;
+        } else {
+            compensation += (value - total) + sum;
+        }
+
+        sum = total;
+    }
+
+    sum + compensation
+}
+
+/// Check the inequality *lhs <= rhs*, warning when the fast and careful
+/// verdicts differ or when the careful left-hand side is within the tolerance
+/// of the bound.
+pub fn check_inequality(inequality: &str, fast_lhs: f64, careful_lhs: f64, rhs: f64) -> Option<NumericWarning> {
+    let tolerance = VALIDATION_TOLERANCE * f64::max(f64::abs(rhs), 1.0);
+
+    if (fast_lhs <= rhs) != (careful_lhs <= rhs) || f64::abs(careful_lhs - rhs) <= tolerance {
+        Some(NumericWarning {
+            inequality: inequality.to_owned(),
+            fast_lhs,
+            careful_lhs,
+            rhs,
+        })
+    } else {
+        None
+    }
+}
+
+/// The time in integer nanoseconds, if exactly representable.
+pub fn exact_nanos(time: Time) -> Option<u64> {
+    let nanos = time.as_nanos();
+
+    (nanos >= 0.0 && nanos.fract() == 0.0 && nanos < u64::MAX as f64)
+        .then_some(nanos as u64)
+}
+
+#[test]
+fn compensated_summation() {
+    let values = std::iter::once(1.0).chain(std::iter::repeat_n(1e-16, 10_000));
+
+    assert_eq!(values.clone().sum::<f64>(), 1.0);
+    assert!(f64::abs(compensated_sum(values) - (1.0 + 1e-12)) < 1e-15);
+}
+
+#[test]
+fn inequality_warnings() {
+    assert!(check_inequality("x <= 1", 0.5, 0.5, 1.0).is_none());
+    assert!(check_inequality("x <= 1", 1.0, 1.0 + 1e-12, 1.0).is_some());
+    assert!(check_inequality("x <= 1", 0.99, 1.01, 1.0).is_some());
+}
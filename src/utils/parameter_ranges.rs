@@ -0,0 +1,209 @@
+//! Analysis of partially specified tasksets, whose parameters are known to
+//! lie in ranges, e.g. in the early design phases, when the WCETs are
+//! estimated and the periods are not settled yet.
+//!
+//! A test is sustainable if every taskset it deems schedulable stays
+//! schedulable with smaller WCETs, longer periods and longer deadlines \[1\].
+//! For a sustainable test, the worst case of the ranges is the taskset with the
+//! largest WCETs and the shortest periods and deadlines, and the best case the
+//! one with the smallest WCETs and the longest periods and deadlines: if the
+//! test deems the worst case schedulable, every taskset in the ranges is
+//! schedulable, while if an exact test deems the best case non schedulable,
+//! none of them is. The tests of the sporadic task model in this crate which
+//! do not depend on the order of the periods, e.g. the uniprocessor response
+//! time and processor demand analyses, are sustainable \[1\].
+//!
+//! Without sustainability, only the schedulable corners are conclusive, as
+//! witnesses of some schedulable tasksets in the ranges.
+//!
+//! ---
+//! #### References:
+//! 1. S. Baruah and A. Burns, “Sustainable Scheduling Analysis,” in 2006 27th
+//!    IEEE International Real-Time Systems Symposium (RTSS'06), Dec. 2006,
+//!    pp. 159–168. doi: 10.1109/RTSS.2006.47.
+
+use crate::prelude::*;
+
+/// Closed range of a task parameter.
+#[derive(Debug, Clone, Copy)]
+#[derive(PartialEq)]
+pub struct ParameterRange {
+    pub min: Time,
+    pub max: Time,
+}
+
+impl ParameterRange {
+    pub fn exactly(value: Time) -> Self {
+        Self { min: value, max: value }
+    }
+
+    pub fn is_exact(&self) -> bool {
+        self.min == self.max
+    }
+}
+
+/// Task whose parameters lie in ranges, refer to the [module](`self`) level
+/// documentation.
+#[derive(Debug, Clone)]
+#[derive(PartialEq)]
+pub struct RTTaskRange {
+    pub wcet: ParameterRange,
+    pub deadline: ParameterRange,
+    pub period: ParameterRange,
+}
+
+impl RTTaskRange {
+    /// Task with implicit deadlines, whose deadline ranges over its period.
+    pub fn implicit(wcet: ParameterRange, period: ParameterRange) -> Self {
+        Self { wcet, deadline: period, period }
+    }
+
+    /// Task with the largest WCET and the shortest deadline and period.
+    pub fn worst_case(&self) -> RTTask {
+        RTTask { wcet: self.wcet.max, deadline: self.deadline.min, period: self.period.min }
+    }
+
+    /// Task with the smallest WCET and the longest deadline and period.
+    pub fn best_case(&self) -> RTTask {
+        RTTask { wcet: self.wcet.min, deadline: self.deadline.max, period: self.period.max }
+    }
+
+    pub fn is_exact(&self) -> bool {
+        self.wcet.is_exact() && self.deadline.is_exact() && self.period.is_exact()
+    }
+}
+
+impl From<&RTTask> for RTTaskRange {
+    fn from(task: &RTTask) -> Self {
+        Self {
+            wcet: ParameterRange::exactly(task.wcet),
+            deadline: ParameterRange::exactly(task.deadline),
+            period: ParameterRange::exactly(task.period),
+        }
+    }
+}
+
+/// Schedulability of the tasksets in the ranges, with the results of the test.
+#[derive(Debug, Clone)]
+pub enum RangeOutcome<T> {
+    /// Every taskset in the ranges is schedulable. The results are the ones of
+    /// the worst case, e.g. its response times, which bound the ones of every
+    /// taskset in the ranges.
+    AllSchedulable(T),
+    /// Some tasksets in the ranges are schedulable, e.g. the given one, with
+    /// its results, and others may not be.
+    SomeSchedulable(Vec<RTTask>, T),
+    /// No taskset in the ranges is schedulable.
+    NoneSchedulable,
+    /// The test cannot tell.
+    Inconclusive,
+}
+
+/// Run the given analysis on the worst and best cases of the parameter ranges.
+///
+/// Refer to the [module](`self`) level documentation.
+pub struct WithParameterRanges<A> {
+    pub analysis: A,
+    /// Whether the analysis is sustainable with respect to smaller WCETs and
+    /// longer periods and deadlines.
+    pub sustainable: bool,
+    /// Whether the analysis is necessary and sufficient.
+    pub exact: bool,
+}
+
+impl<T, A> SchedAnalysis<RangeOutcome<T>, &[RTTaskRange]> for WithParameterRanges<A>
+    where
+        A: for<'a> SchedAnalysis<T, &'a [RTTask]>,
+{
+    fn analyzer_name(&self) -> &str { self.analysis.analyzer_name() }
+
+    fn check_preconditions(&self, taskset: &&[RTTaskRange]) -> Result<(), SchedError> {
+        for (i, task) in taskset.iter().enumerate() {
+            let ranges = [task.wcet, task.deadline, task.period];
+
+            if ranges.iter().any(|range| range.min > range.max) {
+                return Err(SchedError::Precondition(Some(anyhow::format_err!(
+                    "the parameter ranges of task {i} must have their minimum within their maximum."))));
+            } else if task.wcet.min < Time::zero() || task.period.min <= Time::zero() || task.deadline.min <= Time::zero() {
+                return Err(SchedError::Precondition(Some(anyhow::format_err!(
+                    "the parameters of task {i} must be positive."))));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn run_test(&self, taskset: &[RTTaskRange]) -> Result<RangeOutcome<T>, SchedError> {
+        let worst_case: Vec<_> = taskset.iter().map(RTTaskRange::worst_case).collect();
+        let best_case: Vec<_> = taskset.iter().map(RTTaskRange::best_case).collect();
+
+        // A single taskset, whose verdict needs no sustainability.
+        let is_single = taskset.iter().all(RTTaskRange::is_exact);
+
+        let worst_err =
+            match self.analysis.is_schedulable(&worst_case) {
+                Ok(result) if self.sustainable || is_single => return Ok(RangeOutcome::AllSchedulable(result)),
+                Ok(result) => return Ok(RangeOutcome::SomeSchedulable(worst_case, result)),
+                Err(err) => err,
+            };
+
+        let best_result = if is_single { Err(worst_err) } else { self.analysis.is_schedulable(&best_case) };
+        let rules_out = self.exact && (self.sustainable || is_single);
+
+        Ok(match best_result {
+            Ok(result) =>
+                RangeOutcome::SomeSchedulable(best_case, result),
+            Err(err) if rules_out && matches!(err.downcast_ref::<SchedError>(), Some(SchedError::NonSchedulable(_))) =>
+                RangeOutcome::NoneSchedulable,
+            Err(_) =>
+                RangeOutcome::Inconclusive,
+        })
+    }
+}
+
+impl<A: SchedProcessors> SchedProcessors for WithParameterRanges<A> {
+    fn num_processors(&self) -> u64 { self.analysis.num_processors() }
+}
+
+impl<A: SchedPlatform> SchedPlatform for WithParameterRanges<A> {
+    fn supports(&self, platform: &Platform) -> bool { self.analysis.supports(platform) }
+}
+
+#[test]
+fn parameter_ranges() {
+    use crate::algorithms::full_preemption::uniprocessor::fixed_priority::{rta86, rate_monotonic73};
+
+    let range = |min: u64, max: u64| ParameterRange { min: Time::nanos(min as f64), max: Time::nanos(max as f64) };
+    let taskset = |wcet_1| [
+        RTTaskRange::implicit(range(2, 4), range(10, 10)),
+        RTTaskRange { wcet: wcet_1, deadline: range(12, 16), period: range(20, 20) },
+    ];
+    let exact = WithParameterRanges { analysis: rta86::Analysis, sustainable: true, exact: true };
+
+    // Worst case: R_1 = 4 + 4 = 8 <= 12, which bounds the response times.
+    assert!(matches!(exact.is_schedulable(&taskset(range(1, 4))[..]).unwrap(),
+        RangeOutcome::AllSchedulable(response_times) if response_times == vec![Time::nanos(4.0), Time::nanos(8.0)]));
+
+    // Worst case: R_1 = 8 + 2 * 4 = 16 > 12, best case: R_1 = 6 + 2 = 8.
+    let RangeOutcome::SomeSchedulable(witness, _) = exact.is_schedulable(&taskset(range(6, 8))[..]).unwrap()
+        else { panic!("expected some schedulable tasksets"); };
+    assert_eq!((witness[1].wcet, witness[1].deadline), (Time::nanos(6.0), Time::nanos(16.0)));
+
+    // Best case: R_1 = 15 + 2 * 2 = 19 > 16.
+    assert!(matches!(exact.is_schedulable(&taskset(range(15, 16))[..]).unwrap(), RangeOutcome::NoneSchedulable));
+
+    // A sufficient test cannot rule out any taskset, nor can a test which is
+    // not sustainable generalize its verdicts.
+    let sufficient = WithParameterRanges { analysis: rate_monotonic73::Analysis, sustainable: true, exact: false };
+    assert!(matches!(sufficient.is_schedulable(&taskset(range(15, 16))[..]).unwrap(), RangeOutcome::Inconclusive));
+
+    let unsustainable = WithParameterRanges { analysis: rta86::Analysis, sustainable: false, exact: true };
+    assert!(matches!(unsustainable.is_schedulable(&taskset(range(1, 4))[..]).unwrap(), RangeOutcome::SomeSchedulable(..)));
+    assert!(matches!(unsustainable.is_schedulable(&taskset(range(15, 16))[..]).unwrap(), RangeOutcome::Inconclusive));
+
+    // Exact parameters are a single taskset.
+    let single: Vec<_> = [RTTask::new_ns(2, 10, 10), RTTask::new_ns(9, 12, 20)].iter().map(RTTaskRange::from).collect();
+    assert!(matches!(unsustainable.is_schedulable(&single[..]).unwrap(), RangeOutcome::NoneSchedulable));
+
+    assert!(exact.is_schedulable(&taskset(range(4, 1))[..]).is_err());
+}
@@ -0,0 +1,171 @@
+//! Priority assignments of fixed priority tasksets.
+//!
+//! The fixed priority analyses take the tasks sorted by priority, highest
+//! first. A [`PriorityOrder`] sorts the taskset by the given assignment, Rate
+//! Monotonic, Deadline Monotonic or explicit priorities, and [`Prioritized`]
+//! runs any of these analyses on the sorted taskset, reporting the per-task
+//! results in the original order of the tasks.
+//!
+//! The priority order of a fixed priority [`Platform`] applies to the fixed
+//! priority tests of the [registry](`crate::algorithms::registry`), i.e. the
+//! ones whose ID has the `fp` scheduling component, e.g. `up.fp.rta86`, so
+//! that the tasksets need not be sorted by hand. The other tests, e.g.
+//! `smp.gwc.bcl09`, get the tasks in their given order.
+
+use crate::prelude::*;
+
+/// Priority assignment of the tasks, refer to the [module](`self`) level
+/// documentation. Tasks of equal priority keep their given order.
+#[derive(Debug, Clone, Default)]
+#[derive(PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum PriorityOrder {
+    /// The given order of the tasks, highest priority first.
+    #[default]
+    Given,
+    /// Shorter periods first.
+    RateMonotonic,
+    /// Shorter deadlines first.
+    DeadlineMonotonic,
+    /// Explicit priority of each task, higher values are higher priorities, as
+    /// in POSIX.
+    Custom(Vec<u64>),
+}
+
+impl PriorityOrder {
+    /// Check that the priority of every task is given, if explicit.
+    pub fn check_preconditions(&self, taskset: &[RTTask]) -> Result<(), SchedError> {
+        match self {
+            PriorityOrder::Custom(priorities) if priorities.len() != taskset.len() =>
+                Err(SchedError::Precondition(Some(
+                    anyhow::format_err!("the priority of every task must be given.")))),
+            _ => Ok(()),
+        }
+    }
+
+    /// Indices of the tasks, from the highest to the lowest priority. Fails if
+    /// the explicit priorities do not match the tasks.
+    pub fn order(&self, taskset: &[RTTask]) -> Result<Vec<usize>, SchedError> {
+        self.check_preconditions(taskset)?;
+
+        let mut order: Vec<usize> = (0..taskset.len()).collect();
+
+        match self {
+            PriorityOrder::Given => {},
+            PriorityOrder::RateMonotonic => order.sort_by_key(|&i| taskset[i].period),
+            PriorityOrder::DeadlineMonotonic => order.sort_by_key(|&i| taskset[i].deadline),
+            PriorityOrder::Custom(priorities) => order.sort_by_key(|&i| std::cmp::Reverse(priorities[i])),
+        }
+
+        Ok(order)
+    }
+
+    /// Tasks sorted by priority, highest first.
+    pub fn sort_taskset(&self, taskset: &[RTTask]) -> Result<Vec<RTTask>, SchedError> {
+        Ok(self.order(taskset)?.into_iter()
+            .map(|i| taskset[i].clone())
+            .collect())
+    }
+}
+
+/// Results of an analysis which can be reported in the original order of the
+/// tasks, given the indices of the sorted tasks.
+pub trait PrioritizedResult {
+    fn restore(self, order: &[usize]) -> Self;
+}
+
+impl PrioritizedResult for () {
+    fn restore(self, _: &[usize]) -> Self { }
+}
+
+impl PrioritizedResult for Vec<Time> {
+    fn restore(self, order: &[usize]) -> Self {
+        let mut restored = vec![Time::zero(); self.len()];
+
+        for (&i, time) in order.iter().zip(self) {
+            restored[i] = time;
+        }
+
+        restored
+    }
+}
+
+/// Run the given fixed priority analysis on the taskset sorted by the
+/// [`PriorityOrder`].
+///
+/// Refer to the [module](`self`) level documentation.
+///
+/// Only the tasks are sorted, and the per-task results restored, while the
+/// parameters of the wrapped analysis are passed as given. Thus only analyses
+/// without per-task parameters may be wrapped: e.g. the warm-started analyses
+/// only without initial response times, which would otherwise be matched to
+/// the wrong tasks.
+pub struct Prioritized<A> {
+    pub order: PriorityOrder,
+    pub analysis: A,
+}
+
+impl<T, A> SchedAnalysis<T, &[RTTask]> for Prioritized<A>
+    where
+        A: for<'a> SchedAnalysis<T, &'a [RTTask]>,
+        T: PrioritizedResult,
+{
+    fn analyzer_name(&self) -> &str { self.analysis.analyzer_name() }
+
+    fn check_preconditions(&self, taskset: &&[RTTask]) -> Result<(), SchedError> {
+        self.analysis.check_preconditions(&self.order.sort_taskset(taskset)?.as_slice())
+    }
+
+    fn run_test(&self, taskset: &[RTTask]) -> Result<T, SchedError> {
+        let order = self.order.order(taskset)?;
+        let sorted: Vec<_> = order.iter().map(|&i| taskset[i].clone()).collect();
+
+        self.analysis.run_test(&sorted)
+            .map(|result| result.restore(&order))
+    }
+}
+
+impl<A: SchedProcessors> SchedProcessors for Prioritized<A> {
+    fn num_processors(&self) -> u64 { self.analysis.num_processors() }
+}
+
+impl<A: SchedPlatform> SchedPlatform for Prioritized<A> {
+    fn supports(&self, platform: &Platform) -> bool { self.analysis.supports(platform) }
+}
+
+#[test]
+fn priority_orders() {
+    use crate::algorithms::full_preemption::uniprocessor::fixed_priority::{rate_monotonic73, rta86};
+
+    let at = |t: u64| Time::nanos(t as f64);
+
+    let taskset = [
+        RTTask::new_ns(2, 10, 10),
+        RTTask::new_ns(1, 4, 4),
+        RTTask::new_ns(1, 5, 8),
+    ];
+
+    assert_eq!(PriorityOrder::RateMonotonic.order(&taskset).unwrap(), vec![1, 2, 0]);
+    assert_eq!(PriorityOrder::DeadlineMonotonic.order(&taskset).unwrap(), vec![1, 2, 0]);
+    assert_eq!(PriorityOrder::Custom(vec![3, 1, 2]).order(&taskset).unwrap(), vec![0, 2, 1]);
+    assert!(PriorityOrder::Custom(vec![3, 1]).order(&taskset).is_err());
+
+    // The Rate Monotonic test requires the tasks sorted by period.
+    assert!(rate_monotonic73::Analysis.is_schedulable(&taskset).is_err());
+    let analysis = Prioritized { order: PriorityOrder::RateMonotonic, analysis: rate_monotonic73::Analysis };
+    assert!(analysis.is_schedulable(&taskset).is_ok());
+
+    // The response times are reported in the original order of the tasks.
+    let analysis = Prioritized { order: PriorityOrder::DeadlineMonotonic, analysis: rta86::Analysis };
+    assert_eq!(analysis.is_schedulable(&taskset).unwrap(), vec![at(4), at(1), at(2)]);
+
+    let analysis = Prioritized { order: PriorityOrder::Custom(vec![3, 1, 2]), analysis: rta86::Analysis };
+    assert_eq!(analysis.is_schedulable(&taskset).unwrap(), vec![at(2), at(4), at(3)]);
+
+    // Too few priorities fail the test, even when run without checking its
+    // preconditions.
+    let analysis = Prioritized { order: PriorityOrder::Custom(vec![3, 1]), analysis: rta86::Analysis };
+    assert!(analysis.is_schedulable(&taskset).is_err());
+    assert!(analysis.run_test(&taskset).is_err());
+}
@@ -0,0 +1,125 @@
+//! Explicit time quantization of tasksets.
+//!
+//! Many analyses evaluate their conditions only on integer time instants (e.g.
+//! the arrival point scans of the global tests). Rather than letting each
+//! analysis floor or ceil the task parameters, the taskset can be discretized
+//! beforehand to a given time quantum, rounding each parameter in the
+//! direction which keeps the analysis sound:
+//! - WCETs are rounded **up**, jobs never need more than the quantized demand;
+//! - Deadlines are rounded **down**, jobs complete before the actual deadline;
+//! - Periods are rounded **down**, jobs never arrive more often than assumed.
+//!
+//! A taskset deemed schedulable after quantization is therefore schedulable
+//! also before it.
+
+use crate::prelude::*;
+
+/// Time quantization policy, refer to the [module](`self`) level documentation.
+#[derive(Debug, Clone)]
+pub struct Quantization {
+    pub quantum: Time,
+}
+
+impl Quantization {
+    pub fn nanos() -> Self {
+        Self { quantum: Time::one() }
+    }
+
+    pub fn micros() -> Self {
+        Self { quantum: Time::micros(1.0) }
+    }
+
+    /// Smallest multiple of the quantum not less than the given time.
+    pub fn round_up(&self, time: Time) -> Time {
+        (time / self.quantum).ceil() * self.quantum
+    }
+
+    /// Largest multiple of the quantum not greater than the given time.
+    pub fn round_down(&self, time: Time) -> Time {
+        (time / self.quantum).floor() * self.quantum
+    }
+
+    pub fn quantize_task(&self, task: &RTTask) -> RTTask {
+        RTTask {
+            wcet: self.round_up(task.wcet),
+            deadline: self.round_down(task.deadline),
+            period: self.round_down(task.period),
+        }
+    }
+
+    pub fn quantize_taskset(&self, taskset: &[RTTask]) -> Vec<RTTask> {
+        taskset.iter()
+            .map(|task| self.quantize_task(task))
+            .collect()
+    }
+
+    pub fn check_preconditions(&self, taskset: &[RTTask]) -> Result<(), SchedError> {
+        if self.quantum <= Time::zero() {
+            Err(SchedError::Precondition(Some(
+                anyhow::format_err!("time quantum must be positive."))))
+        } else if taskset.iter().any(|task| task.deadline < self.quantum || task.period < self.quantum) {
+            Err(SchedError::Precondition(Some(
+                anyhow::format_err!("task deadlines and periods must not be shorter than the time quantum."))))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Run the given analysis on the taskset discretized by the [`Quantization`].
+///
+/// Refer to the [module](`self`) level documentation.
+pub struct Quantized<A> {
+    pub quantization: Quantization,
+    pub analysis: A,
+}
+
+impl<T, A> SchedAnalysis<T, &[RTTask]> for Quantized<A>
+    where
+        A: for<'a> SchedAnalysis<T, &'a [RTTask]>,
+{
+    fn analyzer_name(&self) -> &str { self.analysis.analyzer_name() }
+
+    fn check_preconditions(&self, taskset: &&[RTTask]) -> Result<(), SchedError> {
+        self.quantization.check_preconditions(taskset)?;
+
+        let taskset = self.quantization.quantize_taskset(taskset);
+        self.analysis.check_preconditions(&taskset.as_slice())
+    }
+
+    fn run_test(&self, taskset: &[RTTask]) -> Result<T, SchedError> {
+        let taskset = self.quantization.quantize_taskset(taskset);
+        self.analysis.run_test(&taskset)
+    }
+}
+
+#[test]
+fn rounding_directions() {
+    let quantization = Quantization { quantum: Time::nanos(10.0) };
+
+    let task = quantization.quantize_task(&RTTask {
+        wcet: Time::nanos(12.5),
+        deadline: Time::nanos(47.0),
+        period: Time::nanos(50.0),
+    });
+
+    assert_eq!(task.wcet, Time::nanos(20.0));
+    assert_eq!(task.deadline, Time::nanos(40.0));
+    assert_eq!(task.period, Time::nanos(50.0));
+}
+
+#[test]
+fn quantized_is_sound() {
+    use crate::algorithms::full_preemption::uniprocessor::earliest_deadline_first::edf73;
+
+    let taskset = [
+        RTTask { wcet: Time::nanos(45.0), deadline: Time::nanos(100.0), period: Time::nanos(100.0) },
+        RTTask { wcet: Time::nanos(51.0), deadline: Time::nanos(100.0), period: Time::nanos(100.0) },
+    ];
+
+    assert!(edf73::Analysis.is_schedulable(&taskset).is_ok());
+    assert!(Quantized { quantization: Quantization::nanos(), analysis: edf73::Analysis }
+        .is_schedulable(&taskset).is_ok());
+    assert!(Quantized { quantization: Quantization { quantum: Time::nanos(10.0) }, analysis: edf73::Analysis }
+        .is_schedulable(&taskset).is_err());
+}
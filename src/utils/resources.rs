@@ -0,0 +1,84 @@
+//! Shared resources and the critical sections of the tasks.
+//!
+//! Besides the processors, the tasks share resources accessed in mutual
+//! exclusion, e.g. data structures protected by semaphores. Each task accesses
+//! them in critical sections, described by the resource and the longest time
+//! for which a job of the task holds it. [`CriticalSections`] annotate each
+//! task with its critical sections, which are not nested, and which the
+//! resource access protocols use to bound the time for which a job is blocked
+//! by lower priority ones, e.g. the [Priority Ceiling
+//! Protocol](crate::algorithms::full_preemption::uniprocessor::fixed_priority::pcp90)
+//! and the [Stack Resource
+//! Policy](crate::algorithms::full_preemption::uniprocessor::earliest_deadline_first::srp91).
+//!
+//! The protocols assign each resource a ceiling: the highest priority, or
+//! preemption level, of the tasks which use it. With the tasks sorted by
+//! priority, highest first, the ceiling is the index of the first task using
+//! the resource, refer to [`CriticalSections::ceiling`].
+
+use crate::prelude::*;
+
+/// Critical section of a task on a shared resource.
+#[derive(Debug, Clone)]
+#[derive(PartialEq)]
+pub struct CriticalSection {
+    /// ID of the resource, shared by the critical sections of the tasks
+    /// accessing the same resource.
+    pub resource: usize,
+    /// Longest time for which a job holds the resource.
+    pub duration: Time,
+}
+
+/// Critical sections of each task, refer to the [module](`self`) level
+/// documentation.
+#[derive(Debug, Clone)]
+#[derive(PartialEq)]
+pub struct CriticalSections {
+    pub sections: Vec<Vec<CriticalSection>>,
+}
+
+impl CriticalSections {
+    /// No critical section for any of the given number of tasks.
+    pub fn none(num_tasks: usize) -> Self {
+        Self { sections: vec![Vec::new(); num_tasks] }
+    }
+
+    /// Check that the critical sections of every task are given, and that
+    /// their durations are non-negative and within the task's WCET.
+    pub fn check_preconditions(&self, taskset: &[RTTask]) -> Result<(), SchedError> {
+        if self.sections.len() != taskset.len() {
+            return Err(SchedError::Precondition(Some(
+                anyhow::format_err!("the critical sections of every task must be given."))));
+        }
+
+        for (i, (task, sections)) in taskset.iter().zip(&self.sections).enumerate() {
+            if sections.iter().any(|section| section.duration < Time::zero() || section.duration > task.wcet) {
+                return Err(SchedError::Precondition(Some(anyhow::format_err!(
+                    "the critical sections of task {i} must last between zero and its WCET."))));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Whether the task accesses the resource.
+    pub fn uses(&self, task: usize, resource: usize) -> bool {
+        self.sections[task].iter().any(|section| section.resource == resource)
+    }
+
+    /// Longest critical section of the task on the resource, if it accesses
+    /// it.
+    pub fn longest(&self, task: usize, resource: usize) -> Option<Time> {
+        self.sections[task].iter()
+            .filter(|section| section.resource == resource)
+            .map(|section| section.duration)
+            .max()
+    }
+
+    /// Ceiling of the resource, i.e. the index of the first task which uses
+    /// it, if any. The tasks are sorted by priority, or preemption level,
+    /// highest first.
+    pub fn ceiling(&self, resource: usize) -> Option<usize> {
+        (0..self.sections.len()).find(|&task| self.uses(task, resource))
+    }
+}
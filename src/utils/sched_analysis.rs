@@ -19,4 +19,119 @@ pub trait SchedAnalysis<T, Taskset> {
         self.run_test(taskset)
             .with_context(|| format!("Schedulability test error for \"{}\"", self.analyzer_name()))
     }
-}
\ No newline at end of file
+
+    /// Check the preconditions and run the schedulability test on each of the
+    /// given tasksets. Analyses may override this to share the precomputations
+    /// which do not depend on the individual tasksets.
+    fn run_batch(&self, tasksets: &[Taskset]) -> Vec<anyhow::Result<T>>
+        where
+            Taskset: Clone,
+    {
+        tasksets.iter()
+            .map(|taskset| self.is_schedulable(taskset.clone()))
+            .collect()
+    }
+}
+
+impl<T, Taskset, A> SchedAnalysis<T, Taskset> for Box<A>
+    where
+        A: SchedAnalysis<T, Taskset> + ?Sized,
+{
+    fn analyzer_name(&self) -> &str { (**self).analyzer_name() }
+
+    fn check_preconditions(&self, taskset: &Taskset) -> Result<(), SchedError> {
+        (**self).check_preconditions(taskset)
+    }
+
+    fn run_test(&self, taskset: Taskset) -> Result<T, SchedError> {
+        (**self).run_test(taskset)
+    }
+
+    fn run_batch(&self, tasksets: &[Taskset]) -> Vec<anyhow::Result<T>>
+        where
+            Taskset: Clone,
+    {
+        (**self).run_batch(tasksets)
+    }
+}
+
+/// Analyses for a platform of identical processors.
+pub trait SchedProcessors {
+    /// Number of processors the taskset is analyzed on.
+    fn num_processors(&self) -> u64;
+}
+
+/// Analyses declaring the platforms they apply to.
+pub trait SchedPlatform {
+    /// Whether the analysis applies to the given [`Platform`].
+    fn supports(&self, platform: &Platform) -> bool;
+}
+
+impl<A: SchedPlatform + ?Sized> SchedPlatform for Box<A> {
+    fn supports(&self, platform: &Platform) -> bool { (**self).supports(platform) }
+}
+
+#[test]
+fn batch_matches_single_runs() {
+    use crate::algorithms::full_preemption::uniprocessor::earliest_deadline_first::edf73;
+
+    let tasksets: [&[RTTask]; 3] = [
+        &[RTTask::new_ns(50, 100, 100), RTTask::new_ns(50, 100, 100)],
+        &[RTTask::new_ns(60, 100, 100), RTTask::new_ns(50, 100, 100)],
+        &[RTTask::new_ns(10, 50, 100)],
+    ];
+
+    let results = edf73::Analysis.run_batch(&tasksets);
+    assert_eq!(results.len(), tasksets.len());
+
+    for (taskset, result) in tasksets.iter().zip(results) {
+        assert_eq!(result.is_ok(), edf73::Analysis.is_schedulable(taskset).is_ok());
+    }
+}
+
+#[test]
+fn streaming_matches_analysis() {
+    use crate::algorithms::full_preemption::uniprocessor::earliest_deadline_first::edf73;
+    use crate::algorithms::full_preemption::uniprocessor::fixed_priority::{hyperbolic01, rate_monotonic73};
+
+    type Test<'a> = &'a dyn Fn(&[RTTask]) -> anyhow::Result<()>;
+
+    fn check(streaming: Test, analysis: Test, tasksets: &[&[RTTask]]) {
+        let to_error = |err: anyhow::Error| err.downcast_ref::<SchedError>().map(SchedError::to_string);
+
+        for taskset in tasksets {
+            assert_eq!(streaming(taskset).map_err(to_error), analysis(taskset).map_err(to_error));
+        }
+    }
+
+    fn stream(taskset: &[RTTask]) -> impl Iterator<Item = anyhow::Result<RTTask>> + '_ {
+        taskset.iter().map(|task| Ok(RTTask { ..*task }))
+    }
+
+    // Schedulable, non-schedulable and precondition failures.
+    check(
+        &|taskset| edf73::AnalysisStreaming.is_schedulable(stream(taskset)),
+        &|taskset| edf73::Analysis.is_schedulable(taskset),
+        &[
+            &[RTTask::new_ns(50, 100, 100), RTTask::new_ns(50, 100, 100)],
+            &[RTTask::new_ns(60, 100, 100), RTTask::new_ns(50, 100, 100)],
+            &[RTTask::new_ns(10, 50, 100)],
+        ],
+    );
+
+    let rate_monotonic_tasksets: [&[RTTask]; 3] = [
+        &[RTTask::new_ns(20, 100, 100), RTTask::new_ns(40, 150, 150)],
+        &[RTTask::new_ns(50, 100, 100), RTTask::new_ns(50, 150, 150)],
+        &[RTTask::new_ns(20, 150, 150), RTTask::new_ns(20, 100, 100)],
+    ];
+    check(
+        &|taskset| rate_monotonic73::AnalysisStreaming.is_schedulable(stream(taskset)),
+        &|taskset| rate_monotonic73::Analysis.is_schedulable(taskset),
+        &rate_monotonic_tasksets,
+    );
+    check(
+        &|taskset| hyperbolic01::AnalysisStreaming.is_schedulable(stream(taskset)),
+        &|taskset| hyperbolic01::Analysis.is_schedulable(taskset),
+        &rate_monotonic_tasksets,
+    );
+}
@@ -0,0 +1,75 @@
+use crate::prelude::*;
+
+/// Known theoretical bounds of a schedulability test.
+pub trait SchedBounds {
+    /// Processor speedup factor (resource augmentation bound) of the test: any
+    /// taskset feasible on the platform is deemed schedulable by the test on a
+    /// platform whose processors are faster by this factor.
+    fn speedup_factor(&self) -> Option<f64> { None }
+
+    /// Total utilization below which the test deems the taskset schedulable.
+    fn utilization_bound(&self, _taskset: &[RTTask]) -> Option<f64> { None }
+}
+
+/// Search the minimum processor speedup for which the given analysis deems the
+/// taskset schedulable, up to `max_speedup` and with the given precision.
+///
+/// The speedup is applied by scaling down all the WCETs. The analysis must be
+/// sustainable with respect to the WCETs, i.e. a taskset schedulable by the
+/// analysis must stay schedulable if any WCET decreases.
+pub fn minimum_speedup<A, T>(
+    analysis: &A,
+    taskset: &[RTTask],
+    max_speedup: f64,
+    precision: f64,
+) -> Option<f64>
+    where
+        A: for<'a> SchedAnalysis<T, &'a [RTTask]>,
+{
+    let is_schedulable_at = |speedup: f64| {
+        let scaled_taskset: Vec<_> = taskset.iter()
+            .map(|task| RTTask {
+                wcet: task.wcet / speedup,
+                deadline: task.deadline,
+                period: task.period,
+            })
+            .collect();
+
+        analysis.is_schedulable(&scaled_taskset).is_ok()
+    };
+
+    if is_schedulable_at(1.0) {
+        return Some(1.0);
+    } else if !is_schedulable_at(max_speedup) {
+        return None;
+    }
+
+    let (mut low, mut high) = (1.0, max_speedup);
+    while high - low > precision {
+        let mid = low + (high - low) / 2.0;
+
+        if is_schedulable_at(mid) {
+            high = mid;
+        } else {
+            low = mid;
+        }
+    }
+
+    Some(high)
+}
+
+#[test]
+fn edf_minimum_speedup() {
+    use crate::algorithms::full_preemption::uniprocessor::earliest_deadline_first::edf73;
+
+    let taskset = [
+        RTTask::new_ns(50, 100, 100),
+        RTTask::new_ns(100, 100, 100),
+    ];
+
+    let speedup = minimum_speedup(&edf73::Analysis, &taskset, 4.0, 1e-6).unwrap();
+    assert!((speedup - 1.5).abs() <= 1e-6);
+
+    assert_eq!(minimum_speedup(&edf73::Analysis, &taskset[0..1], 4.0, 1e-6), Some(1.0));
+    assert_eq!(minimum_speedup(&edf73::Analysis, &taskset, 1.2, 1e-6), None);
+}
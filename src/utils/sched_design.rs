@@ -1,5 +1,19 @@
 use crate::prelude::*;
 use anyhow::Context as _;
+use std::time::{Duration, Instant};
+
+/// Outcome of a design search, together with statistics of the searched space.
+#[derive(Debug)]
+pub struct DesignResult<Model> {
+    /// The designed model, or the reason why none was found.
+    pub model: anyhow::Result<Model>,
+    /// Number of candidate models evaluated by the schedulability test.
+    pub candidates: u64,
+    /// Failing candidate closest to the schedulability boundary, if any.
+    pub tightest_failing: Option<Model>,
+    /// Total time spent in each dimension of the search, in search order.
+    pub search_time: Vec<(&'static str, Duration)>,
+}
 
 pub trait SchedDesign<Taskset, Model> {
     /// Name of the schedulability analysis.
@@ -19,4 +33,66 @@ pub trait SchedDesign<Taskset, Model> {
         self.run_designer(taskset)
             .with_context(|| format!("Designer error for \"{}\"", self.designer_name()))
     }
-}
\ No newline at end of file
+
+    /// Run the designer, recording the statistics of its search. Designers
+    /// which do not search a space of candidates only report the total time.
+    fn run_designer_with_stats(&self, taskset: Taskset) -> DesignResult<Model> {
+        let start = Instant::now();
+        let model = self.run_designer(taskset);
+
+        DesignResult {
+            model: model.map_err(anyhow::Error::from),
+            candidates: 0,
+            tightest_failing: None,
+            search_time: vec![("total", start.elapsed())],
+        }
+    }
+
+    /// Check if the taskset matches the precondtions and run the designer,
+    /// recording the statistics of its search.
+    fn design_with_stats(&self, taskset: Taskset) -> DesignResult<Model> {
+        if let Err(err) = self.check_preconditions(&taskset) {
+            return DesignResult {
+                model: Err(anyhow::Error::from(err)
+                    .context(format!("Precondition check error for \"{}\"", self.designer_name()))),
+                candidates: 0,
+                tightest_failing: None,
+                search_time: Vec::new(),
+            };
+        }
+
+        let result = self.run_designer_with_stats(taskset);
+        DesignResult {
+            model: result.model
+                .with_context(|| format!("Designer error for \"{}\"", self.designer_name())),
+            ..result
+        }
+    }
+}
+
+/// Designers whose search can be saved to a checkpoint and resumed later.
+pub trait SchedDesignResumable<Taskset, Model, Checkpoint>: SchedDesign<Taskset, Model> {
+    /// Run the designer from the checkpoint of a previous run of the same
+    /// search, calling `on_checkpoint` with the progress as the search goes.
+    fn run_designer_resumable(
+        &self,
+        taskset: Taskset,
+        checkpoint: Checkpoint,
+        on_checkpoint: &mut dyn FnMut(&Checkpoint),
+    ) -> Result<Model, SchedError>;
+
+    /// Check if the taskset matches the precondtions and run the designer from
+    /// the given checkpoint.
+    fn design_resumable(
+        &self,
+        taskset: Taskset,
+        checkpoint: Checkpoint,
+        on_checkpoint: &mut dyn FnMut(&Checkpoint),
+    ) -> anyhow::Result<Model> {
+        self.check_preconditions(&taskset)
+            .with_context(|| format!("Precondition check error for \"{}\"", self.designer_name()))?;
+
+        self.run_designer_resumable(taskset, checkpoint, on_checkpoint)
+            .with_context(|| format!("Designer error for \"{}\"", self.designer_name()))
+    }
+}
@@ -1,6 +1,9 @@
 #[derive(Debug)]
 pub enum SchedError {
     NonSchedulable(Option<anyhow::Error>),
+    /// A sufficient test could not reach a verdict (e.g. unbounded search
+    /// space, truncated search), which does not imply non-schedulability.
+    Inconclusive(Option<anyhow::Error>),
     Precondition(Option<anyhow::Error>),
     Other(anyhow::Error),
 }
@@ -12,6 +15,10 @@ impl std::fmt::Display for SchedError {
                 write!(f, "Non schedulable."),
             Self::NonSchedulable(Some(error)) =>
                 write!(f, "Non schedulable, reason: {}", error),
+            Self::Inconclusive(None) =>
+                write!(f, "Inconclusive."),
+            Self::Inconclusive(Some(error)) =>
+                write!(f, "Inconclusive, reason: {}", error),
             Self::Precondition(None) =>
                 write!(f, "Precondition error."),
             Self::Precondition(Some(error)) =>
@@ -33,6 +40,41 @@ impl SchedError {
         }
     }
 
+    /// Copy of the error, with the reasons flattened to their messages, e.g.
+    /// to report a shared failure on each of a batch of tasksets.
+    pub fn duplicate(&self) -> Self {
+        let flatten = |error: &anyhow::Error| anyhow::format_err!("{error:#}");
+
+        match self {
+            Self::NonSchedulable(error) => Self::NonSchedulable(error.as_ref().map(flatten)),
+            Self::Inconclusive(error) => Self::Inconclusive(error.as_ref().map(flatten)),
+            Self::Precondition(error) => Self::Precondition(error.as_ref().map(flatten)),
+            Self::Other(error) => Self::Other(flatten(error)),
+        }
+    }
+
+    /// Error of the same kind, with the reason prefixed by the given context,
+    /// e.g. the component of a composed analysis which failed.
+    pub fn with_context(self, context: &str) -> Self {
+        let wrap = |error: Option<anyhow::Error>| match error {
+            Some(error) => anyhow::format_err!("{context}: {error:#}"),
+            None => anyhow::format_err!("{context}."),
+        };
+
+        match self {
+            Self::NonSchedulable(error) => Self::NonSchedulable(Some(wrap(error))),
+            Self::Inconclusive(error) => Self::Inconclusive(Some(wrap(error))),
+            Self::Precondition(error) => Self::Precondition(Some(wrap(error))),
+            Self::Other(error) => Self::Other(wrap(Some(error))),
+        }
+    }
+
+    pub fn intractable() -> Self {
+        Self::Inconclusive(Some(
+            anyhow::format_err!("arrival times upperbound tends to infinity, the computation becomes intractable.")
+        ))
+    }
+
     pub fn implicit_deadlines() -> Self {
         Self::Precondition(Some(
             anyhow::format_err!("taskset must have implicit deadlines.")
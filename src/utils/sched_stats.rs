@@ -0,0 +1,363 @@
+//! Runtime and accuracy statistics of schedulability tests.
+//!
+//! Sufficient tests are compared on a corpus of tasksets against a reference
+//! exact test (e.g. Response Time Analysis for uniprocessor fixed priority).
+//! The accuracy of a test is the fraction of the tasksets deemed schedulable by
+//! the reference which the test also accepts: all the tests are scored on the
+//! same tasksets, i.e. the ones the reference could analyze, and a test which
+//! cannot analyze one of them (e.g. unmet preconditions) counts as rejecting
+//! it. Tasksets accepted by a test but rejected by the reference are counted
+//! separately, as they reveal an unsound test (or a wrong reference).
+//!
+//! The statistics also record the peak memory of the runs, when the
+//! allocations are counted, refer to [`instrumentation`](super::instrumentation).
+
+use crate::prelude::*;
+use std::time::{Duration, Instant};
+
+/// Outcome of a single schedulability test run.
+#[derive(Debug, Clone, Copy)]
+#[derive(PartialEq, Eq)]
+pub enum SchedOutcome {
+    Schedulable,
+    NonSchedulable,
+    /// The test could not reach a verdict.
+    Inconclusive,
+    /// The test could not be run (e.g. unmet preconditions).
+    Error,
+}
+
+impl SchedOutcome {
+    pub fn from_result<T>(result: &anyhow::Result<T>) -> Self {
+        match result {
+            Ok(_) => Self::Schedulable,
+            Err(err) => match err.downcast_ref::<SchedError>() {
+                Some(SchedError::NonSchedulable(_)) => Self::NonSchedulable,
+                Some(SchedError::Inconclusive(_)) => Self::Inconclusive,
+                _ => Self::Error,
+            },
+        }
+    }
+
+    /// Process exit code for the outcome, so that scripts can tell a
+    /// non-schedulable taskset apart from a test which did not conclude.
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            Self::Schedulable => 0,
+            Self::NonSchedulable => 1,
+            Self::Inconclusive => 2,
+            Self::Error => 3,
+        }
+    }
+}
+
+/// Run the given analysis, measuring its wall-clock runtime.
+pub fn timed_is_schedulable<A, T, Taskset>(analysis: &A, taskset: Taskset) -> (anyhow::Result<T>, Duration)
+    where
+        A: SchedAnalysis<T, Taskset> + ?Sized,
+{
+    let start = Instant::now();
+    let result = analysis.is_schedulable(taskset);
+
+    (result, start.elapsed())
+}
+
+/// Run the given analysis on a batch of tasksets, measuring the mean
+/// wall-clock runtime per taskset.
+pub fn timed_run_batch<A, T, Taskset>(analysis: &A, tasksets: &[Taskset]) -> (Vec<anyhow::Result<T>>, Duration)
+    where
+        A: SchedAnalysis<T, Taskset> + ?Sized,
+        Taskset: Clone,
+{
+    let start = Instant::now();
+    let results = analysis.run_batch(tasksets);
+    let runtime = start.elapsed().div_f64(usize::max(tasksets.len(), 1) as f64);
+
+    (results, runtime)
+}
+
+/// Runtime and acceptance statistics of a schedulability test over a corpus of
+/// tasksets.
+#[derive(Debug, Clone)]
+pub struct SchedStats {
+    pub analyzer_name: String,
+    pub schedulable: u64,
+    pub non_schedulable: u64,
+    pub inconclusive: u64,
+    pub errors: u64,
+    /// Total runtime over the tasksets which the test could analyze.
+    pub runtime: Duration,
+    /// Largest peak memory over all the runs, if the allocations are counted.
+    pub peak_bytes: Option<usize>,
+    /// Tasksets deemed schedulable by the reference test.
+    pub reference_schedulable: u64,
+    /// Tasksets deemed schedulable by both the test and the reference.
+    pub accepted: u64,
+    /// Tasksets deemed schedulable by the test, but not by the reference.
+    pub unsound: u64,
+}
+
+impl SchedStats {
+    pub fn new(analyzer_name: &str) -> Self {
+        Self {
+            analyzer_name: analyzer_name.to_owned(),
+            schedulable: 0,
+            non_schedulable: 0,
+            inconclusive: 0,
+            errors: 0,
+            runtime: Duration::ZERO,
+            peak_bytes: None,
+            reference_schedulable: 0,
+            accepted: 0,
+            unsound: 0,
+        }
+    }
+
+    /// Run the given analysis on the taskset and record its outcome.
+    pub fn run<A, T, Taskset>(&mut self, analysis: &A, taskset: Taskset) -> anyhow::Result<T>
+        where
+            A: SchedAnalysis<T, Taskset> + ?Sized,
+    {
+        let (result, measurement) = measured_is_schedulable(analysis, taskset);
+        self.record(SchedOutcome::from_result(&result), measurement.runtime);
+        self.record_memory(measurement.memory);
+
+        result
+    }
+
+    /// Run the given analysis on the taskset and record its outcome, scoring
+    /// it against the outcome of the reference test on the same taskset.
+    pub fn run_with_reference<A, T, Taskset>(
+        &mut self,
+        analysis: &A,
+        taskset: Taskset,
+        reference: SchedOutcome,
+    ) -> anyhow::Result<T>
+        where
+            A: SchedAnalysis<T, Taskset> + ?Sized,
+    {
+        let (result, measurement) = measured_is_schedulable(analysis, taskset);
+        self.record_with_reference(SchedOutcome::from_result(&result), reference, measurement.runtime);
+        self.record_memory(measurement.memory);
+
+        result
+    }
+
+    /// Run the given analysis on a batch of tasksets, with
+    /// [`SchedAnalysis::run_batch`], and record the outcomes. The runtime of
+    /// the batch is split evenly among its tasksets.
+    pub fn run_batch<A, T, Taskset>(&mut self, analysis: &A, tasksets: &[Taskset]) -> Vec<anyhow::Result<T>>
+        where
+            A: SchedAnalysis<T, Taskset> + ?Sized,
+            Taskset: Clone,
+    {
+        let (results, runtime) = self.measured_run_batch(analysis, tasksets);
+
+        for result in results.iter() {
+            self.record(SchedOutcome::from_result(result), runtime);
+        }
+
+        results
+    }
+
+    /// Run the given analysis on a batch of tasksets and record the outcomes,
+    /// scoring each against the outcome of the reference test on the same
+    /// taskset. The runtime of the batch is split evenly among its tasksets.
+    pub fn run_batch_with_reference<A, T, Taskset>(
+        &mut self,
+        analysis: &A,
+        tasksets: &[Taskset],
+        references: &[SchedOutcome],
+    ) -> Vec<anyhow::Result<T>>
+        where
+            A: SchedAnalysis<T, Taskset> + ?Sized,
+            Taskset: Clone,
+    {
+        assert_eq!(tasksets.len(), references.len());
+
+        let (results, runtime) = self.measured_run_batch(analysis, tasksets);
+
+        for (result, &reference) in results.iter().zip(references) {
+            self.record_with_reference(SchedOutcome::from_result(result), reference, runtime);
+        }
+
+        results
+    }
+
+    // Run the batch, recording its peak memory, and return the mean runtime
+    // per taskset.
+    fn measured_run_batch<A, T, Taskset>(&mut self, analysis: &A, tasksets: &[Taskset]) -> (Vec<anyhow::Result<T>>, Duration)
+        where
+            A: SchedAnalysis<T, Taskset> + ?Sized,
+            Taskset: Clone,
+    {
+        let (results, measurement) = measure(|| analysis.run_batch(tasksets));
+        self.record_memory(measurement.memory);
+
+        (results, measurement.runtime.div_f64(usize::max(tasksets.len(), 1) as f64))
+    }
+
+    pub fn record_with_reference(&mut self, outcome: SchedOutcome, reference: SchedOutcome, runtime: Duration) {
+        self.record(outcome, runtime);
+
+        match (reference, outcome) {
+            (SchedOutcome::Schedulable, SchedOutcome::Schedulable) => {
+                self.reference_schedulable += 1;
+                self.accepted += 1;
+            },
+            (SchedOutcome::Schedulable, _) => self.reference_schedulable += 1,
+            (SchedOutcome::NonSchedulable, SchedOutcome::Schedulable) => self.unsound += 1,
+            _ => {},
+        }
+    }
+
+    pub fn record(&mut self, outcome: SchedOutcome, runtime: Duration) {
+        match outcome {
+            SchedOutcome::Schedulable => self.schedulable += 1,
+            SchedOutcome::NonSchedulable => self.non_schedulable += 1,
+            SchedOutcome::Inconclusive => { self.inconclusive += 1; return; },
+            SchedOutcome::Error => { self.errors += 1; return; },
+        }
+
+        self.runtime += runtime;
+    }
+
+    pub fn record_memory(&mut self, memory: Option<MemoryUsage>) {
+        if let Some(memory) = memory {
+            self.peak_bytes = Some(usize::max(self.peak_bytes.unwrap_or(0), memory.peak_bytes));
+        }
+    }
+
+    /// Number of tasksets the test could analyze.
+    pub fn analyzed(&self) -> u64 {
+        self.schedulable + self.non_schedulable
+    }
+
+    pub fn acceptance_ratio(&self) -> f64 {
+        if self.analyzed() == 0 {
+            return 0.0;
+        }
+
+        self.schedulable as f64 / self.analyzed() as f64
+    }
+
+    pub fn mean_runtime(&self) -> Duration {
+        if self.analyzed() == 0 {
+            return Duration::ZERO;
+        }
+
+        self.runtime.div_f64(self.analyzed() as f64)
+    }
+
+    /// Fraction of the tasksets deemed schedulable by the reference test which
+    /// are also accepted by this test, refer to the [module](`self`) level
+    /// documentation.
+    pub fn accuracy(&self) -> f64 {
+        if self.reference_schedulable == 0 {
+            return 0.0;
+        }
+
+        self.accepted as f64 / self.reference_schedulable as f64
+    }
+
+    /// Accuracy per second of mean runtime.
+    pub fn accuracy_per_second(&self) -> f64 {
+        let mean_runtime = self.mean_runtime().as_secs_f64();
+
+        if mean_runtime == 0.0 {
+            return f64::INFINITY;
+        }
+
+        self.accuracy() / mean_runtime
+    }
+}
+
+/// Sort the statistics by decreasing accuracy per second.
+pub fn rank_by_accuracy_per_second(stats: &mut [SchedStats]) {
+    stats.sort_by(|l, r| r.accuracy_per_second().total_cmp(&l.accuracy_per_second()));
+}
+
+#[test]
+fn stats_ratios() {
+    let mut stats = SchedStats::new("test");
+    assert_eq!(stats.mean_runtime(), Duration::ZERO);
+
+    stats.record(SchedOutcome::Schedulable, Duration::from_millis(10));
+    stats.record(SchedOutcome::NonSchedulable, Duration::from_millis(30));
+    stats.record(SchedOutcome::Inconclusive, Duration::from_millis(1000));
+    stats.record(SchedOutcome::Error, Duration::from_millis(1000));
+
+    assert_eq!(stats.analyzed(), 2);
+    assert_eq!(stats.inconclusive, 1);
+    assert_eq!(stats.acceptance_ratio(), 0.5);
+    assert_eq!(stats.mean_runtime(), Duration::from_millis(20));
+
+    stats.record_memory(None);
+    assert_eq!(stats.peak_bytes, None);
+    stats.record_memory(Some(MemoryUsage { peak_bytes: 100, allocations: 2 }));
+    stats.record_memory(Some(MemoryUsage { peak_bytes: 50, allocations: 1 }));
+    assert_eq!(stats.peak_bytes, Some(100));
+}
+
+#[test]
+fn stats_against_reference() {
+    use SchedOutcome::*;
+
+    let mut stats = SchedStats::new("test");
+    stats.record_with_reference(Schedulable, Schedulable, Duration::from_millis(10));
+    stats.record_with_reference(NonSchedulable, Schedulable, Duration::from_millis(10));
+    // Unmet preconditions count as a rejection.
+    stats.record_with_reference(Error, Schedulable, Duration::from_millis(10));
+    stats.record_with_reference(Schedulable, Schedulable, Duration::from_millis(10));
+    stats.record_with_reference(NonSchedulable, NonSchedulable, Duration::from_millis(10));
+    stats.record_with_reference(Schedulable, NonSchedulable, Duration::from_millis(10));
+
+    assert_eq!(stats.reference_schedulable, 4);
+    assert_eq!(stats.accepted, 2);
+    assert_eq!(stats.unsound, 1);
+    assert_eq!(stats.accuracy(), 0.5);
+    assert_eq!(stats.accuracy_per_second(), 50.0);
+}
+
+#[test]
+fn stats_batch() {
+    use crate::algorithms::full_preemption::uniprocessor::earliest_deadline_first::edf73;
+
+    let tasksets: [&[RTTask]; 3] = [
+        &[RTTask::new_ns(50, 100, 100), RTTask::new_ns(50, 100, 100)],
+        &[RTTask::new_ns(60, 100, 100), RTTask::new_ns(50, 100, 100)],
+        &[RTTask::new_ns(10, 50, 100)],
+    ];
+
+    let mut stats = SchedStats::new("test");
+    let references = [SchedOutcome::Schedulable, SchedOutcome::NonSchedulable, SchedOutcome::Schedulable];
+    let results = stats.run_batch_with_reference(&edf73::Analysis, &tasksets, &references);
+
+    assert_eq!(results.len(), 3);
+    // The constrained deadline taskset fails the preconditions.
+    assert_eq!(stats.analyzed(), 2);
+    assert_eq!(stats.errors, 1);
+    assert_eq!(stats.accuracy(), 0.5);
+}
+
+#[test]
+fn outcome_of_errors() {
+    use SchedOutcome::*;
+
+    let outcome = |error: SchedError| SchedOutcome::from_result::<()>(&Err(error.into()));
+
+    assert_eq!(SchedOutcome::from_result(&anyhow::Ok(())), Schedulable);
+    assert_eq!(outcome(SchedError::NonSchedulable(None)), NonSchedulable);
+    assert_eq!(outcome(SchedError::intractable()), Inconclusive);
+    assert_eq!(outcome(SchedError::constrained_deadlines()), Error);
+    assert_eq!(outcome(SchedError::Other(anyhow::format_err!("failure"))), Error);
+    assert_eq!(SchedOutcome::from_result::<()>(&Err(anyhow::format_err!("failure"))), Error);
+
+    // Inconclusive runs keep their kind through the error helpers.
+    assert_eq!(outcome(SchedError::intractable().duplicate()), Inconclusive);
+    assert_eq!(outcome(SchedError::Inconclusive(None).with_context("task 0")), Inconclusive);
+
+    // Each outcome has its own exit code, zero for schedulable tasksets.
+    let codes: Vec<_> = [Schedulable, NonSchedulable, Inconclusive, Error].iter().map(SchedOutcome::exit_code).collect();
+    assert_eq!(codes, vec![0, 1, 2, 3]);
+}
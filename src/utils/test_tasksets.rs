@@ -0,0 +1,51 @@
+//! Pseudo-random tasksets and shared cross-checks for tests and benchmarks.
+
+use crate::prelude::*;
+
+/// Pseudo-random taskset with the given number of tasks, implicit deadlines and
+/// total utilization close to (not above) the given one, sorted by Rate
+/// Monotonic priority. The same seed always yields the same taskset.
+pub(crate) fn random_taskset(num_tasks: usize, utilization: f64, seed: u64) -> Vec<RTTask> {
+    let mut state = seed;
+    let mut next = move |bound: u64| {
+        state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        (state >> 33) % bound
+    };
+
+    let mut taskset: Vec<_> = (0..num_tasks)
+        .map(|_| {
+            let period = 100 + next(10_000);
+            let wcet = u64::max(1, (period as f64 * utilization / num_tasks as f64) as u64);
+            RTTask::new_ns(wcet, period, period)
+        })
+        .collect();
+
+    taskset.sort_by_key(|task| task.period);
+    taskset
+}
+
+/// Assert that the exact uniprocessor fixed priority test gives the same
+/// verdict as the response time analysis, on small constrained deadline
+/// tasksets with periods 5, 10 and 20, listed in each of the given priority
+/// orders. The total utilization is kept below one, as required by the latter.
+pub(crate) fn assert_same_verdict_as_rta<T, A>(analysis: &A, priority_orders: &[[usize; 3]])
+    where
+        A: for<'a> SchedAnalysis<T, &'a [RTTask]>,
+{
+    use crate::algorithms::full_preemption::uniprocessor::fixed_priority::rta86;
+
+    for (c1, d1, c2, d2, c3, d3) in itertools::iproduct!(1..=3, 2..=5, 1..=4, (4..=10).step_by(2), 1..=8, (8..=20).step_by(3)) {
+        if 4 * c1 + 2 * c2 + c3 >= 20 {
+            continue;
+        }
+
+        let taskset = [RTTask::new_ns(c1, d1, 5), RTTask::new_ns(c2, d2, 10), RTTask::new_ns(c3, d3, 20)];
+
+        for order in priority_orders {
+            let taskset: Vec<_> = order.iter().map(|&i| taskset[i].clone()).collect();
+
+            assert_eq!(analysis.is_schedulable(&taskset).is_ok(), rta86::Analysis.is_schedulable(&taskset).is_ok(),
+                "{}: {taskset:?}", analysis.analyzer_name());
+        }
+    }
+}
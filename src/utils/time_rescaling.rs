@@ -0,0 +1,196 @@
+//! Automatic time rescaling of tasksets.
+//!
+//! Tasksets are often expressed in nanoseconds while all their parameters are
+//! multiples of a much larger unit (e.g. milliseconds). Pseudo-polynomial tests,
+//! which scan arrival points or time instants one unit at a time, spend most of
+//! their runtime on instants where nothing changes. Dividing all the parameters
+//! by their greatest common divisor shrinks these ranges by the same factor,
+//! without changing the outcome of tests whose conditions only depend on the
+//! ratios between the task parameters.
+//!
+//! The rescaling is only applied when all the parameters are integer numbers of
+//! nanoseconds.
+
+use crate::prelude::*;
+
+/// Time unit the taskset has been rescaled by, refer to the [module](`self`)
+/// level documentation.
+#[derive(Debug, Clone, Copy)]
+#[derive(PartialEq)]
+pub struct TimeRescaling {
+    pub unit: Time,
+}
+
+impl TimeRescaling {
+    pub fn identity() -> Self {
+        Self { unit: Time::one() }
+    }
+
+    /// Greatest common divisor of the task parameters, or the identity
+    /// rescaling if any of them is not an integer number of nanoseconds.
+    pub fn from_taskset(taskset: &[RTTask]) -> Self {
+        Self::from_times(taskset.iter().flat_map(|task| [task.wcet, task.deadline, task.period]))
+    }
+
+    /// Greatest common divisor of the given times, or the identity rescaling if
+    /// any of them is not an integer number of nanoseconds.
+    pub fn from_times<I: IntoIterator<Item = Time>>(times: I) -> Self {
+        let gcd = times.into_iter()
+            .try_fold(0u64, |gcd, time| {
+                let nanos = time.as_nanos();
+
+                if nanos < 0.0 || nanos.fract() != 0.0 || nanos > u64::MAX as f64 {
+                    None
+                } else {
+                    Some(greatest_common_divisor(gcd, nanos as u64))
+                }
+            });
+
+        match gcd {
+            Some(gcd) if gcd > 1 => Self { unit: Time::nanos(gcd as f64) },
+            _ => Self::identity(),
+        }
+    }
+
+    pub fn is_identity(&self) -> bool {
+        self.unit == Time::one()
+    }
+
+    /// Express the given time in multiples of the rescaling unit.
+    pub fn rescale(&self, time: Time) -> Time {
+        Time::nanos(time / self.unit)
+    }
+
+    /// Express the given rescaled time back in nanoseconds.
+    pub fn restore(&self, time: Time) -> Time {
+        time.as_nanos() * self.unit
+    }
+
+    pub fn rescale_taskset(&self, taskset: &[RTTask]) -> Vec<RTTask> {
+        taskset.iter()
+            .map(|task| RTTask {
+                wcet: self.rescale(task.wcet),
+                deadline: self.rescale(task.deadline),
+                period: self.rescale(task.period),
+            })
+            .collect()
+    }
+}
+
+fn greatest_common_divisor(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+
+    a
+}
+
+/// Results of an analysis which can be converted back from the rescaled time
+/// unit.
+pub trait RescaledResult {
+    fn restore(self, rescaling: &TimeRescaling) -> Self;
+}
+
+impl RescaledResult for () {
+    fn restore(self, _: &TimeRescaling) -> Self { }
+}
+
+impl RescaledResult for Time {
+    fn restore(self, rescaling: &TimeRescaling) -> Self {
+        rescaling.restore(self)
+    }
+}
+
+impl RescaledResult for Vec<Time> {
+    fn restore(self, rescaling: &TimeRescaling) -> Self {
+        self.into_iter()
+            .map(|time| rescaling.restore(time))
+            .collect()
+    }
+}
+
+/// Run the given analysis on the taskset rescaled by its [`TimeRescaling`].
+///
+/// The results are converted back to nanoseconds, and are returned together
+/// with the applied rescaling.
+///
+/// Refer to the [module](`self`) level documentation.
+///
+/// Only analyses whose time parameters all come from the taskset can be
+/// wrapped: parameters of the analysis itself (e.g. resource models or time
+/// units) are not rescaled.
+pub struct Rescaled<A> {
+    pub analysis: A,
+}
+
+impl<T, A> SchedAnalysis<(T, TimeRescaling), &[RTTask]> for Rescaled<A>
+    where
+        A: for<'a> SchedAnalysis<T, &'a [RTTask]>,
+        T: RescaledResult,
+{
+    fn analyzer_name(&self) -> &str { self.analysis.analyzer_name() }
+
+    fn check_preconditions(&self, taskset: &&[RTTask]) -> Result<(), SchedError> {
+        self.analysis.check_preconditions(taskset)
+    }
+
+    fn run_test(&self, taskset: &[RTTask]) -> Result<(T, TimeRescaling), SchedError> {
+        let rescaling = TimeRescaling::from_taskset(taskset);
+        let taskset = rescaling.rescale_taskset(taskset);
+
+        self.analysis.run_test(&taskset)
+            .map(|result| (result.restore(&rescaling), rescaling))
+    }
+}
+
+#[test]
+fn rescaling_unit() {
+    let taskset = [
+        RTTask::new_ns(2_000_000, 10_000_000, 10_000_000),
+        RTTask::new_ns(3_000_000, 15_000_000, 20_000_000),
+    ];
+
+    assert_eq!(TimeRescaling::from_taskset(&taskset).unit, Time::millis(1.0));
+
+    let taskset = [
+        RTTask { wcet: Time::nanos(1.5), deadline: Time::nanos(10.0), period: Time::nanos(10.0) },
+    ];
+
+    assert!(TimeRescaling::from_taskset(&taskset).is_identity());
+}
+
+#[test]
+fn rescaled_analysis() {
+    use crate::algorithms::full_preemption::uniprocessor::fixed_priority::rta86;
+    use crate::algorithms::full_preemption::global_multiprocessor::earliest_deadline_first::baruah07;
+
+    let taskset = [
+        RTTask::new_ns(10_000, 50_000, 50_000),
+        RTTask::new_ns(20_000, 100_000, 100_000),
+        RTTask::new_ns(30_000, 200_000, 200_000),
+    ];
+
+    let (response_times, rescaling) = Rescaled { analysis: rta86::Analysis }
+        .is_schedulable(&taskset).unwrap();
+    assert_eq!(rescaling.unit, Time::micros(10.0));
+    assert_eq!(response_times, rta86::Analysis.is_schedulable(&taskset).unwrap());
+
+    let taskset = [
+        RTTask::new_ns(35_000, 90_000, 160_000),
+        RTTask::new_ns(70_000, 115_000, 160_000),
+        RTTask::new_ns(30_000, 50_000, 75_000),
+    ];
+
+    let rescaled = [
+        RTTask::new_ns(7, 18, 32),
+        RTTask::new_ns(14, 23, 32),
+        RTTask::new_ns(6, 10, 15),
+    ];
+
+    for num_processors in 1..=2 {
+        assert_eq!(
+            Rescaled { analysis: baruah07::Analysis { num_processors } }.is_schedulable(&taskset).is_ok(),
+            baruah07::Analysis { num_processors }.is_schedulable(&rescaled).is_ok(),
+        );
+    }
+}
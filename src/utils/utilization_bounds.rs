@@ -0,0 +1,231 @@
+//! Closed-form utilization bounds for Rate Monotonic scheduling.
+//!
+//! Pure functions computing the classic utilization bounds of fully-preemptive
+//! Rate Monotonic scheduling on a uniprocessor, for quick back-of-envelope
+//! checks and teaching. The schedulability tests built on them are found in
+//! [`crate::algorithms::full_preemption::uniprocessor::fixed_priority`].
+//!
+//! #### Implements:
+//! - [`liu_layland`] \
+//!   | Least upper bound on the utilization of *n* tasks \[1\]
+//! - [`hyperbolic`] \
+//!   | Hyperbolic bound on the tasks' utilizations \[2\]
+//! - [`kuo_mok`], [`harmonic_chains`] \
+//!   | Least upper bound on the utilization of *K* harmonic chains \[3\]
+//! - [`hyperbolic_blocking`] \
+//!   | Hyperbolic bound with blocking times \[4\]
+//! - [`burchard`] \
+//!   | Least upper bound on the utilization given the spread of the periods
+//!   | \[5\]
+//!
+//! ---
+//! #### References:
+//! 1. C. L. Liu and J. W. Layland, “Scheduling Algorithms for Multiprogramming
+//!    in a Hard-Real-Time Environment,” J. ACM, vol. 20, no. 1, pp. 46–61,
+//!    Jan. 1973, doi: 10.1145/321738.321743.
+//! 2. E. Bini, G. Buttazzo, and G. Buttazzo, “A hyperbolic bound for the rate
+//!    monotonic algorithm,” in Proceedings 13th Euromicro Conference on Real-Time
+//!    Systems, June 2001, pp. 59–66. doi: 10.1109/EMRTS.2001.934000.
+//! 3. T.-W. Kuo and A. K. Mok, “Load adjustment in adaptive real-time systems,”
+//!    in Proceedings Twelfth Real-Time Systems Symposium, Dec. 1991, pp. 160–170.
+//!    doi: 10.1109/REAL.1991.160369.
+//! 4. E. Bini, G. C. Buttazzo, and G. M. Buttazzo, “Rate monotonic analysis:
+//!    the hyperbolic bound,” IEEE Transactions on Computers, vol. 52, no. 7,
+//!    pp. 933–942, July 2003, doi: 10.1109/TC.2003.1214341.
+//! 5. A. Burchard, J. Liebeherr, Y. Oh, and S. H. Son, “New strategies for
+//!    assigning real-time tasks to multiprocessor systems,” IEEE Transactions
+//!    on Computers, vol. 44, no. 12, pp. 1429–1442, Dec. 1995,
+//!    doi: 10.1109/12.477248.
+
+use crate::prelude::*;
+
+/// Least upper bound on the total utilization of `num_tasks` implicit deadline
+/// tasks under Rate Monotonic, *n(2^(1/n) - 1)* \[1\]. The bound decreases
+/// towards *ln(2)* as the number of tasks grows.
+pub fn liu_layland(num_tasks: u64) -> f64 {
+    if num_tasks == 0 {
+        return 1.0;
+    }
+
+    // Theorem 5 [1]
+    let n = num_tasks as f64;
+    n * (f64::powf(2.0, 1.0 / n) - 1.0)
+}
+
+/// Value of the hyperbolic bound, *Π(U_i + 1)*: implicit deadline tasks with
+/// the given utilizations are schedulable under Rate Monotonic if it is at most
+/// two \[2\].
+///
+/// The largest utilization of an additional task is *2 / Π(U_i + 1) - 1*.
+pub fn hyperbolic<I: IntoIterator<Item = f64>>(utilizations: I) -> f64 {
+    // Theorem 1 [2]
+    utilizations.into_iter()
+        .map(|utilization| utilization + 1.0)
+        .product()
+}
+
+/// Least upper bound on the total utilization of implicit deadline tasks under
+/// Rate Monotonic, when their periods can be partitioned in `num_chains`
+/// harmonic chains \[3\]. Refer to [`harmonic_chains`].
+pub fn kuo_mok(num_chains: u64) -> f64 {
+    // Theorem 3 [3]
+    liu_layland(num_chains)
+}
+
+/// Minimum number of harmonic chains the periods can be partitioned in, i.e.
+/// chains of periods each dividing the next.
+///
+/// Divisibility is a partial order, thus the minimum number of chains is the
+/// number of periods minus the size of a maximum matching between each period
+/// and its multiples (Dilworth's theorem). O(*n^3*) complexity.
+pub fn harmonic_chains(periods: &[Time]) -> u64 {
+    let n = periods.len();
+
+    // Period j follows period i in a chain. Equal periods are chained in order
+    // of their index, to keep the relation acyclic.
+    let follows = |i: usize, j: usize| {
+        i != j
+        && periods[j] % periods[i] == Time::zero()
+        && (periods[i] != periods[j] || i < j)
+    };
+
+    // Kuhn's augmenting paths.
+    fn augment(i: usize, n: usize, follows: &dyn Fn(usize, usize) -> bool, visited: &mut [bool], next_of: &mut [Option<usize>]) -> bool {
+        for j in 0..n {
+            if follows(i, j) && !visited[j] {
+                visited[j] = true;
+
+                if next_of[j].is_none_or(|k| augment(k, n, follows, visited, next_of)) {
+                    next_of[j] = Some(i);
+                    return true;
+                }
+            }
+        }
+
+        false
+    }
+
+    let mut next_of = vec![None; n];
+    let matching = (0..n)
+        .filter(|&i| augment(i, n, &follows, &mut vec![false; n], &mut next_of))
+        .count();
+
+    (n - matching) as u64
+}
+
+/// Value of the hyperbolic bound with blocking times for each task, i.e.
+/// *max_i (U_i + B_i / T_i + 1) Π_(j < i)(U_j + 1)*. Implicit deadline tasks,
+/// sorted by period, are schedulable under Rate Monotonic if it is at most two
+/// \[4\].
+///
+/// The tasks' `blocking` times are the longest intervals each task can be
+/// blocked by lower priority tasks.
+pub fn hyperbolic_blocking(taskset: &[RTTask], blocking: &[Time]) -> f64 {
+    let mut hp_product = 1.0;
+
+    // Theorem 3 [4]
+    taskset.iter().zip(blocking)
+        .map(|(task, blocking)| {
+            let bound = (task.utilization() + *blocking / task.period + 1.0) * hp_product;
+            hp_product *= task.utilization() + 1.0;
+
+            bound
+        })
+        .fold(0.0, f64::max)
+}
+
+/// Least upper bound on the total utilization of implicit deadline tasks under
+/// Rate Monotonic, given the spread *β* of the fractional parts of the
+/// logarithms of their periods \[5\]:
+///   *(n - 1)(2^(β / (n - 1)) - 1) + 2^(1 - β) - 1* if *β < 1 - 1/n*,
+/// and the [`liu_layland`] bound otherwise. The bound is one for periods which
+/// are powers of two of each other.
+///
+/// Scaling all the periods by the same factor changes neither the
+/// schedulability nor the bound's validity, thus *β* is taken at the scaling
+/// which minimizes it, i.e. one minus the largest gap between the fractional
+/// parts on the unit circle. O(*n* log(*n*)) complexity.
+pub fn burchard(periods: &[Time]) -> f64 {
+    let n = periods.len();
+
+    let mut fractions: Vec<f64> = periods.iter()
+        .map(|period| period.as_nanos().log2().rem_euclid(1.0))
+        .collect();
+    fractions.sort_by(f64::total_cmp);
+
+    let largest_gap = fractions.windows(2)
+        .map(|pair| pair[1] - pair[0])
+        .chain(fractions.first().zip(fractions.last()).map(|(first, last)| first + 1.0 - last))
+        .fold(0.0, f64::max);
+    let beta = 1.0 - largest_gap;
+
+    // Theorem 3 [5]
+    if n <= 1 || beta >= 1.0 - 1.0 / n as f64 {
+        liu_layland(n as u64)
+    } else {
+        let m = (n - 1) as f64;
+        m * (f64::powf(2.0, beta / m) - 1.0) + f64::powf(2.0, 1.0 - beta) - 1.0
+    }
+}
+
+#[test]
+fn closed_form_bounds() {
+    let close = |l: f64, r: f64| (l - r).abs() < 1e-4;
+
+    assert!(close(liu_layland(1), 1.0));
+    assert!(close(liu_layland(2), 0.8284));
+    assert!(close(liu_layland(3), 0.7798));
+    assert!(liu_layland(1000) > f64::ln(2.0));
+
+    assert!(close(hyperbolic([0.5, 0.25]), 1.875));
+    assert!(close(2.0 / hyperbolic([0.5]) - 1.0, 1.0 / 3.0));
+
+    assert!(close(kuo_mok(1), 1.0));
+    assert!(close(kuo_mok(2), liu_layland(2)));
+
+    let periods = |periods: &[f64]| -> Vec<Time> {
+        periods.iter().map(|&period| Time::nanos(period)).collect()
+    };
+
+    assert!(close(burchard(&periods(&[10.0, 20.0, 40.0])), 1.0));
+    // β = log2(4/3), 2^β - 1 + 2^(1 - β) - 1 = 1/3 + 1/2.
+    assert!(close(burchard(&periods(&[4.0, 6.0])), 5.0 / 6.0));
+    // The spread does not depend on the unit of the periods.
+    assert!(close(burchard(&periods(&[3.0, 4.5])), 5.0 / 6.0));
+    // Evenly spread periods get the Liu & Layland bound.
+    let spread: Vec<f64> = (0..4).map(|k| 1000.0 * f64::powf(2.0, k as f64 / 4.0)).collect();
+    assert!(close(burchard(&periods(&spread)), liu_layland(4)));
+    assert!(burchard(&periods(&[5.0, 7.0, 9.0, 11.0, 13.0])) > liu_layland(5));
+}
+
+#[test]
+fn count_harmonic_chains() {
+    let periods = |periods: &[u64]| -> Vec<Time> {
+        periods.iter().map(|&period| Time::nanos(period as f64)).collect()
+    };
+
+    assert_eq!(harmonic_chains(&[]), 0);
+    assert_eq!(harmonic_chains(&periods(&[10, 20, 40, 80])), 1);
+    assert_eq!(harmonic_chains(&periods(&[10, 15, 20, 30, 60])), 2);
+    assert_eq!(harmonic_chains(&periods(&[10, 10, 10])), 1);
+
+    // e.g. 2 | 4 | 12 and 3 | 6.
+    assert_eq!(harmonic_chains(&periods(&[2, 3, 4, 6, 12])), 2);
+    assert_eq!(harmonic_chains(&periods(&[7, 11, 13])), 3);
+}
+
+#[test]
+fn blocking_bound() {
+    let taskset = [RTTask::new_ns(1, 4, 4), RTTask::new_ns(2, 8, 8)];
+
+    // Without blocking: max(1.25, 1.25 * 1.25), i.e. the hyperbolic bound.
+    let bound = hyperbolic_blocking(&taskset, &[Time::zero(), Time::zero()]);
+    assert!((bound - hyperbolic([0.25, 0.25])).abs() < 1e-9);
+
+    // The first task blocked for 2: max(0.25 + 0.5 + 1, 1.5625).
+    let bound = hyperbolic_blocking(&taskset, &[Time::nanos(2.0), Time::zero()]);
+    assert!((bound - 1.75).abs() < 1e-9);
+
+    let bound = hyperbolic_blocking(&taskset, &[Time::nanos(4.0), Time::zero()]);
+    assert!(bound > 2.0);
+}
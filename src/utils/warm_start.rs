@@ -0,0 +1,24 @@
+//! Warm-starting of Response Time Analyses.
+//!
+//! Response time analyses compute each response time as the least fixpoint of
+//! a monotone equation, iterating from an initial value. The iterations
+//! converge to the least fixpoint from any initial value which is not larger
+//! than it, so when many similar tasksets are analyzed (e.g. during
+//! sensitivity sweeps or in designers), the response times of a previous
+//! analysis can be used as initial values, cutting the number of iterations.
+
+use crate::prelude::*;
+
+/// Initial value of the fixpoint iterations for the `index`-th task, given the
+/// previously computed response times.
+///
+/// The initial response times must be lower bounds of the actual response
+/// times, e.g. the results of a previous analysis of a taskset with smaller or
+/// equal WCETs and larger or equal periods, or with a larger or equal supply.
+/// Larger initial values are not detected, and yield pessimistic response
+/// times. Missing initial values default to the task's WCET, and smaller ones
+/// are raised to it.
+pub fn warm_start_response_time(initial_response_times: &[Time], index: usize, task: &RTTask) -> Time {
+    initial_response_times.get(index)
+        .map_or(task.wcet, |&response| Time::max(response, task.wcet))
+}
@@ -0,0 +1,146 @@
+//! Margins of measurement-derived WCETs.
+//!
+//! WCETs measured on the target, e.g. the maximum observed execution time,
+//! underestimate the true ones by an unknown error. [`WcetMargins`] annotate
+//! each task with the relative margin of its WCET, e.g. 0.2 for the measured
+//! maximum plus 20%, and [`WithWcetMargins`] runs any analysis on the WCETs
+//! inflated by their margins. Comparing the verdicts with and without the
+//! margins, refer to [`WcetMargins::verdicts`], shows how sensitive they are to
+//! the estimation error.
+
+use crate::prelude::*;
+
+/// Relative margin of the WCET of each task, refer to the [module](`self`)
+/// level documentation.
+#[derive(Debug, Clone)]
+#[derive(PartialEq)]
+pub struct WcetMargins {
+    pub margins: Vec<f64>,
+}
+
+impl WcetMargins {
+    /// No margin for any of the given number of tasks.
+    pub fn none(num_tasks: usize) -> Self {
+        Self { margins: vec![0.0; num_tasks] }
+    }
+
+    pub fn is_none(&self) -> bool {
+        self.margins.iter().all(|margin| *margin == 0.0)
+    }
+
+    /// Check that the margin of every task is given and non-negative.
+    pub fn check_preconditions(&self, taskset: &[RTTask]) -> Result<(), SchedError> {
+        if self.margins.len() != taskset.len() {
+            Err(SchedError::Precondition(Some(
+                anyhow::format_err!("the WCET margin of every task must be given."))))
+        } else if self.margins.iter().any(|margin| margin.is_nan() || *margin < 0.0) {
+            Err(SchedError::Precondition(Some(
+                anyhow::format_err!("WCET margins must be non-negative."))))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Tasks with their WCETs inflated by the margins, rounded up to
+    /// [`Time::one`].
+    pub fn apply(&self, taskset: &[RTTask]) -> Vec<RTTask> {
+        taskset.iter().zip(&self.margins)
+            .map(|(task, margin)| RTTask {
+                wcet: (task.wcet * (1.0 + margin)).ceil(),
+                deadline: task.deadline,
+                period: task.period,
+            })
+            .collect()
+    }
+
+    /// Outcomes of the analysis on the taskset as given and with the margins.
+    pub fn verdicts<T, A>(&self, analysis: A, taskset: &[RTTask]) -> MarginVerdicts
+        where
+            A: for<'a> SchedAnalysis<T, &'a [RTTask]>,
+    {
+        let nominal = SchedOutcome::from_result(&analysis.is_schedulable(taskset));
+        let analysis = WithWcetMargins { margins: self.clone(), analysis };
+
+        MarginVerdicts {
+            nominal,
+            with_margins: SchedOutcome::from_result(&analysis.is_schedulable(taskset)),
+        }
+    }
+}
+
+/// Outcomes of an analysis without and with the [`WcetMargins`].
+#[derive(Debug, Clone, Copy)]
+#[derive(PartialEq, Eq)]
+pub struct MarginVerdicts {
+    pub nominal: SchedOutcome,
+    pub with_margins: SchedOutcome,
+}
+
+impl MarginVerdicts {
+    /// Whether the verdict depends on the WCET estimation error.
+    pub fn is_sensitive(&self) -> bool {
+        self.nominal != self.with_margins
+    }
+}
+
+/// Run the given analysis on the WCETs inflated by the [`WcetMargins`].
+///
+/// Refer to the [module](`self`) level documentation.
+pub struct WithWcetMargins<A> {
+    pub margins: WcetMargins,
+    pub analysis: A,
+}
+
+impl<T, A> SchedAnalysis<T, &[RTTask]> for WithWcetMargins<A>
+    where
+        A: for<'a> SchedAnalysis<T, &'a [RTTask]>,
+{
+    fn analyzer_name(&self) -> &str { self.analysis.analyzer_name() }
+
+    fn check_preconditions(&self, taskset: &&[RTTask]) -> Result<(), SchedError> {
+        self.margins.check_preconditions(taskset)?;
+        self.analysis.check_preconditions(&self.margins.apply(taskset).as_slice())
+    }
+
+    fn run_test(&self, taskset: &[RTTask]) -> Result<T, SchedError> {
+        self.analysis.run_test(&self.margins.apply(taskset))
+    }
+}
+
+impl<A: SchedProcessors> SchedProcessors for WithWcetMargins<A> {
+    fn num_processors(&self) -> u64 { self.analysis.num_processors() }
+}
+
+impl<A: SchedPlatform> SchedPlatform for WithWcetMargins<A> {
+    fn supports(&self, platform: &Platform) -> bool { self.analysis.supports(platform) }
+}
+
+#[test]
+fn wcet_margins() {
+    use crate::algorithms::full_preemption::uniprocessor::fixed_priority::rta86;
+
+    let at = |t: u64| Time::nanos(t as f64);
+
+    let taskset = [RTTask::new_ns(10, 40, 40), RTTask::new_ns(20, 35, 80)];
+
+    // 10 * 1.25 and 20 * 1.01, rounded up.
+    let margins = WcetMargins { margins: vec![0.25, 0.01] };
+    let inflated = margins.apply(&taskset);
+    assert_eq!((inflated[0].wcet, inflated[1].wcet), (at(13), at(21)));
+
+    let analysis = WithWcetMargins { margins: margins.clone(), analysis: rta86::Analysis };
+    assert_eq!(analysis.is_schedulable(&taskset).unwrap(), vec![at(13), at(34)]);
+
+    // A 50% margin on the second task pushes it past its deadline.
+    let verdicts = WcetMargins { margins: vec![0.0, 0.5] }.verdicts(rta86::Analysis, &taskset);
+    assert_eq!(verdicts.nominal, SchedOutcome::Schedulable);
+    assert_eq!(verdicts.with_margins, SchedOutcome::NonSchedulable);
+    assert!(verdicts.is_sensitive());
+
+    assert!(!margins.verdicts(rta86::Analysis, &taskset).is_sensitive());
+    assert!(WcetMargins::none(2).is_none());
+
+    assert!(WcetMargins { margins: vec![0.1] }.verdicts(rta86::Analysis, &taskset).with_margins != SchedOutcome::Schedulable);
+    assert!(WithWcetMargins { margins: WcetMargins { margins: vec![-0.1, 0.0] }, analysis: rta86::Analysis }
+        .is_schedulable(&taskset).is_err());
+}
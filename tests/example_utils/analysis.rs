@@ -0,0 +1,70 @@
+use eva_rt_engine::prelude::*;
+use crate::utils::*;
+
+#[test]
+fn what_if() {
+    let what_if = |scale_wcet, scale_period, add_jitter| WhatIfArgs { scale_wcet, scale_period, add_jitter };
+    let taskset = [RTTask::new_ns(1_000_001, 4_000_000, 4_000_000)];
+    let nanos = |taskset: Vec<RTTask>| -> Vec<_> {
+        taskset.iter().map(|task| (task.wcet.as_nanos(), task.deadline.as_nanos(), task.period.as_nanos())).collect()
+    };
+
+    let identity = what_if(1.0, 1.0, 0.0);
+    assert!(identity.is_identity() && identity.provenance_parameters().is_empty());
+    assert_eq!(nanos(identity.apply(&taskset).unwrap()), nanos(taskset.to_vec()));
+
+    // 1_200_001.2ns rounded up, 3ms of period after 1ms of jitter.
+    let what_if = what_if(1.2, 1.0, 1.0);
+    assert_eq!(nanos(what_if.apply(&taskset).unwrap()), [(1_200_002.0, 3_000_000.0, 3_000_000.0)]);
+    assert_eq!(what_if.provenance_parameters(), [
+        ("WCET scaling".to_owned(), "1.2".to_owned()),
+        ("Added jitter".to_owned(), "1ms".to_owned()),
+    ]);
+
+    assert!(WhatIfArgs { scale_period: 0.0, ..what_if.clone() }.apply(&taskset).is_err());
+    assert!(WhatIfArgs { add_jitter: 4.0, ..what_if }.apply(&taskset).is_err());
+}
+
+#[test]
+fn concurrent_analyses() {
+    use std::time::{Duration, Instant};
+
+    struct Sleep(&'static str, Duration);
+
+    impl SchedAnalysis<(), &[RTTask]> for Sleep {
+        fn analyzer_name(&self) -> &str { self.0 }
+
+        fn check_preconditions(&self, _: &&[RTTask]) -> Result<(), SchedError> { Ok(()) }
+
+        fn run_test(&self, _: &[RTTask]) -> Result<(), SchedError> {
+            std::thread::sleep(self.1);
+            Ok(())
+        }
+    }
+
+    let taskset: std::sync::Arc<[RTTask]> = vec![RTTask::new_ns(1, 4, 4)].into();
+    let timeout = Some(Duration::from_secs(10));
+
+    // The tests run at once, and their results keep the order of the stage,
+    // even when the first one is the slowest.
+    let start = Instant::now();
+    let running: Vec<_> = [("slow", 500), ("fast", 0), ("medium", 500)].into_iter()
+        .map(|(name, millis)| spawn_analysis(name, Sleep(name, Duration::from_millis(millis)), taskset.clone(), timeout, false))
+        .collect();
+    let results: Vec<_> = running.into_iter().map(|running| running.wait().unwrap()).collect();
+
+    // One after the other, they would take a second.
+    assert!(start.elapsed() < Duration::from_millis(900));
+    assert_eq!(results.iter().map(|result| result.id.as_str()).collect::<Vec<_>>(), ["slow", "fast", "medium"]);
+    assert!(results.iter().all(|result| result.outcome == SchedOutcome::Schedulable));
+
+    // Each timeout counts from the start of its own test: the second test
+    // completes in time, although it is waited for after the first one timed
+    // out.
+    let timeout = Some(Duration::from_millis(200));
+    let timed_out = spawn_analysis("timed_out", Sleep("timed_out", Duration::from_millis(400)), taskset.clone(), timeout, false);
+    let in_time = spawn_analysis("in_time", Sleep("in_time", Duration::from_millis(50)), taskset, timeout, false);
+
+    assert_eq!(timed_out.wait().unwrap().outcome, SchedOutcome::Inconclusive);
+    assert_eq!(in_time.wait().unwrap().outcome, SchedOutcome::Schedulable);
+}
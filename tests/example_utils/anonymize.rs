@@ -0,0 +1,26 @@
+use eva_rt_engine::prelude::*;
+use crate::utils::anonymize::*;
+
+#[test]
+fn anonymized_taskset() {
+    use eva_rt_engine::algorithms::full_preemption::uniprocessor::fixed_priority::rta86;
+
+    let taskset = [
+        RTTask::new_ns(2_000_000, 10_000_000, 10_000_000),
+        RTTask::new_ns(3_000_000, 15_000_000, 20_000_000),
+    ];
+
+    let anonymized = anonymize_taskset(&taskset, 1);
+    let nanos: Vec<_> = anonymized.iter().map(|task| (task.wcet.as_nanos(), task.deadline.as_nanos(), task.period.as_nanos())).collect();
+    assert_eq!(nanos, vec![(2.0, 10.0, 10.0), (3.0, 15.0, 20.0)]);
+
+    // The response times scale with the times.
+    let anonymized = anonymize_taskset(&taskset, 7);
+    let response_times: Vec<_> = rta86::Analysis.is_schedulable(&taskset).unwrap().into_iter()
+        .map(|response| response.as_nanos() / 1_000_000.0 * 7.0)
+        .collect();
+    let anonymized_response_times: Vec<_> = rta86::Analysis.is_schedulable(&anonymized).unwrap().into_iter()
+        .map(|response| response.as_nanos())
+        .collect();
+    assert_eq!(anonymized_response_times, response_times);
+}
@@ -0,0 +1,50 @@
+use eva_rt_engine::prelude::*;
+use crate::utils::report::Report;
+use crate::utils::diff::*;
+
+#[test]
+fn diff_runs() {
+    use crate::utils::report::TestResult;
+    use eva_rt_engine::algorithms::registry::Profile;
+
+    let platform = Platform::uniprocessor(SchedulingAlgorithm::FixedPriority);
+    let old_taskset = [RTTask::new_ns(1_000_000, 4_000_000, 4_000_000), RTTask::new_ns(2_000_000, 8_000_000, 8_000_000)];
+    let new_taskset = [RTTask::new_ns(1_000_000, 4_000_000, 4_000_000), RTTask::new_ns(7_000_000, 8_000_000, 8_000_000)];
+
+    let run = |taskset, verdict: anyhow::Result<()>, response_times| Report {
+        input_file: "",
+        taskset,
+        platform: &platform,
+        profile: Profile::Exhaustive,
+        results: vec![TestResult::from_result("up.fp.rta86", "RTA", &verdict, std::time::Duration::ZERO, true)],
+        results_with_margins: None,
+        excluded: vec![],
+        response_times,
+        provenance: crate::utils::report::Provenance::new(b"", vec![]),
+    };
+
+    let old = run(&old_taskset, Ok(()), Some(vec![Time::millis(1.0), Time::millis(3.0)]));
+    let new = run(&new_taskset, Err(SchedError::NonSchedulable(None).into()), None);
+
+    assert_eq!(diff(&old, &new), vec![
+        "task 1: wcet 2 -> 7 ms",
+        "\"RTA\": schedulable -> non schedulable",
+        "response times: only known for the old taskset",
+        "utilization margin: 0.5000 -> -0.1250",
+        "verdict: schedulable -> non schedulable",
+    ]);
+
+    assert!(diff(&old, &old).is_empty());
+
+    // The tests are matched by their ID, not by their display name.
+    let mut renamed = run(&old_taskset, Ok(()), Some(vec![Time::millis(1.0), Time::millis(3.0)]));
+    renamed.results[0].analyzer = "RTA (Joseph & Pandya 1986)".to_owned();
+    assert!(diff(&old, &renamed).is_empty());
+
+    let mut other = run(&old_taskset, Ok(()), Some(vec![Time::millis(1.0), Time::millis(3.0)]));
+    other.results[0].id = "up.fp.tda89".to_owned();
+    assert_eq!(diff(&old, &other), vec![
+        "\"RTA\": only run on the old taskset",
+        "\"RTA\": only run on the new taskset",
+    ]);
+}
@@ -0,0 +1,18 @@
+use eva_rt_engine::prelude::*;
+use eva_rt_engine::algorithms::cyclic_executive::Dispatch;
+use crate::utils::TasksetPlainUnit;
+use crate::utils::dispatch_table::*;
+
+#[test]
+fn csv_dispatch_table() {
+    let data = "# hand-written\ntask,job,start,duration\n0,0,0,1\n1, 0, 1, 1.8\n\n";
+    let table = deserialize_csv_table(data, TasksetPlainUnit::Millis).unwrap();
+    assert_eq!(table.table[1], Dispatch { task: 1, job: 0, start: Time::millis(1.0), duration: Time::millis(1.8) });
+
+    let serialized = serialize_csv_table(&table, TasksetPlainUnit::Millis);
+    assert_eq!(deserialize_csv_table(&serialized, TasksetPlainUnit::Millis).unwrap().table, table.table);
+
+    let err = deserialize_csv_table("0,0,0,1\n1,0,-1,1\n", TasksetPlainUnit::Millis).unwrap_err().to_string();
+    assert!(err.contains("line 2") && err.contains("'start'"));
+    assert!(deserialize_csv_table("0,0,1\n", TasksetPlainUnit::Millis).is_err());
+}
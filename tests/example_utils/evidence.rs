@@ -0,0 +1,121 @@
+use std::io::Cursor;
+use eva_rt_engine::prelude::*;
+use crate::utils::report::{self, Report, TestResult};
+use zip::ZipArchive;
+use crate::utils::evidence::*;
+
+#[test]
+fn evidence_bundle() {
+    use eva_rt_engine::algorithms::registry::Profile;
+
+    let taskset = [RTTask::new_ns(1, 4, 4), RTTask::new_ns(2, 8, 8)];
+    let platform = Platform::uniprocessor(SchedulingAlgorithm::FixedPriority);
+    let report = Report {
+        input_file: "tasks.taskset.json",
+        taskset: &taskset,
+        platform: &platform,
+        profile: Profile::Exhaustive,
+        results: vec![TestResult::from_result("up.fp.rta86", "RTA", &Ok(()), std::time::Duration::ZERO, true)],
+        results_with_margins: None,
+        excluded: vec![],
+        response_times: None,
+        provenance: report::Provenance { timestamp: 951_782_400, ..report::Provenance::new(b"a", vec![]) },
+    };
+
+    let results = results_json(&report).unwrap();
+    assert_eq!(results["verdict"], "schedulable");
+    assert_eq!(results["results"][0]["version"], 1);
+    assert_eq!(results["assumptions"][0]["id"], "sporadic_arrivals");
+    assert_eq!(results["assumptions"][0]["tests"][0], "up.fp.rta86");
+
+    let inputs = [("tasks.taskset.json".to_owned(), b"{}".to_vec())];
+    let archive = bundle(&report, &inputs, Some(b"key")).unwrap();
+    assert_eq!(archive, bundle(&report, &inputs, Some(b"key")).unwrap());
+
+    // The central directory lists the manifest, the four files and the
+    // signature.
+    assert_eq!(ZipArchive::new(Cursor::new(&archive)).unwrap().len(), 6);
+
+    assert_eq!(verify(&archive, Some(b"key")).unwrap(),
+        vec!["input/tasks.taskset.json", "results.json", "report.md", "report.html"]);
+    assert!(verify(&archive, Some(b"other key")).is_err());
+
+    // Tampering with a file breaks its digest.
+    let unsigned = bundle(&report, &inputs, None).unwrap();
+    assert!(verify(&unsigned, None).is_ok() && verify(&unsigned, Some(b"key")).is_err());
+
+    let tampered = bundle(&report, &[("tasks.taskset.json".to_owned(), b"{ }".to_vec())], None).unwrap();
+    let mut files = zip_entries(&tampered).unwrap();
+    files[0] = zip_entries(&unsigned).unwrap().swap_remove(0);
+    let err = verify(&zip_stored(&files).unwrap(), None).unwrap_err();
+    assert_eq!(err.to_string(), "the digest of input/tasks.taskset.json does not match the manifest");
+}
+
+#[test]
+fn forged_evidence_bundle() {
+    use eva_rt_engine::algorithms::registry::Profile;
+
+    let taskset = [RTTask::new_ns(1, 4, 4)];
+    let platform = Platform::uniprocessor(SchedulingAlgorithm::FixedPriority);
+    let report = Report {
+        input_file: "a.json",
+        taskset: &taskset,
+        platform: &platform,
+        profile: Profile::Exhaustive,
+        results: vec![],
+        results_with_margins: None,
+        excluded: vec![],
+        response_times: None,
+        provenance: report::Provenance::new(b"a", vec![]),
+    };
+
+    // A second results.json, as `input/a.json` has the same length.
+    let archive = bundle(&report, &[("a.json".to_owned(), b"{}".to_vec())], None).unwrap();
+    let position = |archive: &[u8], name: &[u8]| archive.windows(name.len())
+        .enumerate()
+        .filter(|(_, window)| *window == name)
+        .map(|(at, _)| at)
+        .collect::<Vec<_>>();
+
+    let mut duplicate = archive.clone();
+    for at in position(&archive, b"input/a.json") {
+        duplicate[at..at + 12].copy_from_slice(b"results.json");
+    }
+    let err = verify(&duplicate, None).unwrap_err();
+    assert_eq!(err.to_string(), "the bundle has duplicate file names");
+
+    // A local entry of results.json which is not in the central directory,
+    // right before it.
+    let mut zip = ZipArchive::new(Cursor::new(&archive)).unwrap();
+    let directory_start = zip.central_directory_start() as usize;
+    let forged = {
+        let file = zip.by_name("results.json").unwrap();
+        archive[file.header_start() as usize..(file.data_start().unwrap() + file.compressed_size()) as usize].to_vec()
+    };
+
+    let mut appended = archive[..directory_start].to_vec();
+    appended.extend(&forged);
+    appended.extend(&archive[directory_start..]);
+    let end_record = appended.len() - 22;
+    let directory_start = (directory_start + forged.len()) as u32;
+    appended[end_record + 16..end_record + 20].copy_from_slice(&directory_start.to_le_bytes());
+
+    assert!(ZipArchive::new(Cursor::new(&appended)).is_ok());
+    let err = verify(&appended, None).unwrap_err();
+    assert_eq!(err.to_string(), "the bundle has data which is not in its central directory");
+
+    // A local header whose name differs from the central directory.
+    let mut renamed = archive.clone();
+    let at = zip.by_name("input/a.json").unwrap().header_start() as usize + 30;
+    renamed[at..at + 12].copy_from_slice(b"input/b.json");
+    let err = verify(&renamed, None).unwrap_err();
+    assert_eq!(err.to_string(), "the local header of input/a.json does not match the central directory");
+
+    // A file declared far larger than the bundle, in its central directory
+    // entry.
+    let mut oversized = archive.clone();
+    let size = zip.central_directory_start() as usize + 24;
+    oversized[size..size + 4].copy_from_slice(&0xfff0_0000u32.to_le_bytes());
+    let err = verify(&oversized, None).unwrap_err();
+    assert!(err.to_string().ends_with("is declared larger than the bundle"), "{err}");
+}
@@ -0,0 +1,18 @@
+use eva_rt_engine::prelude::*;
+use crate::utils::golden::*;
+
+#[test]
+fn golden_protocol() {
+    let taskset = [RTTask::new_ns(1_000_000, 4_000_000, 5_000_000)];
+    let platform = Platform::uniprocessor(SchedulingAlgorithm::FixedPriority);
+
+    let input = reference_input("up.fp.rta86", &platform, &taskset).unwrap();
+    assert_eq!(input["test"], "up.fp.rta86");
+    assert_eq!(input["platform"]["cpus"], 1);
+    assert_eq!(input["taskset"]["tasks"][0]["deadline"], 4_000_000.0);
+
+    assert_eq!(parse_verdict("non schedulable\nresponse time 12ms\n").unwrap(), SchedOutcome::NonSchedulable);
+    assert_eq!(parse_verdict(" schedulable ").unwrap(), SchedOutcome::Schedulable);
+    assert!(parse_verdict("ok").is_err());
+    assert!(parse_verdict("").is_err());
+}
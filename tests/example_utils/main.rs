@@ -0,0 +1,17 @@
+//! Tests of the utilities shared by the examples. The utilities are compiled
+//! into every example, but only tested here, once.
+
+#[path = "../../examples/utils/mod.rs"]
+#[allow(dead_code)]
+mod utils;
+
+mod analysis;
+mod anonymize;
+mod diff;
+mod dispatch_table;
+mod evidence;
+mod golden;
+mod report;
+mod rt_app;
+mod schema;
+mod taskset_serde;
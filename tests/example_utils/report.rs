@@ -0,0 +1,85 @@
+use eva_rt_engine::prelude::*;
+use eva_rt_engine::algorithms::registry::{self, Profile};
+use crate::utils::report::*;
+
+#[test]
+fn render_report() {
+    let taskset = [RTTask::new_ns(1, 4, 4), RTTask::new_ns(2, 6, 8)];
+    let platform = Platform::uniprocessor(SchedulingAlgorithm::FixedPriority);
+
+    let result: anyhow::Result<()> = Err(SchedError::NonSchedulable(Some(anyhow::format_err!("task 1 <misses> its deadline."))).into());
+    let report = Report {
+        input_file: "tasks.taskset.json",
+        taskset: &taskset,
+        platform: &platform,
+        profile: Profile::Exhaustive,
+        results: vec![
+            TestResult::from_result("up.fp.sufficient", "Sufficient", &result, std::time::Duration::ZERO, false),
+            TestResult::from_result("up.fp.exact", "Exact", &Ok(()), std::time::Duration::ZERO, true),
+        ],
+        results_with_margins: Some(vec![
+            TestResult::from_result("up.fp.exact", "Exact", &result, std::time::Duration::ZERO, true),
+        ]),
+        excluded: vec![],
+        response_times: Some(vec![Time::nanos(1.0), Time::nanos(3.0)]),
+        provenance: Provenance {
+            timestamp: 951_782_400,
+            ..Provenance::new(b"a", vec![("Selected tests".to_owned(), "up.fp".to_owned())])
+        },
+    };
+
+    assert_eq!(report.verdict(), SchedOutcome::Schedulable);
+    assert!((report.utilization_margin() - 0.5).abs() < 1e-9);
+
+    // Only the registered tests declare their assumptions.
+    let mut report = report;
+    report.results.push(TestResult::from_result("up.fp.rta86", "RTA", &Ok(()), std::time::Duration::ZERO, true));
+    let assumptions = report.assumptions();
+    assert_eq!(assumptions.len(), Assumption::SPORADIC_MODEL.len());
+    assert!(assumptions.iter().all(|(_, ids)| ids == &["up.fp.rta86"]));
+
+    let markdown = report.render(ReportFormat::Markdown);
+    assert!(markdown.contains("| `zero_overheads` | Context switches, scheduling decisions"));
+    assert!(markdown.contains("**Verdict: schedulable** (with WCET margins: non schedulable)"));
+    assert!(markdown.contains("## Tests with WCET margins"));
+    assert!(markdown.contains("xychart-beta"));
+    assert!(markdown.contains("| Input | tasks.taskset.json (FNV-1a af63dc4c8601ec8c) |"));
+    assert!(markdown.contains("| Generated | 2000-02-29T00:00:00Z |"));
+    assert!(markdown.contains("| Selected tests | up.fp |"));
+    assert!(markdown.contains("| Test up.fp.exact | unregistered |"));
+    assert!(markdown.contains("| Test up.fp.rta86 | version 1 |"));
+
+    let html = report.render(ReportFormat::Html);
+    assert!(html.contains("Non schedulable, reason: task 1 &lt;misses&gt; its deadline."));
+    assert!(html.contains("<svg"));
+    assert!(html.contains("<tr><td><code>sporadic_arrivals</code></td>"));
+}
+
+#[test]
+fn provenance() {
+    // Reference values of the 64-bit FNV-1a hash.
+    assert_eq!(fnv1a(b""), 0xcbf29ce484222325);
+    assert_eq!(fnv1a(b"a"), 0xaf63dc4c8601ec8c);
+    assert_eq!(fnv1a(b"foobar"), 0x85944171f73967e8);
+
+    assert_eq!(utc_time(0), "1970-01-01T00:00:00Z");
+    assert_eq!(utc_time(951_868_799), "2000-02-29T23:59:59Z");
+    assert_eq!(utc_time(1_700_000_000), "2023-11-14T22:13:20Z");
+
+    let provenance = Provenance::new(b"foobar", vec![("IRQ latency".to_owned(), "10us".to_owned())]);
+    assert_eq!(provenance.engine_version, env!("CARGO_PKG_VERSION"));
+    assert_eq!(provenance.input_hash, fnv1a(b"foobar"));
+    assert!(provenance.timestamp > 1_700_000_000);
+
+    // The hash of a file is the one of its content.
+    let input_file = std::env::temp_dir().join(format!("eva-provenance-{}.txt", std::process::id()));
+    std::fs::write(&input_file, b"foobar").unwrap();
+    let of_file = Provenance::of_file(input_file.to_str().unwrap(), vec![]);
+    std::fs::remove_file(&input_file).unwrap();
+    assert_eq!(of_file.unwrap().input_hash, provenance.input_hash);
+    assert!(Provenance::of_file("missing.taskset.json", vec![]).is_err());
+
+    // Only the registered tests have a version.
+    assert_eq!(registry::test_version("up.fp.rta86"), Some(1));
+    assert_eq!(registry::test_version("up.fp.unknown"), None);
+}
@@ -0,0 +1,86 @@
+use eva_rt_engine::prelude::*;
+use eva_rt_engine::algorithms::full_preemption::global_multiprocessor::hierarchical::mpr_model09::MPRModel;
+use crate::utils::rt_app::*;
+
+#[test]
+fn round_trip() {
+    let taskset = [
+        RTTask::new_ns(1_000_000, 8_000_000, 10_000_000),
+        RTTask::new_ns(2_500_000, 20_000_000, 20_000_000),
+    ];
+
+    let parameters = |tasks: &[RTTask]| -> Vec<_> {
+        tasks.iter().map(|task| (task.wcet, task.deadline, task.period)).collect()
+    };
+
+    for policy in [RtAppPolicy::Fifo, RtAppPolicy::Deadline] {
+        let workload = serialize_rt_app_taskset(&taskset, policy, 10).unwrap();
+        let imported = deserialize_rt_app_taskset(&workload).unwrap();
+
+        match policy {
+            RtAppPolicy::Deadline => assert_eq!(parameters(&imported), parameters(&taskset)),
+            // SCHED_FIFO threads have implicit deadlines.
+            RtAppPolicy::Fifo => assert_eq!(
+                parameters(&imported),
+                taskset.iter().map(|task| (task.wcet, task.period, task.period)).collect::<Vec<_>>(),
+            ),
+        }
+    }
+
+    let model = MPRModel { resource: Time::millis(5.0), period: Time::millis(10.0), concurrency: 1 };
+    let workload = serialize_rt_app_reservations(&taskset, RtAppPolicy::Deadline, &model, 10).unwrap();
+    let imported = deserialize_rt_app_taskset(&workload).unwrap();
+
+    assert_eq!(imported.len(), taskset.len() + 1);
+    assert!(parameters(&imported).contains(&(Time::millis(5.0), Time::millis(10.0), Time::millis(10.0))));
+}
+
+#[test]
+fn threads() {
+    // A thread with multiple phases is mapped to the task which dominates all
+    // of them, and the numbered events of a phase add up.
+    let data = r#"{ "tasks": {
+        "low": { "priority": 1, "instance": 2, "run": 1000, "timer": { "ref": "low", "period": 10000 } },
+        "high": { "priority": 5, "phases": {
+            "p0": { "run0": 1000, "run1": 500, "timer": { "ref": "high", "period": 20000 } },
+            "p1": { "runtime": 2000, "timer": { "ref": "high", "period": 8000 } }
+        } },
+        "dl": { "dl-runtime": 3000, "dl-deadline": 9000, "dl-period": 12000, "run": 2000, "runtime1": 500 }
+    } }"#;
+
+    let taskset = deserialize_rt_app_taskset(data).unwrap();
+    let parameters: Vec<_> = taskset.iter().map(|task| (task.wcet, task.deadline, task.period)).collect();
+
+    assert_eq!(parameters, [
+        (Time::millis(2.0), Time::millis(8.0), Time::millis(8.0)),
+        (Time::millis(1.0), Time::millis(10.0), Time::millis(10.0)),
+        (Time::millis(1.0), Time::millis(10.0), Time::millis(10.0)),
+        (Time::micros(2500.0), Time::millis(9.0), Time::millis(12.0)),
+    ]);
+
+    // Events with other suffixes are not runs.
+    let data = r#"{ "tasks": { "t": { "run": 1000, "running": 5000, "timer": { "ref": "t", "period": 10000 } } } }"#;
+    assert_eq!(deserialize_rt_app_taskset(data).unwrap()[0].wcet, Time::millis(1.0));
+}
+
+#[test]
+fn thread_errors() {
+    let error = |data: &str| deserialize_rt_app_taskset(data).unwrap_err().to_string();
+
+    assert!(error(r#"{ "global": {} }"#).contains("'tasks' object"));
+    assert!(error(r#"{ "tasks": { "t": 1 } }"#).contains("rt-app thread 't' must be an object"));
+    assert!(error(r#"{ "tasks": { "t": { "phases": {} } } }"#).contains("thread has no phases"));
+    assert!(error(r#"{ "tasks": { "t": { "run": 1000 } } }"#).contains("neither a timer nor a 'dl-period'"));
+    assert!(error(r#"{ "tasks": { "t": { "run": "long", "dl-period": 10000 } } }"#).contains("Failed to parse field 'run'"));
+    assert!(error(r#"{ "tasks": { "t": { "run": 1000, "timer": 10000 } } }"#).contains("'timer' must be an object"));
+    assert!(error(r#"{ "tasks": { "t": { "instance": -1, "run": 1000, "dl-period": 10000 } } }"#).contains("'instance'"));
+
+    // The instances are bounded before any task is built.
+    let data = format!(r#"{{ "tasks": {{ "t": {{ "instance": {}, "run": 1000, "dl-period": 10000 }} }} }}"#, u64::MAX);
+    assert!(error(&data).contains("more than"));
+
+    let data = format!(r#"{{ "tasks": {{
+        "a": {{ "instance": {}, "run": 1000, "dl-period": 10000 }},
+        "b": {{ "instance": 1, "run": 1000, "dl-period": 10000 }} }} }}"#, MAX_RT_APP_TASKS);
+    assert!(error(&data).contains("rt-app thread 'b'"));
+}
@@ -0,0 +1,178 @@
+use eva_rt_engine::prelude::*;
+use eva_rt_engine::algorithms::registry::Profile;
+use serde_json::{Map, Value, json};
+use eva_rt_engine::algorithms::full_preemption::distributed::*;
+use eva_rt_engine::algorithms::cyclic_executive::checker::Precedence;
+use crate::utils::TasksetPlainUnit;
+use crate::utils::schema::*;
+
+#[test]
+fn versioned_taskset() {
+    let data = r#"{ "version": 1, "unit": "millis", "tasks": [ { "wcet": 1, "deadline": 8, "period": 10 } ] }"#;
+    let taskset = deserialize_taskset(data.as_bytes()).unwrap();
+    assert_eq!(taskset[0].deadline, Time::millis(8.0));
+
+    let migrated = migrate_plain_taskset(&taskset, TasksetPlainUnit::Micros);
+    let taskset = deserialize_taskset(migrated.to_string().as_bytes()).unwrap();
+    assert_eq!(taskset[0].deadline, Time::millis(8.0));
+
+    // Newer versions may add fields, which are ignored.
+    let data = r#"{ "version": 4, "unit": "millis", "jitter": true, "tasks": [ { "wcet": 1, "deadline": 8, "period": 10, "jitter": 1 } ] }"#;
+    assert_eq!(deserialize_taskset(data.as_bytes()).unwrap().len(), 1);
+
+    let data = r#"{ "version": 1, "unit": "millis", "tasks": [ { "wcet": 1, "deadline": 8, "period": 10, "jitter": 1, "offset": 0 } ] }"#;
+    let err = deserialize_taskset(data.as_bytes()).unwrap_err().to_string();
+    assert!(err.contains("task 0") && err.contains("unknown field 'jitter'") && err.contains("unknown field 'offset'"));
+
+    assert!(deserialize_taskset(r#"{ "unit": "millis", "tasks": [] }"#.as_bytes()).is_err());
+
+    // Version 2 adds the WCET margins.
+    let data = r#"{ "version": 2, "unit": "millis", "tasks": [ { "wcet": 1, "deadline": 8, "period": 10, "wcet_margin": 0.2 },
+        { "wcet": 1, "deadline": 8, "period": 10 } ] }"#;
+    let (_, margins) = deserialize_annotated_taskset(data.as_bytes()).unwrap();
+    assert_eq!(margins.margins, vec![0.2, 0.0]);
+    assert!(validate(SchemaKind::Taskset, &serde_json::from_str(data).unwrap()).is_ok());
+
+    let data = data.replace("0.2", "-0.2");
+    assert!(deserialize_annotated_taskset(data.as_bytes()).unwrap_err().to_string().contains("'wcet_margin'"));
+    assert!(validate(SchemaKind::Taskset, &serde_json::from_str(&data).unwrap()).is_err());
+
+    // Version 3 adds the release jitters, in the unit of the taskset.
+    let data = r#"{ "version": 3, "unit": "millis", "tasks": [ { "wcet": 1, "deadline": 8, "period": 10, "release_jitter": 2 },
+        { "wcet": 1, "deadline": 8, "period": 10, "wcet_margin": 0.2 } ] }"#;
+    let taskset = deserialize_jittered_taskset(data.as_bytes()).unwrap();
+    assert_eq!(taskset.iter().map(|task| task.release_jitter).collect::<Vec<_>>(), vec![Time::millis(2.0), Time::zero()]);
+    assert!(validate(SchemaKind::Taskset, &serde_json::from_str(data).unwrap()).is_ok());
+
+    let data = data.replace(r#""release_jitter": 2"#, r#""release_jitter": -2"#);
+    assert!(deserialize_jittered_taskset(data.as_bytes()).unwrap_err().to_string().contains("'release_jitter'"));
+    assert!(validate(SchemaKind::Taskset, &serde_json::from_str(&data).unwrap()).is_err());
+}
+
+#[test]
+fn distributed_system() {
+    let data = r#"{ "version": 1, "unit": "millis",
+        "nodes": [ { "algorithm": "FixedPriority", "tasks": [ { "wcet": 1, "deadline": 10, "period": 10 } ] } ],
+        "networks": [ { "messages": [ { "wcet": 0.5, "deadline": 10, "period": 10 } ] } ],
+        "flows": [ { "deadline": 10, "steps": [ { "node": 0, "task": 0 }, { "network": 0, "message": 0 } ] } ] }"#;
+
+    let system = deserialize_system(data.as_bytes()).unwrap();
+    assert_eq!(system.networks[0].messages[0].wcet, Time::micros(500.0));
+    assert_eq!(system.flows[0].steps, vec![Step::Task { node: 0, task: 0 }, Step::Message { network: 0, message: 0 }]);
+    assert!(validate(SchemaKind::System, &serde_json::from_str(data).unwrap()).is_ok());
+
+    let data = data.replace(r#""network": 0, "message": 0"#, r#""network": 0, "task": 0"#);
+    assert!(deserialize_system(data.as_bytes()).unwrap_err().to_string().contains("flow 0: step 1"));
+    assert!(validate(SchemaKind::System, &serde_json::from_str(&data).unwrap()).is_err());
+}
+
+#[test]
+fn dispatch_table() {
+    let data = r#"{ "version": 1, "unit": "millis", "major_frame": 8,
+        "precedences": [ { "predecessor": 0, "successor": 1 } ],
+        "dispatches": [ { "task": 0, "job": 0, "start": 0, "duration": 1 }, { "task": 1, "job": 0, "start": 1.5, "duration": 0.5 } ] }"#;
+
+    let table = deserialize_dispatch_table(data.as_bytes()).unwrap();
+    assert_eq!(table.major_frame, Some(Time::millis(8.0)));
+    assert_eq!(table.precedences, vec![Precedence { predecessor: 0, successor: 1 }]);
+    assert_eq!(table.table[1].start, Time::micros(1500.0));
+    assert!(validate(SchemaKind::Table, &serde_json::from_str(data).unwrap()).is_ok());
+
+    let serialized = serialize_dispatch_table(&table, TasksetPlainUnit::Micros);
+    assert_eq!(deserialize_dispatch_table(serialized.to_string().as_bytes()).unwrap().table, table.table);
+    assert!(validate(SchemaKind::Table, &serialized).is_ok());
+
+    let data = data.replace(r#""job": 0, "start": 1.5"#, r#""start": 1.5"#);
+    assert!(deserialize_dispatch_table(data.as_bytes()).unwrap_err().to_string().contains("dispatch 1"));
+    assert!(validate(SchemaKind::Table, &serde_json::from_str(&data).unwrap()).is_err());
+}
+
+#[test]
+fn schema_migration() {
+    fn migrate(version: u64, mut object: Map<String, Value>) -> anyhow::Result<Map<String, Value>> {
+        assert_eq!(version, 1);
+
+        if let Some(cost) = object.remove("cost") {
+            object.insert("wcet".to_owned(), cost);
+        }
+        Ok(object)
+    }
+
+    const SCHEMA: Schema = Schema {
+        name: "task",
+        version: 2,
+        fields: &["wcet"],
+        renamed: &[(2, "cost", "wcet")],
+        migrate,
+    };
+
+    let object = |key: &str| {
+        let mut object = Map::new();
+        object.insert(key.to_owned(), json!(1));
+        object
+    };
+
+    assert_eq!(SCHEMA.upgrade(object("cost"), 1).unwrap(), object("wcet"));
+
+    let err = SCHEMA.upgrade(object("cost"), 2).unwrap_err().to_string();
+    assert!(err.contains("'cost' was renamed to 'wcet' in version 2"));
+}
+
+#[test]
+fn versioned_config() {
+    let data = r#"{ "version": 1, "platform": { "model": "GlobalMultiprocessor", "algorithm": "FixedPriority", "cpus": 4 }, "irq_latency_us": 10 }"#;
+    let config = deserialize_config(data).unwrap();
+    assert_eq!(config.platform, Platform::global(4, SchedulingAlgorithm::FixedPriority));
+    assert_eq!(config.latencies.irq_to_release, Time::micros(10.0));
+    assert_eq!(config.profile, None);
+
+    let data = r#"{ "version": 2, "platform": { "model": "Uniprocessor", "algorithm": "FixedPriority", "cpus": 1 }, "profile": "exhaustive" }"#;
+    assert_eq!(deserialize_config(data).unwrap().profile, Some(Profile::Exhaustive));
+
+    let data = r#"{ "version": 2, "platform": { "model": "Uniprocessor", "algorithm": "FixedPriority", "cpus": 1 }, "profile": "nightly" }"#;
+    assert!(deserialize_config(data).unwrap_err().to_string().contains("'profile'"));
+
+    let data = r#"{ "version": 3, "platform": { "model": "Uniprocessor", "algorithm": "FixedPriority", "cpus": 1 }, "tests": ["up.fp.rta86"] }"#;
+    assert_eq!(deserialize_config(data).unwrap().tests, vec!["up.fp.rta86"]);
+    assert!(validate(SchemaKind::Config, &serde_json::from_str(data).unwrap()).is_ok());
+
+    let data = r#"{ "version": 4, "platform": { "model": "Uniprocessor", "algorithm": "FixedPriority", "cpus": 1 }, "context_switch_us": 2, "interrupt_period_us": 1000, "interrupt_overhead_us": 5 }"#;
+    let config = deserialize_config(data).unwrap();
+    assert_eq!(config.overheads.context_switch, Time::micros(2.0));
+    assert_eq!(config.overheads.interrupt_task().unwrap().utilization(), 0.005);
+    assert!(validate(SchemaKind::Config, &serde_json::from_str(data).unwrap()).is_ok());
+
+    let data = r#"{ "version": 1, "platform": { "model": "Uniprocessor", "algorithm": "FixedPriority", "cpus": 1 }, "cpus": 2 }"#;
+    assert!(deserialize_config(data).unwrap_err().to_string().contains("unknown field 'cpus'"));
+}
+
+#[test]
+fn strict_validation() {
+    let data = json!({ "version": 1, "unit": "millis", "tasks": [ { "wcet": 1, "deadline": 8, "period": 10 } ] });
+    assert!(validate(SchemaKind::Taskset, &data).is_ok());
+
+    let data = json!({ "version": 4, "unit": "seconds", "tasks": [ { "wcet": -1, "deadline": 8, "period": 10, "jitter": 1 } ] });
+    let err = validate(SchemaKind::Taskset, &data).unwrap_err().to_string();
+    for path in ["'/version'", "'/unit'", "'/tasks/0/wcet'", "'/tasks/0'"] {
+        assert!(err.contains(path), "{err}");
+    }
+
+    let data = json!({ "version": 1, "platform": { "model": "Uniprocessor", "algorithm": "FixedPriority", "cpus": 1 }, "irq_latency_us": 10 });
+    assert!(validate(SchemaKind::Config, &data).is_ok());
+
+    let data = json!({ "version": 1, "platform": { "model": "Mainframe", "algorithm": "FixedPriority", "cpus": 1 } });
+    assert!(validate(SchemaKind::Config, &data).unwrap_err().to_string().contains("'/platform/model'"));
+}
+
+#[test]
+fn published_schemas() {
+    use clap::ValueEnum;
+
+    for kind in SchemaKind::value_variants() {
+        let name = kind.to_possible_value().unwrap().get_name().to_owned();
+        let path = format!("{}/schemas/{name}.schema.json", env!("CARGO_MANIFEST_DIR"));
+
+        let published: Value = serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(published, json_schema(*kind), "{path} is out of date, regenerate it with the json_schema example");
+    }
+}
@@ -0,0 +1,15 @@
+use eva_rt_engine::prelude::*;
+use crate::utils::taskset_serde::*;
+
+#[test]
+fn plain_reader() {
+    let data = "1 10 10\n\n  2.5 20 20  \n";
+
+    let taskset = plain_deserialize_reader(data.as_bytes(), TasksetPlainUnit::Millis).unwrap();
+    assert_eq!(taskset.len(), 2);
+    assert_eq!(taskset[1].wcet, Time::millis(2.5));
+
+    assert!(plain_deserialize_reader("1 10\n".as_bytes(), TasksetPlainUnit::Millis).is_err());
+    assert!(plain_deserialize_reader("1 10 10 10\n".as_bytes(), TasksetPlainUnit::Millis).is_err());
+    assert!(plain_deserialize_reader("1 x 10\n".as_bytes(), TasksetPlainUnit::Millis).is_err());
+}
\ No newline at end of file